@@ -1,11 +1,13 @@
-//! Shared helpers used by forge review-request adapters.
+//! Shared helpers used by forge review-request and issue adapters.
 //!
 //! Each supported forge (GitHub, GitLab) needs the same normalization for
 //! authentication failures, host-resolution failures, status-summary joining,
-//! provider label casing, and spawn-time error mapping. Keeping these in one
-//! module avoids divergence between adapters.
+//! provider label casing, issue-list merging, and spawn-time error mapping.
+//! Keeping these in one module avoids divergence between adapters.
 
-use super::{ForgeCommandError, ForgeKind, ForgeRemote, ReviewRequestError};
+use std::cmp::Reverse;
+
+use super::{ForgeCommandError, ForgeIssueSummary, ForgeKind, ForgeRemote, ReviewRequestError};
 
 /// Returns whether `detail` looks like a forge CLI authentication failure.
 ///
@@ -58,6 +60,45 @@ pub(crate) fn normalize_provider_label(label: &str) -> String {
     normalized
 }
 
+/// Merges issue summaries from several list queries, dropping duplicates and
+/// ordering newest issue numbers first.
+pub(crate) fn merge_issue_summaries(mut issues: Vec<ForgeIssueSummary>) -> Vec<ForgeIssueSummary> {
+    issues.sort_by_key(|issue| Reverse(issue_number(&issue.display_id).unwrap_or_default()));
+    issues.dedup_by(|left, right| left.display_id == right.display_id);
+
+    issues
+}
+
+/// Parses one `#123` issue display id into the numeric CLI argument.
+///
+/// # Errors
+/// Returns [`ReviewRequestError::OperationFailed`] when `display_id` is not a
+/// numeric issue reference.
+pub(crate) fn parse_issue_display_id(
+    forge_kind: ForgeKind,
+    display_id: &str,
+) -> Result<String, ReviewRequestError> {
+    issue_number(display_id)
+        .map(|number| number.to_string())
+        .ok_or_else(|| ReviewRequestError::OperationFailed {
+            forge_kind,
+            message: format!(
+                "invalid {} issue display id: `{display_id}`",
+                forge_kind.display_name()
+            ),
+        })
+}
+
+/// Returns the numeric part of one `#123` issue display id.
+fn issue_number(display_id: &str) -> Option<u64> {
+    let trimmed = display_id.trim().trim_start_matches('#');
+    if trimmed.is_empty() || !trimmed.chars().all(|character| character.is_ascii_digit()) {
+        return None;
+    }
+
+    trimmed.parse().ok()
+}
+
 /// Maps one spawn-time failure into a normalized review-request error for the
 /// forge owning `remote`.
 pub(crate) fn map_spawn_error(
@@ -90,6 +131,50 @@ pub(crate) fn map_spawn_error(
 mod tests {
     use super::*;
 
+    #[test]
+    fn merge_issue_summaries_deduplicates_and_orders_newest_first() {
+        // Arrange
+        let issues = vec![
+            issue_summary("#3"),
+            issue_summary("#10"),
+            issue_summary("#3"),
+        ];
+
+        // Act
+        let merged_issues = merge_issue_summaries(issues);
+
+        // Assert
+        assert_eq!(
+            merged_issues
+                .iter()
+                .map(|issue| issue.display_id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["#10", "#3"]
+        );
+    }
+
+    #[test]
+    fn parse_issue_display_id_strips_hash_prefix() {
+        // Arrange
+        let display_id = " #42 ";
+
+        // Act
+        let issue_number = parse_issue_display_id(ForgeKind::GitLab, display_id);
+
+        // Assert
+        assert_eq!(issue_number, Ok("42".to_string()));
+    }
+
+    fn issue_summary(display_id: &str) -> ForgeIssueSummary {
+        ForgeIssueSummary {
+            display_id: display_id.to_string(),
+            forge_kind: ForgeKind::GitHub,
+            labels: Vec::new(),
+            title: format!("Issue {display_id}"),
+            web_url: String::new(),
+        }
+    }
+
     #[test]
     fn looks_like_authentication_failure_matches_github_cli_login_prompt() {
         // Arrange
@@ -3,8 +3,8 @@
 use std::sync::Arc;
 
 use super::{
-    CreateReviewRequestInput, ForgeCommandRunner, ForgeFuture, ForgeRemote,
-    GitHubReviewRequestAdapter, GitLabReviewRequestAdapter, RealForgeCommandRunner,
+    CreateReviewRequestInput, ForgeCommandRunner, ForgeFuture, ForgeIssue, ForgeIssueSummary,
+    ForgeRemote, GitHubReviewRequestAdapter, GitLabReviewRequestAdapter, RealForgeCommandRunner,
     ReviewRequestError, ReviewRequestSummary, detect_remote,
 };
 
@@ -61,6 +61,28 @@ pub trait ReviewRequestClient: Send + Sync {
         &self,
         review_request: &ReviewRequestSummary,
     ) -> Result<String, ReviewRequestError>;
+
+    /// Lists open issues assigned to the authenticated user or labelled with
+    /// [`crate::AGENT_ISSUE_LABEL`].
+    ///
+    /// # Errors
+    /// Returns a provider-specific error when the forge lookup cannot be
+    /// completed.
+    fn list_issues(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<Vec<ForgeIssueSummary>, ReviewRequestError>>;
+
+    /// Loads one issue with its description and user comments by provider
+    /// display id.
+    ///
+    /// # Errors
+    /// Returns a provider-specific error when the issue cannot be loaded.
+    fn load_issue(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ForgeIssue, ReviewRequestError>>;
 }
 
 /// Production [`ReviewRequestClient`] that routes to forge-specific adapters.
@@ -156,6 +178,43 @@ impl ReviewRequestClient for RealReviewRequestClient {
 
         Ok(review_request.web_url.clone())
     }
+
+    fn list_issues(
+        &self,
+        remote: ForgeRemote,
+    ) -> ForgeFuture<Result<Vec<ForgeIssueSummary>, ReviewRequestError>> {
+        match remote.forge_kind {
+            super::ForgeKind::GitHub => {
+                let adapter = GitHubReviewRequestAdapter::new(Arc::clone(&self.command_runner));
+
+                Box::pin(async move { adapter.list_issues(remote).await })
+            }
+            super::ForgeKind::GitLab => {
+                let adapter = GitLabReviewRequestAdapter::new(Arc::clone(&self.command_runner));
+
+                Box::pin(async move { adapter.list_issues(remote).await })
+            }
+        }
+    }
+
+    fn load_issue(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> ForgeFuture<Result<ForgeIssue, ReviewRequestError>> {
+        match remote.forge_kind {
+            super::ForgeKind::GitHub => {
+                let adapter = GitHubReviewRequestAdapter::new(Arc::clone(&self.command_runner));
+
+                Box::pin(async move { adapter.load_issue(remote, display_id).await })
+            }
+            super::ForgeKind::GitLab => {
+                let adapter = GitLabReviewRequestAdapter::new(Arc::clone(&self.command_runner));
+
+                Box::pin(async move { adapter.load_issue(remote, display_id).await })
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1,14 +1,16 @@
-//! GitHub review-request adapter routed through the `gh` CLI.
+//! GitHub review-request and issue adapter routed through the `gh` CLI.
 
 use std::sync::Arc;
 
 use serde::Deserialize;
 
 use super::{
-    CreateReviewRequestInput, ForgeCommand, ForgeCommandOutput, ForgeCommandRunner, ForgeKind,
-    ForgeRemote, ReviewRequestError, ReviewRequestState, ReviewRequestSummary,
-    command_output_detail, looks_like_authentication_failure, looks_like_host_resolution_failure,
-    map_spawn_error, normalize_provider_label, parse_remote_url, status_summary_parts, strip_port,
+    AGENT_ISSUE_LABEL, CreateReviewRequestInput, ForgeCommand, ForgeCommandOutput,
+    ForgeCommandRunner, ForgeIssue, ForgeIssueComment, ForgeIssueSummary, ForgeKind, ForgeRemote,
+    ReviewRequestError, ReviewRequestState, ReviewRequestSummary, command_output_detail,
+    looks_like_authentication_failure, looks_like_host_resolution_failure, map_spawn_error,
+    merge_issue_summaries, normalize_provider_label, parse_issue_display_id, parse_remote_url,
+    status_summary_parts, strip_port,
 };
 
 /// GitHub pull-request adapter that normalizes `gh` command output.
@@ -67,6 +69,60 @@ impl GitHubReviewRequestAdapter {
             .await
     }
 
+    /// Lists open issues assigned to the current user or labelled for agents.
+    ///
+    /// Both queries are merged and deduplicated, newest issue first.
+    pub(crate) async fn list_issues(
+        &self,
+        remote: ForgeRemote,
+    ) -> Result<Vec<ForgeIssueSummary>, ReviewRequestError> {
+        self.ensure_authenticated(&remote).await?;
+
+        let mut issues = Vec::new();
+        for command in [
+            issue_list_command(&remote, IssueListFilter::AssignedToMe),
+            issue_list_command(&remote, IssueListFilter::AgentLabel),
+        ] {
+            let output = self
+                .run_review_command(&remote, command, "list issues")
+                .await?;
+            let listed_issues = parse_issue_list_response(&output.stdout).map_err(|message| {
+                ReviewRequestError::OperationFailed {
+                    forge_kind: ForgeKind::GitHub,
+                    message,
+                }
+            })?;
+            issues.extend(listed_issues);
+        }
+
+        Ok(merge_issue_summaries(issues))
+    }
+
+    /// Loads one issue with its description and comments by display id.
+    pub(crate) async fn load_issue(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> Result<ForgeIssue, ReviewRequestError> {
+        let issue_number = parse_issue_display_id(ForgeKind::GitHub, &display_id)?;
+        self.ensure_authenticated(&remote).await?;
+
+        let output = self
+            .run_review_command(
+                &remote,
+                issue_view_command(&remote, &issue_number),
+                "load issue",
+            )
+            .await?;
+
+        parse_issue_view_response(&output.stdout).map_err(|message| {
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::GitHub,
+                message,
+            }
+        })
+    }
+
     /// Finds one existing pull request after authentication has been verified.
     async fn find_by_source_branch_after_auth(
         &self,
@@ -287,6 +343,57 @@ fn view_command(remote: &ForgeRemote, pull_request_number: &str) -> ForgeCommand
     )
 }
 
+/// Filter applied to one `gh issue list` query.
+#[derive(Clone, Copy)]
+enum IssueListFilter {
+    /// Issues assigned to the authenticated user.
+    AssignedToMe,
+    /// Issues labelled with [`AGENT_ISSUE_LABEL`].
+    AgentLabel,
+}
+
+/// Builds the `gh issue list` command for one picker filter.
+fn issue_list_command(remote: &ForgeRemote, filter: IssueListFilter) -> ForgeCommand {
+    let (filter_flag, filter_value) = match filter {
+        IssueListFilter::AssignedToMe => ("--assignee", "@me"),
+        IssueListFilter::AgentLabel => ("--label", AGENT_ISSUE_LABEL),
+    };
+
+    github_command(
+        remote,
+        vec![
+            "issue".to_string(),
+            "list".to_string(),
+            "--repo".to_string(),
+            remote.project_path(),
+            "--state".to_string(),
+            "open".to_string(),
+            filter_flag.to_string(),
+            filter_value.to_string(),
+            "--limit".to_string(),
+            "50".to_string(),
+            "--json".to_string(),
+            "number,title,url,labels".to_string(),
+        ],
+    )
+}
+
+/// Builds the `gh issue view` command for one issue number.
+fn issue_view_command(remote: &ForgeRemote, issue_number: &str) -> ForgeCommand {
+    github_command(
+        remote,
+        vec![
+            "issue".to_string(),
+            "view".to_string(),
+            issue_number.to_string(),
+            "--repo".to_string(),
+            remote.project_path(),
+            "--json".to_string(),
+            "number,title,body,url,labels,comments".to_string(),
+        ],
+    )
+}
+
 /// Builds one base `gh` command with deterministic color settings and the
 /// optional session worktree for repository-aware git fallback commands.
 fn github_command(remote: &ForgeRemote, arguments: Vec<String>) -> ForgeCommand {
@@ -339,6 +446,40 @@ fn parse_display_id(display_id: &str) -> Result<String, ReviewRequestError> {
     Ok(trimmed.to_string())
 }
 
+/// Parses one `gh issue list --json` response into issue summaries.
+fn parse_issue_list_response(stdout: &str) -> Result<Vec<ForgeIssueSummary>, String> {
+    let issues: Vec<GitHubIssueResponse> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitHub issue list response: {error}"))?;
+
+    Ok(issues
+        .into_iter()
+        .map(GitHubIssueResponse::into_summary)
+        .collect())
+}
+
+/// Parses one `gh issue view --json` response into a full issue.
+fn parse_issue_view_response(stdout: &str) -> Result<ForgeIssue, String> {
+    let issue: GitHubIssueViewResponse = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitHub issue view response: {error}"))?;
+    let comments = issue
+        .comments
+        .into_iter()
+        .map(|comment| ForgeIssueComment {
+            author: comment
+                .author
+                .map(|author| author.login)
+                .unwrap_or_default(),
+            body: comment.body,
+        })
+        .collect();
+
+    Ok(ForgeIssue {
+        body: issue.body.unwrap_or_default(),
+        comments,
+        summary: issue.issue.into_summary(),
+    })
+}
+
 /// Formats one GitHub merge-state label for the UI.
 fn merge_state_summary(merge_state_status: Option<&str>) -> Option<String> {
     match merge_state_status {
@@ -369,6 +510,58 @@ struct GitHubLookupResponse {
     number: u64,
 }
 
+/// GitHub issue JSON payload returned by `gh issue list --json`.
+#[derive(Deserialize)]
+struct GitHubIssueResponse {
+    #[serde(default)]
+    labels: Vec<GitHubLabelResponse>,
+    number: u64,
+    title: String,
+    url: String,
+}
+
+impl GitHubIssueResponse {
+    /// Converts the GitHub payload into one normalized issue summary.
+    fn into_summary(self) -> ForgeIssueSummary {
+        ForgeIssueSummary {
+            display_id: format!("#{}", self.number),
+            forge_kind: ForgeKind::GitHub,
+            labels: self.labels.into_iter().map(|label| label.name).collect(),
+            title: self.title,
+            web_url: self.url,
+        }
+    }
+}
+
+/// GitHub issue JSON payload returned by `gh issue view --json`.
+#[derive(Deserialize)]
+struct GitHubIssueViewResponse {
+    body: Option<String>,
+    #[serde(default)]
+    comments: Vec<GitHubIssueCommentResponse>,
+    #[serde(flatten)]
+    issue: GitHubIssueResponse,
+}
+
+/// One GitHub issue comment returned by `gh issue view --json comments`.
+#[derive(Deserialize)]
+struct GitHubIssueCommentResponse {
+    author: Option<GitHubAuthorResponse>,
+    body: String,
+}
+
+/// GitHub comment author payload.
+#[derive(Deserialize)]
+struct GitHubAuthorResponse {
+    login: String,
+}
+
+/// GitHub label payload.
+#[derive(Deserialize)]
+struct GitHubLabelResponse {
+    name: String,
+}
+
 /// GitHub pull-request JSON payload returned by `gh pr view --json`.
 #[derive(Deserialize)]
 struct GitHubViewResponse {
@@ -675,6 +868,136 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn list_issues_merges_assigned_and_labelled_queries() {
+        // Arrange
+        let remote = github_remote();
+        let mut sequence = Sequence::new();
+        let mut command_runner = MockForgeCommandRunner::new();
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &auth_status_command(&remote)
+            })
+            .returning(|_| Box::pin(async { Ok(success_output(String::new())) }));
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| {
+                    command == &issue_list_command(&remote, IssueListFilter::AssignedToMe)
+                }
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(success_output(
+                        r#"[{"number":7,"title":"Fix login","url":"https://github.com/agentty-xyz/agentty/issues/7","labels":[]}]"#
+                            .to_string(),
+                    ))
+                })
+            });
+        command_runner
+            .expect_run()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf({
+                let remote = remote.clone();
+
+                move |command| command == &issue_list_command(&remote, IssueListFilter::AgentLabel)
+            })
+            .returning(|_| {
+                Box::pin(async {
+                    Ok(success_output(
+                        r#"[{"number":12,"title":"Add picker","url":"https://github.com/agentty-xyz/agentty/issues/12","labels":[{"name":"agentty"}]},{"number":7,"title":"Fix login","url":"https://github.com/agentty-xyz/agentty/issues/7","labels":[]}]"#
+                            .to_string(),
+                    ))
+                })
+            });
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(command_runner));
+
+        // Act
+        let issues = adapter
+            .list_issues(remote)
+            .await
+            .expect("GitHub issue list should succeed");
+
+        // Assert
+        assert_eq!(
+            issues,
+            vec![
+                ForgeIssueSummary {
+                    display_id: "#12".to_string(),
+                    forge_kind: ForgeKind::GitHub,
+                    labels: vec!["agentty".to_string()],
+                    title: "Add picker".to_string(),
+                    web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+                },
+                ForgeIssueSummary {
+                    display_id: "#7".to_string(),
+                    forge_kind: ForgeKind::GitHub,
+                    labels: Vec::new(),
+                    title: "Fix login".to_string(),
+                    web_url: "https://github.com/agentty-xyz/agentty/issues/7".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_issue_view_response_reads_body_and_comments() {
+        // Arrange
+        let stdout = r#"{
+            "number": 12,
+            "title": "Add picker",
+            "body": "Pick issues from the list.",
+            "url": "https://github.com/agentty-xyz/agentty/issues/12",
+            "labels": [{"name": "agentty"}],
+            "comments": [{"author": {"login": "octocat"}, "body": "Use the forge CLI."}]
+        }"#;
+
+        // Act
+        let issue = parse_issue_view_response(stdout).expect("issue view should parse");
+
+        // Assert
+        assert_eq!(issue.body, "Pick issues from the list.");
+        assert_eq!(issue.summary.display_id, "#12");
+        assert_eq!(
+            issue.comments,
+            vec![ForgeIssueComment {
+                author: "octocat".to_string(),
+                body: "Use the forge CLI.".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn load_issue_rejects_invalid_display_id_before_running_commands() {
+        // Arrange
+        let adapter = GitHubReviewRequestAdapter::new(Arc::new(MockForgeCommandRunner::new()));
+
+        // Act
+        let error = adapter
+            .load_issue(github_remote(), "#abc".to_string())
+            .await
+            .expect_err("invalid display id should be rejected");
+
+        // Assert
+        assert_eq!(
+            error,
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::GitHub,
+                message: "invalid GitHub issue display id: `#abc`".to_string(),
+            }
+        );
+    }
+
     fn github_remote() -> ForgeRemote {
         ForgeRemote {
             command_working_directory: None,
@@ -1,4 +1,4 @@
-//! GitLab review-request adapter routed through the `glab` CLI.
+//! GitLab review-request and issue adapter routed through the `glab` CLI.
 
 use std::sync::Arc;
 
@@ -6,10 +6,11 @@ use serde::Deserialize;
 use url::Url;
 
 use super::{
-    CreateReviewRequestInput, ForgeCommand, ForgeCommandOutput, ForgeCommandRunner, ForgeKind,
-    ForgeRemote, ReviewRequestError, ReviewRequestState, ReviewRequestSummary,
-    command_output_detail, is_gitlab_host, looks_like_authentication_failure,
-    looks_like_host_resolution_failure, map_spawn_error, normalize_provider_label,
+    AGENT_ISSUE_LABEL, CreateReviewRequestInput, ForgeCommand, ForgeCommandOutput,
+    ForgeCommandRunner, ForgeIssue, ForgeIssueComment, ForgeIssueSummary, ForgeKind, ForgeRemote,
+    ReviewRequestError, ReviewRequestState, ReviewRequestSummary, command_output_detail,
+    is_gitlab_host, looks_like_authentication_failure, looks_like_host_resolution_failure,
+    map_spawn_error, merge_issue_summaries, normalize_provider_label, parse_issue_display_id,
     parse_remote_url, status_summary_parts, strip_port,
 };
 
@@ -69,6 +70,70 @@ impl GitLabReviewRequestAdapter {
             .await
     }
 
+    /// Lists open issues assigned to the current user or labelled for agents.
+    ///
+    /// Both queries are merged and deduplicated, newest issue first.
+    pub(crate) async fn list_issues(
+        &self,
+        remote: ForgeRemote,
+    ) -> Result<Vec<ForgeIssueSummary>, ReviewRequestError> {
+        self.ensure_authenticated(&remote).await?;
+
+        let mut issues = Vec::new();
+        for command in [
+            issue_list_command(&remote, IssueListFilter::AssignedToMe),
+            issue_list_command(&remote, IssueListFilter::AgentLabel),
+        ] {
+            let output = self
+                .run_review_command(&remote, command, "list issues")
+                .await?;
+            let listed_issues = parse_issue_list_response(&output.stdout).map_err(|message| {
+                ReviewRequestError::OperationFailed {
+                    forge_kind: ForgeKind::GitLab,
+                    message,
+                }
+            })?;
+            issues.extend(listed_issues);
+        }
+
+        Ok(merge_issue_summaries(issues))
+    }
+
+    /// Loads one issue with its description and user notes by display id.
+    ///
+    /// System notes such as label or assignee changes are dropped so only
+    /// discussion content reaches the session prompt.
+    pub(crate) async fn load_issue(
+        &self,
+        remote: ForgeRemote,
+        display_id: String,
+    ) -> Result<ForgeIssue, ReviewRequestError> {
+        let issue_iid = parse_issue_display_id(ForgeKind::GitLab, &display_id)?;
+        self.ensure_authenticated(&remote).await?;
+
+        let issue_output = self
+            .run_review_command(
+                &remote,
+                issue_view_command(&remote, &issue_iid),
+                "load issue",
+            )
+            .await?;
+        let notes_output = self
+            .run_review_command(
+                &remote,
+                issue_notes_command(&remote, &issue_iid),
+                "load issue notes",
+            )
+            .await?;
+
+        parse_issue_response(&issue_output.stdout, &notes_output.stdout).map_err(|message| {
+            ReviewRequestError::OperationFailed {
+                forge_kind: ForgeKind::GitLab,
+                message,
+            }
+        })
+    }
+
     /// Finds one existing merge request after authentication has been
     /// verified.
     async fn find_by_source_branch_after_auth(
@@ -292,6 +357,75 @@ fn view_command(remote: &ForgeRemote, merge_request_iid: &str) -> ForgeCommand {
     )
 }
 
+/// Filter applied to one `glab issue list` query.
+#[derive(Clone, Copy)]
+enum IssueListFilter {
+    /// Issues assigned to the authenticated user.
+    AssignedToMe,
+    /// Issues labelled with [`AGENT_ISSUE_LABEL`].
+    AgentLabel,
+}
+
+/// Builds the `glab issue list` command for one picker filter.
+fn issue_list_command(remote: &ForgeRemote, filter: IssueListFilter) -> ForgeCommand {
+    let filter_argument = match filter {
+        IssueListFilter::AssignedToMe => "--assignee=@me".to_string(),
+        IssueListFilter::AgentLabel => format!("--label={AGENT_ISSUE_LABEL}"),
+    };
+
+    gitlab_command(
+        remote,
+        "glab",
+        vec![
+            "issue".to_string(),
+            "list".to_string(),
+            "--repo".to_string(),
+            remote.web_url.clone(),
+            filter_argument,
+            "--per-page".to_string(),
+            "50".to_string(),
+            "--output".to_string(),
+            "json".to_string(),
+        ],
+    )
+}
+
+/// Builds the `glab issue view` command for one issue IID.
+fn issue_view_command(remote: &ForgeRemote, issue_iid: &str) -> ForgeCommand {
+    gitlab_command(
+        remote,
+        "glab",
+        vec![
+            "issue".to_string(),
+            "view".to_string(),
+            issue_iid.to_string(),
+            "--repo".to_string(),
+            remote.web_url.clone(),
+            "--output".to_string(),
+            "json".to_string(),
+        ],
+    )
+}
+
+/// Builds the `glab api` command that lists notes for one issue IID in
+/// chronological order.
+fn issue_notes_command(remote: &ForgeRemote, issue_iid: &str) -> ForgeCommand {
+    let encoded_project_path = remote.project_path().replace('/', "%2F");
+
+    gitlab_command(
+        remote,
+        "glab",
+        vec![
+            "api".to_string(),
+            "--hostname".to_string(),
+            remote.host.clone(),
+            format!(
+                "projects/{encoded_project_path}/issues/{issue_iid}/notes?sort=asc&per_page=100"
+            ),
+        ],
+    )
+}
+
 /// Builds one base `glab` command with deterministic color settings and the
 /// optional session worktree for repository-aware host detection.
 fn gitlab_command(
@@ -379,6 +513,43 @@ fn parse_display_id(display_id: &str) -> Result<String, ReviewRequestError> {
     Ok(trimmed.to_string())
 }
 
+/// Parses one `glab issue list --output json` response into issue summaries.
+fn parse_issue_list_response(stdout: &str) -> Result<Vec<ForgeIssueSummary>, String> {
+    let issues: Vec<GitLabIssueResponse> = serde_json::from_str(stdout)
+        .map_err(|error| format!("invalid GitLab issue list response: {error}"))?;
+
+    Ok(issues
+        .into_iter()
+        .map(GitLabIssueResponse::into_summary)
+        .collect())
+}
+
+/// Parses one GitLab issue payload plus its notes into a full issue.
+fn parse_issue_response(issue_stdout: &str, notes_stdout: &str) -> Result<ForgeIssue, String> {
+    let issue: GitLabIssueResponse = serde_json::from_str(issue_stdout)
+        .map_err(|error| format!("invalid GitLab issue view response: {error}"))?;
+    let notes: Vec<GitLabNoteResponse> = serde_json::from_str(notes_stdout)
+        .map_err(|error| format!("invalid GitLab issue notes response: {error}"))?;
+    let comments = notes
+        .into_iter()
+        .filter(|note| !note.system)
+        .map(|note| ForgeIssueComment {
+            author: note
+                .author
+                .map(|author| author.username)
+                .unwrap_or_default(),
+            body: note.body,
+        })
+        .collect();
+    let body = issue.description.clone().unwrap_or_default();
+
+    Ok(ForgeIssue {
+        body,
+        comments,
+        summary: issue.into_summary(),
+    })
+}
+
 /// Formats one GitLab merge-status label for the UI.
 fn merge_status_summary(
     merge_status: Option<&str>,
@@ -404,6 +575,47 @@ struct GitLabLookupResponse {
     iid: u64,
 }
 
+/// GitLab issue JSON payload returned by `glab issue list` and
+/// `glab issue view`.
+#[derive(Deserialize)]
+struct GitLabIssueResponse {
+    description: Option<String>,
+    iid: u64,
+    #[serde(default)]
+    labels: Vec<String>,
+    title: String,
+    #[serde(rename = "web_url")]
+    web_url: String,
+}
+
+impl GitLabIssueResponse {
+    /// Converts the GitLab payload into one normalized issue summary.
+    fn into_summary(self) -> ForgeIssueSummary {
+        ForgeIssueSummary {
+            display_id: format!("#{}", self.iid),
+            forge_kind: ForgeKind::GitLab,
+            labels: self.labels,
+            title: self.title,
+            web_url: self.web_url,
+        }
+    }
+}
+
+/// One GitLab issue note returned by the notes API.
+#[derive(Deserialize)]
+struct GitLabNoteResponse {
+    author: Option<GitLabAuthorResponse>,
+    body: String,
+    #[serde(default)]
+    system: bool,
+}
+
+/// GitLab note author payload.
+#[derive(Deserialize)]
+struct GitLabAuthorResponse {
+    username: String,
+}
+
 /// GitLab merge-request JSON payload returned by `glab mr view --output json`.
 #[derive(Deserialize)]
 struct GitLabViewResponse {
@@ -704,6 +916,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn issue_list_command_filters_by_agent_label() {
+        // Arrange
+        let remote = gitlab_remote();
+
+        // Act
+        let command = issue_list_command(&remote, IssueListFilter::AgentLabel);
+
+        // Assert
+        assert_eq!(command.executable, "glab");
+        assert!(
+            command
+                .arguments
+                .iter()
+                .any(|argument| argument == "--label=agentty")
+        );
+    }
+
+    #[test]
+    fn issue_notes_command_encodes_project_path() {
+        // Arrange
+        let remote = gitlab_remote();
+
+        // Act
+        let command = issue_notes_command(&remote, "12");
+
+        // Assert
+        assert_eq!(
+            command.arguments.last().map(String::as_str),
+            Some("projects/agentty-xyz%2Fagentty/issues/12/notes?sort=asc&per_page=100")
+        );
+    }
+
+    #[test]
+    fn parse_issue_response_drops_system_notes() {
+        // Arrange
+        let issue_stdout = r#"{
+            "iid": 12,
+            "title": "Add picker",
+            "description": null,
+            "labels": ["agentty"],
+            "web_url": "https://gitlab.com/agentty-xyz/agentty/-/issues/12"
+        }"#;
+        let notes_stdout = r#"[
+            {"body": "added ~agentty label", "system": true, "author": {"username": "bot"}},
+            {"body": "Use glab.", "system": false, "author": {"username": "tanuki"}}
+        ]"#;
+
+        // Act
+        let issue =
+            parse_issue_response(issue_stdout, notes_stdout).expect("issue response should parse");
+
+        // Assert
+        assert_eq!(issue.body, "");
+        assert_eq!(issue.summary.display_id, "#12");
+        assert_eq!(issue.summary.labels, vec!["agentty".to_string()]);
+        assert_eq!(
+            issue.comments,
+            vec![ForgeIssueComment {
+                author: "tanuki".to_string(),
+                body: "Use glab.".to_string(),
+            }]
+        );
+    }
+
     /// Builds one normalized GitLab remote for command-construction tests.
     fn gitlab_remote() -> ForgeRemote {
         ForgeRemote {
@@ -1,4 +1,5 @@
-//! Forge review-request adapters, normalized types, and remote detection.
+//! Forge review-request and issue adapters, normalized types, and remote
+//! detection.
 
 mod adapter_common;
 mod client;
@@ -10,7 +11,7 @@ mod remote;
 
 pub(crate) use adapter_common::{
     looks_like_authentication_failure, looks_like_host_resolution_failure, map_spawn_error,
-    normalize_provider_label, status_summary_parts,
+    merge_issue_summaries, normalize_provider_label, parse_issue_display_id, status_summary_parts,
 };
 #[cfg(any(test, feature = "test-utils"))]
 pub use client::MockReviewRequestClient;
@@ -22,8 +23,9 @@ pub(crate) use command::{
 pub(crate) use github::GitHubReviewRequestAdapter;
 pub(crate) use gitlab::GitLabReviewRequestAdapter;
 pub use model::{
-    CreateReviewRequestInput, ForgeFuture, ForgeKind, ForgeRemote, ReviewRequestError,
-    ReviewRequestState, ReviewRequestSummary, is_gitlab_host,
+    AGENT_ISSUE_LABEL, CreateReviewRequestInput, ForgeFuture, ForgeIssue, ForgeIssueComment,
+    ForgeIssueSummary, ForgeKind, ForgeRemote, ReviewRequestError, ReviewRequestState,
+    ReviewRequestSummary, is_gitlab_host,
 };
 pub use remote::detect_remote;
 pub(crate) use remote::{parse_remote_url, strip_port};
@@ -71,6 +71,29 @@ impl ForgeKind {
             Self::GitLab => "MR",
         }
     }
+
+    /// Returns repository-relative review-request template paths in lookup
+    /// order.
+    ///
+    /// GitHub accepts the template in the repository root, `.github/`, or
+    /// `docs/`. GitLab reads the project default from
+    /// `.gitlab/merge_request_templates/`.
+    pub fn review_request_template_paths(self) -> &'static [&'static str] {
+        match self {
+            Self::GitHub => &[
+                ".github/pull_request_template.md",
+                ".github/PULL_REQUEST_TEMPLATE.md",
+                "pull_request_template.md",
+                "PULL_REQUEST_TEMPLATE.md",
+                "docs/pull_request_template.md",
+                "docs/PULL_REQUEST_TEMPLATE.md",
+            ],
+            Self::GitLab => &[
+                ".gitlab/merge_request_templates/Default.md",
+                ".gitlab/merge_request_templates/default.md",
+            ],
+        }
+    }
 }
 
 /// Returns whether `host` looks like one GitLab instance hostname.
@@ -244,6 +267,44 @@ pub struct CreateReviewRequestInput {
     pub title: String,
 }
 
+/// Label that marks forge issues as ready to be picked up by agent sessions.
+pub const AGENT_ISSUE_LABEL: &str = "agentty";
+
+/// Normalized summary for one open forge issue shown in the issue picker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForgeIssueSummary {
+    /// Provider display id such as `#123`.
+    pub display_id: String,
+    /// Forge family that owns the issue.
+    pub forge_kind: ForgeKind,
+    /// Issue label names in provider order.
+    pub labels: Vec<String>,
+    /// Issue title.
+    pub title: String,
+    /// Browser-openable issue URL.
+    pub web_url: String,
+}
+
+/// One user-authored discussion comment attached to a forge issue.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForgeIssueComment {
+    /// Login or username of the comment author.
+    pub author: String,
+    /// Markdown comment body.
+    pub body: String,
+}
+
+/// Full forge issue content used to seed one session prompt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForgeIssue {
+    /// Markdown issue description, empty when the issue has none.
+    pub body: String,
+    /// User-authored comments in chronological order.
+    pub comments: Vec<ForgeIssueComment>,
+    /// Issue identity and title metadata.
+    pub summary: ForgeIssueSummary,
+}
+
 /// Review-request failures normalized for actionable UI messaging.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReviewRequestError {
@@ -414,6 +475,25 @@ fn invalid_web_url_error(remote: &ForgeRemote) -> ReviewRequestError {
 mod tests {
     use super::*;
 
+    #[test]
+    fn review_request_template_paths_prefer_forge_specific_directories() {
+        // Arrange
+        let github_paths = ForgeKind::GitHub.review_request_template_paths();
+        let gitlab_paths = ForgeKind::GitLab.review_request_template_paths();
+
+        // Act
+        let first_github_path = github_paths.first();
+        let first_gitlab_path = gitlab_paths.first();
+
+        // Assert
+        assert_eq!(first_github_path, Some(&".github/pull_request_template.md"));
+        assert_eq!(
+            first_gitlab_path,
+            Some(&".gitlab/merge_request_templates/Default.md")
+        );
+        assert_eq!(github_paths.len(), 6);
+    }
+
     #[test]
     fn authentication_required_message_includes_original_cli_error_detail() {
         // Arrange
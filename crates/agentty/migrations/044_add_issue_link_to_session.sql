@@ -0,0 +1,5 @@
+ALTER TABLE session
+ADD COLUMN issue_display_id TEXT;
+
+ALTER TABLE session
+ADD COLUMN issue_web_url TEXT;
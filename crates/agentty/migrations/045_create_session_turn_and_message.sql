@@ -0,0 +1,121 @@
+CREATE TABLE session_turn (
+    session_id TEXT NOT NULL REFERENCES session(id) ON DELETE CASCADE,
+    turn_number INTEGER NOT NULL,
+    answer TEXT NOT NULL DEFAULT '',
+    created_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    input_tokens INTEGER NOT NULL DEFAULT 0,
+    output_tokens INTEGER NOT NULL DEFAULT 0,
+    prompt TEXT NOT NULL DEFAULT '',
+    questions TEXT,
+    summary TEXT,
+    PRIMARY KEY (session_id, turn_number)
+);
+
+CREATE TABLE session_message (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    session_id TEXT NOT NULL REFERENCES session(id) ON DELETE CASCADE,
+    turn_number INTEGER NOT NULL,
+    content TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    role TEXT NOT NULL
+);
+
+CREATE INDEX session_message_session_id_idx ON session_message (session_id, id);
+
+-- Split legacy transcripts at prompt markers (` › ` at the start of a line).
+-- Text before the first prompt becomes turn 0. Each later chunk starts with
+-- its prompt block, which ends at the first blank line because prompt
+-- continuation lines are always indented.
+CREATE TEMP TABLE legacy_transcript_chunk AS
+WITH RECURSIVE transcript_chunk(session_id, turn_number, chunk, rest) AS (
+    SELECT
+        id,
+        0,
+        CASE
+            WHEN substr(output, 1, 3) = ' › ' THEN ''
+            WHEN instr(output, char(10) || ' › ') > 0
+                THEN substr(output, 1, instr(output, char(10) || ' › ') - 1)
+            ELSE output
+        END,
+        CASE
+            WHEN substr(output, 1, 3) = ' › ' THEN output
+            WHEN instr(output, char(10) || ' › ') > 0
+                THEN substr(output, instr(output, char(10) || ' › '))
+            ELSE ''
+        END
+    FROM session
+    WHERE output <> ''
+    UNION ALL
+    SELECT
+        session_id,
+        turn_number + 1,
+        CASE
+            WHEN instr(substr(rest, 3), char(10) || ' › ') > 0
+                THEN substr(rest, 1, instr(substr(rest, 3), char(10) || ' › ') + 1)
+            ELSE rest
+        END,
+        CASE
+            WHEN instr(substr(rest, 3), char(10) || ' › ') > 0
+                THEN substr(rest, instr(substr(rest, 3), char(10) || ' › ') + 2)
+            ELSE ''
+        END
+    FROM transcript_chunk
+    WHERE rest <> ''
+)
+SELECT
+    session_id,
+    turn_number,
+    CASE
+        WHEN turn_number = 0 THEN ''
+        WHEN instr(substr(chunk, 2), char(10) || char(10)) > 0
+            THEN substr(chunk, 1, instr(substr(chunk, 2), char(10) || char(10)) + 2)
+        ELSE chunk
+    END AS prompt_block,
+    CASE
+        WHEN turn_number = 0 THEN chunk
+        WHEN instr(substr(chunk, 2), char(10) || char(10)) > 0
+            THEN substr(chunk, instr(substr(chunk, 2), char(10) || char(10)) + 3)
+        ELSE ''
+    END AS answer
+FROM transcript_chunk
+WHERE turn_number > 0 OR chunk <> '';
+
+INSERT INTO session_turn (session_id, turn_number, answer, prompt)
+SELECT
+    session_id,
+    turn_number,
+    trim(answer, char(10)),
+    substr(replace(trim(prompt_block, char(10)), char(10) || '   ', char(10)), 4)
+FROM legacy_transcript_chunk
+WHERE turn_number > 0;
+
+UPDATE session_turn
+SET questions = (SELECT questions FROM session WHERE session.id = session_turn.session_id),
+    summary = (SELECT summary FROM session WHERE session.id = session_turn.session_id)
+WHERE turn_number = (
+    SELECT MAX(latest_turn.turn_number)
+    FROM session_turn AS latest_turn
+    WHERE latest_turn.session_id = session_turn.session_id
+);
+
+INSERT INTO session_message (session_id, turn_number, content, role)
+SELECT session_id, turn_number, content, role
+FROM (
+    SELECT session_id, turn_number, prompt_block AS content, 'user' AS role, 0 AS position
+    FROM legacy_transcript_chunk
+    WHERE turn_number > 0
+    UNION ALL
+    SELECT
+        session_id,
+        turn_number,
+        answer AS content,
+        CASE WHEN turn_number = 0 THEN 'system' ELSE 'assistant' END AS role,
+        1 AS position
+    FROM legacy_transcript_chunk
+    WHERE answer <> ''
+)
+ORDER BY session_id, turn_number, position;
+
+DROP TABLE legacy_transcript_chunk;
+
+ALTER TABLE session DROP COLUMN output;
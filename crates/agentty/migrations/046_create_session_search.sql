@@ -0,0 +1,117 @@
+-- Full-text indexes for the global session search overlay.
+--
+-- `session_search` holds one row per non-empty session title and summary.
+-- Structured JSON summaries are flattened to their string leaves so object
+-- keys do not match every query. `session_message_search` indexes transcript
+-- messages in place through `session_message.id`.
+CREATE VIRTUAL TABLE session_search USING fts5(
+    session_id UNINDEXED,
+    source UNINDEXED,
+    body,
+    tokenize = 'porter unicode61'
+);
+
+CREATE VIRTUAL TABLE session_message_search USING fts5(
+    content,
+    content = 'session_message',
+    content_rowid = 'id',
+    tokenize = 'porter unicode61'
+);
+
+INSERT INTO session_search (session_id, source, body)
+SELECT id, 'title', title
+FROM session
+WHERE trim(COALESCE(title, '')) <> '';
+
+INSERT INTO session_search (session_id, source, body)
+SELECT
+    id,
+    'summary',
+    CASE
+        WHEN json_valid(summary)
+            THEN (
+                SELECT group_concat(summary_leaf.value, char(10))
+                FROM json_tree(summary) AS summary_leaf
+                WHERE summary_leaf.type = 'text'
+            )
+        ELSE summary
+    END
+FROM session
+WHERE trim(COALESCE(summary, '')) <> '';
+
+INSERT INTO session_message_search (session_message_search) VALUES ('rebuild');
+
+CREATE TRIGGER session_search_after_insert
+AFTER INSERT ON session
+BEGIN
+    INSERT INTO session_search (session_id, source, body)
+    SELECT NEW.id, 'title', NEW.title
+    WHERE trim(COALESCE(NEW.title, '')) <> '';
+
+    INSERT INTO session_search (session_id, source, body)
+    SELECT
+        NEW.id,
+        'summary',
+        CASE
+            WHEN json_valid(NEW.summary)
+                THEN (
+                    SELECT group_concat(summary_leaf.value, char(10))
+                    FROM json_tree(NEW.summary) AS summary_leaf
+                    WHERE summary_leaf.type = 'text'
+                )
+            ELSE NEW.summary
+        END
+    WHERE trim(COALESCE(NEW.summary, '')) <> '';
+END;
+
+CREATE TRIGGER session_search_after_update
+AFTER UPDATE OF title, summary ON session
+BEGIN
+    DELETE FROM session_search WHERE session_id = OLD.id;
+
+    INSERT INTO session_search (session_id, source, body)
+    SELECT NEW.id, 'title', NEW.title
+    WHERE trim(COALESCE(NEW.title, '')) <> '';
+
+    INSERT INTO session_search (session_id, source, body)
+    SELECT
+        NEW.id,
+        'summary',
+        CASE
+            WHEN json_valid(NEW.summary)
+                THEN (
+                    SELECT group_concat(summary_leaf.value, char(10))
+                    FROM json_tree(NEW.summary) AS summary_leaf
+                    WHERE summary_leaf.type = 'text'
+                )
+            ELSE NEW.summary
+        END
+    WHERE trim(COALESCE(NEW.summary, '')) <> '';
+END;
+
+CREATE TRIGGER session_search_after_delete
+AFTER DELETE ON session
+BEGIN
+    DELETE FROM session_search WHERE session_id = OLD.id;
+END;
+
+CREATE TRIGGER session_message_search_after_insert
+AFTER INSERT ON session_message
+BEGIN
+    INSERT INTO session_message_search (rowid, content) VALUES (NEW.id, NEW.content);
+END;
+
+CREATE TRIGGER session_message_search_after_update
+AFTER UPDATE OF content ON session_message
+BEGIN
+    INSERT INTO session_message_search (session_message_search, rowid, content)
+    VALUES ('delete', OLD.id, OLD.content);
+    INSERT INTO session_message_search (rowid, content) VALUES (NEW.id, NEW.content);
+END;
+
+CREATE TRIGGER session_message_search_after_delete
+AFTER DELETE ON session_message
+BEGIN
+    INSERT INTO session_message_search (session_message_search, rowid, content)
+    VALUES ('delete', OLD.id, OLD.content);
+END;
@@ -0,0 +1,22 @@
+-- Per-project prompt history searched from the prompt composer.
+--
+-- Each project keeps one row per distinct prompt text. Resubmitting a prompt
+-- replaces its row, so `id` order is most-recent-use order.
+CREATE TABLE prompt_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    project_id INTEGER NOT NULL REFERENCES project(id) ON DELETE CASCADE,
+    prompt TEXT NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch()),
+    UNIQUE (project_id, prompt)
+);
+
+-- Seed history from prompts already sent in existing sessions, oldest first
+-- so the newest prompt receives the highest id.
+INSERT INTO prompt_history (project_id, prompt, created_at)
+SELECT session.project_id, session_turn.prompt, MAX(session_turn.created_at)
+FROM session_turn
+JOIN session ON session.id = session_turn.session_id
+WHERE session.project_id IS NOT NULL
+  AND trim(session_turn.prompt) <> ''
+GROUP BY session.project_id, session_turn.prompt
+ORDER BY MAX(session_turn.created_at), MIN(session_turn.rowid);
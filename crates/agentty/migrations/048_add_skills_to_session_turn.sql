@@ -0,0 +1,3 @@
+-- Names of the `SKILL.md` skills attached to each turn's prompt, stored as a
+-- JSON array of strings in attachment order.
+ALTER TABLE session_turn ADD COLUMN skills TEXT NOT NULL DEFAULT '[]';
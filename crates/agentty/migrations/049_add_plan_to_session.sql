@@ -0,0 +1,4 @@
+-- Plan-first state for sessions that must get an approved plan before edits,
+-- stored as a JSON object with `status` and ordered `steps`. `NULL` marks a
+-- regular session.
+ALTER TABLE session ADD COLUMN plan TEXT;
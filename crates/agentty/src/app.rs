@@ -7,7 +7,9 @@ mod assist;
 mod branch_publish;
 mod core;
 mod error;
+mod issue;
 mod merge_queue;
+mod notification;
 mod project;
 mod reducer;
 mod review;
@@ -25,6 +27,7 @@ pub use core::{AGENTTY_WT_DIR, App, UpdateStatus, agentty_home};
 pub(crate) use core::{AppClients, MockSyncMainRunner};
 pub(crate) use core::{AppEvent, SessionStatsUsage};
 
+pub(crate) use branch_publish::ReviewRequestDraft;
 pub use error::AppError;
 pub use project::ProjectManager;
 pub(crate) use review::{
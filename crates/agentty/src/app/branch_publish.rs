@@ -1,15 +1,22 @@
 //! Branch-publish workflow helpers for session review branches.
 
-use std::path::PathBuf;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
 use std::sync::Arc;
 
 use ag_forge as forge;
+use askama::Template;
 
 use super::session::{self, Clock, unix_timestamp_from_system_time};
 use crate::app::review_request;
-use crate::domain::session::{PublishBranchAction, ReviewRequest, Session, SessionId, Status};
-use crate::infra::db;
+use crate::domain::agent::{AgentModel, ReasoningLevel};
+use crate::domain::session::{
+    PublishBranchAction, ReviewRequest, Session, SessionId, SessionIssue, Status,
+};
+use crate::infra::fs::FsClient;
 use crate::infra::git::GitClient;
+use crate::infra::{agent, db};
 use crate::ui::state::app_mode::ConfirmationViewMode;
 
 /// Session snapshot cloned into a branch-publish background task.
@@ -22,11 +29,16 @@ pub(crate) struct BranchPublishTaskSession {
     pub(crate) folder: PathBuf,
     /// Stable session identifier.
     pub(crate) id: SessionId,
+    /// Forge issue that seeded the session, closed by new review requests.
+    pub(crate) issue: Option<SessionIssue>,
     /// Persisted upstream reference from a previous push, when the session
     /// already tracks one.
     pub(crate) published_upstream_ref: Option<String>,
     /// Persisted linked review request, when the session already tracks one.
     pub(crate) review_request: Option<ReviewRequest>,
+    /// Template-filled title and body confirmed in the publish popup, used
+    /// instead of the head commit message when creating a review request.
+    pub(crate) review_request_draft: Option<ReviewRequestDraft>,
     /// Current session lifecycle state checked before push.
     pub(crate) status: Status,
 }
@@ -38,13 +50,54 @@ impl BranchPublishTaskSession {
             base_branch: session.base_branch.clone(),
             folder: session.folder.clone(),
             id: session.id.clone(),
+            issue: session.issue.clone(),
             published_upstream_ref: session.published_upstream_ref.clone(),
             review_request: session.review_request.clone(),
+            review_request_draft: None,
             status: session.status,
         }
     }
 }
 
+/// User-approved review-request title and body that replace the
+/// commit-message defaults when publishing creates a new review request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct ReviewRequestDraft {
+    /// Optional review-request description.
+    pub(crate) body: Option<String>,
+    /// Single-line review-request title.
+    pub(crate) title: String,
+}
+
+/// Inputs needed to fill one repository review-request template in the
+/// background.
+pub(crate) struct ReviewRequestDraftInput {
+    /// Base branch the session diff is compared against.
+    pub(crate) base_branch: String,
+    /// Session worktree that contains the template and branch commits.
+    pub(crate) folder: PathBuf,
+    pub(crate) fs_client: Arc<dyn FsClient>,
+    pub(crate) git_client: Arc<dyn GitClient>,
+    /// Model used for the one-shot template-filling prompt.
+    pub(crate) model: AgentModel,
+    /// Latest persisted session summary, when one exists.
+    pub(crate) session_summary: Option<String>,
+    /// Repository-relative template path returned by
+    /// [`find_review_request_template`].
+    pub(crate) template_path: String,
+}
+
+/// Askama view model for rendering review-request template prompts.
+#[derive(Template)]
+#[template(path = "review_request_template_prompt.md", escape = "none")]
+struct ReviewRequestTemplatePromptTemplate<'a> {
+    commit_message: &'a str,
+    diff_stats: &'a str,
+    session_summary: &'a str,
+    template: &'a str,
+    template_path: &'a str,
+}
+
 /// Final reducer payload for a completed branch-publish background action.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct BranchPublishActionUpdate {
@@ -286,6 +339,154 @@ pub(crate) async fn run_branch_publish_action(
     }
 }
 
+/// Returns the first repository review-request template found in `folder`.
+///
+/// GitHub locations are checked before GitLab ones because the forge is not
+/// resolved until publish time; repositories rarely ship both.
+pub(crate) fn find_review_request_template(
+    fs_client: &dyn FsClient,
+    folder: &Path,
+) -> Option<String> {
+    [forge::ForgeKind::GitHub, forge::ForgeKind::GitLab]
+        .into_iter()
+        .flat_map(|forge_kind| forge_kind.review_request_template_paths().iter())
+        .find(|template_path| fs_client.is_file(folder.join(template_path)))
+        .map(|template_path| (*template_path).to_string())
+}
+
+/// Fills one repository review-request template from the session summary,
+/// head commit message, and diff stats using a one-shot utility prompt.
+///
+/// # Errors
+/// Returns an error when the template, commit message, or diff cannot be
+/// loaded, or when the agent returns no usable title.
+pub(crate) async fn prepare_review_request_draft(
+    input: ReviewRequestDraftInput,
+) -> Result<ReviewRequestDraft, String> {
+    prepare_review_request_draft_with_submitter(input, |folder, model, prompt| {
+        Box::pin(async move {
+            agent::submit_one_shot(agent::OneShotRequest {
+                child_pid: None,
+                folder,
+                model,
+                prompt,
+                request_kind: crate::infra::channel::AgentRequestKind::UtilityPrompt,
+                reasoning_level: ReasoningLevel::default(),
+            })
+            .await
+        })
+    })
+    .await
+}
+
+/// Fills one review-request template using an injected one-shot submitter so
+/// the workflow can be tested without subprocess execution.
+async fn prepare_review_request_draft_with_submitter<Submitter>(
+    input: ReviewRequestDraftInput,
+    submitter: Submitter,
+) -> Result<ReviewRequestDraft, String>
+where
+    Submitter: for<'submit> FnOnce(
+        &'submit Path,
+        AgentModel,
+        &'submit str,
+    ) -> Pin<
+        Box<dyn Future<Output = Result<agent::AgentResponse, String>> + Send + 'submit>,
+    >,
+{
+    let ReviewRequestDraftInput {
+        base_branch,
+        folder,
+        fs_client,
+        git_client,
+        model,
+        session_summary,
+        template_path,
+    } = input;
+    let template = fs_client
+        .read_file(folder.join(&template_path))
+        .await
+        .map_err(|error| {
+            format!("Failed to read review-request template `{template_path}`: {error}")
+        })?;
+    let template = String::from_utf8_lossy(&template);
+    let commit_message = git_client
+        .head_commit_message(folder.clone())
+        .await
+        .map_err(|error| format!("Failed to load session branch commit message: {error}"))?
+        .unwrap_or_default();
+    let diff = git_client
+        .diff(folder.clone(), base_branch)
+        .await
+        .map_err(|error| format!("Failed to load session diff: {error}"))?;
+    let diff_stats = review_request_diff_stats(&diff);
+    let prompt = ReviewRequestTemplatePromptTemplate {
+        commit_message: commit_message.trim(),
+        diff_stats: &diff_stats,
+        session_summary: session_summary.as_deref().map_or("", str::trim),
+        template: template.trim(),
+        template_path: &template_path,
+    }
+    .render()
+    .map_err(|error| format!("Failed to render `review_request_template_prompt.md`: {error}"))?;
+    let agent_response = submitter(&folder, model, &prompt).await?;
+    let review_request_message = review_request::parse_review_request_commit_message(
+        &agent_response.to_answer_display_text(),
+    )
+    .ok_or_else(|| "Review-request template generation returned empty output.".to_string())?;
+
+    Ok(ReviewRequestDraft {
+        body: review_request_message.body,
+        title: review_request_message.title,
+    })
+}
+
+/// Summarizes one unified diff as per-file added and deleted line counts
+/// followed by a total line.
+fn review_request_diff_stats(diff: &str) -> String {
+    let mut file_stats: Vec<(&str, u64, u64)> = Vec::new();
+
+    for line in diff.lines() {
+        if let Some(paths) = line.strip_prefix("diff --git ") {
+            let file_path = paths.rsplit_once(" b/").map_or(paths, |(_, path)| path);
+            file_stats.push((file_path, 0, 0));
+
+            continue;
+        }
+
+        let Some((_, added_lines, deleted_lines)) = file_stats.last_mut() else {
+            continue;
+        };
+        if line.starts_with('+') && !line.starts_with("+++") {
+            *added_lines += 1;
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            *deleted_lines += 1;
+        }
+    }
+
+    let (total_added_lines, total_deleted_lines) = file_stats.iter().fold(
+        (0_u64, 0_u64),
+        |(total_added_lines, total_deleted_lines), (_, added_lines, deleted_lines)| {
+            (
+                total_added_lines + added_lines,
+                total_deleted_lines + deleted_lines,
+            )
+        },
+    );
+    let mut diff_stats = file_stats
+        .iter()
+        .map(|(file_path, added_lines, deleted_lines)| {
+            format!("{file_path} | +{added_lines} -{deleted_lines}")
+        })
+        .collect::<Vec<_>>();
+    diff_stats.push(format!(
+        "{} files changed, +{total_added_lines} -{total_deleted_lines}",
+        file_stats.len()
+    ));
+
+    diff_stats.join("\n")
+}
+
 /// Returns whether error output looks like a git push authentication failure.
 /// Returns whether `normalized_detail` (already lower-cased) contains any
 /// credential- or authentication-related keywords produced by git remote
@@ -590,6 +791,11 @@ async fn review_request_remote(
 
 /// Creates or refreshes one review request for the published session branch and
 /// persists the normalized summary.
+///
+/// A session-provided review-request draft replaces the commit-message title
+/// and body only when a new review request is created; existing requests keep
+/// their forge text. New review requests for issue-seeded sessions also get
+/// the issue closing reference appended to their body.
 async fn create_or_refresh_review_request(
     branch_publish_session: &BranchPublishTaskSession,
     clock: &Arc<dyn Clock>,
@@ -599,19 +805,11 @@ async fn create_or_refresh_review_request(
     remote: forge::ForgeRemote,
     source_branch: String,
 ) -> Result<ReviewRequest, BranchPublishTaskFailure> {
-    let review_request_summary =
-        if let Some(review_request) = &branch_publish_session.review_request {
-            review_request_client
-                .refresh_review_request(remote, review_request.summary.display_id.clone())
-                .await
-                .map_err(|error| {
-                    BranchPublishTaskFailure::failed(
-                        PublishBranchAction::PublishPullRequest,
-                        error.detail_message(),
-                    )
-                })?
-        } else if let Some(existing_review_request) = review_request_client
-            .find_by_source_branch(remote.clone(), source_branch.clone())
+    let review_request_summary = if let Some(review_request) =
+        &branch_publish_session.review_request
+    {
+        review_request_client
+            .refresh_review_request(remote, review_request.summary.display_id.clone())
             .await
             .map_err(|error| {
                 BranchPublishTaskFailure::failed(
@@ -619,31 +817,53 @@ async fn create_or_refresh_review_request(
                     error.detail_message(),
                 )
             })?
-        {
-            review_request_client
-                .refresh_review_request(remote, existing_review_request.display_id)
-                .await
-                .map_err(|error| {
-                    BranchPublishTaskFailure::failed(
-                        PublishBranchAction::PublishPullRequest,
-                        error.detail_message(),
-                    )
-                })?
-        } else {
-            let create_input =
+    } else if let Some(existing_review_request) = review_request_client
+        .find_by_source_branch(remote.clone(), source_branch.clone())
+        .await
+        .map_err(|error| {
+            BranchPublishTaskFailure::failed(
+                PublishBranchAction::PublishPullRequest,
+                error.detail_message(),
+            )
+        })?
+    {
+        review_request_client
+            .refresh_review_request(remote, existing_review_request.display_id)
+            .await
+            .map_err(|error| {
+                BranchPublishTaskFailure::failed(
+                    PublishBranchAction::PublishPullRequest,
+                    error.detail_message(),
+                )
+            })?
+    } else {
+        let mut create_input = match branch_publish_session.review_request_draft.clone() {
+            Some(review_request_draft) => forge::CreateReviewRequestInput {
+                body: review_request_draft.body,
+                source_branch,
+                target_branch: branch_publish_session.base_branch.clone(),
+                title: review_request_draft.title,
+            },
+            None => {
                 load_review_request_create_input(branch_publish_session, git_client, source_branch)
-                    .await?;
-
-            review_request_client
-                .create_review_request(remote, create_input)
-                .await
-                .map_err(|error| {
-                    BranchPublishTaskFailure::failed(
-                        PublishBranchAction::PublishPullRequest,
-                        error.detail_message(),
-                    )
-                })?
+                    .await?
+            }
         };
+        create_input.body = review_request::append_issue_closing_reference(
+            create_input.body,
+            branch_publish_session.issue.as_ref(),
+        );
+
+        review_request_client
+            .create_review_request(remote, create_input)
+            .await
+            .map_err(|error| {
+                BranchPublishTaskFailure::failed(
+                    PublishBranchAction::PublishPullRequest,
+                    error.detail_message(),
+                )
+            })?
+    };
     let review_request = ReviewRequest {
         last_refreshed_at: unix_timestamp_from_system_time(clock.now_system_time()),
         summary: review_request_summary,
@@ -850,7 +1070,109 @@ fn strip_port(host: &str) -> &str {
 mod tests {
     use super::*;
     use crate::infra::db::Database;
-    use crate::infra::git;
+    use crate::infra::{fs, git};
+
+    #[test]
+    fn find_review_request_template_prefers_github_locations() {
+        // Arrange
+        let session_folder = PathBuf::from("/tmp/session-worktree");
+        let mut mock_fs_client = fs::MockFsClient::new();
+        mock_fs_client.expect_is_file().returning({
+            let session_folder = session_folder.clone();
+            move |path| {
+                path == session_folder.join("docs/pull_request_template.md")
+                    || path == session_folder.join(".gitlab/merge_request_templates/Default.md")
+            }
+        });
+
+        // Act
+        let template_path = find_review_request_template(&mock_fs_client, &session_folder);
+
+        // Assert
+        assert_eq!(
+            template_path.as_deref(),
+            Some("docs/pull_request_template.md")
+        );
+    }
+
+    #[test]
+    fn review_request_diff_stats_counts_lines_per_file() {
+        // Arrange
+        let diff = "diff --git a/src/lib.rs b/src/lib.rs\n--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ \
+                    -1 +1,2 @@\n-old\n+new\n+extra\ndiff --git a/README.md b/README.md\n--- \
+                    a/README.md\n+++ b/README.md\n@@ -1 +0,0 @@\n-removed";
+
+        // Act
+        let diff_stats = review_request_diff_stats(diff);
+
+        // Assert
+        assert_eq!(
+            diff_stats,
+            "src/lib.rs | +2 -1\nREADME.md | +0 -1\n2 files changed, +2 -2"
+        );
+    }
+
+    #[tokio::test]
+    async fn prepare_review_request_draft_with_submitter_parses_title_and_body() {
+        // Arrange
+        let session_folder = PathBuf::from("/tmp/session-worktree");
+        let mut mock_fs_client = fs::MockFsClient::new();
+        mock_fs_client
+            .expect_read_file()
+            .once()
+            .withf({
+                let session_folder = session_folder.clone();
+                move |path| path == &session_folder.join(".github/pull_request_template.md")
+            })
+            .returning(|_| Box::pin(async { Ok(b"## Summary\n<!-- describe -->".to_vec()) }));
+        let mut mock_git_client = git::MockGitClient::new();
+        mock_git_client
+            .expect_head_commit_message()
+            .once()
+            .returning(|_| Box::pin(async { Ok(Some("Add templates".to_string())) }));
+        mock_git_client
+            .expect_diff()
+            .once()
+            .withf(|_, base_branch| base_branch == "main")
+            .returning(|_, _| {
+                Box::pin(async { Ok("diff --git a/a.rs b/a.rs\n+line".to_string()) })
+            });
+        let input = ReviewRequestDraftInput {
+            base_branch: "main".to_string(),
+            folder: session_folder,
+            fs_client: Arc::new(mock_fs_client),
+            git_client: Arc::new(mock_git_client),
+            model: AgentModel::Gpt54,
+            session_summary: Some("Adds review-request templates.".to_string()),
+            template_path: ".github/pull_request_template.md".to_string(),
+        };
+
+        // Act
+        let review_request_draft =
+            prepare_review_request_draft_with_submitter(input, |_, _, prompt| {
+                let prompt = prompt.to_string();
+
+                Box::pin(async move {
+                    assert!(prompt.contains("<!-- describe -->"));
+                    assert!(prompt.contains("Adds review-request templates."));
+                    assert!(prompt.contains("a.rs | +1 -0"));
+
+                    Ok(agent::AgentResponse::plain(
+                        "Fill review-request templates\n\n## Summary\nAdds templates.",
+                    ))
+                })
+            })
+            .await;
+
+        // Assert
+        assert_eq!(
+            review_request_draft,
+            Ok(ReviewRequestDraft {
+                body: Some("## Summary\nAdds templates.".to_string()),
+                title: "Fill review-request templates".to_string(),
+            })
+        );
+    }
 
     #[tokio::test]
     async fn review_request_remote_attaches_session_worktree_to_detected_remote() {
@@ -860,8 +1182,10 @@ mod tests {
             base_branch: "main".to_string(),
             folder: session_folder.clone(),
             id: "session-id".into(),
+            issue: None,
             published_upstream_ref: None,
             review_request: None,
+            review_request_draft: None,
             status: Status::Review,
         };
         let mut mock_git_client = git::MockGitClient::new();
@@ -2,8 +2,10 @@
 //!
 //! This parent module intentionally stays router-only and re-exports the
 //! `App` facade plus focused child modules for state, startup, draw, reducer,
-//! and roadmap behavior.
+//! control API, and roadmap behavior.
 
+mod control;
+mod dashboard;
 mod draw;
 mod events;
 mod new;
@@ -0,0 +1,461 @@
+//! Local control API dispatch for the app core module.
+//!
+//! Requests arrive from [`crate::infra::control_socket`] as
+//! [`AppEvent::ControlRequested`] events, are queued by the reducer, and run
+//! here from the runtime loop because session workflows drain queued app
+//! events themselves.
+
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Value, json};
+
+use super::events::AppEvent;
+use super::state::App;
+use crate::domain::agent::AgentModel;
+use crate::domain::session::{Session, SessionId, Status};
+use crate::infra::control_socket::{ControlError, ControlRequest, ControlResult, ControlServer};
+use crate::ui::state::app_mode::AppMode;
+
+/// Notification method published whenever one session snapshot changes.
+const SESSION_UPDATED_NOTIFICATION: &str = "session.updated";
+
+/// Parameters for methods that target one session.
+#[derive(Deserialize)]
+struct SessionIdParams {
+    session_id: String,
+}
+
+/// Parameters accepted by `session.create`.
+#[derive(Default, Deserialize)]
+struct CreateSessionParams {
+    model: Option<String>,
+    prompt: Option<String>,
+}
+
+/// Parameters accepted by `session.reply`.
+#[derive(Deserialize)]
+struct ReplyParams {
+    prompt: String,
+    session_id: String,
+}
+
+/// Parameters accepted by `session.set_model`.
+#[derive(Deserialize)]
+struct SetModelParams {
+    model: String,
+    session_id: String,
+}
+
+impl App {
+    /// Starts the local control socket server at `socket_path`.
+    ///
+    /// Incoming requests are forwarded into the app event channel and answered
+    /// from the runtime loop. When another instance owns the socket or binding
+    /// fails, the app runs without the control API and the status bar shows
+    /// why.
+    pub async fn start_control_server(&mut self, socket_path: PathBuf) {
+        let app_event_tx = self.services.event_sender();
+        let control_server = ControlServer::start(socket_path, move |call| {
+            // Fire-and-forget: a dropped receiver drops the responder, which
+            // answers the client with an internal error.
+            let _ = app_event_tx.send(AppEvent::ControlRequested { call });
+        })
+        .await;
+
+        match control_server {
+            Ok(control_server) => {
+                self.control_server = Some(control_server);
+                self.control_api_error = None;
+            }
+            Err(error) => self.control_api_error = Some(error.to_string()),
+        }
+    }
+
+    /// Runs work deferred by the reducer until after event reduction.
+    ///
+    /// Issue-seeded session creation and control requests both start session
+    /// workflows that drain queued app events, so they cannot run inside
+    /// [`App::apply_app_events`].
+    pub(crate) async fn run_deferred_workflows(&mut self) {
+        self.start_pending_issue_session().await;
+
+        for call in std::mem::take(&mut self.pending_control_calls) {
+            let result = self.handle_control_request(call.request).await;
+            call.responder.respond(result);
+        }
+    }
+
+    /// Publishes `session.updated` notifications for touched sessions to
+    /// control clients subscribed to events.
+    pub(super) fn publish_control_session_updates(&self, session_ids: &HashSet<SessionId>) {
+        let Some(control_server) = &self.control_server else {
+            return;
+        };
+
+        for session in self
+            .sessions
+            .sessions
+            .iter()
+            .filter(|session| session_ids.contains(&session.id))
+        {
+            control_server.publish(
+                SESSION_UPDATED_NOTIFICATION,
+                &json!({
+                    "session_id": session.id.as_str(),
+                    "status": session.status.to_string(),
+                    "title": session.title,
+                }),
+            );
+        }
+    }
+
+    /// Executes one control request against the running app.
+    async fn handle_control_request(&mut self, request: ControlRequest) -> ControlResult {
+        let params = request.params;
+
+        match request.method.as_str() {
+            "session.list" => Ok(Value::Array(
+                self.sessions
+                    .sessions
+                    .iter()
+                    .map(|session| session_value(session, false))
+                    .collect(),
+            )),
+            "session.get" => {
+                let SessionIdParams { session_id } = parse_params(params)?;
+
+                Ok(session_value(self.control_session(&session_id)?, true))
+            }
+            "session.create" => self.control_create_session(parse_params(params)?).await,
+            "session.reply" => self.control_reply(parse_params(params)?).await,
+            "session.set_model" => {
+                let SetModelParams { model, session_id } = parse_params(params)?;
+                let model = parse_model(&model)?;
+                self.control_session(&session_id)?;
+                self.set_session_model(&session_id, model)
+                    .await
+                    .map_err(|error| ControlError::rejected(error.to_string()))?;
+
+                Ok(json!({ "session_id": session_id }))
+            }
+            "session.cancel" => {
+                let SessionIdParams { session_id } = parse_params(params)?;
+                self.control_session(&session_id)?;
+                self.cancel_session(&session_id)
+                    .await
+                    .map_err(|error| ControlError::rejected(error.to_string()))?;
+
+                Ok(json!({ "session_id": session_id }))
+            }
+            "session.delete" => self.control_delete_session(parse_params(params)?).await,
+            method => Err(ControlError::method_not_found(method)),
+        }
+    }
+
+    /// Creates one session, applies an optional model, and starts it when a
+    /// prompt is provided.
+    async fn control_create_session(&mut self, params: CreateSessionParams) -> ControlResult {
+        let model = params.model.as_deref().map(parse_model).transpose()?;
+        let session_id = self
+            .create_session()
+            .await
+            .map_err(|error| ControlError::rejected(error.to_string()))?;
+
+        if let Some(model) = model {
+            self.set_session_model(&session_id, model)
+                .await
+                .map_err(|error| ControlError::rejected(error.to_string()))?;
+        }
+
+        if let Some(prompt) = params.prompt.filter(|prompt| !prompt.trim().is_empty()) {
+            self.start_session(&session_id, prompt)
+                .await
+                .map_err(|error| ControlError::rejected(error.to_string()))?;
+        }
+
+        Ok(json!({ "session_id": session_id }))
+    }
+
+    /// Submits one prompt to a session, starting it when it is still `New`.
+    async fn control_reply(&mut self, params: ReplyParams) -> ControlResult {
+        let ReplyParams { prompt, session_id } = params;
+        if prompt.trim().is_empty() {
+            return Err(ControlError::invalid_params("`prompt` must not be empty"));
+        }
+
+        let status = self.control_session(&session_id)?.status;
+        match status {
+            Status::New => self
+                .start_session(&session_id, prompt)
+                .await
+                .map_err(|error| ControlError::rejected(error.to_string()))?,
+            Status::Review | Status::AgentReview | Status::Question => {
+                self.reply(&session_id, prompt).await;
+            }
+            status => {
+                return Err(ControlError::rejected(format!(
+                    "Session `{session_id}` cannot accept a reply while {status}"
+                )));
+            }
+        }
+
+        Ok(json!({ "session_id": session_id }))
+    }
+
+    /// Deletes one session while the session list is the active view.
+    ///
+    /// Deletion is limited to list mode so a control client cannot remove the
+    /// session behind an open view or popup.
+    async fn control_delete_session(&mut self, params: SessionIdParams) -> ControlResult {
+        let session_id = params.session_id;
+        if !matches!(self.mode, AppMode::List) {
+            return Err(ControlError::rejected(
+                "Sessions can only be deleted while the session list is shown",
+            ));
+        }
+        let Some(session_index) = self.session_index_for_id(&session_id) else {
+            return Err(unknown_session_error(&session_id));
+        };
+
+        self.sessions.table_state.select(Some(session_index));
+        self.delete_selected_session_deferred_cleanup().await;
+
+        Ok(json!({ "session_id": session_id }))
+    }
+
+    /// Returns the session snapshot for `session_id`.
+    fn control_session(&self, session_id: &str) -> Result<&Session, ControlError> {
+        self.sessions
+            .sessions
+            .iter()
+            .find(|session| session.id == session_id)
+            .ok_or_else(|| unknown_session_error(session_id))
+    }
+}
+
+/// Deserializes method parameters, treating omitted params as an empty
+/// object.
+fn parse_params<T: DeserializeOwned>(params: Value) -> Result<T, ControlError> {
+    let params = if params.is_null() { json!({}) } else { params };
+
+    serde_json::from_value(params).map_err(|error| ControlError::invalid_params(error.to_string()))
+}
+
+/// Parses one model identifier such as `gpt-5.4`.
+fn parse_model(model: &str) -> Result<AgentModel, ControlError> {
+    model.parse().map_err(ControlError::invalid_params)
+}
+
+/// Builds the error returned for unknown session identifiers.
+fn unknown_session_error(session_id: &str) -> ControlError {
+    ControlError::invalid_params(format!("Unknown session `{session_id}`"))
+}
+
+/// Serializes one session snapshot for control clients, including the
+/// transcript only when `include_output` is set.
+fn session_value(session: &Session, include_output: bool) -> Value {
+    let mut value = json!({
+        "created_at": session.created_at,
+        "id": session.id.as_str(),
+        "issue": session.issue.as_ref().map(|issue| json!({
+            "display_id": issue.display_id,
+            "web_url": issue.web_url,
+        })),
+        "model": session.model.as_str(),
+        "project_name": session.project_name,
+        "prompt": session.prompt,
+        "review_request_url": session
+            .review_request
+            .as_ref()
+            .map(|review_request| review_request.summary.web_url.as_str()),
+        "status": session.status.to_string(),
+        "summary": session.summary,
+        "title": session.title,
+        "updated_at": session.updated_at,
+    });
+
+    if include_output {
+        value["output"] = Value::String(session.output.clone());
+    }
+
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::app::AppClients;
+    use crate::domain::agent::AgentKind;
+    use crate::domain::session::tests::SessionFixtureBuilder;
+    use crate::infra::agent::StaticAgentAvailabilityProbe;
+    use crate::infra::control_socket::{CONTROL_SOCKET_FILE, ControlCall};
+    use crate::infra::db::Database;
+
+    /// Builds one test app rooted at a temporary workspace.
+    async fn new_test_app() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let clients = AppClients::new().with_agent_availability_probe(Arc::new(
+            StaticAgentAvailabilityProbe {
+                available_agent_kinds: AgentKind::ALL.to_vec(),
+            },
+        ));
+        let app = App::new_with_clients(base_path.clone(), base_path, None, database, clients)
+            .await
+            .expect("failed to build test app");
+
+        (app, base_dir)
+    }
+
+    #[tokio::test]
+    async fn test_start_control_server_records_error_when_socket_is_owned() {
+        // Arrange
+        let (mut owner_app, base_dir) = new_test_app().await;
+        let (mut second_app, _second_base_dir) = new_test_app().await;
+        let socket_path = base_dir.path().join(CONTROL_SOCKET_FILE);
+        owner_app.start_control_server(socket_path.clone()).await;
+
+        // Act
+        second_app.start_control_server(socket_path).await;
+
+        // Assert
+        assert!(owner_app.control_server.is_some());
+        assert_eq!(owner_app.control_api_error, None);
+        assert!(second_app.control_server.is_none());
+        assert!(
+            second_app
+                .control_api_error
+                .as_deref()
+                .is_some_and(|error| error.contains("Another agentty instance"))
+        );
+    }
+
+    /// Builds one control request for `method` with `params`.
+    fn control_request(method: &str, params: Value) -> ControlRequest {
+        ControlRequest {
+            method: method.to_string(),
+            params,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_control_request_lists_and_gets_sessions() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let session = SessionFixtureBuilder::new()
+            .id("session-1")
+            .status(Status::Review)
+            .output("agent output")
+            .build();
+        app.sessions.push_session(session);
+
+        // Act
+        let list = app
+            .handle_control_request(control_request("session.list", Value::Null))
+            .await
+            .expect("list should succeed");
+        let detail = app
+            .handle_control_request(control_request(
+                "session.get",
+                json!({ "session_id": "session-1" }),
+            ))
+            .await
+            .expect("get should succeed");
+
+        // Assert
+        assert_eq!(list[0]["id"], json!("session-1"));
+        assert_eq!(list[0]["status"], json!("Review"));
+        assert!(list[0].get("output").is_none());
+        assert_eq!(detail["output"], json!("agent output"));
+    }
+
+    #[tokio::test]
+    async fn test_handle_control_request_rejects_unknown_methods_and_sessions() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+
+        // Act
+        let unknown_method = app
+            .handle_control_request(control_request("session.rename", Value::Null))
+            .await;
+        let unknown_session = app
+            .handle_control_request(control_request(
+                "session.get",
+                json!({ "session_id": "missing" }),
+            ))
+            .await;
+        let missing_params = app
+            .handle_control_request(control_request("session.reply", Value::Null))
+            .await;
+
+        // Assert
+        assert_eq!(
+            unknown_method.expect_err("should fail").code,
+            ControlError::METHOD_NOT_FOUND
+        );
+        assert_eq!(
+            unknown_session.expect_err("should fail").code,
+            ControlError::INVALID_PARAMS
+        );
+        assert_eq!(
+            missing_params.expect_err("should fail").code,
+            ControlError::INVALID_PARAMS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_control_request_rejects_reply_to_busy_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let session = SessionFixtureBuilder::new()
+            .id("session-1")
+            .status(Status::InProgress)
+            .build();
+        app.sessions.push_session(session);
+
+        // Act
+        let result = app
+            .handle_control_request(control_request(
+                "session.reply",
+                json!({ "session_id": "session-1", "prompt": "Continue" }),
+            ))
+            .await;
+
+        // Assert
+        assert_eq!(
+            result.expect_err("should fail").code,
+            ControlError::REJECTED
+        );
+    }
+
+    #[tokio::test]
+    async fn test_control_requested_event_is_answered_by_deferred_workflows() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let (call, mut receiver) = ControlCall::new(control_request("session.list", Value::Null));
+
+        // Act
+        app.apply_app_events(AppEvent::ControlRequested { call })
+            .await;
+        let pending_before_run = app.pending_control_calls.len();
+        app.run_deferred_workflows().await;
+
+        // Assert
+        assert_eq!(pending_before_run, 1);
+        assert!(app.pending_control_calls.is_empty());
+        assert_eq!(
+            receiver.try_recv().expect("response should be sent"),
+            Ok(json!([]))
+        );
+    }
+}
@@ -0,0 +1,65 @@
+//! Dashboard tile selection helpers for the app core module.
+
+use super::state::App;
+use crate::ui::page::dashboard::dashboard_session_indexes;
+
+impl App {
+    /// Returns the raw session index highlighted on the `Dashboard` tab.
+    ///
+    /// Falls back to the first running session when nothing is selected yet
+    /// or the remembered session stopped running.
+    pub fn selected_dashboard_session_index(&self) -> Option<usize> {
+        let session_indexes = dashboard_session_indexes(&self.sessions.sessions);
+
+        self.dashboard_selected_session_id
+            .as_ref()
+            .and_then(|selected_session_id| {
+                session_indexes.iter().copied().find(|session_index| {
+                    self.sessions.sessions[*session_index].id == *selected_session_id
+                })
+            })
+            .or_else(|| session_indexes.first().copied())
+    }
+
+    /// Highlights the session at raw `session_index` on the `Dashboard` tab.
+    pub fn select_dashboard_session(&mut self, session_index: usize) {
+        self.dashboard_selected_session_id = self
+            .sessions
+            .sessions
+            .get(session_index)
+            .map(|session| session.id.clone());
+    }
+
+    /// Moves the dashboard highlight to the next running session, wrapping
+    /// to the first tile.
+    pub fn next_dashboard_session(&mut self) {
+        self.step_dashboard_selection(|position, tile_count| (position + 1) % tile_count);
+    }
+
+    /// Moves the dashboard highlight to the previous running session,
+    /// wrapping to the last tile.
+    pub fn previous_dashboard_session(&mut self) {
+        self.step_dashboard_selection(|position, tile_count| {
+            (position + tile_count - 1) % tile_count
+        });
+    }
+
+    /// Moves the dashboard highlight to the tile position returned by `step`.
+    fn step_dashboard_selection(&mut self, step: impl Fn(usize, usize) -> usize) {
+        let session_indexes = dashboard_session_indexes(&self.sessions.sessions);
+        if session_indexes.is_empty() {
+            return;
+        }
+
+        let position = self
+            .selected_dashboard_session_index()
+            .and_then(|selected_index| {
+                session_indexes
+                    .iter()
+                    .position(|session_index| *session_index == selected_index)
+            })
+            .unwrap_or(0);
+
+        self.select_dashboard_session(session_indexes[step(position, session_indexes.len())]);
+    }
+}
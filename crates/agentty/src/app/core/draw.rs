@@ -3,8 +3,10 @@
 use ratatui::Frame;
 
 use super::roadmap::ActiveProjectRoadmap;
-use super::state::{App, UpdateStatus};
-use crate::app::session;
+use super::state::{App, UpdateStatus, agentty_home};
+use crate::domain::session::SESSION_DATA_DIR;
+use crate::infra::prompt_template::{self, PROMPTS_DIR};
+use crate::infra::skill::{self, SKILLS_DIR};
 use crate::ui;
 
 impl App {
@@ -35,11 +37,31 @@ impl App {
     }
 
     /// Builds prompt slash-menu state from the cached machine-scoped agent
-    /// availability snapshot.
+    /// availability snapshot and the current prompt template and skill
+    /// files.
+    ///
+    /// Templates and skills are re-read each time a prompt opens so edits
+    /// under `~/.agentty/`, `<project>/skills/`, or `<project>/.agentty/`
+    /// apply without a restart.
     pub(crate) fn prompt_slash_state(&self) -> crate::ui::state::prompt::PromptSlashState {
+        let project_data_dir = self.working_dir().join(SESSION_DATA_DIR);
+        let prompt_templates = prompt_template::load_prompt_templates(
+            &agentty_home().join(PROMPTS_DIR),
+            &project_data_dir.join(PROMPTS_DIR),
+        );
+        let skills = skill::load_skills(
+            &agentty_home().join(SKILLS_DIR),
+            &[
+                self.working_dir().join(SKILLS_DIR).as_path(),
+                project_data_dir.join(SKILLS_DIR).as_path(),
+            ],
+        );
+
         crate::ui::state::prompt::PromptSlashState::with_available_agent_kinds(
             self.services.available_agent_kinds(),
         )
+        .with_prompt_templates(prompt_templates)
+        .with_skills(skills)
     }
 
     /// Returns the newer stable `agentty` version when an update is available.
@@ -59,6 +81,7 @@ impl App {
         self.tabs.normalize(has_tasks_tab);
         let active_project_id = self.projects.active_project_id();
         let current_tab = self.tabs.current();
+        let dashboard_selected_session_index = self.selected_dashboard_session_index();
         let working_dir = self.projects.working_dir().to_path_buf();
         let git_branch = self.projects.git_branch().map(str::to_string);
         let git_upstream_ref = self.projects.git_upstream_ref().map(str::to_string);
@@ -82,11 +105,9 @@ impl App {
         let session_branch_names = self.sessions.session_branch_names().clone();
         let session_index_by_id = self.sessions.state().session_index_by_id().clone();
         let session_worktree_availability = self.sessions.session_worktree_availability().clone();
-        let active_prompt_outputs = self.sessions.active_prompt_outputs().clone();
         let session_progress_messages = self.session_progress_messages.clone();
         let update_status = self.update_status().cloned();
-        let wall_clock_unix_seconds =
-            session::unix_timestamp_from_system_time(self.sessions.state().clock.now_system_time());
+        let wall_clock_unix_seconds = self.wall_clock_unix_seconds();
         let status_bar_fyi_rotation_index =
             u64::try_from(wall_clock_unix_seconds.div_euclid(60)).unwrap_or_default();
         let projects = self.projects.project_items().to_vec();
@@ -100,12 +121,15 @@ impl App {
             ui::RenderContext {
                 active_project_id,
                 current_tab,
+                dashboard_selected_session_index,
                 has_tasks_tab,
                 git_branch: git_branch.as_deref(),
                 git_upstream_ref: git_upstream_ref.as_deref(),
                 git_status,
                 latest_available_version: latest_available_version.as_deref(),
+                control_api_error: self.control_api_error.as_deref(),
                 markdown_render_cache: &self.markdown_render_cache,
+                diff_highlight_cache: &self.diff_highlight_cache,
                 update_status: update_status.as_ref(),
                 mode,
                 project_table_state,
@@ -113,7 +137,6 @@ impl App {
                 task_roadmap: task_roadmap.as_deref(),
                 task_roadmap_error: task_roadmap_error.as_deref(),
                 task_roadmap_scroll_offset: self.task_roadmap_scroll_offset,
-                active_prompt_outputs: &active_prompt_outputs,
                 session_branch_names: &session_branch_names,
                 session_git_statuses: &session_git_statuses,
                 session_index_by_id: &session_index_by_id,
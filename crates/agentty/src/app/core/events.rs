@@ -5,7 +5,7 @@ use std::collections::{HashMap, HashSet};
 
 use app::branch_publish::{
     BranchPublishActionUpdate, BranchPublishTaskResult, BranchPublishTaskSuccess,
-    branch_publish_loading_label as branch_publish_loading_label_text,
+    ReviewRequestDraft, branch_publish_loading_label as branch_publish_loading_label_text,
     branch_publish_loading_message as branch_publish_loading_message_text,
     branch_publish_loading_title as branch_publish_loading_title_text,
     branch_publish_success_title as branch_publish_success_title_text,
@@ -13,6 +13,7 @@ use app::branch_publish::{
     is_git_push_authentication_error,
     pull_request_publish_success_message as pull_request_publish_success_message_text,
 };
+use app::notification;
 use app::reducer::AppEventReducer;
 use app::review::{ReviewUpdate, apply_review_updates, auto_start_reviews};
 
@@ -24,12 +25,19 @@ use crate::app::session::{
 use crate::app::session_state::SessionGitStatus;
 use crate::domain::input::InputState;
 use crate::domain::session::{
-    PublishBranchAction, PublishedBranchSyncStatus, SessionId, SessionSize, Status,
+    ForgeIssue, ForgeIssueSummary, PublishBranchAction, PublishedBranchSyncStatus, SessionId,
+    SessionSize, Status,
 };
+use crate::infra::control_socket::ControlCall;
 use crate::infra::file_index::FileEntry;
+use crate::infra::symbol_index::SymbolIndex;
+use crate::runtime::CapturedContext;
 use crate::runtime::mode::{question, sync_blocked};
-use crate::ui::state::app_mode::{AppMode, ConfirmationViewMode, QuestionFocus};
-use crate::ui::state::prompt::PromptAtMentionState;
+use crate::ui::state::app_mode::{
+    AppMode, ConfirmationViewMode, IssuePickerStatus, QuestionFocus, ReviewRequestDraftFocus,
+    ReviewRequestDraftStatus,
+};
+use crate::ui::state::prompt::{PromptAtMentionState, fail_prompt_context};
 
 /// Internal app events emitted by background workers and workflows.
 ///
@@ -42,6 +50,19 @@ pub(crate) enum AppEvent {
         entries: Vec<FileEntry>,
         session_id: SessionId,
     },
+    /// Indicates a background-built `@#` symbol index for one session.
+    AtMentionSymbolsLoaded {
+        session_id: SessionId,
+        symbol_index: SymbolIndex,
+    },
+    /// Indicates completion of one background `/run` or `/url` capture for
+    /// a pending prompt context placeholder.
+    PromptContextCaptured {
+        capture_id: u64,
+        directive_line: String,
+        result: Result<CapturedContext, String>,
+        session_id: SessionId,
+    },
     /// Indicates the latest project-branch and session-branch ahead/behind
     /// information from the git status worker.
     GitStatusUpdated {
@@ -64,6 +85,11 @@ pub(crate) enum AppEvent {
         reasoning_level_override: Option<crate::domain::agent::ReasoningLevel>,
         session_id: SessionId,
     },
+    /// Indicates a session plan-first state has been persisted.
+    SessionPlanUpdated {
+        plan: Option<crate::domain::plan::SessionPlan>,
+        session_id: SessionId,
+    },
     /// Requests a full session list refresh.
     RefreshSessions,
     /// Requests an immediate git-status refresh outside the periodic poll
@@ -98,6 +124,21 @@ pub(crate) enum AppEvent {
         result: Box<BranchPublishTaskResult>,
         session_id: SessionId,
     },
+    /// Indicates completion of one review-request template draft for the
+    /// publish popup.
+    ReviewRequestDraftPrepared {
+        result: Result<ReviewRequestDraft, String>,
+        session_id: SessionId,
+    },
+    /// Indicates completion of the issue-picker open-issue listing.
+    IssuesListed {
+        result: Result<Vec<ForgeIssueSummary>, String>,
+    },
+    /// Indicates completion of one selected issue load for issue-seeded
+    /// session creation.
+    IssueLoaded {
+        result: Box<Result<ForgeIssue, String>>,
+    },
     /// Indicates review assist output became available for a session.
     ReviewPrepared {
         diff_hash: u64,
@@ -125,6 +166,8 @@ pub(crate) enum AppEvent {
         sync_operation_id: String,
         sync_status: PublishedBranchSyncStatus,
     },
+    /// Carries one local control-socket request awaiting an answer.
+    ControlRequested { call: ControlCall },
     /// Indicates completion of one background review-request status refresh.
     ReviewRequestStatusUpdated {
         result: Result<SyncReviewRequestTaskResult, String>,
@@ -137,16 +180,23 @@ pub(crate) enum AppEvent {
 pub(super) struct AppEventBatch {
     pub(super) applied_turns: HashMap<SessionId, TurnAppliedState>,
     pub(super) at_mention_entries_updates: HashMap<SessionId, Vec<FileEntry>>,
+    pub(super) at_mention_symbol_updates: HashMap<SessionId, SymbolIndex>,
     pub(super) branch_publish_action_update: Option<BranchPublishActionUpdate>,
+    pub(super) control_calls: Vec<ControlCall>,
+    pub(super) prompt_context_captures: Vec<PromptContextCapture>,
     pub(super) git_status_update: Option<GitStatusBatchUpdate>,
+    pub(super) issue_list_result: Option<Result<Vec<ForgeIssueSummary>, String>>,
+    pub(super) issue_load_result: Option<Result<ForgeIssue, String>>,
     pub(super) latest_available_version_update: Option<LatestAvailableVersionUpdate>,
     pub(super) published_branch_sync_updates: Vec<(SessionId, PublishedBranchSyncUpdate)>,
+    pub(super) review_request_draft_update: Option<(SessionId, Result<ReviewRequestDraft, String>)>,
     pub(super) review_updates: HashMap<SessionId, ReviewUpdate>,
     pub(super) session_git_status_updates: HashMap<SessionId, SessionGitStatus>,
     pub(super) session_ids: HashSet<SessionId>,
     pub(super) session_model_updates: HashMap<SessionId, crate::domain::agent::AgentModel>,
     pub(super) session_reasoning_level_updates:
         HashMap<SessionId, Option<crate::domain::agent::ReasoningLevel>>,
+    pub(super) session_plan_updates: HashMap<SessionId, Option<crate::domain::plan::SessionPlan>>,
     pub(super) session_progress_updates: HashMap<SessionId, Option<String>>,
     pub(super) session_size_updates: HashMap<SessionId, (u64, u64, SessionSize)>,
     pub(super) session_title_generation_finished: HashMap<SessionId, u64>,
@@ -179,6 +229,19 @@ pub(super) struct PublishedBranchSyncUpdate {
     sync_status: PublishedBranchSyncStatus,
 }
 
+/// Completed background prompt context capture ready for reducer
+/// application.
+pub(super) struct PromptContextCapture {
+    /// Id of the pending context attachment this capture fills.
+    capture_id: u64,
+    /// Directive line restored in place of the placeholder on failure.
+    directive_line: String,
+    /// Captured text, or the human-readable capture failure.
+    result: Result<CapturedContext, String>,
+    /// Session whose prompt composer started the capture.
+    session_id: SessionId,
+}
+
 /// Completed review-request status refresh payload ready for reducer
 /// application.
 pub(super) struct ReviewRequestStatusUpdate {
@@ -194,12 +257,8 @@ impl AppEventBatch {
     /// tick preserves cumulative usage from multiple completed turns.
     pub(super) fn collect_event(&mut self, event: AppEvent) {
         match event {
-            AppEvent::AtMentionEntriesLoaded {
-                entries,
-                session_id,
-            } => {
-                self.at_mention_entries_updates.insert(session_id, entries);
-            }
+            event @ (AppEvent::AtMentionEntriesLoaded { .. }
+            | AppEvent::AtMentionSymbolsLoaded { .. }) => self.collect_at_mention_event(event),
             AppEvent::GitStatusUpdated {
                 session_statuses,
                 status,
@@ -210,19 +269,9 @@ impl AppEventBatch {
             AppEvent::UpdateStatusChanged { update_status } => {
                 self.update_status = Some(update_status);
             }
-            AppEvent::SessionModelUpdated {
-                session_id,
-                session_model,
-            } => {
-                self.session_model_updates.insert(session_id, session_model);
-            }
-            AppEvent::SessionReasoningLevelUpdated {
-                reasoning_level_override,
-                session_id,
-            } => {
-                self.session_reasoning_level_updates
-                    .insert(session_id, reasoning_level_override);
-            }
+            event @ (AppEvent::SessionModelUpdated { .. }
+            | AppEvent::SessionReasoningLevelUpdated { .. }
+            | AppEvent::SessionPlanUpdated { .. }) => self.collect_session_setting_event(event),
             AppEvent::RefreshSessions => {
                 self.should_force_reload = true;
             }
@@ -236,9 +285,7 @@ impl AppEventBatch {
                 self.session_progress_updates
                     .insert(session_id, progress_message);
             }
-            AppEvent::SyncMainCompleted { result } => {
-                self.collect_sync_main_completed(result);
-            }
+            AppEvent::SyncMainCompleted { result } => self.collect_sync_main_completed(result),
             AppEvent::SessionSizeUpdated {
                 added_lines,
                 deleted_lines,
@@ -260,6 +307,23 @@ impl AppEventBatch {
                 result,
                 session_id,
             } => self.collect_branch_publish_action_completed(restore_view, *result, session_id),
+            AppEvent::ReviewRequestDraftPrepared { result, session_id } => {
+                self.review_request_draft_update = Some((session_id, result));
+            }
+            AppEvent::IssuesListed { result } => self.issue_list_result = Some(result),
+            AppEvent::IssueLoaded { result } => self.issue_load_result = Some(*result),
+            AppEvent::ControlRequested { call } => self.control_calls.push(call),
+            AppEvent::PromptContextCaptured {
+                capture_id,
+                directive_line,
+                result,
+                session_id,
+            } => self.prompt_context_captures.push(PromptContextCapture {
+                capture_id,
+                directive_line,
+                result,
+                session_id,
+            }),
             AppEvent::ReviewPrepared {
                 diff_hash,
                 review_text,
@@ -292,6 +356,50 @@ impl AppEventBatch {
         }
     }
 
+    /// Stores background-loaded `@` mention data, keeping the latest file
+    /// entries and symbol index per session.
+    fn collect_at_mention_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::AtMentionEntriesLoaded {
+                entries,
+                session_id,
+            } => {
+                self.at_mention_entries_updates.insert(session_id, entries);
+            }
+            AppEvent::AtMentionSymbolsLoaded {
+                session_id,
+                symbol_index,
+            } => {
+                self.at_mention_symbol_updates
+                    .insert(session_id, symbol_index);
+            }
+            _ => {}
+        }
+    }
+
+    /// Stores the latest per-session model, reasoning, and plan settings.
+    fn collect_session_setting_event(&mut self, event: AppEvent) {
+        match event {
+            AppEvent::SessionModelUpdated {
+                session_id,
+                session_model,
+            } => {
+                self.session_model_updates.insert(session_id, session_model);
+            }
+            AppEvent::SessionReasoningLevelUpdated {
+                reasoning_level_override,
+                session_id,
+            } => {
+                self.session_reasoning_level_updates
+                    .insert(session_id, reasoning_level_override);
+            }
+            AppEvent::SessionPlanUpdated { plan, session_id } => {
+                self.session_plan_updates.insert(session_id, plan);
+            }
+            _ => {}
+        }
+    }
+
     /// Stores the latest git status event for this reducer batch.
     fn collect_git_status_updated(
         &mut self,
@@ -496,38 +604,23 @@ impl App {
                 .clone_from(&latest_available_version_update.latest_available_version);
         }
 
-        if let Some(update_status) = event_batch.update_status {
+        if let Some(update_status) = event_batch.update_status.take() {
             self.update_status = Some(update_status);
         }
 
-        for (session_id, session_model) in event_batch.session_model_updates {
-            self.sessions
-                .apply_session_model_updated(&session_id, session_model);
-        }
+        self.apply_session_metadata_updates(&mut event_batch);
 
-        for (session_id, reasoning_level_override) in event_batch.session_reasoning_level_updates {
-            self.sessions
-                .apply_session_reasoning_level_updated(&session_id, reasoning_level_override);
-        }
-
-        for (session_id, (added_lines, deleted_lines, session_size)) in
-            event_batch.session_size_updates
-        {
-            self.sessions.apply_session_size_updated(
-                &session_id,
-                added_lines,
-                deleted_lines,
-                session_size,
-            );
+        for (session_id, entries) in event_batch.at_mention_entries_updates {
+            self.apply_prompt_at_mention_entries(&session_id, entries);
         }
 
-        for (session_id, generation) in event_batch.session_title_generation_finished {
-            self.sessions
-                .clear_title_generation_task_if_matches(&session_id, generation);
+        for (session_id, symbol_index) in event_batch.at_mention_symbol_updates {
+            self.apply_prompt_at_mention_symbols(&session_id, symbol_index);
         }
 
-        for (session_id, entries) in event_batch.at_mention_entries_updates {
-            self.apply_prompt_at_mention_entries(&session_id, entries);
+        for prompt_context_capture in event_batch.prompt_context_captures {
+            self.apply_prompt_context_capture(prompt_context_capture)
+                .await;
         }
 
         apply_review_updates(
@@ -541,6 +634,19 @@ impl App {
             self.apply_branch_publish_action_update(branch_publish_action_update);
         }
 
+        if let Some((session_id, result)) = event_batch.review_request_draft_update {
+            self.apply_review_request_draft_update(&session_id, result);
+        }
+
+        if let Some(result) = event_batch.issue_list_result {
+            self.apply_issue_list_result(result);
+        }
+
+        if let Some(result) = event_batch.issue_load_result {
+            self.apply_issue_load_result(result);
+        }
+        self.pending_control_calls.extend(event_batch.control_calls);
+
         for review_request_status_update in event_batch.review_request_status_updates {
             self.apply_review_request_status_update(review_request_status_update)
                 .await;
@@ -562,6 +668,8 @@ impl App {
         }
         self.sessions
             .clear_terminal_session_workers(&event_batch.session_ids);
+        self.publish_control_session_updates(&event_batch.session_ids);
+        self.notify_session_transitions(&previous_session_states);
 
         auto_start_reviews(
             &mut self.review_cache,
@@ -586,7 +694,132 @@ impl App {
         self.handle_merge_queue_progress(&event_batch.session_ids, &previous_session_states)
             .await;
         self.retain_valid_session_progress_messages();
-        self.sessions.retain_active_prompt_outputs();
+    }
+
+    /// Applies one open-issue listing result to a still-loading issue picker.
+    fn apply_issue_list_result(&mut self, result: Result<Vec<ForgeIssueSummary>, String>) {
+        let AppMode::IssuePicker {
+            issues,
+            selected_issue_index,
+            status,
+        } = &mut self.mode
+        else {
+            return;
+        };
+        if *status != IssuePickerStatus::Loading {
+            return;
+        }
+
+        match result {
+            Ok(listed_issues) => {
+                *issues = listed_issues;
+                *selected_issue_index = 0;
+                *status = IssuePickerStatus::Ready;
+            }
+            Err(error) => {
+                *status = IssuePickerStatus::Failed(error);
+            }
+        }
+    }
+
+    /// Applies one selected-issue load result to a still-starting issue
+    /// picker.
+    ///
+    /// Loaded issues are queued for the runtime loop because session creation
+    /// processes pending app events and cannot run inside this reducer.
+    fn apply_issue_load_result(&mut self, result: Result<ForgeIssue, String>) {
+        let AppMode::IssuePicker { status, .. } = &mut self.mode else {
+            return;
+        };
+        if *status != IssuePickerStatus::Starting {
+            return;
+        }
+
+        match result {
+            Ok(issue) => {
+                self.pending_issue_session = Some(issue);
+            }
+            Err(error) => {
+                *status = IssuePickerStatus::Failed(error);
+            }
+        }
+    }
+
+    /// Applies per-session model, reasoning, plan, size, and title-generation
+    /// updates from one reducer batch.
+    fn apply_session_metadata_updates(&mut self, event_batch: &mut AppEventBatch) {
+        for (session_id, session_model) in std::mem::take(&mut event_batch.session_model_updates) {
+            self.sessions
+                .apply_session_model_updated(&session_id, session_model);
+        }
+
+        for (session_id, reasoning_level_override) in
+            std::mem::take(&mut event_batch.session_reasoning_level_updates)
+        {
+            self.sessions
+                .apply_session_reasoning_level_updated(&session_id, reasoning_level_override);
+        }
+
+        for (session_id, plan) in std::mem::take(&mut event_batch.session_plan_updates) {
+            self.sessions.apply_session_plan_updated(&session_id, plan);
+        }
+
+        for (session_id, (added_lines, deleted_lines, session_size)) in
+            std::mem::take(&mut event_batch.session_size_updates)
+        {
+            self.sessions.apply_session_size_updated(
+                &session_id,
+                added_lines,
+                deleted_lines,
+                session_size,
+            );
+        }
+
+        for (session_id, generation) in
+            std::mem::take(&mut event_batch.session_title_generation_finished)
+        {
+            self.sessions
+                .clear_title_generation_task_if_matches(&session_id, generation);
+        }
+    }
+
+    /// Sends configured notifications for touched sessions whose status
+    /// changed during one reducer batch.
+    pub(super) fn notify_session_transitions(
+        &self,
+        previous_session_states: &HashMap<SessionId, Status>,
+    ) {
+        let Some(notification_client) = &self.notification_client else {
+            return;
+        };
+        let notification_settings = self.settings.notification_settings();
+        let is_inside_tmux = std::env::var_os("TMUX").is_some();
+
+        for (session_id, previous_status) in previous_session_states {
+            let Some(session) = self
+                .sessions
+                .sessions
+                .iter()
+                .find(|session| session.id == *session_id)
+            else {
+                continue;
+            };
+            let Some(session_notification) = notification::session_notification(
+                notification_settings,
+                *previous_status,
+                session,
+            ) else {
+                continue;
+            };
+
+            notification::dispatch_session_notification(
+                notification_settings,
+                session_notification,
+                notification_client,
+                &self.tmux_client,
+                is_inside_tmux,
+            );
+        }
     }
 
     /// Returns status snapshots for sessions touched before applying a
@@ -652,8 +885,10 @@ impl App {
 
         if self.is_viewing_session(session_id) {
             let (review_status_message, review_text) = self.question_mode_review_state(session_id);
+            let input = question::default_question_input(&questions, 0);
             self.mode = AppMode::Question {
                 at_mention_state: None,
+                checked_option_indexes: Vec::new(),
                 selected_option_index: question::default_option_index(&questions, 0),
                 session_id: session_id.into(),
                 questions,
@@ -662,9 +897,10 @@ impl App {
                 responses: Vec::new(),
                 current_index: 0,
                 focus: QuestionFocus::Answer,
-                input: InputState::default(),
+                input,
                 scroll_offset: None,
             };
+            question::sync_question_at_mention_state(self);
         }
     }
 
@@ -696,6 +932,14 @@ impl App {
                     },
                 ..
             }
+            | AppMode::PlanReview {
+                restore_view:
+                    ConfirmationViewMode {
+                        session_id: view_id,
+                        ..
+                    },
+                ..
+            }
             | AppMode::PublishBranchInput {
                 restore_view:
                     ConfirmationViewMode {
@@ -715,6 +959,8 @@ impl App {
             AppMode::List
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::IssuePicker { .. }
+            | AppMode::SessionSearch { .. }
             | AppMode::Help { .. } => false,
         }
     }
@@ -739,6 +985,7 @@ impl App {
                 ..
             } => (review_status_message.clone(), review_text.clone()),
             AppMode::OpenCommandSelector { restore_view, .. }
+            | AppMode::PlanReview { restore_view, .. }
             | AppMode::PublishBranchInput { restore_view, .. }
             | AppMode::ViewInfoPopup { restore_view, .. } => (
                 restore_view.review_status_message.clone(),
@@ -751,6 +998,8 @@ impl App {
             AppMode::List
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::IssuePicker { .. }
+            | AppMode::SessionSearch { .. }
             | AppMode::Diff { .. }
             | AppMode::Help { .. } => (None, None),
         }
@@ -788,6 +1037,77 @@ impl App {
     /// Applies loaded at-mention entries to the currently focused prompt or
     /// question session, if the mention query is still active.
     fn apply_prompt_at_mention_entries(&mut self, session_id: &str, entries: Vec<FileEntry>) {
+        let Some(at_mention_state) = self.active_at_mention_state_mut(session_id) else {
+            return;
+        };
+
+        if let Some(state) = at_mention_state.as_mut() {
+            state.all_entries = entries;
+            state.selected_index = 0;
+
+            return;
+        }
+
+        *at_mention_state = Some(Box::new(PromptAtMentionState::new(entries)));
+    }
+
+    /// Applies a loaded `@#` symbol index to the currently focused prompt or
+    /// question session, if its mention dropdown is still open.
+    fn apply_prompt_at_mention_symbols(&mut self, session_id: &str, symbol_index: SymbolIndex) {
+        if let Some(Some(state)) = self.active_at_mention_state_mut(session_id) {
+            state.symbol_index = Some(symbol_index);
+        }
+    }
+
+    /// Fills or drops the pending prompt context placeholder for one finished
+    /// background capture.
+    ///
+    /// Results for a prompt that was closed, switched to another session, or
+    /// whose placeholder was deleted are ignored. Failures put the directive
+    /// line back so it can be corrected, and report the error in the session
+    /// transcript.
+    async fn apply_prompt_context_capture(&mut self, prompt_context_capture: PromptContextCapture) {
+        let PromptContextCapture {
+            capture_id,
+            directive_line,
+            result,
+            session_id,
+        } = prompt_context_capture;
+        let AppMode::Prompt {
+            attachment_state,
+            input,
+            session_id: prompt_session_id,
+            ..
+        } = &mut self.mode
+        else {
+            return;
+        };
+        if *prompt_session_id != session_id {
+            return;
+        }
+
+        match result {
+            Ok(captured) => {
+                attachment_state.complete_context(capture_id, captured.label, captured.content);
+            }
+            Err(error) => {
+                if fail_prompt_context(attachment_state, input, capture_id, &directive_line) {
+                    self.append_output_for_session(
+                        &session_id,
+                        &format!("\n[Attach Context Error] {error}\n"),
+                    )
+                    .await;
+                }
+            }
+        }
+    }
+
+    /// Returns the at-mention slot for the focused prompt or question session
+    /// when its input still contains an active `@` query.
+    fn active_at_mention_state_mut(
+        &mut self,
+        session_id: &str,
+    ) -> Option<&mut Option<Box<PromptAtMentionState>>> {
         let (at_mention_state, has_query) = match &mut self.mode {
             AppMode::Prompt {
                 at_mention_state,
@@ -805,21 +1125,10 @@ impl App {
             } if mode_session_id == session_id => {
                 (at_mention_state, input.at_mention_query().is_some())
             }
-            _ => return,
+            _ => return None,
         };
 
-        if !has_query {
-            return;
-        }
-
-        if let Some(state) = at_mention_state.as_mut() {
-            state.all_entries = entries;
-            state.selected_index = 0;
-
-            return;
-        }
-
-        *at_mention_state = Some(PromptAtMentionState::new(entries));
+        has_query.then_some(at_mention_state)
     }
 
     /// Applies one review assist update to cache and focused render state.
@@ -853,6 +1162,43 @@ impl App {
         .await;
     }
 
+    /// Applies one completed review-request template draft to the publish
+    /// popup when it is still open for the same session.
+    pub(super) fn apply_review_request_draft_update(
+        &mut self,
+        session_id: &SessionId,
+        result: Result<ReviewRequestDraft, String>,
+    ) {
+        let AppMode::PublishBranchInput {
+            locked_upstream_ref,
+            restore_view,
+            review_request_draft: Some(review_request_draft),
+            ..
+        } = &mut self.mode
+        else {
+            return;
+        };
+        if restore_view.session_id != *session_id
+            || review_request_draft.status != ReviewRequestDraftStatus::Loading
+        {
+            return;
+        }
+
+        match result {
+            Ok(ReviewRequestDraft { body, title }) => {
+                review_request_draft.body = InputState::with_text(body.unwrap_or_default());
+                review_request_draft.status = ReviewRequestDraftStatus::Ready;
+                review_request_draft.title = InputState::with_text(title);
+                if locked_upstream_ref.is_some() {
+                    review_request_draft.focus = ReviewRequestDraftFocus::Title;
+                }
+            }
+            Err(error) => {
+                review_request_draft.status = ReviewRequestDraftStatus::Failed(error);
+            }
+        }
+    }
+
     /// Applies one completed branch-publish action and updates the popup.
     pub(super) fn apply_branch_publish_action_update(
         &mut self,
@@ -20,6 +20,17 @@ use crate::infra::git::GitClient;
 #[cfg(test)]
 use crate::infra::project_discovery::ProjectDiscoveryClient;
 
+/// Startup behavior that differs between the terminal UI and headless CLI
+/// commands.
+#[derive(Clone, Copy)]
+enum AppStartupMode {
+    /// Terminal UI startup that recovers interrupted operations and runs
+    /// background housekeeping.
+    Interactive { auto_update: bool },
+    /// Headless command startup without recovery or background polling.
+    Headless,
+}
+
 impl App {
     /// Builds the app state from persisted data and starts background
     /// housekeeping tasks.
@@ -38,8 +49,45 @@ impl App {
         db: Database,
     ) -> Result<Self, AppError> {
         let clients = AppClients::new();
+        let startup_mode = AppStartupMode::Interactive { auto_update };
+
+        Self::new_with_options(
+            startup_mode,
+            base_path,
+            working_dir,
+            git_branch,
+            db,
+            clients,
+        )
+        .await
+    }
+
+    /// Builds the app state for one headless CLI command.
+    ///
+    /// Headless startup skips auto-update and background status polling, and
+    /// leaves unfinished session operations untouched so a terminal UI running
+    /// in another process keeps ownership of its in-flight turns.
+    ///
+    /// # Errors
+    /// Returns an error if startup project metadata cannot be persisted or
+    /// required startup state cannot be loaded from the database.
+    pub async fn new_headless(
+        base_path: PathBuf,
+        working_dir: PathBuf,
+        git_branch: Option<String>,
+        db: Database,
+    ) -> Result<Self, AppError> {
+        let clients = AppClients::new();
 
-        Self::new_with_options(auto_update, base_path, working_dir, git_branch, db, clients).await
+        Self::new_with_options(
+            AppStartupMode::Headless,
+            base_path,
+            working_dir,
+            git_branch,
+            db,
+            clients,
+        )
+        .await
     }
 
     /// Builds app state from persisted data with explicit external clients.
@@ -58,7 +106,17 @@ impl App {
         db: Database,
         clients: AppClients,
     ) -> Result<Self, AppError> {
-        Self::new_with_options(false, base_path, working_dir, git_branch, db, clients).await
+        let startup_mode = AppStartupMode::Interactive { auto_update: false };
+
+        Self::new_with_options(
+            startup_mode,
+            base_path,
+            working_dir,
+            git_branch,
+            db,
+            clients,
+        )
+        .await
     }
 
     /// Core constructor with all options explicit.
@@ -67,7 +125,7 @@ impl App {
     /// Returns an error if startup project metadata cannot be persisted or
     /// required startup state cannot be loaded from the database.
     async fn new_with_options(
-        auto_update: bool,
+        startup_mode: AppStartupMode,
         base_path: PathBuf,
         working_dir: PathBuf,
         git_branch: Option<String>,
@@ -96,11 +154,13 @@ impl App {
             &clients,
         )
         .await?;
-        SessionManager::fail_unfinished_operations_from_previous_run(
-            repositories.clone(),
-            Arc::clone(&clock),
-        )
-        .await;
+        if let AppStartupMode::Interactive { .. } = startup_mode {
+            SessionManager::fail_unfinished_operations_from_previous_run(
+                repositories.clone(),
+                Arc::clone(&clock),
+            )
+            .await;
+        }
         let projects = crate::app::project::ProjectManager::new(
             active_project_id,
             active_project_name,
@@ -131,7 +191,15 @@ impl App {
         )
         .await;
 
-        AppStartup::spawn_background_tasks(auto_update, &event_tx, &projects, &services, &sessions);
+        if let AppStartupMode::Interactive { auto_update } = startup_mode {
+            AppStartup::spawn_background_tasks(
+                auto_update,
+                &event_tx,
+                &projects,
+                &services,
+                &sessions,
+            );
+        }
 
         Ok(Self {
             mode: crate::ui::state::app_mode::AppMode::List,
@@ -143,15 +211,24 @@ impl App {
             active_project_has_tasks_tab,
             active_project_roadmap,
             task_roadmap_scroll_offset: 0,
+            dashboard_selected_session_id: None,
             event_rx,
             review_cache: std::collections::HashMap::new(),
             latest_available_version: None,
             markdown_render_cache: crate::ui::markdown::MarkdownRenderCache::default(),
+            diff_highlight_cache: crate::ui::page::diff::DiffHighlightCache::default(),
             merge_queue: crate::app::merge_queue::MergeQueue::default(),
+            pending_issue_session: None,
+            pending_control_calls: Vec::new(),
+            control_server: None,
+            control_api_error: None,
             session_progress_messages: std::collections::HashMap::new(),
             update_status: None,
             sync_main_runner: clients.sync_main_runner,
             tmux_client: clients.tmux_client,
+            browser_client: clients.browser_client,
+            notification_client: matches!(startup_mode, AppStartupMode::Interactive { .. })
+                .then_some(clients.notification_client),
         })
     }
 
@@ -12,7 +12,10 @@ use ag_forge::{RealReviewRequestClient, ReviewRequestClient};
 use app::branch_publish::detected_forge_kind_from_git_push_error;
 #[cfg(test)]
 use app::branch_publish::{BranchPublishTaskFailure, branch_push_failure, push_session_branch};
-use app::branch_publish::{BranchPublishTaskSession, run_branch_publish_action};
+use app::branch_publish::{
+    BranchPublishTaskSession, ReviewRequestDraft, ReviewRequestDraftInput,
+    find_review_request_template, prepare_review_request_draft, run_branch_publish_action,
+};
 use app::merge_queue::{MergeQueue, MergeQueueProgress};
 use app::project::ProjectManager;
 use app::review::{
@@ -36,29 +39,43 @@ use super::roadmap::ActiveProjectRoadmap;
 #[cfg(test)]
 use super::roadmap::TASKS_ROADMAP_PATH;
 use crate::app;
-use crate::app::{AppError, session};
+use crate::app::{AppError, Tab, issue, session};
 use crate::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
 use crate::domain::input::InputState;
 use crate::domain::permission::PermissionMode;
-use crate::domain::session::{FollowUpTaskAction, PublishBranchAction, Session, SessionId, Status};
+use crate::domain::plan::SessionPlan;
+use crate::domain::session::{
+    FollowUpTaskAction, ForgeIssue, PublishBranchAction, Session, SessionId, SessionIssue,
+    SessionSearchHit, Status,
+};
+use crate::infra::browser::{BrowserClient, RealBrowserClient};
 use crate::infra::channel::TurnPrompt;
+use crate::infra::control_socket::{ControlCall, ControlServer};
 #[cfg(test)]
 use crate::infra::db;
+use crate::infra::db::SessionSearchHitRow;
 use crate::infra::fs::{FsClient, RealFsClient};
 use crate::infra::git::{GitClient, RealGitClient};
+use crate::infra::notification::{NotificationClient, RealNotificationClient};
 use crate::infra::project_discovery::{ProjectDiscoveryClient, RealProjectDiscoveryClient};
 use crate::infra::tmux::{RealTmuxClient, TmuxClient};
 use crate::infra::{agent, app_server};
 use crate::runtime::mode::{at_mention, question};
 use crate::ui::markdown;
 use crate::ui::state::app_mode::{
-    AppMode, ConfirmationViewMode, DoneSessionOutputMode, QuestionFocus,
+    AppMode, ConfirmationViewMode, DoneSessionOutputMode, IssuePickerStatus, QuestionFocus,
 };
 
 /// Relative directory name used for session git worktrees within the
 /// `agentty` home directory.
 pub const AGENTTY_WT_DIR: &str = "wt";
 
+/// Maximum number of sessions listed by the global session search overlay.
+const SESSION_SEARCH_HIT_LIMIT: u32 = 50;
+
+/// Maximum number of persisted prompts loaded into the prompt-history search.
+const PROMPT_HISTORY_LIMIT: u32 = 500;
+
 /// Returns the resolved `agentty` home directory.
 ///
 /// The `AGENTTY_ROOT` environment variable takes precedence when set to a
@@ -171,8 +188,10 @@ impl SyncMainRunner for TokioSyncMainRunner {
 pub(crate) struct AppClients {
     pub(super) agent_availability_probe: Arc<dyn agent::AgentAvailabilityProbe>,
     pub(super) app_server_client_override: Option<Arc<dyn app_server::AppServerClient>>,
+    pub(super) browser_client: Arc<dyn BrowserClient>,
     pub(super) fs_client: Arc<dyn FsClient>,
     pub(super) git_client: Arc<dyn GitClient>,
+    pub(super) notification_client: Arc<dyn NotificationClient>,
     pub(super) project_discovery_client: Arc<dyn ProjectDiscoveryClient>,
     pub(super) review_request_client: Arc<dyn ReviewRequestClient>,
     pub(super) sync_main_runner: Arc<dyn SyncMainRunner>,
@@ -186,8 +205,10 @@ impl AppClients {
         Self {
             agent_availability_probe: Arc::new(agent::RealAgentAvailabilityProbe),
             app_server_client_override: None,
+            browser_client: Arc::new(RealBrowserClient),
             fs_client: Arc::new(RealFsClient),
             git_client: Arc::new(RealGitClient),
+            notification_client: Arc::new(RealNotificationClient),
             project_discovery_client: Arc::new(RealProjectDiscoveryClient),
             review_request_client: Arc::new(RealReviewRequestClient::default()),
             sync_main_runner: Arc::new(TokioSyncMainRunner),
@@ -230,6 +251,29 @@ impl AppClients {
         self
     }
 
+    /// Replaces the notification boundary while preserving the remaining
+    /// clients.
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn with_notification_client(
+        mut self,
+        notification_client: Arc<dyn NotificationClient>,
+    ) -> Self {
+        self.notification_client = notification_client;
+
+        self
+    }
+
+    /// Replaces the default-browser boundary while preserving the remaining
+    /// clients.
+    #[cfg(test)]
+    #[must_use]
+    pub(crate) fn with_browser_client(mut self, browser_client: Arc<dyn BrowserClient>) -> Self {
+        self.browser_client = browser_client;
+
+        self
+    }
+
     /// Replaces the filesystem boundary while preserving the remaining
     /// clients.
     #[cfg(test)]
@@ -273,6 +317,9 @@ pub struct App {
     /// Stores the current vertical scroll offset for the active project's
     /// `Tasks` page.
     pub(super) task_roadmap_scroll_offset: u16,
+    /// Remembers the session highlighted on the `Dashboard` tab by id, so the
+    /// highlight follows it while other sessions start and stop.
+    pub(super) dashboard_selected_session_id: Option<SessionId>,
     /// Receives app events emitted by background tasks and workflows.
     pub(super) event_rx: mpsc::UnboundedReceiver<AppEvent>,
     /// Stores the latest available stable `agentty` version when one is
@@ -286,9 +333,28 @@ pub struct App {
     pub(super) session_progress_messages: HashMap<SessionId, String>,
     /// Interacts with tmux panes for session-specific terminal workflows.
     pub(super) tmux_client: Arc<dyn TmuxClient>,
+    /// Opens review-request links clicked in the session list.
+    pub(super) browser_client: Arc<dyn BrowserClient>,
+    /// Delivers terminal and hook-command session notifications; `None` for
+    /// headless commands so their stdout stays script-friendly.
+    pub(super) notification_client: Option<Arc<dyn NotificationClient>>,
     /// Caches rendered markdown output for the session transcript panel so
     /// unchanged content is not re-parsed on every frame.
     pub(super) markdown_render_cache: markdown::MarkdownRenderCache,
+    /// Caches syntax-highlighted diff segments so the diff page does not
+    /// re-run the highlighter on every frame.
+    pub(super) diff_highlight_cache: crate::ui::page::diff::DiffHighlightCache,
+    /// Stores one issue loaded by the picker until the runtime loop starts a
+    /// session from it outside event reduction.
+    pub(super) pending_issue_session: Option<ForgeIssue>,
+    /// Stores control-socket requests queued by the reducer until the runtime
+    /// loop answers them outside event reduction.
+    pub(super) pending_control_calls: Vec<ControlCall>,
+    /// Serves the local control socket when this instance owns it.
+    pub(super) control_server: Option<ControlServer>,
+    /// Explains why the control socket failed to start, shown in the status
+    /// bar while the app runs without the control API.
+    pub(super) control_api_error: Option<String>,
     /// Stores the current auto-update progress state when an update is running.
     pub(super) update_status: Option<UpdateStatus>,
 }
@@ -316,6 +382,37 @@ impl App {
         self.sessions.previous();
     }
 
+    /// Selects the session at `index` in the session list.
+    pub fn select_session(&mut self, index: usize) {
+        self.sessions.select(index);
+    }
+
+    /// Opens the linked review request of the session at `index` in the
+    /// default browser.
+    ///
+    /// Returns `false` when the session has no linked review request.
+    pub fn open_session_review_request(&self, index: usize) -> bool {
+        let Some(web_url) = self
+            .sessions
+            .sessions
+            .get(index)
+            .and_then(|session| session.review_request.as_ref())
+            .map(|review_request| review_request.summary.web_url.clone())
+        else {
+            return false;
+        };
+
+        self.browser_client.open_url(web_url);
+
+        true
+    }
+
+    /// Returns the current wall-clock time as Unix seconds, matching the
+    /// timestamp used for render-time timers.
+    pub(crate) fn wall_clock_unix_seconds(&self) -> i64 {
+        session::unix_timestamp_from_system_time(self.sessions.state().clock.now_system_time())
+    }
+
     /// Moves selection to the next project in the projects list.
     pub fn next_project(&mut self) {
         self.projects.next_project();
@@ -561,6 +658,23 @@ impl App {
         Ok(())
     }
 
+    /// Persists and applies the plan-first state for a session.
+    ///
+    /// # Errors
+    /// Returns an error if persistence fails.
+    pub async fn set_session_plan(
+        &mut self,
+        session_id: &str,
+        plan: Option<SessionPlan>,
+    ) -> Result<(), AppError> {
+        self.sessions
+            .set_session_plan(&self.services, session_id, plan)
+            .await?;
+        self.process_pending_app_events().await;
+
+        Ok(())
+    }
+
     /// Returns the currently selected session, if any.
     pub fn selected_session(&self) -> Option<&Session> {
         self.sessions.selected_session()
@@ -652,6 +766,201 @@ impl App {
         Ok(())
     }
 
+    /// Opens the issue picker and lists open forge issues for the active
+    /// project in the background.
+    pub(crate) fn start_issue_picker(&mut self) {
+        self.pending_issue_session = None;
+        self.mode = AppMode::IssuePicker {
+            issues: Vec::new(),
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Loading,
+        };
+
+        let app_event_tx = self.services.event_sender();
+        let issue_task_context = self.issue_task_context();
+        tokio::spawn(async move {
+            let result = issue::list_issues(issue_task_context).await;
+
+            // Fire-and-forget: receiver may be dropped during shutdown.
+            let _ = app_event_tx.send(AppEvent::IssuesListed { result });
+        });
+    }
+
+    /// Loads the selected picker issue with its discussion in the background
+    /// so a session can be started from it.
+    pub(crate) fn start_selected_issue_load(&mut self) {
+        let AppMode::IssuePicker {
+            issues,
+            selected_issue_index,
+            status,
+        } = &mut self.mode
+        else {
+            return;
+        };
+        if *status != IssuePickerStatus::Ready {
+            return;
+        }
+        let Some(display_id) = issues
+            .get(*selected_issue_index)
+            .map(|issue| issue.display_id.clone())
+        else {
+            return;
+        };
+
+        *status = IssuePickerStatus::Starting;
+
+        let app_event_tx = self.services.event_sender();
+        let issue_task_context = self.issue_task_context();
+        tokio::spawn(async move {
+            let result = issue::load_issue(issue_task_context, display_id).await;
+
+            // Fire-and-forget: receiver may be dropped during shutdown.
+            let _ = app_event_tx.send(AppEvent::IssueLoaded {
+                result: Box::new(result),
+            });
+        });
+    }
+
+    /// Creates and starts one session from the issue loaded by the picker,
+    /// links the issue to the session, and opens it.
+    ///
+    /// This runs from the runtime loop after event reduction because session
+    /// creation drains queued app events itself. Failures keep the picker
+    /// open with the error message.
+    pub(super) async fn start_pending_issue_session(&mut self) {
+        let Some(issue) = self.pending_issue_session.take() else {
+            return;
+        };
+
+        if let Err(error) = self.start_session_from_issue(&issue).await
+            && let AppMode::IssuePicker { status, .. } = &mut self.mode
+        {
+            *status = IssuePickerStatus::Failed(error.to_string());
+        }
+    }
+
+    /// Creates one session seeded with the issue prompt and persists the issue
+    /// link used by review-request publishing.
+    ///
+    /// # Errors
+    /// Returns an error if creating, linking, or starting the session fails.
+    async fn start_session_from_issue(&mut self, issue: &ForgeIssue) -> Result<(), AppError> {
+        let session_id = self.create_session().await?;
+        let session_issue = SessionIssue {
+            display_id: issue.summary.display_id.clone(),
+            web_url: issue.summary.web_url.clone(),
+        };
+        self.services
+            .db()
+            .update_session_issue(
+                &session_id,
+                &session_issue.display_id,
+                &session_issue.web_url,
+            )
+            .await?;
+        self.sessions.set_session_issue(&session_id, session_issue);
+        self.start_session(
+            &session_id,
+            TurnPrompt::from_text(issue::issue_session_prompt(issue)),
+        )
+        .await?;
+        self.open_session(&session_id);
+
+        Ok(())
+    }
+
+    /// Builds the repository context used by issue-picker background tasks.
+    fn issue_task_context(&self) -> issue::IssueTaskContext {
+        issue::IssueTaskContext {
+            git_client: self.services.git_client(),
+            review_request_client: self.services.review_request_client(),
+            working_dir: self.projects.working_dir().to_path_buf(),
+        }
+    }
+
+    /// Opens the global session search overlay with an empty query.
+    pub(crate) fn start_session_search(&mut self) {
+        self.mode = AppMode::SessionSearch {
+            hits: Vec::new(),
+            input: InputState::new(),
+            selected_hit_index: 0,
+        };
+    }
+
+    /// Searches session titles, summaries, and transcripts across all
+    /// projects.
+    ///
+    /// Search failures are reported as no hits so a malformed index never
+    /// interrupts typing in the overlay.
+    pub(crate) async fn search_sessions(&self, query: &str) -> Vec<SessionSearchHit> {
+        self.services
+            .db()
+            .search_sessions(query, SESSION_SEARCH_HIT_LIMIT)
+            .await
+            .map(|rows| {
+                rows.into_iter()
+                    .map(SessionSearchHitRow::into_session_search_hit)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Loads the active project's persisted prompt history, most recently
+    /// used first.
+    ///
+    /// Load failures are reported as an empty history so opening the search
+    /// never interrupts prompt composition.
+    pub(crate) async fn load_prompt_history(&self) -> Vec<String> {
+        self.services
+            .db()
+            .load_prompt_history(self.projects.active_project_id(), PROMPT_HISTORY_LIMIT)
+            .await
+            .unwrap_or_default()
+    }
+
+    /// Records one submitted prompt in the active project's history.
+    ///
+    /// Blank prompts are skipped. Persistence failures are ignored because
+    /// history must never block prompt submission.
+    pub(crate) async fn record_prompt_history(&self, prompt: &str) {
+        if prompt.trim().is_empty() {
+            return;
+        }
+
+        let _ = self
+            .services
+            .db()
+            .record_prompt_history(self.projects.active_project_id(), prompt)
+            .await;
+    }
+
+    /// Makes the project that owns `hit` active and selects the matched
+    /// session in the sessions list.
+    ///
+    /// Returns the matched session index, or `None` when the session is no
+    /// longer loaded for its project.
+    ///
+    /// # Errors
+    /// Returns an error if switching to the hit project fails.
+    pub(crate) async fn select_session_search_hit(
+        &mut self,
+        hit: &SessionSearchHit,
+    ) -> Result<Option<usize>, AppError> {
+        if let Some(project_id) = hit.project_id
+            && project_id != self.projects.active_project_id()
+        {
+            self.switch_project(project_id).await?;
+        }
+
+        let session_index = self.session_index_for_id(&hit.session_id);
+        if session_index.is_some() {
+            self.tabs.set(Tab::Sessions);
+            self.sessions.table_state.select(session_index);
+        }
+
+        Ok(session_index)
+    }
+
     /// Deletes the selected session, clears transient review and `@`-mention
     /// state for that session, and schedules list refresh.
     pub async fn delete_selected_session(&mut self) {
@@ -749,8 +1058,9 @@ impl App {
         session_id: &str,
         publish_branch_action: PublishBranchAction,
         remote_branch_name: Option<String>,
+        review_request_draft: Option<ReviewRequestDraft>,
     ) {
-        let Some(branch_publish_session) = self.branch_publish_task_session(session_id) else {
+        let Some(mut branch_publish_session) = self.branch_publish_task_session(session_id) else {
             self.mode = Self::view_info_popup_mode(
                 "Branch push failed".to_string(),
                 "Session is no longer available.".to_string(),
@@ -761,6 +1071,7 @@ impl App {
 
             return;
         };
+        branch_publish_session.review_request_draft = review_request_draft;
 
         let loading_title = Self::branch_publish_loading_title(publish_branch_action);
         let loading_message = Self::branch_publish_loading_message(
@@ -804,6 +1115,45 @@ impl App {
         });
     }
 
+    /// Starts filling the repository review-request template for one session
+    /// in the background.
+    ///
+    /// Returns the detected template path so the publish popup can show a
+    /// loading preview, or `None` when the session already links a review
+    /// request or the worktree has no template.
+    pub(crate) fn start_review_request_draft(&self, session_id: &str) -> Option<String> {
+        let session = self
+            .sessions
+            .sessions
+            .iter()
+            .find(|session| session.id == session_id)
+            .filter(|session| session.review_request.is_none())?;
+        let fs_client = self.services.fs_client();
+        let template_path = find_review_request_template(fs_client.as_ref(), &session.folder)?;
+        let draft_input = ReviewRequestDraftInput {
+            base_branch: session.base_branch.clone(),
+            folder: session.folder.clone(),
+            fs_client,
+            git_client: self.services.git_client(),
+            model: self.settings.default_fast_model,
+            session_summary: session.summary.clone(),
+            template_path: template_path.clone(),
+        };
+        let event_sender = self.services.event_sender();
+        let event_session_id = session.id.clone();
+
+        tokio::spawn(async move {
+            let result = prepare_review_request_draft(draft_input).await;
+            // Fire-and-forget: receiver may be dropped during shutdown.
+            let _ = event_sender.send(AppEvent::ReviewRequestDraftPrepared {
+                result,
+                session_id: event_session_id,
+            });
+        });
+
+        Some(template_path)
+    }
+
     /// Returns all configured open commands in user-defined order.
     #[must_use]
     pub(crate) fn configured_open_commands(&self) -> Vec<String> {
@@ -878,6 +1228,38 @@ impl App {
         self.start_next_merge_from_queue(true).await
     }
 
+    /// Applies app events until one session leaves its busy statuses and
+    /// returns the settled status.
+    ///
+    /// Headless commands use this in place of the terminal event loop so
+    /// agent turns and merges started in-process run to completion before the
+    /// command exits.
+    ///
+    /// # Errors
+    /// Returns an error if the session disappears or the app event channel
+    /// closes before the session settles.
+    pub async fn wait_for_session_idle(&mut self, session_id: &str) -> Result<Status, AppError> {
+        loop {
+            let status = self
+                .sessions
+                .sessions
+                .iter()
+                .find(|session| session.id == session_id)
+                .map(|session| session.status)
+                .ok_or_else(|| AppError::Workflow(format!("Session `{session_id}` not found")))?;
+            if !status.is_busy() && !self.merge_queue.is_queued_or_active(session_id) {
+                return Ok(status);
+            }
+
+            let Some(event) = self.next_app_event().await else {
+                return Err(AppError::Workflow(
+                    "App event channel closed before the session finished".to_string(),
+                ));
+            };
+            self.apply_app_events(event).await;
+        }
+    }
+
     /// Rebases a reviewed session branch onto its base branch.
     ///
     /// # Errors
@@ -1108,10 +1490,12 @@ impl App {
         };
         if session.status == Status::Question {
             let questions = session.questions.clone();
+            let input = question::default_question_input(&questions, 0);
             let selected_option_index = question::default_option_index(&questions, 0);
             let (review_status_message, review_text) = self.review_view_state(target_session_id);
             self.mode = AppMode::Question {
                 at_mention_state: None,
+                checked_option_indexes: Vec::new(),
                 session_id: SessionId::from(target_session_id),
                 questions,
                 review_status_message,
@@ -1119,10 +1503,11 @@ impl App {
                 responses: Vec::new(),
                 current_index: 0,
                 focus: QuestionFocus::Answer,
-                input: InputState::default(),
+                input,
                 scroll_offset: None,
                 selected_option_index,
             };
+            question::sync_question_at_mention_state(self);
 
             return;
         }
@@ -1135,6 +1520,7 @@ impl App {
             review_text,
             session_id: SessionId::from(target_session_id),
             scroll_offset: None,
+            transcript_search: None,
         };
     }
 
@@ -1352,7 +1738,10 @@ mod tests {
         HOME_PROJECT_SCAN_MAX_RESULTS, RealProjectDiscoveryClient,
     };
     use crate::infra::tmux::{MockTmuxClient, TmuxClient};
-    use crate::ui::state::app_mode::{ConfirmationViewMode, DoneSessionOutputMode};
+    use crate::ui::state::app_mode::{
+        ConfirmationViewMode, DoneSessionOutputMode, ReviewRequestDraftFocus,
+        ReviewRequestDraftState, ReviewRequestDraftStatus,
+    };
 
     /// Builds one mock app-server client wrapped in `Arc`.
     fn mock_app_server() -> Arc<dyn app_server::AppServerClient> {
@@ -1389,8 +1778,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "test-project".to_string(),
             prompt: "test prompt".to_string(),
             reasoning_level_override: None,
@@ -1403,6 +1794,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
@@ -1425,6 +1817,7 @@ mod tests {
                     text: text.to_string(),
                 })
                 .collect(),
+            plan: None,
             questions,
             summary: summary.and_then(|summary| serde_json::to_string(&summary).ok()),
             token_usage_delta,
@@ -2427,6 +2820,108 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn apply_review_request_draft_update_fills_loading_publish_popup() {
+        // Arrange
+        let session_folder = tempdir().expect("failed to create temp dir");
+        let mut app = new_test_app_with_selected_session(
+            session_folder.path().to_path_buf(),
+            "",
+            Arc::new(MockTmuxClient::new()),
+        )
+        .await;
+        app.mode = AppMode::PublishBranchInput {
+            default_branch_name: "wt/session-1".to_string(),
+            input: InputState::default(),
+            locked_upstream_ref: Some("origin/wt/session-1".to_string()),
+            publish_branch_action: PublishBranchAction::PublishPullRequest,
+            restore_view: ConfirmationViewMode {
+                done_session_output_mode: DoneSessionOutputMode::Summary,
+                review_status_message: None,
+                review_text: None,
+                scroll_offset: None,
+                session_id: "session-1".into(),
+            },
+            review_request_draft: Some(ReviewRequestDraftState::loading(
+                ".github/pull_request_template.md".to_string(),
+            )),
+        };
+
+        // Act
+        app.apply_review_request_draft_update(
+            &"session-1".into(),
+            Ok(ReviewRequestDraft {
+                body: Some("## Summary\n- Adds templates".to_string()),
+                title: "Fill review-request templates".to_string(),
+            }),
+        );
+
+        // Assert
+        let AppMode::PublishBranchInput {
+            review_request_draft: Some(review_request_draft),
+            ..
+        } = &app.mode
+        else {
+            unreachable!("publish popup should stay open");
+        };
+        assert_eq!(review_request_draft.status, ReviewRequestDraftStatus::Ready);
+        assert_eq!(review_request_draft.focus, ReviewRequestDraftFocus::Title);
+        assert_eq!(
+            review_request_draft.title.text(),
+            "Fill review-request templates"
+        );
+        assert_eq!(
+            review_request_draft.body.text(),
+            "## Summary\n- Adds templates"
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_review_request_draft_update_ignores_other_sessions() {
+        // Arrange
+        let session_folder = tempdir().expect("failed to create temp dir");
+        let mut app = new_test_app_with_selected_session(
+            session_folder.path().to_path_buf(),
+            "",
+            Arc::new(MockTmuxClient::new()),
+        )
+        .await;
+        app.mode = AppMode::PublishBranchInput {
+            default_branch_name: "wt/session-1".to_string(),
+            input: InputState::default(),
+            locked_upstream_ref: None,
+            publish_branch_action: PublishBranchAction::PublishPullRequest,
+            restore_view: ConfirmationViewMode {
+                done_session_output_mode: DoneSessionOutputMode::Summary,
+                review_status_message: None,
+                review_text: None,
+                scroll_offset: None,
+                session_id: "session-1".into(),
+            },
+            review_request_draft: Some(ReviewRequestDraftState::loading(
+                ".github/pull_request_template.md".to_string(),
+            )),
+        };
+
+        // Act
+        app.apply_review_request_draft_update(
+            &"session-2".into(),
+            Err("agent unavailable".to_string()),
+        );
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::PublishBranchInput {
+                review_request_draft: Some(ReviewRequestDraftState {
+                    status: ReviewRequestDraftStatus::Loading,
+                    ..
+                }),
+                ..
+            }
+        ));
+    }
+
     #[tokio::test]
     async fn apply_branch_publish_action_update_sets_pull_request_success_popup() {
         // Arrange
@@ -3317,6 +3812,7 @@ mod tests {
             review_text: Some("Focused review".to_string()),
             session_id: "session-1".into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let expected_questions = vec![
             QuestionItem::with_options(
@@ -3818,6 +4314,137 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn apply_app_events_fills_loading_issue_picker_with_listed_issues() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = AppMode::IssuePicker {
+            issues: Vec::new(),
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Loading,
+        };
+        let issue = test_issue_summary();
+
+        // Act
+        app.apply_app_events(AppEvent::IssuesListed {
+            result: Ok(vec![issue.clone()]),
+        })
+        .await;
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::IssuePicker {
+                ref issues,
+                selected_issue_index: 0,
+                status: IssuePickerStatus::Ready,
+            } if issues == &vec![issue]
+        ));
+    }
+
+    #[tokio::test]
+    async fn apply_app_events_ignores_issue_load_after_picker_closed() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = AppMode::List;
+
+        // Act
+        app.apply_app_events(AppEvent::IssueLoaded {
+            result: Box::new(Ok(test_issue())),
+        })
+        .await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::List));
+        assert_eq!(app.pending_issue_session, None);
+    }
+
+    #[tokio::test]
+    async fn notify_session_transitions_writes_configured_terminal_notification() {
+        // Arrange
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let mut notification_client = crate::infra::notification::MockNotificationClient::new();
+        notification_client
+            .expect_write_terminal_sequence()
+            .with(eq("\x07".to_string()))
+            .times(1)
+            .return_const(());
+        let clients = test_app_clients()
+            .with_app_server_client_override(mock_app_server())
+            .with_notification_client(Arc::new(notification_client));
+        let mut app = App::new_with_clients(base_path.clone(), base_path, None, database, clients)
+            .await
+            .expect("failed to build test app");
+        let project_id = app.projects.active_project_id();
+        app.services
+            .db()
+            .upsert_project_setting(project_id, SettingName::TerminalNotification, "Bell")
+            .await
+            .expect("failed to persist terminal notification");
+        app.settings = SettingsManager::new(&app.services, project_id).await;
+        let session = test_session(base_dir.path().join("session"));
+        let session_id = session.id.clone();
+        app.sessions.push_session(session);
+        let previous_session_states = HashMap::from([(session_id, Status::InProgress)]);
+
+        // Act
+        app.notify_session_transitions(&previous_session_states);
+
+        // Assert
+        // `mockall` verifies the bell is written exactly once.
+    }
+
+    #[tokio::test]
+    async fn start_pending_issue_session_keeps_picker_open_with_creation_error() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = AppMode::IssuePicker {
+            issues: vec![test_issue_summary()],
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Starting,
+        };
+        app.apply_app_events(AppEvent::IssueLoaded {
+            result: Box::new(Ok(test_issue())),
+        })
+        .await;
+
+        // Act
+        app.start_pending_issue_session().await;
+
+        // Assert
+        assert!(app.sessions.sessions.is_empty());
+        assert_eq!(app.pending_issue_session, None);
+        assert!(matches!(
+            app.mode,
+            AppMode::IssuePicker {
+                status: IssuePickerStatus::Failed(ref message),
+                ..
+            } if message.contains("Git branch is required to create a session")
+        ));
+    }
+
+    fn test_issue_summary() -> forge::ForgeIssueSummary {
+        forge::ForgeIssueSummary {
+            display_id: "#12".to_string(),
+            forge_kind: forge::ForgeKind::GitHub,
+            labels: vec![forge::AGENT_ISSUE_LABEL.to_string()],
+            title: "Add picker".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+        }
+    }
+
+    fn test_issue() -> ForgeIssue {
+        ForgeIssue {
+            body: "Pick issues from the list.".to_string(),
+            comments: Vec::new(),
+            summary: test_issue_summary(),
+        }
+    }
+
     #[tokio::test]
     /// Verifies a viewed session keeps summary mode when its live status
     /// transition reaches `Done`.
@@ -3836,6 +4463,7 @@ mod tests {
             review_text: Some("Review text".to_string()),
             session_id: "session-1".into(),
             scroll_offset: Some(9),
+            transcript_search: None,
         };
 
         // Act
@@ -3903,6 +4531,7 @@ mod tests {
             review_text: None,
             session_id: "session-1".into(),
             scroll_offset: None,
+            transcript_search: None,
         };
 
         // Act
@@ -0,0 +1,212 @@
+//! Forge issue picker workflow helpers for issue-seeded sessions.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use ag_forge as forge;
+
+use crate::infra::git::GitClient;
+
+/// Repository context cloned into one issue-picker background task.
+#[derive(Clone)]
+pub(crate) struct IssueTaskContext {
+    pub(crate) git_client: Arc<dyn GitClient>,
+    pub(crate) review_request_client: Arc<dyn forge::ReviewRequestClient>,
+    /// Active project worktree used to resolve the forge remote.
+    pub(crate) working_dir: PathBuf,
+}
+
+/// Lists open issues assigned to the current user or labelled for agents in
+/// the active project repository.
+///
+/// # Errors
+/// Returns user-facing error text when the remote cannot be resolved or the
+/// forge CLI lookup fails.
+pub(crate) async fn list_issues(
+    context: IssueTaskContext,
+) -> Result<Vec<forge::ForgeIssueSummary>, String> {
+    let remote = issue_remote(&context).await?;
+
+    context
+        .review_request_client
+        .list_issues(remote)
+        .await
+        .map_err(|error| error.detail_message())
+}
+
+/// Loads one issue with its description and comments from the active project
+/// repository.
+///
+/// # Errors
+/// Returns user-facing error text when the remote cannot be resolved or the
+/// forge CLI lookup fails.
+pub(crate) async fn load_issue(
+    context: IssueTaskContext,
+    display_id: String,
+) -> Result<forge::ForgeIssue, String> {
+    let remote = issue_remote(&context).await?;
+
+    context
+        .review_request_client
+        .load_issue(remote, display_id)
+        .await
+        .map_err(|error| error.detail_message())
+}
+
+/// Builds the initial session prompt from one issue title, link, body, and
+/// discussion comments.
+pub(crate) fn issue_session_prompt(issue: &forge::ForgeIssue) -> String {
+    let mut prompt = format!(
+        "Resolve issue {}: {}\n{}",
+        issue.summary.display_id, issue.summary.title, issue.summary.web_url
+    );
+
+    let body = issue.body.trim();
+    if !body.is_empty() {
+        prompt.push_str("\n\n");
+        prompt.push_str(body);
+    }
+
+    let comments = issue
+        .comments
+        .iter()
+        .filter(|comment| !comment.body.trim().is_empty())
+        .collect::<Vec<_>>();
+    if !comments.is_empty() {
+        prompt.push_str("\n\n## Comments");
+        for comment in comments {
+            // Infallible: writing to a String cannot fail.
+            let _ = write!(prompt, "\n\n@{}:\n{}", comment.author, comment.body.trim());
+        }
+    }
+
+    prompt
+}
+
+/// Resolves the forge remote for the active project repository.
+async fn issue_remote(context: &IssueTaskContext) -> Result<forge::ForgeRemote, String> {
+    let repo_url = context
+        .git_client
+        .repo_url(context.working_dir.clone())
+        .await
+        .map_err(|error| format!("Failed to resolve repository remote for issues: {error}"))?;
+
+    context
+        .review_request_client
+        .detect_remote(repo_url)
+        .map(|remote| remote.with_command_working_directory(context.working_dir.clone()))
+        .map_err(|error| error.detail_message())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infra::git;
+
+    #[tokio::test]
+    async fn list_issues_detects_remote_from_project_worktree() {
+        // Arrange
+        let working_dir = PathBuf::from("/tmp/project");
+        let remote = forge::ForgeRemote {
+            command_working_directory: None,
+            forge_kind: forge::ForgeKind::GitHub,
+            host: "github.com".to_string(),
+            namespace: "agentty-xyz".to_string(),
+            project: "agentty".to_string(),
+            repo_url: "https://github.com/agentty-xyz/agentty.git".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty".to_string(),
+        };
+        let issue = forge::ForgeIssueSummary {
+            display_id: "#12".to_string(),
+            forge_kind: forge::ForgeKind::GitHub,
+            labels: vec![forge::AGENT_ISSUE_LABEL.to_string()],
+            title: "Add picker".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+        };
+        let mut mock_git_client = git::MockGitClient::new();
+        mock_git_client
+            .expect_repo_url()
+            .once()
+            .withf({
+                let working_dir = working_dir.clone();
+
+                move |folder| folder == &working_dir
+            })
+            .returning(|_| {
+                Box::pin(async { Ok("https://github.com/agentty-xyz/agentty.git".to_string()) })
+            });
+        let mut mock_review_request_client = forge::MockReviewRequestClient::new();
+        mock_review_request_client
+            .expect_detect_remote()
+            .once()
+            .returning({
+                let remote = remote.clone();
+
+                move |_| Ok(remote.clone())
+            });
+        mock_review_request_client
+            .expect_list_issues()
+            .once()
+            .withf({
+                let working_dir = working_dir.clone();
+
+                move |remote| remote.command_working_directory.as_ref() == Some(&working_dir)
+            })
+            .returning({
+                let issue = issue.clone();
+
+                move |_| {
+                    let issue = issue.clone();
+
+                    Box::pin(async move { Ok(vec![issue]) })
+                }
+            });
+        let context = IssueTaskContext {
+            git_client: Arc::new(mock_git_client),
+            review_request_client: Arc::new(mock_review_request_client),
+            working_dir,
+        };
+
+        // Act
+        let issues = list_issues(context).await;
+
+        // Assert
+        assert_eq!(issues, Ok(vec![issue]));
+    }
+
+    #[test]
+    fn issue_session_prompt_includes_title_body_and_comments() {
+        // Arrange
+        let issue = forge::ForgeIssue {
+            body: "Pick issues from the list.\n".to_string(),
+            comments: vec![
+                forge::ForgeIssueComment {
+                    author: "octocat".to_string(),
+                    body: "Use the forge CLI.".to_string(),
+                },
+                forge::ForgeIssueComment {
+                    author: "ghost".to_string(),
+                    body: "  ".to_string(),
+                },
+            ],
+            summary: forge::ForgeIssueSummary {
+                display_id: "#12".to_string(),
+                forge_kind: forge::ForgeKind::GitHub,
+                labels: Vec::new(),
+                title: "Add picker".to_string(),
+                web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+            },
+        };
+
+        // Act
+        let prompt = issue_session_prompt(&issue);
+
+        // Assert
+        assert_eq!(
+            prompt,
+            "Resolve issue #12: Add picker\nhttps://github.com/agentty-xyz/agentty/issues/12\n\n\
+             Pick issues from the list.\n\n## Comments\n\n@octocat:\nUse the forge CLI."
+        );
+    }
+}
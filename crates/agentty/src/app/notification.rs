@@ -0,0 +1,409 @@
+//! Session attention notifications raised on status transitions.
+
+use std::sync::Arc;
+
+use crate::domain::session::{Session, Status};
+use crate::infra::notification::NotificationClient;
+use crate::infra::tmux::TmuxClient;
+
+/// Terminal channel used to surface session notifications.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) enum TerminalNotification {
+    /// No terminal notification is emitted.
+    #[default]
+    Off,
+    /// Rings the terminal bell.
+    Bell,
+    /// Emits an `OSC 9` desktop notification (iTerm2, `WezTerm`, Ghostty).
+    Osc9,
+    /// Emits an `OSC 777` desktop notification (urxvt, foot, `Konsole`).
+    Osc777,
+}
+
+impl TerminalNotification {
+    /// Ordered list of terminal channels used by the settings selector.
+    pub(crate) const ALL: [Self; 4] = [Self::Off, Self::Bell, Self::Osc9, Self::Osc777];
+
+    /// Returns the persisted setting value for this channel.
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Bell => "Bell",
+            Self::Osc9 => "Osc9",
+            Self::Osc777 => "Osc777",
+        }
+    }
+
+    /// Returns the human-readable label shown in settings.
+    pub(crate) fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Disabled",
+            Self::Bell => "Terminal bell",
+            Self::Osc9 => "OSC 9",
+            Self::Osc777 => "OSC 777",
+        }
+    }
+
+    /// Parses one persisted setting value.
+    pub(crate) fn from_persisted(value: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|terminal_notification| terminal_notification.as_str() == value)
+    }
+
+    /// Returns the next channel in selector order.
+    #[must_use]
+    pub(crate) fn next(self) -> Self {
+        let current_index = Self::ALL
+            .iter()
+            .position(|terminal_notification| *terminal_notification == self)
+            .unwrap_or(0);
+
+        Self::ALL[(current_index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Status transitions that raise a notification.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub(crate) struct NotificationTransitions {
+    /// Whether sessions reaching `Done` notify.
+    pub(crate) done: bool,
+    /// Whether sessions reaching `Question` notify.
+    pub(crate) question: bool,
+    /// Whether sessions reaching `Review` notify.
+    pub(crate) review: bool,
+}
+
+impl NotificationTransitions {
+    /// Returns whether a transition into `status` should notify.
+    fn notifies_for(self, status: Status) -> bool {
+        match status {
+            Status::Review => self.review,
+            Status::Question => self.question,
+            Status::Done => self.done,
+            Status::New
+            | Status::InProgress
+            | Status::AgentReview
+            | Status::Queued
+            | Status::Rebasing
+            | Status::Merging
+            | Status::Canceled => false,
+        }
+    }
+}
+
+/// Per-project notification preferences loaded from settings.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) struct NotificationSettings {
+    /// Shell hook run for each notification; empty disables the hook.
+    pub(crate) command: String,
+    /// Terminal escape-sequence channel.
+    pub(crate) terminal: TerminalNotification,
+    /// Whether notifications are shown in the tmux status line.
+    pub(crate) tmux: bool,
+    /// Status transitions that notify.
+    pub(crate) transitions: NotificationTransitions,
+}
+
+impl NotificationSettings {
+    /// Returns whether at least one delivery channel is configured.
+    fn has_channel(&self) -> bool {
+        self.terminal != TerminalNotification::Off || self.tmux || !self.command.trim().is_empty()
+    }
+}
+
+/// One notification raised by a session status transition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SessionNotification {
+    /// Short notification title naming the project.
+    pub(crate) title: String,
+    /// Notification body naming the session and its new state.
+    pub(crate) body: String,
+}
+
+/// Builds the notification for `session` after it moved from
+/// `previous_status`, when `settings` enable one for its new status.
+pub(crate) fn session_notification(
+    settings: &NotificationSettings,
+    previous_status: Status,
+    session: &Session,
+) -> Option<SessionNotification> {
+    if previous_status == session.status
+        || !settings.has_channel()
+        || !settings.transitions.notifies_for(session.status)
+    {
+        return None;
+    }
+
+    let state_text = match session.status {
+        Status::Question => "has a question for you",
+        Status::Done => "is done",
+        _ => "is ready for review",
+    };
+    let project_name = if session.project_name.is_empty() {
+        "agentty"
+    } else {
+        session.project_name.as_str()
+    };
+
+    Some(SessionNotification {
+        title: format!("Agentty: {project_name}"),
+        body: format!("{} {state_text}", session.display_title()),
+    })
+}
+
+/// Returns the escape sequence written for `notification` on `terminal`.
+///
+/// OSC sequences are wrapped in the tmux DCS passthrough when
+/// `is_inside_tmux` is set so they reach the outer terminal.
+pub(crate) fn terminal_notification_sequence(
+    terminal: TerminalNotification,
+    notification: &SessionNotification,
+    is_inside_tmux: bool,
+) -> Option<String> {
+    let title = sanitize_terminal_text(&notification.title);
+    let body = sanitize_terminal_text(&notification.body);
+    let sequence = match terminal {
+        TerminalNotification::Off => return None,
+        TerminalNotification::Bell => return Some("\x07".to_string()),
+        TerminalNotification::Osc9 => format!("\x1b]9;{title}: {body}\x07"),
+        TerminalNotification::Osc777 => format!(
+            "\x1b]777;notify;{};{}\x07",
+            title.replace(';', ","),
+            body.replace(';', ",")
+        ),
+    };
+
+    if is_inside_tmux {
+        return Some(format!(
+            "\x1bPtmux;{}\x1b\\",
+            sequence.replace('\x1b', "\x1b\x1b")
+        ));
+    }
+
+    Some(sequence)
+}
+
+/// Delivers `notification` through every channel enabled in `settings`.
+///
+/// Tmux messages and hook commands run in background tasks so slow commands
+/// never block event reduction.
+pub(crate) fn dispatch_session_notification(
+    settings: &NotificationSettings,
+    notification: SessionNotification,
+    notification_client: &Arc<dyn NotificationClient>,
+    tmux_client: &Arc<dyn TmuxClient>,
+    is_inside_tmux: bool,
+) {
+    if let Some(sequence) =
+        terminal_notification_sequence(settings.terminal, &notification, is_inside_tmux)
+    {
+        notification_client.write_terminal_sequence(sequence);
+    }
+
+    if settings.tmux && is_inside_tmux {
+        let message = format!("{}: {}", notification.title, notification.body);
+        tokio::spawn(tmux_client.display_message(message));
+    }
+
+    let command = settings.command.trim();
+    if !command.is_empty() {
+        tokio::spawn(notification_client.run_command(
+            command.to_string(),
+            notification.title,
+            notification.body,
+        ));
+    }
+}
+
+/// Strips control characters that would terminate or corrupt an escape
+/// sequence.
+fn sanitize_terminal_text(text: &str) -> String {
+    text.chars()
+        .map(|character| {
+            if character.is_control() {
+                ' '
+            } else {
+                character
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use mockall::predicate::eq;
+
+    use super::*;
+    use crate::domain::session::tests::SessionFixtureBuilder;
+    use crate::infra::notification::MockNotificationClient;
+    use crate::infra::tmux::MockTmuxClient;
+
+    /// Builds settings with review notifications routed to `terminal`.
+    fn review_settings(terminal: TerminalNotification) -> NotificationSettings {
+        NotificationSettings {
+            terminal,
+            transitions: NotificationTransitions {
+                review: true,
+                ..NotificationTransitions::default()
+            },
+            ..NotificationSettings::default()
+        }
+    }
+
+    /// Builds one notification fixture.
+    fn notification() -> SessionNotification {
+        SessionNotification {
+            title: "Agentty: api".to_string(),
+            body: "Fix login is ready for review".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_terminal_notification_round_trips_persisted_values_and_cycles() {
+        // Arrange
+        let terminal_notifications = TerminalNotification::ALL;
+
+        // Act & Assert
+        for terminal_notification in terminal_notifications {
+            assert_eq!(
+                TerminalNotification::from_persisted(terminal_notification.as_str()),
+                Some(terminal_notification)
+            );
+        }
+        assert_eq!(TerminalNotification::from_persisted("Toast"), None);
+        assert_eq!(
+            TerminalNotification::Osc777.next(),
+            TerminalNotification::Off
+        );
+    }
+
+    #[test]
+    fn test_session_notification_reports_enabled_transition() {
+        // Arrange
+        let settings = review_settings(TerminalNotification::Bell);
+        let session = SessionFixtureBuilder::new()
+            .project_name("api")
+            .status(Status::Review)
+            .title(Some("Fix login".to_string()))
+            .build();
+
+        // Act
+        let notification = session_notification(&settings, Status::InProgress, &session);
+
+        // Assert
+        assert_eq!(notification, Some(self::notification()));
+    }
+
+    #[test]
+    fn test_session_notification_skips_disabled_unchanged_or_channelless_transitions() {
+        // Arrange
+        let settings = review_settings(TerminalNotification::Bell);
+        let channelless_settings = review_settings(TerminalNotification::Off);
+        let review_session = SessionFixtureBuilder::new().status(Status::Review).build();
+        let question_session = SessionFixtureBuilder::new()
+            .status(Status::Question)
+            .build();
+
+        // Act
+        let unchanged = session_notification(&settings, Status::Review, &review_session);
+        let disabled_status =
+            session_notification(&settings, Status::InProgress, &question_session);
+        let channelless =
+            session_notification(&channelless_settings, Status::InProgress, &review_session);
+
+        // Assert
+        assert_eq!(unchanged, None);
+        assert_eq!(disabled_status, None);
+        assert_eq!(channelless, None);
+    }
+
+    #[test]
+    fn test_terminal_notification_sequence_formats_osc_and_bell() {
+        // Arrange
+        let notification = notification();
+
+        // Act
+        let osc9 = terminal_notification_sequence(TerminalNotification::Osc9, &notification, false);
+        let osc777 =
+            terminal_notification_sequence(TerminalNotification::Osc777, &notification, false);
+        let bell = terminal_notification_sequence(TerminalNotification::Bell, &notification, true);
+        let off = terminal_notification_sequence(TerminalNotification::Off, &notification, false);
+
+        // Assert
+        assert_eq!(
+            osc9,
+            Some("\x1b]9;Agentty: api: Fix login is ready for review\x07".to_string())
+        );
+        assert_eq!(
+            osc777,
+            Some("\x1b]777;notify;Agentty: api;Fix login is ready for review\x07".to_string())
+        );
+        assert_eq!(bell, Some("\x07".to_string()));
+        assert_eq!(off, None);
+    }
+
+    #[test]
+    fn test_terminal_notification_sequence_wraps_for_tmux_and_strips_controls() {
+        // Arrange
+        let notification = SessionNotification {
+            title: "Agentty".to_string(),
+            body: "Evil\x07 title".to_string(),
+        };
+
+        // Act
+        let sequence =
+            terminal_notification_sequence(TerminalNotification::Osc9, &notification, true);
+
+        // Assert
+        assert_eq!(
+            sequence,
+            Some("\x1bPtmux;\x1b\x1b]9;Agentty: Evil  title\x07\x1b\\".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_session_notification_uses_enabled_channels() {
+        // Arrange
+        let settings = NotificationSettings {
+            command: "notify-send \"$AGENTTY_NOTIFICATION_BODY\"".to_string(),
+            tmux: true,
+            ..review_settings(TerminalNotification::Osc9)
+        };
+        let mut notification_client = MockNotificationClient::new();
+        notification_client
+            .expect_write_terminal_sequence()
+            .times(1)
+            .return_const(());
+        notification_client
+            .expect_run_command()
+            .with(
+                eq(settings.command.clone()),
+                eq("Agentty: api".to_string()),
+                eq("Fix login is ready for review".to_string()),
+            )
+            .times(1)
+            .returning(|_, _, _| Box::pin(async {}));
+        let mut tmux_client = MockTmuxClient::new();
+        tmux_client
+            .expect_display_message()
+            .with(eq("Agentty: api: Fix login is ready for review".to_string()))
+            .times(1)
+            .returning(|_| Box::pin(async {}));
+        let notification_client: Arc<dyn NotificationClient> = Arc::new(notification_client);
+        let tmux_client: Arc<dyn TmuxClient> = Arc::new(tmux_client);
+
+        // Act
+        dispatch_session_notification(
+            &settings,
+            notification(),
+            &notification_client,
+            &tmux_client,
+            true,
+        );
+        tokio::task::yield_now().await;
+
+        // Assert
+        // `mockall` verifies each enabled channel is used exactly once.
+    }
+}
@@ -354,6 +354,7 @@ fn review_mode_target<'a>(mode: &'a mut AppMode, session_id: &str) -> Option<Rev
             review_text,
         }),
         AppMode::OpenCommandSelector { restore_view, .. }
+        | AppMode::PlanReview { restore_view, .. }
         | AppMode::PublishBranchInput { restore_view, .. }
         | AppMode::ViewInfoPopup { restore_view, .. } => {
             confirmation_review_mode_target(restore_view, session_id)
@@ -361,6 +362,8 @@ fn review_mode_target<'a>(mode: &'a mut AppMode, session_id: &str) -> Option<Rev
         AppMode::List
         | AppMode::Confirmation { .. }
         | AppMode::SyncBlockedPopup { .. }
+        | AppMode::IssuePicker { .. }
+        | AppMode::SessionSearch { .. }
         | AppMode::Prompt { .. }
         | AppMode::Question { .. }
         | AppMode::Diff { .. }
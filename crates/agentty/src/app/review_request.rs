@@ -1,5 +1,7 @@
 //! Shared review-request helpers used by app workflows.
 
+use crate::domain::session::SessionIssue;
+
 /// Parsed commit-message metadata used to populate a new review request.
 #[derive(Debug, Eq, PartialEq)]
 pub(crate) struct ReviewRequestCommitMessage {
@@ -27,10 +29,131 @@ pub(crate) fn parse_review_request_commit_message(
     })
 }
 
+/// Appends the issue closing reference to one review-request body so merging
+/// the review request also closes the issue that seeded the session.
+///
+/// Bodies that already contain the closing reference as a whole token are
+/// returned unchanged.
+pub(crate) fn append_issue_closing_reference(
+    body: Option<String>,
+    issue: Option<&SessionIssue>,
+) -> Option<String> {
+    let Some(issue) = issue else {
+        return body;
+    };
+    let closing_reference = issue.closing_reference();
+
+    match body {
+        Some(body) if contains_closing_reference(&body, &closing_reference) => Some(body),
+        Some(body) if !body.trim().is_empty() => {
+            Some(format!("{}\n\n{closing_reference}", body.trim_end()))
+        }
+        _ => Some(closing_reference),
+    }
+}
+
+/// Returns whether one body line contains `closing_reference` as a whole
+/// token.
+///
+/// The reference must not continue into a longer issue number, so
+/// `Closes #1` does not match inside `Closes #12`, and it must not be the
+/// tail of a longer word.
+fn contains_closing_reference(body: &str, closing_reference: &str) -> bool {
+    body.lines().any(|line| {
+        line.match_indices(closing_reference).any(|(start, _)| {
+            let end = start + closing_reference.len();
+            let is_word_start = line[..start]
+                .chars()
+                .next_back()
+                .is_none_or(|character| !character.is_alphanumeric());
+            let is_number_end = line[end..]
+                .chars()
+                .next()
+                .is_none_or(|character| !character.is_ascii_digit());
+
+            is_word_start && is_number_end
+        })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Verifies the issue closing line is appended after the existing body.
+    #[test]
+    fn append_issue_closing_reference_appends_line_after_body() {
+        // Arrange
+        let issue = SessionIssue {
+            display_id: "#12".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+        };
+
+        // Act
+        let body = append_issue_closing_reference(Some("- Add picker\n".to_string()), Some(&issue));
+
+        // Assert
+        assert_eq!(body, Some("- Add picker\n\nCloses #12".to_string()));
+    }
+
+    /// Verifies an existing closing line is kept and a longer issue number
+    /// does not count as the session issue reference.
+    #[test]
+    fn append_issue_closing_reference_matches_whole_issue_numbers() {
+        // Arrange
+        let issue = SessionIssue {
+            display_id: "#1".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty/issues/1".to_string(),
+        };
+
+        // Act
+        let longer_number_body =
+            append_issue_closing_reference(Some("Closes #12".to_string()), Some(&issue));
+        let existing_reference_body = append_issue_closing_reference(
+            Some("- Add picker\nCloses #1, see notes".to_string()),
+            Some(&issue),
+        );
+
+        // Assert
+        assert_eq!(
+            longer_number_body,
+            Some("Closes #12\n\nCloses #1".to_string())
+        );
+        assert_eq!(
+            existing_reference_body,
+            Some("- Add picker\nCloses #1, see notes".to_string())
+        );
+    }
+
+    /// Verifies sessions without an issue keep the original body.
+    #[test]
+    fn append_issue_closing_reference_keeps_body_without_issue() {
+        // Arrange
+        let body = Some("- Add picker".to_string());
+
+        // Act
+        let body = append_issue_closing_reference(body, None);
+
+        // Assert
+        assert_eq!(body, Some("- Add picker".to_string()));
+    }
+
+    /// Verifies an empty body becomes the closing line.
+    #[test]
+    fn append_issue_closing_reference_uses_closing_line_for_empty_body() {
+        // Arrange
+        let issue = SessionIssue {
+            display_id: "#3".to_string(),
+            web_url: "https://gitlab.com/agentty-xyz/agentty/-/issues/3".to_string(),
+        };
+
+        // Act
+        let body = append_issue_closing_reference(None, Some(&issue));
+
+        // Assert
+        assert_eq!(body, Some("Closes #3".to_string()));
+    }
+
     /// Verifies parsing keeps the first non-empty line as the title and trims
     /// the remaining description into the PR body.
     #[test]
@@ -17,9 +17,10 @@ use super::workflow::worker::SessionWorkerService;
 use crate::app::session_state::SessionGitStatus;
 use crate::app::{AppServices, SessionState, setting};
 use crate::domain::agent::{AgentModel, ReasoningLevel};
+use crate::domain::plan::SessionPlan;
 use crate::domain::session::{
     DailyActivity, FollowUpTaskAction, PublishedBranchSyncStatus, ReviewRequest, Session,
-    SessionFollowUpTask, SessionId, SessionStats,
+    SessionFollowUpTask, SessionId, SessionIssue, SessionStats,
 };
 use crate::infra::agent::protocol::QuestionItem;
 use crate::infra::git;
@@ -46,6 +47,8 @@ pub(crate) struct SessionDefaults {
 pub(crate) struct TurnAppliedState {
     /// Persisted follow-up tasks for the latest completed turn.
     pub(crate) follow_up_tasks: Vec<SessionFollowUpTask>,
+    /// Persisted plan after the turn, or `None` for sessions without a plan.
+    pub(crate) plan: Option<SessionPlan>,
     /// Persisted clarification questions for the latest completed turn.
     pub(crate) questions: Vec<QuestionItem>,
     /// Raw persisted summary payload, if the turn produced one.
@@ -57,12 +60,13 @@ pub(crate) struct TurnAppliedState {
 impl TurnAppliedState {
     /// Merges one newer reducer projection into this batched state.
     ///
-    /// Latest-turn fields (`follow_up_tasks`, `questions`, `summary`) replace
-    /// the previous projection, while `token_usage_delta` accumulates so
-    /// multiple completed turns queued in one reducer tick do not undercount
-    /// session usage.
+    /// Latest-turn fields (`follow_up_tasks`, `plan`, `questions`, `summary`)
+    /// replace the previous projection, while `token_usage_delta` accumulates
+    /// so multiple completed turns queued in one reducer tick do not
+    /// undercount session usage.
     pub(crate) fn merge_newer(&mut self, newer_turn_applied_state: Self) {
         self.follow_up_tasks = newer_turn_applied_state.follow_up_tasks;
+        self.plan = newer_turn_applied_state.plan;
         self.questions = newer_turn_applied_state.questions;
         self.summary = newer_turn_applied_state.summary;
         self.token_usage_delta.input_tokens = self
@@ -100,7 +104,6 @@ impl Clock for RealClock {
 
 /// Session domain state and worker orchestration state.
 pub struct SessionManager {
-    pub(super) active_prompt_outputs: HashMap<SessionId, String>,
     pub(super) default_session_model: AgentModel,
     pub(super) git_client: Arc<dyn git::GitClient>,
     pub(super) merge_service: SessionMergeService,
@@ -133,7 +136,6 @@ impl SessionManager {
         let pending_history_replay = Self::startup_history_replay_set(&state.sessions);
 
         Self {
-            active_prompt_outputs: HashMap::new(),
             default_session_model: defaults.model,
             git_client,
             merge_service: SessionMergeService,
@@ -253,12 +255,6 @@ impl SessionManager {
         )
     }
 
-    /// Returns the active prompt transcript block cached for sessions that are
-    /// currently running a turn.
-    pub(crate) fn active_prompt_outputs(&self) -> &HashMap<SessionId, String> {
-        &self.active_prompt_outputs
-    }
-
     /// Returns shared immutable access to session render and refresh state.
     pub(crate) fn state(&self) -> &SessionState {
         &self.state
@@ -302,6 +298,23 @@ impl SessionManager {
         }
     }
 
+    /// Applies one persisted plan-first update to the matching in-memory
+    /// session snapshot.
+    pub(crate) fn apply_session_plan_updated(
+        &mut self,
+        session_id: &str,
+        plan: Option<SessionPlan>,
+    ) {
+        if let Some(session) = self
+            .state
+            .sessions
+            .iter_mut()
+            .find(|session| session.id == session_id)
+        {
+            session.plan = plan;
+        }
+    }
+
     /// Applies one persisted published-upstream reference to the matching
     /// in-memory session snapshot.
     pub(crate) fn apply_published_upstream_ref(
@@ -399,6 +412,7 @@ impl SessionManager {
         session
             .follow_up_tasks
             .clone_from(&turn_applied_state.follow_up_tasks);
+        session.plan.clone_from(&turn_applied_state.plan);
         session.questions.clone_from(&turn_applied_state.questions);
         session.summary.clone_from(&turn_applied_state.summary);
         session.stats.input_tokens = session
@@ -409,35 +423,6 @@ impl SessionManager {
             .stats
             .output_tokens
             .saturating_add(turn_applied_state.token_usage_delta.output_tokens);
-        self.active_prompt_outputs.remove(session_id);
-    }
-
-    /// Caches one exact prompt transcript block for an active session turn so
-    /// rendering can anchor synthetic metadata to the correct boundary without
-    /// reparsing generic transcript markers.
-    pub(crate) fn set_active_prompt_output(&mut self, session_id: &str, prompt_output: String) {
-        self.active_prompt_outputs
-            .insert(SessionId::from(session_id), prompt_output);
-    }
-
-    /// Drops cached prompt transcript blocks for sessions that are no longer
-    /// actively running a turn.
-    pub(crate) fn retain_active_prompt_outputs(&mut self) {
-        self.active_prompt_outputs.retain(|session_id, _| {
-            self.state
-                .sessions
-                .iter()
-                .find(|session| session.id == *session_id)
-                .is_some_and(|session| {
-                    matches!(
-                        session.status,
-                        crate::domain::session::Status::InProgress
-                            | crate::domain::session::Status::Queued
-                            | crate::domain::session::Status::Rebasing
-                            | crate::domain::session::Status::Merging
-                    )
-                })
-        });
     }
 
     /// Replaces cached session git-status snapshots from the latest
@@ -610,6 +595,11 @@ impl SessionManager {
             launched_session_id,
         );
     }
+
+    /// Links one forge issue to the in-memory session snapshot.
+    pub(crate) fn set_session_issue(&mut self, session_id: &str, issue: SessionIssue) {
+        self.state.set_session_issue(session_id, issue);
+    }
 }
 
 /// Backward-compatible state-field access shim while call sites migrate to
@@ -678,7 +668,8 @@ mod tests {
     use crate::app::{App, SyncSessionStartError, Tab};
     use crate::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
     use crate::domain::session::{
-        DailyActivity, SESSION_DATA_DIR, Session, SessionHandles, SessionSize, SessionStats, Status,
+        DailyActivity, SESSION_DATA_DIR, Session, SessionHandles, SessionMessageRole, SessionSize,
+        SessionStats, Status,
     };
     use crate::domain::setting::SettingName;
     use crate::infra::agent::AgentResponse;
@@ -1162,8 +1153,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: String::new(),
             prompt: prompt.to_string(),
             reasoning_level_override: None,
@@ -1176,6 +1169,7 @@ mod tests {
             status,
             summary: None,
             title: Some(prompt.to_string()),
+            turns: Vec::new(),
             updated_at: 0,
         });
         if app.sessions.table_state.selected().is_none() {
@@ -1207,8 +1201,10 @@ mod tests {
                 in_progress_started_at: None,
                 in_progress_total_seconds: 0,
                 is_draft: false,
+                issue: None,
                 model: AgentModel::Gpt54,
                 output: String::new(),
+                plan: None,
                 project_name: "project".to_string(),
                 prompt: String::new(),
                 reasoning_level_override,
@@ -1222,6 +1218,7 @@ mod tests {
                 status: Status::Review,
                 summary: None,
                 title: Some("Title".to_string()),
+                turns: Vec::new(),
                 updated_at: 0,
             }],
             ratatui::widgets::TableState::default(),
@@ -1265,80 +1262,6 @@ mod tests {
         );
     }
 
-    #[tokio::test]
-    async fn test_apply_turn_applied_state_clears_active_prompt_output() {
-        // Arrange
-        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
-        let mut app = new_test_app(temp_dir.path().to_path_buf()).await;
-        add_manual_session_with_status(
-            &mut app,
-            temp_dir.path(),
-            "session-id",
-            "Prompt",
-            Status::InProgress,
-        );
-        app.sessions
-            .set_active_prompt_output("session-id", " › Prompt\n\n".to_string());
-
-        // Act
-        app.sessions.apply_turn_applied_state(
-            "session-id",
-            &TurnAppliedState {
-                follow_up_tasks: Vec::new(),
-                questions: Vec::new(),
-                summary: None,
-                token_usage_delta: SessionStats::default(),
-            },
-        );
-
-        // Assert
-        assert!(
-            !app.sessions
-                .active_prompt_outputs()
-                .contains_key("session-id")
-        );
-    }
-
-    #[tokio::test]
-    async fn test_retain_active_prompt_outputs_keeps_only_active_sessions() {
-        // Arrange
-        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
-        let mut app = new_test_app(temp_dir.path().to_path_buf()).await;
-        add_manual_session_with_status(
-            &mut app,
-            temp_dir.path(),
-            "active-session",
-            "Prompt",
-            Status::InProgress,
-        );
-        add_manual_session_with_status(
-            &mut app,
-            temp_dir.path(),
-            "review-session",
-            "Prompt",
-            Status::Review,
-        );
-        app.sessions
-            .set_active_prompt_output("active-session", " › Active\n\n".to_string());
-        app.sessions
-            .set_active_prompt_output("review-session", " › Review\n\n".to_string());
-
-        // Act
-        app.sessions.retain_active_prompt_outputs();
-
-        // Assert
-        assert!(
-            app.sessions
-                .active_prompt_outputs()
-                .contains_key("active-session")
-        );
-        assert!(
-            !app.sessions
-                .active_prompt_outputs()
-                .contains_key("review-session")
-        );
-    }
-
     /// Helper: creates a session and starts it with the given prompt (two-step
     /// flow).
     async fn create_and_start_session(app: &mut App, prompt: &str) {
@@ -2016,7 +1939,15 @@ mod tests {
             .await
             .expect("failed to load session activity timestamps");
         assert_eq!(db_sessions[0].prompt, "Hello");
-        assert_eq!(db_sessions[0].output, " › Hello\n\n");
+        let transcript = app
+            .services
+            .db()
+            .load_session_transcript(&db_sessions[0].id)
+            .await
+            .expect("failed to load transcript");
+        assert_eq!(transcript, " › Hello\n\n");
+        assert_eq!(app.sessions.sessions[0].turns.len(), 1);
+        assert_eq!(app.sessions.sessions[0].turns[0].prompt, "Hello");
         assert_eq!(activity_timestamps.len(), 1);
     }
 
@@ -2285,6 +2216,8 @@ mod tests {
                     placeholder: "[Image #1]".to_string(),
                     local_image_path: dir.path().join("draft-image.png"),
                 }],
+                context_attachments: Vec::new(),
+                skills: Vec::new(),
                 text: "First draft".to_string(),
             },
         )
@@ -2516,7 +2449,7 @@ mod tests {
         db.update_session_prompt("12345678", "Existing")
             .await
             .expect("failed to update prompt");
-        db.append_session_output("12345678", "Output")
+        db.append_session_message("12345678", SessionMessageRole::System, "Output")
             .await
             .expect("failed to update output");
 
@@ -2896,6 +2829,7 @@ mod tests {
             review_text: None,
             session_id: selected_session_id.clone(),
             scroll_offset: None,
+            transcript_search: None,
         };
 
         // Act
@@ -3755,6 +3689,8 @@ mod tests {
         app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Sessions);
         app.next_tab();
+        assert_eq!(app.tabs.current(), Tab::Dashboard);
+        app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Stats);
         app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Settings);
@@ -3789,6 +3725,8 @@ mod tests {
         app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Sessions);
         app.next_tab();
+        assert_eq!(app.tabs.current(), Tab::Dashboard);
+        app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Tasks);
         app.next_tab();
         assert_eq!(app.tabs.current(), Tab::Stats);
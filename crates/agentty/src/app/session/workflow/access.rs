@@ -86,8 +86,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -100,6 +102,7 @@ mod tests {
             status,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
@@ -18,7 +18,10 @@ use crate::app::{
     AppEvent, AppServices, ProjectManager, SessionManager, agentty_home, review_request, setting,
 };
 use crate::domain::agent::{AgentModel, ReasoningLevel};
-use crate::domain::session::{ReviewRequest, SESSION_DATA_DIR, Session, SessionId, Status};
+use crate::domain::plan::SessionPlan;
+use crate::domain::session::{
+    ReviewRequest, SESSION_DATA_DIR, Session, SessionId, SessionTurn, Status,
+};
 use crate::domain::setting::SettingName;
 use crate::infra::channel::{AgentRequestKind, TurnPrompt, TurnPromptAttachment};
 use crate::infra::fs::FsClient;
@@ -129,6 +132,15 @@ impl SessionManager {
         self.state.table_state.select(Some(grouped_indexes[index]));
     }
 
+    /// Selects the session at `index` in raw list order.
+    ///
+    /// Out-of-range indexes leave the current selection unchanged.
+    pub fn select(&mut self, index: usize) {
+        if index < self.state.sessions.len() {
+            self.state.table_state.select(Some(index));
+        }
+    }
+
     /// Creates a blank session with an empty prompt and output.
     ///
     /// Returns the identifier of the newly created session.
@@ -482,6 +494,10 @@ impl SessionManager {
         prompt: impl Into<TurnPrompt>,
     ) -> Result<(), SessionError> {
         let prompt = prompt.into();
+        let title_source = prompt.transcript_text();
+        // Draft bundles persist only prompt text and image metadata, so text
+        // context is folded into the staged text up front.
+        let prompt = prompt.with_inlined_context();
         let session_index = self.session_index_or_err(session_id)?;
         let (
             folder,
@@ -514,7 +530,7 @@ impl SessionManager {
                 &prompt,
                 next_attachment_number,
             ));
-            let title_to_save = session.title.is_none().then(|| prompt.transcript_text());
+            let title_to_save = session.title.is_none().then_some(title_source);
 
             (
                 session.folder.clone(),
@@ -615,6 +631,8 @@ impl SessionManager {
 
             TurnPrompt {
                 attachments: session.draft_attachments.clone(),
+                context_attachments: Vec::new(),
+                skills: Vec::new(),
                 text: session.prompt.clone(),
             }
         };
@@ -682,16 +700,15 @@ impl SessionManager {
         self.persist_first_message_metadata(services, &persisted_session_id, &prompt.text, &title)
             .await;
 
-        let initial_output = Self::formatted_prompt_output(&prompt, false);
-        SessionTaskService::append_session_output(
+        self.append_prompt_turn(
+            services,
             &output,
-            services.db(),
             &app_event_tx,
             &persisted_session_id,
-            &initial_output,
+            &prompt,
+            false,
         )
         .await;
-        self.set_active_prompt_output(&persisted_session_id, initial_output);
 
         // Best-effort: status transition failure is non-critical.
         let _ = SessionTaskService::update_status(
@@ -710,6 +727,7 @@ impl SessionManager {
             request_kind: AgentRequestKind::SessionStart,
             prompt: prompt.clone(),
             turn_metadata: TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref: None,
                 session_model,
             },
@@ -842,6 +860,35 @@ impl SessionManager {
         Ok(())
     }
 
+    /// Updates and persists the plan-first state for a single session.
+    ///
+    /// Passing `None` turns plan-first mode off so future turns run with the
+    /// regular write-enabled permissions.
+    ///
+    /// # Errors
+    /// Returns an error if the session is missing or persistence fails.
+    pub async fn set_session_plan(
+        &mut self,
+        services: &AppServices,
+        session_id: &str,
+        plan: Option<SessionPlan>,
+    ) -> Result<(), SessionError> {
+        self.session_index_or_err(session_id)?;
+
+        let plan_json = plan.as_ref().map(SessionPlan::to_json);
+        services
+            .db()
+            .update_session_plan(session_id, plan_json.as_deref())
+            .await?;
+
+        services.emit_app_event(AppEvent::SessionPlanUpdated {
+            plan,
+            session_id: SessionId::from(session_id),
+        });
+
+        Ok(())
+    }
+
     /// Returns whether session model switches should also persist
     /// `DefaultSmartModel`.
     async fn should_persist_last_used_model_as_default(
@@ -1140,7 +1187,10 @@ impl SessionManager {
         })?;
 
         Ok(forge::CreateReviewRequestInput {
-            body: review_request_commit_message.body,
+            body: review_request::append_issue_closing_reference(
+                review_request_commit_message.body,
+                session.issue.as_ref(),
+            ),
             source_branch,
             target_branch: session.base_branch.clone(),
             title: review_request_commit_message.title,
@@ -1293,12 +1343,13 @@ impl SessionManager {
             .await;
         }
 
-        self.append_reply_prompt_line(
+        self.append_prompt_turn(
             services,
             &output,
             &app_event_tx,
             &persisted_session_id,
             &effective_prompt,
+            true,
         )
         .await;
         let published_upstream_ref = self
@@ -1398,25 +1449,46 @@ impl SessionManager {
             .await;
     }
 
-    /// Appends the user reply marker line to session output.
-    async fn append_reply_prompt_line(
+    /// Starts one persisted transcript turn for `prompt` and records its
+    /// transcript offset and attached skill names on the in-memory session.
+    ///
+    /// The offset lets the session output split the active turn from earlier
+    /// history without re-parsing prompt markers.
+    async fn append_prompt_turn(
         &mut self,
         services: &AppServices,
         output: &Arc<Mutex<String>>,
         app_event_tx: &mpsc::UnboundedSender<AppEvent>,
         session_id: &str,
         prompt: &TurnPrompt,
+        prepend_newline: bool,
     ) {
-        let reply_line = Self::formatted_prompt_output(prompt, true);
-        SessionTaskService::append_session_output(
+        let prompt_text = prompt.transcript_text();
+        let prompt_block = Self::formatted_prompt_output(prompt, prepend_newline);
+        let skills = prompt.skill_names();
+        let transcript_start = SessionTaskService::append_session_prompt(
             output,
             services.db(),
             app_event_tx,
             session_id,
-            &reply_line,
+            &prompt_text,
+            &prompt_block,
+            &skills,
         )
         .await;
-        self.set_active_prompt_output(session_id, reply_line);
+
+        if let Some(session) = self
+            .session_index_for_id(session_id)
+            .and_then(|session_index| self.sessions.get_mut(session_index))
+        {
+            let number = session.turns.last().map_or(1, |turn| turn.number + 1);
+            session.turns.push(SessionTurn {
+                number,
+                prompt: prompt_text,
+                skills,
+                transcript_start,
+            });
+        }
     }
 
     /// Formats one user prompt block for persisted session output.
@@ -1522,6 +1594,7 @@ impl SessionManager {
             request_kind,
             prompt,
             turn_metadata: TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref,
                 session_model,
             },
@@ -2197,8 +2270,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::ClaudeSonnet46,
             output: output.to_string(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: prompt.to_string(),
             reasoning_level_override: None,
@@ -2211,6 +2286,7 @@ mod tests {
             status,
             summary: None,
             title: title.map(ToString::to_string),
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
@@ -2470,6 +2546,43 @@ mod tests {
         assert!(event_rx.try_recv().is_err());
     }
 
+    #[tokio::test]
+    /// Ensures `set_session_plan()` persists the plan and emits the matching
+    /// reducer event.
+    async fn test_set_session_plan_persists_plan_and_emits_event() {
+        // Arrange
+        let session = test_session("Prompt", Status::Review, Some("Title"), "");
+        let database = database_with_session(&session).await;
+        let mut session_manager = session_manager_with_one_session(session);
+        let (services, mut event_rx) = test_services_with_event_receiver(
+            &database,
+            Arc::new(git::MockGitClient::new()),
+            Arc::new(forge::MockReviewRequestClient::new()),
+        );
+
+        // Act
+        session_manager
+            .set_session_plan(&services, "session-id", Some(SessionPlan::planning()))
+            .await
+            .expect("plan update should succeed");
+        let persisted_plan = database
+            .load_session_plan("session-id")
+            .await
+            .expect("plan should load");
+        let emitted_event = event_rx.try_recv().expect("expected plan update event");
+
+        // Assert
+        assert_eq!(persisted_plan, Some(SessionPlan::planning().to_json()));
+        assert_eq!(
+            emitted_event,
+            AppEvent::SessionPlanUpdated {
+                plan: Some(SessionPlan::planning()),
+                session_id: "session-id".into(),
+            }
+        );
+        assert!(event_rx.try_recv().is_err());
+    }
+
     #[tokio::test]
     async fn test_publish_review_request_creates_and_persists_link_when_lookup_misses() {
         // Arrange
@@ -2626,6 +2739,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: PathBuf::from("/tmp/image-1.png"),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Review [Image #1]".to_string(),
         };
 
@@ -3000,6 +3115,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: PathBuf::from("/tmp/image-1.png"),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Review [Image #1]".to_string(),
         };
 
@@ -3018,6 +3135,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: PathBuf::from("/tmp/image-1.png"),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Attach [Image #1] but keep literal [Image #1] text".to_string(),
         };
 
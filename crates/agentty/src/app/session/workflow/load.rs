@@ -6,9 +6,11 @@ use std::path::Path;
 use super::{draft, session_folder};
 use crate::app::SessionManager;
 use crate::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
+use crate::domain::plan::SessionPlan;
 use crate::domain::session::{
     DailyActivity, PublishedBranchSyncStatus, ReviewRequest, ReviewRequestSummary, Session,
-    SessionFollowUpTask, SessionHandles, SessionId, SessionSize, SessionStats, Status,
+    SessionFollowUpTask, SessionHandles, SessionId, SessionIssue, SessionSize, SessionStats,
+    SessionTurn, Status,
 };
 use crate::infra::agent::protocol::QuestionItem;
 #[cfg(test)]
@@ -22,6 +24,7 @@ struct LoadedSessionInput {
     draft_attachments: Vec<crate::infra::channel::TurnPromptAttachment>,
     follow_up_tasks: Vec<SessionFollowUpTask>,
     folder: std::path::PathBuf,
+    plan: Option<SessionPlan>,
     project_name: String,
     questions: Vec<QuestionItem>,
     reasoning_level_override: Option<ReasoningLevel>,
@@ -32,6 +35,7 @@ struct LoadedSessionInput {
     session_output: String,
     session_status: Status,
     size: SessionSize,
+    turns: Vec<SessionTurn>,
 }
 
 impl SessionManager {
@@ -43,9 +47,10 @@ impl SessionManager {
     ///
     /// When a handle already exists, live handle output is treated as
     /// authoritative for the returned in-memory snapshot to avoid clobbering
-    /// fresh runtime output with stale persisted rows. Active statuses are also
-    /// preserved from live handles, while terminal persisted statuses (`Done`,
-    /// `Canceled`) override stale in-memory status.
+    /// fresh runtime output with stale persisted rows, so persisted
+    /// transcripts are only read for sessions without a handle. Active statuses
+    /// are also preserved from live handles, while terminal persisted
+    /// statuses (`Done`, `Canceled`) override stale in-memory status.
     ///
     /// Retired persisted model ids are upgraded to their current replacement
     /// models while rows are loaded.
@@ -74,9 +79,19 @@ impl SessionManager {
             .await
             .unwrap_or_default();
         let persisted_follow_up_tasks = db.load_session_follow_up_tasks().await.unwrap_or_default();
+        let persisted_turns = db.load_session_turns().await.unwrap_or_default();
+        let persisted_plans = db.load_session_plans().await.unwrap_or_default();
         let stats_activity = db.load_session_activity().await.unwrap_or_default();
         let mut sessions: Vec<Session> = Vec::new();
         let mut follow_up_tasks_by_session = HashMap::<SessionId, Vec<_>>::new();
+        let mut turns_by_session = HashMap::<SessionId, Vec<_>>::new();
+        let mut plans_by_session = persisted_plans
+            .into_iter()
+            .filter_map(|persisted_plan| {
+                SessionPlan::from_json(&persisted_plan.plan)
+                    .map(|plan| (SessionId::from(persisted_plan.session_id), plan))
+            })
+            .collect::<HashMap<_, _>>();
         let mut session_worktree_availability = HashMap::new();
 
         for persisted_follow_up_task in persisted_follow_up_tasks {
@@ -85,6 +100,12 @@ impl SessionManager {
                 .or_default()
                 .push(persisted_follow_up_task.into_session_follow_up_task());
         }
+        for persisted_turn in persisted_turns {
+            turns_by_session
+                .entry(SessionId::from(persisted_turn.session_id.clone()))
+                .or_default()
+                .push(persisted_turn.into_session_turn());
+        }
         for row in db_rows {
             let session_id = SessionId::from(row.id.clone());
             let folder = session_folder(base, &session_id);
@@ -107,34 +128,8 @@ impl SessionManager {
             let session_model = AgentModel::parse_persisted(&row.model)
                 .unwrap_or_else(|_| AgentKind::Gemini.default_model());
 
-            let (session_output, session_status) = if let Some(existing) = handles.get(&session_id)
-            {
-                let output_from_handle = existing
-                    .output
-                    .lock()
-                    .ok()
-                    .map_or_else(|| row.output.clone(), |output| output.clone());
-                let status_from_handle = existing
-                    .status
-                    .lock()
-                    .ok()
-                    .map_or(persisted_status, |status| *status);
-                let merged_status =
-                    merge_loaded_session_status(persisted_status, status_from_handle);
-
-                if let Ok(mut handle_status) = existing.status.lock() {
-                    *handle_status = merged_status;
-                }
-
-                (output_from_handle, merged_status)
-            } else {
-                handles.insert(
-                    session_id.clone(),
-                    SessionHandles::new(row.output.clone(), persisted_status),
-                );
-
-                (row.output.clone(), persisted_status)
-            };
+            let (session_output, session_status) =
+                Self::sync_loaded_session_handle(db, handles, &session_id, persisted_status).await;
 
             let review_request = parse_review_request(&row);
             let draft_attachments =
@@ -151,10 +146,13 @@ impl SessionManager {
             let follow_up_tasks = follow_up_tasks_by_session
                 .remove(&session_id)
                 .unwrap_or_default();
+            let turns = turns_by_session.remove(&session_id).unwrap_or_default();
+            let plan = plans_by_session.remove(&session_id);
             sessions.push(Self::build_loaded_session(LoadedSessionInput {
                 draft_attachments,
                 follow_up_tasks,
                 folder,
+                plan,
                 project_name: project_name.clone(),
                 questions,
                 reasoning_level_override,
@@ -165,12 +163,57 @@ impl SessionManager {
                 session_output,
                 session_status,
                 size: persisted_size,
+                turns,
             }));
         }
 
         (sessions, stats_activity, session_worktree_availability)
     }
 
+    /// Returns the transcript and status for one loaded session row.
+    ///
+    /// Sessions with a live handle keep its in-memory transcript and merge the
+    /// persisted status into it. Other sessions read their transcript from
+    /// persisted turn messages and get a new handle.
+    async fn sync_loaded_session_handle(
+        db: &AppRepositories,
+        handles: &mut HashMap<SessionId, SessionHandles>,
+        session_id: &SessionId,
+        persisted_status: Status,
+    ) -> (String, Status) {
+        if let Some(existing) = handles.get(session_id) {
+            let output_from_handle = existing
+                .output
+                .lock()
+                .ok()
+                .map(|output| output.clone())
+                .unwrap_or_default();
+            let status_from_handle = existing
+                .status
+                .lock()
+                .ok()
+                .map_or(persisted_status, |status| *status);
+            let merged_status = merge_loaded_session_status(persisted_status, status_from_handle);
+
+            if let Ok(mut handle_status) = existing.status.lock() {
+                *handle_status = merged_status;
+            }
+
+            return (output_from_handle, merged_status);
+        }
+
+        let persisted_output = db
+            .load_session_transcript(session_id)
+            .await
+            .unwrap_or_default();
+        handles.insert(
+            session_id.clone(),
+            SessionHandles::new(persisted_output.clone(), persisted_status),
+        );
+
+        (persisted_output, persisted_status)
+    }
+
     /// Computes diff-derived session size and line-count totals from one
     /// worktree folder using the injected filesystem boundary.
     pub(crate) async fn session_diff_stats_for_folder(
@@ -209,8 +252,15 @@ impl SessionManager {
             in_progress_started_at: input.row.in_progress_started_at,
             in_progress_total_seconds: input.row.in_progress_total_seconds,
             is_draft: input.row.is_draft,
+            issue: input.row.issue_display_id.zip(input.row.issue_web_url).map(
+                |(display_id, web_url)| SessionIssue {
+                    display_id,
+                    web_url,
+                },
+            ),
             model: input.session_model,
             output: input.session_output,
+            plan: input.plan,
             project_name: input.project_name,
             prompt: input.row.prompt,
             reasoning_level_override: input.reasoning_level_override,
@@ -228,6 +278,7 @@ impl SessionManager {
             status: input.session_status,
             summary: input.row.summary,
             title: input.row.title,
+            turns: input.turns,
             updated_at: input.row.updated_at,
         }
     }
@@ -321,15 +372,7 @@ fn parse_questions_json(raw_json: &str) -> Option<Vec<QuestionItem>> {
 
     serde_json::from_str::<Vec<String>>(raw_json)
         .ok()
-        .map(|texts| {
-            texts
-                .into_iter()
-                .map(|text| QuestionItem {
-                    options: Vec::new(),
-                    text,
-                })
-                .collect()
-        })
+        .map(|texts| texts.into_iter().map(QuestionItem::new).collect())
 }
 
 #[cfg(test)]
@@ -338,7 +381,10 @@ mod tests {
     use std::path::{Path, PathBuf};
 
     use super::*;
-    use crate::domain::session::{ForgeKind, ReviewRequestState, ReviewRequestSummary};
+    use crate::domain::plan::PlanStep;
+    use crate::domain::session::{
+        ForgeKind, ReviewRequestState, ReviewRequestSummary, SessionMessageRole,
+    };
     use crate::infra::db::SessionReviewRequestRow;
     use crate::infra::fs;
 
@@ -384,7 +430,7 @@ mod tests {
         )
         .await
         .expect("failed to insert session");
-        db.append_session_output(session_id, "DB Output")
+        db.append_session_message(session_id, SessionMessageRole::System, "DB Output")
             .await
             .expect("failed to append persisted output");
 
@@ -547,6 +593,57 @@ mod tests {
         assert_eq!(session.summary.as_deref(), Some("persisted summary"));
     }
 
+    /// Ensures reload attaches the persisted plan to plan-first sessions.
+    #[tokio::test]
+    async fn test_load_sessions_reads_persisted_plan() {
+        // Arrange
+        let db = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = db
+            .upsert_project("/tmp/test", None)
+            .await
+            .expect("failed to upsert project");
+
+        let session_id = "test-session";
+        db.insert_session(
+            session_id,
+            "gemini-3-flash-preview",
+            "main",
+            "Review",
+            project_id,
+        )
+        .await
+        .expect("failed to insert session");
+        let plan = SessionPlan::approved(vec![PlanStep::new("Add the column", Vec::new())]);
+        db.update_session_plan(session_id, Some(&plan.to_json()))
+            .await
+            .expect("failed to update session plan");
+
+        let base_path = Path::new("/virtual/session-base");
+        let session_dir = session_folder(base_path, session_id);
+        let mock_fs_client = create_folder_lookup_mock(vec![session_dir]);
+        let mut handles: HashMap<SessionId, SessionHandles> = HashMap::new();
+
+        // Act
+        let (sessions, _, _) = SessionManager::load_sessions_with_fs_client(
+            base_path,
+            &db,
+            project_id,
+            Path::new("/tmp/test"),
+            &mut handles,
+            &mock_fs_client,
+        )
+        .await;
+
+        // Assert
+        let session = sessions
+            .iter()
+            .find(|session| session.id == session_id)
+            .expect("missing reloaded session");
+        assert_eq!(session.plan, Some(plan));
+    }
+
     /// Ensures terminal persisted statuses replace stale active handle status
     /// during reload.
     #[tokio::test]
@@ -774,8 +871,9 @@ mod tests {
             in_progress_total_seconds: 0,
             input_tokens: 0,
             is_draft: false,
+            issue_display_id: None,
+            issue_web_url: None,
             model: "gpt-5.4".to_string(),
-            output: String::new(),
             output_tokens: 0,
             project_id: Some(1),
             prompt: String::new(),
@@ -243,6 +243,10 @@ impl SessionManager {
                 restore_view: ConfirmationViewMode { session_id, .. },
                 ..
             }
+            | AppMode::PlanReview {
+                restore_view: ConfirmationViewMode { session_id, .. },
+                ..
+            }
             | AppMode::PublishBranchInput {
                 restore_view: ConfirmationViewMode { session_id, .. },
                 ..
@@ -436,8 +440,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "Implement forge review support".to_string(),
             reasoning_level_override: None,
@@ -450,6 +456,7 @@ mod tests {
             status,
             summary: None,
             title: Some("Add forge review support".to_string()),
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
@@ -77,8 +77,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -91,6 +93,7 @@ mod tests {
             status,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
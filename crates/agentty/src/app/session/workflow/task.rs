@@ -14,7 +14,7 @@ use crate::app::assist::{
 use crate::app::session::{Clock, SessionError, unix_timestamp_from_system_time};
 use crate::app::{AppEvent, SessionManager};
 use crate::domain::agent::AgentModel;
-use crate::domain::session::{SessionId, SessionSize, Status};
+use crate::domain::session::{SessionId, SessionMessageRole, SessionSize, Status};
 use crate::domain::setting::SettingName;
 use crate::infra::agent;
 use crate::infra::db::AppRepositories;
@@ -673,7 +673,7 @@ impl SessionTaskService {
 
         let answer_text = assist_submission.response.to_answer_display_text();
         if !answer_text.trim().is_empty() {
-            Self::append_session_output(&output, &db, &app_event_tx, &id, &answer_text).await;
+            Self::append_assistant_output(&output, &db, &app_event_tx, &id, &answer_text).await;
         }
 
         // Best-effort: stats persistence failure is non-critical.
@@ -764,19 +764,93 @@ impl SessionTaskService {
         true
     }
 
-    /// Appends output to the in-memory handle buffer and database.
+    /// Appends one app-generated notice to the in-memory handle buffer and
+    /// the latest persisted turn.
     pub(crate) async fn append_session_output(
         output: &Arc<Mutex<String>>,
         db: &AppRepositories,
         app_event_tx: &mpsc::UnboundedSender<AppEvent>,
         id: &str,
         message: &str,
+    ) {
+        Self::append_session_message(
+            output,
+            db,
+            app_event_tx,
+            id,
+            SessionMessageRole::System,
+            message,
+        )
+        .await;
+    }
+
+    /// Appends agent answer text to the in-memory handle buffer and the
+    /// latest persisted turn.
+    pub(crate) async fn append_assistant_output(
+        output: &Arc<Mutex<String>>,
+        db: &AppRepositories,
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        id: &str,
+        message: &str,
+    ) {
+        Self::append_session_message(
+            output,
+            db,
+            app_event_tx,
+            id,
+            SessionMessageRole::Assistant,
+            message,
+        )
+        .await;
+    }
+
+    /// Appends one user prompt block to the in-memory handle buffer and
+    /// persists it, with the names of the attached `skills`, as the first
+    /// message of a new turn.
+    ///
+    /// Returns the byte offset where the prompt block starts in the buffer.
+    pub(crate) async fn append_session_prompt(
+        output: &Arc<Mutex<String>>,
+        db: &AppRepositories,
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        id: &str,
+        prompt: &str,
+        prompt_block: &str,
+        skills: &[String],
+    ) -> usize {
+        let transcript_start = output.lock().map_or(0, |mut buf| {
+            let transcript_start = buf.len();
+            buf.push_str(prompt_block);
+
+            transcript_start
+        });
+        // Best-effort: output persistence failure is non-critical.
+        let _ = db
+            .start_session_turn(id, prompt, prompt_block, skills)
+            .await;
+        // Fire-and-forget: receiver may be dropped during shutdown.
+        let _ = app_event_tx.send(AppEvent::SessionUpdated {
+            session_id: SessionId::from(id),
+        });
+
+        transcript_start
+    }
+
+    /// Appends one transcript message to the in-memory handle buffer and the
+    /// latest persisted turn.
+    async fn append_session_message(
+        output: &Arc<Mutex<String>>,
+        db: &AppRepositories,
+        app_event_tx: &mpsc::UnboundedSender<AppEvent>,
+        id: &str,
+        role: SessionMessageRole,
+        message: &str,
     ) {
         if let Ok(mut buf) = output.lock() {
             buf.push_str(message);
         }
         // Best-effort: output persistence failure is non-critical.
-        let _ = db.append_session_output(id, message).await;
+        let _ = db.append_session_message(id, role, message).await;
         // Fire-and-forget: receiver may be dropped during shutdown.
         let _ = app_event_tx.send(AppEvent::SessionUpdated {
             session_id: SessionId::from(id),
@@ -18,16 +18,19 @@ use crate::app::session::{
 };
 use crate::app::{AppEvent, AppServices, SessionManager, branch_publish};
 use crate::domain::agent::{AgentModel, ReasoningLevel};
+use crate::domain::mcp::{McpServer, parse_mcp_servers};
+use crate::domain::permission::PermissionMode;
+use crate::domain::plan::{self, PlanStep, SessionPlan};
 use crate::domain::session::{
-    PublishBranchAction, PublishedBranchSyncStatus, SessionFollowUpTask, SessionId, SessionStats,
-    Status,
+    PublishBranchAction, PublishedBranchSyncStatus, SESSION_DATA_DIR, SessionFollowUpTask,
+    SessionId, SessionStats, Status,
 };
 use crate::domain::setting::SettingName;
 use crate::infra::channel::{
     AgentChannel, AgentError, AgentRequestKind, TurnEvent, TurnPrompt, TurnRequest, TurnResult,
     create_agent_channel,
 };
-use crate::infra::db::{AppRepositories, SessionTurnMetadata};
+use crate::infra::db::{AppRepositories, DbError, SessionTurnMetadata};
 use crate::infra::fs::FsClient;
 use crate::infra::git::GitClient;
 use crate::infra::{agent, process};
@@ -35,6 +38,10 @@ use crate::infra::{agent, process};
 const RESTART_FAILURE_REASON: &str = "Interrupted by app restart";
 const CANCEL_BEFORE_EXECUTION_REASON: &str = "Session canceled before execution";
 
+/// Project-relative file whose contents Agentty adds to the project
+/// instructions configured in Settings.
+const PROJECT_INSTRUCTIONS_FILE: &str = "instructions.md";
+
 /// Per-turn data captured at enqueue time that travels alongside the channel
 /// turn but is consumed only after turn completion.
 ///
@@ -44,6 +51,12 @@ const CANCEL_BEFORE_EXECUTION_REASON: &str = "Session canceled before execution"
 /// policies, model overrides, etc.) should be added here instead of widening
 /// every intermediate signature.
 pub(super) struct TurnMetadata {
+    /// Project instructions delivered with the turn.
+    ///
+    /// Enqueue sites leave this `None`; the worker resolves the current
+    /// instructions when the turn starts so the persisted bootstrap marker
+    /// matches what the provider actually received.
+    pub(super) project_instructions: Option<String>,
     /// Published-upstream reference captured when the turn was queued,
     /// consumed after turn completion by the auto-push workflow.
     pub(super) published_upstream_ref: Option<String>,
@@ -123,6 +136,7 @@ struct SessionWorkerContext {
 /// corresponding reducer projection.
 struct TurnPersistence<'a> {
     context: &'a SessionWorkerContext,
+    project_instructions: Option<&'a str>,
     session_model: AgentModel,
 }
 
@@ -369,22 +383,7 @@ impl SessionWorkerService {
         let turn_cancel_token = fresh_turn_cancel_token(context)?;
 
         if matches!(request_kind, AgentRequestKind::SessionResume { .. }) {
-            // Best-effort: questions persistence failure is non-critical.
-            let _ = context
-                .db
-                .update_session_questions(&context.session_id, "")
-                .await;
-
-            // Best-effort: status transition failure is non-critical.
-            let _ = SessionTaskService::update_status(
-                &context.status,
-                context.clock.as_ref(),
-                &context.db,
-                &context.app_event_tx,
-                &context.session_id,
-                Status::InProgress,
-            )
-            .await;
+            reopen_resumed_session(context).await;
         }
 
         let session_project_id = load_session_project_id(&context.db, &context.session_id).await;
@@ -403,15 +402,22 @@ impl SessionWorkerService {
             .await
             .ok()
             .flatten();
+        let turn_metadata =
+            with_project_instructions(context, session_project_id, turn_metadata).await;
+        let mcp_servers = load_project_mcp_servers(&context.db, session_project_id).await;
+        let permission_mode = load_turn_permission_mode(&context.db, &context.session_id).await;
 
         let req = TurnRequest {
             folder: context.folder.clone(),
             live_session_output: Some(Arc::clone(&context.output)),
             model: turn_metadata.session_model.as_str().to_string(),
             request_kind: request_kind.clone(),
-            prompt: prompt.clone(),
+            prompt: plan_turn_prompt(prompt.clone(), permission_mode),
             provider_conversation_id,
             persisted_instruction_conversation_id,
+            mcp_servers,
+            permission_mode,
+            project_instructions: turn_metadata.project_instructions.clone(),
             reasoning_level,
         };
 
@@ -631,12 +637,13 @@ impl TurnPersistence<'_> {
             input_tokens,
             output_tokens,
         };
-        let instruction_conversation_id =
-            if agent::transport_mode(self.session_model.kind()).uses_app_server() {
-                agent::normalize_instruction_conversation_id(provider_conversation_id)
-            } else {
-                None
-            };
+        let instruction_conversation_id = if agent::transport_mode(self.session_model.kind())
+            .uses_app_server()
+        {
+            agent::instruction_bootstrap_marker(provider_conversation_id, self.project_instructions)
+        } else {
+            None
+        };
         self.context
             .db
             .persist_session_turn_metadata(
@@ -655,9 +662,11 @@ impl TurnPersistence<'_> {
             .db
             .replace_session_follow_up_tasks(&self.context.session_id, &persisted_follow_up_text)
             .await?;
+        let plan = self.apply_plan_progress(assistant_message).await?;
 
         Ok(TurnAppliedState {
             follow_up_tasks,
+            plan,
             questions,
             summary: (!summary.is_empty()).then_some(summary),
             token_usage_delta,
@@ -665,6 +674,42 @@ impl TurnPersistence<'_> {
     }
 }
 
+impl TurnPersistence<'_> {
+    /// Applies the turn's `plan` block to the persisted session plan.
+    ///
+    /// Planning turns replace the proposed steps, while turns on an approved
+    /// plan tick off reported steps. Returns `None` for sessions without a
+    /// plan.
+    async fn apply_plan_progress(
+        &self,
+        assistant_message: &agent::AgentResponse,
+    ) -> Result<Option<SessionPlan>, SessionError> {
+        let Some(mut session_plan) =
+            load_session_plan(&self.context.db, &self.context.session_id).await?
+        else {
+            return Ok(None);
+        };
+        let Some(response_plan) = &assistant_message.plan else {
+            return Ok(Some(session_plan));
+        };
+
+        session_plan.propose_steps(
+            response_plan
+                .steps
+                .iter()
+                .map(|step| PlanStep::new(step.text.clone(), step.files.clone()))
+                .collect(),
+        );
+        session_plan.complete_steps(&response_plan.completed_steps);
+        self.context
+            .db
+            .update_session_plan(&self.context.session_id, Some(&session_plan.to_json()))
+            .await?;
+
+        Ok(Some(session_plan))
+    }
+}
+
 /// Applies the turn result: appends the final response, persists follow-up
 /// metadata, updates stats, and runs auto-commit. Returns `Ok(Status)` on
 /// success or `Err(description)` on turn failure after appending the error to
@@ -831,7 +876,7 @@ async fn apply_successful_turn_result(
     } = result;
 
     if let Some(message) = build_assistant_transcript_output(&assistant_message) {
-        SessionTaskService::append_session_output(
+        SessionTaskService::append_assistant_output(
             &context.output,
             &context.db,
             &context.app_event_tx,
@@ -842,6 +887,7 @@ async fn apply_successful_turn_result(
     }
     let turn_applied_state = match (TurnPersistence {
         context,
+        project_instructions: turn_metadata.project_instructions.as_deref(),
         session_model: turn_metadata.session_model,
     }
     .apply(
@@ -1064,6 +1110,151 @@ async fn load_session_project_id(db: &AppRepositories, session_id: &str) -> Opti
     db.load_session_project_id(session_id).await.ok().flatten()
 }
 
+/// Clears pending questions and moves a resumed session back to
+/// `InProgress` before its turn runs.
+async fn reopen_resumed_session(context: &SessionWorkerContext) {
+    // Best-effort: questions persistence failure is non-critical.
+    let _ = context
+        .db
+        .update_session_questions(&context.session_id, "")
+        .await;
+
+    // Best-effort: status transition failure is non-critical.
+    let _ = SessionTaskService::update_status(
+        &context.status,
+        context.clock.as_ref(),
+        &context.db,
+        &context.app_event_tx,
+        &context.session_id,
+        Status::InProgress,
+    )
+    .await;
+}
+
+/// Resolves the project instructions for one turn and stores them on its
+/// metadata.
+///
+/// The same text is sent to the provider and later fingerprinted into the
+/// persisted instruction bootstrap marker, so both must come from this one
+/// lookup.
+async fn with_project_instructions(
+    context: &SessionWorkerContext,
+    session_project_id: Option<i64>,
+    turn_metadata: TurnMetadata,
+) -> TurnMetadata {
+    let project_instructions =
+        load_project_instructions(&context.db, context.fs_client.as_ref(), session_project_id)
+            .await;
+
+    TurnMetadata {
+        project_instructions,
+        ..turn_metadata
+    }
+}
+
+/// Loads the Agentty-managed instructions for one session's project.
+///
+/// Combines `.agentty/instructions.md` in the project root with the
+/// `Project Instructions` setting, in that order. Returns `None` when both
+/// are missing or blank.
+async fn load_project_instructions(
+    db: &AppRepositories,
+    fs_client: &dyn FsClient,
+    project_id: Option<i64>,
+) -> Option<String> {
+    let project_id = project_id?;
+    let setting_instructions = db
+        .get_project_setting(project_id, SettingName::ProjectInstructions)
+        .await
+        .ok()
+        .flatten();
+    let file_instructions = match db.get_project(project_id).await {
+        Ok(Some(project)) => fs_client
+            .read_file(
+                PathBuf::from(project.path)
+                    .join(SESSION_DATA_DIR)
+                    .join(PROJECT_INSTRUCTIONS_FILE),
+            )
+            .await
+            .ok()
+            .map(|bytes| String::from_utf8_lossy(&bytes).into_owned()),
+        _ => None,
+    };
+
+    compose_project_instructions(
+        file_instructions.as_deref(),
+        setting_instructions.as_deref(),
+    )
+}
+
+/// Loads the MCP servers configured for one session's project.
+///
+/// Returns no servers when the project or setting is missing or the setting
+/// does not parse; Settings shows the parse error on the `MCP Servers` row.
+async fn load_project_mcp_servers(db: &AppRepositories, project_id: Option<i64>) -> Vec<McpServer> {
+    let Some(project_id) = project_id else {
+        return Vec::new();
+    };
+
+    db.get_project_setting(project_id, SettingName::McpServers)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|content| parse_mcp_servers(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Joins non-blank instruction sources with one blank line between them.
+fn compose_project_instructions(
+    file_instructions: Option<&str>,
+    setting_instructions: Option<&str>,
+) -> Option<String> {
+    let sections = [file_instructions, setting_instructions]
+        .into_iter()
+        .flatten()
+        .map(str::trim)
+        .filter(|section| !section.is_empty())
+        .collect::<Vec<_>>();
+
+    (!sections.is_empty()).then(|| sections.join("\n\n"))
+}
+
+/// Loads the plan-first state for one session, when present.
+async fn load_session_plan(
+    db: &AppRepositories,
+    session_id: &str,
+) -> Result<Option<SessionPlan>, DbError> {
+    let plan = db
+        .load_session_plan(session_id)
+        .await?
+        .and_then(|content| SessionPlan::from_json(&content));
+
+    Ok(plan)
+}
+
+/// Loads the permission mode for the next turn of one session.
+///
+/// A failed plan lookup falls back to [`PermissionMode::Plan`], so a
+/// plan-first session never gains write access because its state could not
+/// be read.
+async fn load_turn_permission_mode(db: &AppRepositories, session_id: &str) -> PermissionMode {
+    match load_session_plan(db, session_id).await {
+        Ok(Some(session_plan)) => session_plan.permission_mode(),
+        Ok(None) => PermissionMode::default(),
+        Err(_) => PermissionMode::Plan,
+    }
+}
+
+/// Prepends the read-only planning instruction to prompts sent while a
+/// plan-first session waits for an approved plan.
+fn plan_turn_prompt(mut prompt: TurnPrompt, permission_mode: PermissionMode) -> TurnPrompt {
+    if permission_mode.is_read_only() {
+        prompt.text = plan::planning_prompt(&prompt.text);
+    }
+
+    prompt
+}
+
 /// Loads the effective reasoning level for one session context.
 async fn load_session_reasoning_level(
     db: &AppRepositories,
@@ -1216,17 +1407,26 @@ mod tests {
 
     use super::*;
     use crate::infra::agent::AgentResponse;
-    use crate::infra::agent::protocol::{AgentResponseSummary, QuestionItem};
+    use crate::infra::agent::protocol::{
+        AgentResponsePlan, AgentResponsePlanStep, AgentResponseSummary, QuestionItem,
+    };
     use crate::infra::channel::MockAgentChannel;
     use crate::infra::db::Database;
     use crate::infra::fs;
     use crate::infra::git::MockGitClient;
 
     /// Builds one filesystem mock that treats every probed path as an
-    /// existing directory.
+    /// existing directory and reports every file read as missing.
     fn mock_fs_client_with_existing_directories() -> fs::MockFsClient {
         let mut fs_client = fs::MockFsClient::new();
         fs_client.expect_is_dir().times(0..).returning(|_| true);
+        fs_client.expect_read_file().times(0..).returning(|_| {
+            Box::pin(async {
+                Err(fs::FsError::Io(std::io::Error::from(
+                    std::io::ErrorKind::NotFound,
+                )))
+            })
+        });
 
         fs_client
     }
@@ -1241,6 +1441,7 @@ mod tests {
             request_kind: AgentRequestKind::SessionStart,
             prompt: "prompt".into(),
             turn_metadata: TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref: None,
                 session_model: AgentModel::ClaudeSonnet46,
             },
@@ -1252,6 +1453,7 @@ mod tests {
             },
             prompt: "prompt".into(),
             turn_metadata: TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref: None,
                 session_model: AgentModel::ClaudeSonnet46,
             },
@@ -1271,6 +1473,7 @@ mod tests {
         // Arrange
         let agent_response = AgentResponse {
             answer: "Implemented the feature.".to_string(),
+            plan: None,
             questions: vec![
                 QuestionItem::new("Need a target branch?"),
                 QuestionItem::new("Need migration notes?"),
@@ -1299,6 +1502,7 @@ mod tests {
              checks and\nrollback notes?";
         let agent_response = AgentResponse {
             answer: String::new(),
+            plan: None,
             questions: vec![QuestionItem::new(numbered_questions)],
             summary: None,
         };
@@ -1317,6 +1521,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: "Implemented the fix.".to_string(),
+            plan: None,
             questions: vec![QuestionItem::new("Need me to run tests?")],
             summary: None,
         };
@@ -1338,6 +1543,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: String::new(),
+            plan: None,
             questions: vec![QuestionItem::new("Should I apply the patch?")],
             summary: None,
         };
@@ -1358,6 +1564,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: String::new(),
+            plan: None,
             questions: vec![QuestionItem::new("\n")],
             summary: None,
         };
@@ -1376,6 +1583,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: "Implemented the fix.".to_string(),
+            plan: None,
             questions: Vec::new(),
             summary: Some(AgentResponseSummary {
                 turn: "Updated the greeting flow.".to_string(),
@@ -1497,6 +1705,7 @@ mod tests {
         let result = SessionWorkerService::run_channel_turn(
             &context,
             TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref: None,
                 session_model: AgentModel::Gemini3FlashPreview,
             },
@@ -1550,6 +1759,7 @@ mod tests {
                     Ok(TurnResult {
                         assistant_message: AgentResponse {
                             answer: "done".to_string(),
+                            plan: None,
                             questions: Vec::new(),
                             summary: None,
                         },
@@ -1593,6 +1803,7 @@ mod tests {
         let result = SessionWorkerService::run_channel_turn(
             &context,
             TurnMetadata {
+                project_instructions: None,
                 published_upstream_ref: None,
                 session_model: AgentModel::Gemini3FlashPreview,
             },
@@ -1654,6 +1865,9 @@ mod tests {
             prompt: "test".into(),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
         };
 
@@ -1720,6 +1934,9 @@ mod tests {
             prompt: "test".into(),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
         };
 
@@ -1901,6 +2118,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Implemented the change.".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: Some(AgentResponseSummary {
                     turn: "- Updated the worker flow.".to_string(),
@@ -1915,6 +2133,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: None,
             published_upstream_ref: None,
             session_model: AgentModel::Gemini3FlashPreview,
         };
@@ -1943,6 +2162,97 @@ mod tests {
         assert!(!output.contains("Document the worker summary flow."));
     }
 
+    #[tokio::test]
+    /// Verifies planning turns store the proposed steps as a plan awaiting
+    /// user approval.
+    async fn test_apply_turn_result_persists_proposed_plan() {
+        // Arrange
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let db = Database::open_in_memory().await.expect("failed to open db");
+        let project_id = db
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to upsert project");
+        db.insert_session(
+            "sess1",
+            "gemini-3-flash-preview",
+            "main",
+            "InProgress",
+            project_id,
+        )
+        .await
+        .expect("failed to insert session");
+        let repositories = AppRepositories::from_database(&db);
+        repositories
+            .update_session_plan("sess1", Some(&SessionPlan::planning().to_json()))
+            .await
+            .expect("failed to store plan");
+
+        let mut mock_git_client = MockGitClient::new();
+        mock_git_client
+            .expect_is_worktree_clean()
+            .times(1)
+            .returning(|_| Box::pin(async { Ok(true) }));
+        let context = SessionWorkerContext {
+            app_event_tx: mpsc::unbounded_channel().0,
+            cancel_token: Arc::new(Mutex::new(CancellationToken::new())),
+            channel: Arc::new(MockAgentChannel::new()),
+            child_pid: Arc::new(Mutex::new(None)),
+            clock: Arc::new(crate::app::session::RealClock),
+            db: repositories.clone(),
+            folder: base_dir.path().to_path_buf(),
+            fs_client: Arc::new(fs::MockFsClient::new()),
+            git_client: Arc::new(mock_git_client),
+            output: Arc::new(Mutex::new(String::new())),
+            session_id: "sess1".into(),
+            status: Arc::new(Mutex::new(Status::InProgress)),
+        };
+        let turn_result = Ok(TurnResult {
+            assistant_message: AgentResponse {
+                answer: "Here is the plan.".to_string(),
+                plan: Some(AgentResponsePlan {
+                    completed_steps: Vec::new(),
+                    steps: vec![AgentResponsePlanStep {
+                        files: vec!["src/lib.rs".to_string()],
+                        text: "Add the parser".to_string(),
+                    }],
+                }),
+                questions: Vec::new(),
+                summary: None,
+            },
+            context_reset: false,
+            input_tokens: 0,
+            output_tokens: 0,
+            provider_conversation_id: None,
+        });
+        let turn_metadata = TurnMetadata {
+            project_instructions: None,
+            published_upstream_ref: None,
+            session_model: AgentModel::Gemini3FlashPreview,
+        };
+
+        // Act
+        apply_turn_result(&context, turn_metadata, turn_result)
+            .await
+            .expect("turn result should succeed");
+        let stored_plan = repositories
+            .load_session_plan("sess1")
+            .await
+            .expect("failed to load plan")
+            .and_then(|content| SessionPlan::from_json(&content))
+            .expect("plan should be stored");
+
+        // Assert
+        assert!(stored_plan.is_awaiting_approval());
+        assert_eq!(
+            stored_plan.steps,
+            vec![PlanStep::new(
+                "Add the parser",
+                vec!["src/lib.rs".to_string()]
+            )]
+        );
+    }
+
     #[tokio::test]
     /// Verifies completed turns auto-push already-published session branches
     /// in the background and report sync progress through app events.
@@ -1993,6 +2303,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Implemented the change.".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: None,
             },
@@ -2004,6 +2315,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: None,
             published_upstream_ref: Some("origin/wt/session-id".to_string()),
             session_model: AgentModel::Gemini3FlashPreview,
         };
@@ -2095,6 +2407,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Implemented the change.".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: None,
             },
@@ -2106,6 +2419,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: None,
             published_upstream_ref: Some("origin/wt/session-id".to_string()),
             session_model: AgentModel::Gemini3FlashPreview,
         };
@@ -2181,6 +2495,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Implemented the change.".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: Some(AgentResponseSummary {
                     turn: "- Attempted the update.".to_string(),
@@ -2195,6 +2510,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: None,
             published_upstream_ref: None,
             session_model: AgentModel::Gemini3FlashPreview,
         };
@@ -2270,6 +2586,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Hey! How can I help you today?".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: Some(AgentResponseSummary {
                     turn: "No changes".to_string(),
@@ -2284,6 +2601,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: None,
             published_upstream_ref: None,
             session_model: AgentModel::Gemini3FlashPreview,
         };
@@ -2338,6 +2656,7 @@ mod tests {
         let turn_result = Ok(TurnResult {
             assistant_message: AgentResponse {
                 answer: "Implemented the change.".to_string(),
+                plan: None,
                 questions: Vec::new(),
                 summary: None,
             },
@@ -2349,6 +2668,7 @@ mod tests {
 
         // Act
         let turn_metadata = TurnMetadata {
+            project_instructions: Some("Use tabs.".to_string()),
             published_upstream_ref: None,
             session_model: AgentModel::Gpt54,
         };
@@ -2364,8 +2684,92 @@ mod tests {
         assert_eq!(status, Status::Review);
         assert_eq!(
             instruction_conversation_id,
-            agent::normalize_instruction_conversation_id(Some("thread-123"))
+            agent::instruction_bootstrap_marker(Some("thread-123"), Some("Use tabs."))
+        );
+    }
+
+    #[tokio::test]
+    /// Verifies project instructions combine the `.agentty/instructions.md`
+    /// file with the project setting and skip blank sources.
+    async fn test_load_project_instructions_combines_file_and_setting() {
+        // Arrange
+        let project_dir = tempdir().expect("failed to create temp dir");
+        let data_dir = project_dir.path().join(SESSION_DATA_DIR);
+        std::fs::create_dir_all(&data_dir).expect("failed to create data dir");
+        std::fs::write(
+            data_dir.join(PROJECT_INSTRUCTIONS_FILE),
+            "\nRun `cargo test`.\n",
+        )
+        .expect("failed to write instructions file");
+        let db = Database::open_in_memory().await.expect("failed to open db");
+        let project_id = db
+            .upsert_project(&project_dir.path().to_string_lossy(), Some("main"))
+            .await
+            .expect("failed to upsert project");
+        let repositories = AppRepositories::from_database(&db);
+
+        // Act
+        let file_only =
+            load_project_instructions(&repositories, &fs::RealFsClient, Some(project_id)).await;
+        repositories
+            .upsert_project_setting(
+                project_id,
+                SettingName::ProjectInstructions,
+                "Prefer small commits.",
+            )
+            .await
+            .expect("failed to persist project instructions");
+        let combined =
+            load_project_instructions(&repositories, &fs::RealFsClient, Some(project_id)).await;
+        let without_project =
+            load_project_instructions(&repositories, &fs::RealFsClient, None).await;
+
+        // Assert
+        assert_eq!(file_only.as_deref(), Some("Run `cargo test`."));
+        assert_eq!(
+            combined.as_deref(),
+            Some("Run `cargo test`.\n\nPrefer small commits.")
+        );
+        assert!(without_project.is_none());
+    }
+
+    #[tokio::test]
+    /// Verifies project MCP servers load from the project setting and fall
+    /// back to none when the setting does not parse.
+    async fn test_load_project_mcp_servers_parses_setting_and_ignores_invalid_json() {
+        // Arrange
+        let db = Database::open_in_memory().await.expect("failed to open db");
+        let project_id = db
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to upsert project");
+        let repositories = AppRepositories::from_database(&db);
+        repositories
+            .upsert_project_setting(
+                project_id,
+                SettingName::McpServers,
+                r#"{"docs": {"command": "docs-mcp"}}"#,
+            )
+            .await
+            .expect("failed to persist MCP servers");
+
+        // Act
+        let servers = load_project_mcp_servers(&repositories, Some(project_id)).await;
+        repositories
+            .upsert_project_setting(project_id, SettingName::McpServers, "{")
+            .await
+            .expect("failed to persist MCP servers");
+        let invalid_servers = load_project_mcp_servers(&repositories, Some(project_id)).await;
+
+        // Assert
+        assert_eq!(
+            servers
+                .iter()
+                .map(|server| server.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["docs"]
         );
+        assert!(invalid_servers.is_empty());
     }
 
     #[tokio::test]
@@ -5,7 +5,7 @@ use std::time::Instant;
 use ratatui::widgets::TableState;
 
 use crate::app::session::{Clock, SESSION_REFRESH_INTERVAL};
-use crate::domain::session::{Session, SessionHandles, SessionId, SessionSize};
+use crate::domain::session::{Session, SessionHandles, SessionId, SessionIssue, SessionSize};
 
 /// Cached ahead/behind snapshots for one session branch.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
@@ -224,6 +224,16 @@ impl SessionState {
         follow_up_task.launched_session_id = launched_session_id;
     }
 
+    /// Links one forge issue to a session snapshot so later review-request
+    /// publishing can reference it.
+    pub(crate) fn set_session_issue(&mut self, session_id: &str, issue: SessionIssue) {
+        let Some(session) = self.session_mut_for_id(session_id) else {
+            return;
+        };
+
+        session.issue = Some(issue);
+    }
+
     /// Replaces all cached session git-status snapshots with one fresh poll
     /// result.
     pub(crate) fn replace_session_git_statuses(
@@ -450,8 +460,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: "old".to_string(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -464,6 +476,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let handles: HashMap<SessionId, SessionHandles> = HashMap::from([(
@@ -501,8 +514,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: "Old".to_string(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -515,6 +530,7 @@ mod tests {
             status: Status::New,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let handles = SessionHandles::new("New".to_string(), Status::InProgress);
@@ -541,8 +557,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: "first line\n".to_string(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -555,6 +573,7 @@ mod tests {
             status: Status::InProgress,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let handles =
@@ -583,8 +602,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -597,6 +618,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let mut state = SessionState::new(
@@ -631,8 +653,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -645,6 +669,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let replacement_session = Session {
@@ -657,8 +682,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -671,6 +698,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let mut state = SessionState::new(
@@ -705,8 +733,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -719,6 +749,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let second_session = Session {
@@ -731,8 +762,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -745,6 +778,7 @@ mod tests {
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let mut state = SessionState::new(
@@ -783,8 +817,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: "abc".to_string(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -797,6 +833,7 @@ mod tests {
             status: Status::InProgress,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let handles = SessionHandles::new("xyzq".to_string(), Status::Review);
@@ -875,8 +912,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -889,6 +928,7 @@ mod tests {
             status: Status::Done,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         surviving_session
@@ -909,8 +949,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: "prompt".to_string(),
             reasoning_level_override: None,
@@ -923,6 +965,7 @@ mod tests {
             status: Status::Done,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
         let mut state = SessionState::new(
@@ -2,8 +2,13 @@ use ratatui::widgets::TableState;
 
 use crate::agent::{AgentKind, AgentModel, ReasoningLevel};
 use crate::app::AppServices;
+use crate::app::notification::{
+    NotificationSettings, NotificationTransitions, TerminalNotification,
+};
 use crate::domain::input::InputState;
+use crate::domain::mcp::parse_mcp_servers;
 use crate::domain::setting::SettingName;
+use crate::ui::theme::{self, ThemeMode};
 
 /// Loads the persisted smart-model default used for new sessions.
 ///
@@ -64,16 +69,36 @@ enum SettingRow {
     DefaultReviewModel,
     IncludeCoauthoredByAgentty,
     OpenCommand,
+    ProjectInstructions,
+    McpServers,
+    NotifyOnReview,
+    NotifyOnQuestion,
+    NotifyOnDone,
+    TerminalNotification,
+    TmuxNotification,
+    NotificationCommand,
+    Theme,
+    SplitLayout,
 }
 
 impl SettingRow {
-    const ALL: [Self; 6] = [
+    const ALL: [Self; 16] = [
         Self::ReasoningLevel,
         Self::DefaultSmartModel,
         Self::DefaultFastModel,
         Self::DefaultReviewModel,
         Self::IncludeCoauthoredByAgentty,
         Self::OpenCommand,
+        Self::ProjectInstructions,
+        Self::McpServers,
+        Self::NotifyOnReview,
+        Self::NotifyOnQuestion,
+        Self::NotifyOnDone,
+        Self::TerminalNotification,
+        Self::TmuxNotification,
+        Self::NotificationCommand,
+        Self::Theme,
+        Self::SplitLayout,
     ];
     const ROW_COUNT: usize = Self::ALL.len();
 
@@ -94,6 +119,16 @@ impl SettingRow {
             Self::DefaultReviewModel => "Default Review Model",
             Self::IncludeCoauthoredByAgentty => "Coauthored by Agentty",
             Self::OpenCommand => "Open Commands",
+            Self::ProjectInstructions => "Project Instructions",
+            Self::McpServers => "MCP Servers",
+            Self::NotifyOnReview => "Notify On Review",
+            Self::NotifyOnQuestion => "Notify On Question",
+            Self::NotifyOnDone => "Notify On Done",
+            Self::TerminalNotification => "Terminal Notification",
+            Self::TmuxNotification => "Tmux Notification",
+            Self::NotificationCommand => "Notification Command",
+            Self::Theme => "Theme",
+            Self::SplitLayout => "Split Layout",
         }
     }
 
@@ -104,8 +139,18 @@ impl SettingRow {
             | Self::DefaultSmartModel
             | Self::DefaultFastModel
             | Self::DefaultReviewModel
-            | Self::IncludeCoauthoredByAgentty => SettingControl::Selector,
-            Self::OpenCommand => SettingControl::TextInput,
+            | Self::IncludeCoauthoredByAgentty
+            | Self::NotifyOnReview
+            | Self::NotifyOnQuestion
+            | Self::NotifyOnDone
+            | Self::TerminalNotification
+            | Self::TmuxNotification
+            | Self::Theme
+            | Self::SplitLayout => SettingControl::Selector,
+            Self::OpenCommand
+            | Self::ProjectInstructions
+            | Self::McpServers
+            | Self::NotificationCommand => SettingControl::TextInput,
         }
     }
 
@@ -118,6 +163,16 @@ impl SettingRow {
             Self::DefaultReviewModel => SettingName::DefaultReviewModel,
             Self::IncludeCoauthoredByAgentty => SettingName::IncludeCoauthoredByAgentty,
             Self::OpenCommand => SettingName::OpenCommand,
+            Self::ProjectInstructions => SettingName::ProjectInstructions,
+            Self::McpServers => SettingName::McpServers,
+            Self::NotifyOnReview => SettingName::NotifyOnReview,
+            Self::NotifyOnQuestion => SettingName::NotifyOnQuestion,
+            Self::NotifyOnDone => SettingName::NotifyOnDone,
+            Self::TerminalNotification => SettingName::TerminalNotification,
+            Self::TmuxNotification => SettingName::TmuxNotification,
+            Self::NotificationCommand => SettingName::NotificationCommand,
+            Self::Theme => SettingName::Theme,
+            Self::SplitLayout => SettingName::SplitLayout,
         }
     }
 }
@@ -132,6 +187,12 @@ pub struct SettingsManager {
     pub default_smart_model: AgentModel,
     /// Optional command run in tmux when opening a session worktree.
     pub open_command: String,
+    /// Project instructions injected into every agent bootstrap for the
+    /// active project.
+    pub project_instructions: String,
+    /// JSON MCP server list passed to every provider launch for the active
+    /// project.
+    pub mcp_servers: String,
     /// Default reasoning effort preference for models that support this
     /// setting.
     ///
@@ -147,9 +208,17 @@ pub struct SettingsManager {
     /// New projects start with this disabled until the user explicitly enables
     /// it.
     include_coauthored_by_agentty: bool,
-    open_command_input: Option<InputState>,
+    /// Session notification preferences for the active project.
+    notification: NotificationSettings,
     /// Active project identifier that owns these persisted settings.
     project_id: i64,
+    /// Whether wide terminals render the session list beside the selected
+    /// session, shared by every project.
+    split_layout: bool,
+    /// Editor state for the text row named by `editing_text_row`.
+    text_input: Option<InputState>,
+    /// Color theme selection shared by every project.
+    theme_mode: ThemeMode,
     use_last_used_model_as_default: bool,
 }
 
@@ -181,6 +250,18 @@ impl SettingsManager {
             .await
             .unwrap_or(None)
             .unwrap_or_default();
+        let project_instructions = services
+            .db()
+            .get_project_setting(project_id, SettingName::ProjectInstructions)
+            .await
+            .unwrap_or(None)
+            .unwrap_or_default();
+        let mcp_servers = services
+            .db()
+            .get_project_setting(project_id, SettingName::McpServers)
+            .await
+            .unwrap_or(None)
+            .unwrap_or_default();
 
         let include_coauthored_by_agentty = load_project_bool_setting(
             services,
@@ -196,6 +277,9 @@ impl SettingsManager {
             false,
         )
         .await;
+        let notification = load_notification_settings(services, project_id).await;
+        let theme_mode = load_theme_mode_setting(services).await;
+        let split_layout = load_split_layout_setting(services).await;
 
         let mut table_state = TableState::default();
         table_state.select(Some(0));
@@ -205,13 +289,18 @@ impl SettingsManager {
             default_review_model,
             default_smart_model,
             open_command,
+            project_instructions,
+            mcp_servers,
             reasoning_level,
             table_state,
             available_agent_kinds,
             editing_text_row: None,
             include_coauthored_by_agentty,
-            open_command_input: None,
+            notification,
             project_id,
+            split_layout,
+            text_input: None,
+            theme_mode,
             use_last_used_model_as_default,
         }
     }
@@ -264,6 +353,15 @@ impl SettingsManager {
         self.is_editing_text_input_for(SettingRow::OpenCommand)
     }
 
+    /// Returns whether the active text editor accepts newlines, as the
+    /// `Open Commands`, `Project Instructions`, and `MCP Servers` editors do.
+    #[must_use]
+    pub fn is_editing_multiline_text_input(&self) -> bool {
+        self.is_editing_open_commands()
+            || self.is_editing_text_input_for(SettingRow::ProjectInstructions)
+            || self.is_editing_text_input_for(SettingRow::McpServers)
+    }
+
     /// Exits settings text input editing mode and clears editor cursor state.
     pub fn stop_text_input_editing(&mut self) {
         self.finish_text_input_editing();
@@ -328,6 +426,11 @@ impl SettingsManager {
         if self.is_editing_text_input_for(SettingRow::OpenCommand) {
             "Editing open commands: one command per line, Alt+Enter/Shift+Enter inserts newline, \
              Enter/Esc finish"
+        } else if self.is_editing_text_input_for(SettingRow::ProjectInstructions) {
+            "Editing project instructions: Alt+Enter/Shift+Enter inserts newline, Enter/Esc finish"
+        } else if self.is_editing_text_input_for(SettingRow::McpServers) {
+            "Editing MCP servers: JSON object keyed by server name, Alt+Enter/Shift+Enter inserts \
+             newline, Enter/Esc finish"
         } else if self.is_editing_text_input() {
             "Editing setting value: type text, Enter to finish, Esc to cancel"
         } else {
@@ -343,6 +446,23 @@ impl SettingsManager {
         parse_open_commands(self.open_command.as_str())
     }
 
+    /// Returns the persisted color theme selection.
+    #[must_use]
+    pub fn theme_mode(&self) -> ThemeMode {
+        self.theme_mode
+    }
+
+    /// Returns whether the split list-and-session layout is enabled.
+    #[must_use]
+    pub fn split_layout(&self) -> bool {
+        self.split_layout
+    }
+
+    /// Returns the session notification preferences for the active project.
+    pub(crate) fn notification_settings(&self) -> &NotificationSettings {
+        &self.notification
+    }
+
     /// Returns the currently selected row index.
     fn selected_row_index(&self) -> usize {
         self.table_state
@@ -375,19 +495,39 @@ impl SettingsManager {
     /// Starts text editing mode for the requested row.
     fn start_text_input_editing(&mut self, row: SettingRow) {
         self.editing_text_row = Some(row);
-        if row == SettingRow::OpenCommand {
-            self.open_command_input = Some(InputState::with_text(self.open_command.clone()));
-        }
+        self.text_input = self
+            .text_value(row)
+            .map(|text_value| InputState::with_text(text_value.to_string()));
     }
 
     /// Finalizes the active text editing session and synchronizes cached text.
     fn finish_text_input_editing(&mut self) {
-        if self.is_editing_text_input_for(SettingRow::OpenCommand) {
-            self.sync_open_command_from_input();
-            self.open_command_input = None;
+        self.sync_text_value_from_input();
+        self.text_input = None;
+        self.editing_text_row = None;
+    }
+
+    /// Returns the cached text value backing one text-input row.
+    fn text_value(&self, row: SettingRow) -> Option<&str> {
+        match row {
+            SettingRow::OpenCommand => Some(&self.open_command),
+            SettingRow::ProjectInstructions => Some(&self.project_instructions),
+            SettingRow::McpServers => Some(&self.mcp_servers),
+            SettingRow::NotificationCommand => Some(&self.notification.command),
+            _ => None,
         }
+    }
 
-        self.editing_text_row = None;
+    /// Returns mutable access to the cached text value backing one text-input
+    /// row.
+    fn text_value_mut(&mut self, row: SettingRow) -> Option<&mut String> {
+        match row {
+            SettingRow::OpenCommand => Some(&mut self.open_command),
+            SettingRow::ProjectInstructions => Some(&mut self.project_instructions),
+            SettingRow::McpServers => Some(&mut self.mcp_servers),
+            SettingRow::NotificationCommand => Some(&mut self.notification.command),
+            _ => None,
+        }
     }
 
     /// Appends text to the selected setting row.
@@ -395,9 +535,9 @@ impl SettingsManager {
         match row.control() {
             SettingControl::Selector => false,
             SettingControl::TextInput => {
-                let open_command_input = self.open_command_input_mut();
-                open_command_input.insert_char(character);
-                self.sync_open_command_from_input();
+                let text_input = self.text_input_mut(row);
+                text_input.insert_char(character);
+                self.sync_text_value_from_input();
 
                 true
             }
@@ -409,13 +549,13 @@ impl SettingsManager {
         match row.control() {
             SettingControl::Selector => false,
             SettingControl::TextInput => {
-                let open_command_input = self.open_command_input_mut();
-                let previous_text = open_command_input.text().to_string();
-                open_command_input.delete_backward();
+                let text_input = self.text_input_mut(row);
+                let previous_text = text_input.text().to_string();
+                text_input.delete_backward();
 
-                let is_changed = open_command_input.text() != previous_text;
+                let is_changed = text_input.text() != previous_text;
                 if is_changed {
-                    self.sync_open_command_from_input();
+                    self.sync_text_value_from_input();
                 }
 
                 is_changed
@@ -436,26 +576,38 @@ impl SettingsManager {
             return;
         }
 
-        let open_command_input = self.open_command_input_mut();
+        let text_input = self.text_input_mut(row);
         match direction {
-            TextCursorDirection::Down => open_command_input.move_down(),
-            TextCursorDirection::Left => open_command_input.move_left(),
-            TextCursorDirection::Right => open_command_input.move_right(),
-            TextCursorDirection::Up => open_command_input.move_up(),
+            TextCursorDirection::Down => text_input.move_down(),
+            TextCursorDirection::Left => text_input.move_left(),
+            TextCursorDirection::Right => text_input.move_right(),
+            TextCursorDirection::Up => text_input.move_up(),
         }
     }
 
-    /// Returns mutable access to the `Open Commands` editor state.
-    fn open_command_input_mut(&mut self) -> &mut InputState {
-        let open_command_value = self.open_command.clone();
-        self.open_command_input
-            .get_or_insert_with(|| InputState::with_text(open_command_value))
+    /// Returns mutable access to the text editor state for `row`.
+    fn text_input_mut(&mut self, row: SettingRow) -> &mut InputState {
+        let text_value = self.text_value(row).unwrap_or_default().to_string();
+        self.text_input
+            .get_or_insert_with(|| InputState::with_text(text_value))
     }
 
-    /// Synchronizes the persisted `open_command` value from editor state.
-    fn sync_open_command_from_input(&mut self) {
-        if let Some(open_command_input) = &self.open_command_input {
-            self.open_command = open_command_input.text().to_string();
+    /// Synchronizes the cached value of the edited text row from editor
+    /// state.
+    fn sync_text_value_from_input(&mut self) {
+        let Some(editing_row) = self.editing_text_row else {
+            return;
+        };
+        let Some(text) = self
+            .text_input
+            .as_ref()
+            .map(|input| input.text().to_string())
+        else {
+            return;
+        };
+
+        if let Some(text_value) = self.text_value_mut(editing_row) {
+            *text_value = text;
         }
     }
 
@@ -475,23 +627,60 @@ impl SettingsManager {
             SettingRow::IncludeCoauthoredByAgentty => {
                 bool_setting_display(self.include_coauthored_by_agentty)
             }
-            SettingRow::OpenCommand => {
-                if self.is_editing_text_input_for(row) {
-                    display_open_command_with_cursor(&self.open_command, self.open_command_cursor())
-                } else if self.open_command.is_empty() {
-                    "<empty>".to_string()
-                } else {
-                    self.open_command.clone()
-                }
+            SettingRow::OpenCommand
+            | SettingRow::ProjectInstructions
+            | SettingRow::NotificationCommand => self.display_text_value_for_row(row),
+            SettingRow::McpServers => self.display_mcp_servers_value(),
+            SettingRow::NotifyOnReview => {
+                bool_setting_display(self.notification.transitions.review)
+            }
+            SettingRow::NotifyOnQuestion => {
+                bool_setting_display(self.notification.transitions.question)
             }
+            SettingRow::NotifyOnDone => bool_setting_display(self.notification.transitions.done),
+            SettingRow::TerminalNotification => self.notification.terminal.label().to_string(),
+            SettingRow::TmuxNotification => bool_setting_display(self.notification.tmux),
+            SettingRow::Theme => self.theme_mode.label().to_string(),
+            SettingRow::SplitLayout => bool_setting_display(self.split_layout),
+        }
+    }
+
+    /// Returns the text displayed for one text-input row, including the
+    /// cursor marker while it is edited.
+    fn display_text_value_for_row(&self, row: SettingRow) -> String {
+        let text_value = self.text_value(row).unwrap_or_default();
+
+        if self.is_editing_text_input_for(row) {
+            display_open_command_with_cursor(text_value, self.text_cursor(text_value))
+        } else if text_value.is_empty() {
+            "<empty>".to_string()
+        } else {
+            text_value.to_string()
         }
     }
 
-    /// Returns the active `Open Commands` editor cursor position.
-    fn open_command_cursor(&self) -> usize {
-        self.open_command_input
+    /// Returns the `MCP Servers` row value: the raw JSON while it is edited,
+    /// and otherwise the configured server names or the parse error.
+    fn display_mcp_servers_value(&self) -> String {
+        if self.is_editing_text_input_for(SettingRow::McpServers) || self.mcp_servers.is_empty() {
+            return self.display_text_value_for_row(SettingRow::McpServers);
+        }
+
+        match parse_mcp_servers(&self.mcp_servers) {
+            Ok(servers) => servers
+                .iter()
+                .map(|server| server.name.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            Err(error) => format!("Invalid: {error}"),
+        }
+    }
+
+    /// Returns the active text editor cursor position.
+    fn text_cursor(&self, text_value: &str) -> usize {
+        self.text_input
             .as_ref()
-            .map_or_else(|| self.open_command.chars().count(), |input| input.cursor)
+            .map_or_else(|| text_value.chars().count(), |input| input.cursor)
     }
 
     /// Cycles selector-type rows and persists their updated values.
@@ -517,8 +706,25 @@ impl SettingsManager {
                 self.toggle_include_coauthored_by_agentty_selector(services)
                     .await;
             }
+            SettingName::NotifyOnReview
+            | SettingName::NotifyOnQuestion
+            | SettingName::NotifyOnDone
+            | SettingName::TerminalNotification
+            | SettingName::TmuxNotification => {
+                self.cycle_notification_selector(services, row.setting_name())
+                    .await;
+            }
+            SettingName::Theme => {
+                self.cycle_theme_selector(services).await;
+            }
+            SettingName::SplitLayout => {
+                self.toggle_split_layout_selector(services).await;
+            }
             SettingName::ActiveProjectId
             | SettingName::OpenCommand
+            | SettingName::ProjectInstructions
+            | SettingName::McpServers
+            | SettingName::NotificationCommand
             | SettingName::LastUsedModelAsDefault => {}
         }
     }
@@ -529,26 +735,82 @@ impl SettingsManager {
             return;
         }
 
-        match row.setting_name() {
-            SettingName::OpenCommand => {
-                // Best-effort: settings persistence failure is non-critical.
-                let _ = services
-                    .db()
-                    .upsert_project_setting(
-                        self.project_id,
-                        SettingName::OpenCommand,
-                        &self.open_command,
-                    )
-                    .await;
+        let Some(text_value) = self.text_value(row) else {
+            return;
+        };
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = services
+            .db()
+            .upsert_project_setting(self.project_id, row.setting_name(), text_value)
+            .await;
+    }
+
+    /// Toggles or cycles one notification selector row and persists the new
+    /// value.
+    async fn cycle_notification_selector(
+        &mut self,
+        services: &AppServices,
+        setting_name: SettingName,
+    ) {
+        let setting_value = match setting_name {
+            SettingName::NotifyOnReview => {
+                self.notification.transitions.review = !self.notification.transitions.review;
+
+                self.notification.transitions.review.to_string()
             }
-            SettingName::ReasoningLevel
-            | SettingName::ActiveProjectId
-            | SettingName::DefaultFastModel
-            | SettingName::DefaultReviewModel
-            | SettingName::DefaultSmartModel
-            | SettingName::IncludeCoauthoredByAgentty
-            | SettingName::LastUsedModelAsDefault => {}
-        }
+            SettingName::NotifyOnQuestion => {
+                self.notification.transitions.question = !self.notification.transitions.question;
+
+                self.notification.transitions.question.to_string()
+            }
+            SettingName::NotifyOnDone => {
+                self.notification.transitions.done = !self.notification.transitions.done;
+
+                self.notification.transitions.done.to_string()
+            }
+            SettingName::TerminalNotification => {
+                self.notification.terminal = self.notification.terminal.next();
+
+                self.notification.terminal.as_str().to_string()
+            }
+            SettingName::TmuxNotification => {
+                self.notification.tmux = !self.notification.tmux;
+
+                self.notification.tmux.to_string()
+            }
+            _ => return,
+        };
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = services
+            .db()
+            .upsert_project_setting(self.project_id, setting_name, &setting_value)
+            .await;
+    }
+
+    /// Cycles the theme selector, applies the new palette immediately, and
+    /// persists the choice globally.
+    async fn cycle_theme_selector(&mut self, services: &AppServices) {
+        self.theme_mode = self.theme_mode.next();
+        theme::apply(self.theme_mode);
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = services
+            .db()
+            .upsert_setting(SettingName::Theme, self.theme_mode.as_str())
+            .await;
+    }
+
+    /// Toggles the split layout and persists the choice globally.
+    async fn toggle_split_layout_selector(&mut self, services: &AppServices) {
+        self.split_layout = !self.split_layout;
+
+        // Best-effort: settings persistence failure is non-critical.
+        let _ = services
+            .db()
+            .upsert_setting(SettingName::SplitLayout, &self.split_layout.to_string())
+            .await;
     }
 
     /// Cycles the reasoning-level selector through all supported values.
@@ -742,6 +1004,64 @@ async fn load_project_bool_setting(
         .unwrap_or(default_value)
 }
 
+/// Loads the project-scoped session notification preferences.
+///
+/// Review and question transitions notify by default, while every delivery
+/// channel starts disabled until the user picks one.
+async fn load_notification_settings(
+    services: &AppServices,
+    project_id: i64,
+) -> NotificationSettings {
+    let notify_on_review = load_project_bool_setting(
+        services,
+        Some(project_id),
+        SettingName::NotifyOnReview,
+        true,
+    )
+    .await;
+    let notify_on_question = load_project_bool_setting(
+        services,
+        Some(project_id),
+        SettingName::NotifyOnQuestion,
+        true,
+    )
+    .await;
+    let notify_on_done =
+        load_project_bool_setting(services, Some(project_id), SettingName::NotifyOnDone, false)
+            .await;
+    let tmux = load_project_bool_setting(
+        services,
+        Some(project_id),
+        SettingName::TmuxNotification,
+        false,
+    )
+    .await;
+    let terminal = services
+        .db()
+        .get_project_setting(project_id, SettingName::TerminalNotification)
+        .await
+        .unwrap_or(None)
+        .and_then(|setting_value| TerminalNotification::from_persisted(&setting_value))
+        .unwrap_or_default();
+    let command = services
+        .db()
+        .get_project_setting(project_id, SettingName::NotificationCommand)
+        .await
+        .unwrap_or(None)
+        .unwrap_or_default();
+
+    NotificationSettings {
+        command,
+        terminal,
+        tmux,
+        transitions: NotificationTransitions {
+            done: notify_on_done,
+            question: notify_on_question,
+            review: notify_on_review,
+        },
+    }
+}
+
 /// Returns the human-readable value shown for one boolean selector row.
 fn bool_setting_display(setting_value: bool) -> String {
     if setting_value {
@@ -848,6 +1168,32 @@ async fn load_reasoning_level_setting(
         .unwrap_or_default()
 }
 
+/// Loads the global theme selection, defaulting to automatic detection for
+/// missing or unknown values.
+async fn load_theme_mode_setting(services: &AppServices) -> ThemeMode {
+    services
+        .db()
+        .get_setting(SettingName::Theme)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Loads the global split-layout flag, defaulting to the single-pane layout
+/// for missing or invalid values.
+async fn load_split_layout_setting(services: &AppServices) -> bool {
+    services
+        .db()
+        .get_setting(SettingName::SplitLayout)
+        .await
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(false)
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
@@ -902,13 +1248,18 @@ mod tests {
             default_review_model: AgentKind::Gemini.default_model(),
             default_smart_model: AgentKind::Gemini.default_model(),
             open_command: String::new(),
+            project_instructions: String::new(),
+            mcp_servers: String::new(),
             reasoning_level: ReasoningLevel::High,
             table_state,
             available_agent_kinds: AgentKind::ALL.to_vec(),
             editing_text_row: None,
             include_coauthored_by_agentty: false,
-            open_command_input: None,
+            notification: NotificationSettings::default(),
             project_id: 1,
+            split_layout: false,
+            text_input: None,
+            theme_mode: ThemeMode::Auto,
             use_last_used_model_as_default: false,
         }
     }
@@ -1176,6 +1527,25 @@ mod tests {
         assert!(!manager.use_last_used_model_as_default);
     }
 
+    #[tokio::test]
+    async fn settings_manager_new_loads_global_theme_mode() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        services
+            .db()
+            .upsert_setting(SettingName::Theme, "high-contrast")
+            .await
+            .expect("failed to persist theme");
+
+        // Act
+        let manager = SettingsManager::new(&services, project_id).await;
+        let rows = manager.settings_rows();
+
+        // Assert
+        assert_eq!(manager.theme_mode(), ThemeMode::HighContrast);
+        assert_eq!(rows[14].1, "High Contrast");
+    }
+
     #[tokio::test]
     async fn settings_manager_new_defaults_invalid_coauthor_flag_to_false() {
         // Arrange
@@ -1210,7 +1580,7 @@ mod tests {
     }
 
     #[test]
-    fn previous_wraps_to_open_command_row_from_reasoning_level_row() {
+    fn previous_wraps_to_split_layout_row_from_reasoning_level_row() {
         // Arrange
         let mut manager = new_settings_manager();
 
@@ -1218,7 +1588,7 @@ mod tests {
         manager.previous();
 
         // Assert
-        assert_eq!(manager.table_state.selected(), Some(5));
+        assert_eq!(manager.table_state.selected(), Some(15));
     }
 
     #[test]
@@ -1234,7 +1604,7 @@ mod tests {
     }
 
     #[test]
-    fn settings_rows_include_reasoning_model_coauthor_and_open_command_options() {
+    fn settings_rows_include_model_coauthor_open_command_notification_theme_and_layout_options() {
         // Arrange
         let manager = new_settings_manager();
 
@@ -1242,13 +1612,20 @@ mod tests {
         let rows = manager.settings_rows();
 
         // Assert
-        assert_eq!(rows.len(), 6);
+        assert_eq!(rows.len(), 16);
         assert_eq!(rows[0].0, "Default Reasoning Level");
         assert_eq!(rows[1].0, "Default Smart Model");
         assert_eq!(rows[2].0, "Default Fast Model");
         assert_eq!(rows[3].0, "Default Review Model");
         assert_eq!(rows[4].0, "Coauthored by Agentty");
         assert_eq!(rows[5].0, "Open Commands");
+        assert_eq!(rows[6].0, "Project Instructions");
+        assert_eq!(rows[7].0, "MCP Servers");
+        assert_eq!(rows[8].0, "Notify On Review");
+        assert_eq!(rows[11].0, "Terminal Notification");
+        assert_eq!(rows[13].0, "Notification Command");
+        assert_eq!(rows[14].0, "Theme");
+        assert_eq!(rows[15].0, "Split Layout");
     }
 
     #[test]
@@ -1342,7 +1719,7 @@ mod tests {
         let mut manager = new_settings_manager();
         manager.open_command = "abc".to_string();
         manager.editing_text_row = Some(SettingRow::OpenCommand);
-        manager.open_command_input = Some(InputState::with_text(manager.open_command.clone()));
+        manager.text_input = Some(InputState::with_text(manager.open_command.clone()));
         manager.move_selected_text_cursor_left();
 
         // Act
@@ -1430,14 +1807,14 @@ mod tests {
 
         // Assert
         assert!(manager.is_editing_open_commands());
-        assert!(manager.open_command_input.is_some());
+        assert!(manager.text_input.is_some());
 
         // Act
         manager.handle_enter(&services).await;
 
         // Assert
         assert!(!manager.is_editing_open_commands());
-        assert!(manager.open_command_input.is_none());
+        assert!(manager.text_input.is_none());
     }
 
     #[tokio::test]
@@ -1462,7 +1839,7 @@ mod tests {
         let mut manager = new_settings_manager();
         manager.open_command = "old command".to_string();
         manager.editing_text_row = Some(SettingRow::OpenCommand);
-        manager.open_command_input = Some(InputState::with_text("new command".to_string()));
+        manager.text_input = Some(InputState::with_text("new command".to_string()));
 
         // Act
         manager.stop_text_input_editing();
@@ -1470,7 +1847,7 @@ mod tests {
         // Assert
         assert_eq!(manager.open_command, "new command");
         assert!(manager.editing_text_row.is_none());
-        assert!(manager.open_command_input.is_none());
+        assert!(manager.text_input.is_none());
     }
 
     #[tokio::test]
@@ -1498,6 +1875,56 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn project_instructions_editor_accepts_newlines_and_persists_per_project() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = SettingsManager::new(&services, project_id).await;
+        select_row(&mut manager, 6);
+        manager.handle_enter(&services).await;
+
+        // Act
+        manager.append_selected_text_character(&services, 'a').await;
+        manager
+            .append_selected_text_character(&services, '\n')
+            .await;
+        manager.append_selected_text_character(&services, 'b').await;
+        let is_multiline = manager.is_editing_multiline_text_input();
+        manager.stop_text_input_editing();
+        let reloaded_manager = SettingsManager::new(&services, project_id).await;
+
+        // Assert
+        assert!(is_multiline);
+        assert_eq!(manager.project_instructions, "a\nb");
+        assert_eq!(reloaded_manager.project_instructions, "a\nb");
+        assert!(reloaded_manager.open_command.is_empty());
+    }
+
+    #[tokio::test]
+    async fn mcp_servers_row_shows_server_names_or_parse_error() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        services
+            .db()
+            .upsert_project_setting(
+                project_id,
+                SettingName::McpServers,
+                r#"{"github": {"command": "gh-mcp"}, "docs": {"command": "docs-mcp"}}"#,
+            )
+            .await
+            .expect("failed to persist MCP servers");
+        let mut manager = SettingsManager::new(&services, project_id).await;
+
+        // Act
+        let valid_value = manager.settings_rows()[7].1.clone();
+        manager.mcp_servers = r#"{"docs": {}}"#.to_string();
+        let invalid_value = manager.settings_rows()[7].1.clone();
+
+        // Assert
+        assert_eq!(valid_value, "docs, github");
+        assert_eq!(invalid_value, "Invalid: MCP server `docs` has no command");
+    }
+
     #[tokio::test]
     async fn handle_enter_toggles_coauthor_setting_and_persists_value() {
         // Arrange
@@ -1520,6 +1947,96 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn handle_enter_toggles_split_layout_and_persists_value_globally() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = SettingsManager::new(&services, project_id).await;
+        let was_split_layout = manager.split_layout();
+        select_row(&mut manager, 15);
+
+        // Act
+        manager.handle_enter(&services).await;
+        let reloaded_manager = SettingsManager::new(&services, project_id).await;
+
+        // Assert
+        assert!(!was_split_layout);
+        assert!(manager.split_layout());
+        assert!(reloaded_manager.split_layout());
+        assert_eq!(
+            services
+                .db()
+                .get_setting(SettingName::SplitLayout)
+                .await
+                .expect("failed to load split layout setting"),
+            Some("true".to_string())
+        );
+    }
+
+    #[tokio::test]
+    async fn settings_manager_new_defaults_notifications_to_review_and_question_without_channel() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+
+        // Act
+        let manager = SettingsManager::new(&services, project_id).await;
+
+        // Assert
+        let notification_settings = manager.notification_settings();
+        assert!(notification_settings.transitions.review);
+        assert!(notification_settings.transitions.question);
+        assert!(!notification_settings.transitions.done);
+        assert!(!notification_settings.tmux);
+        assert_eq!(notification_settings.terminal, TerminalNotification::Off);
+        assert!(notification_settings.command.is_empty());
+    }
+
+    #[tokio::test]
+    async fn handle_enter_cycles_terminal_notification_and_persists_value() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = SettingsManager::new(&services, project_id).await;
+        select_row(&mut manager, 11);
+
+        // Act
+        manager.handle_enter(&services).await;
+        manager.handle_enter(&services).await;
+        let reloaded_manager = SettingsManager::new(&services, project_id).await;
+
+        // Assert
+        assert_eq!(manager.notification.terminal, TerminalNotification::Osc9);
+        assert_eq!(
+            reloaded_manager.notification.terminal,
+            TerminalNotification::Osc9
+        );
+        assert_eq!(manager.settings_rows()[11].1, "OSC 9");
+    }
+
+    #[tokio::test]
+    async fn append_selected_text_character_persists_notification_command() {
+        // Arrange
+        let (services, project_id) = test_services().await;
+        let mut manager = SettingsManager::new(&services, project_id).await;
+        select_row(&mut manager, 13);
+        manager.handle_enter(&services).await;
+
+        // Act
+        manager.append_selected_text_character(&services, 'x').await;
+        manager.stop_text_input_editing();
+
+        // Assert
+        assert_eq!(manager.notification.command, "x");
+        assert!(manager.open_command.is_empty());
+        assert_eq!(
+            services
+                .db()
+                .get_project_setting(project_id, SettingName::NotificationCommand)
+                .await
+                .expect("failed to load notification command"),
+            Some("x".to_string())
+        );
+    }
+
     #[tokio::test]
     async fn text_editing_apis_are_noops_without_active_text_row() {
         // Arrange
@@ -12,6 +12,7 @@ pub enum TabScope {
 pub enum Tab {
     Projects,
     Sessions,
+    Dashboard,
     Tasks,
     Stats,
     Settings,
@@ -20,23 +21,34 @@ pub enum Tab {
 impl Tab {
     /// Tabs in the order they are rendered when the active project does not
     /// expose a roadmap-backed tasks page.
-    pub const ALL_WITHOUT_TASKS: [Self; 4] =
-        [Self::Projects, Self::Sessions, Self::Stats, Self::Settings];
+    pub const ALL_WITHOUT_TASKS: [Self; 5] = [
+        Self::Projects,
+        Self::Sessions,
+        Self::Dashboard,
+        Self::Stats,
+        Self::Settings,
+    ];
     /// Tabs in the order they are rendered when the active project exposes a
     /// roadmap-backed tasks page.
-    pub const ALL_WITH_TASKS: [Self; 5] = [
+    pub const ALL_WITH_TASKS: [Self; 6] = [
         Self::Projects,
         Self::Sessions,
+        Self::Dashboard,
         Self::Tasks,
         Self::Stats,
         Self::Settings,
     ];
     /// Project-scoped tabs in display order when the tasks page is hidden.
-    pub const PROJECT_SCOPED_WITHOUT_TASKS: [Self; 3] =
-        [Self::Sessions, Self::Stats, Self::Settings];
+    pub const PROJECT_SCOPED_WITHOUT_TASKS: [Self; 4] =
+        [Self::Sessions, Self::Dashboard, Self::Stats, Self::Settings];
     /// Project-scoped tabs in display order when the tasks page is available.
-    pub const PROJECT_SCOPED_WITH_TASKS: [Self; 4] =
-        [Self::Sessions, Self::Tasks, Self::Stats, Self::Settings];
+    pub const PROJECT_SCOPED_WITH_TASKS: [Self; 5] = [
+        Self::Sessions,
+        Self::Dashboard,
+        Self::Tasks,
+        Self::Stats,
+        Self::Settings,
+    ];
 
     /// Returns the tabs available for the current project context.
     pub fn available_tabs(has_tasks_tab: bool) -> &'static [Self] {
@@ -62,6 +74,7 @@ impl Tab {
         match self {
             Tab::Projects => "Projects",
             Tab::Sessions => "Sessions",
+            Tab::Dashboard => "Dashboard",
             Tab::Tasks => "Tasks",
             Tab::Stats => "Stats",
             Tab::Settings => "Settings",
@@ -73,7 +86,9 @@ impl Tab {
     pub fn scope(self) -> TabScope {
         match self {
             Tab::Projects => TabScope::Global,
-            Tab::Sessions | Tab::Tasks | Tab::Stats | Tab::Settings => TabScope::Project,
+            Tab::Sessions | Tab::Dashboard | Tab::Tasks | Tab::Stats | Tab::Settings => {
+                TabScope::Project
+            }
         }
     }
 
@@ -174,7 +189,14 @@ mod tests {
         // Assert
         assert_eq!(
             titles,
-            ["Projects", "Sessions", "Tasks", "Stats", "Settings"]
+            [
+                "Projects",
+                "Sessions",
+                "Dashboard",
+                "Tasks",
+                "Stats",
+                "Settings"
+            ]
         );
     }
 
@@ -193,6 +215,7 @@ mod tests {
                 TabScope::Project,
                 TabScope::Project,
                 TabScope::Project,
+                TabScope::Project,
                 TabScope::Project
             ]
         );
@@ -210,6 +233,7 @@ mod tests {
             next_tabs,
             [
                 Tab::Sessions,
+                Tab::Dashboard,
                 Tab::Tasks,
                 Tab::Stats,
                 Tab::Settings,
@@ -228,7 +252,13 @@ mod tests {
         // Assert
         assert_eq!(
             next_tabs,
-            [Tab::Sessions, Tab::Stats, Tab::Settings, Tab::Projects]
+            [
+                Tab::Sessions,
+                Tab::Dashboard,
+                Tab::Stats,
+                Tab::Settings,
+                Tab::Projects
+            ]
         );
     }
 
@@ -246,6 +276,7 @@ mod tests {
                 Tab::Settings,
                 Tab::Projects,
                 Tab::Sessions,
+                Tab::Dashboard,
                 Tab::Tasks,
                 Tab::Stats
             ]
@@ -262,7 +293,13 @@ mod tests {
         // Assert
         assert_eq!(
             previous_tabs,
-            [Tab::Settings, Tab::Projects, Tab::Sessions, Tab::Stats]
+            [
+                Tab::Settings,
+                Tab::Projects,
+                Tab::Sessions,
+                Tab::Dashboard,
+                Tab::Stats
+            ]
         );
     }
 
@@ -276,7 +313,13 @@ mod tests {
         // Assert
         assert_eq!(
             project_scoped_tabs,
-            &[Tab::Sessions, Tab::Tasks, Tab::Stats, Tab::Settings]
+            &[
+                Tab::Sessions,
+                Tab::Dashboard,
+                Tab::Tasks,
+                Tab::Stats,
+                Tab::Settings
+            ]
         );
     }
 
@@ -290,7 +333,7 @@ mod tests {
         // Assert
         assert_eq!(
             project_scoped_tabs,
-            &[Tab::Sessions, Tab::Stats, Tab::Settings]
+            &[Tab::Sessions, Tab::Dashboard, Tab::Stats, Tab::Settings]
         );
     }
 
@@ -323,6 +366,8 @@ mod tests {
         observed_tabs.push(manager.current());
         manager.next(true);
         observed_tabs.push(manager.current());
+        manager.next(true);
+        observed_tabs.push(manager.current());
 
         // Assert
         assert_eq!(
@@ -330,6 +375,7 @@ mod tests {
             vec![
                 Tab::Projects,
                 Tab::Sessions,
+                Tab::Dashboard,
                 Tab::Tasks,
                 Tab::Stats,
                 Tab::Settings,
@@ -354,6 +400,8 @@ mod tests {
         observed_tabs.push(manager.current());
         manager.previous(false);
         observed_tabs.push(manager.current());
+        manager.previous(false);
+        observed_tabs.push(manager.current());
 
         // Assert
         assert_eq!(
@@ -362,6 +410,7 @@ mod tests {
                 Tab::Projects,
                 Tab::Settings,
                 Tab::Stats,
+                Tab::Dashboard,
                 Tab::Sessions,
                 Tab::Projects
             ]
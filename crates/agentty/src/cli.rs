@@ -0,0 +1,540 @@
+//! Headless command-line surface for scripting sessions without the terminal
+//! UI.
+//!
+//! `agentty` without a subcommand launches the terminal UI. The `session`
+//! subcommands reuse [`App`] for session workflows so shell scripts, git hooks,
+//! and cron jobs can start, inspect, continue, merge, and wait for sessions.
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand};
+use serde::Serialize;
+
+use crate::app::{App, AppError};
+use crate::domain::agent::AgentModel;
+use crate::domain::session::Status;
+use crate::infra::channel::TurnPrompt;
+use crate::infra::db::{AppRepositories, Database, SessionRow};
+use crate::infra::git::{GitClient, RealGitClient};
+
+/// Delay between persisted status polls while `session wait` runs.
+const SESSION_WAIT_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Command-line entry point for `agentty`.
+#[derive(Debug, Parser)]
+#[command(name = "agentty", version, about)]
+pub struct Cli {
+    /// Disables the background auto-update for this terminal UI run.
+    #[arg(long, global = true)]
+    pub no_update: bool,
+    /// Disables mouse capture so the terminal keeps native text selection.
+    #[arg(long)]
+    pub no_mouse: bool,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+/// Top-level headless subcommands.
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Manages sessions without launching the terminal UI.
+    Session {
+        #[command(subcommand)]
+        command: SessionCommand,
+    },
+}
+
+/// Headless session subcommands.
+#[derive(Debug, Subcommand)]
+pub enum SessionCommand {
+    /// Creates a session, runs its first turn, and prints the session id.
+    New {
+        /// Project directory for the session; defaults to the current
+        /// directory.
+        #[arg(long)]
+        project: Option<PathBuf>,
+        /// Model used for the session, such as `gpt-5.4`.
+        #[arg(long, value_parser = AgentModel::from_str)]
+        model: Option<AgentModel>,
+        /// Prompt for the first turn.
+        prompt: String,
+    },
+    /// Lists sessions, optionally limited to one project.
+    List {
+        /// Project directory whose sessions are listed.
+        #[arg(long)]
+        project: Option<PathBuf>,
+        /// Prints sessions as a JSON array.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Sends a follow-up prompt, waits for the turn, and prints the status.
+    Reply {
+        /// Session id or unique id prefix.
+        session_id: String,
+        /// Follow-up prompt.
+        prompt: String,
+    },
+    /// Merges a reviewed session into its base branch.
+    Merge {
+        /// Session id or unique id prefix.
+        session_id: String,
+    },
+    /// Waits until a session stops running and prints its status.
+    Wait {
+        /// Session id or unique id prefix.
+        session_id: String,
+        /// Maximum number of seconds to wait.
+        #[arg(long)]
+        timeout: Option<u64>,
+    },
+}
+
+/// Shared startup inputs for headless session commands.
+pub struct HeadlessContext {
+    /// Root directory for session worktrees.
+    pub base_path: PathBuf,
+    /// Open application database.
+    pub db: Database,
+    /// Directory the command was invoked from.
+    pub working_dir: PathBuf,
+}
+
+/// One session entry printed by `agentty session list`.
+#[derive(Debug, Eq, PartialEq, Serialize)]
+struct SessionListEntry {
+    created_at: i64,
+    id: String,
+    issue: Option<String>,
+    model: String,
+    project: Option<String>,
+    review_request: Option<String>,
+    status: String,
+    title: Option<String>,
+    updated_at: i64,
+}
+
+/// Runs one headless session command and writes its output.
+///
+/// # Errors
+/// Returns an error if the session cannot be resolved, the workflow fails,
+/// a wait times out, or output cannot be written.
+pub async fn run_session_command(
+    command: SessionCommand,
+    context: HeadlessContext,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    match command {
+        SessionCommand::New {
+            project,
+            model,
+            prompt,
+        } => {
+            let project_dir = project.unwrap_or_else(|| context.working_dir.clone());
+
+            new_session(context, &project_dir, model, prompt, output).await
+        }
+        SessionCommand::List { project, json } => {
+            list_sessions(&context.db, project.as_deref(), json, output).await
+        }
+        SessionCommand::Reply { session_id, prompt } => {
+            reply_to_session(context, &session_id, prompt, output).await
+        }
+        SessionCommand::Merge { session_id } => merge_session(context, &session_id, output).await,
+        SessionCommand::Wait {
+            session_id,
+            timeout,
+        } => {
+            let status =
+                wait_for_session(&context.db, &session_id, timeout.map(Duration::from_secs))
+                    .await?;
+
+            write_line(output, &status.to_string())
+        }
+    }
+}
+
+/// Creates one session in `project_dir`, prints its id, and runs the first
+/// turn to completion.
+async fn new_session(
+    context: HeadlessContext,
+    project_dir: &Path,
+    model: Option<AgentModel>,
+    prompt: String,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    let mut app = headless_app(context, project_dir).await?;
+    let session_id = app.create_session().await?;
+    if let Some(model) = model {
+        app.set_session_model(&session_id, model).await?;
+    }
+    write_line(output, &session_id)?;
+
+    app.start_session(&session_id, TurnPrompt::from_text(prompt))
+        .await?;
+    app.wait_for_session_idle(&session_id).await?;
+
+    Ok(())
+}
+
+/// Sends one follow-up prompt and prints the status reached by that turn.
+async fn reply_to_session(
+    context: HeadlessContext,
+    session_id: &str,
+    prompt: String,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    let (mut app, session_id) = headless_app_for_session(context, session_id).await?;
+    app.reply(&session_id, TurnPrompt::from_text(prompt)).await;
+    let status = app.wait_for_session_idle(&session_id).await?;
+
+    write_line(output, &status.to_string())
+}
+
+/// Merges one session and prints `Done` once the merge completes.
+async fn merge_session(
+    context: HeadlessContext,
+    session_id: &str,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    let (mut app, session_id) = headless_app_for_session(context, session_id).await?;
+    app.merge_session(&session_id).await?;
+    let status = app.wait_for_session_idle(&session_id).await?;
+    if status != Status::Done {
+        return Err(AppError::Workflow(format!(
+            "Merge did not complete; session `{session_id}` is in {status}"
+        )));
+    }
+
+    write_line(output, &status.to_string())
+}
+
+/// Lists persisted sessions as tab-separated lines or as a JSON array.
+async fn list_sessions(
+    db: &Database,
+    project_dir: Option<&Path>,
+    json: bool,
+    output: &mut impl Write,
+) -> Result<(), AppError> {
+    let repositories = AppRepositories::from_database(db);
+    let projects = repositories.load_projects_with_stats().await?;
+    let session_rows = match project_dir {
+        Some(project_dir) => {
+            let project_path = project_path(project_dir);
+            let project_id = projects
+                .iter()
+                .find(|project| project.path == project_path)
+                .map(|project| project.id)
+                .ok_or_else(|| {
+                    AppError::Workflow(format!("Project `{project_path}` is not registered"))
+                })?;
+
+            repositories.load_sessions_for_project(project_id).await?
+        }
+        None => repositories.load_sessions().await?,
+    };
+    let entries = session_rows
+        .into_iter()
+        .map(|row| {
+            let project = row.project_id.and_then(|project_id| {
+                projects
+                    .iter()
+                    .find(|project| project.id == project_id)
+                    .map(|project| project.path.clone())
+            });
+
+            session_list_entry(row, project)
+        })
+        .collect::<Vec<_>>();
+
+    if json {
+        let json = serde_json::to_string_pretty(&entries).map_err(|error| {
+            AppError::Workflow(format!("Failed to serialize sessions: {error}"))
+        })?;
+
+        return write_line(output, &json);
+    }
+
+    for entry in entries {
+        write_line(
+            output,
+            &format!(
+                "{}\t{}\t{}\t{}",
+                entry.id,
+                entry.status,
+                entry.project.unwrap_or_default(),
+                entry.title.unwrap_or_default()
+            ),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Polls persisted session status until the session stops running.
+///
+/// Polling the database lets `session wait` follow sessions driven by another
+/// `agentty` process, including the terminal UI.
+async fn wait_for_session(
+    db: &Database,
+    session_id: &str,
+    timeout: Option<Duration>,
+) -> Result<Status, AppError> {
+    let repositories = AppRepositories::from_database(db);
+    let started_at = tokio::time::Instant::now();
+
+    loop {
+        let session_row = resolve_session_row(&repositories, session_id).await?;
+        let status = Status::from_str(&session_row.status).map_err(AppError::Workflow)?;
+        if !status.is_busy() {
+            return Ok(status);
+        }
+
+        if timeout.is_some_and(|timeout| started_at.elapsed() >= timeout) {
+            return Err(AppError::Workflow(format!(
+                "Timed out waiting for session `{}`; it is still {status}",
+                session_row.id
+            )));
+        }
+
+        tokio::time::sleep(SESSION_WAIT_POLL_INTERVAL).await;
+    }
+}
+
+/// Builds a headless app whose active project is the persisted project of
+/// one session and returns the resolved full session id.
+async fn headless_app_for_session(
+    context: HeadlessContext,
+    session_id: &str,
+) -> Result<(App, String), AppError> {
+    let repositories = AppRepositories::from_database(&context.db);
+    let session_row = resolve_session_row(&repositories, session_id).await?;
+    let project = match session_row.project_id {
+        Some(project_id) => repositories.get_project(project_id).await?,
+        None => None,
+    }
+    .ok_or_else(|| {
+        AppError::Workflow(format!(
+            "Session `{}` is not linked to a project",
+            session_row.id
+        ))
+    })?;
+
+    let app = headless_app(context, Path::new(&project.path)).await?;
+
+    Ok((app, session_row.id))
+}
+
+/// Builds a headless app with `project_dir` as the active project.
+async fn headless_app(context: HeadlessContext, project_dir: &Path) -> Result<App, AppError> {
+    let project_dir = PathBuf::from(project_path(project_dir));
+    let git_branch = RealGitClient.detect_git_info(project_dir.clone()).await;
+
+    App::new_headless(context.base_path, project_dir, git_branch, context.db).await
+}
+
+/// Resolves one session row by full id or unique id prefix.
+async fn resolve_session_row(
+    repositories: &AppRepositories,
+    session_id: &str,
+) -> Result<SessionRow, AppError> {
+    let mut matching_rows = repositories
+        .load_sessions()
+        .await?
+        .into_iter()
+        .filter(|row| row.id.starts_with(session_id))
+        .collect::<Vec<_>>();
+
+    if let Some(position) = matching_rows.iter().position(|row| row.id == session_id) {
+        return Ok(matching_rows.swap_remove(position));
+    }
+
+    match matching_rows.len() {
+        0 => Err(AppError::Workflow(format!(
+            "Session `{session_id}` not found"
+        ))),
+        1 => Ok(matching_rows.remove(0)),
+        _ => Err(AppError::Workflow(format!(
+            "Session id prefix `{session_id}` matches {} sessions",
+            matching_rows.len()
+        ))),
+    }
+}
+
+/// Converts one persisted session row into its list entry.
+fn session_list_entry(row: SessionRow, project: Option<String>) -> SessionListEntry {
+    SessionListEntry {
+        created_at: row.created_at,
+        id: row.id,
+        issue: row.issue_web_url,
+        model: row.model,
+        project,
+        review_request: row
+            .review_request
+            .map(|review_request| review_request.web_url),
+        status: row.status,
+        title: row.title,
+        updated_at: row.updated_at,
+    }
+}
+
+/// Returns the canonical project path string used for project lookups,
+/// falling back to the given path when it cannot be canonicalized.
+fn project_path(project_dir: &Path) -> String {
+    std::fs::canonicalize(project_dir)
+        .unwrap_or_else(|_| project_dir.to_path_buf())
+        .to_string_lossy()
+        .into_owned()
+}
+
+/// Writes one output line.
+fn write_line(output: &mut impl Write, line: &str) -> Result<(), AppError> {
+    writeln!(output, "{line}")
+        .and_then(|()| output.flush())
+        .map_err(|error| AppError::Workflow(format!("Failed to write command output: {error}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Opens an in-memory database with one project and one session row.
+    async fn database_with_session(session_id: &str, status: Status) -> (Database, i64) {
+        let db = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let repositories = AppRepositories::from_database(&db);
+        let project_id = repositories
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to upsert project");
+        repositories
+            .insert_session(
+                session_id,
+                AgentModel::Gpt54.as_str(),
+                "main",
+                &status.to_string(),
+                project_id,
+            )
+            .await
+            .expect("failed to insert session");
+
+        (db, project_id)
+    }
+
+    #[test]
+    fn cli_parses_session_new_with_model_and_prompt() {
+        // Arrange
+        let args = [
+            "agentty",
+            "session",
+            "new",
+            "--model",
+            "gpt-5.4",
+            "Fix the flaky test",
+        ];
+
+        // Act
+        let cli = Cli::try_parse_from(args).expect("failed to parse args");
+
+        // Assert
+        assert!(matches!(
+            cli.command,
+            Some(Command::Session {
+                command: SessionCommand::New {
+                    project: None,
+                    model: Some(AgentModel::Gpt54),
+                    ref prompt,
+                },
+            }) if prompt == "Fix the flaky test"
+        ));
+    }
+
+    #[test]
+    fn cli_rejects_unknown_model() {
+        // Arrange
+        let args = ["agentty", "session", "new", "--model", "unknown", "Prompt"];
+
+        // Act
+        let result = Cli::try_parse_from(args);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn list_sessions_prints_json_entries() {
+        // Arrange
+        let (db, _project_id) = database_with_session("session-1", Status::Review).await;
+        let mut output = Vec::new();
+
+        // Act
+        list_sessions(&db, None, true, &mut output)
+            .await
+            .expect("failed to list sessions");
+
+        // Assert
+        let entries: serde_json::Value =
+            serde_json::from_slice(&output).expect("output should be json");
+        assert_eq!(entries[0]["id"], "session-1");
+        assert_eq!(entries[0]["status"], "Review");
+        assert_eq!(entries[0]["project"], "/tmp/project");
+        assert_eq!(entries[0]["model"], "gpt-5.4");
+    }
+
+    #[tokio::test]
+    async fn wait_for_session_returns_settled_status_for_id_prefix() {
+        // Arrange
+        let (db, _project_id) = database_with_session("session-1", Status::Question).await;
+
+        // Act
+        let status = wait_for_session(&db, "session-", Some(Duration::ZERO)).await;
+
+        // Assert
+        assert_eq!(status.expect("wait should succeed"), Status::Question);
+    }
+
+    #[tokio::test]
+    async fn wait_for_session_times_out_while_session_is_busy() {
+        // Arrange
+        let (db, _project_id) = database_with_session("session-1", Status::InProgress).await;
+
+        // Act
+        let result = wait_for_session(&db, "session-1", Some(Duration::ZERO)).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::Workflow(message)) if message.contains("still InProgress")
+        ));
+    }
+
+    #[tokio::test]
+    async fn resolve_session_row_rejects_ambiguous_prefix() {
+        // Arrange
+        let (db, project_id) = database_with_session("session-1", Status::Review).await;
+        let repositories = AppRepositories::from_database(&db);
+        repositories
+            .insert_session(
+                "session-2",
+                AgentModel::Gpt54.as_str(),
+                "main",
+                "Review",
+                project_id,
+            )
+            .await
+            .expect("failed to insert session");
+
+        // Act
+        let result = resolve_session_row(&repositories, "session-").await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(AppError::Workflow(message)) if message.contains("matches 2 sessions")
+        ));
+    }
+}
@@ -3,7 +3,11 @@
 pub mod agent;
 pub mod composer;
 pub mod input;
+pub mod mcp;
 pub mod permission;
+pub mod plan;
 pub mod project;
+pub mod prompt_template;
 pub mod session;
 pub mod setting;
+pub mod skill;
@@ -5,6 +5,11 @@ use std::path::PathBuf;
 
 use crate::domain::agent::{self, AgentKind, AgentModel, AgentSelectionMetadata, ReasoningLevel};
 use crate::domain::input::{InputState, is_at_mention_boundary, is_at_mention_query_character};
+use crate::domain::prompt_template::PromptTemplate;
+use crate::domain::skill::Skill;
+
+/// Built-in slash commands in menu order.
+const BUILT_IN_COMMANDS: [&str; 5] = ["/apply", "/model", "/plan", "/reasoning", "/stats"];
 
 /// One selectable row in the prompt slash-command menu.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -41,6 +46,12 @@ pub enum PromptSuggestionSelection {
     Model(AgentModel),
     /// Session-scoped reasoning selection chosen during `/reasoning`.
     Reasoning(ReasoningLevel),
+    /// Prompt template selected from the first stage, as an index into
+    /// [`PromptSlashState::prompt_templates`].
+    Template(usize),
+    /// Skill selected from the first stage, as an index into
+    /// [`PromptSlashState::skills`].
+    Skill(usize),
 }
 
 /// Inline attachment metadata for one pasted local image placeholder.
@@ -72,28 +83,156 @@ impl PromptAttachment {
     }
 }
 
+/// Source of one non-image text attachment added to the prompt composer.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PromptContextKind {
+    /// Captured stdout and stderr of a shell command run in the worktree.
+    Command,
+    /// Worktree diff of another session against its base branch.
+    Diff,
+    /// Contents of a local file or log.
+    File,
+    /// `SKILL.md` skill loaded from the user or project skill folders.
+    Skill,
+    /// Saved text snippet loaded from the user or project snippet folder.
+    Snippet,
+    /// Response body of an `http` or `https` URL.
+    Url,
+}
+
+impl PromptContextKind {
+    /// All context kinds in directive lookup order.
+    pub const ALL: [Self; 6] = [
+        Self::Command,
+        Self::Diff,
+        Self::File,
+        Self::Skill,
+        Self::Snippet,
+        Self::Url,
+    ];
+
+    /// Returns the line directive that attaches this kind, such as `/file`.
+    #[must_use]
+    pub fn directive(self) -> &'static str {
+        match self {
+            Self::Command => "/run",
+            Self::Diff => "/diff",
+            Self::File => "/file",
+            Self::Skill => "/skill",
+            Self::Snippet => "/snippet",
+            Self::Url => "/url",
+        }
+    }
+
+    /// Returns the word used inside this kind's `[<Name> #n]` placeholder.
+    #[must_use]
+    pub fn placeholder_name(self) -> &'static str {
+        match self {
+            Self::Command => "Command",
+            Self::Diff => "Diff",
+            Self::File => "File",
+            Self::Skill => "Skill",
+            Self::Snippet => "Snippet",
+            Self::Url => "URL",
+        }
+    }
+}
+
+/// Inline attachment metadata for one non-image context placeholder such as
+/// `[File #1]`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromptContextAttachment {
+    /// Stable display number shown inside the inline placeholder token.
+    pub attachment_number: usize,
+    /// Captured text sent to the agent in place of the placeholder.
+    pub content: String,
+    /// Attachment source kind.
+    pub kind: PromptContextKind,
+    /// Human-readable source description, such as a path or command line.
+    pub label: String,
+    /// Background capture id while the content is still being captured.
+    pub pending_capture_id: Option<u64>,
+    /// Placeholder token inserted into the prompt composer text.
+    pub placeholder: String,
+}
+
+impl PromptContextAttachment {
+    /// Creates attachment metadata for one captured context source.
+    #[must_use]
+    pub fn new(
+        attachment_number: usize,
+        kind: PromptContextKind,
+        label: String,
+        content: String,
+    ) -> Self {
+        Self {
+            attachment_number,
+            content,
+            kind,
+            label,
+            pending_capture_id: None,
+            placeholder: Self::placeholder_for(kind, attachment_number),
+        }
+    }
+
+    /// Returns whether the content is still being captured in the
+    /// background.
+    #[must_use]
+    pub fn is_pending(&self) -> bool {
+        self.pending_capture_id.is_some()
+    }
+
+    /// Builds the inline placeholder token for one context kind and number.
+    #[must_use]
+    pub fn placeholder_for(kind: PromptContextKind, attachment_number: usize) -> String {
+        format!("[{} #{attachment_number}]", kind.placeholder_name())
+    }
+}
+
+/// One context directive line parsed from the prompt composer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromptContextDirective {
+    /// Directive argument, such as a path, command line, or session name.
+    pub argument: String,
+    /// Exclusive character index where the directive line ends.
+    pub end: usize,
+    /// Attachment kind requested by the directive.
+    pub kind: PromptContextKind,
+    /// Character index where the directive line starts.
+    pub start: usize,
+}
+
 /// Attachment-only snapshot drained from the prompt composer during submit.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PromptComposerSubmission {
     /// Attachments that still appear in the submitted prompt text.
     pub attachments: Vec<PromptAttachment>,
+    /// Text context attachments that still appear in the submitted prompt
+    /// text.
+    pub context_attachments: Vec<PromptContextAttachment>,
     /// Submitted prompt text after draining the input buffer.
     pub text: String,
 }
 
 impl PromptComposerSubmission {
-    /// Returns whether both the text and attachment list are empty.
+    /// Returns whether the text and both attachment lists are empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.text.trim().is_empty() && self.attachments.is_empty()
+        self.text.trim().is_empty()
+            && self.attachments.is_empty()
+            && self.context_attachments.is_empty()
     }
 }
 
-/// UI state for pasted local-image attachments in prompt mode.
+/// UI state for pasted local-image and text context attachments in prompt
+/// mode.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PromptAttachmentState {
     /// Attachments in the same order their placeholders were inserted.
     pub attachments: Vec<PromptAttachment>,
+    /// Text context attachments in the same order their placeholders were
+    /// inserted.
+    pub context_attachments: Vec<PromptContextAttachment>,
     /// Next placeholder number that should be assigned to a pasted image.
     pub next_attachment_number: usize,
 }
@@ -104,10 +243,89 @@ impl PromptAttachmentState {
     pub fn new() -> Self {
         Self {
             attachments: Vec::new(),
+            context_attachments: Vec::new(),
             next_attachment_number: 1,
         }
     }
 
+    /// Registers one captured text context source and returns the
+    /// placeholder inserted into the prompt input text.
+    ///
+    /// Numbering is tracked per kind and reuses the smallest missing number,
+    /// matching image placeholders.
+    pub fn register_context(
+        &mut self,
+        kind: PromptContextKind,
+        label: String,
+        content: String,
+    ) -> String {
+        let attachment =
+            PromptContextAttachment::new(self.next_context_number(kind), kind, label, content);
+        let placeholder = attachment.placeholder.clone();
+        self.context_attachments.push(attachment);
+
+        placeholder
+    }
+
+    /// Registers one context source whose capture runs in the background and
+    /// returns the placeholder reserved for it.
+    ///
+    /// The attachment stays pending until [`Self::complete_context`] fills
+    /// it or [`fail_prompt_context`] removes it.
+    pub fn register_pending_context(&mut self, kind: PromptContextKind, capture_id: u64) -> String {
+        let mut attachment = PromptContextAttachment::new(
+            self.next_context_number(kind),
+            kind,
+            String::new(),
+            String::new(),
+        );
+        attachment.pending_capture_id = Some(capture_id);
+        let placeholder = attachment.placeholder.clone();
+        self.context_attachments.push(attachment);
+
+        placeholder
+    }
+
+    /// Fills the pending context attachment for `capture_id` with its
+    /// captured text.
+    ///
+    /// Returns whether a pending attachment with that id was still present.
+    pub fn complete_context(&mut self, capture_id: u64, label: String, content: String) -> bool {
+        let Some(attachment) = self
+            .context_attachments
+            .iter_mut()
+            .find(|attachment| attachment.pending_capture_id == Some(capture_id))
+        else {
+            return false;
+        };
+
+        attachment.content = content;
+        attachment.label = label;
+        attachment.pending_capture_id = None;
+
+        true
+    }
+
+    /// Returns whether any context attachment is still being captured.
+    #[must_use]
+    pub fn has_pending_context(&self) -> bool {
+        self.context_attachments
+            .iter()
+            .any(PromptContextAttachment::is_pending)
+    }
+
+    /// Returns the smallest context placeholder number not used by `kind`.
+    fn next_context_number(&self, kind: PromptContextKind) -> usize {
+        let mut attachment_number = 1;
+        while self.context_attachments.iter().any(|attachment| {
+            attachment.kind == kind && attachment.attachment_number == attachment_number
+        }) {
+            attachment_number += 1;
+        }
+
+        attachment_number
+    }
+
     /// Registers a pasted local image and returns the placeholder inserted
     /// into the prompt input text.
     pub fn register_local_image(&mut self, local_image_path: PathBuf) -> String {
@@ -148,6 +366,7 @@ impl PromptAttachmentState {
     /// placeholder.
     pub fn reset(&mut self) {
         self.attachments.clear();
+        self.context_attachments.clear();
         self.next_attachment_number = 1;
     }
 }
@@ -165,6 +384,8 @@ pub struct PromptHistoryState {
     pub draft_text: Option<String>,
     /// Previously sent user prompts in chronological order.
     pub entries: Vec<String>,
+    /// Active `Ctrl+R` search over the project's persisted prompt history.
+    pub search: Option<PromptHistorySearchState>,
     /// Currently selected history entry index, if any.
     pub selected_index: Option<usize>,
 }
@@ -176,6 +397,7 @@ impl PromptHistoryState {
         Self {
             draft_text: None,
             entries,
+            search: None,
             selected_index: None,
         }
     }
@@ -187,6 +409,29 @@ impl PromptHistoryState {
     }
 }
 
+/// UI state for the `Ctrl+R` fuzzy search over persisted prompt history.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PromptHistorySearchState {
+    /// Prompts submitted in the active project, most recently used first.
+    pub entries: Vec<String>,
+    /// Fuzzy query typed since the search opened.
+    pub query: String,
+    /// Highlighted row in the filtered matches.
+    pub selected_index: usize,
+}
+
+impl PromptHistorySearchState {
+    /// Creates a search with an empty query over `entries`.
+    #[must_use]
+    pub fn new(entries: Vec<String>) -> Self {
+        Self {
+            entries,
+            query: String::new(),
+            selected_index: 0,
+        }
+    }
+}
+
 /// Steps in prompt slash command selection.
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
 pub enum PromptSlashStage {
@@ -207,8 +452,13 @@ pub struct PromptSlashState {
     pub available_agent_kinds: Vec<AgentKind>,
     /// Agent selected for the current slash workflow, when applicable.
     pub selected_agent: Option<AgentKind>,
+    /// User- and project-level prompt templates listed after built-in
+    /// commands.
+    pub prompt_templates: Vec<PromptTemplate>,
     /// Highlighted option inside the active slash menu.
     pub selected_index: usize,
+    /// User- and project-level skills listed after prompt templates.
+    pub skills: Vec<Skill>,
     /// Active slash-command selection stage.
     pub stage: PromptSlashStage,
 }
@@ -226,12 +476,31 @@ impl PromptSlashState {
     pub fn with_available_agent_kinds(available_agent_kinds: Vec<AgentKind>) -> Self {
         Self {
             available_agent_kinds,
+            prompt_templates: Vec::new(),
             selected_agent: None,
             selected_index: 0,
+            skills: Vec::new(),
             stage: PromptSlashStage::Command,
         }
     }
 
+    /// Returns this slash state with `prompt_templates` offered in the
+    /// command stage.
+    #[must_use]
+    pub fn with_prompt_templates(mut self, prompt_templates: Vec<PromptTemplate>) -> Self {
+        self.prompt_templates = prompt_templates;
+
+        self
+    }
+
+    /// Returns this slash state with `skills` offered in the command stage.
+    #[must_use]
+    pub fn with_skills(mut self, skills: Vec<Skill>) -> Self {
+        self.skills = skills;
+
+        self
+    }
+
     /// Replaces the locally available agent kinds while keeping prompt slash
     /// selection state coherent.
     pub fn replace_available_agent_kinds(&mut self, available_agent_kinds: Vec<AgentKind>) {
@@ -250,7 +519,8 @@ impl PromptSlashState {
         }
     }
 
-    /// Resets slash state back to command selection.
+    /// Resets slash state back to command selection while keeping the loaded
+    /// prompt templates and skills.
     pub fn reset(&mut self) {
         self.selected_agent = None;
         self.selected_index = 0;
@@ -362,7 +632,7 @@ impl PromptComposerState {
     }
 
     /// Applies a prompt deletion range by delegating to the canonical
-    /// field-level helper, expanding it to whole image placeholders and
+    /// field-level helper, expanding it to whole attachment placeholders and
     /// pruning orphaned attachment metadata.
     pub fn delete_range(&mut self, start: usize, end: usize) {
         apply_prompt_delete_range(
@@ -393,22 +663,11 @@ impl Default for PromptComposerState {
 #[must_use]
 pub fn prompt_slash_option_count(
     input: &str,
-    stage: PromptSlashStage,
-    selected_agent: Option<AgentKind>,
-    available_agent_kinds: &[AgentKind],
+    slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
 ) -> usize {
-    build_prompt_slash_suggestion_list(
-        input,
-        &PromptSlashState {
-            available_agent_kinds: available_agent_kinds.to_vec(),
-            selected_agent,
-            selected_index: 0,
-            stage,
-        },
-        session_agent_kind,
-    )
-    .map_or(0, |suggestion_list| suggestion_list.items.len())
+    build_prompt_slash_suggestion_list(input, slash_state, session_agent_kind)
+        .map_or(0, |suggestion_list| suggestion_list.items.len())
 }
 
 /// Returns the character range deleted by one current-line delete action.
@@ -445,14 +704,18 @@ pub fn current_line_delete_range(input: &InputState) -> Option<(usize, usize)> {
     Some(delete_range)
 }
 
-/// Expands one deletion range to cover any overlapping `[Image #n]`
+/// Expands one deletion range to cover any overlapping attachment
 /// placeholders so partial token edits remove the whole placeholder.
 #[must_use]
-pub fn expand_delete_range_to_image_tokens(text: &str, start: usize, end: usize) -> (usize, usize) {
+pub fn expand_delete_range_to_attachment_tokens(
+    text: &str,
+    start: usize,
+    end: usize,
+) -> (usize, usize) {
     let mut expanded_start = start;
     let mut expanded_end = end;
 
-    for (token_start, token_end, _) in image_token_ranges(text) {
+    for (token_start, token_end, _) in attachment_token_ranges(text) {
         if token_start < expanded_end && expanded_start < token_end {
             expanded_start = expanded_start.min(token_start);
             expanded_end = expanded_end.max(token_end);
@@ -462,15 +725,16 @@ pub fn expand_delete_range_to_image_tokens(text: &str, start: usize, end: usize)
     (expanded_start, expanded_end)
 }
 
-/// Returns all valid `[Image #n]` placeholder token ranges in `text`.
+/// Returns all valid attachment placeholder token ranges in `text`, covering
+/// `[Image #n]` and every [`PromptContextKind`] placeholder.
 #[must_use]
-pub fn image_token_ranges(text: &str) -> Vec<(usize, usize, String)> {
+pub fn attachment_token_ranges(text: &str) -> Vec<(usize, usize, String)> {
     let characters = text.chars().collect::<Vec<_>>();
     let mut ranges = Vec::new();
     let mut index = 0;
 
     while index < characters.len() {
-        if let Some(end_index) = image_token_end_index(&characters, index) {
+        if let Some(end_index) = attachment_token_end_index(&characters, index) {
             let placeholder = characters[index..end_index].iter().collect::<String>();
             ranges.push((index, end_index, placeholder));
             index = end_index;
@@ -484,6 +748,40 @@ pub fn image_token_ranges(text: &str) -> Vec<(usize, usize, String)> {
     ranges
 }
 
+/// Returns the context directive on the cursor line, if that line consists of
+/// one directive such as `/file src/main.rs` followed by a non-empty argument.
+#[must_use]
+pub fn prompt_context_directive_at_cursor(input: &InputState) -> Option<PromptContextDirective> {
+    let characters: Vec<char> = input.text().chars().collect();
+    let cursor = input.cursor.min(characters.len());
+    let mut start = cursor;
+    while start > 0 && characters[start - 1] != '\n' {
+        start -= 1;
+    }
+
+    let mut end = cursor;
+    while end < characters.len() && characters[end] != '\n' {
+        end += 1;
+    }
+
+    let line = characters[start..end].iter().collect::<String>();
+    let (directive, argument) = line.trim().split_once(char::is_whitespace)?;
+    let kind = PromptContextKind::ALL
+        .into_iter()
+        .find(|kind| kind.directive() == directive)?;
+    let argument = argument.trim();
+    if argument.is_empty() {
+        return None;
+    }
+
+    Some(PromptContextDirective {
+        argument: argument.to_string(),
+        end,
+        kind,
+        start,
+    })
+}
+
 /// Inserts pasted prompt text and clears transient slash/history state.
 ///
 /// This is the canonical field-level mutation path used by runtime code.
@@ -534,8 +832,107 @@ pub fn insert_prompt_local_image(
     slash_state.reset();
 }
 
-/// Applies one prompt deletion range, expanding it to whole image placeholders
-/// and pruning orphaned attachment metadata.
+/// Replaces one context directive line with the placeholder for its captured
+/// content and records the attachment metadata.
+///
+/// This is the canonical field-level mutation path used by runtime code.
+pub fn insert_prompt_context(
+    attachment_state: &mut PromptAttachmentState,
+    history_state: &mut PromptHistoryState,
+    input: &mut InputState,
+    slash_state: &mut PromptSlashState,
+    directive: &PromptContextDirective,
+    label: String,
+    content: String,
+) {
+    let placeholder = attachment_state.register_context(directive.kind, label, content);
+    input.replace_range(directive.start, directive.end, &placeholder);
+    history_state.reset_navigation();
+    slash_state.reset();
+}
+
+/// Replaces one context directive line with a placeholder reserved for a
+/// background capture and records the pending attachment.
+///
+/// This is the canonical field-level mutation path used by runtime code.
+pub fn insert_pending_prompt_context(
+    attachment_state: &mut PromptAttachmentState,
+    history_state: &mut PromptHistoryState,
+    input: &mut InputState,
+    slash_state: &mut PromptSlashState,
+    directive: &PromptContextDirective,
+    capture_id: u64,
+) {
+    let placeholder = attachment_state.register_pending_context(directive.kind, capture_id);
+    input.replace_range(directive.start, directive.end, &placeholder);
+    history_state.reset_navigation();
+    slash_state.reset();
+}
+
+/// Drops the pending context attachment for `capture_id` and puts
+/// `directive_line` back in place of its placeholder so it can be corrected.
+///
+/// The cursor keeps its position relative to the surrounding text. Returns
+/// whether a pending attachment with that id was still present.
+pub fn fail_prompt_context(
+    attachment_state: &mut PromptAttachmentState,
+    input: &mut InputState,
+    capture_id: u64,
+    directive_line: &str,
+) -> bool {
+    let Some(attachment_index) = attachment_state
+        .context_attachments
+        .iter()
+        .position(|attachment| attachment.pending_capture_id == Some(capture_id))
+    else {
+        return false;
+    };
+    let attachment = attachment_state
+        .context_attachments
+        .remove(attachment_index);
+
+    let Some(byte_start) = input.text().find(&attachment.placeholder) else {
+        return true;
+    };
+    let start = input.text()[..byte_start].chars().count();
+    let end = start + attachment.placeholder.chars().count();
+    let cursor = input.cursor;
+    input.replace_range(start, end, directive_line);
+    input.cursor = if cursor <= start {
+        cursor
+    } else {
+        cursor.max(end) - end + start + directive_line.chars().count()
+    };
+
+    true
+}
+
+/// Replaces the whole prompt text, keeping attachments whose placeholders are
+/// still present and dropping the rest.
+///
+/// This is the canonical field-level mutation path used by runtime code when
+/// the prompt is edited outside the composer, such as in an external editor.
+pub fn replace_prompt_text(
+    attachment_state: &mut PromptAttachmentState,
+    history_state: &mut PromptHistoryState,
+    input: &mut InputState,
+    slash_state: &mut PromptSlashState,
+    text: String,
+) {
+    *input = InputState::with_text(text);
+    attachment_state
+        .attachments
+        .retain(|attachment| input.text().contains(&attachment.placeholder));
+    attachment_state
+        .context_attachments
+        .retain(|attachment| input.text().contains(&attachment.placeholder));
+    attachment_state.refresh_next_attachment_number();
+    history_state.reset_navigation();
+    slash_state.reset();
+}
+
+/// Applies one prompt deletion range, expanding it to whole attachment
+/// placeholders and pruning orphaned attachment metadata.
 ///
 /// This is the canonical field-level mutation path used by runtime code.
 /// [`PromptComposerState::delete_range`] delegates here to keep behavior
@@ -548,7 +945,8 @@ pub fn apply_prompt_delete_range(
     start: usize,
     end: usize,
 ) {
-    let (delete_start, delete_end) = expand_delete_range_to_image_tokens(input.text(), start, end);
+    let (delete_start, delete_end) =
+        expand_delete_range_to_attachment_tokens(input.text(), start, end);
     if delete_start >= delete_end {
         return;
     }
@@ -557,6 +955,9 @@ pub fn apply_prompt_delete_range(
     attachment_state
         .attachments
         .retain(|attachment| input.text().contains(&attachment.placeholder));
+    attachment_state
+        .context_attachments
+        .retain(|attachment| input.text().contains(&attachment.placeholder));
     attachment_state.refresh_next_attachment_number();
     history_state.reset_navigation();
     slash_state.reset();
@@ -580,17 +981,31 @@ pub fn drain_prompt_submission(
         .cloned()
         .collect::<Vec<_>>();
     attachments.sort_by_key(|attachment| text.find(&attachment.placeholder).unwrap_or(usize::MAX));
+    let mut context_attachments = attachment_state
+        .context_attachments
+        .iter()
+        .filter(|attachment| text.contains(&attachment.placeholder))
+        .cloned()
+        .collect::<Vec<_>>();
+    context_attachments
+        .sort_by_key(|attachment| text.find(&attachment.placeholder).unwrap_or(usize::MAX));
     attachment_state.reset();
 
-    PromptComposerSubmission { attachments, text }
+    PromptComposerSubmission {
+        attachments,
+        context_attachments,
+        text,
+    }
 }
 
 /// Rewrites user-entered `@` lookups into quoted agent-facing path tokens.
 ///
 /// File and directory lookups like `@path/to/file` are rewritten to
-/// `"path/to/file"`. Non-lookup uses of `@`, including email-like tokens and
-/// lone `@`, are preserved so the UI and persisted transcript can continue
-/// storing the original user input unchanged.
+/// `"path/to/file"`, and symbol anchors like `@src/lib.rs:42` keep their line
+/// suffix inside the quotes as `"src/lib.rs:42"`. Non-lookup uses of `@`,
+/// including email-like tokens and lone `@`, are preserved so the UI and
+/// persisted transcript can continue storing the original user input
+/// unchanged.
 #[must_use]
 pub fn render_prompt_text_for_agent(text: &str) -> String {
     let characters = text.chars().collect::<Vec<char>>();
@@ -621,6 +1036,8 @@ pub fn render_prompt_text_for_agent(text: &str) -> String {
             continue;
         }
 
+        scan_index = line_anchor_end(&characters, scan_index);
+
         output.push('"');
         output.extend(characters[index + 1..scan_index].iter());
         output.push('"');
@@ -630,6 +1047,24 @@ pub fn render_prompt_text_for_agent(text: &str) -> String {
     output
 }
 
+/// Returns the end index after an optional `:line` suffix starting at
+/// `scan_index`, or `scan_index` unchanged when no line number follows.
+fn line_anchor_end(characters: &[char], scan_index: usize) -> usize {
+    if characters.get(scan_index) != Some(&':') {
+        return scan_index;
+    }
+
+    let digit_count = characters[scan_index + 1..]
+        .iter()
+        .take_while(|character| character.is_ascii_digit())
+        .count();
+    if digit_count == 0 {
+        return scan_index;
+    }
+
+    scan_index + 1 + digit_count
+}
+
 /// Builds the render-ready prompt slash suggestion list for the provided
 /// input and slash state.
 #[must_use]
@@ -638,14 +1073,7 @@ pub fn build_prompt_slash_suggestion_list(
     slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
 ) -> Option<PromptSuggestionList> {
-    build_slash_suggestion_list(
-        input,
-        &slash_state.available_agent_kinds,
-        slash_state.stage,
-        slash_state.selected_agent,
-        session_agent_kind,
-        slash_state.selected_index,
-    )
+    build_slash_suggestion_list(input, slash_state, session_agent_kind)
 }
 
 /// Resolves the semantic prompt slash action behind the current selection.
@@ -659,39 +1087,25 @@ pub fn resolve_prompt_slash_selection(
     slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
 ) -> Option<PromptSuggestionSelection> {
-    selected_slash_action(
-        input,
-        &slash_state.available_agent_kinds,
-        slash_state.stage,
-        slash_state.selected_agent,
-        slash_state.selected_index,
-        session_agent_kind,
-    )
+    selected_slash_action(input, slash_state, session_agent_kind)
 }
 
 /// Builds one prompt slash suggestion list for the provided input state.
 fn build_slash_suggestion_list(
     input: &str,
-    available_agent_kinds: &[AgentKind],
-    stage: PromptSlashStage,
-    selected_agent: Option<AgentKind>,
+    slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
-    selected_index: usize,
 ) -> Option<PromptSuggestionList> {
     if !input.starts_with('/') {
         return None;
     }
 
-    let (title, items): (&str, Vec<PromptSuggestionItem>) = match stage {
+    let available_agent_kinds = slash_state.available_agent_kinds.as_slice();
+    let (title, items): (&str, Vec<PromptSuggestionItem>) = match slash_state.stage {
         PromptSlashStage::Command => {
-            let commands = prompt_slash_commands(input)
+            let commands = prompt_slash_commands(input, slash_state)
                 .into_iter()
-                .map(|command| PromptSuggestionItem {
-                    badge: None,
-                    detail: Some(command_description(command).to_string()),
-                    label: command.to_string(),
-                    metadata: None,
-                })
+                .filter_map(|command| command_suggestion_item(command, slash_state))
                 .collect::<Vec<_>>();
 
             ("Slash Command (j/k move, Enter select)", commands)
@@ -712,7 +1126,7 @@ fn build_slash_suggestion_list(
             let selected_agent_kind = resolve_model_stage_agent(
                 session_agent_kind,
                 available_agent_kinds,
-                selected_agent,
+                slash_state.selected_agent,
             )?;
             let models = selected_agent_kind
                 .models()
@@ -741,7 +1155,7 @@ fn build_slash_suggestion_list(
 
     Some(PromptSuggestionList {
         items,
-        selected_index: selected_index.min(max_index),
+        selected_index: slash_state.selected_index.min(max_index),
         title: title.to_string(),
     })
 }
@@ -749,20 +1163,19 @@ fn build_slash_suggestion_list(
 /// Returns the semantic slash action mapped to the current selection state.
 fn selected_slash_action(
     input: &str,
-    available_agent_kinds: &[AgentKind],
-    stage: PromptSlashStage,
-    selected_agent: Option<AgentKind>,
-    selected_index: usize,
+    slash_state: &PromptSlashState,
     session_agent_kind: AgentKind,
 ) -> Option<PromptSuggestionSelection> {
-    match stage {
+    let available_agent_kinds = slash_state.available_agent_kinds.as_slice();
+    let selected_index = slash_state.selected_index;
+
+    match slash_state.stage {
         PromptSlashStage::Command => {
-            let commands = prompt_slash_commands(input);
-            let selected_command = commands
-                .get(clamp_selected_index(selected_index, commands.len()))
-                .copied()?;
+            let commands = prompt_slash_commands(input, slash_state);
 
-            Some(PromptSuggestionSelection::Command(selected_command))
+            commands
+                .get(clamp_selected_index(selected_index, commands.len()))
+                .copied()
         }
         PromptSlashStage::Agent => available_agent_kinds
             .get(clamp_selected_index(
@@ -775,7 +1188,7 @@ fn selected_slash_action(
             let selected_agent_kind = resolve_model_stage_agent(
                 session_agent_kind,
                 available_agent_kinds,
-                selected_agent,
+                slash_state.selected_agent,
             )?;
             let models = selected_agent_kind.models();
             let selected_model = models
@@ -821,21 +1234,133 @@ fn command_description(command: &str) -> &'static str {
     match command {
         "/apply" => "Apply review suggestions to the codebase.",
         "/model" => "Choose an agent and model for this session.",
+        "/plan" => "Toggle plan-first mode for this session.",
         "/reasoning" => "Override the reasoning level for this session.",
         "/stats" => "Check session stats.",
         _ => "Prompt slash command.",
     }
 }
 
-/// Returns all slash commands whose prefixes match the current input.
-fn prompt_slash_commands(input: &str) -> Vec<&'static str> {
+/// Returns the command-stage entries matching the current input.
+///
+/// Built-in commands match by prefix of the whole input and come first.
+/// Templates match by prefix of the first input word, or exactly once
+/// arguments follow it; templates shadowed by a built-in name are skipped.
+/// Skills come last and match when their `/skill <name>` directive starts
+/// with the whole input.
+fn prompt_slash_commands(
+    input: &str,
+    slash_state: &PromptSlashState,
+) -> Vec<PromptSuggestionSelection> {
     let lowered = input.to_lowercase();
-    let mut commands = vec!["/apply", "/model", "/reasoning", "/stats"];
-    commands.retain(|command| command.starts_with(&lowered));
+    let (command_query, has_arguments) = lowered
+        .split_once(char::is_whitespace)
+        .map_or((lowered.as_str(), false), |(command_query, _)| {
+            (command_query, true)
+        });
+    let mut commands = BUILT_IN_COMMANDS
+        .into_iter()
+        .filter(|command| command.starts_with(&lowered))
+        .map(PromptSuggestionSelection::Command)
+        .collect::<Vec<_>>();
+
+    commands.extend(
+        slash_state
+            .prompt_templates
+            .iter()
+            .enumerate()
+            .filter(|(_, template)| {
+                let command = template.command();
+                let is_match = if has_arguments {
+                    command == command_query
+                } else {
+                    command.starts_with(command_query)
+                };
+
+                is_match && !BUILT_IN_COMMANDS.contains(&command.as_str())
+            })
+            .map(|(template_index, _)| PromptSuggestionSelection::Template(template_index)),
+    );
+    commands.extend(
+        slash_state
+            .skills
+            .iter()
+            .enumerate()
+            .filter(|(_, skill)| skill.command().starts_with(&lowered))
+            .map(|(skill_index, _)| PromptSuggestionSelection::Skill(skill_index)),
+    );
 
     commands
 }
 
+/// Returns the render-ready dropdown row for one command-stage entry.
+fn command_suggestion_item(
+    command: PromptSuggestionSelection,
+    slash_state: &PromptSlashState,
+) -> Option<PromptSuggestionItem> {
+    match command {
+        PromptSuggestionSelection::Command(command) => Some(PromptSuggestionItem {
+            badge: None,
+            detail: Some(command_description(command).to_string()),
+            label: command.to_string(),
+            metadata: None,
+        }),
+        PromptSuggestionSelection::Template(template_index) => {
+            let template = slash_state.prompt_templates.get(template_index)?;
+
+            Some(PromptSuggestionItem {
+                badge: Some(template.scope.label().to_string()),
+                detail: Some(
+                    template
+                        .description
+                        .clone()
+                        .unwrap_or_else(|| "Insert prompt template.".to_string()),
+                ),
+                label: template.command(),
+                metadata: template_metadata(template),
+            })
+        }
+        PromptSuggestionSelection::Skill(skill_index) => {
+            let skill = slash_state.skills.get(skill_index)?;
+
+            Some(PromptSuggestionItem {
+                badge: Some(skill.scope.label().to_string()),
+                detail: Some(
+                    skill
+                        .description
+                        .clone()
+                        .unwrap_or_else(|| "Attach skill instructions.".to_string()),
+                ),
+                label: skill.command(),
+                metadata: None,
+            })
+        }
+        PromptSuggestionSelection::Agent(_)
+        | PromptSuggestionSelection::Model(_)
+        | PromptSuggestionSelection::Reasoning(_) => None,
+    }
+}
+
+/// Returns the parameter hint and pinned settings shown beside a template.
+fn template_metadata(template: &PromptTemplate) -> Option<String> {
+    let mut parts = Vec::new();
+    if !template.parameters.is_empty() {
+        parts.push(template.parameter_hint());
+    }
+    if let Some(model) = template.model {
+        parts.push(model.name().to_string());
+    }
+    if let Some(reasoning_level) = template.reasoning_level {
+        parts.push(format!("reasoning {}", reasoning_level.name()));
+    }
+
+    if parts.is_empty() {
+        return None;
+    }
+
+    Some(parts.join(" | "))
+}
+
 /// Returns the stable `/reasoning` selection options.
 fn reasoning_options() -> Vec<ReasoningLevel> {
     ReasoningLevel::ALL.to_vec()
@@ -854,20 +1379,25 @@ fn reasoning_suggestion_items() -> Vec<PromptSuggestionItem> {
         .collect()
 }
 
-/// Returns the exclusive end index for an `[Image #n]` placeholder token that
-/// starts at `start_index`.
-fn image_token_end_index(characters: &[char], start_index: usize) -> Option<usize> {
-    let token_body = characters.get(start_index..)?;
-    if token_body.len() < "[Image #1]".chars().count() || token_body.first() != Some(&'[') {
+/// Returns the exclusive end index for an `[Image #n]` or context placeholder
+/// token such as `[File #n]` that starts at `start_index`.
+#[must_use]
+pub fn attachment_token_end_index(characters: &[char], start_index: usize) -> Option<usize> {
+    if characters.get(start_index) != Some(&'[') {
         return None;
     }
 
-    let image_prefix = ['[', 'I', 'm', 'a', 'g', 'e', ' ', '#'];
-    if token_body.get(..image_prefix.len())? != image_prefix {
-        return None;
-    }
+    let name_end = std::iter::once("Image")
+        .chain(PromptContextKind::ALL.map(PromptContextKind::placeholder_name))
+        .find_map(|name| {
+            let prefix = name.chars().chain([' ', '#']);
+            let prefix_end = start_index + 1 + name.chars().count() + 2;
+            let body = characters.get(start_index + 1..prefix_end)?;
+
+            body.iter().copied().eq(prefix).then_some(prefix_end)
+        })?;
 
-    let mut scan_index = start_index + image_prefix.len();
+    let mut scan_index = name_end;
     let mut saw_digit = false;
     while let Some(character) = characters.get(scan_index) {
         if character.is_ascii_digit() {
@@ -890,6 +1420,8 @@ fn image_token_end_index(characters: &[char], start_index: usize) -> Option<usiz
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::prompt_template::PromptTemplateScope;
+    use crate::domain::skill::SkillScope;
 
     #[test]
     fn test_prompt_attachment_state_registers_images_in_placeholder_order() {
@@ -941,6 +1473,7 @@ mod tests {
                 PromptAttachment::new(1, PathBuf::from("/tmp/first-image.png")),
                 PromptAttachment::new(3, PathBuf::from("/tmp/third-image.png")),
             ],
+            context_attachments: Vec::new(),
             next_attachment_number: 99,
         };
 
@@ -1171,6 +1704,250 @@ mod tests {
         assert_eq!(submission.attachments[0].placeholder, "[Image #2]");
     }
 
+    #[test]
+    fn test_prompt_attachment_state_numbers_context_placeholders_per_kind() {
+        // Arrange
+        let mut attachment_state = PromptAttachmentState::new();
+
+        // Act
+        let first_file = attachment_state.register_context(
+            PromptContextKind::File,
+            "src/main.rs".to_string(),
+            "fn main() {}".to_string(),
+        );
+        let command = attachment_state.register_context(
+            PromptContextKind::Command,
+            "cargo test".to_string(),
+            "ok".to_string(),
+        );
+        let second_file = attachment_state.register_context(
+            PromptContextKind::File,
+            "README.md".to_string(),
+            "# Readme".to_string(),
+        );
+
+        // Assert
+        assert_eq!(first_file, "[File #1]");
+        assert_eq!(command, "[Command #1]");
+        assert_eq!(second_file, "[File #2]");
+        assert_eq!(attachment_state.next_attachment_number, 1);
+    }
+
+    #[test]
+    fn test_prompt_context_directive_at_cursor_parses_cursor_line() {
+        // Arrange
+        let input = InputState::with_text("Explain this\n/run cargo test -p agentty".to_string());
+
+        // Act
+        let directive = prompt_context_directive_at_cursor(&input);
+
+        // Assert
+        assert_eq!(
+            directive,
+            Some(PromptContextDirective {
+                argument: "cargo test -p agentty".to_string(),
+                end: 39,
+                kind: PromptContextKind::Command,
+                start: 13,
+            })
+        );
+    }
+
+    #[test]
+    fn test_prompt_context_directive_at_cursor_parses_url_directive() {
+        // Arrange
+        let input = InputState::with_text("/url https://example.com/docs".to_string());
+
+        // Act
+        let directive = prompt_context_directive_at_cursor(&input);
+
+        // Assert
+        assert_eq!(
+            directive.map(|directive| (directive.kind, directive.argument)),
+            Some((
+                PromptContextKind::Url,
+                "https://example.com/docs".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_prompt_context_directive_at_cursor_ignores_missing_argument_and_plain_text() {
+        // Arrange
+        let bare_directive = InputState::with_text("/file   ".to_string());
+        let plain_text = InputState::with_text("please /file src/main.rs".to_string());
+
+        // Act
+        let bare_result = prompt_context_directive_at_cursor(&bare_directive);
+        let plain_result = prompt_context_directive_at_cursor(&plain_text);
+
+        // Assert
+        assert_eq!(bare_result, None);
+        assert_eq!(plain_result, None);
+    }
+
+    #[test]
+    fn test_insert_prompt_context_replaces_directive_line_with_placeholder() {
+        // Arrange
+        let mut composer = PromptComposerState::new(AgentKind::ALL.to_vec());
+        composer.insert_text("Compare with\n/diff fix-login");
+        let directive =
+            prompt_context_directive_at_cursor(&composer.input).expect("expected directive");
+
+        // Act
+        insert_prompt_context(
+            &mut composer.attachment_state,
+            &mut composer.history_state,
+            &mut composer.input,
+            &mut composer.slash_state,
+            &directive,
+            "fix-login".to_string(),
+            "diff --git a/x b/x".to_string(),
+        );
+
+        // Assert
+        assert_eq!(composer.input.text(), "Compare with\n[Diff #1]");
+        assert_eq!(composer.attachment_state.context_attachments.len(), 1);
+        assert_eq!(
+            composer.attachment_state.context_attachments[0].label,
+            "fix-login"
+        );
+    }
+
+    #[test]
+    fn test_insert_pending_prompt_context_fills_placeholder_on_completion() {
+        // Arrange
+        let mut composer = PromptComposerState::new(AgentKind::ALL.to_vec());
+        composer.insert_text("Fix\n/run cargo test");
+        let directive =
+            prompt_context_directive_at_cursor(&composer.input).expect("expected directive");
+        insert_pending_prompt_context(
+            &mut composer.attachment_state,
+            &mut composer.history_state,
+            &mut composer.input,
+            &mut composer.slash_state,
+            &directive,
+            7,
+        );
+        let is_pending_after_insert = composer.attachment_state.has_pending_context();
+
+        // Act
+        let completed = composer.attachment_state.complete_context(
+            7,
+            "Command `cargo test`".to_string(),
+            "ok".to_string(),
+        );
+
+        // Assert
+        assert!(is_pending_after_insert);
+        assert!(completed);
+        assert_eq!(composer.input.text(), "Fix\n[Command #1]");
+        assert!(!composer.attachment_state.has_pending_context());
+        assert_eq!(
+            composer.attachment_state.context_attachments[0].content,
+            "ok"
+        );
+        assert!(
+            !composer
+                .attachment_state
+                .complete_context(7, String::new(), String::new())
+        );
+    }
+
+    #[test]
+    fn test_fail_prompt_context_restores_directive_and_keeps_cursor_offset() {
+        // Arrange
+        let mut composer = PromptComposerState::new(AgentKind::ALL.to_vec());
+        composer.insert_text("/url https://example.com");
+        let directive =
+            prompt_context_directive_at_cursor(&composer.input).expect("expected directive");
+        insert_pending_prompt_context(
+            &mut composer.attachment_state,
+            &mut composer.history_state,
+            &mut composer.input,
+            &mut composer.slash_state,
+            &directive,
+            3,
+        );
+        composer.insert_text(" then fix");
+
+        // Act
+        let failed = fail_prompt_context(
+            &mut composer.attachment_state,
+            &mut composer.input,
+            3,
+            "/url https://example.com",
+        );
+
+        // Assert
+        assert!(failed);
+        assert_eq!(composer.input.text(), "/url https://example.com then fix");
+        assert_eq!(composer.input.cursor, composer.input.text().chars().count());
+        assert!(composer.attachment_state.context_attachments.is_empty());
+    }
+
+    #[test]
+    fn test_prompt_composer_delete_range_removes_whole_context_token() {
+        // Arrange
+        let mut composer = PromptComposerState::new(AgentKind::ALL.to_vec());
+        composer.insert_text("Use [Snippet #1] and [File #1]");
+        let _ = composer.attachment_state.register_context(
+            PromptContextKind::Snippet,
+            "style".to_string(),
+            "Be brief.".to_string(),
+        );
+        let _ = composer.attachment_state.register_context(
+            PromptContextKind::File,
+            "notes.txt".to_string(),
+            "notes".to_string(),
+        );
+
+        // Act
+        composer.delete_range(8, 9);
+        let submission = composer.take_submission();
+
+        // Assert
+        assert_eq!(submission.text, "Use  and [File #1]");
+        assert_eq!(submission.context_attachments.len(), 1);
+        assert_eq!(submission.context_attachments[0].placeholder, "[File #1]");
+    }
+
+    #[test]
+    fn test_replace_prompt_text_keeps_only_surviving_attachments() {
+        // Arrange
+        let mut composer = PromptComposerState::new(AgentKind::ALL.to_vec());
+        composer.insert_local_image(PathBuf::from("/tmp/one.png"));
+        composer.insert_local_image(PathBuf::from("/tmp/two.png"));
+        let _ = composer.attachment_state.register_context(
+            PromptContextKind::File,
+            "notes.txt".to_string(),
+            "notes".to_string(),
+        );
+
+        // Act
+        replace_prompt_text(
+            &mut composer.attachment_state,
+            &mut composer.history_state,
+            &mut composer.input,
+            &mut composer.slash_state,
+            "Rewritten\n\nSee [Image #2]".to_string(),
+        );
+
+        // Assert
+        assert_eq!(composer.input.text(), "Rewritten\n\nSee [Image #2]");
+        assert_eq!(
+            composer.input.cursor,
+            "Rewritten\n\nSee [Image #2]".chars().count()
+        );
+        assert_eq!(composer.attachment_state.attachments.len(), 1);
+        assert_eq!(
+            composer.attachment_state.attachments[0].placeholder,
+            "[Image #2]"
+        );
+        assert!(composer.attachment_state.context_attachments.is_empty());
+        assert_eq!(composer.attachment_state.next_attachment_number, 1);
+    }
+
     #[test]
     fn test_drain_prompt_submission_keeps_raw_at_lookup_text() {
         // Arrange
@@ -1218,6 +1995,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_render_prompt_text_for_agent_keeps_symbol_line_anchor_quoted() {
+        // Arrange
+        let prompt_text = "Fix @src/session.rs:42 and see @README.md: it explains";
+
+        // Act
+        let rendered_text = render_prompt_text_for_agent(prompt_text);
+
+        // Assert
+        assert_eq!(
+            rendered_text,
+            "Fix \"src/session.rs:42\" and see \"README.md\": it explains"
+        );
+    }
+
     /// Ensures prompt preparation does not special-case literal `looked/up/`
     /// text beyond ordinary `@` lookup quoting.
     #[test]
@@ -1284,4 +2076,146 @@ mod tests {
             Some("Apply review suggestions to the codebase.")
         );
     }
+
+    fn prompt_templates() -> Vec<PromptTemplate> {
+        vec![
+            PromptTemplate::parse(
+                "stats",
+                "Shadowed by the built-in command.",
+                PromptTemplateScope::User,
+            )
+            .expect("template should parse"),
+            PromptTemplate::parse(
+                "tests",
+                "+++\ndescription = \"Write tests\"\nreasoning = \"high\"\n+++\nTest {{target}}.",
+                PromptTemplateScope::Project,
+            )
+            .expect("template should parse"),
+            PromptTemplate::parse("testplan", "Draft a test plan.", PromptTemplateScope::User)
+                .expect("template should parse"),
+        ]
+    }
+
+    #[test]
+    fn test_slash_suggestion_list_lists_prompt_templates_after_built_ins() {
+        // Arrange
+        let mut composer = PromptComposerState::with_input_and_history(
+            InputState::with_text("/".to_string()),
+            AgentKind::ALL.to_vec(),
+            Vec::new(),
+        );
+        composer.slash_state = composer
+            .slash_state
+            .with_prompt_templates(prompt_templates());
+
+        // Act
+        let suggestion_list = composer
+            .slash_suggestion_list(AgentKind::Codex)
+            .expect("expected suggestion list");
+
+        // Assert
+        let labels = suggestion_list
+            .items
+            .iter()
+            .map(|item| item.label.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            labels,
+            vec![
+                "/apply",
+                "/model",
+                "/plan",
+                "/reasoning",
+                "/stats",
+                "/tests",
+                "/testplan"
+            ]
+        );
+        assert_eq!(
+            suggestion_list.items[5],
+            PromptSuggestionItem {
+                badge: Some("project".to_string()),
+                detail: Some("Write tests".to_string()),
+                label: "/tests".to_string(),
+                metadata: Some("<target> | reasoning high".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_selected_slash_action_matches_template_exactly_when_arguments_follow() {
+        // Arrange
+        let mut composer = PromptComposerState::with_input_and_history(
+            InputState::with_text("/tests src/lib.rs".to_string()),
+            AgentKind::ALL.to_vec(),
+            Vec::new(),
+        );
+        composer.slash_state = composer
+            .slash_state
+            .with_prompt_templates(prompt_templates());
+
+        // Act
+        let suggestion_list = composer
+            .slash_suggestion_list(AgentKind::Codex)
+            .expect("expected suggestion list");
+        let selection = composer.selected_slash_action(AgentKind::Codex);
+
+        // Assert
+        assert_eq!(suggestion_list.items.len(), 1);
+        assert_eq!(selection, Some(PromptSuggestionSelection::Template(1)));
+    }
+
+    #[test]
+    fn test_slash_suggestion_list_lists_skills_matching_skill_directive() {
+        // Arrange
+        let skills = ["release", "review"]
+            .map(|name| {
+                Skill::parse(
+                    name,
+                    &format!("---\ndescription: Run {name}\n---\nBody"),
+                    PathBuf::from(format!("skills/{name}/SKILL.md")),
+                    SkillScope::Project,
+                )
+                .expect("skill should parse")
+            })
+            .to_vec();
+        let mut composer = PromptComposerState::with_input_and_history(
+            InputState::with_text("/skill rev".to_string()),
+            AgentKind::ALL.to_vec(),
+            Vec::new(),
+        );
+        composer.slash_state = composer.slash_state.with_skills(skills);
+
+        // Act
+        let suggestion_list = composer
+            .slash_suggestion_list(AgentKind::Codex)
+            .expect("expected suggestion list");
+        let selection = composer.selected_slash_action(AgentKind::Codex);
+
+        // Assert
+        assert_eq!(
+            suggestion_list.items,
+            vec![PromptSuggestionItem {
+                badge: Some("project".to_string()),
+                detail: Some("Run review".to_string()),
+                label: "/skill review".to_string(),
+                metadata: None,
+            }]
+        );
+        assert_eq!(selection, Some(PromptSuggestionSelection::Skill(1)));
+    }
+
+    #[test]
+    fn test_prompt_slash_state_reset_keeps_prompt_templates() {
+        // Arrange
+        let mut slash_state = PromptSlashState::new().with_prompt_templates(prompt_templates());
+        slash_state.stage = PromptSlashStage::Reasoning;
+
+        // Act
+        slash_state.reset();
+
+        // Assert
+        assert_eq!(slash_state.stage, PromptSlashStage::Command);
+        assert_eq!(slash_state.prompt_templates, prompt_templates());
+    }
 }
@@ -0,0 +1,204 @@
+//! Per-project MCP server configuration shared by every provider.
+
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+/// Top-level key accepted when the configuration is pasted from a provider
+/// config file that wraps servers in `{"mcpServers": {...}}`.
+const MCP_SERVERS_KEY: &str = "mcpServers";
+
+/// One stdio MCP server that Agentty passes to every provider launch.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct McpServer {
+    /// Tool names the agent may call, or empty to allow every tool the
+    /// server exposes.
+    pub allowed_tools: Vec<String>,
+    /// Arguments passed to `command`.
+    pub args: Vec<String>,
+    /// Executable that starts the server over stdio.
+    pub command: String,
+    /// Extra environment variables set for the server process.
+    pub env: BTreeMap<String, String>,
+    /// Server name used in provider tool identifiers.
+    pub name: String,
+}
+
+/// Separator providers place between the server and tool name in tool
+/// identifiers such as `mcp__<server>__<tool>`.
+const TOOL_IDENTIFIER_SEPARATOR: &str = "__";
+
+/// Failure raised while parsing the `MCP Servers` setting.
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum McpConfigError {
+    /// The setting is not a JSON object of server entries.
+    #[error("Invalid MCP server JSON: {0}")]
+    Json(String),
+
+    /// The server name cannot be used in provider tool identifiers.
+    #[error("Invalid MCP server name `{0}`, expected letters, digits, `-`, or `_` without `__`")]
+    InvalidName(String),
+
+    /// The server entry has no command to launch.
+    #[error("MCP server `{0}` has no command")]
+    MissingCommand(String),
+}
+
+/// Raw JSON shape of one server entry.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct McpServerEntry {
+    #[serde(alias = "allowedTools", default)]
+    allowed_tools: Vec<String>,
+    #[serde(default)]
+    args: Vec<String>,
+    #[serde(default)]
+    command: String,
+    #[serde(default)]
+    env: BTreeMap<String, String>,
+}
+
+/// Parses the `MCP Servers` setting into servers sorted by name.
+///
+/// The value is a JSON object keyed by server name, optionally wrapped in
+/// `{"mcpServers": {...}}`. A blank value configures no servers.
+///
+/// # Errors
+/// Returns an error when the JSON is malformed, a server name is invalid, or
+/// a server has no command.
+pub fn parse_mcp_servers(content: &str) -> Result<Vec<McpServer>, McpConfigError> {
+    if content.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let mut value = serde_json::from_str::<serde_json::Value>(content)
+        .map_err(|error| McpConfigError::Json(error.to_string()))?;
+    if let Some(servers) = value.get_mut(MCP_SERVERS_KEY) {
+        value = servers.take();
+    }
+    let entries = serde_json::from_value::<BTreeMap<String, McpServerEntry>>(value)
+        .map_err(|error| McpConfigError::Json(error.to_string()))?;
+
+    entries
+        .into_iter()
+        .map(|(name, entry)| {
+            if !is_valid_server_name(&name) {
+                return Err(McpConfigError::InvalidName(name));
+            }
+            if entry.command.trim().is_empty() {
+                return Err(McpConfigError::MissingCommand(name));
+            }
+
+            Ok(McpServer {
+                allowed_tools: entry.allowed_tools,
+                args: entry.args,
+                command: entry.command,
+                env: entry.env,
+                name,
+            })
+        })
+        .collect()
+}
+
+/// Returns whether `name` can be embedded in provider tool identifiers.
+///
+/// Names must be non-empty, use only `[A-Za-z0-9_-]`, and avoid `__`, which
+/// would make `mcp__<server>__<tool>` identifiers ambiguous.
+fn is_valid_server_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(is_server_name_character)
+        && !name.contains(TOOL_IDENTIFIER_SEPARATOR)
+}
+
+/// Returns whether `character` may appear in an MCP server name.
+fn is_server_name_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '-' || character == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_mcp_servers_reads_entries_sorted_by_name() {
+        // Arrange
+        let content = r#"{
+            "mcpServers": {
+                "github": {
+                    "command": "npx",
+                    "args": ["-y", "@modelcontextprotocol/server-github"],
+                    "env": {"GITHUB_TOKEN": "token"},
+                    "allowedTools": ["create_issue"]
+                },
+                "docs": {"command": "docs-mcp"}
+            }
+        }"#;
+
+        // Act
+        let servers = parse_mcp_servers(content).expect("config should parse");
+
+        // Assert
+        assert_eq!(
+            servers,
+            vec![
+                McpServer {
+                    command: "docs-mcp".to_string(),
+                    name: "docs".to_string(),
+                    ..McpServer::default()
+                },
+                McpServer {
+                    allowed_tools: vec!["create_issue".to_string()],
+                    args: vec![
+                        "-y".to_string(),
+                        "@modelcontextprotocol/server-github".to_string(),
+                    ],
+                    command: "npx".to_string(),
+                    env: BTreeMap::from([("GITHUB_TOKEN".to_string(), "token".to_string())]),
+                    name: "github".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_mcp_servers_returns_empty_for_blank_content() {
+        // Arrange
+        let content = "  \n";
+
+        // Act
+        let servers = parse_mcp_servers(content);
+
+        // Assert
+        assert_eq!(servers, Ok(Vec::new()));
+    }
+
+    #[test]
+    fn test_parse_mcp_servers_rejects_invalid_entries() {
+        // Arrange
+        let invalid_name = r#"{"my server": {"command": "run"}}"#;
+        let separator_name = r#"{"git__hub": {"command": "run"}}"#;
+        let missing_command = r#"{"docs": {"args": ["--stdio"]}}"#;
+        let unknown_field = r#"{"docs": {"command": "run", "url": "http://localhost"}}"#;
+
+        // Act
+        let invalid_name = parse_mcp_servers(invalid_name);
+        let separator_name = parse_mcp_servers(separator_name);
+        let missing_command = parse_mcp_servers(missing_command);
+        let unknown_field = parse_mcp_servers(unknown_field);
+
+        // Assert
+        assert_eq!(
+            invalid_name,
+            Err(McpConfigError::InvalidName("my server".to_string()))
+        );
+        assert_eq!(
+            separator_name,
+            Err(McpConfigError::InvalidName("git__hub".to_string()))
+        );
+        assert_eq!(
+            missing_command,
+            Err(McpConfigError::MissingCommand("docs".to_string()))
+        );
+        assert!(matches!(unknown_field, Err(McpConfigError::Json(_))));
+    }
+}
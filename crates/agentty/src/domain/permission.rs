@@ -6,17 +6,31 @@ use std::str::FromStr;
 pub enum PermissionMode {
     #[default]
     AutoEdit,
+    /// Read-only turn that explores the worktree and proposes a plan without
+    /// editing files.
+    Plan,
 }
 
 impl PermissionMode {
     /// Returns the wire label used for persistence and display.
     pub fn label(self) -> &'static str {
-        "auto_edit"
+        match self {
+            Self::AutoEdit => "auto_edit",
+            Self::Plan => "plan",
+        }
     }
 
     /// Returns the user-facing label shown in the UI.
     pub fn display_label(self) -> &'static str {
-        "Auto Edit"
+        match self {
+            Self::AutoEdit => "Auto Edit",
+            Self::Plan => "Plan",
+        }
+    }
+
+    /// Returns whether turns in this mode must not modify the worktree.
+    pub fn is_read_only(self) -> bool {
+        matches!(self, Self::Plan)
     }
 }
 
@@ -32,6 +46,7 @@ impl FromStr for PermissionMode {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "auto_edit" => Ok(PermissionMode::AutoEdit),
+            "plan" => Ok(PermissionMode::Plan),
             _ => Err(format!("Unknown permission mode: {s}")),
         }
     }
@@ -53,6 +68,20 @@ mod tests {
         assert_eq!(parsed_permission_mode, Ok(PermissionMode::AutoEdit));
     }
 
+    #[test]
+    fn test_from_str_round_trips_plan_label() {
+        // Arrange
+        let permission_mode = PermissionMode::Plan;
+
+        // Act
+        let parsed_permission_mode = PermissionMode::from_str(permission_mode.label());
+
+        // Assert
+        assert_eq!(parsed_permission_mode, Ok(PermissionMode::Plan));
+        assert!(PermissionMode::Plan.is_read_only());
+        assert!(!PermissionMode::AutoEdit.is_read_only());
+    }
+
     #[test]
     fn test_from_str_rejects_removed_permission_modes() {
         // Arrange
@@ -0,0 +1,295 @@
+//! Plan-first session state shared by the worker, persistence, and UI.
+//!
+//! A plan-first session runs read-only turns until the agent proposes an
+//! ordered plan and the user approves it. Approved plans unlock write-enabled
+//! turns, which tick off steps as the agent reports them completed.
+
+use std::fmt::Write as _;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::permission::PermissionMode;
+
+/// Instruction prepended to prompts while a plan-first session waits for an
+/// approved plan.
+const PLANNING_INSTRUCTION: &str = "Plan-first mode: this turn is read-only. Explore the worktree \
+                                    without editing files, then return an ordered implementation \
+                                    plan in the top-level `plan` field. List the files each step \
+                                    touches.";
+
+/// Approval stage of one plan-first session.
+#[derive(Clone, Copy, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanStatus {
+    /// The next turn runs read-only and asks the agent for a plan.
+    #[default]
+    Planning,
+    /// The agent proposed steps that wait for user review.
+    AwaitingApproval,
+    /// The user approved the plan, so turns may edit the worktree.
+    Approved,
+}
+
+impl PlanStatus {
+    /// Returns the compact label shown next to the plan checklist.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Planning => "Planning",
+            Self::AwaitingApproval => "Awaiting approval",
+            Self::Approved => "Approved",
+        }
+    }
+}
+
+/// One ordered step of a session plan.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct PlanStep {
+    /// Worktree-relative files the step expects to touch.
+    #[serde(default)]
+    pub files: Vec<String>,
+    /// Whether a write-enabled turn reported this step as completed.
+    #[serde(default)]
+    pub is_done: bool,
+    /// Step description.
+    pub text: String,
+}
+
+impl PlanStep {
+    /// Creates one pending step.
+    pub fn new(text: impl Into<String>, files: Vec<String>) -> Self {
+        Self {
+            files,
+            is_done: false,
+            text: text.into(),
+        }
+    }
+}
+
+/// Plan attached to one plan-first session.
+#[derive(Clone, Debug, Default, Deserialize, Eq, PartialEq, Serialize)]
+pub struct SessionPlan {
+    /// Current approval stage.
+    pub status: PlanStatus,
+    /// Ordered plan steps, empty until the agent proposes a plan.
+    #[serde(default)]
+    pub steps: Vec<PlanStep>,
+}
+
+impl SessionPlan {
+    /// Creates an empty plan whose next turn asks the agent for steps.
+    pub fn planning() -> Self {
+        Self::default()
+    }
+
+    /// Parses one persisted plan payload.
+    ///
+    /// Returns `None` for blank payloads. Malformed payloads fall back to a
+    /// fresh planning plan, so a damaged column keeps the session read-only
+    /// instead of granting write access.
+    pub fn from_json(content: &str) -> Option<Self> {
+        if content.trim().is_empty() {
+            return None;
+        }
+
+        Some(serde_json::from_str(content).unwrap_or_else(|_| Self::planning()))
+    }
+
+    /// Serializes this plan for persistence.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// Returns the permission mode used for the next turn.
+    pub fn permission_mode(&self) -> PermissionMode {
+        if self.status == PlanStatus::Approved {
+            PermissionMode::AutoEdit
+        } else {
+            PermissionMode::Plan
+        }
+    }
+
+    /// Returns whether the plan has steps waiting for user review.
+    pub fn is_awaiting_approval(&self) -> bool {
+        self.status == PlanStatus::AwaitingApproval && !self.steps.is_empty()
+    }
+
+    /// Returns how many steps are completed.
+    pub fn completed_step_count(&self) -> usize {
+        self.steps.iter().filter(|step| step.is_done).count()
+    }
+
+    /// Replaces the steps with a newly proposed plan.
+    ///
+    /// Approved plans keep their steps so later turns cannot silently
+    /// rewrite what the user signed off on. Empty proposals are ignored.
+    pub fn propose_steps(&mut self, steps: Vec<PlanStep>) {
+        if self.status == PlanStatus::Approved || steps.is_empty() {
+            return;
+        }
+
+        self.steps = steps;
+        self.status = PlanStatus::AwaitingApproval;
+    }
+
+    /// Marks the given 1-based step numbers as completed.
+    ///
+    /// Only approved plans track progress; out-of-range numbers are ignored.
+    pub fn complete_steps(&mut self, step_numbers: &[u32]) {
+        if self.status != PlanStatus::Approved {
+            return;
+        }
+
+        for step_number in step_numbers {
+            let Some(step_index) = usize::try_from(*step_number)
+                .ok()
+                .and_then(|step_number| step_number.checked_sub(1))
+            else {
+                continue;
+            };
+            if let Some(step) = self.steps.get_mut(step_index) {
+                step.is_done = true;
+            }
+        }
+    }
+
+    /// Returns an approved plan holding `steps`.
+    pub fn approved(steps: Vec<PlanStep>) -> Self {
+        Self {
+            status: PlanStatus::Approved,
+            steps,
+        }
+    }
+
+    /// Renders the prompt that starts the first write-enabled turn.
+    pub fn approval_prompt(&self) -> String {
+        let mut prompt = String::from(
+            "The plan below is approved. Implement it step by step, then report finished steps as \
+             1-based numbers in `plan.completed_steps`.\n",
+        );
+        for (step_index, step) in self.steps.iter().enumerate() {
+            let _ = write!(prompt, "\n{}. {}", step_index + 1, step.text);
+            if !step.files.is_empty() {
+                let _ = write!(prompt, " ({})", step.files.join(", "));
+            }
+        }
+
+        prompt
+    }
+}
+
+/// Prepends the read-only planning instruction to one user prompt.
+pub fn planning_prompt(prompt: &str) -> String {
+    format!("{PLANNING_INSTRUCTION}\n\n{prompt}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_propose_steps_moves_plan_to_awaiting_approval() {
+        // Arrange
+        let mut plan = SessionPlan::planning();
+
+        // Act
+        plan.propose_steps(vec![PlanStep::new(
+            "Add the column",
+            vec!["migrations/001.sql".to_string()],
+        )]);
+
+        // Assert
+        assert_eq!(plan.status, PlanStatus::AwaitingApproval);
+        assert!(plan.is_awaiting_approval());
+        assert_eq!(plan.permission_mode(), PermissionMode::Plan);
+    }
+
+    #[test]
+    fn test_propose_steps_keeps_approved_steps() {
+        // Arrange
+        let mut plan = SessionPlan::approved(vec![PlanStep::new("Keep me", Vec::new())]);
+
+        // Act
+        plan.propose_steps(vec![PlanStep::new("Replace me", Vec::new())]);
+
+        // Assert
+        assert_eq!(plan.steps, vec![PlanStep::new("Keep me", Vec::new())]);
+        assert_eq!(plan.permission_mode(), PermissionMode::AutoEdit);
+    }
+
+    #[test]
+    fn test_complete_steps_ticks_only_valid_step_numbers() {
+        // Arrange
+        let mut plan = SessionPlan::approved(vec![
+            PlanStep::new("First", Vec::new()),
+            PlanStep::new("Second", Vec::new()),
+        ]);
+
+        // Act
+        plan.complete_steps(&[0, 2, 7]);
+
+        // Assert
+        assert!(!plan.steps[0].is_done);
+        assert!(plan.steps[1].is_done);
+        assert_eq!(plan.completed_step_count(), 1);
+    }
+
+    #[test]
+    fn test_complete_steps_ignores_unapproved_plans() {
+        // Arrange
+        let mut plan = SessionPlan::planning();
+        plan.propose_steps(vec![PlanStep::new("First", Vec::new())]);
+
+        // Act
+        plan.complete_steps(&[1]);
+
+        // Assert
+        assert_eq!(plan.completed_step_count(), 0);
+    }
+
+    #[test]
+    fn test_approval_prompt_lists_numbered_steps_with_files() {
+        // Arrange
+        let plan = SessionPlan::approved(vec![
+            PlanStep::new("Add the column", vec!["migrations/001.sql".to_string()]),
+            PlanStep::new("Update docs", Vec::new()),
+        ]);
+
+        // Act
+        let prompt = plan.approval_prompt();
+
+        // Assert
+        assert!(prompt.contains("plan.completed_steps"));
+        assert!(prompt.contains("\n1. Add the column (migrations/001.sql)"));
+        assert!(prompt.ends_with("\n2. Update docs"));
+    }
+
+    #[test]
+    fn test_from_json_round_trips_and_rejects_blank_content() {
+        // Arrange
+        let plan = SessionPlan::approved(vec![PlanStep::new("First", Vec::new())]);
+
+        // Act
+        let parsed_plan = SessionPlan::from_json(&plan.to_json());
+        let blank_plan = SessionPlan::from_json("  ");
+
+        // Assert
+        assert_eq!(parsed_plan, Some(plan));
+        assert_eq!(blank_plan, None);
+    }
+
+    #[test]
+    fn test_from_json_keeps_malformed_plans_read_only() {
+        // Arrange
+        let content = r#"{"status":"approved","steps":"#;
+
+        // Act
+        let plan = SessionPlan::from_json(content);
+
+        // Assert
+        assert_eq!(plan, Some(SessionPlan::planning()));
+        assert_eq!(
+            plan.map(|plan| plan.permission_mode()),
+            Some(PermissionMode::Plan)
+        );
+    }
+}
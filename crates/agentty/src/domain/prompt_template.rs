@@ -0,0 +1,394 @@
+//! Reusable prompt templates exposed as composer slash commands.
+
+use serde::Deserialize;
+
+use crate::domain::agent::{AgentModel, ReasoningLevel};
+
+/// Delimiter line that opens and closes the optional TOML front matter.
+const FRONT_MATTER_DELIMITER: &str = "+++";
+
+/// Where one prompt template was loaded from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PromptTemplateScope {
+    /// Loaded from the active project's prompt directory.
+    Project,
+    /// Loaded from the user's agentty home prompt directory.
+    User,
+}
+
+impl PromptTemplateScope {
+    /// Returns the compact badge shown in the slash menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::User => "user",
+        }
+    }
+}
+
+/// One parsed prompt template.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromptTemplate {
+    /// Template text with `{{parameter}}` placeholders.
+    pub body: String,
+    /// Optional one-line summary shown in the slash menu.
+    pub description: Option<String>,
+    /// Optional model pinned for the session when the template expands.
+    pub model: Option<AgentModel>,
+    /// Lowercase command name, used as `/<name>`.
+    pub name: String,
+    /// Placeholder names in order of first appearance in `body`.
+    pub parameters: Vec<String>,
+    /// Optional reasoning level pinned for the session when the template
+    /// expands.
+    pub reasoning_level: Option<ReasoningLevel>,
+    /// Directory the template was loaded from.
+    pub scope: PromptTemplateScope,
+}
+
+/// Failure raised while parsing one prompt template file.
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum PromptTemplateError {
+    /// The file name cannot be used as a slash command.
+    #[error("Invalid template name `{0}`, expected letters, digits, `-`, or `_`")]
+    InvalidName(String),
+
+    /// The front matter is not closed by a second `+++` line.
+    #[error("Unterminated front matter, expected a closing `+++` line")]
+    UnterminatedFrontMatter,
+
+    /// The front matter is not valid TOML for the template schema.
+    #[error("Invalid front matter: {0}")]
+    FrontMatter(String),
+
+    /// The front matter names an unknown model.
+    #[error("Unknown model `{0}`")]
+    UnknownModel(String),
+
+    /// The front matter names an unknown reasoning level.
+    #[error("Unknown reasoning level `{0}`, expected `low`, `medium`, `high`, or `xhigh`")]
+    UnknownReasoningLevel(String),
+}
+
+/// On-disk shape of the template front matter.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PromptTemplateFrontMatter {
+    description: Option<String>,
+    model: Option<String>,
+    reasoning: Option<String>,
+}
+
+impl PromptTemplate {
+    /// Parses one template file named `name` from its `content`.
+    ///
+    /// The content may start with `+++`-delimited TOML front matter holding
+    /// `description`, `model`, and `reasoning`; everything after it is the
+    /// template body.
+    ///
+    /// # Errors
+    /// Returns an error when the name is not a valid command name or the
+    /// front matter is malformed.
+    pub fn parse(
+        name: &str,
+        content: &str,
+        scope: PromptTemplateScope,
+    ) -> Result<Self, PromptTemplateError> {
+        if name.is_empty() || !name.chars().all(is_template_name_character) {
+            return Err(PromptTemplateError::InvalidName(name.to_string()));
+        }
+
+        let (front_matter, body) = split_front_matter(content)?;
+        let front_matter = toml::from_str::<PromptTemplateFrontMatter>(front_matter)
+            .map_err(|error| PromptTemplateError::FrontMatter(error.message().to_string()))?;
+        let model = front_matter
+            .model
+            .map(|model| {
+                model
+                    .parse::<AgentModel>()
+                    .map_err(|_| PromptTemplateError::UnknownModel(model))
+            })
+            .transpose()?;
+        let reasoning_level = front_matter
+            .reasoning
+            .map(|reasoning| {
+                reasoning
+                    .parse::<ReasoningLevel>()
+                    .map_err(|_| PromptTemplateError::UnknownReasoningLevel(reasoning))
+            })
+            .transpose()?;
+        let body = body.trim().to_string();
+
+        Ok(Self {
+            parameters: placeholder_names(&body),
+            body,
+            description: front_matter.description,
+            model,
+            name: name.to_lowercase(),
+            reasoning_level,
+            scope,
+        })
+    }
+
+    /// Returns the slash command that expands this template.
+    pub fn command(&self) -> String {
+        format!("/{}", self.name)
+    }
+
+    /// Returns the `<parameter>` usage hint shown in the slash menu.
+    pub fn parameter_hint(&self) -> String {
+        self.parameters
+            .iter()
+            .map(|parameter| format!("<{parameter}>"))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Expands the template body with positional `arguments`.
+    ///
+    /// Arguments split on whitespace, and double quotes group words into one
+    /// argument. Surplus arguments join into the last parameter, while
+    /// parameters without an argument keep their `{{parameter}}` placeholder
+    /// so they can be filled in the composer.
+    pub fn expand(&self, arguments: &str) -> String {
+        let mut values = split_arguments(arguments);
+        if values.len() > self.parameters.len() && !self.parameters.is_empty() {
+            let surplus = values.split_off(self.parameters.len() - 1);
+            values.push(surplus.join(" "));
+        }
+
+        let mut expanded = String::with_capacity(self.body.len());
+        let mut remaining = self.body.as_str();
+        while let Some((before, name, after)) = next_placeholder(remaining) {
+            expanded.push_str(before);
+            let value = self
+                .parameters
+                .iter()
+                .position(|parameter| parameter == name)
+                .and_then(|parameter_index| values.get(parameter_index));
+            if let Some(value) = value {
+                expanded.push_str(value);
+            } else {
+                expanded.push_str("{{");
+                expanded.push_str(name);
+                expanded.push_str("}}");
+            }
+            remaining = after;
+        }
+        expanded.push_str(remaining);
+
+        expanded
+    }
+}
+
+/// Returns whether `character` may appear in a template or parameter name.
+fn is_template_name_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '-' || character == '_'
+}
+
+/// Splits `content` into its front matter and body.
+fn split_front_matter(content: &str) -> Result<(&str, &str), PromptTemplateError> {
+    let Some(after_opening) = content
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(|rest| {
+            rest.strip_prefix('\n')
+                .or_else(|| rest.strip_prefix("\r\n"))
+        })
+    else {
+        return Ok(("", content));
+    };
+
+    let mut offset = 0;
+    for line in after_opening.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Ok((
+                &after_opening[..offset],
+                &after_opening[offset + line.len()..],
+            ));
+        }
+
+        offset += line.len();
+    }
+
+    Err(PromptTemplateError::UnterminatedFrontMatter)
+}
+
+/// Returns the text before the next `{{name}}` placeholder, its trimmed
+/// name, and the text after it.
+fn next_placeholder(text: &str) -> Option<(&str, &str, &str)> {
+    let mut search_start = 0;
+    while let Some(open_offset) = text[search_start..].find("{{") {
+        let open_index = search_start + open_offset;
+        let name_start = open_index + 2;
+        let close_index = name_start + text[name_start..].find("}}")?;
+        let name = text[name_start..close_index].trim();
+        if !name.is_empty() && name.chars().all(is_template_name_character) {
+            return Some((&text[..open_index], name, &text[close_index + 2..]));
+        }
+
+        search_start = name_start;
+    }
+
+    None
+}
+
+/// Returns the distinct placeholder names in `body` in order of first
+/// appearance.
+fn placeholder_names(body: &str) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+    let mut remaining = body;
+    while let Some((_, name, after)) = next_placeholder(remaining) {
+        if !names.iter().any(|known_name| known_name == name) {
+            names.push(name.to_string());
+        }
+        remaining = after;
+    }
+
+    names
+}
+
+/// Splits slash-command arguments on whitespace, keeping double-quoted
+/// groups together.
+fn split_arguments(arguments: &str) -> Vec<String> {
+    let mut values = Vec::new();
+    let mut current = String::new();
+    let mut is_quoted = false;
+    let mut has_value = false;
+
+    for character in arguments.chars() {
+        match character {
+            '"' => {
+                is_quoted = !is_quoted;
+                has_value = true;
+            }
+            character if character.is_whitespace() && !is_quoted => {
+                if has_value {
+                    values.push(std::mem::take(&mut current));
+                    has_value = false;
+                }
+            }
+            character => {
+                current.push(character);
+                has_value = true;
+            }
+        }
+    }
+
+    if has_value {
+        values.push(current);
+    }
+
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_front_matter_and_parameters() {
+        // Arrange
+        let content = "+++\ndescription = \"Write tests\"\nmodel = \"claude-opus-4-7\"\nreasoning \
+                       = \"high\"\n+++\nWrite tests for {{target}} covering {{ cases }}.\nKeep \
+                       {{target}} unchanged.\n";
+
+        // Act
+        let template = PromptTemplate::parse("Tests", content, PromptTemplateScope::Project)
+            .expect("template should parse");
+
+        // Assert
+        assert_eq!(template.name, "tests");
+        assert_eq!(template.command(), "/tests");
+        assert_eq!(template.description.as_deref(), Some("Write tests"));
+        assert_eq!(template.model, Some(AgentModel::ClaudeOpus47));
+        assert_eq!(template.reasoning_level, Some(ReasoningLevel::High));
+        assert_eq!(template.parameters, vec!["target", "cases"]);
+        assert_eq!(template.parameter_hint(), "<target> <cases>");
+    }
+
+    #[test]
+    fn test_parse_accepts_plain_body_without_front_matter() {
+        // Arrange
+        let content = "Address the open review comments.\n";
+
+        // Act
+        let template = PromptTemplate::parse("review", content, PromptTemplateScope::User)
+            .expect("template should parse");
+
+        // Assert
+        assert_eq!(template.body, "Address the open review comments.");
+        assert_eq!(template.description, None);
+        assert!(template.parameters.is_empty());
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_name_and_front_matter() {
+        // Arrange
+        let unknown_model = "+++\nmodel = \"gpt-0\"\n+++\nBody";
+        let unterminated = "+++\ndescription = \"Body\"\nBody";
+        let unknown_field = "+++\ntitle = \"Body\"\n+++\nBody";
+
+        // Act
+        let invalid_name = PromptTemplate::parse("two words", "Body", PromptTemplateScope::User);
+        let unknown_model =
+            PromptTemplate::parse("review", unknown_model, PromptTemplateScope::User);
+        let unterminated = PromptTemplate::parse("review", unterminated, PromptTemplateScope::User);
+        let unknown_field =
+            PromptTemplate::parse("review", unknown_field, PromptTemplateScope::User);
+
+        // Assert
+        assert_eq!(
+            invalid_name,
+            Err(PromptTemplateError::InvalidName("two words".to_string()))
+        );
+        assert_eq!(
+            unknown_model,
+            Err(PromptTemplateError::UnknownModel("gpt-0".to_string()))
+        );
+        assert_eq!(
+            unterminated,
+            Err(PromptTemplateError::UnterminatedFrontMatter)
+        );
+        assert!(matches!(
+            unknown_field,
+            Err(PromptTemplateError::FrontMatter(_))
+        ));
+    }
+
+    #[test]
+    fn test_expand_fills_positional_arguments_and_keeps_missing_placeholders() {
+        // Arrange
+        let template = PromptTemplate::parse(
+            "tests",
+            "Test {{target}} for {{cases}} in {{style}}.",
+            PromptTemplateScope::User,
+        )
+        .expect("template should parse");
+
+        // Act
+        let expanded = template.expand("src/parser.rs \"edge cases\"");
+
+        // Assert
+        assert_eq!(expanded, "Test src/parser.rs for edge cases in {{style}}.");
+    }
+
+    #[test]
+    fn test_expand_joins_surplus_arguments_into_last_parameter() {
+        // Arrange
+        let template = PromptTemplate::parse(
+            "changelog",
+            "Add a changelog entry for {{component}}: {{summary}}",
+            PromptTemplateScope::User,
+        )
+        .expect("template should parse");
+
+        // Act
+        let expanded = template.expand("parser faster   startup times");
+
+        // Assert
+        assert_eq!(
+            expanded,
+            "Add a changelog entry for parser: faster startup times"
+        );
+    }
+}
@@ -1,6 +1,6 @@
 use std::borrow::Borrow;
 use std::fmt;
-use std::ops::Deref;
+use std::ops::{Deref, Range};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Mutex};
@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 
 use super::agent::{AgentModel, ReasoningLevel};
+use super::plan::SessionPlan;
 use crate::infra::agent::protocol::QuestionItem;
 use crate::infra::channel::TurnPromptAttachment;
 
@@ -187,6 +188,16 @@ impl Status {
         matches!(self, Status::Review | Status::AgentReview)
     }
 
+    /// Returns whether the session is still running agent or git work, so
+    /// callers waiting for the next user-actionable state should keep
+    /// waiting.
+    pub fn is_busy(self) -> bool {
+        matches!(
+            self,
+            Status::InProgress | Status::Queued | Status::Rebasing | Status::Merging
+        )
+    }
+
     /// Returns whether a transition to `next` is valid.
     ///
     /// Draft-session-only guards still live on [`Session`] methods, so
@@ -343,6 +354,12 @@ impl FromStr for SessionSize {
     }
 }
 
+/// Label that marks forge issues as ready to be picked up by agent sessions.
+pub use ag_forge::AGENT_ISSUE_LABEL;
+/// Forge issue with description and comments used to seed one session.
+pub use ag_forge::ForgeIssue;
+/// Normalized summary for one open forge issue listed by the issue picker.
+pub use ag_forge::ForgeIssueSummary;
 /// Supported forge families for persisted session review-request links.
 pub use ag_forge::ForgeKind;
 /// Normalized remote lifecycle state for one linked review request.
@@ -362,6 +379,23 @@ pub struct ReviewRequest {
     pub summary: ReviewRequestSummary,
 }
 
+/// Forge issue that seeded one session through the issue picker.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionIssue {
+    /// Provider display id such as `#123`.
+    pub display_id: String,
+    /// Browser-openable issue URL.
+    pub web_url: String,
+}
+
+impl SessionIssue {
+    /// Returns the closing keyword line appended to published review
+    /// requests so merging them also closes the issue.
+    pub fn closing_reference(&self) -> String {
+        format!("Closes {}", self.display_id)
+    }
+}
+
 /// Session-view action currently available for manual session-branch
 /// publication.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -467,6 +501,101 @@ impl SessionFollowUpTask {
         FollowUpTaskAction::Launch
     }
 }
+
+/// Author of one persisted transcript message.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionMessageRole {
+    /// Agent answer text shown for a completed turn.
+    Assistant,
+    /// App-generated notices such as commit results and turn errors.
+    System,
+    /// User prompt block, including clarification answers.
+    User,
+}
+
+impl SessionMessageRole {
+    /// Returns the persisted role label stored in `session_message.role`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Assistant => "assistant",
+            Self::System => "system",
+            Self::User => "user",
+        }
+    }
+}
+
+/// One prompt-and-answer exchange within a session transcript.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionTurn {
+    /// One-based turn number within the session.
+    pub number: u32,
+    /// User prompt text that started the turn.
+    pub prompt: String,
+    /// Names of the `SKILL.md` skills attached to the turn's prompt.
+    pub skills: Vec<String>,
+    /// Byte offset in [`Session::output`] where the turn's prompt block
+    /// starts.
+    pub transcript_start: usize,
+}
+
+/// Session field that produced one global search hit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SessionSearchSource {
+    /// User prompt block within one turn.
+    Prompt,
+    /// Latest structured turn summary.
+    Summary,
+    /// Session display title.
+    Title,
+    /// Agent answer or app notice within one turn.
+    Transcript,
+}
+
+impl SessionSearchSource {
+    /// Parses the source label returned by the session search query.
+    ///
+    /// Unknown labels fall back to [`SessionSearchSource::Transcript`].
+    pub fn from_persisted(value: &str) -> Self {
+        match value {
+            "prompt" => Self::Prompt,
+            "summary" => Self::Summary,
+            "title" => Self::Title,
+            _ => Self::Transcript,
+        }
+    }
+
+    /// Returns the short label shown next to search hits.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Prompt => "prompt",
+            Self::Summary => "summary",
+            Self::Title => "title",
+            Self::Transcript => "transcript",
+        }
+    }
+}
+
+/// Best-ranked full-text match for one session across all projects.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SessionSearchHit {
+    /// Project that owns the matched session, when linked.
+    pub project_id: Option<i64>,
+    /// Project label shown next to the hit.
+    pub project_name: String,
+    /// Matched session identifier.
+    pub session_id: SessionId,
+    /// Single-line excerpt around the match.
+    pub snippet: String,
+    /// Byte ranges inside `snippet` covering matched terms.
+    pub snippet_match_ranges: Vec<Range<usize>>,
+    /// Session field that matched.
+    pub source: SessionSearchSource,
+    /// Session display title.
+    pub title: String,
+    /// Transcript turn containing the match, for prompt and transcript hits.
+    pub turn_number: Option<u32>,
+}
+
 /// In-memory snapshot of one persisted session row used by the UI and app
 /// orchestration layers.
 pub struct Session {
@@ -492,10 +621,16 @@ pub struct Session {
     /// Whether the session was created through the explicit draft workflow
     /// from the sessions list.
     pub is_draft: bool,
+    /// Forge issue this session was started from, when picked from the issue
+    /// list.
+    pub issue: Option<SessionIssue>,
     /// Agent model selected for this session.
     pub model: AgentModel,
     /// Captured output transcript.
     pub output: String,
+    /// Plan-first state, or `None` for sessions that edit without an approved
+    /// plan.
+    pub plan: Option<SessionPlan>,
     /// Human-readable project name associated with the session.
     pub project_name: String,
     /// Initial user prompt used to create the session.
@@ -527,6 +662,8 @@ pub struct Session {
     pub summary: Option<String>,
     /// Optional explicit session title.
     pub title: Option<String>,
+    /// Persisted prompt turns in transcript order.
+    pub turns: Vec<SessionTurn>,
     /// Last update timestamp (Unix seconds).
     pub updated_at: i64,
 }
@@ -726,8 +863,10 @@ pub(crate) mod tests {
                     in_progress_started_at: None,
                     in_progress_total_seconds: 0,
                     is_draft: false,
+                    issue: None,
                     model: AgentModel::Gemini3FlashPreview,
                     output: String::new(),
+                    plan: None,
                     project_name: "project".to_string(),
                     prompt: String::new(),
                     reasoning_level_override: None,
@@ -740,6 +879,7 @@ pub(crate) mod tests {
                     status: Status::Review,
                     summary: None,
                     title: None,
+                    turns: Vec::new(),
                     updated_at: 0,
                 },
             }
@@ -973,6 +1113,29 @@ pub(crate) mod tests {
         assert!(allows_review_actions);
     }
 
+    #[test]
+    fn test_status_is_busy_only_for_running_work() {
+        // Arrange
+        let statuses = Status::ALL;
+
+        // Act
+        let busy_statuses = statuses
+            .into_iter()
+            .filter(|status| status.is_busy())
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(
+            busy_statuses,
+            vec![
+                Status::InProgress,
+                Status::Queued,
+                Status::Rebasing,
+                Status::Merging
+            ]
+        );
+    }
+
     #[test]
     fn test_status_transition_new_to_canceled() {
         // Arrange
@@ -1139,8 +1302,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1153,6 +1318,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::Review,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
@@ -1176,8 +1342,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1190,6 +1358,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::AgentReview,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
@@ -1213,8 +1382,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: Some(60),
             in_progress_total_seconds: 120,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1227,6 +1398,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::InProgress,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
@@ -1250,8 +1422,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: None,
             in_progress_total_seconds: 180,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1264,6 +1438,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::Done,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
@@ -1287,8 +1462,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: Some(120),
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1301,6 +1478,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::InProgress,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
@@ -1324,8 +1502,10 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             in_progress_started_at: Some(200),
             in_progress_total_seconds: 90,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1338,6 +1518,7 @@ diff --git a/src/lib.rs b/src/lib.rs\n@@ -1 +1,2 @@\n-old line\n+new line\n+anot
             status: Status::InProgress,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         };
 
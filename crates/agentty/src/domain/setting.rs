@@ -20,8 +20,30 @@ pub(crate) enum SettingName {
     IncludeCoauthoredByAgentty,
     /// Persists the configured open-command override.
     OpenCommand,
+    /// Persists the project instructions Agentty injects into every agent
+    /// bootstrap.
+    ProjectInstructions,
+    /// Persists the project MCP server list passed to every provider launch.
+    McpServers,
     /// Persists whether the last used model should become the default.
     LastUsedModelAsDefault,
+    /// Persists the shell hook run for session notifications.
+    NotificationCommand,
+    /// Persists whether sessions reaching `Done` notify.
+    NotifyOnDone,
+    /// Persists whether sessions reaching `Question` notify.
+    NotifyOnQuestion,
+    /// Persists whether sessions reaching `Review` notify.
+    NotifyOnReview,
+    /// Persists the terminal escape-sequence notification channel.
+    TerminalNotification,
+    /// Persists whether notifications appear in the tmux status line.
+    TmuxNotification,
+    /// Persists the global color theme selection.
+    Theme,
+    /// Persists whether wide terminals show the session list and the selected
+    /// session side by side.
+    SplitLayout,
 }
 
 impl SettingName {
@@ -35,7 +57,17 @@ impl SettingName {
             Self::DefaultSmartModel => "DefaultSmartModel",
             Self::IncludeCoauthoredByAgentty => "IncludeCoauthoredByAgentty",
             Self::OpenCommand => "OpenCommand",
+            Self::ProjectInstructions => "ProjectInstructions",
+            Self::McpServers => "McpServers",
             Self::LastUsedModelAsDefault => "LastUsedModelAsDefault",
+            Self::NotificationCommand => "NotificationCommand",
+            Self::NotifyOnDone => "NotifyOnDone",
+            Self::NotifyOnQuestion => "NotifyOnQuestion",
+            Self::NotifyOnReview => "NotifyOnReview",
+            Self::TerminalNotification => "TerminalNotification",
+            Self::TmuxNotification => "TmuxNotification",
+            Self::Theme => "Theme",
+            Self::SplitLayout => "SplitLayout",
         }
     }
 }
@@ -65,10 +97,20 @@ mod tests {
                 "IncludeCoauthoredByAgentty",
             ),
             (SettingName::OpenCommand, "OpenCommand"),
+            (SettingName::ProjectInstructions, "ProjectInstructions"),
+            (SettingName::McpServers, "McpServers"),
             (
                 SettingName::LastUsedModelAsDefault,
                 "LastUsedModelAsDefault",
             ),
+            (SettingName::NotificationCommand, "NotificationCommand"),
+            (SettingName::NotifyOnDone, "NotifyOnDone"),
+            (SettingName::NotifyOnQuestion, "NotifyOnQuestion"),
+            (SettingName::NotifyOnReview, "NotifyOnReview"),
+            (SettingName::TerminalNotification, "TerminalNotification"),
+            (SettingName::TmuxNotification, "TmuxNotification"),
+            (SettingName::Theme, "Theme"),
+            (SettingName::SplitLayout, "SplitLayout"),
         ];
 
         // Act & Assert
@@ -89,7 +131,11 @@ mod tests {
             SettingName::DefaultSmartModel,
             SettingName::IncludeCoauthoredByAgentty,
             SettingName::OpenCommand,
+            SettingName::ProjectInstructions,
+            SettingName::McpServers,
             SettingName::LastUsedModelAsDefault,
+            SettingName::NotificationCommand,
+            SettingName::TerminalNotification,
         ];
 
         // Act & Assert
@@ -0,0 +1,271 @@
+//! Reusable agent skills defined as `SKILL.md` files and attached to prompts.
+
+use std::path::PathBuf;
+
+/// Delimiter line that opens and closes the YAML front matter.
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+/// Where one skill was loaded from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SkillScope {
+    /// Loaded from the active project's skill directories.
+    Project,
+    /// Loaded from the user's agentty home skill directory.
+    User,
+}
+
+impl SkillScope {
+    /// Returns the compact badge shown in the slash menu.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Project => "project",
+            Self::User => "user",
+        }
+    }
+}
+
+/// One parsed `SKILL.md` skill.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Skill {
+    /// Skill instructions after the front matter.
+    pub body: String,
+    /// Optional one-line summary shown in the slash menu.
+    pub description: Option<String>,
+    /// Lowercase skill name, used as `/skill <name>`.
+    pub name: String,
+    /// Path of the `SKILL.md` file the skill was loaded from.
+    pub path: PathBuf,
+    /// Directory the skill was loaded from.
+    pub scope: SkillScope,
+}
+
+/// Failure raised while parsing one `SKILL.md` file.
+#[derive(Debug, Eq, PartialEq, thiserror::Error)]
+pub enum SkillError {
+    /// The skill name cannot be used as a directive argument.
+    #[error("Invalid skill name `{0}`, expected letters, digits, `-`, or `_`")]
+    InvalidName(String),
+
+    /// The front matter is not closed by a second `---` line.
+    #[error("Unterminated front matter, expected a closing `---` line")]
+    UnterminatedFrontMatter,
+
+    /// The skill has no instructions after its front matter.
+    #[error("Skill `{0}` has no instructions")]
+    EmptyBody(String),
+}
+
+impl Skill {
+    /// Parses one `SKILL.md` file stored in the skill directory
+    /// `directory_name`.
+    ///
+    /// The content may start with `---`-delimited front matter. Only the
+    /// single-line `name` and `description` keys are read; other keys are
+    /// ignored. A missing `name` falls back to `directory_name`.
+    ///
+    /// # Errors
+    /// Returns an error when the name is not a valid skill name, the front
+    /// matter is not closed, or the body is empty.
+    pub fn parse(
+        directory_name: &str,
+        content: &str,
+        path: PathBuf,
+        scope: SkillScope,
+    ) -> Result<Self, SkillError> {
+        let (front_matter, body) = split_front_matter(content)?;
+        let name = front_matter_value(front_matter, "name")
+            .unwrap_or(directory_name)
+            .to_lowercase();
+        if name.is_empty() || !name.chars().all(is_skill_name_character) {
+            return Err(SkillError::InvalidName(name));
+        }
+
+        let body = body.trim().to_string();
+        if body.is_empty() {
+            return Err(SkillError::EmptyBody(name));
+        }
+
+        Ok(Self {
+            body,
+            description: front_matter_value(front_matter, "description").map(str::to_string),
+            name,
+            path,
+            scope,
+        })
+    }
+
+    /// Returns the directive that attaches this skill.
+    pub fn command(&self) -> String {
+        format!("/skill {}", self.name)
+    }
+}
+
+/// Returns the skill named `query`, or else the first skill whose name starts
+/// with it.
+///
+/// The prefix fallback matches the first row the slash menu highlights for
+/// the same query.
+pub fn find_skill<'a>(skills: &'a [Skill], query: &str) -> Option<&'a Skill> {
+    let query = query.trim().to_lowercase();
+
+    skills
+        .iter()
+        .find(|skill| skill.name == query)
+        .or_else(|| skills.iter().find(|skill| skill.name.starts_with(&query)))
+}
+
+/// Returns whether `character` may appear in a skill name.
+fn is_skill_name_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '-' || character == '_'
+}
+
+/// Splits `content` into its front matter and body.
+fn split_front_matter(content: &str) -> Result<(&str, &str), SkillError> {
+    let Some(after_opening) = content
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(|rest| {
+            rest.strip_prefix('\n')
+                .or_else(|| rest.strip_prefix("\r\n"))
+        })
+    else {
+        return Ok(("", content));
+    };
+
+    let mut offset = 0;
+    for line in after_opening.split_inclusive('\n') {
+        if line.trim_end() == FRONT_MATTER_DELIMITER {
+            return Ok((
+                &after_opening[..offset],
+                &after_opening[offset + line.len()..],
+            ));
+        }
+
+        offset += line.len();
+    }
+
+    Err(SkillError::UnterminatedFrontMatter)
+}
+
+/// Returns the unquoted value of one top-level `key: value` front matter
+/// line, ignoring empty values.
+fn front_matter_value<'a>(front_matter: &'a str, key: &str) -> Option<&'a str> {
+    front_matter.lines().find_map(|line| {
+        let (line_key, value) = line.split_once(':')?;
+        if line_key != key {
+            return None;
+        }
+
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|value| value.strip_suffix('"'))
+            .or_else(|| {
+                value
+                    .strip_prefix('\'')
+                    .and_then(|value| value.strip_suffix('\''))
+            })
+            .unwrap_or(value);
+
+        (!value.is_empty()).then_some(value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reads_front_matter_and_body() {
+        // Arrange
+        let content = "---\nname: Review\ndescription: \"Review the diff: bugs first\"\nlicense: \
+                       MIT\n---\n\n# Review Skill\n\nCheck every changed file.\n";
+
+        // Act
+        let skill = Skill::parse(
+            "review-dir",
+            content,
+            PathBuf::from("/repo/skills/review-dir/SKILL.md"),
+            SkillScope::Project,
+        )
+        .expect("skill should parse");
+
+        // Assert
+        assert_eq!(skill.name, "review");
+        assert_eq!(skill.command(), "/skill review");
+        assert_eq!(
+            skill.description.as_deref(),
+            Some("Review the diff: bugs first")
+        );
+        assert_eq!(skill.body, "# Review Skill\n\nCheck every changed file.");
+    }
+
+    #[test]
+    fn test_parse_falls_back_to_directory_name_without_front_matter() {
+        // Arrange
+        let content = "Audit dependencies.\n";
+
+        // Act
+        let skill = Skill::parse(
+            "security-audit",
+            content,
+            PathBuf::from("SKILL.md"),
+            SkillScope::User,
+        )
+        .expect("skill should parse");
+
+        // Assert
+        assert_eq!(skill.name, "security-audit");
+        assert_eq!(skill.description, None);
+        assert_eq!(skill.body, "Audit dependencies.");
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_name_unterminated_front_matter_and_empty_body() {
+        // Arrange
+        let path = PathBuf::from("SKILL.md");
+
+        // Act
+        let invalid_name = Skill::parse("two words", "Body", path.clone(), SkillScope::User);
+        let unterminated = Skill::parse(
+            "review",
+            "---\nname: review\nBody",
+            path.clone(),
+            SkillScope::User,
+        );
+        let empty_body = Skill::parse(
+            "review",
+            "---\nname: review\n---\n\n",
+            path,
+            SkillScope::User,
+        );
+
+        // Assert
+        assert_eq!(
+            invalid_name,
+            Err(SkillError::InvalidName("two words".to_string()))
+        );
+        assert_eq!(unterminated, Err(SkillError::UnterminatedFrontMatter));
+        assert_eq!(empty_body, Err(SkillError::EmptyBody("review".to_string())));
+    }
+
+    #[test]
+    fn test_find_skill_prefers_exact_name_then_first_prefix_match() {
+        // Arrange
+        let skills = ["release", "review", "rev"]
+            .map(|name| {
+                Skill::parse(name, "Body", PathBuf::from("SKILL.md"), SkillScope::User)
+                    .expect("skill should parse")
+            })
+            .to_vec();
+
+        // Act
+        let exact = find_skill(&skills, "rev");
+        let prefix = find_skill(&skills, "Re");
+        let missing = find_skill(&skills, "deploy");
+
+        // Assert
+        assert_eq!(exact.map(|skill| skill.name.as_str()), Some("rev"));
+        assert_eq!(prefix.map(|skill| skill.name.as_str()), Some("release"));
+        assert_eq!(missing, None);
+    }
+}
@@ -7,18 +7,32 @@ pub mod app_server;
 pub mod app_server_router;
 /// Shared stdio JSON-RPC transport for app-server protocols.
 pub mod app_server_transport;
+/// Default-browser boundary for opening review-request links.
+pub mod browser;
 /// Provider-agnostic agent channel abstraction for session turn execution.
 pub mod channel;
+/// Local Unix-socket JSON-RPC control API for a running instance.
+pub mod control_socket;
 pub mod db;
 /// Gitignore-aware file indexing and fuzzy path filtering.
 pub mod file_index;
 /// Filesystem trait boundary used by app orchestration.
 pub mod fs;
 pub mod git;
+/// Shared loader for named markdown files in user and project directories.
+pub(crate) mod markdown_dir;
+/// Terminal and hook-command boundary for session notifications.
+pub mod notification;
 /// Process-management utilities for agent subprocess lifecycle.
 pub(crate) mod process;
 /// Startup project-discovery boundary for home-directory repository scans.
 pub mod project_discovery;
+/// Filesystem loader for user- and project-level prompt templates.
+pub mod prompt_template;
+/// Filesystem loader for user- and project-level `SKILL.md` skills.
+pub mod skill;
+/// Ctags-style symbol indexing and fuzzy filtering for `@#` mentions.
+pub mod symbol_index;
 /// Tmux process boundary used by app orchestration.
 pub mod tmux;
 pub mod version;
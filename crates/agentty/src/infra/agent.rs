@@ -25,7 +25,7 @@ pub use availability::{
 };
 pub use backend::{AgentBackend, AgentBackendError, AgentTransport, BuildCommandRequest};
 pub(crate) use instruction::{
-    InstructionDeliveryMode, normalize_instruction_conversation_id,
+    InstructionDeliveryMode, instruction_bootstrap_marker, normalize_instruction_conversation_id,
     plan_app_server_instruction_delivery,
 };
 pub(crate) use prompt::{PromptPreparationRequest, prepare_prompt_text};
@@ -33,7 +33,8 @@ pub(crate) use protocol::AgentResponse;
 pub use protocol::ProtocolRequestProfile;
 pub(crate) use provider::{
     build_command_stdin_payload, create_app_server_client, is_app_server_thought_chunk,
-    parse_stream_output_line, parse_turn_response, provider_kind_for_model,
+    parse_stream_output_line, parse_turn_response, provider_kind_for_model, remove_command_files,
+    skill_prompt_mode,
 };
 pub use provider::{create_backend, parse_response, transport_mode};
 pub use response_parser::ParsedResponse;
@@ -32,6 +32,7 @@ impl RealCodexAppServerClient {
     ) -> Result<AppServerTurnResponse, AppServerError> {
         let stream_tx = stream_tx.clone();
         let reasoning_level = request.reasoning_level;
+        let permission_mode = request.permission_mode;
 
         app_server::run_turn_with_restart_retry(
             sessions,
@@ -64,6 +65,7 @@ impl RealCodexAppServerClient {
                         &mut runtime.state,
                         prompt,
                         reasoning_level,
+                        permission_mode,
                         stream_tx,
                     )
                     .await
@@ -120,8 +122,13 @@ struct CodexSessionRuntime {
 
 impl CodexSessionRuntime {
     /// Returns whether the stored runtime configuration matches one request.
+    ///
+    /// A changed project MCP server list restarts the runtime so the new
+    /// thread registers the current servers.
     fn matches_request(&self, request: &AppServerTurnRequest) -> bool {
-        self.state.folder == request.folder && self.state.model == request.model
+        self.state.folder == request.folder
+            && self.state.model == request.model
+            && self.state.mcp_servers == request.mcp_servers
     }
 
     /// Returns whether the runtime was bootstrapped by resuming stored thread
@@ -153,6 +160,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::{AgentModel, ReasoningLevel};
+    use crate::domain::permission::PermissionMode;
     use crate::infra::agent::app_server::codex::{
         MockCodexRuntimeTransport, lifecycle, policy, stream_parser, usage,
     };
@@ -162,7 +170,8 @@ mod tests {
         let folder = std::env::temp_dir().join(format!(
             "agentty-codex-runtime-state-{thread_id}-{latest_input_tokens}"
         ));
-        let mut state = CodexRuntimeState::new(folder, AgentModel::Gpt54.as_str().to_string());
+        let mut state =
+            CodexRuntimeState::new(folder, AgentModel::Gpt54.as_str().to_string(), Vec::new());
         state.thread_id = thread_id.to_string();
         state.latest_input_tokens = latest_input_tokens;
 
@@ -288,7 +297,9 @@ mod tests {
             &mut transport,
             folder.path(),
             AgentModel::Gpt54.as_str(),
+            PermissionMode::default(),
             ReasoningLevel::default(),
+            &[],
         )
         .await;
 
@@ -378,7 +389,9 @@ mod tests {
             folder.path(),
             AgentModel::Gpt54.as_str(),
             Some("thread-existing"),
+            PermissionMode::default(),
             ReasoningLevel::default(),
+            &[],
         )
         .await;
 
@@ -475,6 +488,7 @@ mod tests {
             &mut state,
             "Implement the task",
             ReasoningLevel::default(),
+            PermissionMode::default(),
             stream_tx,
         )
         .await;
@@ -627,8 +641,9 @@ mod tests {
         });
 
         // Act
-        let approval_response = policy::build_pre_action_approval_response(&response_value)
-            .expect("approval response should be generated");
+        let approval_response =
+            policy::build_pre_action_approval_response(&response_value, PermissionMode::AutoEdit)
+                .expect("approval response should be generated");
 
         // Assert
         assert_eq!(
@@ -642,6 +657,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_pre_action_approval_response_declines_requests_in_plan_mode() {
+        // Arrange
+        let response_value = serde_json::json!({
+            "id": "approval-1",
+            "method": "item/fileChange/requestApproval",
+        });
+
+        // Act
+        let approval_response =
+            policy::build_pre_action_approval_response(&response_value, PermissionMode::Plan)
+                .expect("approval response should be generated");
+
+        // Assert
+        assert_eq!(
+            approval_response,
+            serde_json::json!({
+                "id": "approval-1",
+                "result": {
+                    "decision": "decline"
+                }
+            })
+        );
+    }
+
     #[test]
     fn parse_turn_completed_returns_success_for_completed_turn() {
         // Arrange
@@ -672,6 +712,7 @@ mod tests {
             folder.path(),
             AgentModel::Gpt54.as_str(),
             ReasoningLevel::default(),
+            PermissionMode::default(),
             "thread-123",
             "Implement the task",
             "turn-start-1",
@@ -687,4 +728,35 @@ mod tests {
             Some("object")
         );
     }
+    #[test]
+    fn build_turn_start_payload_uses_read_only_sandbox_in_plan_mode() {
+        // Arrange
+        let folder = tempdir().expect("temporary folder should be created");
+
+        // Act
+        let payload = lifecycle::build_turn_start_payload(
+            folder.path(),
+            AgentModel::Gpt54.as_str(),
+            ReasoningLevel::default(),
+            PermissionMode::Plan,
+            "thread-123",
+            "Plan the task",
+            "turn-start-1",
+        );
+
+        // Assert
+        assert_eq!(
+            payload
+                .get("params")
+                .and_then(|params| params.get("sandboxPolicy")),
+            Some(&serde_json::json!({"type": "readOnly"}))
+        );
+        assert_eq!(
+            payload
+                .get("params")
+                .and_then(|params| params.get("approvalPolicy"))
+                .and_then(Value::as_str),
+            Some("never")
+        );
+    }
 }
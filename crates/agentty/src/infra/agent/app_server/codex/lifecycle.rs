@@ -9,6 +9,8 @@ use tokio::sync::mpsc;
 use super::transport::{CodexRuntimeTransport, CodexStdioTransport};
 use super::{policy, stream_parser, usage};
 use crate::domain::agent::{AgentKind, ReasoningLevel};
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::agent;
 use crate::infra::agent::protocol::agent_response_output_schema;
 use crate::infra::app_server::{AppServerError, AppServerStreamEvent, AppServerTurnRequest};
@@ -21,6 +23,8 @@ pub(super) struct CodexRuntimeState {
     pub(super) folder: PathBuf,
     /// Most recent input token count reported by the app-server.
     pub(super) latest_input_tokens: u64,
+    /// Project MCP servers registered with the active thread.
+    pub(super) mcp_servers: Vec<McpServer>,
     /// Selected Codex model identifier.
     pub(super) model: String,
     /// Whether startup restored provider-native context.
@@ -31,10 +35,11 @@ pub(super) struct CodexRuntimeState {
 
 impl CodexRuntimeState {
     /// Creates runtime state for one pending session bootstrap.
-    pub(super) fn new(folder: PathBuf, model: String) -> Self {
+    pub(super) fn new(folder: PathBuf, model: String, mcp_servers: Vec<McpServer>) -> Self {
         Self {
             folder,
             latest_input_tokens: 0,
+            mcp_servers,
             model,
             restored_context: false,
             thread_id: String::new(),
@@ -59,6 +64,9 @@ pub(super) async fn start_runtime(
         .build_command(agent::BuildCommandRequest {
             attachments: &[],
             folder: request.folder.as_path(),
+            mcp_servers: &request.mcp_servers,
+            permission_mode: request.permission_mode,
+            project_instructions: None,
             prompt: "",
             request_kind: &request_kind,
             model: &request.model,
@@ -89,7 +97,11 @@ pub(super) async fn start_runtime_with_built_command(
     let (mut child, stdin, stdout) =
         app_server_transport::spawn_runtime_command(command, "codex app-server")?;
     let mut transport = CodexStdioTransport::new(stdin, stdout);
-    let mut state = CodexRuntimeState::new(request.folder.clone(), request.model.clone());
+    let mut state = CodexRuntimeState::new(
+        request.folder.clone(),
+        request.model.clone(),
+        request.mcp_servers.clone(),
+    );
 
     let bootstrap_result = async {
         initialize_runtime(&mut transport).await?;
@@ -98,7 +110,9 @@ pub(super) async fn start_runtime_with_built_command(
             &state.folder,
             &state.model,
             request.provider_conversation_id.as_deref(),
+            request.permission_mode,
             request.reasoning_level,
+            &state.mcp_servers,
         )
         .await
     }
@@ -163,16 +177,33 @@ pub(super) async fn start_or_resume_thread<Transport: CodexRuntimeTransport>(
     folder: &Path,
     model: &str,
     provider_conversation_id: Option<&str>,
+    permission_mode: PermissionMode,
     reasoning_level: ReasoningLevel,
+    mcp_servers: &[McpServer],
 ) -> Result<(String, bool), AppServerError> {
     if let Some(provider_conversation_id) = provider_conversation_id
-        && let Ok(thread_id) =
-            resume_thread(transport, provider_conversation_id, model, reasoning_level).await
+        && let Ok(thread_id) = resume_thread(
+            transport,
+            provider_conversation_id,
+            model,
+            permission_mode,
+            reasoning_level,
+            mcp_servers,
+        )
+        .await
     {
         return Ok((thread_id, true));
     }
 
-    let thread_id = start_thread(transport, folder, model, reasoning_level).await?;
+    let thread_id = start_thread(
+        transport,
+        folder,
+        model,
+        permission_mode,
+        reasoning_level,
+        mcp_servers,
+    )
+    .await?;
 
     Ok((thread_id, false))
 }
@@ -182,11 +213,19 @@ pub(super) async fn start_thread<Transport: CodexRuntimeTransport>(
     transport: &mut Transport,
     folder: &Path,
     model: &str,
+    permission_mode: PermissionMode,
     reasoning_level: ReasoningLevel,
+    mcp_servers: &[McpServer],
 ) -> Result<String, AppServerError> {
     let thread_start_id = format!("thread-start-{}", uuid::Uuid::new_v4());
-    let thread_start_payload =
-        build_thread_start_payload(folder, model, reasoning_level, &thread_start_id);
+    let thread_start_payload = build_thread_start_payload(
+        folder,
+        model,
+        permission_mode,
+        reasoning_level,
+        mcp_servers,
+        &thread_start_id,
+    );
 
     transport.write_json_line(thread_start_payload).await?;
     let response_line = transport.wait_for_response_line(thread_start_id).await?;
@@ -214,11 +253,19 @@ pub(super) async fn resume_thread<Transport: CodexRuntimeTransport>(
     transport: &mut Transport,
     thread_id: &str,
     model: &str,
+    permission_mode: PermissionMode,
     reasoning_level: ReasoningLevel,
+    mcp_servers: &[McpServer],
 ) -> Result<String, AppServerError> {
     let thread_resume_request_id = format!("thread-resume-{}", uuid::Uuid::new_v4());
-    let thread_resume_payload =
-        build_thread_resume_payload(&thread_resume_request_id, thread_id, model, reasoning_level);
+    let thread_resume_payload = build_thread_resume_payload(
+        &thread_resume_request_id,
+        thread_id,
+        model,
+        permission_mode,
+        reasoning_level,
+        mcp_servers,
+    );
 
     transport.write_json_line(thread_resume_payload).await?;
     let response_line = transport
@@ -245,10 +292,15 @@ pub(super) async fn resume_thread<Transport: CodexRuntimeTransport>(
 }
 
 /// Builds one `thread/start` request payload for a runtime folder.
+///
+/// Thread-level approval and sandbox defaults follow `permission_mode`, so a
+/// plan-mode session starts read-only before any turn-level override.
 pub(super) fn build_thread_start_payload(
     folder: &Path,
     model: &str,
+    permission_mode: PermissionMode,
     reasoning_level: ReasoningLevel,
+    mcp_servers: &[McpServer],
     thread_start_id: &str,
 ) -> Value {
     serde_json::json!({
@@ -257,9 +309,9 @@ pub(super) fn build_thread_start_payload(
         "params": {
             "model": model,
             "cwd": folder.to_string_lossy(),
-            "approvalPolicy": policy::approval_policy(),
-            "sandbox": policy::thread_sandbox_mode(),
-            "config": policy::thread_config(reasoning_level),
+            "approvalPolicy": policy::approval_policy(permission_mode),
+            "sandbox": policy::thread_sandbox_mode(permission_mode),
+            "config": policy::thread_config(reasoning_level, mcp_servers),
             "experimentalRawEvents": false,
             "persistExtendedHistory": false
         }
@@ -271,7 +323,9 @@ pub(super) fn build_thread_resume_payload(
     thread_resume_request_id: &str,
     thread_id: &str,
     model: &str,
+    permission_mode: PermissionMode,
     reasoning_level: ReasoningLevel,
+    mcp_servers: &[McpServer],
 ) -> Value {
     serde_json::json!({
         "method": "thread/resume",
@@ -279,9 +333,9 @@ pub(super) fn build_thread_resume_payload(
         "params": {
             "threadId": thread_id,
             "model": model,
-            "approvalPolicy": policy::approval_policy(),
-            "sandbox": policy::thread_sandbox_mode(),
-            "config": policy::thread_config(reasoning_level),
+            "approvalPolicy": policy::approval_policy(permission_mode),
+            "sandbox": policy::thread_sandbox_mode(permission_mode),
+            "config": policy::thread_config(reasoning_level, mcp_servers),
             "experimentalRawEvents": false,
             "persistExtendedHistory": false
         }
@@ -294,6 +348,7 @@ pub(super) async fn run_turn_with_runtime<Transport: CodexRuntimeTransport>(
     state: &mut CodexRuntimeState,
     prompt: impl Into<TurnPrompt>,
     reasoning_level: ReasoningLevel,
+    permission_mode: PermissionMode,
     stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
 ) -> Result<(String, u64, u64), AppServerError> {
     let prompt = prompt.into();
@@ -308,11 +363,10 @@ pub(super) async fn run_turn_with_runtime<Transport: CodexRuntimeTransport>(
 
     let result = execute_turn_event_loop(
         transport,
-        &state.folder,
-        &state.model,
-        &state.thread_id,
+        state,
         &prompt,
         reasoning_level,
+        permission_mode,
         stream_tx.clone(),
     )
     .await;
@@ -332,11 +386,10 @@ pub(super) async fn run_turn_with_runtime<Transport: CodexRuntimeTransport>(
 
             let (message, input_tokens, output_tokens) = execute_turn_event_loop(
                 transport,
-                &state.folder,
-                &state.model,
-                &state.thread_id,
+                state,
                 &prompt,
                 reasoning_level,
+                permission_mode,
                 stream_tx,
             )
             .await?;
@@ -422,25 +475,25 @@ pub(super) async fn send_compact_request_with_timeout<Transport: CodexRuntimeTra
     .map_err(|_| compaction_timeout_error(turn_timeout))?
 }
 
-/// Sends one `turn/start` request and processes the event stream until
-/// `turn/completed` is received.
+/// Sends one `turn/start` request on the runtime's active thread and
+/// processes the event stream until `turn/completed` is received.
 pub(super) async fn execute_turn_event_loop<Transport: CodexRuntimeTransport>(
     transport: &mut Transport,
-    folder: &Path,
-    model: &str,
-    thread_id: &str,
+    state: &CodexRuntimeState,
     prompt: impl Into<TurnPrompt>,
     reasoning_level: ReasoningLevel,
+    permission_mode: PermissionMode,
     stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
 ) -> Result<(String, u64, u64), AppServerError> {
     let prompt = prompt.into();
     let input = CodexTurnEventLoopInput {
-        folder,
-        model,
+        folder: &state.folder,
+        model: &state.model,
+        permission_mode,
         prompt,
         reasoning_level,
         stream_tx,
-        thread_id,
+        thread_id: &state.thread_id,
         turn_timeout: app_server_transport::TURN_TIMEOUT,
     };
 
@@ -453,6 +506,8 @@ pub(super) struct CodexTurnEventLoopInput<'a> {
     folder: &'a Path,
     /// Model id requested for the turn.
     model: &'a str,
+    /// Permission mode applied to the turn sandbox and approval responses.
+    permission_mode: PermissionMode,
     /// Prompt payload sent to the runtime.
     prompt: TurnPrompt,
     /// Reasoning level sent to the runtime.
@@ -473,6 +528,7 @@ pub(super) async fn execute_turn_event_loop_with_timeout<Transport: CodexRuntime
 ) -> Result<(String, u64, u64), AppServerError> {
     let turn_start_id = write_turn_start_request(transport, &input).await?;
     let CodexTurnEventLoopInput {
+        permission_mode,
         stream_tx,
         turn_timeout,
         ..
@@ -516,7 +572,7 @@ pub(super) async fn execute_turn_event_loop_with_timeout<Transport: CodexRuntime
                 }
 
                 if let Some(approval_response) =
-                    policy::build_pre_action_approval_response(&response_value)
+                    policy::build_pre_action_approval_response(&response_value, permission_mode)
                 {
                     transport.write_json_line(approval_response).await?;
 
@@ -582,6 +638,7 @@ async fn write_turn_start_request<Transport: CodexRuntimeTransport>(
         input.folder,
         input.model,
         input.reasoning_level,
+        input.permission_mode,
         input.thread_id,
         &input.prompt,
         &turn_start_id,
@@ -596,6 +653,7 @@ pub(super) fn build_turn_start_payload(
     folder: &Path,
     model: &str,
     reasoning_level: ReasoningLevel,
+    permission_mode: PermissionMode,
     thread_id: &str,
     prompt: impl Into<TurnPrompt>,
     turn_start_id: &str,
@@ -609,8 +667,8 @@ pub(super) fn build_turn_start_payload(
             "threadId": thread_id,
             "input": build_turn_input_items(&prompt),
             "cwd": folder.to_string_lossy(),
-            "approvalPolicy": policy::approval_policy(),
-            "sandboxPolicy": policy::turn_sandbox_policy(),
+            "approvalPolicy": policy::approval_policy(permission_mode),
+            "sandboxPolicy": policy::turn_sandbox_policy(permission_mode),
             "model": model,
             "effort": reasoning_level.codex(),
             "summary": Value::Null,
@@ -832,11 +890,12 @@ mod tests {
         let model = AgentModel::Gpt54.as_str().to_string();
 
         // Act
-        let state = CodexRuntimeState::new(folder.clone(), model.clone());
+        let state = CodexRuntimeState::new(folder.clone(), model.clone(), Vec::new());
 
         // Assert
         assert_eq!(state.folder, folder);
         assert_eq!(state.model, model);
+        assert!(state.mcp_servers.is_empty());
         assert_eq!(state.latest_input_tokens, 0);
         assert!(!state.restored_context);
         assert!(state.thread_id.is_empty());
@@ -849,8 +908,14 @@ mod tests {
         let model = AgentModel::Gpt54.as_str();
 
         // Act
-        let payload =
-            build_thread_start_payload(&folder, model, ReasoningLevel::High, "thread-start-1");
+        let payload = build_thread_start_payload(
+            &folder,
+            model,
+            PermissionMode::default(),
+            ReasoningLevel::High,
+            &[],
+            "thread-start-1",
+        );
 
         // Assert
         assert_eq!(
@@ -880,6 +945,96 @@ mod tests {
             Some(false)
         );
         assert!(params.get("config").is_some());
+        assert!(payload.pointer("/params/config/mcp_servers").is_none());
+    }
+
+    #[test]
+    fn build_thread_start_payload_registers_project_mcp_servers_in_config() {
+        // Arrange
+        let folder = PathBuf::from("/tmp/agentty-codex-thread-start");
+        let mcp_servers = [
+            McpServer {
+                command: "docs-mcp".to_string(),
+                name: "docs".to_string(),
+                ..McpServer::default()
+            },
+            McpServer {
+                allowed_tools: vec!["create_issue".to_string()],
+                args: vec!["--stdio".to_string()],
+                command: "gh-mcp".to_string(),
+                env: [("GITHUB_TOKEN".to_string(), "token".to_string())].into(),
+                name: "github".to_string(),
+            },
+        ];
+
+        // Act
+        let payload = build_thread_start_payload(
+            &folder,
+            AgentModel::Gpt54.as_str(),
+            PermissionMode::default(),
+            ReasoningLevel::High,
+            &mcp_servers,
+            "thread-start-1",
+        );
+
+        // Assert
+        assert_eq!(
+            payload.pointer("/params/config/mcp_servers"),
+            Some(&serde_json::json!({
+                "docs": {"command": "docs-mcp", "args": [], "env": {}},
+                "github": {
+                    "command": "gh-mcp",
+                    "args": ["--stdio"],
+                    "env": {"GITHUB_TOKEN": "token"},
+                    "enabled_tools": ["create_issue"]
+                }
+            }))
+        );
+        assert_eq!(
+            payload
+                .pointer("/params/config/model_reasoning_effort")
+                .and_then(Value::as_str),
+            Some(ReasoningLevel::High.codex())
+        );
+    }
+
+    #[test]
+    fn build_thread_payloads_start_plan_mode_threads_read_only() {
+        // Arrange
+        let folder = PathBuf::from("/tmp/agentty-codex-thread-start");
+        let model = AgentModel::Gpt54.as_str();
+
+        // Act
+        let start_payload = build_thread_start_payload(
+            &folder,
+            model,
+            PermissionMode::Plan,
+            ReasoningLevel::High,
+            &[],
+            "thread-start-1",
+        );
+        let resume_payload = build_thread_resume_payload(
+            "thread-resume-1",
+            "existing-thread",
+            model,
+            PermissionMode::Plan,
+            ReasoningLevel::High,
+            &[],
+        );
+
+        // Assert
+        for payload in [start_payload, resume_payload] {
+            assert_eq!(
+                payload.pointer("/params/sandbox").and_then(Value::as_str),
+                Some("read-only")
+            );
+            assert_eq!(
+                payload
+                    .pointer("/params/approvalPolicy")
+                    .and_then(Value::as_str),
+                Some("never")
+            );
+        }
     }
 
     #[test]
@@ -892,7 +1047,9 @@ mod tests {
             "thread-resume-1",
             "existing-thread",
             model,
+            PermissionMode::default(),
             ReasoningLevel::Medium,
+            &[],
         );
 
         // Assert
@@ -944,6 +1101,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: attachment_path.clone(),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Describe [Image #1] please".to_string(),
         };
 
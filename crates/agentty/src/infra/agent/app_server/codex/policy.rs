@@ -3,6 +3,7 @@
 use serde_json::Value;
 
 use crate::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
+use crate::domain::mcp::McpServer;
 use crate::domain::permission::PermissionMode;
 
 /// Canonical wire-level policy mapping for one [`PermissionMode`].
@@ -30,6 +31,16 @@ const AUTO_EDIT_POLICY: PermissionModePolicy = PermissionModePolicy {
     web_search_mode: "live",
 };
 
+const PLAN_POLICY: PermissionModePolicy = PermissionModePolicy {
+    approval_policy: "never",
+    legacy_pre_action_decision: "denied",
+    pre_action_decision: "decline",
+    thread_sandbox_mode: "read-only",
+    turn_network_access: false,
+    turn_sandbox_type: "readOnly",
+    web_search_mode: "live",
+};
+
 /// Proactive compaction threshold for Codex models with a 400k context window.
 ///
 /// [`AgentModel::Gpt54`] uses this larger threshold to keep enough room for
@@ -57,18 +68,18 @@ pub(super) fn auto_compact_input_token_threshold(model: &str) -> u64 {
 }
 
 /// Returns the app-server approval policy used for one permission mode.
-pub(super) fn approval_policy() -> &'static str {
-    permission_mode_policy(PermissionMode::default()).approval_policy
+pub(super) fn approval_policy(permission_mode: PermissionMode) -> &'static str {
+    permission_mode_policy(permission_mode).approval_policy
 }
 
 /// Returns the thread-level sandbox mode used for one permission mode.
-pub(super) fn thread_sandbox_mode() -> &'static str {
-    permission_mode_policy(PermissionMode::default()).thread_sandbox_mode
+pub(super) fn thread_sandbox_mode(permission_mode: PermissionMode) -> &'static str {
+    permission_mode_policy(permission_mode).thread_sandbox_mode
 }
 
 /// Returns the turn-level sandbox policy object for one permission mode.
-pub(super) fn turn_sandbox_policy() -> Value {
-    let policy = permission_mode_policy(PermissionMode::default());
+pub(super) fn turn_sandbox_policy(permission_mode: PermissionMode) -> Value {
+    let policy = permission_mode_policy(permission_mode);
     let mut turn_sandbox_policy = serde_json::json!({
         "type": policy.turn_sandbox_type
     });
@@ -87,13 +98,44 @@ pub(super) fn turn_sandbox_policy() -> Value {
 
 /// Returns per-thread config overrides for one permission mode.
 ///
-/// This keeps overrides minimal while enabling live `web_search` and applying
-/// the selected Codex reasoning effort.
-pub(super) fn thread_config(reasoning_level: ReasoningLevel) -> Value {
-    serde_json::json!({
+/// This keeps overrides minimal while enabling live `web_search`, applying
+/// the selected Codex reasoning effort, and registering project MCP servers
+/// under `mcp_servers`. Servers with an explicit tool list set
+/// `enabled_tools` so Codex hides every other tool.
+pub(super) fn thread_config(reasoning_level: ReasoningLevel, mcp_servers: &[McpServer]) -> Value {
+    let mut config = serde_json::json!({
         "web_search": web_search_mode(),
         "model_reasoning_effort": reasoning_level.codex(),
-    })
+    });
+    if !mcp_servers.is_empty()
+        && let Some(config_object) = config.as_object_mut()
+    {
+        config_object.insert(
+            "mcp_servers".to_string(),
+            Value::Object(mcp_servers.iter().map(mcp_server_config).collect()),
+        );
+    }
+
+    config
+}
+
+/// Returns one `mcp_servers.<name>` config entry.
+fn mcp_server_config(server: &McpServer) -> (String, Value) {
+    let mut server_config = serde_json::json!({
+        "command": server.command,
+        "args": server.args,
+        "env": server.env,
+    });
+    if !server.allowed_tools.is_empty()
+        && let Some(server_object) = server_config.as_object_mut()
+    {
+        server_object.insert(
+            "enabled_tools".to_string(),
+            serde_json::json!(server.allowed_tools),
+        );
+    }
+
+    (server.name.clone(), server_config)
 }
 
 /// Returns the `web_search` mode for one permission mode.
@@ -105,14 +147,19 @@ pub(super) fn web_search_mode() -> &'static str {
 ///
 /// Returns `None` when the input line is not a supported approval request or
 /// does not include a request id.
-pub(super) fn build_pre_action_approval_response(response_value: &Value) -> Option<Value> {
+pub(super) fn build_pre_action_approval_response(
+    response_value: &Value,
+    permission_mode: PermissionMode,
+) -> Option<Value> {
     let method = response_value.get("method")?.as_str()?;
     let request_id = response_value.get("id")?.clone();
     let decision = match method {
         "item/commandExecution/requestApproval" | "item/fileChange/requestApproval" => {
-            pre_action_approval_decision()
+            pre_action_approval_decision(permission_mode)
+        }
+        "execCommandApproval" | "applyPatchApproval" => {
+            legacy_pre_action_approval_decision(permission_mode)
         }
-        "execCommandApproval" | "applyPatchApproval" => legacy_pre_action_approval_decision(),
         _ => return None,
     };
 
@@ -125,18 +172,19 @@ pub(super) fn build_pre_action_approval_response(response_value: &Value) -> Opti
 }
 
 /// Returns the modern pre-action approval decision for one permission mode.
-fn pre_action_approval_decision() -> &'static str {
-    permission_mode_policy(PermissionMode::default()).pre_action_decision
+fn pre_action_approval_decision(permission_mode: PermissionMode) -> &'static str {
+    permission_mode_policy(permission_mode).pre_action_decision
 }
 
 /// Returns the legacy pre-action approval decision for one permission mode.
-fn legacy_pre_action_approval_decision() -> &'static str {
-    permission_mode_policy(PermissionMode::default()).legacy_pre_action_decision
+fn legacy_pre_action_approval_decision(permission_mode: PermissionMode) -> &'static str {
+    permission_mode_policy(permission_mode).legacy_pre_action_decision
 }
 
 /// Returns the canonical wire-level policy for one permission mode.
 fn permission_mode_policy(permission_mode: PermissionMode) -> &'static PermissionModePolicy {
     match permission_mode {
         PermissionMode::AutoEdit => &AUTO_EDIT_POLICY,
+        PermissionMode::Plan => &PLAN_POLICY,
     }
 }
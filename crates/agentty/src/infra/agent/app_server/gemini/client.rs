@@ -30,6 +30,7 @@ impl RealGeminiAcpClient {
         stream_tx: &mpsc::UnboundedSender<AppServerStreamEvent>,
     ) -> Result<AppServerTurnResponse, AppServerError> {
         let stream_tx = stream_tx.clone();
+        let permission_mode = request.permission_mode;
 
         app_server::run_turn_with_restart_retry(
             sessions,
@@ -60,6 +61,7 @@ impl RealGeminiAcpClient {
                     lifecycle::run_turn_with_runtime(
                         &mut runtime.transport,
                         &runtime.state.session_id,
+                        permission_mode,
                         prompt,
                         stream_tx,
                     )
@@ -117,8 +119,13 @@ struct GeminiSessionRuntime {
 
 impl GeminiSessionRuntime {
     /// Returns whether the runtime matches one incoming turn request.
+    ///
+    /// A changed project MCP server list restarts the runtime so the new
+    /// session registers the current servers.
     fn matches_request(&self, request: &AppServerTurnRequest) -> bool {
-        self.state.folder == request.folder && self.state.model == request.model
+        self.state.folder == request.folder
+            && self.state.model == request.model
+            && self.state.mcp_servers == request.mcp_servers
     }
 
     /// Returns whether runtime startup restored prior provider context.
@@ -148,6 +155,7 @@ mod tests {
     use tokio::sync::mpsc;
 
     use super::*;
+    use crate::domain::permission::PermissionMode;
     use crate::infra::agent::app_server::gemini::{
         MockGeminiRuntimeTransport, lifecycle, policy, stream_parser, usage,
     };
@@ -302,7 +310,8 @@ mod tests {
             });
 
         // Act
-        let session_id = lifecycle::bootstrap_runtime_session(&mut transport, folder.path()).await;
+        let session_id =
+            lifecycle::bootstrap_runtime_session(&mut transport, folder.path(), &[]).await;
 
         // Assert
         assert_eq!(session_id.expect("bootstrap should succeed"), "session-1");
@@ -322,6 +331,7 @@ mod tests {
         let result = lifecycle::run_turn_with_runtime(
             &mut transport,
             "session-1",
+            PermissionMode::default(),
             "Implement the task",
             stream_tx,
         )
@@ -571,8 +581,12 @@ mod tests {
         });
 
         // Act
-        let permission_response = policy::build_permission_response(&response_value, "session-1")
-            .expect("permission response should be built");
+        let permission_response = policy::build_permission_response(
+            &response_value,
+            "session-1",
+            PermissionMode::default(),
+        )
+        .expect("permission response should be built");
 
         // Assert
         assert_eq!(
@@ -585,6 +599,73 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_permission_response_rejects_requests_in_plan_mode() {
+        // Arrange
+        let response_value = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-1",
+            "method": "session/request_permission",
+            "params": {
+                "sessionId": "session-1",
+                "options": [{
+                    "optionId": "allow-once",
+                    "kind": "allow_once"
+                }, {
+                    "optionId": "reject-once",
+                    "kind": "reject_once"
+                }]
+            }
+        });
+
+        // Act
+        let permission_response =
+            policy::build_permission_response(&response_value, "session-1", PermissionMode::Plan)
+                .expect("permission response should be built");
+
+        // Assert
+        assert_eq!(
+            permission_response
+                .get("result")
+                .and_then(|result| result.get("outcome"))
+                .and_then(|outcome| outcome.get("optionId"))
+                .and_then(Value::as_str),
+            Some("reject-once")
+        );
+    }
+
+    #[test]
+    fn build_permission_response_cancels_plan_mode_requests_without_reject_option() {
+        // Arrange
+        let response_value = serde_json::json!({
+            "jsonrpc": "2.0",
+            "id": "permission-1",
+            "method": "session/request_permission",
+            "params": {
+                "sessionId": "session-1",
+                "options": [{
+                    "optionId": "allow-once",
+                    "kind": "allow_once"
+                }]
+            }
+        });
+
+        // Act
+        let permission_response =
+            policy::build_permission_response(&response_value, "session-1", PermissionMode::Plan)
+                .expect("permission response should be built");
+
+        // Assert
+        assert_eq!(
+            permission_response
+                .get("result")
+                .and_then(|result| result.get("outcome"))
+                .and_then(|outcome| outcome.get("outcome"))
+                .and_then(Value::as_str),
+            Some("cancelled")
+        );
+    }
+
     #[test]
     fn select_preferred_assistant_message_prefers_structured_completion_payload() {
         // Arrange
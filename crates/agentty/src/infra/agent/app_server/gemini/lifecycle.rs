@@ -15,6 +15,8 @@ use tokio::sync::mpsc;
 use super::transport::{GeminiRuntimeTransport, GeminiStdioTransport};
 use super::{policy, stream_parser, usage};
 use crate::domain::agent::AgentKind;
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::agent;
 use crate::infra::app_server::{AppServerError, AppServerStreamEvent, AppServerTurnRequest};
 use crate::infra::app_server_transport::{self, extract_json_error_message, response_id_matches};
@@ -24,6 +26,8 @@ use crate::infra::channel::{TurnPrompt, TurnPromptAttachment, TurnPromptContentP
 pub(super) struct GeminiRuntimeState {
     /// Session worktree folder used as the runtime cwd.
     pub(super) folder: PathBuf,
+    /// Project MCP servers registered with the active session.
+    pub(super) mcp_servers: Vec<McpServer>,
     /// Selected Gemini model identifier.
     pub(super) model: String,
     /// Whether startup restored provider-native context.
@@ -34,9 +38,10 @@ pub(super) struct GeminiRuntimeState {
 
 impl GeminiRuntimeState {
     /// Creates runtime state for one pending Gemini bootstrap.
-    pub(super) fn new(folder: PathBuf, model: String) -> Self {
+    pub(super) fn new(folder: PathBuf, model: String, mcp_servers: Vec<McpServer>) -> Self {
         Self {
             folder,
+            mcp_servers,
             model,
             restored_context: false,
             session_id: String::new(),
@@ -60,6 +65,9 @@ pub(super) async fn start_runtime(
         .build_command(agent::BuildCommandRequest {
             attachments: &[],
             folder: request.folder.as_path(),
+            mcp_servers: &request.mcp_servers,
+            permission_mode: request.permission_mode,
+            project_instructions: None,
             prompt: "",
             request_kind: &request_kind,
             model: &request.model,
@@ -71,9 +79,15 @@ pub(super) async fn start_runtime(
     let (mut child, stdin, stdout) =
         app_server_transport::spawn_runtime_command(command, "gemini --acp")?;
     let mut transport = GeminiStdioTransport::new(stdin, stdout);
-    let mut state = GeminiRuntimeState::new(request.folder.clone(), request.model.clone());
+    let mut state = GeminiRuntimeState::new(
+        request.folder.clone(),
+        request.model.clone(),
+        request.mcp_servers.clone(),
+    );
 
-    match bootstrap_runtime_session(&mut transport, state.folder.as_path()).await {
+    match bootstrap_runtime_session(&mut transport, state.folder.as_path(), &state.mcp_servers)
+        .await
+    {
         Ok(session_id) => {
             state.session_id = session_id;
 
@@ -93,10 +107,11 @@ pub(super) async fn start_runtime(
 pub(super) async fn bootstrap_runtime_session<Transport: GeminiRuntimeTransport>(
     transport: &mut Transport,
     folder: &Path,
+    mcp_servers: &[McpServer],
 ) -> Result<String, AppServerError> {
     initialize_runtime(transport).await?;
 
-    start_session(transport, folder).await
+    start_session(transport, folder, mcp_servers).await
 }
 
 /// Sends the ACP initialize handshake.
@@ -199,13 +214,11 @@ pub(super) fn parse_json_rpc_result<T: serde::de::DeserializeOwned>(
 pub(super) async fn start_session<Transport: GeminiRuntimeTransport>(
     transport: &mut Transport,
     folder: &Path,
+    mcp_servers: &[McpServer],
 ) -> Result<String, AppServerError> {
     let session_new_id = format!("session-new-{}", uuid::Uuid::new_v4());
-    let session_new_payload = build_json_rpc_request_payload(
-        &session_new_id,
-        AGENT_METHOD_NAMES.session_new,
-        NewSessionRequest::new(folder.to_path_buf()),
-    )?;
+    let session_new_payload =
+        build_session_new_request_payload(&session_new_id, folder, mcp_servers)?;
     transport.write_json_line(session_new_payload).await?;
     let response_line = transport.wait_for_response_line(session_new_id).await?;
     let response_value = serde_json::from_str::<Value>(&response_line).map_err(|error| {
@@ -217,6 +230,48 @@ pub(super) async fn start_session<Transport: GeminiRuntimeTransport>(
     parse_session_new_response(&response_value)
 }
 
+/// Builds one ACP `session/new` request that registers project MCP servers.
+///
+/// ACP stdio servers carry `env` as a `{name, value}` list and have no tool
+/// filter, so Gemini exposes every tool of each configured server.
+pub(super) fn build_session_new_request_payload(
+    request_id: &str,
+    folder: &Path,
+    mcp_servers: &[McpServer],
+) -> Result<Value, AppServerError> {
+    let mut session_new_payload = build_json_rpc_request_payload(
+        request_id,
+        AGENT_METHOD_NAMES.session_new,
+        NewSessionRequest::new(folder.to_path_buf()),
+    )?;
+    let Some(params) = session_new_payload
+        .get_mut("params")
+        .and_then(Value::as_object_mut)
+    else {
+        return Err(AppServerError::Provider(
+            "Failed to build Gemini ACP `session/new` request params object".to_string(),
+        ));
+    };
+    let mcp_servers = mcp_servers
+        .iter()
+        .map(|server| {
+            serde_json::json!({
+                "name": server.name,
+                "command": server.command,
+                "args": server.args,
+                "env": server
+                    .env
+                    .iter()
+                    .map(|(name, value)| serde_json::json!({"name": name, "value": value}))
+                    .collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+    params.insert("mcpServers".to_string(), Value::Array(mcp_servers));
+
+    Ok(session_new_payload)
+}
+
 /// Parses one ACP `session/new` response into a session identifier.
 pub(super) fn parse_session_new_response(response_value: &Value) -> Result<String, AppServerError> {
     if response_value.get("error").is_some() {
@@ -244,9 +299,13 @@ pub(super) fn parse_session_new_response(response_value: &Value) -> Result<Strin
 }
 
 /// Sends one prompt turn and waits for the matching prompt response id.
+///
+/// Permission requests raised during the turn are answered according to
+/// `permission_mode`, so read-only turns decline edits and commands.
 pub(super) async fn run_turn_with_runtime<Transport: GeminiRuntimeTransport>(
     transport: &mut Transport,
     session_id: &str,
+    permission_mode: PermissionMode,
     prompt: impl Into<TurnPrompt>,
     stream_tx: mpsc::UnboundedSender<AppServerStreamEvent>,
 ) -> Result<(String, u64, u64), AppServerError> {
@@ -278,7 +337,7 @@ pub(super) async fn run_turn_with_runtime<Transport: GeminiRuntimeTransport>(
             };
 
             if let Some(permission_response) =
-                policy::build_permission_response(&response_value, session_id)
+                policy::build_permission_response(&response_value, session_id, permission_mode)
             {
                 transport.write_json_line(permission_response).await?;
 
@@ -462,7 +521,7 @@ mod tests {
         let model = "gemini-3-flash-preview".to_string();
 
         // Act
-        let state = GeminiRuntimeState::new(folder.clone(), model.clone());
+        let state = GeminiRuntimeState::new(folder.clone(), model.clone(), Vec::new());
 
         // Assert
         assert_eq!(state.folder, folder);
@@ -471,6 +530,42 @@ mod tests {
         assert!(!state.restored_context);
     }
 
+    #[test]
+    fn build_session_new_request_payload_registers_project_mcp_servers() {
+        // Arrange
+        let folder = PathBuf::from("/tmp/agentty-gemini-session");
+        let mcp_servers = [McpServer {
+            allowed_tools: vec!["create_issue".to_string()],
+            args: vec!["--stdio".to_string()],
+            command: "gh-mcp".to_string(),
+            env: [("GITHUB_TOKEN".to_string(), "token".to_string())].into(),
+            name: "github".to_string(),
+        }];
+
+        // Act
+        let payload = build_session_new_request_payload("session-new-1", &folder, &mcp_servers)
+            .expect("session/new payload should build");
+
+        // Assert
+        assert_eq!(
+            payload.get("method").and_then(Value::as_str),
+            Some("session/new")
+        );
+        assert_eq!(
+            payload.pointer("/params/cwd").and_then(Value::as_str),
+            Some("/tmp/agentty-gemini-session")
+        );
+        assert_eq!(
+            payload.pointer("/params/mcpServers"),
+            Some(&serde_json::json!([{
+                "name": "github",
+                "command": "gh-mcp",
+                "args": ["--stdio"],
+                "env": [{"name": "GITHUB_TOKEN", "value": "token"}]
+            }]))
+        );
+    }
+
     #[test]
     fn build_initialize_request_payload_carries_jsonrpc_method_and_client_capabilities_object() {
         // Arrange / Act
@@ -658,6 +753,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: attachment_path,
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Look at [Image #1] now".to_string(),
         };
 
@@ -914,7 +1011,7 @@ mod tests {
             });
 
         // Act
-        let session_id = start_session(&mut transport, folder.path()).await;
+        let session_id = start_session(&mut transport, folder.path(), &[]).await;
 
         // Assert
         assert_eq!(
@@ -939,7 +1036,7 @@ mod tests {
             .returning(|_| Box::pin(async { Err(AppServerTransportError::ProcessTerminated) }));
 
         // Act
-        let result = start_session(&mut transport, folder.path()).await;
+        let result = start_session(&mut transport, folder.path(), &[]).await;
 
         // Assert
         let error = result.expect_err("start_session should propagate transport error");
@@ -6,15 +6,32 @@ use agent_client_protocol::{
 };
 use serde_json::Value;
 
+use crate::domain::permission::PermissionMode;
+
+/// Permission option kinds preferred while edits are allowed.
+const ALLOW_OPTION_KINDS: [PermissionOptionKind; 2] = [
+    PermissionOptionKind::AllowAlways,
+    PermissionOptionKind::AllowOnce,
+];
+
+/// Permission option kinds preferred while a turn must stay read-only.
+const REJECT_OPTION_KINDS: [PermissionOptionKind; 2] = [
+    PermissionOptionKind::RejectOnce,
+    PermissionOptionKind::RejectAlways,
+];
+
 /// Builds a `session/request_permission` response for the active session.
 ///
 /// The response follows ACP's `RequestPermissionResponse` shape. When an allow
-/// option is available, this selects it to match auto-edit behavior. When no
-/// options are provided or parsable, this returns a `cancelled` outcome to
-/// avoid leaving the turn blocked indefinitely.
+/// option is available, this selects it to match auto-edit behavior. Read-only
+/// [`PermissionMode::Plan`] turns select a reject option instead, because
+/// Gemini only asks permission for tools that edit files or run commands.
+/// When no matching option is provided or parsable, this returns a
+/// `cancelled` outcome to avoid leaving the turn blocked indefinitely.
 pub(super) fn build_permission_response(
     response_value: &Value,
     expected_session_id: &str,
+    permission_mode: PermissionMode,
 ) -> Option<Value> {
     if response_value.get("method").and_then(Value::as_str)
         != Some(CLIENT_METHOD_NAMES.session_request_permission)
@@ -31,8 +48,9 @@ pub(super) fn build_permission_response(
             return None;
         }
 
-        let selected_option_id = select_permission_option(&permission_request.options)
-            .map(|option| option.option_id.clone().to_string());
+        let selected_option_id =
+            select_permission_option(&permission_request.options, permission_mode)
+                .map(|option| option.option_id.clone().to_string());
 
         return Some(build_permission_result_payload(
             &request_id,
@@ -46,7 +64,7 @@ pub(super) fn build_permission_response(
 
     let selected_option_id = params
         .get("options")
-        .and_then(select_permission_option_id_from_value);
+        .and_then(|options| select_permission_option_id_from_value(options, permission_mode));
 
     Some(build_permission_result_payload(
         &request_id,
@@ -98,24 +116,47 @@ fn build_permission_result_value_fallback(selected_option_id: Option<String>) ->
     })
 }
 
-/// Selects the preferred allow option from typed ACP permission choices.
-fn select_permission_option(options: &[PermissionOption]) -> Option<&PermissionOption> {
-    for preferred_kind in [
-        PermissionOptionKind::AllowAlways,
-        PermissionOptionKind::AllowOnce,
-    ] {
+/// Returns the option kinds to prefer, in order, for one permission mode.
+fn preferred_option_kinds(permission_mode: PermissionMode) -> [PermissionOptionKind; 2] {
+    if permission_mode.is_read_only() {
+        REJECT_OPTION_KINDS
+    } else {
+        ALLOW_OPTION_KINDS
+    }
+}
+
+/// Selects the preferred option from typed ACP permission choices.
+///
+/// Read-only turns never fall back to an arbitrary first option, so a
+/// request without reject choices is cancelled rather than approved.
+fn select_permission_option(
+    options: &[PermissionOption],
+    permission_mode: PermissionMode,
+) -> Option<&PermissionOption> {
+    for preferred_kind in preferred_option_kinds(permission_mode) {
         if let Some(option) = options.iter().find(|option| option.kind == preferred_kind) {
             return Some(option);
         }
     }
+    if permission_mode.is_read_only() {
+        return None;
+    }
 
     options.first()
 }
 
-/// Selects the preferred allow option identifier from raw ACP choices.
-fn select_permission_option_id_from_value(options: &Value) -> Option<String> {
+/// Selects the preferred option identifier from raw ACP choices.
+fn select_permission_option_id_from_value(
+    options: &Value,
+    permission_mode: PermissionMode,
+) -> Option<String> {
     let options = options.as_array()?;
-    for preferred_kind in ["allow_always", "allow_once"] {
+    let preferred_kinds = if permission_mode.is_read_only() {
+        ["reject_once", "reject_always"]
+    } else {
+        ["allow_always", "allow_once"]
+    };
+    for preferred_kind in preferred_kinds {
         if let Some(option_id) = options.iter().find_map(|option| {
             if option.get("kind").and_then(Value::as_str) == Some(preferred_kind) {
                 return option
@@ -130,6 +171,10 @@ fn select_permission_option_id_from_value(options: &Value) -> Option<String> {
         }
     }
 
+    if permission_mode.is_read_only() {
+        return None;
+    }
+
     options
         .first()
         .and_then(|option| option.get("optionId"))
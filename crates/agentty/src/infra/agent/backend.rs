@@ -4,6 +4,8 @@ use std::path::Path;
 use std::process::Command;
 
 use crate::domain::agent::ReasoningLevel;
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::channel::{AgentRequestKind, TurnPromptAttachment};
 
 /// Transport runtime used to execute turns for one backend.
@@ -47,6 +49,21 @@ pub struct BuildCommandRequest<'a> {
     pub attachments: &'a [TurnPromptAttachment],
     /// Working directory where the command will run.
     pub folder: &'a Path,
+    /// Project MCP servers the provider should expose to the agent.
+    ///
+    /// Claude receives them as launch flags; app-server runtimes register
+    /// them when they start or resume a thread instead.
+    pub mcp_servers: &'a [McpServer],
+    /// Permission mode for this turn.
+    ///
+    /// Claude receives read-only turns as `--permission-mode plan`;
+    /// app-server runtimes apply it to each turn instead.
+    pub permission_mode: PermissionMode,
+    /// Project-specific instructions configured in Agentty for this turn.
+    ///
+    /// Rendered into the prompt by CLI providers; app-server runtimes receive
+    /// them with each turn instead.
+    pub project_instructions: Option<&'a str>,
     /// User prompt to send.
     pub prompt: &'a str,
     /// Canonical request kind that drives execution and protocol semantics.
@@ -1,8 +1,13 @@
-use std::path::Path;
+use std::hash::{DefaultHasher, Hash as _, Hasher as _};
+use std::io::Write as _;
+use std::os::unix::fs::OpenOptionsExt as _;
+use std::path::{Path, PathBuf};
 use std::process::{Command, Stdio};
 
 use super::backend::{AgentBackend, AgentBackendError, BuildCommandRequest};
 use super::prompt::{PromptPreparationRequest, prepare_prompt_text};
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::agent::protocol::agent_response_output_schema_json;
 use crate::infra::channel::{
     TurnPromptAttachment, TurnPromptContentPart, split_turn_prompt_content,
@@ -12,13 +17,27 @@ use crate::infra::channel::{
 /// file editing, multi-edit, and write operations.
 const CLAUDE_ALLOWED_TOOLS: &str = "Edit,MultiEdit,Write,Bash,EnterPlanMode,ExitPlanMode";
 
+/// Lists the read-only Claude tools Agentty enables for plan-first planning
+/// turns.
+const CLAUDE_PLAN_ALLOWED_TOOLS: &str = "Read,Glob,Grep";
+
+/// File name prefix for the per-session `--mcp-config` file in the system
+/// temp directory.
+const MCP_CONFIG_FILE_PREFIX: &str = "agentty-claude-mcp-";
+
+/// Unix permissions for the `--mcp-config` file, which may hold server
+/// tokens, so only the current user can read it.
+const MCP_CONFIG_FILE_MODE: u32 = 0o600;
+
 /// Backend implementation for the Claude CLI.
 ///
 /// Commands are built with `--strict-mcp-config` so provider-level MCP
 /// connector defaults (for example Claude.ai account connectors) are ignored
-/// unless explicitly configured by Agentty. Claude runs in `stream-json` mode
-/// so progress and tool-use events can surface live while the final turn still
-/// honors native schema validation.
+/// and only the project MCP servers configured in Agentty are loaded. Claude
+/// runs in `stream-json` mode so progress and tool-use events can surface live
+/// while the final turn still honors native schema validation. Read-only
+/// [`PermissionMode::Plan`] turns add `--permission-mode plan` and drop the
+/// write-capable tools.
 pub(super) struct ClaudeBackend;
 
 impl AgentBackend for ClaudeBackend {
@@ -36,6 +55,9 @@ impl AgentBackend for ClaudeBackend {
             folder,
             request_kind,
             model,
+            mcp_servers,
+            permission_mode,
+            project_instructions: _project_instructions,
             prompt: _prompt,
             reasoning_level,
         } = request;
@@ -48,9 +70,19 @@ impl AgentBackend for ClaudeBackend {
         append_attachment_access_directories(&mut command, request.attachments);
 
         command.arg("-p");
-        command.arg("--allowedTools").arg(CLAUDE_ALLOWED_TOOLS);
+        command
+            .arg("--allowedTools")
+            .arg(claude_allowed_tools(permission_mode, mcp_servers));
+        if permission_mode.is_read_only() {
+            command.arg("--permission-mode").arg("plan");
+        }
         command.arg("--input-format").arg("text");
         command.arg("--strict-mcp-config");
+        if !mcp_servers.is_empty() {
+            command
+                .arg("--mcp-config")
+                .arg(write_mcp_config_file(folder, mcp_servers)?);
+        }
         command.arg("--verbose");
         command.arg("--effort").arg(reasoning_level.claude());
         command.arg("--output-format").arg("stream-json");
@@ -81,6 +113,7 @@ pub(super) fn build_prompt_stdin_payload(
         } else {
             super::instruction::InstructionDeliveryMode::BootstrapFull
         },
+        project_instructions: request.project_instructions,
         prompt: &prompt,
         protocol_profile: request.request_kind.protocol_profile(),
         replay_session_output: request.request_kind.session_output(),
@@ -89,6 +122,108 @@ pub(super) fn build_prompt_stdin_payload(
     Ok(prompt.into_bytes())
 }
 
+/// Returns the `--allowedTools` value extended with project MCP tools.
+///
+/// Read-only permission modes allow only [`CLAUDE_PLAN_ALLOWED_TOOLS`] and no
+/// MCP tools, since project servers may expose tools that write.
+/// Otherwise, servers without an explicit tool list allow every tool through
+/// the `mcp__<server>` prefix and the rest allow each tool as
+/// `mcp__<server>__<tool>`.
+fn claude_allowed_tools(permission_mode: PermissionMode, mcp_servers: &[McpServer]) -> String {
+    if permission_mode.is_read_only() {
+        return CLAUDE_PLAN_ALLOWED_TOOLS.to_string();
+    }
+
+    let mut allowed_tools = vec![CLAUDE_ALLOWED_TOOLS.to_string()];
+    for server in mcp_servers {
+        if server.allowed_tools.is_empty() {
+            allowed_tools.push(format!("mcp__{}", server.name));
+        } else {
+            allowed_tools.extend(
+                server
+                    .allowed_tools
+                    .iter()
+                    .map(|tool| format!("mcp__{}__{tool}", server.name)),
+            );
+        }
+    }
+
+    allowed_tools.join(",")
+}
+
+/// Returns the `--mcp-config` file path used for turns in `folder`.
+///
+/// Each session folder runs one turn at a time, so one path per folder is
+/// enough and each turn overwrites the previous file.
+fn mcp_config_path(folder: &Path) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    folder.hash(&mut hasher);
+
+    std::env::temp_dir().join(format!(
+        "{MCP_CONFIG_FILE_PREFIX}{:016x}.json",
+        hasher.finish()
+    ))
+}
+
+/// Writes project MCP servers to the owner-only `--mcp-config` file for
+/// `folder` and returns its path.
+///
+/// Server `env` values often carry tokens, so they go to a `0600` file instead
+/// of argv, where any local user could read them from the process list. The
+/// previous file is removed first so the new one is always created with the
+/// restricted mode.
+///
+/// # Errors
+/// Returns an error when the config file cannot be written.
+fn write_mcp_config_file(
+    folder: &Path,
+    mcp_servers: &[McpServer],
+) -> Result<PathBuf, AgentBackendError> {
+    let path = mcp_config_path(folder);
+    remove_mcp_config_file(folder);
+
+    std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(MCP_CONFIG_FILE_MODE)
+        .open(&path)
+        .and_then(|mut file| file.write_all(claude_mcp_config_json(mcp_servers).as_bytes()))
+        .map_err(|error| {
+            AgentBackendError::CommandBuild(format!(
+                "Failed to write MCP config `{}`: {error}",
+                path.display()
+            ))
+        })?;
+
+    Ok(path)
+}
+
+/// Removes the `--mcp-config` file written for `folder`, if any.
+pub(super) fn remove_mcp_config_file(folder: &Path) {
+    // Best-effort: a missing file means no MCP servers were configured.
+    let _ = std::fs::remove_file(mcp_config_path(folder));
+}
+
+/// Renders project MCP servers as the JSON accepted by `--mcp-config`.
+fn claude_mcp_config_json(mcp_servers: &[McpServer]) -> String {
+    let servers = mcp_servers
+        .iter()
+        .map(|server| {
+            (
+                server.name.clone(),
+                serde_json::json!({
+                    "type": "stdio",
+                    "command": server.command,
+                    "args": server.args,
+                    "env": server.env,
+                }),
+            )
+        })
+        .collect::<serde_json::Map<_, _>>();
+
+    serde_json::json!({ "mcpServers": servers }).to_string()
+}
+
 /// Adds Claude file-access roots for prompt attachments that live outside the
 /// current worktree.
 ///
@@ -183,6 +318,7 @@ fn attachment_path_for_prompt(
 
 #[cfg(test)]
 mod tests {
+    use std::os::unix::fs::PermissionsExt as _;
     use std::path::PathBuf;
 
     use tempfile::tempdir;
@@ -212,6 +348,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Plan prompt",
                 request_kind: &session_start_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -238,6 +377,163 @@ mod tests {
         assert!(!args.iter().any(String::is_empty));
     }
 
+    #[test]
+    /// Verifies read-only plan turns use Claude's plan permission mode
+    /// without write-capable tools.
+    fn test_claude_plan_mode_uses_read_only_permission_mode() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let backend = ClaudeBackend;
+
+        // Act
+        let command = AgentBackend::build_command(
+            &backend,
+            BuildCommandRequest {
+                attachments: &[],
+                folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::Plan,
+                project_instructions: None,
+                prompt: "Plan prompt",
+                request_kind: &session_start_request_kind(),
+                model: "claude-sonnet-4-6",
+                reasoning_level: ReasoningLevel::default(),
+            },
+        )
+        .expect("command should build");
+        let args = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        // Assert
+        let permission_mode_pos = args
+            .iter()
+            .position(|arg| arg == "--permission-mode")
+            .expect("--permission-mode flag should be present");
+        assert_eq!(args[permission_mode_pos + 1], "plan");
+        let allowed_tools_pos = args
+            .iter()
+            .position(|arg| arg == "--allowedTools")
+            .expect("--allowedTools flag should be present");
+        assert_eq!(args[allowed_tools_pos + 1], CLAUDE_PLAN_ALLOWED_TOOLS);
+    }
+
+    #[test]
+    /// Verifies read-only plan turns leave project MCP tools out of the
+    /// allowlist.
+    fn test_claude_plan_mode_does_not_allow_project_mcp_tools() {
+        // Arrange
+        let mcp_servers = [
+            McpServer {
+                command: "docs-mcp".to_string(),
+                name: "docs".to_string(),
+                ..McpServer::default()
+            },
+            McpServer {
+                allowed_tools: vec!["create_issue".to_string()],
+                command: "gh-mcp".to_string(),
+                name: "github".to_string(),
+                ..McpServer::default()
+            },
+        ];
+
+        // Act
+        let allowed_tools = claude_allowed_tools(PermissionMode::Plan, &mcp_servers);
+
+        // Assert
+        assert_eq!(allowed_tools, CLAUDE_PLAN_ALLOWED_TOOLS);
+        assert!(!allowed_tools.contains("mcp__"));
+    }
+
+    #[test]
+    /// Verifies project MCP servers are passed through an owner-only config
+    /// file and their tools are allowed.
+    fn test_claude_command_passes_project_mcp_servers() {
+        // Arrange
+        let temp_directory = tempdir().expect("failed to create temp dir");
+        let backend = ClaudeBackend;
+        let mcp_servers = [
+            McpServer {
+                command: "docs-mcp".to_string(),
+                name: "docs".to_string(),
+                ..McpServer::default()
+            },
+            McpServer {
+                allowed_tools: vec!["create_issue".to_string(), "get_issue".to_string()],
+                args: vec!["--stdio".to_string()],
+                command: "gh-mcp".to_string(),
+                env: [("GITHUB_TOKEN".to_string(), "token".to_string())].into(),
+                name: "github".to_string(),
+            },
+        ];
+
+        // Act
+        let command = AgentBackend::build_command(
+            &backend,
+            BuildCommandRequest {
+                attachments: &[],
+                folder: temp_directory.path(),
+                mcp_servers: &mcp_servers,
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
+                prompt: "Do work",
+                request_kind: &session_start_request_kind(),
+                model: "claude-sonnet-4-6",
+                reasoning_level: ReasoningLevel::default(),
+            },
+        )
+        .expect("command should build");
+        let args = command
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        // Assert
+        let allowed_tools_pos = args
+            .iter()
+            .position(|arg| arg == "--allowedTools")
+            .expect("--allowedTools flag should be present");
+        assert_eq!(
+            args[allowed_tools_pos + 1],
+            format!(
+                "{CLAUDE_ALLOWED_TOOLS},mcp__docs,mcp__github__create_issue,mcp__github__get_issue"
+            )
+        );
+        let mcp_config_pos = args
+            .iter()
+            .position(|arg| arg == "--mcp-config")
+            .expect("--mcp-config flag should be present");
+        let config_path = PathBuf::from(&args[mcp_config_pos + 1]);
+        assert_eq!(config_path, mcp_config_path(temp_directory.path()));
+        assert!(!args.iter().any(|arg| arg.contains("token")));
+        let mcp_config_mode = std::fs::metadata(&config_path)
+            .expect("MCP config file should exist")
+            .permissions()
+            .mode();
+        let mcp_config = serde_json::from_str::<serde_json::Value>(
+            &std::fs::read_to_string(&config_path).expect("MCP config should be readable"),
+        )
+        .expect("MCP config should be JSON");
+        remove_mcp_config_file(temp_directory.path());
+        assert_eq!(mcp_config_mode & 0o777, MCP_CONFIG_FILE_MODE);
+        assert!(!config_path.exists());
+        assert_eq!(
+            mcp_config,
+            serde_json::json!({
+                "mcpServers": {
+                    "docs": {"type": "stdio", "command": "docs-mcp", "args": [], "env": {}},
+                    "github": {
+                        "type": "stdio",
+                        "command": "gh-mcp",
+                        "args": ["--stdio"],
+                        "env": {"GITHUB_TOKEN": "token"}
+                    }
+                }
+            })
+        );
+    }
+
     #[test]
     /// Verifies the `--effort` flag is passed to Claude with the correct value
     /// for each `ReasoningLevel`.
@@ -259,6 +555,9 @@ mod tests {
                 BuildCommandRequest {
                     attachments: &[],
                     folder: temp_directory.path(),
+                    mcp_servers: &[],
+                    permission_mode: PermissionMode::default(),
+                    project_instructions: None,
                     prompt: "Do work",
                     request_kind: &session_start_request_kind(),
                     model: "claude-sonnet-4-6",
@@ -308,6 +607,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &attachments,
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Inspect [Image #1] and [Image #2]",
                 request_kind: &session_start_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -341,6 +643,9 @@ mod tests {
             build_prompt_stdin_payload(BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Plan prompt",
                 request_kind: &session_start_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -370,6 +675,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Generate title",
                 request_kind: &utility_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -382,6 +690,9 @@ mod tests {
             build_prompt_stdin_payload(BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Generate title",
                 request_kind: &utility_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -414,6 +725,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Return protocol response",
                 request_kind: &session_start_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -426,6 +740,9 @@ mod tests {
             build_prompt_stdin_payload(BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Return protocol response",
                 request_kind: &session_start_request_kind(),
                 model: "claude-sonnet-4-6",
@@ -29,10 +29,15 @@ impl AgentBackend for CodexBackend {
 ///
 /// The prompt payload is sent later over JSON-RPC, so prompt text, request
 /// kind, attachments, and reasoning level do not change the spawned process.
+/// Project MCP servers are registered through `thread/start` config instead
+/// of launch flags.
 fn build_app_server_command(request: BuildCommandRequest<'_>) -> Command {
     let BuildCommandRequest {
         attachments: _attachments,
         folder,
+        mcp_servers: _mcp_servers,
+        permission_mode: _permission_mode,
+        project_instructions: _project_instructions,
         prompt: _prompt,
         request_kind: _request_kind,
         model,
@@ -55,6 +60,7 @@ mod tests {
     use tempfile::tempdir;
 
     use super::*;
+    use crate::domain::permission::PermissionMode;
     use crate::infra::channel::AgentRequestKind;
 
     fn session_start_request_kind() -> AgentRequestKind {
@@ -80,6 +86,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Run checks",
                 request_kind: &session_start_request_kind(),
                 model: "gpt-5.4",
@@ -108,6 +117,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Continue edits",
                 request_kind: &session_resume_request_kind(Some("previous assistant output")),
                 model: "gpt-5.4",
@@ -140,6 +152,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Generate title",
                 request_kind: &AgentRequestKind::UtilityPrompt,
                 model: "gpt-5.4",
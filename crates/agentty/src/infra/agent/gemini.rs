@@ -25,10 +25,14 @@ impl AgentBackend for GeminiBackend {
 ///
 /// Prompt submission and resume behavior happen over ACP after the process is
 /// running, so startup only depends on the working directory and model.
+/// Project MCP servers are registered through `session/new` instead.
 fn build_app_server_command(request: BuildCommandRequest<'_>) -> Command {
     let BuildCommandRequest {
         attachments: _attachments,
         folder,
+        mcp_servers: _mcp_servers,
+        permission_mode: _permission_mode,
+        project_instructions: _project_instructions,
         prompt: _prompt,
         request_kind: _request_kind,
         model,
@@ -57,6 +61,7 @@ pub(super) fn build_prompt_stdin_payload(
         } else {
             super::instruction::InstructionDeliveryMode::BootstrapFull
         },
+        project_instructions: request.project_instructions,
         prompt: request.prompt,
         protocol_profile: request.request_kind.protocol_profile(),
         replay_session_output: request.request_kind.session_output(),
@@ -71,6 +76,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::ReasoningLevel;
+    use crate::domain::permission::PermissionMode;
     use crate::infra::channel::AgentRequestKind;
 
     fn session_start_request_kind() -> AgentRequestKind {
@@ -110,6 +116,9 @@ mod tests {
             build_prompt_stdin_payload(BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Plan prompt",
                 request_kind: &session_start_request_kind(),
                 model: "gemini-3-flash-preview",
@@ -138,6 +147,9 @@ mod tests {
             BuildCommandRequest {
                 attachments: &[],
                 folder: temp_directory.path(),
+                mcp_servers: &[],
+                permission_mode: PermissionMode::default(),
+                project_instructions: None,
                 prompt: "Generate title",
                 request_kind: &utility_request_kind(),
                 model: "gemini-3-flash-preview",
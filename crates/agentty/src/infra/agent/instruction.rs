@@ -2,6 +2,10 @@
 
 use crate::infra::channel::AgentRequestKind;
 
+/// Separator between the provider conversation id and the project
+/// instructions fingerprint inside one persisted bootstrap marker.
+const PROJECT_INSTRUCTIONS_MARKER_SEPARATOR: &str = "#project-instructions=";
+
 /// Normalizes one provider-native conversation id for persisted bootstrap
 /// reuse tracking.
 pub(crate) fn normalize_instruction_conversation_id(
@@ -13,6 +17,50 @@ pub(crate) fn normalize_instruction_conversation_id(
         .map(ToString::to_string)
 }
 
+/// Builds the persisted bootstrap marker for one provider conversation.
+///
+/// The marker is the normalized provider conversation id, suffixed with a
+/// fingerprint of the project instructions delivered in that conversation so
+/// later turns can detect when the instructions changed.
+pub(crate) fn instruction_bootstrap_marker(
+    provider_conversation_id: Option<&str>,
+    project_instructions: Option<&str>,
+) -> Option<String> {
+    let conversation_id = normalize_instruction_conversation_id(provider_conversation_id)?;
+
+    match project_instructions {
+        Some(project_instructions) => Some(format!(
+            "{conversation_id}{PROJECT_INSTRUCTIONS_MARKER_SEPARATOR}{:016x}",
+            project_instructions_fingerprint(project_instructions)
+        )),
+        None => Some(conversation_id),
+    }
+}
+
+/// Returns the provider conversation id part of one persisted bootstrap
+/// marker.
+fn marker_conversation_id(marker: &str) -> &str {
+    marker
+        .split_once(PROJECT_INSTRUCTIONS_MARKER_SEPARATOR)
+        .map_or(marker, |(conversation_id, _)| conversation_id)
+}
+
+/// Computes a stable FNV-1a fingerprint of project instructions.
+///
+/// The fingerprint is persisted across runs, so it avoids
+/// [`std::collections::hash_map::DefaultHasher`], whose output may change
+/// between Rust releases.
+fn project_instructions_fingerprint(project_instructions: &str) -> u64 {
+    const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    project_instructions
+        .bytes()
+        .fold(FNV_OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(FNV_PRIME)
+        })
+}
+
 /// Prompt-shaping mode used for one app-server turn attempt.
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
 pub(crate) enum InstructionDeliveryMode {
@@ -21,6 +69,10 @@ pub(crate) enum InstructionDeliveryMode {
     /// Reuse the existing provider-managed bootstrap and send only a compact
     /// reminder.
     DeltaOnly,
+    /// Reuse the existing provider-managed bootstrap and send the compact
+    /// reminder together with project instructions that changed since the
+    /// bootstrap.
+    DeltaWithProjectInstructions,
     /// Re-send the full instruction contract while replaying the transcript
     /// after context loss.
     BootstrapWithReplay,
@@ -28,10 +80,16 @@ pub(crate) enum InstructionDeliveryMode {
 
 /// Plans how one app-server turn should deliver Agentty's instruction
 /// contract.
+///
+/// `persisted_instruction_conversation_id` is the marker built by
+/// [`instruction_bootstrap_marker`] after the last completed turn, so a
+/// matching conversation with different `project_instructions` only needs
+/// the updated instructions instead of a full bootstrap.
 pub(crate) fn plan_app_server_instruction_delivery(
     request_kind: &AgentRequestKind,
     current_provider_conversation_id: Option<&str>,
     persisted_instruction_conversation_id: Option<&str>,
+    project_instructions: Option<&str>,
     should_replay_session_output: bool,
 ) -> InstructionDeliveryMode {
     if should_replay_session_output {
@@ -42,12 +100,22 @@ pub(crate) fn plan_app_server_instruction_delivery(
         return InstructionDeliveryMode::BootstrapFull;
     }
 
-    if normalize_instruction_conversation_id(current_provider_conversation_id).as_deref()
-        == persisted_instruction_conversation_id
-    {
+    let current_marker =
+        instruction_bootstrap_marker(current_provider_conversation_id, project_instructions);
+    if current_marker.as_deref() == persisted_instruction_conversation_id {
         return InstructionDeliveryMode::DeltaOnly;
     }
 
+    let is_same_conversation = current_marker
+        .as_deref()
+        .zip(persisted_instruction_conversation_id)
+        .is_some_and(|(current_marker, persisted_marker)| {
+            marker_conversation_id(current_marker) == marker_conversation_id(persisted_marker)
+        });
+    if is_same_conversation {
+        return InstructionDeliveryMode::DeltaWithProjectInstructions;
+    }
+
     InstructionDeliveryMode::BootstrapFull
 }
 
@@ -70,6 +138,7 @@ mod tests {
             },
             Some("thread-123"),
             persisted_instruction_conversation_id.as_deref(),
+            None,
             false,
         );
 
@@ -92,6 +161,7 @@ mod tests {
             },
             Some("thread-456"),
             persisted_instruction_conversation_id.as_deref(),
+            None,
             true,
         );
 
@@ -113,6 +183,7 @@ mod tests {
             },
             Some("thread-456"),
             persisted_instruction_conversation_id.as_deref(),
+            None,
             false,
         );
 
@@ -133,10 +204,78 @@ mod tests {
             &AgentRequestKind::UtilityPrompt,
             Some("thread-123"),
             persisted_instruction_conversation_id.as_deref(),
+            None,
             false,
         );
 
         // Assert
         assert_eq!(mode, InstructionDeliveryMode::BootstrapFull);
     }
+
+    #[test]
+    /// Re-sends only the project instructions when they changed inside the
+    /// still-active provider conversation.
+    fn test_plan_app_server_instruction_delivery_sends_changed_project_instructions() {
+        // Arrange
+        let persisted_instruction_conversation_id =
+            instruction_bootstrap_marker(Some("thread-123"), Some("Use tabs."));
+
+        // Act
+        let changed_mode = plan_app_server_instruction_delivery(
+            &AgentRequestKind::SessionResume {
+                session_output: None,
+            },
+            Some("thread-123"),
+            persisted_instruction_conversation_id.as_deref(),
+            Some("Use spaces."),
+            false,
+        );
+        let removed_mode = plan_app_server_instruction_delivery(
+            &AgentRequestKind::SessionResume {
+                session_output: None,
+            },
+            Some("thread-123"),
+            persisted_instruction_conversation_id.as_deref(),
+            None,
+            false,
+        );
+        let unchanged_mode = plan_app_server_instruction_delivery(
+            &AgentRequestKind::SessionResume {
+                session_output: None,
+            },
+            Some("thread-123"),
+            persisted_instruction_conversation_id.as_deref(),
+            Some("Use tabs."),
+            false,
+        );
+
+        // Assert
+        assert_eq!(
+            changed_mode,
+            InstructionDeliveryMode::DeltaWithProjectInstructions
+        );
+        assert_eq!(
+            removed_mode,
+            InstructionDeliveryMode::DeltaWithProjectInstructions
+        );
+        assert_eq!(unchanged_mode, InstructionDeliveryMode::DeltaOnly);
+    }
+
+    #[test]
+    /// Keeps the bootstrap marker equal to the plain conversation id when no
+    /// project instructions are configured.
+    fn test_instruction_bootstrap_marker_appends_fingerprint_only_for_instructions() {
+        // Arrange
+        let conversation_id = Some(" thread-123 ");
+
+        // Act
+        let plain_marker = instruction_bootstrap_marker(conversation_id, None);
+        let instructions_marker = instruction_bootstrap_marker(conversation_id, Some("Use tabs."));
+
+        // Assert
+        assert_eq!(plain_marker.as_deref(), Some("thread-123"));
+        let instructions_marker = instructions_marker.expect("marker should be built");
+        assert!(instructions_marker.starts_with("thread-123#project-instructions="));
+        assert_eq!(marker_conversation_id(&instructions_marker), "thread-123");
+    }
 }
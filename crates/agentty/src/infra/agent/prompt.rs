@@ -31,6 +31,9 @@ struct ResumeWithSessionOutputPromptTemplate<'a> {
 #[derive(Template)]
 #[template(path = "protocol_instruction_prompt.md", escape = "none")]
 struct ProtocolInstructionPromptTemplate<'a> {
+    /// Project-specific instructions configured in Agentty, rendered after
+    /// the shared path and quality-check rules when present.
+    project_instructions: Option<&'a str>,
     /// Request-family-specific instructions that reinforce the expected
     /// response shape for the active prompt type.
     protocol_usage_instructions: &'a str,
@@ -45,6 +48,9 @@ struct ProtocolInstructionPromptTemplate<'a> {
 #[derive(Template)]
 #[template(path = "protocol_refresh_prompt.md", escape = "none")]
 struct ProtocolRefreshPromptTemplate<'a> {
+    /// Rendered project-instructions update appended after the reminder
+    /// when the instructions changed since the bootstrap.
+    project_instructions_update: Option<&'a str>,
     /// Request-family-specific reminder that reinforces the expected response
     /// shape for the active prompt type.
     protocol_refresh_instructions: &'a str,
@@ -52,6 +58,15 @@ struct ProtocolRefreshPromptTemplate<'a> {
     prompt: &'a str,
 }
 
+/// Askama view model for rendering changed project instructions inside the
+/// compact refresh reminder.
+#[derive(Template)]
+#[template(path = "project_instructions_update_prompt.md", escape = "none")]
+struct ProjectInstructionsUpdatePromptTemplate<'a> {
+    /// Current project instructions, or `None` when they were removed.
+    project_instructions: Option<&'a str>,
+}
+
 /// Askama view model for rendering session-turn protocol usage guidance.
 #[derive(Template)]
 #[template(path = "protocol_instruction_session_turn_usage.md", escape = "none")]
@@ -80,6 +95,8 @@ struct UtilityPromptProtocolRefreshInstructionsTemplate;
 pub(crate) struct PromptPreparationRequest<'a> {
     /// Delivery mode selected for the current provider attempt.
     pub instruction_delivery_mode: InstructionDeliveryMode,
+    /// Project-specific instructions configured in Agentty for this turn.
+    pub project_instructions: Option<&'a str>,
     /// Base user prompt before replay wrapping and protocol instructions.
     pub prompt: &'a str,
     /// Protocol family that determines the rendered instruction envelope.
@@ -96,16 +113,32 @@ pub(crate) fn prepare_prompt_text(
     request: PromptPreparationRequest<'_>,
 ) -> Result<String, AgentBackendError> {
     match request.instruction_delivery_mode {
-        InstructionDeliveryMode::BootstrapFull => {
-            prepend_protocol_instructions(request.prompt, request.protocol_profile)
-        }
+        InstructionDeliveryMode::BootstrapFull => prepend_protocol_instructions(
+            request.prompt,
+            request.protocol_profile,
+            request.project_instructions,
+        ),
         InstructionDeliveryMode::DeltaOnly => {
-            prepend_protocol_refresh_reminder(request.prompt, request.protocol_profile)
+            prepend_protocol_refresh_reminder(request.prompt, request.protocol_profile, None)
+        }
+        InstructionDeliveryMode::DeltaWithProjectInstructions => {
+            let project_instructions_update =
+                render_project_instructions_update(request.project_instructions)?;
+
+            prepend_protocol_refresh_reminder(
+                request.prompt,
+                request.protocol_profile,
+                Some(&project_instructions_update),
+            )
         }
         InstructionDeliveryMode::BootstrapWithReplay => {
             let prompt = build_resume_prompt(request.prompt, request.replay_session_output)?;
 
-            prepend_protocol_instructions(&prompt, request.protocol_profile)
+            prepend_protocol_instructions(
+                &prompt,
+                request.protocol_profile,
+                request.project_instructions,
+            )
         }
     }
 }
@@ -141,9 +174,10 @@ pub(crate) fn build_resume_prompt(
 /// rendered answers. The shared prompt contract also reminds agents to run the
 /// repository-defined quality checks for touched files and the affected
 /// dependency graph, or to fall back to the full repository validation suite
-/// when targeted coverage is unclear. If the prompt already contains the
-/// protocol marker, this function returns the prompt unchanged to avoid
-/// duplicated guidance.
+/// when targeted coverage is unclear. Project instructions configured in
+/// Agentty are rendered right after those shared rules. If the prompt already
+/// contains the protocol marker, this function returns the prompt unchanged to
+/// avoid duplicated guidance.
 ///
 /// # Errors
 /// Returns an error if Askama template rendering fails.
@@ -152,6 +186,7 @@ pub(crate) fn build_resume_prompt(
 pub(crate) fn prepend_protocol_instructions(
     prompt: &str,
     profile: ProtocolRequestProfile,
+    project_instructions: Option<&str>,
 ) -> Result<String, AgentBackendError> {
     if prompt.contains(PROTOCOL_INSTRUCTIONS_MARKER) {
         return Ok(prompt.to_string());
@@ -160,6 +195,7 @@ pub(crate) fn prepend_protocol_instructions(
     let response_json_schema = protocol::agent_response_json_schema_json();
     let protocol_usage_instructions = render_protocol_usage_instructions(profile)?;
     let template = ProtocolInstructionPromptTemplate {
+        project_instructions,
         protocol_usage_instructions: &protocol_usage_instructions,
         prompt,
         response_json_schema: &response_json_schema,
@@ -170,9 +206,13 @@ pub(crate) fn prepend_protocol_instructions(
 
 /// Prepends a compact refresh reminder for providers that already received
 /// the full instruction contract in the active context.
+///
+/// `project_instructions_update` carries the rendered project-instructions
+/// update when the instructions changed since the bootstrap.
 pub(crate) fn prepend_protocol_refresh_reminder(
     prompt: &str,
     profile: ProtocolRequestProfile,
+    project_instructions_update: Option<&str>,
 ) -> Result<String, AgentBackendError> {
     if prompt.contains(PROTOCOL_INSTRUCTIONS_MARKER)
         || prompt.contains(PROTOCOL_REFRESH_REMINDER_MARKER)
@@ -182,6 +222,7 @@ pub(crate) fn prepend_protocol_refresh_reminder(
 
     let protocol_refresh_instructions = render_protocol_refresh_instructions(profile)?;
     let template = ProtocolRefreshPromptTemplate {
+        project_instructions_update,
         protocol_refresh_instructions: &protocol_refresh_instructions,
         prompt,
     };
@@ -189,6 +230,19 @@ pub(crate) fn prepend_protocol_refresh_reminder(
     render_template("protocol_refresh_prompt.md", &template)
 }
 
+/// Renders the refresh-reminder section announcing changed or removed
+/// project instructions.
+fn render_project_instructions_update(
+    project_instructions: Option<&str>,
+) -> Result<String, AgentBackendError> {
+    render_template(
+        "project_instructions_update_prompt.md",
+        &ProjectInstructionsUpdatePromptTemplate {
+            project_instructions,
+        },
+    )
+}
+
 /// Returns request-family-specific protocol guidance for the shared prompt
 /// preamble from Askama-backed markdown templates.
 fn render_protocol_usage_instructions(
@@ -294,7 +348,7 @@ mod tests {
 
         // Act
         let rendered_prompt =
-            prepend_protocol_instructions(prompt, ProtocolRequestProfile::SessionTurn)
+            prepend_protocol_instructions(prompt, ProtocolRequestProfile::SessionTurn, None)
                 .expect("protocol instruction prompt should render");
 
         // Assert
@@ -330,13 +384,16 @@ mod tests {
     /// Ensures protocol instructions are not duplicated when already present.
     fn test_prepend_protocol_instructions_is_idempotent() {
         // Arrange
-        let prompt =
-            prepend_protocol_instructions("Implement feature", ProtocolRequestProfile::SessionTurn)
-                .expect("protocol instruction prompt should render");
+        let prompt = prepend_protocol_instructions(
+            "Implement feature",
+            ProtocolRequestProfile::SessionTurn,
+            None,
+        )
+        .expect("protocol instruction prompt should render");
 
         // Act
         let rendered_prompt =
-            prepend_protocol_instructions(&prompt, ProtocolRequestProfile::UtilityPrompt)
+            prepend_protocol_instructions(&prompt, ProtocolRequestProfile::UtilityPrompt, None)
                 .expect("protocol instruction prompt should render");
 
         // Assert
@@ -352,7 +409,7 @@ mod tests {
 
         // Act
         let rendered_prompt =
-            prepend_protocol_instructions(prompt, ProtocolRequestProfile::UtilityPrompt)
+            prepend_protocol_instructions(prompt, ProtocolRequestProfile::UtilityPrompt, None)
                 .expect("protocol instruction prompt should render");
 
         // Assert
@@ -371,6 +428,7 @@ mod tests {
         // Arrange
         let request = PromptPreparationRequest {
             instruction_delivery_mode: InstructionDeliveryMode::BootstrapWithReplay,
+            project_instructions: None,
             prompt: "Continue edits",
             protocol_profile: ProtocolRequestProfile::SessionTurn,
             replay_session_output: Some("previous output"),
@@ -394,7 +452,7 @@ mod tests {
 
         // Act
         let rendered_prompt =
-            prepend_protocol_refresh_reminder(prompt, ProtocolRequestProfile::SessionTurn)
+            prepend_protocol_refresh_reminder(prompt, ProtocolRequestProfile::SessionTurn, None)
                 .expect("protocol refresh reminder should render");
 
         // Assert
@@ -412,6 +470,7 @@ mod tests {
         // Arrange
         let request = PromptPreparationRequest {
             instruction_delivery_mode: InstructionDeliveryMode::DeltaOnly,
+            project_instructions: None,
             prompt: "Continue edits",
             protocol_profile: ProtocolRequestProfile::SessionTurn,
             replay_session_output: Some("previous output"),
@@ -426,4 +485,69 @@ mod tests {
         assert!(!prepared_prompt.contains("previous output"));
         assert!(prepared_prompt.ends_with("Continue edits"));
     }
+
+    #[test]
+    /// Ensures configured project instructions render between the shared
+    /// rules and the structured response protocol.
+    fn test_prepend_protocol_instructions_includes_project_instructions() {
+        // Arrange
+        let prompt = "Implement feature";
+        let project_instructions = "Run `make lint` before finishing.";
+
+        // Act
+        let rendered_prompt = prepend_protocol_instructions(
+            prompt,
+            ProtocolRequestProfile::SessionTurn,
+            Some(project_instructions),
+        )
+        .expect("protocol instruction prompt should render");
+        let rendered_without_instructions =
+            prepend_protocol_instructions(prompt, ProtocolRequestProfile::SessionTurn, None)
+                .expect("protocol instruction prompt should render");
+
+        // Assert
+        let instructions_index = rendered_prompt
+            .find(project_instructions)
+            .expect("project instructions should render");
+        let protocol_index = rendered_prompt
+            .find(PROTOCOL_INSTRUCTIONS_MARKER)
+            .expect("protocol marker should render");
+        assert!(rendered_prompt.contains("Project instructions:"));
+        assert!(instructions_index < protocol_index);
+        assert!(!rendered_without_instructions.contains("Project instructions:"));
+        assert!(rendered_without_instructions.contains(
+            "full repository test/check suite instead.\n\nStructured response protocol:"
+        ));
+        assert!(rendered_prompt.ends_with(prompt));
+    }
+
+    #[test]
+    /// Ensures changed project instructions ride along with the compact
+    /// reminder instead of forcing a full bootstrap.
+    fn test_prepare_prompt_text_sends_changed_project_instructions_with_reminder() {
+        // Arrange
+        let changed_request = PromptPreparationRequest {
+            instruction_delivery_mode: InstructionDeliveryMode::DeltaWithProjectInstructions,
+            project_instructions: Some("Use tabs for indentation."),
+            prompt: "Continue edits",
+            protocol_profile: ProtocolRequestProfile::SessionTurn,
+            replay_session_output: None,
+        };
+        let removed_request = PromptPreparationRequest {
+            project_instructions: None,
+            ..changed_request
+        };
+
+        // Act
+        let changed_prompt = prepare_prompt_text(changed_request).expect("prompt should render");
+        let removed_prompt = prepare_prompt_text(removed_request).expect("prompt should render");
+
+        // Assert
+        assert!(changed_prompt.contains(PROTOCOL_REFRESH_REMINDER_MARKER));
+        assert!(changed_prompt.contains("Project instructions update:"));
+        assert!(changed_prompt.contains("Use tabs for indentation."));
+        assert!(!changed_prompt.contains("Authoritative JSON Schema:"));
+        assert!(changed_prompt.ends_with("Continue edits"));
+        assert!(removed_prompt.contains("project instructions were removed"));
+    }
 }
@@ -31,7 +31,37 @@ pub enum ProtocolRequestProfile {
     UtilityPrompt,
 }
 
-/// One extracted question with predefined answer choices.
+/// Answer widget the question UI renders for one clarification question.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(
+    title = "QuestionKind",
+    description = "Answer widget shown for one clarification question."
+)]
+pub enum QuestionKind {
+    /// Pick exactly one of `options`, or type a custom answer.
+    #[default]
+    SingleChoice,
+    /// Check any number of `options`, optionally adding a custom answer.
+    MultiSelect,
+    /// Type a free-form answer. `options` are ignored.
+    FreeText,
+    /// Answer yes or no. `options` are ignored.
+    Confirm,
+    /// Pick one file or directory from the session worktree. `options` are
+    /// ignored.
+    FilePick,
+}
+
+impl QuestionKind {
+    /// Returns whether this kind offers a free-text input next to its
+    /// options.
+    pub fn allows_free_text(self) -> bool {
+        !matches!(self, Self::Confirm)
+    }
+}
+
+/// One extracted question with its answer widget and predefined choices.
 ///
 /// The UI and persistence layers use this as the canonical clarification
 /// question representation.
@@ -42,13 +72,24 @@ pub enum ProtocolRequestProfile {
                    each item focused to one actionable decision."
 )]
 pub struct QuestionItem {
+    /// Answer widget used to collect the response.
+    #[serde(default)]
+    #[schemars(
+        title = "kind",
+        description = "Answer widget for this question: `single_choice` picks one option, \
+                       `multi_select` checks any number of options, `free_text` asks for typed \
+                       text, `confirm` asks yes or no, and `file_pick` picks one file from the \
+                       session worktree. Defaults to `single_choice` when omitted."
+    )]
+    pub kind: QuestionKind,
     /// Predefined answer choices the user can select from.
     #[serde(default)]
     #[schemars(
         title = "options",
-        description = "Predefined answer choices the user can select from. Keep this list focused \
-                       to 1-3 likely answers, put the recommended choice first, and omit deferral \
-                       or non-answer choices. Defaults to an empty list when omitted."
+        description = "Predefined answer choices for `single_choice` and `multi_select` \
+                       questions. Keep this list focused to 1-3 likely answers, put the \
+                       recommended choice first, and omit deferral or non-answer choices. Use an \
+                       empty list for other kinds. Defaults to an empty list when omitted."
     )]
     pub options: Vec<String>,
     /// The clarification question text.
@@ -60,11 +101,15 @@ pub struct QuestionItem {
     pub text: String,
 }
 
+/// Choices offered for [`QuestionKind::Confirm`] questions.
+const CONFIRM_OPTIONS: [&str; 2] = ["Yes", "No"];
+
 impl QuestionItem {
     /// Constructs one clarification question without predefined answer
     /// options.
     pub fn new(text: impl Into<String>) -> Self {
         Self {
+            kind: QuestionKind::default(),
             options: Vec::new(),
             text: text.into(),
         }
@@ -73,10 +118,31 @@ impl QuestionItem {
     /// Constructs one clarification question with predefined answer options.
     pub fn with_options(text: impl Into<String>, options: Vec<String>) -> Self {
         Self {
+            kind: QuestionKind::default(),
             options,
             text: text.into(),
         }
     }
+
+    /// Returns this question with its answer widget replaced by `kind`.
+    #[must_use]
+    pub fn with_kind(mut self, kind: QuestionKind) -> Self {
+        self.kind = kind;
+
+        self
+    }
+
+    /// Returns the choices the question UI lists for this question's kind.
+    ///
+    /// Confirm questions always offer `Yes` and `No`, while free-text and
+    /// file-pick questions offer no choices.
+    pub fn answer_options(&self) -> Vec<String> {
+        match self.kind {
+            QuestionKind::SingleChoice | QuestionKind::MultiSelect => self.options.clone(),
+            QuestionKind::Confirm => CONFIRM_OPTIONS.map(ToString::to_string).to_vec(),
+            QuestionKind::FreeText | QuestionKind::FilePick => Vec::new(),
+        }
+    }
 }
 
 /// Structured session summary block emitted alongside protocol messages.
@@ -106,6 +172,59 @@ pub struct AgentResponseSummary {
     pub turn: String,
 }
 
+/// One ordered step of a proposed implementation plan.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(
+    title = "AgentResponsePlanStep",
+    description = "One ordered implementation step with the files it touches."
+)]
+pub struct AgentResponsePlanStep {
+    /// Worktree-relative files the step expects to touch.
+    #[serde(default)]
+    #[schemars(
+        title = "files",
+        description = "Worktree-relative paths the step creates, edits, or deletes. Defaults to \
+                       an empty list when omitted."
+    )]
+    pub files: Vec<String>,
+    /// Step description.
+    #[schemars(
+        title = "text",
+        description = "One concise, actionable step description."
+    )]
+    pub text: String,
+}
+
+/// Structured plan block emitted by plan-first session turns.
+///
+/// Read-only planning turns fill `steps`, while write-enabled turns that
+/// follow an approved plan report progress through `completed_steps`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(
+    title = "AgentResponsePlan",
+    description = "Structured plan block for plan-first sessions. Propose `steps` on read-only \
+                   planning turns and report `completed_steps` while implementing an approved \
+                   plan."
+)]
+pub struct AgentResponsePlan {
+    /// 1-based numbers of approved steps finished so far.
+    #[serde(default)]
+    #[schemars(
+        title = "completed_steps",
+        description = "1-based numbers of approved plan steps that are finished, including steps \
+                       finished in earlier turns. Use an empty array while planning."
+    )]
+    pub completed_steps: Vec<u32>,
+    /// Ordered proposed steps.
+    #[serde(default)]
+    #[schemars(
+        title = "steps",
+        description = "Ordered implementation steps proposed on a planning turn. Use an empty \
+                       array when reporting progress on an approved plan."
+    )]
+    pub steps: Vec<AgentResponsePlanStep>,
+}
+
 /// Wire-format protocol payload used for schema-driven provider output.
 ///
 /// Providers that support output schemas (for example, Codex app-server) are
@@ -128,6 +247,15 @@ pub struct AgentResponse {
                        them through `questions` instead."
     )]
     pub answer: String,
+    /// Structured plan for plan-first sessions, or `None` for regular turns.
+    #[serde(default)]
+    #[schemars(
+        title = "plan",
+        description = "Structured plan for plan-first sessions. Populate it only when the prompt \
+                       asks for a plan or reports progress on an approved plan; otherwise use \
+                       `null`."
+    )]
+    pub plan: Option<AgentResponsePlan>,
     /// Ordered clarification questions emitted for this turn.
     #[serde(default)]
     #[schemars(
@@ -174,6 +302,7 @@ impl AgentResponse {
     pub fn plain(text: impl Into<String>) -> Self {
         Self {
             answer: text.into(),
+            plan: None,
             questions: Vec::new(),
             summary: None,
         }
@@ -242,6 +371,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: "Primary answer".to_string(),
+            plan: None,
             questions: vec![QuestionItem::new("Need one clarification.")],
             summary: None,
         };
@@ -259,6 +389,7 @@ mod tests {
         // Arrange
         let response = AgentResponse {
             answer: String::new(),
+            plan: None,
             questions: (0..=MAX_QUESTIONS)
                 .map(|index| QuestionItem::new(format!("Question {index}")))
                 .collect(),
@@ -271,4 +402,48 @@ mod tests {
         // Assert
         assert_eq!(questions.len(), MAX_QUESTIONS);
     }
+
+    #[test]
+    /// Ensures omitted question kinds deserialize as single choice.
+    fn test_question_item_deserializes_missing_kind_as_single_choice() {
+        // Arrange
+        let raw = r#"{"text":"Which approach?","options":["A","B"]}"#;
+
+        // Act
+        let question =
+            serde_json::from_str::<QuestionItem>(raw).expect("question should deserialize");
+
+        // Assert
+        assert_eq!(question.kind, QuestionKind::SingleChoice);
+        assert_eq!(
+            question.answer_options(),
+            vec!["A".to_string(), "B".to_string()]
+        );
+    }
+
+    #[test]
+    /// Ensures each question kind lists the choices its widget renders.
+    fn test_question_item_answer_options_follow_kind() {
+        // Arrange
+        let options = vec!["A".to_string(), "B".to_string()];
+        let multi_select = QuestionItem::with_options("Pick some", options.clone())
+            .with_kind(QuestionKind::MultiSelect);
+        let confirm = QuestionItem::with_options("Proceed?", options.clone())
+            .with_kind(QuestionKind::Confirm);
+        let free_text =
+            QuestionItem::with_options("Why?", options.clone()).with_kind(QuestionKind::FreeText);
+        let file_pick = QuestionItem::new("Which file?").with_kind(QuestionKind::FilePick);
+
+        // Act
+        let multi_select_options = multi_select.answer_options();
+        let confirm_options = confirm.answer_options();
+        let free_text_options = free_text.answer_options();
+        let file_pick_options = file_pick.answer_options();
+
+        // Assert
+        assert_eq!(multi_select_options, options);
+        assert_eq!(confirm_options, vec!["Yes".to_string(), "No".to_string()]);
+        assert!(free_text_options.is_empty());
+        assert!(file_pick_options.is_empty());
+    }
 }
@@ -31,7 +31,8 @@ pub(crate) fn normalize_turn_response(
 /// Parses one raw assistant message strictly as protocol payload.
 ///
 /// The final assistant payload must match [`AgentResponse`] and contain at
-/// least one recognized protocol key (`answer`, `questions`, or `summary`).
+/// least one recognized protocol key (`answer`, `plan`, `questions`, or
+/// `summary`).
 ///
 /// When a provider prepends stray prose before the final schema object, this
 /// still recovers the trailing protocol payload as long as nothing except
@@ -147,8 +148,8 @@ pub(crate) fn format_protocol_parse_debug_details(raw: &str) -> String {
 /// Attempts to parse one schema-driven structured JSON response.
 ///
 /// The raw text must parse as a JSON object containing at least one
-/// recognized protocol key (`answer`, `questions`, or `summary`). Returns
-/// `None` when parsing fails or no recognized keys are present.
+/// recognized protocol key (`answer`, `plan`, `questions`, or `summary`).
+/// Returns `None` when parsing fails or no recognized keys are present.
 fn parse_structured_json_response(raw: &str) -> Option<AgentResponse> {
     parse_structured_json_response_with_reason(raw).ok()
 }
@@ -179,7 +180,7 @@ fn parse_structured_json_response_with_reason(
 }
 
 /// Top-level keys the protocol recognizes in a structured response payload.
-const PROTOCOL_KEYS: &[&str] = &["answer", "questions", "summary"];
+const PROTOCOL_KEYS: &[&str] = &["answer", "plan", "questions", "summary"];
 
 /// Returns whether a parsed JSON value is an object containing at least one
 /// recognized protocol key.
@@ -378,6 +379,7 @@ fn format_debug_preview(raw: &str, max_chars: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::infra::agent::protocol::{AgentResponsePlan, AgentResponsePlanStep};
 
     #[test]
     /// Strict parsing accepts a complete schema payload.
@@ -415,6 +417,29 @@ mod tests {
         );
     }
 
+    #[test]
+    /// Strict parsing accepts plan-only payloads emitted by read-only
+    /// planning turns.
+    fn test_parse_agent_response_strict_plan_only_payload() {
+        // Arrange
+        let raw = r#"{"plan":{"steps":[{"text":"Add the column","files":["a.sql"]}]}}"#;
+
+        // Act
+        let response = parse_agent_response_strict(raw);
+
+        // Assert
+        assert_eq!(
+            response.expect("response should parse").plan,
+            Some(AgentResponsePlan {
+                completed_steps: Vec::new(),
+                steps: vec![AgentResponsePlanStep {
+                    files: vec!["a.sql".to_string()],
+                    text: "Add the column".to_string(),
+                }],
+            })
+        );
+    }
+
     #[test]
     /// Strict parsing recovers a trailing protocol payload when a provider
     /// prepends extra prose before the final JSON object.
@@ -480,7 +505,9 @@ mod tests {
         assert!(details.contains("direct_json_type: object"));
         assert!(details.contains("direct_json_keys: message"));
         assert!(details.contains("direct_json_recognized_protocol_keys: (none)"));
-        assert!(details.contains("direct_json_missing_protocol_keys: answer, questions, summary"));
+        assert!(
+            details.contains("direct_json_missing_protocol_keys: answer, plan, questions, summary")
+        );
     }
 
     #[test]
@@ -161,6 +161,7 @@ fn normalize_schema_for_transport(value: &mut Value) {
 
             normalize_ref_object_for_codex(object);
             normalize_required_for_codex(object);
+            normalize_integer_format_for_codex(object);
 
             let one_of_values = object
                 .get("oneOf")
@@ -233,6 +234,17 @@ fn normalize_required_for_codex(object: &mut serde_json::Map<String, Value>) {
     }
 }
 
+/// Drops Rust width hints such as `"format": "uint32"` from integer schemas.
+///
+/// Schemars annotates unsigned integers with formats that Codex does not
+/// recognize, while the `minimum` bound already carries the useful
+/// constraint.
+fn normalize_integer_format_for_codex(object: &mut serde_json::Map<String, Value>) {
+    if object.get("type").and_then(Value::as_str) == Some("integer") {
+        object.remove("format");
+    }
+}
+
 /// Rewrites one `$ref` schema object to Codex-compatible form.
 ///
 /// Codex rejects sibling keywords alongside `$ref` (for example
@@ -505,6 +517,7 @@ mod tests {
             "Human-readable markdown text for this question. Ask one specific actionable question \
              instead of bundling multiple decisions into one item.",
         );
+        assert_schema_property_title(question_properties, "kind", "kind");
         assert_schema_property_title(question_properties, "options", "options");
         assert_schema_property_title_and_description(
             summary_properties,
@@ -555,6 +568,83 @@ mod tests {
                 .all(|field| field.as_str() != Some("options")),
             "question schema should keep `options` optional for omitted empty lists"
         );
+        assert!(
+            question_required_fields
+                .iter()
+                .all(|field| field.as_str() != Some("kind")),
+            "question schema should keep `kind` optional so single choice stays the default"
+        );
+        assert!(
+            response_required_fields
+                .iter()
+                .all(|field| field.as_str() != Some("plan")),
+            "raw prompt schema should keep `plan` optional for regular sessions"
+        );
+    }
+
+    #[test]
+    /// Exposes the plan block with integer step numbers Codex accepts.
+    fn test_agent_response_output_schema_describes_plan_block() {
+        // Arrange / Act
+        let schema = agent_response_output_schema();
+        let plan_definition = schema
+            .get("$defs")
+            .and_then(|value| value.get("AgentResponsePlan"))
+            .and_then(Value::as_object)
+            .expect("plan definition should exist");
+        let completed_steps_items = plan_definition
+            .get("properties")
+            .and_then(|value| value.get("completed_steps"))
+            .and_then(|value| value.get("items"))
+            .expect("completed steps items should exist");
+
+        // Assert
+        assert!(
+            schema
+                .get("properties")
+                .and_then(Value::as_object)
+                .is_some_and(|properties| properties.contains_key("plan"))
+        );
+        assert!(
+            plan_definition
+                .get("properties")
+                .and_then(Value::as_object)
+                .is_some_and(|properties| properties.contains_key("steps"))
+        );
+        assert_eq!(
+            completed_steps_items.get("type").and_then(Value::as_str),
+            Some("integer")
+        );
+        assert!(!contains_schema_key(&schema, "format"));
+    }
+
+    #[test]
+    /// Exposes every question kind as a plain string enum in the transport
+    /// schema.
+    fn test_agent_response_output_schema_lists_question_kinds() {
+        // Arrange / Act
+        let schema = agent_response_output_schema();
+        let kind_definition = schema
+            .get("$defs")
+            .and_then(|value| value.get("QuestionKind"))
+            .and_then(Value::as_object)
+            .expect("question kind definition should exist");
+
+        // Assert
+        assert_eq!(
+            kind_definition.get("type").and_then(Value::as_str),
+            Some("string")
+        );
+        assert_eq!(
+            kind_definition.get("enum"),
+            Some(&serde_json::json!([
+                "single_choice",
+                "multi_select",
+                "free_text",
+                "confirm",
+                "file_pick"
+            ]))
+        );
     }
 
     #[test]
@@ -1,5 +1,6 @@
 //! Shared provider registry and transport policy descriptors.
 
+use std::path::Path;
 use std::sync::Arc;
 
 use super::backend::{
@@ -10,6 +11,7 @@ use super::protocol;
 use super::response_parser::ParsedResponse;
 use crate::domain::agent::{AgentKind, AgentModel};
 use crate::infra::app_server::AppServerClient;
+use crate::infra::channel::SkillPromptMode;
 
 /// Factory hook used to build or override provider-specific app-server
 /// clients.
@@ -55,6 +57,14 @@ pub(crate) fn prompt_transport(kind: AgentKind) -> AgentPromptTransport {
     provider_descriptor(kind).prompt_transport
 }
 
+/// Returns how attached skills are rendered for the selected provider.
+///
+/// Providers that load `SKILL.md` files natively only receive the skill
+/// paths; the rest receive the skill bodies inline.
+pub(crate) fn skill_prompt_mode(kind: AgentKind) -> SkillPromptMode {
+    provider_descriptor(kind).skill_prompt_mode
+}
+
 /// Parses one final assistant payload strictly against the shared protocol and
 /// normalizes it for the active request profile.
 ///
@@ -118,6 +128,16 @@ pub(crate) fn build_command_stdin_payload(
     }
 }
 
+/// Removes per-turn files a provider command wrote for `folder`.
+///
+/// CLI channels call this once the provider process has exited.
+pub(crate) fn remove_command_files(kind: AgentKind, folder: &Path) {
+    match kind {
+        AgentKind::Claude => super::claude::remove_mcp_config_file(folder),
+        AgentKind::Codex | AgentKind::Gemini => {}
+    }
+}
+
 /// Parses one model string into its owning provider kind.
 ///
 /// # Errors
@@ -137,6 +157,7 @@ struct AgentProviderDescriptor {
     parse_response: fn(&str, &str) -> ParsedResponse,
     parse_stream_output_line: fn(&str) -> Option<(String, bool)>,
     prompt_transport: AgentPromptTransport,
+    skill_prompt_mode: SkillPromptMode,
     transport: AgentTransport,
 }
 
@@ -154,6 +175,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             parse_response: super::response_parser::parse_gemini_response_with_fallback,
             parse_stream_output_line: super::response_parser::parse_gemini_stream_output_line,
             prompt_transport: AgentPromptTransport::Stdin,
+            skill_prompt_mode: SkillPromptMode::Inline,
             transport: AgentTransport::AppServer,
         },
         AgentKind::Claude => AgentProviderDescriptor {
@@ -163,6 +185,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             parse_response: super::response_parser::parse_claude_response_with_fallback,
             parse_stream_output_line: super::response_parser::parse_claude_stream_output_line,
             prompt_transport: AgentPromptTransport::Stdin,
+            skill_prompt_mode: SkillPromptMode::Reference,
             transport: AgentTransport::Cli,
         },
         AgentKind::Codex => AgentProviderDescriptor {
@@ -177,6 +200,7 @@ fn provider_descriptor(kind: AgentKind) -> AgentProviderDescriptor {
             parse_response: super::response_parser::parse_codex_response_with_fallback,
             parse_stream_output_line: super::response_parser::parse_codex_stream_output_line,
             prompt_transport: AgentPromptTransport::Argv,
+            skill_prompt_mode: SkillPromptMode::Inline,
             transport: AgentTransport::AppServer,
         },
     }
@@ -219,6 +243,26 @@ mod tests {
         assert_eq!(gemini_transport, AgentTransport::AppServer);
     }
 
+    #[test]
+    /// Ensures only providers with native skill support receive skill
+    /// references instead of inlined skill bodies.
+    fn test_skill_prompt_mode_reports_expected_mode_by_provider() {
+        // Arrange
+        let claude_kind = AgentKind::Claude;
+        let codex_kind = AgentKind::Codex;
+        let gemini_kind = AgentKind::Gemini;
+
+        // Act
+        let claude_mode = skill_prompt_mode(claude_kind);
+        let codex_mode = skill_prompt_mode(codex_kind);
+        let gemini_mode = skill_prompt_mode(gemini_kind);
+
+        // Assert
+        assert_eq!(claude_mode, SkillPromptMode::Reference);
+        assert_eq!(codex_mode, SkillPromptMode::Inline);
+        assert_eq!(gemini_mode, SkillPromptMode::Inline);
+    }
+
     #[test]
     /// Ensures prompt delivery is also derived from the shared provider
     /// descriptor.
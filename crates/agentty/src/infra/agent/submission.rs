@@ -16,6 +16,7 @@ use super::{
     ParsedResponse, create_app_server_client, create_backend, parse_response, transport_mode,
 };
 use crate::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
+use crate::domain::permission::PermissionMode;
 use crate::domain::session::SessionStats;
 use crate::infra::app_server::{AppServerClient, AppServerTurnRequest};
 use crate::infra::channel::AgentRequestKind;
@@ -126,6 +127,9 @@ pub(crate) async fn submit_one_shot_with_app_server_client(
         request_kind: request.request_kind.clone(),
         provider_conversation_id: None,
         persisted_instruction_conversation_id: None,
+        mcp_servers: Vec::new(),
+        permission_mode: PermissionMode::default(),
+        project_instructions: None,
         reasoning_level: request.reasoning_level,
         session_id: session_id.clone(),
     };
@@ -276,6 +280,9 @@ async fn attempt_one_shot_app_server_repair(
         request_kind: request.request_kind,
         provider_conversation_id: provider_conversation_id.map(String::from),
         persisted_instruction_conversation_id: None,
+        mcp_servers: Vec::new(),
+        permission_mode: PermissionMode::default(),
+        project_instructions: None,
         reasoning_level: request.reasoning_level,
         session_id: session_id.to_string(),
     };
@@ -316,6 +323,9 @@ async fn execute_one_shot_command(
     let build_request = BuildCommandRequest {
         attachments: &prompt_payload.attachments,
         folder: request.folder,
+        mcp_servers: &[],
+        permission_mode: PermissionMode::default(),
+        project_instructions: None,
         prompt,
         request_kind: &request.request_kind,
         model: request.model.as_str(),
@@ -8,6 +8,8 @@ use std::sync::{Arc, Mutex};
 use tokio::sync::mpsc;
 
 use crate::domain::agent::ReasoningLevel;
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::app_server::AppServerError;
 use crate::infra::channel::{AgentRequestKind, TurnPrompt};
 
@@ -67,6 +69,12 @@ pub struct AppServerTurnRequest {
     /// Persisted provider-native conversation id that already received the
     /// full instruction bootstrap, when available.
     pub persisted_instruction_conversation_id: Option<String>,
+    /// Project MCP servers registered when the runtime starts its thread.
+    pub mcp_servers: Vec<McpServer>,
+    /// Permission mode applied to this turn's sandbox and approvals.
+    pub permission_mode: PermissionMode,
+    /// Project-specific instructions configured in Agentty, when present.
+    pub project_instructions: Option<String>,
     /// Reasoning effort preference for this turn.
     ///
     /// Ignored by providers/models that do not support reasoning effort.
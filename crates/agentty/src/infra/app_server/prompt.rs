@@ -34,7 +34,8 @@ pub(crate) fn read_latest_session_output(request: &AppServerTurnRequest) -> Opti
 ///
 /// `BootstrapFull` and `BootstrapWithReplay` include the full shared protocol
 /// preamble, while `DeltaOnly` emits only a compact reminder for provider
-/// contexts that already received that contract.
+/// contexts that already received that contract. `DeltaWithProjectInstructions`
+/// adds the changed `project_instructions` to that reminder.
 ///
 /// # Errors
 /// Returns an error when Askama prompt rendering fails after a context reset.
@@ -43,11 +44,13 @@ pub(crate) fn turn_prompt_for_runtime(
     request_kind: &AgentRequestKind,
     replay_session_output: Option<&str>,
     instruction_delivery_mode: InstructionDeliveryMode,
+    project_instructions: Option<&str>,
 ) -> Result<TurnPrompt, AppServerError> {
     let prompt = prompt.into();
     let agent_prompt = prompt.agent_text();
     let turn_prompt = agent::prepare_prompt_text(agent::PromptPreparationRequest {
         instruction_delivery_mode,
+        project_instructions,
         prompt: &agent_prompt,
         protocol_profile: request_kind.protocol_profile(),
         replay_session_output,
@@ -56,6 +59,8 @@ pub(crate) fn turn_prompt_for_runtime(
 
     Ok(TurnPrompt {
         attachments: prompt.attachments,
+        context_attachments: Vec::new(),
+        skills: Vec::new(),
         text: turn_prompt,
     })
 }
@@ -71,6 +76,7 @@ pub(crate) fn instruction_delivery_mode_for_runtime(
         &request.request_kind,
         runtime_provider_conversation_id,
         request.persisted_instruction_conversation_id.as_deref(),
+        request.project_instructions.as_deref(),
         should_replay_session_output,
     )
 }
@@ -82,6 +88,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::ReasoningLevel;
+    use crate::domain::permission::PermissionMode;
 
     /// Returns one persisted bootstrap marker that matches the active
     /// app-server instruction contract for session turns.
@@ -102,6 +109,9 @@ mod tests {
             prompt: TurnPrompt::from("hello"),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             session_id: "test-session".to_string(),
@@ -125,6 +135,9 @@ mod tests {
             prompt: TurnPrompt::from("hello"),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             session_id: "test-session".to_string(),
@@ -147,6 +160,9 @@ mod tests {
             prompt: TurnPrompt::from("hello"),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionStart,
             session_id: "test-session".to_string(),
@@ -171,6 +187,7 @@ mod tests {
             &request_kind,
             None,
             InstructionDeliveryMode::BootstrapFull,
+            None,
         );
 
         // Assert
@@ -193,6 +210,7 @@ mod tests {
             &request_kind,
             None,
             InstructionDeliveryMode::DeltaOnly,
+            None,
         );
 
         // Assert
@@ -213,6 +231,7 @@ mod tests {
             &request_kind,
             None,
             InstructionDeliveryMode::BootstrapFull,
+            None,
         );
 
         // Assert
@@ -233,6 +252,9 @@ mod tests {
             provider_conversation_id: Some("thread-123".to_string()),
             persisted_instruction_conversation_id:
                 persisted_instruction_conversation_id_for_session_turn(Some("thread-123")),
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             request_kind: AgentRequestKind::SessionResume {
                 session_output: None,
@@ -247,4 +269,46 @@ mod tests {
         // Assert
         assert_eq!(delivery_mode, InstructionDeliveryMode::DeltaOnly);
     }
+
+    #[test]
+    fn instruction_delivery_mode_for_runtime_resends_changed_project_instructions() {
+        // Arrange
+        let request = AppServerTurnRequest {
+            folder: PathBuf::from("/tmp/test"),
+            live_session_output: None,
+            model: "test-model".to_string(),
+            prompt: TurnPrompt::from("hello"),
+            provider_conversation_id: Some("thread-123".to_string()),
+            persisted_instruction_conversation_id:
+                persisted_instruction_conversation_id_for_session_turn(Some("thread-123")),
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: Some("Prefer small commits.".to_string()),
+            reasoning_level: ReasoningLevel::default(),
+            request_kind: AgentRequestKind::SessionResume {
+                session_output: None,
+            },
+            session_id: "test-session".to_string(),
+        };
+
+        // Act
+        let delivery_mode =
+            instruction_delivery_mode_for_runtime(&request, Some("thread-123"), false);
+        let turn_prompt = turn_prompt_for_runtime(
+            request.prompt.clone(),
+            &request.request_kind,
+            None,
+            delivery_mode,
+            request.project_instructions.as_deref(),
+        )
+        .expect("prompt rendering should succeed");
+
+        // Assert
+        assert_eq!(
+            delivery_mode,
+            InstructionDeliveryMode::DeltaWithProjectInstructions
+        );
+        assert!(turn_prompt.text.contains("Protocol refresh reminder:"));
+        assert!(turn_prompt.text.contains("Prefer small commits."));
+    }
 }
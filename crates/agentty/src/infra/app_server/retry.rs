@@ -204,6 +204,7 @@ where
         &request.request_kind,
         session_output.as_deref(),
         instruction_delivery_mode,
+        request.project_instructions.as_deref(),
     ) {
         Ok(prompt) => Ok(prompt),
         Err(error) => {
@@ -222,6 +223,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::ReasoningLevel;
+    use crate::domain::permission::PermissionMode;
     use crate::infra::agent::InstructionDeliveryMode;
     use crate::infra::channel::{AgentRequestKind, TurnPrompt};
 
@@ -275,6 +277,7 @@ mod tests {
             &session_start_request_kind(),
             Some("prior context"),
             InstructionDeliveryMode::BootstrapFull,
+            None,
         )
         .expect("turn prompt should render");
 
@@ -295,6 +298,7 @@ mod tests {
             &session_resume_request_kind(Some("assistant: proposed plan")),
             Some("assistant: proposed plan"),
             InstructionDeliveryMode::BootstrapWithReplay,
+            None,
         )
         .expect("turn prompt should render");
 
@@ -316,6 +320,7 @@ mod tests {
             &AgentRequestKind::UtilityPrompt,
             None,
             InstructionDeliveryMode::BootstrapFull,
+            None,
         )
         .expect("turn prompt should render");
 
@@ -336,6 +341,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("stale snapshot")),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -359,6 +367,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("stale snapshot")),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -381,6 +392,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("stale snapshot")),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -403,6 +417,9 @@ mod tests {
             request_kind: session_start_request_kind(),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -427,6 +444,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("stale snapshot")),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -502,6 +522,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("previous output")),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -585,6 +608,9 @@ mod tests {
             request_kind: session_resume_request_kind(Some("previous output")),
             provider_conversation_id: Some("thread-123".to_string()),
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -85,6 +85,7 @@ impl AppServerClient for RoutingAppServerClient {
 mod tests {
     use super::*;
     use crate::domain::agent::{AgentModel, ReasoningLevel};
+    use crate::domain::permission::PermissionMode;
     use crate::infra::app_server::MockAppServerClient;
 
     #[tokio::test]
@@ -118,6 +119,9 @@ mod tests {
             request_kind: crate::infra::channel::AgentRequestKind::SessionStart,
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -163,6 +167,9 @@ mod tests {
             request_kind: crate::infra::channel::AgentRequestKind::SessionStart,
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
@@ -197,6 +204,9 @@ mod tests {
             request_kind: crate::infra::channel::AgentRequestKind::SessionStart,
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
             session_id: "session-1".to_string(),
         };
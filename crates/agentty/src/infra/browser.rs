@@ -0,0 +1,67 @@
+use std::process::Stdio;
+
+/// Boundary that hands URLs to the desktop's default browser.
+#[cfg_attr(test, mockall::automock)]
+pub trait BrowserClient: Send + Sync {
+    /// Opens `url` in the default browser without waiting for it to exit.
+    fn open_url(&self, url: String);
+}
+
+/// Production [`BrowserClient`] spawning the platform URL opener.
+pub struct RealBrowserClient;
+
+impl RealBrowserClient {
+    /// Builds the platform opener invocation for one URL: `open` on macOS and
+    /// `xdg-open` elsewhere.
+    fn open_command(url: &str) -> tokio::process::Command {
+        let program = if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        };
+        let mut open_command = tokio::process::Command::new(program);
+        open_command
+            .arg(url)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        open_command
+    }
+}
+
+impl BrowserClient for RealBrowserClient {
+    fn open_url(&self, url: String) {
+        // Best-effort: a missing opener must not interrupt the TUI. Tokio
+        // reaps the detached child in the background.
+        let _ = Self::open_command(&url).spawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn open_command_passes_url_to_platform_opener() {
+        // Arrange
+        let url = "https://github.com/agentty-xyz/agentty/pull/42";
+
+        // Act
+        let open_command = RealBrowserClient::open_command(url);
+        let std_command = open_command.as_std();
+        let arguments = std_command
+            .get_args()
+            .map(|argument| argument.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+
+        // Assert
+        let expected_program = if cfg!(target_os = "macos") {
+            "open"
+        } else {
+            "xdg-open"
+        };
+        assert_eq!(std_command.get_program(), expected_program);
+        assert_eq!(arguments, vec![url.to_string()]);
+    }
+}
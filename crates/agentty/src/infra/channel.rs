@@ -11,8 +11,9 @@ pub mod factory;
 #[cfg(test)]
 pub use contract::MockAgentChannel;
 pub use contract::{
-    AgentChannel, AgentError, AgentFuture, AgentRequestKind, SessionRef, StartSessionRequest,
-    TurnEvent, TurnPrompt, TurnPromptAttachment, TurnRequest, TurnResult,
+    AgentChannel, AgentError, AgentFuture, AgentRequestKind, SessionRef, SkillPromptMode,
+    StartSessionRequest, TurnEvent, TurnPrompt, TurnPromptAttachment, TurnPromptContext,
+    TurnPromptSkill, TurnRequest, TurnResult,
 };
 pub(crate) use contract::{TurnPromptContentPart, split_turn_prompt_content};
 pub use factory::create_agent_channel;
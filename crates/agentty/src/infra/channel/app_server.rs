@@ -74,6 +74,9 @@ impl AgentChannel for AppServerAgentChannel {
                 request_kind: req.request_kind,
                 provider_conversation_id: req.provider_conversation_id,
                 persisted_instruction_conversation_id: req.persisted_instruction_conversation_id,
+                mcp_servers: req.mcp_servers,
+                permission_mode: req.permission_mode,
+                project_instructions: req.project_instructions,
                 reasoning_level: req.reasoning_level,
                 session_id,
             };
@@ -237,6 +240,9 @@ async fn parse_or_repair_app_server_response(
         request_kind: repair_request.request_kind,
         provider_conversation_id: repair_provider_conversation_id,
         persisted_instruction_conversation_id: None,
+        mcp_servers: repair_request.mcp_servers,
+        permission_mode: repair_request.permission_mode,
+        project_instructions: None,
         reasoning_level: repair_request.reasoning_level,
         session_id: repair_request.session_id,
     };
@@ -279,6 +285,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::ReasoningLevel;
+    use crate::domain::permission::PermissionMode;
     use crate::infra::app_server::{AppServerTurnResponse, MockAppServerClient};
     use crate::infra::channel::{AgentRequestKind, TurnPromptAttachment};
 
@@ -291,6 +298,9 @@ mod tests {
             prompt: "Do something".into(),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
         }
     }
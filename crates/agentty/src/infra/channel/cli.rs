@@ -58,6 +58,9 @@ fn build_command_request<'a>(
     BuildCommandRequest {
         attachments: &request.prompt.attachments,
         folder: &request.folder,
+        mcp_servers: &request.mcp_servers,
+        permission_mode: request.permission_mode,
+        project_instructions: request.project_instructions.as_deref(),
         prompt: prompt_text,
         request_kind: &request.request_kind,
         model: &request.model,
@@ -97,7 +100,7 @@ impl AgentChannel for CliAgentChannel {
         let backend = Arc::clone(&self.backend);
 
         Box::pin(async move {
-            let prompt_text = req.prompt.agent_text();
+            let prompt_text = req.prompt.agent_text_for(agent::skill_prompt_mode(kind));
             let build_request = build_command_request(&req, &prompt_text);
             let build_result = backend.build_command(build_request);
             let stdin_payload_result = agent::build_command_stdin_payload(kind, build_request);
@@ -118,9 +121,11 @@ impl AgentChannel for CliAgentChannel {
             tokio_cmd.stderr(std::process::Stdio::piped());
             tokio_cmd.kill_on_drop(true);
 
-            let mut child = tokio_cmd
-                .spawn()
-                .map_err(|error| AgentError::Io(format!("Failed to spawn process: {error}")))?;
+            let mut child = tokio_cmd.spawn().map_err(|error| {
+                agent::remove_command_files(kind, &req.folder);
+
+                AgentError::Io(format!("Failed to spawn process: {error}"))
+            })?;
 
             // Notify the consumer of the child PID so cancellation signals can
             // be sent while the process is running.
@@ -169,6 +174,7 @@ impl AgentChannel for CliAgentChannel {
             let _ = stderr_task.await;
 
             let exit_status = child.wait().await.ok();
+            agent::remove_command_files(kind, &req.folder);
             stdin::await_optional_stdin_write(
                 stdin_write_task,
                 "stdin write task failed",
@@ -252,21 +258,13 @@ async fn parse_or_repair_cli_response(
             ))
         })?;
 
-    let repair_content = execute_cli_repair_turn(
-        backend.as_ref(),
-        kind,
-        &req.folder,
-        &req.model,
-        &req.request_kind,
-        req.reasoning_level,
-        &repair_prompt,
-    )
-    .await
-    .map_err(|error| {
-        AgentError::Backend(format!(
-            "{parse_error}\nprotocol repair transport failed: {error}"
-        ))
-    })?;
+    let repair_content = execute_cli_repair_turn(backend.as_ref(), kind, req, &repair_prompt)
+        .await
+        .map_err(|error| {
+            AgentError::Backend(format!(
+                "{parse_error}\nprotocol repair transport failed: {error}"
+            ))
+        })?;
 
     agent::parse_turn_response(kind, &repair_content, protocol_profile).map_err(|error| {
         AgentError::Backend(format!(
@@ -335,20 +333,20 @@ const REPAIR_TURN_TIMEOUT: std::time::Duration = std::time::Duration::from_mins(
 async fn execute_cli_repair_turn(
     backend: &dyn AgentBackend,
     kind: AgentKind,
-    folder: &std::path::Path,
-    model: &str,
-    request_kind: &crate::infra::channel::AgentRequestKind,
-    reasoning_level: crate::domain::agent::ReasoningLevel,
+    req: &TurnRequest,
     repair_prompt: &str,
 ) -> Result<String, String> {
     let prompt_payload = TurnPrompt::from_text(repair_prompt.to_string());
     let build_request = BuildCommandRequest {
         attachments: &prompt_payload.attachments,
-        folder,
+        folder: &req.folder,
+        mcp_servers: &[],
+        permission_mode: req.permission_mode,
+        project_instructions: None,
         prompt: repair_prompt,
-        request_kind,
-        model,
-        reasoning_level,
+        request_kind: &req.request_kind,
+        model: &req.model,
+        reasoning_level: req.reasoning_level,
     };
     let command = backend
         .build_command(build_request)
@@ -436,6 +434,7 @@ mod tests {
 
     use super::*;
     use crate::domain::agent::{AgentKind, ReasoningLevel};
+    use crate::domain::permission::PermissionMode;
     use crate::infra::agent::tests::MockAgentBackend;
     use crate::infra::channel::{AgentRequestKind, TurnPrompt, TurnPromptAttachment};
 
@@ -448,6 +447,9 @@ mod tests {
             prompt: "Write a test".into(),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
         }
     }
@@ -474,6 +476,9 @@ mod tests {
             prompt: TurnPrompt::from("Review @src/main.rs"),
             provider_conversation_id: None,
             persisted_instruction_conversation_id: None,
+            mcp_servers: Vec::new(),
+            permission_mode: PermissionMode::default(),
+            project_instructions: None,
             reasoning_level: ReasoningLevel::default(),
         };
         let prompt_text = request.prompt.agent_text();
@@ -724,6 +729,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: image_path.clone(),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Review [Image #1]".to_string(),
         };
 
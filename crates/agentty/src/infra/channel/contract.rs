@@ -1,6 +1,6 @@
 //! Shared channel trait and transport-agnostic turn data types.
 
-use std::fmt;
+use std::fmt::{self, Write as _};
 use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
@@ -10,6 +10,8 @@ use tokio::sync::mpsc;
 
 use crate::domain::agent::ReasoningLevel;
 use crate::domain::composer;
+use crate::domain::mcp::McpServer;
+use crate::domain::permission::PermissionMode;
 use crate::infra::agent::AgentResponse;
 
 /// Boxed async result used by [`AgentChannel`] trait methods.
@@ -24,11 +26,56 @@ pub struct TurnPromptAttachment {
     pub local_image_path: PathBuf,
 }
 
+/// Upper bound, in bytes, for all text context attachments rendered into one
+/// agent prompt.
+const MAX_PROMPT_CONTEXT_BYTES: usize = 256 * 1024;
+
+/// One text context attachment, such as a file or command output, referenced
+/// from a prompt placeholder.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TurnPromptContext {
+    /// Captured text rendered for the agent.
+    pub content: String,
+    /// Human-readable source description shown next to the content.
+    pub label: String,
+    /// Inline placeholder token such as `[File #1]` used in prompt text.
+    pub placeholder: String,
+}
+
+/// One `SKILL.md` skill referenced from a `[Skill #n]` prompt placeholder.
+#[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
+pub struct TurnPromptSkill {
+    /// Skill instructions without front matter.
+    pub body: String,
+    /// Skill name recorded on the persisted turn.
+    pub name: String,
+    /// `SKILL.md` path referenced for providers with native skill support.
+    pub path: PathBuf,
+    /// Inline placeholder token such as `[Skill #1]` used in prompt text.
+    pub placeholder: String,
+}
+
+/// How attached skills are rendered into agent-facing prompt text.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SkillPromptMode {
+    /// Skill bodies are inlined for providers without native skill support.
+    Inline,
+    /// Only `SKILL.md` paths are listed so providers with native skill
+    /// support load the skills themselves.
+    Reference,
+}
+
 /// Structured prompt payload for one agent turn.
 #[derive(Debug, Clone, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct TurnPrompt {
     /// Ordered local image attachments referenced by `text`.
     pub attachments: Vec<TurnPromptAttachment>,
+    /// Ordered text context attachments referenced by `text`.
+    #[serde(default)]
+    pub context_attachments: Vec<TurnPromptContext>,
+    /// Ordered skills referenced by `text`.
+    #[serde(default)]
+    pub skills: Vec<TurnPromptSkill>,
     /// Prompt text submitted by the user, including inline placeholders.
     pub text: String,
 }
@@ -50,6 +97,8 @@ impl TurnPrompt {
     pub fn from_text(text: String) -> Self {
         Self {
             attachments: Vec::new(),
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text,
         }
     }
@@ -57,7 +106,16 @@ impl TurnPrompt {
     /// Returns whether the payload contains no text and no attachments.
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.text.is_empty() && self.attachments.is_empty()
+        self.text.is_empty()
+            && self.attachments.is_empty()
+            && self.context_attachments.is_empty()
+            && self.skills.is_empty()
+    }
+
+    /// Returns the names of the attached skills in placeholder order.
+    #[must_use]
+    pub fn skill_names(&self) -> Vec<String> {
+        self.skills.iter().map(|skill| skill.name.clone()).collect()
     }
 
     /// Returns whether the payload contains one or more image attachments.
@@ -88,11 +146,41 @@ impl TurnPrompt {
     /// Returns the prompt text as it should be sent to an agent runtime.
     ///
     /// User-entered `@path` lookups are rewritten to quoted path tokens for
-    /// transport, while the persisted transcript continues to use the original
-    /// raw prompt text.
+    /// transport, and text context attachments and skill bodies are appended
+    /// as labelled blocks, while the persisted transcript continues to use
+    /// the original raw prompt text.
     #[must_use]
     pub fn agent_text(&self) -> String {
-        composer::render_prompt_text_for_agent(&self.text)
+        self.agent_text_for(SkillPromptMode::Inline)
+    }
+
+    /// Returns the prompt text as it should be sent to an agent runtime,
+    /// rendering attached skills according to `skill_prompt_mode`.
+    #[must_use]
+    pub fn agent_text_for(&self, skill_prompt_mode: SkillPromptMode) -> String {
+        let agent_text = composer::render_prompt_text_for_agent(&self.text);
+        let agent_text = append_context_attachments(agent_text, &self.context_attachments);
+
+        append_skills(agent_text, &self.skills, skill_prompt_mode)
+    }
+
+    /// Returns a copy of this prompt with text context attachments and skill
+    /// bodies appended to `text` as they would be rendered for the agent.
+    ///
+    /// Used when the prompt text is persisted on its own, such as staged
+    /// draft messages, so the captured context survives without separate
+    /// metadata.
+    #[must_use]
+    pub fn with_inlined_context(self) -> Self {
+        let text = append_context_attachments(self.text, &self.context_attachments);
+        let text = append_skills(text, &self.skills, SkillPromptMode::Inline);
+
+        Self {
+            attachments: self.attachments,
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
+            text,
+        }
     }
 
     /// Returns prompt text spans and image attachments in transport order.
@@ -141,6 +229,99 @@ impl TurnPrompt {
     }
 }
 
+/// Appends one labelled fenced block per context attachment after `text`.
+///
+/// Blocks keep their placeholder so the agent can match them to the inline
+/// references. Once the combined content would exceed
+/// [`MAX_PROMPT_CONTEXT_BYTES`], remaining attachments are listed without
+/// their content.
+fn append_context_attachments(
+    mut text: String,
+    context_attachments: &[TurnPromptContext],
+) -> String {
+    if context_attachments.is_empty() {
+        return text;
+    }
+
+    text.push_str("\n\nAttached context:");
+    let mut remaining_bytes = MAX_PROMPT_CONTEXT_BYTES;
+    for context_attachment in context_attachments {
+        let TurnPromptContext {
+            content,
+            label,
+            placeholder,
+        } = context_attachment;
+        let _ = write!(text, "\n\n{placeholder} {label}\n");
+
+        if content.len() > remaining_bytes {
+            text.push_str("(omitted: prompt context limit reached)");
+
+            continue;
+        }
+
+        remaining_bytes -= content.len();
+        let fence = context_fence(content);
+        let _ = write!(text, "{fence}\n{}\n{fence}", content.trim_end_matches('\n'));
+    }
+
+    text
+}
+
+/// Appends one labelled block per attached skill after `text`.
+///
+/// [`SkillPromptMode::Inline`] embeds each skill body in a fenced block,
+/// while [`SkillPromptMode::Reference`] only points at the `SKILL.md` file.
+fn append_skills(
+    mut text: String,
+    skills: &[TurnPromptSkill],
+    skill_prompt_mode: SkillPromptMode,
+) -> String {
+    if skills.is_empty() {
+        return text;
+    }
+
+    text.push_str("\n\nAttached skills:");
+    for skill in skills {
+        let TurnPromptSkill {
+            body,
+            name,
+            path,
+            placeholder,
+        } = skill;
+
+        match skill_prompt_mode {
+            SkillPromptMode::Inline => {
+                let fence = context_fence(body);
+                let _ = write!(
+                    text,
+                    "\n\n{placeholder} Skill `{name}`\n{fence}\n{}\n{fence}",
+                    body.trim_end_matches('\n')
+                );
+            }
+            SkillPromptMode::Reference => {
+                let _ = write!(
+                    text,
+                    "\n\n{placeholder} Skill `{name}`: load and follow `{}`",
+                    path.display()
+                );
+            }
+        }
+    }
+
+    text
+}
+
+/// Returns a backtick fence longer than any backtick run inside `content`.
+fn context_fence(content: &str) -> String {
+    let longest_run = content
+        .split(|character| character != '`')
+        .map(str::len)
+        .max()
+        .unwrap_or(0);
+
+    "`".repeat(longest_run.max(2) + 1)
+}
+
 /// Splits prompt text into ordered text and attachment parts for transport
 /// serialization.
 #[must_use]
@@ -303,6 +484,23 @@ pub struct TurnRequest {
     /// compact reminder for the active provider context. CLI channels ignore
     /// this field.
     pub persisted_instruction_conversation_id: Option<String>,
+    /// Project MCP servers configured in Agentty for the session's project.
+    ///
+    /// Every provider exposes the same servers so all agents in a session
+    /// see the same tools.
+    pub mcp_servers: Vec<McpServer>,
+    /// Permission mode for this turn.
+    ///
+    /// Plan-first sessions run [`PermissionMode::Plan`] turns until the user
+    /// approves a plan, so providers must not modify the worktree.
+    pub permission_mode: PermissionMode,
+    /// Project-specific instructions configured in Agentty for the session's
+    /// project.
+    ///
+    /// Every provider receives them with the full instruction bootstrap;
+    /// app-server channels also resend them with the compact reminder when
+    /// they changed since the bootstrap.
+    pub project_instructions: Option<String>,
     /// Reasoning effort preference for the turn.
     ///
     /// Ignored by providers/models that do not support reasoning effort.
@@ -486,6 +684,8 @@ mod tests {
                 placeholder: "[Image #1]".to_string(),
                 local_image_path: PathBuf::from("/tmp/image-1.png"),
             }],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Review [Image #1] carefully".to_string(),
         };
 
@@ -515,6 +715,129 @@ mod tests {
         );
     }
 
+    #[test]
+    /// Ensures text context attachments are appended as labelled fenced
+    /// blocks while transcript text keeps only the placeholder.
+    fn test_turn_prompt_agent_text_appends_context_attachments() {
+        // Arrange
+        let prompt = TurnPrompt {
+            attachments: Vec::new(),
+            context_attachments: vec![TurnPromptContext {
+                content: "let code = ```inline```;\n".to_string(),
+                label: "File `src/lib.rs`".to_string(),
+                placeholder: "[File #1]".to_string(),
+            }],
+            skills: Vec::new(),
+            text: "Explain [File #1]".to_string(),
+        };
+
+        // Act
+        let agent_text = prompt.agent_text();
+        let transcript_text = prompt.transcript_text();
+
+        // Assert
+        assert_eq!(
+            agent_text,
+            "Explain [File #1]\n\nAttached context:\n\n[File #1] File `src/lib.rs`\n````\nlet \
+             code = ```inline```;\n````"
+        );
+        assert_eq!(transcript_text, "Explain [File #1]");
+    }
+
+    #[test]
+    /// Ensures context beyond the prompt-wide byte limit is listed without
+    /// its content.
+    fn test_turn_prompt_agent_text_omits_context_over_limit() {
+        // Arrange
+        let prompt = TurnPrompt {
+            attachments: Vec::new(),
+            context_attachments: vec![
+                TurnPromptContext {
+                    content: "a".repeat(MAX_PROMPT_CONTEXT_BYTES),
+                    label: "File `big.log`".to_string(),
+                    placeholder: "[File #1]".to_string(),
+                },
+                TurnPromptContext {
+                    content: "b".to_string(),
+                    label: "Snippet `style`".to_string(),
+                    placeholder: "[Snippet #1]".to_string(),
+                },
+            ],
+            skills: Vec::new(),
+            text: "Use [File #1] and [Snippet #1]".to_string(),
+        };
+
+        // Act
+        let agent_text = prompt.agent_text();
+
+        // Assert
+        assert!(agent_text.contains(&"a".repeat(MAX_PROMPT_CONTEXT_BYTES)));
+        assert!(
+            agent_text
+                .ends_with("[Snippet #1] Snippet `style`\n(omitted: prompt context limit reached)")
+        );
+    }
+
+    #[test]
+    /// Ensures skills are inlined by default and referenced by path for
+    /// providers with native skill support.
+    fn test_turn_prompt_agent_text_renders_skills_by_mode() {
+        // Arrange
+        let prompt = TurnPrompt {
+            attachments: Vec::new(),
+            context_attachments: Vec::new(),
+            skills: vec![TurnPromptSkill {
+                body: "Check every changed file.\n".to_string(),
+                name: "review".to_string(),
+                path: PathBuf::from("/repo/skills/review/SKILL.md"),
+                placeholder: "[Skill #1]".to_string(),
+            }],
+            text: "[Skill #1] Review the parser".to_string(),
+        };
+
+        // Act
+        let inline_text = prompt.agent_text();
+        let reference_text = prompt.agent_text_for(SkillPromptMode::Reference);
+
+        // Assert
+        assert_eq!(
+            inline_text,
+            "[Skill #1] Review the parser\n\nAttached skills:\n\n[Skill #1] Skill \
+             `review`\n```\nCheck every changed file.\n```"
+        );
+        assert_eq!(
+            reference_text,
+            "[Skill #1] Review the parser\n\nAttached skills:\n\n[Skill #1] Skill `review`: load \
+             and follow `/repo/skills/review/SKILL.md`"
+        );
+        assert_eq!(prompt.skill_names(), vec!["review".to_string()]);
+    }
+
+    #[test]
+    /// Ensures inlining context moves it into the text and clears the
+    /// structured attachment list.
+    fn test_turn_prompt_with_inlined_context_moves_context_into_text() {
+        // Arrange
+        let prompt = TurnPrompt {
+            attachments: Vec::new(),
+            context_attachments: vec![TurnPromptContext {
+                content: "ok".to_string(),
+                label: "Command `make`".to_string(),
+                placeholder: "[Command #1]".to_string(),
+            }],
+            skills: Vec::new(),
+            text: "Why [Command #1]?".to_string(),
+        };
+        let expected_text = prompt.agent_text();
+
+        // Act
+        let inlined_prompt = prompt.with_inlined_context();
+
+        // Assert
+        assert_eq!(inlined_prompt.text, expected_text);
+        assert!(inlined_prompt.context_attachments.is_empty());
+    }
+
     #[test]
     /// Ensures transcript text appends any attachment markers missing from the
     /// text payload.
@@ -531,6 +854,8 @@ mod tests {
                     local_image_path: PathBuf::from("/tmp/image-2.png"),
                 },
             ],
+            context_attachments: Vec::new(),
+            skills: Vec::new(),
             text: "Review".to_string(),
         };
 
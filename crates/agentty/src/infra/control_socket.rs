@@ -0,0 +1,705 @@
+//! Local Unix-domain-socket JSON-RPC server for a running `agentty` instance.
+//!
+//! Clients write one JSON-RPC 2.0 request per line and read one response per
+//! line. Method handling is delegated to the app through [`ControlCall`]
+//! values, while `events.subscribe` is served here by streaming notifications
+//! published through [`ControlServer::publish`].
+
+use std::fs::{DirBuilder, Permissions};
+use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use std::{fmt, io};
+
+use serde_json::{Value, json};
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{broadcast, mpsc, oneshot};
+use tokio::task::JoinHandle;
+use tracing::warn;
+
+/// Socket filename created under the `agentty` home directory.
+pub const CONTROL_SOCKET_FILE: &str = "control.sock";
+
+/// Method that switches one connection into notification streaming.
+pub const SUBSCRIBE_METHOD: &str = "events.subscribe";
+
+/// Maximum number of buffered notifications per lagging subscriber.
+const NOTIFICATION_CAPACITY: usize = 256;
+
+/// Maximum accepted size of one request line, including its newline.
+const MAX_REQUEST_BYTES: u64 = 1024 * 1024;
+
+/// Pause after a failed `accept` so persistent errors such as descriptor
+/// exhaustion do not spin the accept loop.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+/// Result delivered back to one control client request.
+pub type ControlResult = Result<Value, ControlError>;
+
+/// Handler invoked for every non-subscription control request.
+type CallHandler = Arc<dyn Fn(ControlCall) + Send + Sync>;
+
+/// JSON-RPC error payload returned to control clients.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlError {
+    /// JSON-RPC error code.
+    pub code: i64,
+    /// Human-readable error description.
+    pub message: String,
+}
+
+impl ControlError {
+    /// JSON-RPC code for internal handler failures.
+    pub const INTERNAL_ERROR: i64 = -32603;
+    /// JSON-RPC code for invalid method parameters.
+    pub const INVALID_PARAMS: i64 = -32602;
+    /// JSON-RPC code for structurally invalid requests.
+    pub const INVALID_REQUEST: i64 = -32600;
+    /// JSON-RPC code for unknown methods.
+    pub const METHOD_NOT_FOUND: i64 = -32601;
+    /// JSON-RPC code for malformed JSON input.
+    pub const PARSE_ERROR: i64 = -32700;
+    /// Implementation-defined code for requests rejected by current app state.
+    pub const REJECTED: i64 = -32000;
+
+    /// Builds an invalid-parameters error.
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self {
+            code: Self::INVALID_PARAMS,
+            message: message.into(),
+        }
+    }
+
+    /// Builds an unknown-method error for `method`.
+    pub fn method_not_found(method: &str) -> Self {
+        Self {
+            code: Self::METHOD_NOT_FOUND,
+            message: format!("Unknown method `{method}`"),
+        }
+    }
+
+    /// Builds an internal-failure error.
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self {
+            code: Self::INTERNAL_ERROR,
+            message: message.into(),
+        }
+    }
+
+    /// Builds an error for requests the app cannot satisfy in its current
+    /// state.
+    pub fn rejected(message: impl Into<String>) -> Self {
+        Self {
+            code: Self::REJECTED,
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for ControlError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "{} ({})", self.message, self.code)
+    }
+}
+
+/// One parsed JSON-RPC request forwarded to the app.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlRequest {
+    /// Requested method name, for example `session.list`.
+    pub method: String,
+    /// Raw method parameters, `Value::Null` when omitted.
+    pub params: Value,
+}
+
+/// Delivers exactly one response for a forwarded control request.
+///
+/// Cloning shares the same underlying sender so the type can travel inside
+/// cloneable app events; only the first `respond()` call is delivered.
+#[derive(Clone)]
+pub struct ControlResponder {
+    sender: Arc<Mutex<Option<oneshot::Sender<ControlResult>>>>,
+}
+
+impl ControlResponder {
+    /// Sends `result` to the waiting client connection.
+    pub fn respond(&self, result: ControlResult) {
+        let Ok(mut sender) = self.sender.lock() else {
+            return;
+        };
+
+        if let Some(sender) = sender.take() {
+            // Fire-and-forget: the client may disconnect before the response.
+            let _ = sender.send(result);
+        }
+    }
+}
+
+impl fmt::Debug for ControlResponder {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("ControlResponder")
+    }
+}
+
+impl PartialEq for ControlResponder {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.sender, &other.sender)
+    }
+}
+
+impl Eq for ControlResponder {}
+
+/// Control request paired with the responder for its reply.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ControlCall {
+    /// Request received from the client.
+    pub request: ControlRequest,
+    /// Responder that completes the client request.
+    pub responder: ControlResponder,
+}
+
+impl ControlCall {
+    /// Creates one call for `request` and the receiver awaiting its result.
+    pub fn new(request: ControlRequest) -> (Self, oneshot::Receiver<ControlResult>) {
+        let (sender, receiver) = oneshot::channel();
+        let call = Self {
+            request,
+            responder: ControlResponder {
+                sender: Arc::new(Mutex::new(Some(sender))),
+            },
+        };
+
+        (call, receiver)
+    }
+}
+
+/// Running control socket server.
+///
+/// Dropping the server stops accepting connections and removes the socket
+/// file.
+pub struct ControlServer {
+    accept_task: JoinHandle<()>,
+    notification_tx: broadcast::Sender<Value>,
+    socket_path: PathBuf,
+}
+
+impl ControlServer {
+    /// Binds `socket_path` and starts accepting control connections, passing
+    /// every non-subscription request to `call_handler`.
+    ///
+    /// A stale socket file left behind by a crashed instance is replaced.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::AddrInUse`] when another live instance already
+    /// listens on `socket_path`, or any I/O error raised while binding.
+    pub async fn start(
+        socket_path: PathBuf,
+        call_handler: impl Fn(ControlCall) + Send + Sync + 'static,
+    ) -> io::Result<Self> {
+        remove_stale_socket(&socket_path).await?;
+
+        let listener = bind_private_listener(&socket_path)?;
+
+        let (notification_tx, _) = broadcast::channel(NOTIFICATION_CAPACITY);
+        let call_handler: CallHandler = Arc::new(call_handler);
+        let accept_notification_tx = notification_tx.clone();
+        let accept_task = tokio::spawn(async move {
+            loop {
+                let stream = match listener.accept().await {
+                    Ok((stream, _)) => stream,
+                    Err(error) => {
+                        warn!("Control socket accept failed: {error}");
+                        tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+
+                        continue;
+                    }
+                };
+
+                tokio::spawn(handle_connection(
+                    stream,
+                    Arc::clone(&call_handler),
+                    accept_notification_tx.clone(),
+                ));
+            }
+        });
+
+        Ok(Self {
+            accept_task,
+            notification_tx,
+            socket_path,
+        })
+    }
+
+    /// Returns the bound socket path.
+    pub fn socket_path(&self) -> &Path {
+        &self.socket_path
+    }
+
+    /// Publishes one JSON-RPC notification to every subscribed client.
+    pub fn publish(&self, method: &str, params: &Value) {
+        // Fire-and-forget: there may be no subscribers.
+        let _ = self.notification_tx.send(json!({
+            "jsonrpc": "2.0",
+            "method": method,
+            "params": params,
+        }));
+    }
+}
+
+impl Drop for ControlServer {
+    fn drop(&mut self) {
+        self.accept_task.abort();
+        // Best-effort: the socket file may already be gone.
+        let _ = std::fs::remove_file(&self.socket_path);
+    }
+}
+
+/// Removes a leftover socket file when no instance answers on it.
+///
+/// # Errors
+/// Returns [`io::ErrorKind::AddrInUse`] when a live server still accepts
+/// connections at `socket_path`.
+async fn remove_stale_socket(socket_path: &Path) -> io::Result<()> {
+    if !socket_path.exists() {
+        return Ok(());
+    }
+
+    if UnixStream::connect(socket_path).await.is_ok() {
+        return Err(io::Error::new(
+            io::ErrorKind::AddrInUse,
+            format!(
+                "Another agentty instance is listening on {}",
+                socket_path.display()
+            ),
+        ));
+    }
+
+    std::fs::remove_file(socket_path)
+}
+
+/// Binds a listener at `socket_path` that only the current user can reach.
+///
+/// The socket is bound inside a fresh `0700` staging directory, restricted to
+/// `0600`, and only then renamed into place, so other local users never see a
+/// connectable socket regardless of the process umask.
+///
+/// # Errors
+/// Returns any I/O error raised while staging, binding, or moving the socket.
+fn bind_private_listener(socket_path: &Path) -> io::Result<UnixListener> {
+    let staging_dir = socket_path.with_file_name(format!(".control-{}", std::process::id()));
+    // Best-effort: a directory left by a crashed process with the same pid.
+    let _ = std::fs::remove_dir_all(&staging_dir);
+    DirBuilder::new().mode(0o700).create(&staging_dir)?;
+
+    let staged_socket_path = staging_dir.join(CONTROL_SOCKET_FILE);
+    let listener = bind_staged_listener(&staged_socket_path, socket_path);
+    // Best-effort: the staging directory is empty after a successful rename.
+    let _ = std::fs::remove_dir_all(&staging_dir);
+
+    listener
+}
+
+/// Binds `staged_socket_path`, restricts it to `0600`, and moves it to
+/// `socket_path`.
+fn bind_staged_listener(staged_socket_path: &Path, socket_path: &Path) -> io::Result<UnixListener> {
+    let listener = UnixListener::bind(staged_socket_path)?;
+    std::fs::set_permissions(staged_socket_path, Permissions::from_mode(0o600))?;
+    std::fs::rename(staged_socket_path, socket_path)?;
+
+    Ok(listener)
+}
+
+/// Serves one client connection until it disconnects.
+async fn handle_connection(
+    stream: UnixStream,
+    call_handler: CallHandler,
+    notification_tx: broadcast::Sender<Value>,
+) {
+    let (reader, mut writer) = stream.into_split();
+    let (line_tx, mut line_rx) = mpsc::unbounded_channel::<Value>();
+    let writer_task = tokio::spawn(async move {
+        while let Some(payload) = line_rx.recv().await {
+            if writer
+                .write_all(format!("{payload}\n").as_bytes())
+                .await
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+    let mut subscription_task: Option<JoinHandle<()>> = None;
+    let mut reader = BufReader::new(reader);
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let Ok(read_len) = (&mut reader)
+            .take(MAX_REQUEST_BYTES)
+            .read_line(&mut line)
+            .await
+        else {
+            break;
+        };
+        if read_len == 0 {
+            break;
+        }
+        if !line.ends_with('\n') && read_len as u64 == MAX_REQUEST_BYTES {
+            // The rest of the oversized line is never read, so the
+            // connection cannot resynchronize and closes after the error.
+            let error = ControlError {
+                code: ControlError::INVALID_REQUEST,
+                message: format!("Request exceeds {MAX_REQUEST_BYTES} bytes"),
+            };
+            // Fire-and-forget: the writer only stops when the client left.
+            let _ = line_tx.send(response_payload(&Value::Null, Err(error)));
+
+            break;
+        }
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let (id, result) = match parse_request_line(&line) {
+            Ok((id, request)) if request.method == SUBSCRIBE_METHOD => {
+                if subscription_task.is_none() {
+                    subscription_task = Some(spawn_subscription(
+                        notification_tx.subscribe(),
+                        line_tx.clone(),
+                    ));
+                }
+
+                (id, Ok(json!({ "subscribed": true })))
+            }
+            Ok((id, request)) => (id, dispatch_call(call_handler.as_ref(), request).await),
+            Err((id, error)) => (Some(id), Err(error)),
+        };
+
+        let Some(id) = id else {
+            continue;
+        };
+
+        if line_tx.send(response_payload(&id, result)).is_err() {
+            break;
+        }
+    }
+
+    if let Some(subscription_task) = subscription_task {
+        subscription_task.abort();
+    }
+    drop(line_tx);
+    // Best-effort: the writer task only fails when the client disconnected.
+    let _ = writer_task.await;
+}
+
+/// Forwards broadcast notifications into one connection writer.
+fn spawn_subscription(
+    mut notification_rx: broadcast::Receiver<Value>,
+    line_tx: mpsc::UnboundedSender<Value>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            match notification_rx.recv().await {
+                Ok(notification) => {
+                    if line_tx.send(notification).is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(_)) => {}
+                Err(broadcast::error::RecvError::Closed) => break,
+            }
+        }
+    })
+}
+
+/// Hands one request to the app and waits for its result.
+async fn dispatch_call(
+    call_handler: &(dyn Fn(ControlCall) + Send + Sync),
+    request: ControlRequest,
+) -> ControlResult {
+    let (call, receiver) = ControlCall::new(request);
+    call_handler(call);
+
+    receiver
+        .await
+        .unwrap_or_else(|_| Err(ControlError::internal("agentty stopped before answering")))
+}
+
+/// Parses one JSON-RPC request line.
+///
+/// Returns the request id (`None` for notifications) with the parsed request,
+/// or the id to answer with and the protocol error.
+fn parse_request_line(
+    line: &str,
+) -> Result<(Option<Value>, ControlRequest), (Value, ControlError)> {
+    let payload: Value = serde_json::from_str(line).map_err(|error| {
+        (
+            Value::Null,
+            ControlError {
+                code: ControlError::PARSE_ERROR,
+                message: format!("Invalid JSON: {error}"),
+            },
+        )
+    })?;
+    let id = payload.get("id").cloned();
+    let Some(method) = payload.get("method").and_then(Value::as_str) else {
+        return Err((
+            id.unwrap_or(Value::Null),
+            ControlError {
+                code: ControlError::INVALID_REQUEST,
+                message: "Request is missing a string `method`".to_string(),
+            },
+        ));
+    };
+    let request = ControlRequest {
+        method: method.to_string(),
+        params: payload.get("params").cloned().unwrap_or(Value::Null),
+    };
+
+    Ok((id, request))
+}
+
+/// Builds one JSON-RPC response object for `id`.
+fn response_payload(id: &Value, result: ControlResult) -> Value {
+    match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Err(error) => json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "error": { "code": error.code, "message": error.message },
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    use super::*;
+
+    /// Writes one request line and reads the next response line.
+    async fn round_trip(
+        lines: &mut tokio::io::Lines<BufReader<tokio::net::unix::OwnedReadHalf>>,
+        writer: &mut tokio::net::unix::OwnedWriteHalf,
+        request: &str,
+    ) -> Value {
+        writer
+            .write_all(format!("{request}\n").as_bytes())
+            .await
+            .expect("failed to write request");
+        let line = lines
+            .next_line()
+            .await
+            .expect("failed to read response")
+            .expect("connection closed");
+
+        serde_json::from_str(&line).expect("response should be JSON")
+    }
+
+    #[test]
+    fn test_parse_request_line_reads_method_params_and_id() {
+        // Arrange
+        let line =
+            r#"{"jsonrpc":"2.0","id":7,"method":"session.get","params":{"session_id":"abc"}}"#;
+
+        // Act
+        let (id, request) = parse_request_line(line).expect("request should parse");
+
+        // Assert
+        assert_eq!(id, Some(json!(7)));
+        assert_eq!(request.method, "session.get");
+        assert_eq!(request.params, json!({ "session_id": "abc" }));
+    }
+
+    #[test]
+    fn test_parse_request_line_rejects_invalid_json_and_missing_method() {
+        // Arrange
+        let invalid_json = "{not json";
+        let missing_method = r#"{"jsonrpc":"2.0","id":3}"#;
+
+        // Act
+        let invalid_json_error = parse_request_line(invalid_json).expect_err("should fail");
+        let missing_method_error = parse_request_line(missing_method).expect_err("should fail");
+
+        // Assert
+        assert_eq!(invalid_json_error.0, Value::Null);
+        assert_eq!(invalid_json_error.1.code, ControlError::PARSE_ERROR);
+        assert_eq!(missing_method_error.0, json!(3));
+        assert_eq!(missing_method_error.1.code, ControlError::INVALID_REQUEST);
+    }
+
+    #[test]
+    fn test_response_payload_formats_result_and_error() {
+        // Arrange
+        let error = ControlError::method_not_found("nope");
+
+        // Act
+        let success = response_payload(&json!(1), Ok(json!({ "ok": true })));
+        let failure = response_payload(&json!(2), Err(error));
+
+        // Assert
+        assert_eq!(
+            success,
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "ok": true } })
+        );
+        assert_eq!(
+            failure["error"]["code"],
+            json!(ControlError::METHOD_NOT_FOUND)
+        );
+        assert_eq!(failure["id"], json!(2));
+    }
+
+    #[test]
+    fn test_control_responder_delivers_only_first_result() {
+        // Arrange
+        let request = ControlRequest {
+            method: "session.list".to_string(),
+            params: Value::Null,
+        };
+        let (call, mut receiver) = ControlCall::new(request);
+        let cloned_call = call.clone();
+
+        // Act
+        call.responder.respond(Ok(json!("first")));
+        cloned_call.responder.respond(Ok(json!("second")));
+
+        // Assert
+        assert_eq!(
+            receiver.try_recv().expect("result should exist"),
+            Ok(json!("first"))
+        );
+        assert_eq!(call, cloned_call);
+    }
+
+    #[tokio::test]
+    async fn test_control_server_answers_requests_and_streams_notifications() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+        let server = ControlServer::start(socket_path.clone(), |call: ControlCall| {
+            let method = call.request.method.clone();
+            call.responder.respond(Ok(json!({ "echo": method })));
+        })
+        .await
+        .expect("server should start");
+        let stream = UnixStream::connect(&socket_path)
+            .await
+            .expect("failed to connect");
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+
+        // Act
+        let echo = round_trip(
+            &mut lines,
+            &mut writer,
+            r#"{"jsonrpc":"2.0","id":1,"method":"session.list"}"#,
+        )
+        .await;
+        let subscribed = round_trip(
+            &mut lines,
+            &mut writer,
+            r#"{"jsonrpc":"2.0","id":2,"method":"events.subscribe"}"#,
+        )
+        .await;
+        server.publish("session.updated", &json!({ "session_id": "abc" }));
+        let notification_line = lines
+            .next_line()
+            .await
+            .expect("failed to read notification")
+            .expect("connection closed");
+        let notification: Value =
+            serde_json::from_str(&notification_line).expect("notification should be JSON");
+
+        // Assert
+        assert_eq!(echo["result"], json!({ "echo": "session.list" }));
+        assert_eq!(subscribed["result"], json!({ "subscribed": true }));
+        assert_eq!(notification["method"], json!("session.updated"));
+        assert_eq!(notification["params"]["session_id"], json!("abc"));
+        assert_eq!(server.socket_path(), socket_path.as_path());
+    }
+
+    #[tokio::test]
+    async fn test_control_server_binds_owner_only_socket_without_staging_leftovers() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+
+        // Act
+        let _server = ControlServer::start(socket_path.clone(), |_call: ControlCall| {})
+            .await
+            .expect("server should start");
+
+        // Assert
+        let socket_mode = std::fs::metadata(&socket_path)
+            .expect("socket should exist")
+            .permissions()
+            .mode();
+        let entries = std::fs::read_dir(temp_dir.path())
+            .expect("temp dir should be readable")
+            .map(|entry| entry.expect("entry should be readable").file_name())
+            .collect::<Vec<_>>();
+        assert_eq!(socket_mode & 0o777, 0o600);
+        assert_eq!(entries, vec![std::ffi::OsString::from(CONTROL_SOCKET_FILE)]);
+        assert!(UnixStream::connect(&socket_path).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_control_server_rejects_oversized_request_line() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+        let _server = ControlServer::start(socket_path.clone(), |call: ControlCall| {
+            call.responder.respond(Ok(Value::Null));
+        })
+        .await
+        .expect("server should start");
+        let stream = UnixStream::connect(&socket_path)
+            .await
+            .expect("failed to connect");
+        let (reader, mut writer) = stream.into_split();
+        let mut lines = BufReader::new(reader).lines();
+        let oversized_line = "x".repeat(usize::try_from(MAX_REQUEST_BYTES).expect("fits") + 1);
+
+        // Act
+        let write_task = tokio::spawn(async move {
+            // The server closes the connection mid-write, so this may fail.
+            let _ = writer.write_all(oversized_line.as_bytes()).await;
+        });
+        let response_line = lines
+            .next_line()
+            .await
+            .expect("failed to read response")
+            .expect("connection closed");
+        let response: Value = serde_json::from_str(&response_line).expect("response is JSON");
+        let next_line = lines.next_line().await;
+        write_task.await.expect("write task should finish");
+
+        // Assert
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(
+            response["error"]["code"],
+            json!(ControlError::INVALID_REQUEST)
+        );
+        assert!(matches!(next_line, Ok(None) | Err(_)));
+    }
+
+    #[tokio::test]
+    async fn test_control_server_refuses_live_socket_and_cleans_up_on_drop() {
+        // Arrange
+        let temp_dir = tempfile::tempdir().expect("failed to create temp dir");
+        let socket_path = temp_dir.path().join(CONTROL_SOCKET_FILE);
+        let server = ControlServer::start(socket_path.clone(), |_call: ControlCall| {})
+            .await
+            .expect("server should start");
+
+        // Act
+        let second_start = ControlServer::start(socket_path.clone(), |_call: ControlCall| {}).await;
+        drop(server);
+
+        // Assert
+        let error = second_start.err().expect("second server should fail");
+        assert_eq!(error.kind(), io::ErrorKind::AddrInUse);
+        assert!(!socket_path.exists());
+    }
+}
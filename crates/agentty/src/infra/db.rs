@@ -11,6 +11,7 @@ use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, S
 mod activity;
 mod operation;
 mod project;
+mod prompt_history;
 mod review;
 mod session;
 mod setting;
@@ -21,18 +22,22 @@ pub use operation::SessionOperationRow;
 pub(crate) use operation::{OperationRepository, SqliteOperationRepository};
 pub use project::{ProjectListRow, ProjectRow};
 pub(crate) use project::{ProjectRepository, SqliteProjectRepository};
+pub(crate) use prompt_history::{PromptHistoryRepository, SqlitePromptHistoryRepository};
 pub use review::SessionReviewRequestRow;
 pub(crate) use review::{ReviewRepository, SqliteReviewRepository};
 #[cfg(test)]
 pub(crate) use session::SessionJoinRow;
 pub use session::SessionRow;
-pub(crate) use session::{SessionRepository, SessionTurnMetadata, SqliteSessionRepository};
+pub(crate) use session::{
+    SessionPlanRow, SessionRepository, SessionSearchHitRow, SessionTurnMetadata, SessionTurnRow,
+    SqliteSessionRepository,
+};
 pub(crate) use setting::{SettingRepository, SqliteSettingRepository};
 pub use usage::SessionUsageRow;
 pub(crate) use usage::{SqliteUsageRepository, UsageRepository};
 
 use crate::domain::agent::ReasoningLevel;
-use crate::domain::session::{DailyActivity, ReviewRequest, SessionStats};
+use crate::domain::session::{DailyActivity, ReviewRequest, SessionMessageRole, SessionStats};
 
 /// Typed error returned by database operations.
 ///
@@ -80,6 +85,7 @@ pub struct AppRepositories {
     activity: Arc<dyn ActivityRepository>,
     operation: Arc<dyn OperationRepository>,
     project: Arc<dyn ProjectRepository>,
+    prompt_history: Arc<dyn PromptHistoryRepository>,
     review: Arc<dyn ReviewRepository>,
     session: Arc<dyn SessionRepository>,
     setting: Arc<dyn SettingRepository>,
@@ -87,27 +93,6 @@ pub struct AppRepositories {
 }
 
 impl AppRepositories {
-    /// Creates a repository bundle from explicit repository implementations.
-    pub(crate) fn new(
-        activity: Arc<dyn ActivityRepository>,
-        operation: Arc<dyn OperationRepository>,
-        project: Arc<dyn ProjectRepository>,
-        review: Arc<dyn ReviewRepository>,
-        session: Arc<dyn SessionRepository>,
-        setting: Arc<dyn SettingRepository>,
-        usage: Arc<dyn UsageRepository>,
-    ) -> Self {
-        Self {
-            activity,
-            operation,
-            project,
-            review,
-            session,
-            setting,
-            usage,
-        }
-    }
-
     /// Creates a repository bundle backed by one shared `SQLite` pool.
     pub(crate) fn from_database(database: &Database) -> Self {
         Self::from_pool(database.pool().clone())
@@ -115,15 +100,16 @@ impl AppRepositories {
 
     /// Creates a repository bundle backed by one shared `SQLite` pool.
     pub(crate) fn from_pool(pool: SqlitePool) -> Self {
-        Self::new(
-            Arc::new(SqliteActivityRepository::new(pool.clone())),
-            Arc::new(SqliteOperationRepository::new(pool.clone())),
-            Arc::new(SqliteProjectRepository::new(pool.clone())),
-            Arc::new(SqliteReviewRepository::new(pool.clone())),
-            Arc::new(SqliteSessionRepository::new(pool.clone())),
-            Arc::new(SqliteSettingRepository::new(pool.clone())),
-            Arc::new(SqliteUsageRepository::new(pool)),
-        )
+        Self {
+            activity: Arc::new(SqliteActivityRepository::new(pool.clone())),
+            operation: Arc::new(SqliteOperationRepository::new(pool.clone())),
+            project: Arc::new(SqliteProjectRepository::new(pool.clone())),
+            prompt_history: Arc::new(SqlitePromptHistoryRepository::new(pool.clone())),
+            review: Arc::new(SqliteReviewRepository::new(pool.clone())),
+            session: Arc::new(SqliteSessionRepository::new(pool.clone())),
+            setting: Arc::new(SqliteSettingRepository::new(pool.clone())),
+            usage: Arc::new(SqliteUsageRepository::new(pool)),
+        }
     }
 
     /// Loads aggregated session-creation activity counts keyed by local day.
@@ -320,6 +306,30 @@ impl AppRepositories {
             .await
     }
 
+    /// Loads up to `limit` distinct prompts submitted in one project, most
+    /// recently used first.
+    pub(crate) async fn load_prompt_history(
+        &self,
+        project_id: i64,
+        limit: u32,
+    ) -> Result<Vec<String>, DbError> {
+        self.prompt_history
+            .load_prompt_history(project_id, limit)
+            .await
+    }
+
+    /// Records one submitted prompt for a project, moving an identical
+    /// earlier prompt to the front of the history.
+    pub(crate) async fn record_prompt_history(
+        &self,
+        project_id: i64,
+        prompt: &str,
+    ) -> Result<(), DbError> {
+        self.prompt_history
+            .record_prompt_history(project_id, prompt)
+            .await
+    }
+
     /// Updates the persisted forge review-request linkage for a session.
     ///
     /// # Errors
@@ -334,9 +344,14 @@ impl AppRepositories {
             .await
     }
 
-    /// Appends text to the saved output for a session row.
-    pub(crate) async fn append_session_output(&self, id: &str, chunk: &str) -> Result<(), DbError> {
-        self.session.append_session_output(id, chunk).await
+    /// Appends one transcript message to the latest turn of a session.
+    pub(crate) async fn append_session_message(
+        &self,
+        id: &str,
+        role: SessionMessageRole,
+        content: &str,
+    ) -> Result<(), DbError> {
+        self.session.append_session_message(id, role, content).await
     }
 
     /// Sets `project_id` for sessions that do not yet reference a project.
@@ -411,7 +426,6 @@ impl AppRepositories {
             .await
     }
 
-    #[cfg(test)]
     /// Loads all sessions ordered by most recent update.
     pub(crate) async fn load_sessions(&self) -> Result<Vec<SessionRow>, DbError> {
         self.session.load_sessions().await
@@ -457,6 +471,19 @@ impl AppRepositories {
             .await
     }
 
+    /// Loads the persisted plan payload for one plan-first session.
+    pub(crate) async fn load_session_plan(
+        &self,
+        session_id: &str,
+    ) -> Result<Option<String>, DbError> {
+        self.session.load_session_plan(session_id).await
+    }
+
+    /// Loads the persisted plan payloads of every plan-first session.
+    pub(crate) async fn load_session_plans(&self) -> Result<Vec<SessionPlanRow>, DbError> {
+        self.session.load_session_plans().await
+    }
+
     /// Loads the persisted summary text associated with one session.
     pub(crate) async fn load_session_summary(
         &self,
@@ -465,6 +492,19 @@ impl AppRepositories {
         self.session.load_session_summary(session_id).await
     }
 
+    /// Loads the full transcript text for one session.
+    pub(crate) async fn load_session_transcript(
+        &self,
+        session_id: &str,
+    ) -> Result<String, DbError> {
+        self.session.load_session_transcript(session_id).await
+    }
+
+    /// Loads all persisted turns with their transcript start offsets.
+    pub(crate) async fn load_session_turns(&self) -> Result<Vec<SessionTurnRow>, DbError> {
+        self.session.load_session_turns().await
+    }
+
     /// Returns `(created_at, updated_at)` timestamps for a session.
     pub(crate) async fn load_session_timestamps(
         &self,
@@ -485,14 +525,29 @@ impl AppRepositories {
             .await
     }
 
-    #[cfg(test)]
-    /// Replaces the full output for a session row.
-    pub(crate) async fn replace_session_output(
+    /// Returns the best-ranked hit per session for `query` across all
+    /// projects.
+    pub(crate) async fn search_sessions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SessionSearchHitRow>, DbError> {
+        self.session.search_sessions(query, limit).await
+    }
+
+    /// Opens the next turn for a session, records the names of the skills
+    /// attached to its prompt, and stores its prompt block as the turn's
+    /// first user message.
+    pub(crate) async fn start_session_turn(
         &self,
         id: &str,
-        output: &str,
+        prompt: &str,
+        prompt_block: &str,
+        skills: &[String],
     ) -> Result<(), DbError> {
-        self.session.replace_session_output(id, output).await
+        self.session
+            .start_session_turn(id, prompt, prompt_block, skills)
+            .await
     }
 
     /// Updates persisted diff-derived size and line-count fields for a
@@ -555,6 +610,17 @@ impl AppRepositories {
             .await
     }
 
+    /// Updates the persisted plan payload, or clears it with `None`.
+    pub(crate) async fn update_session_plan(
+        &self,
+        id: &str,
+        plan: Option<&str>,
+    ) -> Result<(), DbError> {
+        self.session
+            .update_session_plan(id, plan.map(str::to_string))
+            .await
+    }
+
     /// Updates the model clarification questions for a session row.
     pub(crate) async fn update_session_questions(
         &self,
@@ -575,6 +641,19 @@ impl AppRepositories {
             .await
     }
 
+    /// Updates the forge issue link recorded for a session started from the
+    /// issue picker.
+    pub(crate) async fn update_session_issue(
+        &self,
+        id: &str,
+        issue_display_id: &str,
+        issue_web_url: &str,
+    ) -> Result<(), DbError> {
+        self.session
+            .update_session_issue(id, issue_display_id.to_string(), issue_web_url.to_string())
+            .await
+    }
+
     /// Updates the persisted upstream reference for a published session
     /// branch.
     pub(crate) async fn update_session_published_upstream_ref(
@@ -682,7 +761,6 @@ impl AppRepositories {
         self.setting.load_project_reasoning_level(project_id).await
     }
 
-    #[cfg(test)]
     /// Looks up a setting value by name.
     pub(crate) async fn get_setting(
         &self,
@@ -734,7 +812,6 @@ impl AppRepositories {
             .await
     }
 
-    #[cfg(test)]
     /// Inserts or updates a setting by name.
     pub(crate) async fn upsert_setting(
         &self,
@@ -864,7 +941,9 @@ mod tests {
     use super::*;
     use crate::agent::AgentModel;
     use crate::domain::agent::ReasoningLevel;
-    use crate::domain::session::{ForgeKind, ReviewRequestState, ReviewRequestSummary};
+    use crate::domain::session::{
+        ForgeKind, ReviewRequestState, ReviewRequestSummary, SessionSearchSource,
+    };
     use crate::domain::setting::SettingName;
     /// Environment flag used to run the DST regression helper in an isolated
     /// subprocess with a fixed timezone.
@@ -1042,7 +1121,6 @@ WHERE id = ?
         assert_eq!(session_row.in_progress_total_seconds, 120);
         assert_eq!(session_row.project_id, Some(project_id));
         assert_eq!(session_row.prompt, "Implement the feature");
-        assert_eq!(session_row.output, "First line\nSecond line");
         assert_eq!(session_row.added_lines, 14);
         assert_eq!(session_row.deleted_lines, 6);
         assert_eq!(session_row.input_tokens, 11);
@@ -1136,8 +1214,7 @@ WHERE id = ?
             .expect("failed to update review request");
     }
 
-    /// Persists timing and output fields asserted by the joined-session
-    /// mapping test.
+    /// Persists timing fields asserted by the joined-session mapping test.
     async fn persist_joined_session_output(database: &Database) {
         database
             .update_session_status_with_timing_at("session-a", "InProgress", 50)
@@ -1147,14 +1224,6 @@ WHERE id = ?
             .update_session_status_with_timing_at("session-a", "Review", 170)
             .await
             .expect("failed to close in-progress timing window");
-        database
-            .replace_session_output("session-a", "First line")
-            .await
-            .expect("failed to replace session output");
-        database
-            .append_session_output("session-a", "\nSecond line")
-            .await
-            .expect("failed to append session output");
         database
             .update_session_updated_at("session-a", 200)
             .await
@@ -2622,6 +2691,59 @@ WHERE model = ?
         assert_eq!(loaded_project_id, Some(project_id));
     }
 
+    #[tokio::test]
+    async fn test_update_session_plan_round_trips_and_clears_plan() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = database
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        database
+            .insert_session("session-a", "gpt-5.4", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        database
+            .insert_session("session-b", "gpt-5.4", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+
+        // Act
+        database
+            .update_session_plan("session-a", Some(r#"{"status":"planning"}"#))
+            .await
+            .expect("failed to update session plan");
+        let loaded_plan = database
+            .load_session_plan("session-a")
+            .await
+            .expect("failed to load session plan");
+        let loaded_plans = database
+            .load_session_plans()
+            .await
+            .expect("failed to load session plans");
+        database
+            .update_session_plan("session-a", None)
+            .await
+            .expect("failed to clear session plan");
+        let cleared_plan = database
+            .load_session_plan("session-a")
+            .await
+            .expect("failed to load cleared session plan");
+
+        // Assert
+        assert_eq!(loaded_plan.as_deref(), Some(r#"{"status":"planning"}"#));
+        assert_eq!(
+            loaded_plans,
+            vec![SessionPlanRow {
+                plan: r#"{"status":"planning"}"#.to_string(),
+                session_id: "session-a".to_string(),
+            }]
+        );
+        assert_eq!(cleared_plan, None);
+    }
+
     #[tokio::test]
     async fn test_load_session_summary_returns_persisted_summary() {
         // Arrange
@@ -2716,6 +2838,478 @@ WHERE model = ?
         assert_eq!(provider_conversation_id.as_deref(), None);
     }
 
+    #[tokio::test]
+    /// Verifies turn messages rebuild the transcript and report each turn's
+    /// byte offset inside it.
+    async fn test_start_session_turn_records_transcript_offsets() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = database
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        database
+            .insert_session("session-a", "gpt-5.4", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+
+        // Act
+        database
+            .start_session_turn("session-a", "first", " › first\n\n", &[])
+            .await
+            .expect("failed to start first turn");
+        database
+            .append_session_message("session-a", SessionMessageRole::Assistant, "Done ✓\n")
+            .await
+            .expect("failed to append first answer");
+        database
+            .append_session_message("session-a", SessionMessageRole::System, "\n[Commit] ok\n")
+            .await
+            .expect("failed to append commit notice");
+        database
+            .start_session_turn(
+                "session-a",
+                "second",
+                "\n › second\n\n",
+                &["review".to_string()],
+            )
+            .await
+            .expect("failed to start second turn");
+        let transcript = database
+            .load_session_transcript("session-a")
+            .await
+            .expect("failed to load transcript");
+        let turns = database
+            .load_session_turns()
+            .await
+            .expect("failed to load turns");
+        let answer: String = sqlx::query_scalar(
+            "SELECT answer FROM session_turn WHERE session_id = 'session-a' AND turn_number = 1",
+        )
+        .fetch_one(database.pool())
+        .await
+        .expect("failed to load first answer");
+
+        // Assert
+        assert_eq!(
+            transcript,
+            " › first\n\nDone ✓\n\n[Commit] ok\n\n › second\n\n"
+        );
+        assert_eq!(answer, "Done ✓\n");
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].turn_number, 1);
+        assert_eq!(turns[0].transcript_start, 0);
+        assert_eq!(turns[1].turn_number, 2);
+        assert_eq!(turns[1].prompt, "second");
+        assert_eq!(turns[0].skills, "[]");
+        assert_eq!(turns[1].skills, "[\"review\"]");
+        assert_eq!(
+            usize::try_from(turns[1].transcript_start).expect("offset should fit"),
+            transcript
+                .find("\n › second")
+                .expect("second prompt should exist")
+        );
+    }
+
+    #[tokio::test]
+    /// Verifies turn offsets count messages written before the first turn
+    /// and stay scoped to their own session.
+    async fn test_load_session_turns_counts_pre_turn_messages_per_session() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = database
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        for session_id in ["session-a", "session-b"] {
+            database
+                .insert_session(session_id, "gpt-5.4", "main", "Review", project_id)
+                .await
+                .expect("failed to insert session");
+        }
+        database
+            .append_session_message("session-a", SessionMessageRole::System, "setup\n")
+            .await
+            .expect("failed to append pre-turn notice");
+        database
+            .start_session_turn("session-a", "first", " › first\n\n", &[])
+            .await
+            .expect("failed to start session-a turn");
+        database
+            .start_session_turn("session-b", "other", " › other\n\n", &[])
+            .await
+            .expect("failed to start session-b turn");
+
+        // Act
+        let turns = database
+            .load_session_turns()
+            .await
+            .expect("failed to load turns");
+
+        // Assert
+        assert_eq!(turns.len(), 2);
+        assert_eq!(turns[0].session_id, "session-a");
+        assert_eq!(turns[0].transcript_start, 6);
+        assert_eq!(turns[1].session_id, "session-b");
+        assert_eq!(turns[1].transcript_start, 0);
+    }
+
+    #[tokio::test]
+    /// Verifies migration 045 splits a legacy multi-turn `session.output`
+    /// into turns and messages that reproduce the original transcript.
+    async fn test_migration_045_splits_legacy_output_into_turns_and_messages() {
+        // Arrange
+        let options = SqliteConnectOptions::new()
+            .filename(":memory:")
+            .foreign_keys(true);
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(options)
+            .await
+            .expect("failed to open in-memory db");
+        let migrator = sqlx::migrate!("./migrations");
+        let pre_045_migrator = sqlx::migrate::Migrator {
+            migrations: std::borrow::Cow::Owned(
+                migrator
+                    .iter()
+                    .filter(|migration| migration.version < 45)
+                    .cloned()
+                    .collect(),
+            ),
+            ..sqlx::migrate!("./migrations")
+        };
+        pre_045_migrator
+            .run(&pool)
+            .await
+            .expect("failed to run pre-045 migrations");
+        let output = "[Setup] ready\n › first\n   line two\n\nAnswer one\n\n[Commit] ok\n\n › \
+                      second\n\nAnswer two ✓\n";
+        sqlx::query("INSERT INTO project (path) VALUES ('/tmp/project')")
+            .execute(&pool)
+            .await
+            .expect("failed to insert project");
+        sqlx::query(
+            "INSERT INTO session (id, model, base_branch, status, project_id, output, summary) \
+             VALUES ('session-a', 'gpt-5.4', 'main', 'Review', 1, ?, 'Summary')",
+        )
+        .bind(output)
+        .execute(&pool)
+        .await
+        .expect("failed to insert legacy session");
+
+        // Act
+        migrator
+            .run(&pool)
+            .await
+            .expect("failed to run migration 045");
+        let database = Database {
+            repositories: AppRepositories::from_pool(pool.clone()),
+            pool,
+        };
+        let transcript = database
+            .load_session_transcript("session-a")
+            .await
+            .expect("failed to load transcript");
+        let turns: Vec<(i64, String, String, Option<String>)> = sqlx::query_as(
+            "SELECT turn_number, prompt, answer, summary FROM session_turn WHERE session_id = \
+             'session-a' ORDER BY turn_number",
+        )
+        .fetch_all(database.pool())
+        .await
+        .expect("failed to load turns");
+        let messages: Vec<(i64, String)> = sqlx::query_as(
+            "SELECT turn_number, role FROM session_message WHERE session_id = 'session-a' ORDER \
+             BY id",
+        )
+        .fetch_all(database.pool())
+        .await
+        .expect("failed to load messages");
+
+        // Assert
+        assert_eq!(transcript, output);
+        assert_eq!(
+            turns,
+            vec![
+                (
+                    1,
+                    "first\nline two".to_string(),
+                    "Answer one\n\n[Commit] ok".to_string(),
+                    None,
+                ),
+                (
+                    2,
+                    "second".to_string(),
+                    "Answer two ✓".to_string(),
+                    Some("Summary".to_string()),
+                ),
+            ]
+        );
+        assert_eq!(
+            messages,
+            vec![
+                (0, "system".to_string()),
+                (1, "user".to_string()),
+                (1, "assistant".to_string()),
+                (2, "user".to_string()),
+                (2, "assistant".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    /// Verifies turn metadata is also recorded on the latest transcript turn.
+    async fn test_persist_session_turn_metadata_updates_latest_turn() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = database
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        database
+            .insert_session("session-a", "gpt-5.4", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        database
+            .start_session_turn("session-a", "first", " › first\n\n", &[])
+            .await
+            .expect("failed to start turn");
+
+        // Act
+        database
+            .persist_session_turn_metadata(
+                "session-a",
+                &SessionTurnMetadata {
+                    instruction_conversation_id: None,
+                    model: AgentModel::Gpt54.as_str(),
+                    provider_conversation_id: None,
+                    questions_json: "[]",
+                    summary: "turn summary",
+                    token_usage_delta: &SessionStats {
+                        added_lines: 0,
+                        deleted_lines: 0,
+                        input_tokens: 3,
+                        output_tokens: 5,
+                    },
+                },
+            )
+            .await
+            .expect("failed to persist turn metadata");
+        let (summary, input_tokens, output_tokens): (Option<String>, i64, i64) = sqlx::query_as(
+            "SELECT summary, input_tokens, output_tokens FROM session_turn WHERE session_id = \
+             'session-a'",
+        )
+        .fetch_one(database.pool())
+        .await
+        .expect("failed to load turn metadata");
+
+        // Assert
+        assert_eq!(summary.as_deref(), Some("turn summary"));
+        assert_eq!(input_tokens, 3);
+        assert_eq!(output_tokens, 5);
+    }
+
+    #[tokio::test]
+    async fn test_search_sessions_ranks_hits_across_projects_and_turns() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let first_project_id = database
+            .upsert_project("/tmp/alpha", Some("main"))
+            .await
+            .expect("failed to insert first project");
+        let second_project_id = database
+            .upsert_project("/tmp/beta", Some("main"))
+            .await
+            .expect("failed to insert second project");
+        database
+            .insert_session("session-a", "gpt-5.4", "main", "Review", first_project_id)
+            .await
+            .expect("failed to insert first session");
+        database
+            .insert_session("session-b", "gpt-5.4", "main", "Review", second_project_id)
+            .await
+            .expect("failed to insert second session");
+        database
+            .update_session_title("session-a", "Stabilize flaky sqlx test")
+            .await
+            .expect("failed to update title");
+        database
+            .start_session_turn("session-b", "first", " › first\n\n", &[])
+            .await
+            .expect("failed to start first turn");
+        database
+            .start_session_turn("session-b", "second", "\n › second\n\n", &[])
+            .await
+            .expect("failed to start second turn");
+        database
+            .append_session_message(
+                "session-b",
+                SessionMessageRole::Assistant,
+                "I fixed the flaky\nsqlx test by pinning the pool size.",
+            )
+            .await
+            .expect("failed to append answer");
+
+        // Act
+        let hits = database
+            .search_sessions("flak sqlx", 10)
+            .await
+            .expect("failed to search sessions")
+            .into_iter()
+            .map(SessionSearchHitRow::into_session_search_hit)
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(hits.len(), 2);
+        let title_hit = hits
+            .iter()
+            .find(|hit| hit.session_id.as_str() == "session-a")
+            .expect("title hit should be returned");
+        assert_eq!(title_hit.source, SessionSearchSource::Title);
+        assert_eq!(title_hit.project_name, "alpha");
+        assert_eq!(title_hit.turn_number, None);
+        let transcript_hit = hits
+            .iter()
+            .find(|hit| hit.session_id.as_str() == "session-b")
+            .expect("transcript hit should be returned");
+        assert_eq!(transcript_hit.source, SessionSearchSource::Transcript);
+        assert_eq!(transcript_hit.project_id, Some(second_project_id));
+        assert_eq!(transcript_hit.turn_number, Some(2));
+        assert!(!transcript_hit.snippet.contains('\n'));
+        let matched_terms = transcript_hit
+            .snippet_match_ranges
+            .iter()
+            .map(|range| &transcript_hit.snippet[range.clone()])
+            .collect::<Vec<_>>();
+        assert_eq!(matched_terms, vec!["flaky", "sqlx"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_sessions_follows_title_and_summary_updates_and_deletes() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let project_id = database
+            .upsert_project("/tmp/project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        database
+            .insert_session("session-a", "gpt-5.4", "main", "Review", project_id)
+            .await
+            .expect("failed to insert session");
+        database
+            .update_session_title("session-a", "Old heading")
+            .await
+            .expect("failed to update title");
+        database
+            .update_session_title("session-a", "New heading")
+            .await
+            .expect("failed to replace title");
+        database
+            .update_session_summary("session-a", r#"{"turn":"Pinned pool size"}"#)
+            .await
+            .expect("failed to update summary");
+
+        // Act
+        let old_title_hits = database
+            .search_sessions("old", 10)
+            .await
+            .expect("failed to search old title");
+        let summary_hits = database
+            .search_sessions("pool", 10)
+            .await
+            .expect("failed to search summary");
+        let summary_key_hits = database
+            .search_sessions("turn", 10)
+            .await
+            .expect("failed to search summary key");
+        database
+            .delete_session("session-a")
+            .await
+            .expect("failed to delete session");
+        let deleted_hits = database
+            .search_sessions("heading", 10)
+            .await
+            .expect("failed to search deleted session");
+
+        // Assert
+        assert!(old_title_hits.is_empty());
+        assert_eq!(summary_hits.len(), 1);
+        assert_eq!(summary_hits[0].source, "summary");
+        assert!(summary_key_hits.is_empty());
+        assert!(deleted_hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_search_sessions_returns_empty_for_blank_query() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+
+        // Act
+        let hits = database
+            .search_sessions("   ", 10)
+            .await
+            .expect("failed to search sessions");
+
+        // Assert
+        assert!(hits.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_record_prompt_history_moves_repeated_prompt_to_front_per_project() {
+        // Arrange
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let first_project_id = database
+            .upsert_project("/tmp/alpha", Some("main"))
+            .await
+            .expect("failed to insert first project");
+        let second_project_id = database
+            .upsert_project("/tmp/beta", Some("main"))
+            .await
+            .expect("failed to insert second project");
+        for prompt in ["Fix the login flow", "Add tests", "Fix the login flow"] {
+            database
+                .record_prompt_history(first_project_id, prompt)
+                .await
+                .expect("failed to record prompt");
+        }
+        database
+            .record_prompt_history(second_project_id, "Update docs")
+            .await
+            .expect("failed to record prompt");
+
+        // Act
+        let first_history = database
+            .load_prompt_history(first_project_id, 10)
+            .await
+            .expect("failed to load first history");
+        let limited_history = database
+            .load_prompt_history(first_project_id, 1)
+            .await
+            .expect("failed to load limited history");
+        let second_history = database
+            .load_prompt_history(second_project_id, 10)
+            .await
+            .expect("failed to load second history");
+
+        // Assert
+        assert_eq!(first_history, vec!["Fix the login flow", "Add tests"]);
+        assert_eq!(limited_history, vec!["Fix the login flow"]);
+        assert_eq!(second_history, vec!["Update docs"]);
+    }
+
     #[tokio::test]
     async fn test_set_project_favorite_updates_project_state() {
         // Arrange
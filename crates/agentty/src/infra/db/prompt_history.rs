@@ -0,0 +1,78 @@
+//! Per-project prompt-history persistence adapters and query helpers.
+
+use async_trait::async_trait;
+use sqlx::SqlitePool;
+
+use crate::infra::db::DbError;
+
+/// Prompt-history persistence boundary used by app orchestration and tests.
+#[cfg_attr(test, mockall::automock)]
+#[async_trait]
+pub(crate) trait PromptHistoryRepository: Send + Sync {
+    /// Loads up to `limit` distinct prompts submitted in one project, most
+    /// recently used first.
+    async fn load_prompt_history(
+        &self,
+        project_id: i64,
+        limit: u32,
+    ) -> Result<Vec<String>, DbError>;
+
+    /// Records one submitted prompt for a project.
+    ///
+    /// Submitting the same text again moves it to the front of the history
+    /// instead of storing a duplicate row.
+    async fn record_prompt_history(&self, project_id: i64, prompt: &str) -> Result<(), DbError>;
+}
+
+/// `SQLite` implementation of [`PromptHistoryRepository`].
+#[derive(Clone)]
+pub(crate) struct SqlitePromptHistoryRepository(SqlitePool);
+
+impl SqlitePromptHistoryRepository {
+    /// Creates a prompt-history repository backed by the provided pool.
+    pub(crate) fn new(pool: SqlitePool) -> Self {
+        Self(pool)
+    }
+}
+
+#[async_trait]
+impl PromptHistoryRepository for SqlitePromptHistoryRepository {
+    async fn load_prompt_history(
+        &self,
+        project_id: i64,
+        limit: u32,
+    ) -> Result<Vec<String>, DbError> {
+        let prompts = sqlx::query_scalar::<_, String>(
+            r"
+SELECT prompt
+FROM prompt_history
+WHERE project_id = ?
+ORDER BY id DESC
+LIMIT ?
+",
+        )
+        .bind(project_id)
+        .bind(i64::from(limit))
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(prompts)
+    }
+
+    async fn record_prompt_history(&self, project_id: i64, prompt: &str) -> Result<(), DbError> {
+        // `REPLACE` deletes the older duplicate and inserts a fresh row, so
+        // the new row id keeps `ORDER BY id DESC` in most-recent-first order.
+        sqlx::query(
+            r"
+INSERT OR REPLACE INTO prompt_history (project_id, prompt, created_at)
+VALUES (?, ?, unixepoch())
+",
+        )
+        .bind(project_id)
+        .bind(prompt)
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+}
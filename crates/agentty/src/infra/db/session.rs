@@ -1,15 +1,28 @@
 //! Session-scoped persistence adapters and query helpers.
 
+use std::ops::Range;
+use std::path::Path;
+
 use async_trait::async_trait;
 use sqlx::SqlitePool;
 
 use super::AppRepositories;
 use super::review::SessionReviewRequestRow;
 use crate::domain::agent::ReasoningLevel;
-use crate::domain::session::{SessionFollowUpTask, SessionId, SessionStats};
+use crate::domain::project::project_name_from_path;
+use crate::domain::session::{
+    SessionFollowUpTask, SessionId, SessionMessageRole, SessionSearchHit, SessionSearchSource,
+    SessionStats, SessionTurn,
+};
 use crate::infra::agent;
 use crate::infra::db::DbError;
 
+/// Marker inserted by `snippet()` before one matched search term.
+const SEARCH_SNIPPET_MATCH_START: char = '\u{2}';
+
+/// Marker inserted by `snippet()` after one matched search term.
+const SEARCH_SNIPPET_MATCH_END: char = '\u{3}';
+
 /// Transactional turn-metadata payload persisted after one completed agent
 /// turn.
 pub(crate) struct SessionTurnMetadata<'a> {
@@ -42,8 +55,9 @@ pub struct SessionRow {
     pub in_progress_total_seconds: i64,
     pub input_tokens: i64,
     pub is_draft: bool,
+    pub issue_display_id: Option<String>,
+    pub issue_web_url: Option<String>,
     pub model: String,
-    pub output: String,
     pub output_tokens: i64,
     pub project_id: Option<i64>,
     pub prompt: String,
@@ -80,12 +94,136 @@ impl SessionFollowUpTaskRow {
         }
     }
 }
+/// Row returned when loading one persisted session plan payload.
+#[derive(Clone, Debug, Eq, PartialEq, sqlx::FromRow)]
+pub(crate) struct SessionPlanRow {
+    pub plan: String,
+    pub session_id: String,
+}
+
+/// Row returned when loading one persisted `session_turn`.
+#[derive(Clone, Debug, Eq, PartialEq, sqlx::FromRow)]
+pub(crate) struct SessionTurnRow {
+    pub prompt: String,
+    pub session_id: String,
+    pub skills: String,
+    pub transcript_start: i64,
+    pub turn_number: i64,
+}
+
+impl SessionTurnRow {
+    /// Converts one turn row into the domain snapshot used by the UI.
+    ///
+    /// A malformed skills payload loads as an empty skill list.
+    pub(crate) fn into_session_turn(self) -> SessionTurn {
+        SessionTurn {
+            number: u32::try_from(self.turn_number).unwrap_or(u32::MAX),
+            prompt: self.prompt,
+            skills: serde_json::from_str(&self.skills).unwrap_or_default(),
+            transcript_start: usize::try_from(self.transcript_start).unwrap_or(0),
+        }
+    }
+}
+
+/// Row returned when searching session titles, summaries, and transcripts.
+#[derive(Clone, Debug, Eq, PartialEq, sqlx::FromRow)]
+pub(crate) struct SessionSearchHitRow {
+    pub project_display_name: Option<String>,
+    pub project_id: Option<i64>,
+    pub project_path: Option<String>,
+    pub session_id: String,
+    pub snippet: String,
+    pub source: String,
+    pub title: String,
+    pub turn_number: Option<i64>,
+}
+
+impl SessionSearchHitRow {
+    /// Converts one search row into the domain hit used by the search
+    /// overlay.
+    ///
+    /// Snippet match markers become byte ranges, and line breaks become
+    /// spaces so the excerpt renders on one row.
+    pub(crate) fn into_session_search_hit(self) -> SessionSearchHit {
+        let project_name = self
+            .project_display_name
+            .filter(|display_name| !display_name.trim().is_empty())
+            .or_else(|| {
+                self.project_path
+                    .as_deref()
+                    .map(|path| project_name_from_path(Path::new(path)))
+            })
+            .unwrap_or_default();
+        let (snippet, snippet_match_ranges) = parse_search_snippet(&self.snippet);
+
+        SessionSearchHit {
+            project_id: self.project_id,
+            project_name,
+            session_id: SessionId::from(self.session_id),
+            snippet,
+            snippet_match_ranges,
+            source: SessionSearchSource::from_persisted(&self.source),
+            title: self.title,
+            turn_number: self
+                .turn_number
+                .and_then(|turn_number| u32::try_from(turn_number).ok()),
+        }
+    }
+}
+
+/// Strips `snippet()` match markers from `marked_snippet` and returns the
+/// plain single-line excerpt with the byte ranges of every marked term.
+fn parse_search_snippet(marked_snippet: &str) -> (String, Vec<Range<usize>>) {
+    let mut snippet = String::with_capacity(marked_snippet.len());
+    let mut match_ranges = Vec::new();
+    let mut match_start = None;
+
+    for character in marked_snippet.chars() {
+        match character {
+            SEARCH_SNIPPET_MATCH_START => match_start = Some(snippet.len()),
+            SEARCH_SNIPPET_MATCH_END => {
+                if let Some(start) = match_start.take() {
+                    match_ranges.push(start..snippet.len());
+                }
+            }
+            '\n' | '\r' | '\t' => snippet.push(' '),
+            _ => snippet.push(character),
+        }
+    }
+
+    (snippet, match_ranges)
+}
+
+/// Builds one `FTS5` `MATCH` expression from free-form user input.
+///
+/// Every whitespace-separated term becomes a quoted prefix query, so terms
+/// are matched independently of `FTS5` syntax and partially typed words still
+/// match. Returns `None` when the input has no terms.
+fn session_search_match_query(query: &str) -> Option<String> {
+    let terms = query
+        .split_whitespace()
+        .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+        .collect::<Vec<_>>();
+    if terms.is_empty() {
+        return None;
+    }
+
+    Some(terms.join(" "))
+}
+
 /// Session-focused persistence boundary used by app orchestration and tests.
 #[cfg_attr(test, mockall::automock)]
 #[async_trait]
 pub(crate) trait SessionRepository: Send + Sync {
-    /// Appends text to the saved output for a session row.
-    async fn append_session_output(&self, id: &str, chunk: &str) -> Result<(), DbError>;
+    /// Appends one transcript message to the latest turn of a session.
+    ///
+    /// Assistant messages are also accumulated into the turn's `answer`.
+    async fn append_session_message(
+        &self,
+        id: &str,
+        role: SessionMessageRole,
+        content: &str,
+    ) -> Result<(), DbError>;
 
     /// Sets `project_id` for sessions that do not yet reference a project.
     async fn backfill_session_project(&self, project_id: i64) -> Result<(), DbError>;
@@ -130,7 +268,6 @@ pub(crate) trait SessionRepository: Send + Sync {
         project_id: i64,
     ) -> Result<(), DbError>;
 
-    #[cfg(test)]
     /// Loads all sessions ordered by most recent update.
     async fn load_sessions(&self) -> Result<Vec<SessionRow>, DbError>;
 
@@ -144,6 +281,12 @@ pub(crate) trait SessionRepository: Send + Sync {
     /// Loads lightweight session metadata used for cheap change detection.
     async fn load_sessions_metadata(&self) -> Result<(i64, i64), DbError>;
 
+    /// Loads the persisted plan payload for one plan-first session.
+    async fn load_session_plan(&self, session_id: &str) -> Result<Option<String>, DbError>;
+
+    /// Loads the persisted plan payloads of every plan-first session.
+    async fn load_session_plans(&self) -> Result<Vec<SessionPlanRow>, DbError>;
+
     /// Loads the project identifier associated with one session.
     async fn load_session_project_id(&self, session_id: &str) -> Result<Option<i64>, DbError>;
 
@@ -163,6 +306,14 @@ pub(crate) trait SessionRepository: Send + Sync {
     /// Loads the persisted summary text associated with one session.
     async fn load_session_summary(&self, session_id: &str) -> Result<Option<String>, DbError>;
 
+    /// Loads the full transcript text for one session by concatenating its
+    /// messages in insertion order.
+    async fn load_session_transcript(&self, session_id: &str) -> Result<String, DbError>;
+
+    /// Loads all persisted turns with the transcript offset where each turn's
+    /// prompt block starts, ordered by session and turn number.
+    async fn load_session_turns(&self) -> Result<Vec<SessionTurnRow>, DbError>;
+
     /// Returns `(created_at, updated_at)` timestamps for a session.
     async fn load_session_timestamps(
         &self,
@@ -177,10 +328,6 @@ pub(crate) trait SessionRepository: Send + Sync {
         turn_metadata: &'a SessionTurnMetadata<'a>,
     ) -> Result<(), DbError>;
 
-    #[cfg(test)]
-    /// Replaces the full output for a session row.
-    async fn replace_session_output(&self, id: &str, output: &str) -> Result<(), DbError>;
-
     /// Replaces the persisted follow-up task list for one session.
     async fn replace_session_follow_up_tasks(
         &self,
@@ -188,6 +335,25 @@ pub(crate) trait SessionRepository: Send + Sync {
         follow_up_tasks: &[String],
     ) -> Result<(), DbError>;
 
+    /// Returns the best-ranked hit per session for `query` across all
+    /// projects, ordered by relevance and capped at `limit` sessions.
+    async fn search_sessions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SessionSearchHitRow>, DbError>;
+
+    /// Opens the next turn for a session, records the names of the skills
+    /// attached to its prompt, and stores its prompt block as the turn's
+    /// first user message.
+    async fn start_session_turn(
+        &self,
+        id: &str,
+        prompt: &str,
+        prompt_block: &str,
+        skills: &[String],
+    ) -> Result<(), DbError>;
+
     /// Updates persisted diff-derived size and line-count fields for a
     /// session row.
     async fn update_session_diff_stats(
@@ -228,6 +394,9 @@ pub(crate) trait SessionRepository: Send + Sync {
         provider_conversation_id: Option<String>,
     ) -> Result<(), DbError>;
 
+    /// Updates the persisted plan payload, or clears it with `None`.
+    async fn update_session_plan(&self, id: &str, plan: Option<String>) -> Result<(), DbError>;
+
     /// Updates the model clarification questions for a session row.
     async fn update_session_questions(&self, id: &str, questions: &str) -> Result<(), DbError>;
 
@@ -238,6 +407,15 @@ pub(crate) trait SessionRepository: Send + Sync {
         reasoning_level: Option<String>,
     ) -> Result<(), DbError>;
 
+    /// Updates the forge issue link recorded for a session started from the
+    /// issue picker.
+    async fn update_session_issue(
+        &self,
+        id: &str,
+        issue_display_id: String,
+        issue_web_url: String,
+    ) -> Result<(), DbError>;
+
     /// Updates the persisted upstream reference for a published session
     /// branch.
     async fn update_session_published_upstream_ref(
@@ -346,8 +524,9 @@ pub(crate) struct SessionJoinRow {
     in_progress_total_seconds: i64,
     input_tokens: i64,
     is_draft: bool,
+    issue_display_id: Option<String>,
+    issue_web_url: Option<String>,
     model: String,
-    output: String,
     output_tokens: i64,
     project_id: Option<i64>,
     prompt: String,
@@ -383,8 +562,9 @@ impl SessionJoinRow {
             in_progress_total_seconds,
             input_tokens,
             is_draft,
+            issue_display_id,
+            issue_web_url,
             model,
-            output,
             output_tokens,
             project_id,
             prompt,
@@ -430,8 +610,9 @@ impl SessionJoinRow {
             in_progress_total_seconds,
             input_tokens,
             is_draft,
+            issue_display_id,
+            issue_web_url,
             model,
-            output,
             output_tokens,
             project_id,
             prompt,
@@ -460,8 +641,9 @@ impl SessionJoinRow {
             in_progress_total_seconds: 0,
             input_tokens: 11,
             is_draft: false,
+            issue_display_id: Some("#7".to_string()),
+            issue_web_url: Some("https://github.com/agentty-xyz/agentty/issues/7".to_string()),
             model: "gpt-5.4".to_string(),
-            output: "Saved output".to_string(),
             output_tokens: 29,
             project_id: Some(7),
             prompt: "Implement feature".to_string(),
@@ -534,19 +716,50 @@ impl SessionReviewRequestJoinRow {
 
 #[async_trait]
 impl SessionRepository for SqliteSessionRepository {
-    async fn append_session_output(&self, id: &str, chunk: &str) -> Result<(), DbError> {
+    async fn append_session_message(
+        &self,
+        id: &str,
+        role: SessionMessageRole,
+        content: &str,
+    ) -> Result<(), DbError> {
+        let mut transaction = self.0.begin().await?;
+
         sqlx::query(
             r"
-UPDATE session
-SET output = output || ?
-WHERE id = ?
+INSERT INTO session_message (session_id, turn_number, content, role)
+VALUES (
+    ?,
+    COALESCE((SELECT MAX(turn_number) FROM session_turn WHERE session_id = ?), 0),
+    ?,
+    ?
+)
 ",
         )
-        .bind(chunk)
         .bind(id)
-        .execute(&self.0)
+        .bind(id)
+        .bind(content)
+        .bind(role.as_str())
+        .execute(&mut *transaction)
         .await?;
 
+        if role == SessionMessageRole::Assistant {
+            sqlx::query(
+                r"
+UPDATE session_turn
+SET answer = answer || ?
+WHERE session_id = ?
+  AND turn_number = (SELECT MAX(turn_number) FROM session_turn WHERE session_id = ?)
+",
+            )
+            .bind(content)
+            .bind(id)
+            .bind(id)
+            .execute(&mut *transaction)
+            .await?;
+        }
+
+        transaction.commit().await?;
+
         Ok(())
     }
 
@@ -652,7 +865,6 @@ WHERE id = ?
             .await
     }
 
-    #[cfg(test)]
     async fn load_sessions(&self) -> Result<Vec<SessionRow>, DbError> {
         let rows = sqlx::query_as!(
             SessionJoinRow,
@@ -666,8 +878,9 @@ SELECT session.base_branch AS "base_branch!",
        session.in_progress_total_seconds AS "in_progress_total_seconds!",
        session.input_tokens AS "input_tokens!",
        session.is_draft AS "is_draft!: bool",
+       session.issue_display_id,
+       session.issue_web_url,
        session.model AS "model!",
-       session.output AS "output!",
        session.output_tokens AS "output_tokens!",
        session.project_id,
        session.prompt AS "prompt!",
@@ -716,8 +929,9 @@ SELECT session.base_branch AS "base_branch!",
        session.in_progress_total_seconds AS "in_progress_total_seconds!",
        session.input_tokens AS "input_tokens!",
        session.is_draft AS "is_draft!: bool",
+       session.issue_display_id,
+       session.issue_web_url,
        session.model AS "model!",
-       session.output AS "output!",
        session.output_tokens AS "output_tokens!",
        session.project_id,
        session.prompt AS "prompt!",
@@ -800,6 +1014,36 @@ SELECT (SELECT COUNT(*) FROM session) AS "session_count!: _",
         Ok((row.session_count, row.max_updated_at))
     }
 
+    async fn load_session_plan(&self, session_id: &str) -> Result<Option<String>, DbError> {
+        let plan = sqlx::query_scalar::<_, Option<String>>(
+            r"
+SELECT plan
+FROM session
+WHERE id = ?
+",
+        )
+        .bind(session_id)
+        .fetch_optional(&self.0)
+        .await?;
+
+        Ok(plan.flatten())
+    }
+
+    async fn load_session_plans(&self) -> Result<Vec<SessionPlanRow>, DbError> {
+        let rows = sqlx::query_as::<_, SessionPlanRow>(
+            r"
+SELECT id AS session_id,
+       plan
+FROM session
+WHERE plan IS NOT NULL
+",
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(rows)
+    }
+
     async fn load_session_project_id(&self, session_id: &str) -> Result<Option<i64>, DbError> {
         let row = sqlx::query_as!(
             OptionalI64ValueRow,
@@ -861,6 +1105,61 @@ WHERE id = ?
         Ok(row.flatten())
     }
 
+    async fn load_session_transcript(&self, session_id: &str) -> Result<String, DbError> {
+        let contents = sqlx::query_scalar::<_, String>(
+            r"
+SELECT content
+FROM session_message
+WHERE session_id = ?
+ORDER BY id
+",
+        )
+        .bind(session_id)
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(contents.concat())
+    }
+
+    async fn load_session_turns(&self) -> Result<Vec<SessionTurnRow>, DbError> {
+        let rows = sqlx::query_as::<_, SessionTurnRow>(
+            r"
+WITH turn_byte_count AS (
+    SELECT session_id, turn_number, SUM(byte_count) AS byte_count
+    FROM (
+        SELECT session_id, turn_number, length(CAST(content AS BLOB)) AS byte_count
+        FROM session_message
+        UNION ALL
+        SELECT session_id, turn_number, 0 AS byte_count
+        FROM session_turn
+    )
+    GROUP BY session_id, turn_number
+),
+turn_offset AS (
+    SELECT session_id,
+           turn_number,
+           SUM(byte_count) OVER (PARTITION BY session_id ORDER BY turn_number)
+               - byte_count AS transcript_start
+    FROM turn_byte_count
+)
+SELECT session_turn.prompt,
+       session_turn.session_id,
+       session_turn.skills,
+       turn_offset.transcript_start,
+       session_turn.turn_number
+FROM session_turn
+JOIN turn_offset
+  ON turn_offset.session_id = session_turn.session_id
+ AND turn_offset.turn_number = session_turn.turn_number
+ORDER BY session_turn.session_id, session_turn.turn_number
+",
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(rows)
+    }
+
     async fn load_session_timestamps(
         &self,
         session_id: &str,
@@ -908,6 +1207,26 @@ WHERE id = ?
             return Err(sqlx::Error::RowNotFound.into());
         }
 
+        sqlx::query(
+            r"
+UPDATE session_turn
+SET questions = ?,
+    summary = ?,
+    input_tokens = input_tokens + ?,
+    output_tokens = output_tokens + ?
+WHERE session_id = ?
+  AND turn_number = (SELECT MAX(turn_number) FROM session_turn WHERE session_id = ?)
+",
+        )
+        .bind(turn_metadata.questions_json)
+        .bind(turn_metadata.summary)
+        .bind(turn_metadata.token_usage_delta.input_tokens.cast_signed())
+        .bind(turn_metadata.token_usage_delta.output_tokens.cast_signed())
+        .bind(session_id)
+        .bind(session_id)
+        .execute(&mut *transaction)
+        .await?;
+
         if turn_metadata.token_usage_delta.input_tokens != 0
             || turn_metadata.token_usage_delta.output_tokens != 0
         {
@@ -948,23 +1267,6 @@ ON CONFLICT(session_id, model) DO UPDATE SET
         Ok(())
     }
 
-    #[cfg(test)]
-    async fn replace_session_output(&self, id: &str, output: &str) -> Result<(), DbError> {
-        sqlx::query(
-            r"
-UPDATE session
-SET output = ?
-WHERE id = ?
-",
-        )
-        .bind(output)
-        .bind(id)
-        .execute(&self.0)
-        .await?;
-
-        Ok(())
-    }
-
     async fn replace_session_follow_up_tasks(
         &self,
         session_id: &str,
@@ -1012,6 +1314,122 @@ VALUES (?, ?, ?)
         Ok(())
     }
 
+    async fn search_sessions(
+        &self,
+        query: &str,
+        limit: u32,
+    ) -> Result<Vec<SessionSearchHitRow>, DbError> {
+        let Some(match_query) = session_search_match_query(query) else {
+            return Ok(Vec::new());
+        };
+        let match_start = SEARCH_SNIPPET_MATCH_START.to_string();
+        let match_end = SEARCH_SNIPPET_MATCH_END.to_string();
+
+        let rows = sqlx::query_as::<_, SessionSearchHitRow>(
+            r"
+WITH search_hit AS (
+    SELECT session_search.session_id,
+           NULL AS turn_number,
+           session_search.source,
+           snippet(session_search, 2, ?, ?, '…', 16) AS snippet,
+           bm25(session_search)
+               * CASE session_search.source WHEN 'title' THEN 2.0 ELSE 1.0 END AS rank
+    FROM session_search
+    WHERE session_search MATCH ?
+    UNION ALL
+    SELECT session_message.session_id,
+           session_message.turn_number,
+           CASE session_message.role WHEN 'user' THEN 'prompt' ELSE 'transcript' END,
+           snippet(session_message_search, 0, ?, ?, '…', 16),
+           bm25(session_message_search)
+    FROM session_message_search
+    JOIN session_message ON session_message.id = session_message_search.rowid
+    WHERE session_message_search MATCH ?
+),
+ranked_hit AS (
+    SELECT search_hit.*,
+           ROW_NUMBER() OVER (
+               PARTITION BY search_hit.session_id
+               ORDER BY search_hit.rank, search_hit.turn_number DESC
+           ) AS session_hit_position
+    FROM search_hit
+)
+SELECT project.display_name AS project_display_name,
+       session.project_id,
+       project.path AS project_path,
+       ranked_hit.session_id,
+       ranked_hit.snippet,
+       ranked_hit.source,
+       COALESCE(NULLIF(trim(session.title), ''), session.prompt) AS title,
+       ranked_hit.turn_number
+FROM ranked_hit
+JOIN session ON session.id = ranked_hit.session_id
+LEFT JOIN project ON project.id = session.project_id
+WHERE ranked_hit.session_hit_position = 1
+ORDER BY ranked_hit.rank
+LIMIT ?
+",
+        )
+        .bind(&match_start)
+        .bind(&match_end)
+        .bind(&match_query)
+        .bind(&match_start)
+        .bind(&match_end)
+        .bind(&match_query)
+        .bind(i64::from(limit))
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(rows)
+    }
+
+    async fn start_session_turn(
+        &self,
+        id: &str,
+        prompt: &str,
+        prompt_block: &str,
+        skills: &[String],
+    ) -> Result<(), DbError> {
+        let skills_json = serde_json::to_string(skills).unwrap_or_else(|_| "[]".to_string());
+        let mut transaction = self.0.begin().await?;
+
+        let turn_number = sqlx::query_scalar::<_, i64>(
+            r"
+INSERT INTO session_turn (session_id, turn_number, prompt, skills)
+VALUES (
+    ?,
+    COALESCE((SELECT MAX(turn_number) FROM session_turn WHERE session_id = ?), 0) + 1,
+    ?,
+    ?
+)
+RETURNING turn_number
+",
+        )
+        .bind(id)
+        .bind(id)
+        .bind(prompt)
+        .bind(skills_json)
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        sqlx::query(
+            r"
+INSERT INTO session_message (session_id, turn_number, content, role)
+VALUES (?, ?, ?, ?)
+",
+        )
+        .bind(id)
+        .bind(turn_number)
+        .bind(prompt_block)
+        .bind(SessionMessageRole::User.as_str())
+        .execute(&mut *transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+
     async fn update_session_diff_stats(
         &self,
         added_lines: u64,
@@ -1146,6 +1564,22 @@ WHERE id = ?
         Ok(())
     }
 
+    async fn update_session_plan(&self, id: &str, plan: Option<String>) -> Result<(), DbError> {
+        sqlx::query(
+            r"
+UPDATE session
+SET plan = ?
+WHERE id = ?
+",
+        )
+        .bind(plan)
+        .bind(id)
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
     async fn update_session_questions(&self, id: &str, questions: &str) -> Result<(), DbError> {
         sqlx::query(
             r"
@@ -1182,6 +1616,29 @@ WHERE id = ?
         Ok(())
     }
 
+    async fn update_session_issue(
+        &self,
+        id: &str,
+        issue_display_id: String,
+        issue_web_url: String,
+    ) -> Result<(), DbError> {
+        sqlx::query(
+            r"
+UPDATE session
+SET issue_display_id = ?,
+    issue_web_url = ?
+WHERE id = ?
+",
+        )
+        .bind(issue_display_id)
+        .bind(issue_web_url)
+        .bind(id)
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
     async fn update_session_published_upstream_ref(
         &self,
         id: &str,
@@ -1360,8 +1817,8 @@ async fn insert_session_with_draft_mode(
 ) -> Result<(), DbError> {
     sqlx::query(
         r"
-INSERT INTO session (id, model, base_branch, status, is_draft, project_id, prompt, output)
-VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+INSERT INTO session (id, model, base_branch, status, is_draft, project_id, prompt)
+VALUES (?, ?, ?, ?, ?, ?, ?)
 ",
     )
     .bind(id)
@@ -1371,7 +1828,6 @@ VALUES (?, ?, ?, ?, ?, ?, ?, ?)
     .bind(is_draft)
     .bind(project_id)
     .bind("")
-    .bind("")
     .execute(pool)
     .await?;
 
@@ -182,7 +182,7 @@ fn prioritize_directories_for_trailing_slash(entries: &mut Vec<&FileEntry>, quer
 /// `None` if the path does not match. When `append_trailing_slash` is `true`,
 /// scoring behaves as if `path` ended with `/` without allocating a new
 /// `String`.
-fn fuzzy_score(
+pub(crate) fn fuzzy_score(
     path: &str,
     query_chars: &[char],
     query_lower: &str,
@@ -4,6 +4,8 @@ use std::future::Future;
 use std::path::PathBuf;
 use std::pin::Pin;
 
+use tokio::io::AsyncReadExt;
+
 /// Boxed async result used by [`FsClient`] trait methods.
 pub type FsFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
 
@@ -42,6 +44,17 @@ pub trait FsClient: Send + Sync {
     /// Returns an error when file read fails.
     fn read_file(&self, path: PathBuf) -> FsFuture<Result<Vec<u8>, FsError>>;
 
+    /// Reads at most `max_len` leading bytes of one file and returns them
+    /// with the full file length in bytes.
+    ///
+    /// # Errors
+    /// Returns an error when the file cannot be opened or read.
+    fn read_file_prefix(
+        &self,
+        path: PathBuf,
+        max_len: usize,
+    ) -> FsFuture<Result<(Vec<u8>, u64), FsError>>;
+
     /// Writes one byte buffer to `path`, replacing any existing file.
     ///
     /// # Errors
@@ -90,6 +103,23 @@ impl FsClient for RealFsClient {
         Box::pin(async move { tokio::fs::read(path).await.map_err(FsError::from) })
     }
 
+    fn read_file_prefix(
+        &self,
+        path: PathBuf,
+        max_len: usize,
+    ) -> FsFuture<Result<(Vec<u8>, u64), FsError>> {
+        Box::pin(async move {
+            let file = tokio::fs::File::open(path).await?;
+            let file_len = file.metadata().await?.len();
+            let mut prefix = Vec::new();
+            file.take(u64::try_from(max_len).unwrap_or(u64::MAX))
+                .read_to_end(&mut prefix)
+                .await?;
+
+            Ok((prefix, file_len))
+        })
+    }
+
     fn write_file(&self, path: PathBuf, contents: Vec<u8>) -> FsFuture<Result<(), FsError>> {
         Box::pin(async move {
             tokio::fs::write(path, contents)
@@ -153,6 +183,29 @@ mod tests {
         assert_eq!(content, b"hello world");
     }
 
+    /// Verifies `RealFsClient::read_file_prefix()` stops at the byte cap and
+    /// still reports the full file length.
+    #[tokio::test]
+    async fn test_real_fs_client_read_file_prefix_stops_at_cap() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        let file_path = temp_dir.path().join("example.txt");
+        tokio::fs::write(&file_path, b"hello world")
+            .await
+            .expect("write file");
+        let fs_client = RealFsClient;
+
+        // Act
+        let (prefix, file_len) = fs_client
+            .read_file_prefix(file_path, 5)
+            .await
+            .expect("read file prefix");
+
+        // Assert
+        assert_eq!(prefix, b"hello");
+        assert_eq!(file_len, 11);
+    }
+
     /// Verifies `RealFsClient::read_file()` surfaces read failures through the
     /// async boundary.
     #[tokio::test]
@@ -0,0 +1,40 @@
+//! Shared loader for named markdown files kept in user and project
+//! directories.
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// Loads named items from markdown files found in `directories`.
+///
+/// Directories are read in order and `locate` maps each directory entry to
+/// an item file name and path. Items parsed from later directories replace
+/// earlier items with the same name. Missing directories contribute nothing,
+/// and files that cannot be read or parsed are skipped so one broken file
+/// never hides the others. Results are sorted by name.
+pub(crate) fn load_named_markdown_files<'a, S: Copy, T>(
+    directories: impl IntoIterator<Item = (&'a Path, S)>,
+    locate: impl Fn(&Path) -> Option<(String, PathBuf)>,
+    parse: impl Fn(&str, &str, PathBuf, S) -> Option<T>,
+    item_name: impl Fn(&T) -> &str,
+) -> Vec<T> {
+    let mut items_by_name = BTreeMap::new();
+    for (directory, scope) in directories {
+        let Ok(entries) = std::fs::read_dir(directory) else {
+            continue;
+        };
+
+        for entry_path in entries.filter_map(Result::ok).map(|entry| entry.path()) {
+            let Some((name, path)) = locate(&entry_path) else {
+                continue;
+            };
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(item) = parse(&name, &content, path, scope) {
+                items_by_name.insert(item_name(&item).to_string(), item);
+            }
+        }
+    }
+
+    items_by_name.into_values().collect()
+}
@@ -0,0 +1,105 @@
+use std::future::Future;
+use std::io::{self, Write};
+use std::pin::Pin;
+use std::process::Stdio;
+
+/// Boxed async result returned by [`NotificationClient`] methods.
+pub type NotificationFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+/// Environment variable carrying the notification title for hook commands.
+pub const NOTIFICATION_TITLE_ENV: &str = "AGENTTY_NOTIFICATION_TITLE";
+
+/// Environment variable carrying the notification body for hook commands.
+pub const NOTIFICATION_BODY_ENV: &str = "AGENTTY_NOTIFICATION_BODY";
+
+/// Desktop and terminal notification boundary used by app orchestration.
+#[cfg_attr(test, mockall::automock)]
+pub trait NotificationClient: Send + Sync {
+    /// Writes one terminal escape sequence, such as an OSC notification or a
+    /// bell, to the controlling terminal.
+    fn write_terminal_sequence(&self, sequence: String);
+
+    /// Runs one user-configured hook `command` through `sh -c`, exposing the
+    /// notification through [`NOTIFICATION_TITLE_ENV`] and
+    /// [`NOTIFICATION_BODY_ENV`].
+    fn run_command(&self, command: String, title: String, body: String) -> NotificationFuture<()>;
+}
+
+/// Production [`NotificationClient`] writing to stdout and spawning `sh`.
+pub struct RealNotificationClient;
+
+impl RealNotificationClient {
+    /// Builds the shell invocation for one notification hook command.
+    fn hook_command(command: &str, title: &str, body: &str) -> tokio::process::Command {
+        let mut hook_command = tokio::process::Command::new("sh");
+        hook_command
+            .arg("-c")
+            .arg(command)
+            .env(NOTIFICATION_TITLE_ENV, title)
+            .env(NOTIFICATION_BODY_ENV, body)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null());
+
+        hook_command
+    }
+}
+
+impl NotificationClient for RealNotificationClient {
+    fn write_terminal_sequence(&self, sequence: String) {
+        let mut stdout = io::stdout().lock();
+
+        // Best-effort: the terminal may be detached or closed.
+        let _ = stdout.write_all(sequence.as_bytes());
+        let _ = stdout.flush();
+    }
+
+    fn run_command(&self, command: String, title: String, body: String) -> NotificationFuture<()> {
+        Box::pin(async move {
+            let mut hook_command = Self::hook_command(&command, &title, &body);
+
+            // Best-effort: a failing hook must not interrupt session work.
+            let _ = hook_command.status().await;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hook_command_runs_shell_with_notification_environment() {
+        // Arrange
+        let command = "notify-send \"$AGENTTY_NOTIFICATION_TITLE\"";
+
+        // Act
+        let hook_command = RealNotificationClient::hook_command(command, "Review", "Fix login");
+        let std_command = hook_command.as_std();
+        let arguments = std_command
+            .get_args()
+            .map(|argument| argument.to_string_lossy().into_owned())
+            .collect::<Vec<_>>();
+        let environment = std_command
+            .get_envs()
+            .map(|(key, value)| {
+                (
+                    key.to_string_lossy().into_owned(),
+                    value.map(|value| value.to_string_lossy().into_owned()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(std_command.get_program(), "sh");
+        assert_eq!(arguments, vec!["-c".to_string(), command.to_string()]);
+        assert!(environment.contains(&(
+            NOTIFICATION_TITLE_ENV.to_string(),
+            Some("Review".to_string())
+        )));
+        assert!(environment.contains(&(
+            NOTIFICATION_BODY_ENV.to_string(),
+            Some("Fix login".to_string())
+        )));
+    }
+}
@@ -0,0 +1,99 @@
+//! Filesystem loader for user- and project-level prompt templates.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::prompt_template::{PromptTemplate, PromptTemplateScope};
+use crate::infra::markdown_dir;
+
+/// Directory name that holds prompt template files; it lives under agentty
+/// home or a project's `.agentty/` folder.
+pub const PROMPTS_DIR: &str = "prompts";
+
+/// File extension recognized as a prompt template.
+const TEMPLATE_EXTENSION: &str = "md";
+
+/// Loads prompt templates from `user_dir` and then `project_dir`.
+///
+/// Each `*.md` file becomes one [`PromptTemplate`] named after its file stem,
+/// so a project template overrides a user template with the same name.
+pub fn load_prompt_templates(user_dir: &Path, project_dir: &Path) -> Vec<PromptTemplate> {
+    markdown_dir::load_named_markdown_files(
+        [
+            (user_dir, PromptTemplateScope::User),
+            (project_dir, PromptTemplateScope::Project),
+        ],
+        template_file,
+        |name, content, _path, scope| PromptTemplate::parse(name, content, scope).ok(),
+        |template: &PromptTemplate| template.name.as_str(),
+    )
+}
+
+/// Returns the template name and path when `path` is a template file.
+fn template_file(path: &Path) -> Option<(String, PathBuf)> {
+    let is_template = path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension == TEMPLATE_EXTENSION);
+    if !is_template {
+        return None;
+    }
+    let name = path.file_stem()?.to_str()?.to_string();
+
+    Some((name, path.to_path_buf()))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_load_prompt_templates_prefers_project_templates_and_sorts_by_name() {
+        // Arrange
+        let user_dir = tempdir().expect("failed to create user dir");
+        let project_dir = tempdir().expect("failed to create project dir");
+        std::fs::write(user_dir.path().join("review.md"), "User review").expect("write failed");
+        std::fs::write(user_dir.path().join("changelog.md"), "Add entry").expect("write failed");
+        std::fs::write(user_dir.path().join("notes.txt"), "Ignored").expect("write failed");
+        std::fs::write(project_dir.path().join("review.md"), "Project review")
+            .expect("write failed");
+        std::fs::write(project_dir.path().join("broken.md"), "+++\nmodel = 1\n")
+            .expect("write failed");
+
+        // Act
+        let templates = load_prompt_templates(user_dir.path(), project_dir.path());
+
+        // Assert
+        let summary = templates
+            .iter()
+            .map(|template| {
+                (
+                    template.name.as_str(),
+                    template.body.as_str(),
+                    template.scope,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("changelog", "Add entry", PromptTemplateScope::User),
+                ("review", "Project review", PromptTemplateScope::Project),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_load_prompt_templates_returns_empty_for_missing_directories() {
+        // Arrange
+        let root = tempdir().expect("failed to create root dir");
+
+        // Act
+        let templates =
+            load_prompt_templates(&root.path().join("user"), &root.path().join("project"));
+
+        // Assert
+        assert!(templates.is_empty());
+    }
+}
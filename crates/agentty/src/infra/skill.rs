@@ -0,0 +1,107 @@
+//! Filesystem loader for user- and project-level `SKILL.md` skills.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::skill::{Skill, SkillScope};
+use crate::infra::markdown_dir;
+
+/// Directory name that holds one sub-directory per skill; it lives under
+/// agentty home, a project root, or a project's `.agentty/` folder.
+pub const SKILLS_DIR: &str = "skills";
+
+/// File name that defines one skill inside its directory.
+const SKILL_FILE_NAME: &str = "SKILL.md";
+
+/// Loads skills from `user_dir` followed by each of `project_dirs`.
+///
+/// Each `<directory>/<skill>/SKILL.md` file becomes one [`Skill`] keyed by
+/// its parsed name, so a project skill overrides a user skill and later
+/// project directories override earlier ones.
+pub fn load_skills(user_dir: &Path, project_dirs: &[&Path]) -> Vec<Skill> {
+    let directories = std::iter::once((user_dir, SkillScope::User)).chain(
+        project_dirs
+            .iter()
+            .map(|project_dir| (*project_dir, SkillScope::Project)),
+    );
+
+    markdown_dir::load_named_markdown_files(
+        directories,
+        skill_file,
+        |directory_name, content, path, scope| {
+            Skill::parse(directory_name, content, path, scope).ok()
+        },
+        |skill: &Skill| skill.name.as_str(),
+    )
+}
+
+/// Returns the skill directory name and its `SKILL.md` path.
+fn skill_file(skill_dir: &Path) -> Option<(String, PathBuf)> {
+    let directory_name = skill_dir.file_name()?.to_str()?.to_string();
+
+    Some((directory_name, skill_dir.join(SKILL_FILE_NAME)))
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    /// Writes one `SKILL.md` file under `directory/skill_dir`.
+    fn write_skill(directory: &Path, skill_dir: &str, content: &str) {
+        std::fs::create_dir_all(directory.join(skill_dir)).expect("create dir failed");
+        std::fs::write(directory.join(skill_dir).join(SKILL_FILE_NAME), content)
+            .expect("write failed");
+    }
+
+    #[test]
+    fn test_load_skills_prefers_later_project_directories_and_sorts_by_name() {
+        // Arrange
+        let user_dir = tempdir().expect("failed to create user dir");
+        let repo_dir = tempdir().expect("failed to create repo dir");
+        let agentty_dir = tempdir().expect("failed to create agentty dir");
+        write_skill(user_dir.path(), "review", "User review");
+        write_skill(user_dir.path(), "tech-debt", "Find debt");
+        write_skill(repo_dir.path(), "review", "Repo review");
+        write_skill(repo_dir.path(), "release", "---\nname: ship\n---\nShip it");
+        write_skill(agentty_dir.path(), "review", "Agentty review");
+        write_skill(agentty_dir.path(), "broken", "---\nname: broken\n");
+        std::fs::write(repo_dir.path().join("notes.md"), "Ignored").expect("write failed");
+
+        // Act
+        let skills = load_skills(user_dir.path(), &[repo_dir.path(), agentty_dir.path()]);
+
+        // Assert
+        let summary = skills
+            .iter()
+            .map(|skill| (skill.name.as_str(), skill.body.as_str(), skill.scope))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            summary,
+            vec![
+                ("review", "Agentty review", SkillScope::Project),
+                ("ship", "Ship it", SkillScope::Project),
+                ("tech-debt", "Find debt", SkillScope::User),
+            ]
+        );
+        assert_eq!(
+            skills[1].path,
+            repo_dir.path().join("release").join(SKILL_FILE_NAME)
+        );
+    }
+
+    #[test]
+    fn test_load_skills_returns_empty_for_missing_directories() {
+        // Arrange
+        let root = tempdir().expect("failed to create root dir");
+
+        // Act
+        let skills = load_skills(
+            &root.path().join("user"),
+            &[root.path().join("project").as_path()],
+        );
+
+        // Assert
+        assert!(skills.is_empty());
+    }
+}
@@ -0,0 +1,979 @@
+//! Ctags-style symbol index and fuzzy filtering used by `@#` symbol mentions.
+//!
+//! The index scans supported source files line by line and records function,
+//! type, and module declarations with their one-based line numbers. It is a
+//! heuristic scanner rather than a parser, so it favors speed and predictable
+//! results over completeness.
+
+use std::path::{Path, PathBuf};
+
+use ignore::WalkBuilder;
+
+use crate::infra::file_index;
+
+/// Character that switches an `@` mention query from paths to symbols.
+pub const SYMBOL_QUERY_PREFIX: char = '#';
+
+const MAX_DEPTH: usize = 10;
+/// Largest source file, in bytes, that is scanned for symbols.
+const MAX_FILE_BYTES: u64 = 512 * 1024;
+/// Upper bound on scanned source files so huge worktrees stay responsive.
+const MAX_INDEXED_FILES: usize = 5_000;
+/// Upper bound on lines inserted for one symbol snippet.
+const MAX_SNIPPET_LINES: usize = 60;
+/// Rust keywords that can prefix a declaration without changing its kind.
+const RUST_DECLARATION_MODIFIERS: [&str; 5] = ["async", "const", "default", "unsafe", "extern"];
+/// Keywords that can prefix one JavaScript or TypeScript declaration.
+const SCRIPT_DECLARATION_MODIFIERS: [&str; 10] = [
+    "export",
+    "default",
+    "declare",
+    "abstract",
+    "async",
+    "public",
+    "private",
+    "protected",
+    "static",
+    "readonly",
+];
+/// Control-flow keywords that look like method calls in class bodies.
+const SCRIPT_NON_METHOD_KEYWORDS: [&str; 7] = [
+    "if", "for", "while", "switch", "catch", "return", "function",
+];
+
+/// Kind of declaration recorded by the symbol index.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SymbolKind {
+    /// Free function, method, or arrow-function binding.
+    Function,
+    /// Module or namespace declaration.
+    Module,
+    /// Struct, enum, trait, class, interface, or type alias.
+    Type,
+}
+
+impl SymbolKind {
+    /// Returns the short label shown next to symbols in the dropdown.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Function => "fn",
+            Self::Module => "mod",
+            Self::Type => "type",
+        }
+    }
+}
+
+/// A single declaration entry for the `@#` symbol mention dropdown.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SymbolEntry {
+    /// Declaration kind.
+    pub kind: SymbolKind,
+    /// One-based line number of the declaration.
+    pub line: usize,
+    /// Qualified symbol name (e.g., `SessionManager::merge_session`).
+    pub name: String,
+    /// Relative path from the index root (e.g., `src/app/session.rs`).
+    pub path: String,
+}
+
+impl SymbolEntry {
+    /// Returns the `path:line` anchor inserted for this symbol.
+    pub fn anchor(&self) -> String {
+        format!("{}:{}", self.path, self.line)
+    }
+}
+
+/// Symbols collected from one worktree root.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SymbolIndex {
+    /// Directory the symbol paths are relative to.
+    pub root: PathBuf,
+    /// Indexed symbols sorted by path and line.
+    pub symbols: Vec<SymbolEntry>,
+}
+
+impl SymbolIndex {
+    /// Reads the declaration source for `symbol` from disk.
+    ///
+    /// Brace-delimited languages stop at the brace that closes the
+    /// declaration; Python stops at the first line that dedents back to the
+    /// declaration level. Snippets are capped at a fixed number of lines and
+    /// `None` is returned when the file can no longer be read.
+    pub fn snippet(&self, symbol: &SymbolEntry) -> Option<String> {
+        let content = std::fs::read_to_string(self.root.join(&symbol.path)).ok()?;
+        let language = Language::from_path(Path::new(&symbol.path))?;
+        let lines: Vec<&str> = content.lines().skip(symbol.line.checked_sub(1)?).collect();
+        if lines.is_empty() {
+            return None;
+        }
+
+        let line_count = match language {
+            Language::Python => indented_block_line_count(&lines),
+            Language::Go | Language::Rust | Language::Script => braced_block_line_count(&lines),
+        };
+
+        Some(lines[..line_count.min(MAX_SNIPPET_LINES)].join("\n"))
+    }
+}
+
+/// Returns the symbol query when one `@` mention query starts with
+/// [`SYMBOL_QUERY_PREFIX`] (e.g., `#merge_session` in `@#merge_session`).
+pub fn symbol_query(query: &str) -> Option<&str> {
+    query.strip_prefix(SYMBOL_QUERY_PREFIX)
+}
+
+/// Indexes declarations in supported source files under `root`, respecting
+/// `.gitignore`.
+///
+/// Files larger than a fixed size are skipped and scanning stops after a fixed
+/// number of files. Results are sorted by path and line.
+pub fn build_symbol_index(root: &Path) -> SymbolIndex {
+    let walker = WalkBuilder::new(root)
+        .max_depth(Some(MAX_DEPTH))
+        .hidden(false)
+        .build();
+
+    let mut symbols: Vec<SymbolEntry> = walker
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.file_type().is_some_and(|ft| ft.is_file())
+                && entry
+                    .metadata()
+                    .is_ok_and(|metadata| metadata.len() <= MAX_FILE_BYTES)
+        })
+        .filter_map(|entry| {
+            let language = Language::from_path(entry.path())?;
+            let relative = entry.path().strip_prefix(root).ok()?;
+
+            Some((
+                entry.path().to_path_buf(),
+                relative.to_string_lossy().to_string(),
+                language,
+            ))
+        })
+        .take(MAX_INDEXED_FILES)
+        .flat_map(|(absolute_path, relative_path, language)| {
+            std::fs::read_to_string(absolute_path)
+                .map(|content| extract_symbols(&relative_path, &content, language))
+                .unwrap_or_default()
+        })
+        .collect();
+
+    symbols.sort_by(|first, second| {
+        first
+            .path
+            .cmp(&second.path)
+            .then(first.line.cmp(&second.line))
+    });
+
+    SymbolIndex {
+        root: root.to_path_buf(),
+        symbols,
+    }
+}
+
+/// Fuzzy-filters symbols and returns them sorted by best match.
+///
+/// Query characters must appear in order (case-insensitive) within the
+/// qualified symbol name. Matches whose last name segment equals or starts
+/// with the query rank first; equal scores prefer shorter names, then path
+/// and line order.
+pub fn filter_symbols<'a>(symbols: &'a [SymbolEntry], query: &str) -> Vec<&'a SymbolEntry> {
+    if query.is_empty() {
+        return symbols.iter().collect();
+    }
+
+    let query_lower = query.to_lowercase();
+    let query_chars: Vec<char> = query_lower.chars().collect();
+    let mut scored: Vec<(i32, &SymbolEntry)> = symbols
+        .iter()
+        .filter_map(|symbol| {
+            file_index::fuzzy_score(&symbol.name, &query_chars, &query_lower, false).map(|score| {
+                (
+                    score + name_segment_bonus(&symbol.name, &query_lower),
+                    symbol,
+                )
+            })
+        })
+        .collect();
+
+    scored.sort_by(|(first_score, first), (second_score, second)| {
+        second_score
+            .cmp(first_score)
+            .then_with(|| first.name.len().cmp(&second.name.len()))
+            .then_with(|| first.path.cmp(&second.path))
+            .then(first.line.cmp(&second.line))
+    });
+
+    scored.into_iter().map(|(_, symbol)| symbol).collect()
+}
+
+/// Returns an extra score when the query matches the last name segment.
+///
+/// This keeps `@#merge_session` pointed at `SessionManager::merge_session`
+/// ahead of longer names that only contain the query somewhere in the middle.
+fn name_segment_bonus(name: &str, query: &str) -> i32 {
+    let last_segment = name
+        .rsplit(['.', ':'])
+        .next()
+        .unwrap_or(name)
+        .to_lowercase();
+
+    if last_segment == query {
+        return 100;
+    }
+
+    if last_segment.starts_with(query) {
+        return 40;
+    }
+
+    0
+}
+
+/// Source languages understood by the line scanner.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Language {
+    /// Go sources.
+    Go,
+    /// Python sources.
+    Python,
+    /// Rust sources.
+    Rust,
+    /// JavaScript and TypeScript, including JSX variants.
+    Script,
+}
+
+impl Language {
+    /// Resolves the scanner language from one file extension.
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "go" => Some(Self::Go),
+            "py" => Some(Self::Python),
+            "rs" => Some(Self::Rust),
+            "cjs" | "js" | "jsx" | "mjs" | "ts" | "tsx" => Some(Self::Script),
+            _ => None,
+        }
+    }
+
+    /// Returns the separator placed between a container and member name.
+    fn member_separator(self) -> &'static str {
+        match self {
+            Self::Rust => "::",
+            Self::Go | Self::Python | Self::Script => ".",
+        }
+    }
+}
+
+/// One open container scope (`impl`, `trait`, `mod`, or `class` block).
+struct Scope {
+    /// Leading whitespace width of the line that opened the scope.
+    indent: usize,
+    /// Container name prepended to nested declarations.
+    name: String,
+}
+
+/// One declaration recognized on a single source line.
+struct Declaration {
+    /// Declaration kind.
+    kind: SymbolKind,
+    /// Unqualified declaration name, empty for scope-only lines like `impl`.
+    name: String,
+    /// Container name when the declaration opens a scope for nested members.
+    opens_scope: Option<String>,
+}
+
+/// Extracts declarations from one source file.
+///
+/// Container scopes are tracked by indentation: a scope closes on the first
+/// non-blank line indented at or before the line that opened it, which
+/// matches both brace-closing lines and Python dedents in formatted code.
+fn extract_symbols(path: &str, content: &str, language: Language) -> Vec<SymbolEntry> {
+    let mut scopes: Vec<Scope> = Vec::new();
+    let mut symbols = Vec::new();
+
+    for (line_index, line) in content.lines().enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() || is_comment_line(trimmed, language) {
+            continue;
+        }
+
+        let indent = line.len() - trimmed.len();
+        while scopes.last().is_some_and(|scope| scope.indent >= indent) {
+            scopes.pop();
+        }
+
+        let Some(declaration) = parse_declaration(trimmed, language, !scopes.is_empty()) else {
+            continue;
+        };
+
+        let container = scopes
+            .iter()
+            .map(|scope| scope.name.as_str())
+            .collect::<Vec<_>>()
+            .join(language.member_separator());
+
+        if !declaration.name.is_empty() {
+            let name = if container.is_empty() {
+                declaration.name
+            } else {
+                format!(
+                    "{container}{}{}",
+                    language.member_separator(),
+                    declaration.name
+                )
+            };
+
+            symbols.push(SymbolEntry {
+                kind: declaration.kind,
+                line: line_index + 1,
+                name,
+                path: path.to_string(),
+            });
+        }
+
+        if let Some(scope_name) = declaration.opens_scope {
+            scopes.push(Scope {
+                indent,
+                name: scope_name,
+            });
+        }
+    }
+
+    symbols
+}
+
+/// Returns whether one trimmed line is a line comment for `language`.
+fn is_comment_line(trimmed: &str, language: Language) -> bool {
+    match language {
+        Language::Python => trimmed.starts_with('#'),
+        Language::Go | Language::Rust | Language::Script => {
+            trimmed.starts_with("//") || trimmed.starts_with("/*") || trimmed.starts_with('*')
+        }
+    }
+}
+
+/// Parses one trimmed line into a declaration for `language`.
+fn parse_declaration(trimmed: &str, language: Language, in_scope: bool) -> Option<Declaration> {
+    match language {
+        Language::Go => parse_go_declaration(trimmed),
+        Language::Python => parse_python_declaration(trimmed),
+        Language::Rust => parse_rust_declaration(trimmed),
+        Language::Script => parse_script_declaration(trimmed, in_scope),
+    }
+}
+
+/// Parses Rust `fn`, type, `mod`, and `impl` declarations.
+///
+/// `impl` blocks produce no symbol of their own but open a scope named after
+/// the implemented type so methods are recorded as `Type::method`.
+fn parse_rust_declaration(trimmed: &str) -> Option<Declaration> {
+    let mut rest = strip_rust_visibility(trimmed);
+    loop {
+        let (word, remainder) = split_keyword(rest)?;
+        if !RUST_DECLARATION_MODIFIERS.contains(&word) {
+            break;
+        }
+
+        rest = remainder;
+        if word == "extern"
+            && let Some(abi_and_rest) = rest.strip_prefix('"')
+        {
+            rest = abi_and_rest
+                .split_once('"')
+                .map_or(rest, |(_, after)| after.trim_start());
+        }
+    }
+
+    let opens_block = trimmed.trim_end().ends_with('{');
+    if let Some(impl_header) = rest.strip_prefix("impl")
+        && impl_header.starts_with(|ch: char| ch == '<' || ch.is_whitespace())
+    {
+        return opens_block.then(|| Declaration {
+            kind: SymbolKind::Type,
+            name: String::new(),
+            opens_scope: rust_impl_self_type(impl_header),
+        });
+    }
+
+    let (keyword, remainder) = split_keyword(rest)?;
+    match keyword {
+        "fn" => Some(Declaration {
+            kind: SymbolKind::Function,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        "enum" | "struct" | "type" | "union" => Some(Declaration {
+            kind: SymbolKind::Type,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        "trait" | "mod" => {
+            let name = leading_identifier(remainder)?;
+            let kind = if keyword == "mod" {
+                SymbolKind::Module
+            } else {
+                SymbolKind::Type
+            };
+
+            Some(Declaration {
+                kind,
+                opens_scope: opens_block.then(|| name.clone()),
+                name,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Removes a leading `pub`, `pub(crate)`, or other restricted visibility.
+fn strip_rust_visibility(trimmed: &str) -> &str {
+    let Some(rest) = trimmed.strip_prefix("pub") else {
+        return trimmed;
+    };
+
+    if let Some(restricted) = rest.strip_prefix('(') {
+        return restricted
+            .split_once(')')
+            .map_or(trimmed, |(_, after)| after.trim_start());
+    }
+
+    if rest.starts_with(char::is_whitespace) {
+        return rest.trim_start();
+    }
+
+    trimmed
+}
+
+/// Returns the self type name from the text following `impl`.
+///
+/// Handles `impl<T> Type<T>`, `impl Trait for Type`, and path-qualified
+/// types by keeping the last path segment without generic arguments.
+fn rust_impl_self_type(remainder: &str) -> Option<String> {
+    let without_generics = skip_generic_parameters(remainder.trim_start());
+    let header = without_generics
+        .split(" where ")
+        .next()
+        .unwrap_or(without_generics)
+        .trim_end_matches('{')
+        .trim();
+    let self_type = header.rsplit(" for ").next().unwrap_or(header).trim();
+    let self_type = self_type.split('<').next().unwrap_or(self_type);
+    let last_segment = self_type.rsplit("::").next().unwrap_or(self_type);
+
+    leading_identifier(last_segment.trim_start_matches(['&', '(']))
+}
+
+/// Skips one leading `<...>` generic parameter list, if present.
+fn skip_generic_parameters(text: &str) -> &str {
+    if !text.starts_with('<') {
+        return text;
+    }
+
+    let mut depth = 0_usize;
+    for (index, ch) in text.char_indices() {
+        match ch {
+            '<' => depth += 1,
+            '>' => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return text[index + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    text
+}
+
+/// Parses Python `def`, `async def`, and `class` declarations.
+fn parse_python_declaration(trimmed: &str) -> Option<Declaration> {
+    let rest = trimmed.strip_prefix("async ").unwrap_or(trimmed);
+    let (keyword, remainder) = split_keyword(rest)?;
+    match keyword {
+        "def" => Some(Declaration {
+            kind: SymbolKind::Function,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        "class" => {
+            let name = leading_identifier(remainder)?;
+
+            Some(Declaration {
+                kind: SymbolKind::Type,
+                opens_scope: Some(name.clone()),
+                name,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Parses Go `func`, method, and `type` declarations.
+///
+/// Methods are recorded as `Receiver.Method` using the receiver type name.
+fn parse_go_declaration(trimmed: &str) -> Option<Declaration> {
+    let (keyword, remainder) = split_keyword(trimmed)?;
+    match keyword {
+        "func" => {
+            let Some(receiver_and_rest) = remainder.strip_prefix('(') else {
+                return Some(Declaration {
+                    kind: SymbolKind::Function,
+                    name: leading_identifier(remainder)?,
+                    opens_scope: None,
+                });
+            };
+
+            let (receiver, rest) = receiver_and_rest.split_once(')')?;
+            let receiver_type = receiver
+                .split_whitespace()
+                .last()?
+                .trim_start_matches('*')
+                .split('[')
+                .next()?;
+            let method = leading_identifier(rest.trim_start())?;
+
+            Some(Declaration {
+                kind: SymbolKind::Function,
+                name: format!("{receiver_type}.{method}"),
+                opens_scope: None,
+            })
+        }
+        "type" => Some(Declaration {
+            kind: SymbolKind::Type,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        _ => None,
+    }
+}
+
+/// Parses JavaScript and TypeScript functions, classes, type declarations,
+/// arrow-function bindings, and class methods.
+fn parse_script_declaration(trimmed: &str, in_scope: bool) -> Option<Declaration> {
+    let mut rest = trimmed;
+    while let Some((word, remainder)) = split_keyword(rest) {
+        if !SCRIPT_DECLARATION_MODIFIERS.contains(&word) {
+            break;
+        }
+
+        rest = remainder;
+    }
+
+    let (keyword, remainder) = split_keyword(rest).unwrap_or((rest, ""));
+    match keyword {
+        "function" | "function*" => Some(Declaration {
+            kind: SymbolKind::Function,
+            name: leading_identifier(remainder.trim_start_matches('*').trim_start())?,
+            opens_scope: None,
+        }),
+        "class" => {
+            let name = leading_identifier(remainder)?;
+
+            Some(Declaration {
+                kind: SymbolKind::Type,
+                opens_scope: trimmed.trim_end().ends_with('{').then(|| name.clone()),
+                name,
+            })
+        }
+        "enum" | "interface" | "type" => Some(Declaration {
+            kind: SymbolKind::Type,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        "namespace" => Some(Declaration {
+            kind: SymbolKind::Module,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        "const" | "let" if remainder.contains("=>") => Some(Declaration {
+            kind: SymbolKind::Function,
+            name: leading_identifier(remainder)?,
+            opens_scope: None,
+        }),
+        _ if in_scope => parse_script_method(rest),
+        _ => None,
+    }
+}
+
+/// Parses one class-body line such as `render(props) {` into a method.
+fn parse_script_method(rest: &str) -> Option<Declaration> {
+    let rest = ["get ", "set "]
+        .iter()
+        .find_map(|prefix| rest.strip_prefix(prefix))
+        .unwrap_or(rest);
+    let name = leading_identifier(rest)?;
+    let after_name = rest[name.len()..].trim_start();
+    if SCRIPT_NON_METHOD_KEYWORDS.contains(&name.as_str())
+        || !(after_name.starts_with('(') || after_name.starts_with('<'))
+        || !rest.trim_end().ends_with('{')
+    {
+        return None;
+    }
+
+    Some(Declaration {
+        kind: SymbolKind::Function,
+        name,
+        opens_scope: None,
+    })
+}
+
+/// Splits one leading whitespace-delimited word from `text`.
+fn split_keyword(text: &str) -> Option<(&str, &str)> {
+    let (word, remainder) = text.split_once(char::is_whitespace)?;
+
+    Some((word, remainder.trim_start()))
+}
+
+/// Returns the identifier at the start of `text`, if any.
+fn leading_identifier(text: &str) -> Option<String> {
+    let identifier: String = text
+        .chars()
+        .take_while(|ch| ch.is_alphanumeric() || matches!(ch, '_' | '$'))
+        .collect();
+    if identifier.is_empty() || identifier.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return None;
+    }
+
+    Some(identifier)
+}
+
+/// Returns how many lines belong to the brace-delimited declaration starting
+/// at `lines[0]`.
+///
+/// Declarations without a body (for example `struct Marker;`) end on the
+/// first line terminated by `;`.
+fn braced_block_line_count(lines: &[&str]) -> usize {
+    let mut depth = 0_usize;
+    let mut opened = false;
+    for (index, line) in lines.iter().enumerate() {
+        for ch in line.chars() {
+            match ch {
+                '{' => {
+                    depth += 1;
+                    opened = true;
+                }
+                '}' => depth = depth.saturating_sub(1),
+                _ => {}
+            }
+        }
+
+        if (opened && depth == 0) || (!opened && line.trim_end().ends_with(';')) {
+            return index + 1;
+        }
+    }
+
+    lines.len()
+}
+
+/// Returns how many lines belong to the indentation-delimited declaration
+/// starting at `lines[0]`, excluding trailing blank lines.
+fn indented_block_line_count(lines: &[&str]) -> usize {
+    let Some(first_line) = lines.first() else {
+        return 0;
+    };
+    let declaration_indent = first_line.len() - first_line.trim_start().len();
+    let mut last_body_index = 0;
+    for (index, line) in lines.iter().enumerate().skip(1) {
+        let trimmed = line.trim_start();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        if line.len() - trimmed.len() <= declaration_indent {
+            break;
+        }
+
+        last_body_index = index;
+    }
+
+    last_body_index + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use tempfile::TempDir;
+
+    use super::*;
+
+    /// Returns the qualified names extracted from `content`.
+    fn symbol_names(content: &str, language: Language) -> Vec<String> {
+        extract_symbols("file", content, language)
+            .into_iter()
+            .map(|symbol| symbol.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_extract_symbols_qualifies_rust_impl_methods() {
+        // Arrange
+        let content = "\
+pub struct SessionManager;
+
+impl<T: Clone> Default for Wrapper<T> {
+    fn default() -> Self {
+        todo()
+    }
+}
+
+impl SessionManager {
+    pub(crate) async fn merge_session(&self) {}
+}
+
+fn free_function() {}
+";
+
+        // Act
+        let symbols = extract_symbols("src/session.rs", content, Language::Rust);
+
+        // Assert
+        let names: Vec<(&str, usize)> = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.line))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("SessionManager", 1),
+                ("Wrapper::default", 4),
+                ("SessionManager::merge_session", 10),
+                ("free_function", 13),
+            ]
+        );
+        assert_eq!(symbols[2].anchor(), "src/session.rs:10");
+    }
+
+    #[test]
+    fn test_extract_symbols_records_rust_modules_and_traits() {
+        // Arrange
+        let content = "\
+mod tests {
+    fn helper() {}
+}
+
+pub trait Render {
+    fn render(&self);
+}
+";
+
+        // Act
+        let symbols = extract_symbols("lib.rs", content, Language::Rust);
+
+        // Assert
+        let kinds: Vec<(&str, SymbolKind)> = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("tests", SymbolKind::Module),
+                ("tests::helper", SymbolKind::Function),
+                ("Render", SymbolKind::Type),
+                ("Render::render", SymbolKind::Function),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_extract_symbols_skips_rust_comments() {
+        // Arrange
+        let content = "// fn commented() {}\n/// fn documented() {}\nfn real() {}\n";
+
+        // Act
+        let names = symbol_names(content, Language::Rust);
+
+        // Assert
+        assert_eq!(names, vec!["real"]);
+    }
+
+    #[test]
+    fn test_extract_symbols_qualifies_python_methods() {
+        // Arrange
+        let content = "\
+class Worker:
+    def run(self):
+        pass
+
+    async def stop(self):
+        pass
+
+def main():
+    pass
+";
+
+        // Act
+        let names = symbol_names(content, Language::Python);
+
+        // Assert
+        assert_eq!(names, vec!["Worker", "Worker.run", "Worker.stop", "main"]);
+    }
+
+    #[test]
+    fn test_extract_symbols_records_go_receiver_methods() {
+        // Arrange
+        let content = "\
+type Server struct {
+}
+
+func (s *Server) Start() error {
+}
+
+func main() {
+}
+";
+
+        // Act
+        let names = symbol_names(content, Language::Go);
+
+        // Assert
+        assert_eq!(names, vec!["Server", "Server.Start", "main"]);
+    }
+
+    #[test]
+    fn test_extract_symbols_records_script_classes_and_functions() {
+        // Arrange
+        let content = "\
+export default class Panel {
+  render(props) {
+    if (props) {
+    }
+  }
+}
+
+export async function loadPanel() {}
+export const formatLabel = (value) => value;
+interface PanelProps {}
+";
+
+        // Act
+        let names = symbol_names(content, Language::Script);
+
+        // Assert
+        assert_eq!(
+            names,
+            vec![
+                "Panel",
+                "Panel.render",
+                "loadPanel",
+                "formatLabel",
+                "PanelProps"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_filter_symbols_prefers_exact_last_segment() {
+        // Arrange
+        let symbols = vec![
+            SymbolEntry {
+                kind: SymbolKind::Function,
+                line: 3,
+                name: "merge_session_branches".to_string(),
+                path: "src/git.rs".to_string(),
+            },
+            SymbolEntry {
+                kind: SymbolKind::Function,
+                line: 40,
+                name: "SessionManager::merge_session".to_string(),
+                path: "src/session.rs".to_string(),
+            },
+        ];
+
+        // Act
+        let filtered = filter_symbols(&symbols, "merge_session");
+
+        // Assert
+        assert_eq!(filtered.len(), 2);
+        assert_eq!(filtered[0].name, "SessionManager::merge_session");
+    }
+
+    #[test]
+    fn test_filter_symbols_matches_qualified_query() {
+        // Arrange
+        let symbols = vec![
+            SymbolEntry {
+                kind: SymbolKind::Function,
+                line: 1,
+                name: "App::render".to_string(),
+                path: "src/app.rs".to_string(),
+            },
+            SymbolEntry {
+                kind: SymbolKind::Function,
+                line: 1,
+                name: "Page::render".to_string(),
+                path: "src/page.rs".to_string(),
+            },
+        ];
+
+        // Act
+        let filtered = filter_symbols(&symbols, "page::ren");
+
+        // Assert
+        assert_eq!(filtered.len(), 1);
+        assert_eq!(filtered[0].path, "src/page.rs");
+    }
+
+    #[test]
+    fn test_build_symbol_index_returns_relative_sorted_symbols() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("test expectation should hold");
+        fs::create_dir_all(temp_dir.path().join("src")).expect("test expectation should hold");
+        fs::write(
+            temp_dir.path().join("src/lib.rs"),
+            "fn beta() {}\nfn alpha() {}\n",
+        )
+        .expect("test expectation should hold");
+        fs::write(temp_dir.path().join("notes.txt"), "fn ignored() {}\n")
+            .expect("test expectation should hold");
+
+        // Act
+        let index = build_symbol_index(temp_dir.path());
+
+        // Assert
+        let anchors: Vec<String> = index.symbols.iter().map(SymbolEntry::anchor).collect();
+        assert_eq!(anchors, vec!["src/lib.rs:1", "src/lib.rs:2"]);
+        assert_eq!(index.root, temp_dir.path());
+    }
+
+    #[test]
+    fn test_snippet_reads_braced_declaration_body() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("test expectation should hold");
+        fs::write(
+            temp_dir.path().join("lib.rs"),
+            "fn first() {\n    if true {\n    }\n}\n\nfn second() {}\n",
+        )
+        .expect("test expectation should hold");
+        let index = build_symbol_index(temp_dir.path());
+
+        // Act
+        let snippet = index.snippet(&index.symbols[0]);
+
+        // Assert
+        assert_eq!(
+            snippet.as_deref(),
+            Some("fn first() {\n    if true {\n    }\n}")
+        );
+    }
+
+    #[test]
+    fn test_snippet_reads_python_block_until_dedent() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("test expectation should hold");
+        fs::write(
+            temp_dir.path().join("tool.py"),
+            "def run():\n    return 1\n\n\nvalue = run()\n",
+        )
+        .expect("test expectation should hold");
+        let index = build_symbol_index(temp_dir.path());
+
+        // Act
+        let snippet = index.snippet(&index.symbols[0]);
+
+        // Assert
+        assert_eq!(snippet.as_deref(), Some("def run():\n    return 1"));
+    }
+}
@@ -17,6 +17,9 @@ pub trait TmuxClient: Send + Sync {
 
     /// Sends `command` followed by Enter to the target tmux `window_id`.
     fn run_command_in_window(&self, window_id: String, command: String) -> TmuxFuture<()>;
+
+    /// Shows `message` in the tmux status line of the current client.
+    fn display_message(&self, message: String) -> TmuxFuture<()>;
 }
 
 /// Captured tmux subprocess result used by injected command runners.
@@ -116,6 +119,14 @@ impl ProcessTmuxCommandRunner {
         tmux_command
     }
 
+    /// Builds the `tmux display-message` command for one status message.
+    fn display_message_command(message: String) -> tokio::process::Command {
+        let mut tmux_command = tokio::process::Command::new("tmux");
+        tmux_command.arg("display-message").arg(message);
+
+        tmux_command
+    }
+
     /// Sends Enter to one tmux `window_id`.
     async fn send_enter_key_impl(window_id: String) -> io::Result<TmuxCommandOutput> {
         let mut tmux_command = Self::send_enter_key_command(window_id);
@@ -161,6 +172,14 @@ impl RealTmuxClient {
         Self::run_command_in_window_with_runner(&command_runner, window_id, command).await;
     }
 
+    /// Shows `message` in the tmux status line.
+    async fn display_message_impl(message: String) {
+        let mut tmux_command = ProcessTmuxCommandRunner::display_message_command(message);
+
+        // Best-effort: agentty may be running outside tmux.
+        let _ = tmux_command.output().await;
+    }
+
     /// Opens one tmux window using the provided command runner.
     async fn open_window_for_folder_with_runner(
         command_runner: &dyn TmuxCommandRunner,
@@ -214,6 +233,10 @@ impl TmuxClient for RealTmuxClient {
     fn run_command_in_window(&self, window_id: String, command: String) -> TmuxFuture<()> {
         Box::pin(async move { Self::run_command_in_window_impl(window_id, command).await })
     }
+
+    fn display_message(&self, message: String) -> TmuxFuture<()> {
+        Box::pin(async move { Self::display_message_impl(message).await })
+    }
 }
 
 #[cfg(test)]
@@ -464,6 +487,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn display_message_command_builds_expected_tmux_invocation() {
+        // Arrange
+        let message = "agentty: Fix login needs review".to_string();
+
+        // Act
+        let command = ProcessTmuxCommandRunner::display_message_command(message.clone());
+        let (program, arguments) = command_parts(&command);
+
+        // Assert
+        assert_eq!(program, "tmux");
+        assert_eq!(arguments, vec!["display-message".to_string(), message]);
+    }
+
     /// Extracts one command executable and arguments for exact CLI assertions.
     fn command_parts(command: &tokio::process::Command) -> (String, Vec<String>) {
         let std_command = command.as_std();
@@ -17,6 +17,7 @@ impl Sleeper for ThreadSleeper {
 }
 
 pub mod app;
+pub mod cli;
 pub mod domain;
 pub mod infra;
 pub mod ui;
@@ -3,8 +3,13 @@ use std::path::PathBuf;
 use std::process::ExitCode;
 
 use agentty::app::{AGENTTY_WT_DIR, App, AppError, agentty_home};
+use agentty::cli::{Cli, Command, HeadlessContext};
+use agentty::infra::control_socket::CONTROL_SOCKET_FILE;
 use agentty::infra::db::{DB_DIR, DB_FILE, Database};
 use agentty::infra::git::{GitClient, RealGitClient};
+use agentty::ui::state::keymap::{self, KEYMAP_FILE};
+use agentty::ui::theme::{self, THEME_FILE};
+use clap::Parser;
 
 /// Runs the `agentty` application runtime using the configured workspace and
 /// database.
@@ -21,26 +26,54 @@ async fn main() -> ExitCode {
     }
 }
 
-/// Builds startup dependencies, then launches the `agentty` runtime.
+/// Builds startup dependencies, then runs one headless command or launches
+/// the `agentty` runtime with the configured keymap, theme, and local control
+/// socket.
 ///
 /// # Errors
-/// Returns an error if database startup, app construction, or runtime
-/// execution fails.
+/// Returns an error if database startup, keymap or theme validation, app
+/// construction, headless command execution, or runtime execution fails.
 async fn run() -> Result<(), AppError> {
-    let auto_update = !std::env::args().any(|arg| arg == "--no-update");
+    let cli = Cli::parse();
+    let auto_update = !cli.no_update;
 
     let home = agentty_home();
     let base_path = home.join(AGENTTY_WT_DIR);
     let working_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("/"));
-    let git_client = RealGitClient;
-    let git_branch = git_client.detect_git_info(working_dir.clone()).await;
 
     let db_path = home.join(DB_DIR).join(DB_FILE);
     let db = Database::open(&db_path).await?;
 
+    if let Some(Command::Session { command }) = cli.command {
+        let context = HeadlessContext {
+            base_path,
+            db,
+            working_dir,
+        };
+
+        return agentty::cli::run_session_command(command, context, &mut io::stdout()).await;
+    }
+
+    let keymap = keymap::load(&home.join(KEYMAP_FILE))
+        .map_err(|error| AppError::Workflow(error.to_string()))?;
+    keymap::install(keymap);
+    if let Some(custom_theme) = theme::load_custom(&home.join(THEME_FILE))
+        .map_err(|error| AppError::Workflow(error.to_string()))?
+    {
+        theme::install_custom(custom_theme);
+    }
+
+    let git_client = RealGitClient;
+    let git_branch = git_client.detect_git_info(working_dir.clone()).await;
+
     let mut app = App::new(auto_update, base_path, working_dir, git_branch, db).await?;
+    // Another running instance may already own the control socket, in which
+    // case this instance runs without the control API and says so in the
+    // status bar.
+    app.start_control_server(home.join(CONTROL_SOCKET_FILE))
+        .await;
 
-    agentty::runtime::run(&mut app)
+    agentty::runtime::run(&mut app, !cli.no_mouse)
         .await
         .map_err(|error| AppError::Workflow(format!("Failed to run terminal UI: {error}")))
 }
@@ -4,14 +4,18 @@
 //! runtime entry APIs.
 
 mod clipboard_image;
+mod context_attachment;
 mod core;
 mod event;
+mod external_editor;
 mod key_handler;
 pub mod mode;
+mod mouse_handler;
 mod terminal;
 mod timing;
 
 pub(crate) use core::{EventResult, TuiTerminal, backend_err};
 pub use core::{run, run_with_backend};
 
+pub(crate) use context_attachment::CapturedContext;
 pub(crate) use timing::FRAME_INTERVAL;
@@ -0,0 +1,724 @@
+//! Text context capture helpers for prompt-mode `/file`, `/run`, `/url`,
+//! `/diff`, `/snippet`, and `/skill` attachments.
+
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::{ExitStatus, Stdio};
+use std::time::Duration;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+use crate::domain::skill::{self, Skill};
+use crate::infra::fs::{FsClient, FsError};
+
+/// Directory name for saved text snippets.
+pub(crate) const SNIPPETS_DIR: &str = "snippets";
+
+/// Upper bound, in bytes, for one captured context attachment.
+const MAX_CONTEXT_ATTACHMENT_BYTES: usize = 64 * 1024;
+
+/// Maximum time one `/run` command or `/url` fetch may take before it is
+/// killed.
+const COMMAND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Chunk size used while draining child process output.
+const OUTPUT_READ_CHUNK_BYTES: usize = 8 * 1024;
+
+/// File extension tried first when resolving a snippet name.
+const SNIPPET_EXTENSION: &str = "md";
+
+/// Typed error returned while capturing one prompt context attachment.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ContextAttachmentError {
+    /// The referenced file or URL contains NUL bytes and is treated as
+    /// binary.
+    #[error("`{path}` is not a text file")]
+    BinaryFile {
+        /// Path or URL as typed in the directive.
+        path: String,
+    },
+
+    /// The command could not be started or its output could not be read.
+    #[error("Failed to run `{command}`: {source}")]
+    CommandSpawn {
+        /// Command line as typed in the directive.
+        command: String,
+        /// Underlying process spawn error.
+        source: std::io::Error,
+    },
+
+    /// The command did not finish within [`COMMAND_TIMEOUT`].
+    #[error("`{command}` did not finish within {seconds} seconds")]
+    CommandTimeout {
+        /// Command line as typed in the directive.
+        command: String,
+        /// Timeout that was exceeded.
+        seconds: u64,
+    },
+
+    /// The session diff could not be read.
+    #[error("Failed to read diff for `{session}`: {reason}")]
+    Diff {
+        /// Display name of the referenced session.
+        session: String,
+        /// Human-readable git failure.
+        reason: String,
+    },
+
+    /// The snippet name is empty or refers outside the snippet folders.
+    #[error("`{name}` is not a valid snippet name")]
+    InvalidSnippetName {
+        /// Snippet name as typed in the directive.
+        name: String,
+    },
+
+    /// The URL does not use the `http` or `https` scheme.
+    #[error("`{url}` is not an http or https URL")]
+    UnsupportedUrl {
+        /// URL as typed in the directive.
+        url: String,
+    },
+
+    /// The URL could not be fetched.
+    #[error("Failed to fetch `{url}`: {reason}")]
+    UrlFetch {
+        /// URL as typed in the directive.
+        url: String,
+        /// Human-readable `curl` failure.
+        reason: String,
+    },
+
+    /// The referenced session has no changes against its base branch.
+    #[error("`{session}` has no changes to attach")]
+    EmptyDiff {
+        /// Display name of the referenced session.
+        session: String,
+    },
+
+    /// The referenced file could not be read.
+    #[error("Failed to read `{path}`: {source}")]
+    ReadFile {
+        /// Path as typed in the directive.
+        path: String,
+        /// Underlying filesystem-boundary error.
+        source: FsError,
+    },
+
+    /// No session in the active project matches the directive argument.
+    #[error("No session matches `{query}`")]
+    SessionNotFound {
+        /// Session id prefix or title as typed in the directive.
+        query: String,
+    },
+
+    /// No discovered skill matches the directive argument.
+    #[error("Skill `{name}` was not found")]
+    SkillNotFound {
+        /// Skill name or name prefix as typed in the directive.
+        name: String,
+    },
+
+    /// No user or project snippet exists with the requested name.
+    #[error("Snippet `{name}` was not found")]
+    SnippetNotFound {
+        /// Snippet name as typed in the directive.
+        name: String,
+    },
+}
+
+/// Captured text plus the label shown next to it in the agent prompt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct CapturedContext {
+    /// Captured text, truncated to [`MAX_CONTEXT_ATTACHMENT_BYTES`].
+    pub(crate) content: String,
+    /// Human-readable source description.
+    pub(crate) label: String,
+}
+
+/// Reads one local file or log relative to `root`.
+///
+/// Absolute paths are read as-is so logs outside the worktree can be
+/// attached. Reading stops at [`MAX_CONTEXT_ATTACHMENT_BYTES`] so large logs
+/// are never loaded whole.
+///
+/// # Errors
+/// Returns an error when the file cannot be read or contains binary data.
+pub(crate) async fn capture_file(
+    fs_client: &dyn FsClient,
+    root: &Path,
+    path: &str,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    let (bytes, file_len) = fs_client
+        .read_file_prefix(root.join(path), MAX_CONTEXT_ATTACHMENT_BYTES)
+        .await
+        .map_err(|source| ContextAttachmentError::ReadFile {
+            path: path.to_string(),
+            source,
+        })?;
+    if bytes.contains(&0) {
+        return Err(ContextAttachmentError::BinaryFile {
+            path: path.to_string(),
+        });
+    }
+
+    Ok(CapturedContext {
+        content: truncate_prefix_context(&bytes, file_len),
+        label: format!("File `{path}`"),
+    })
+}
+
+/// Runs one shell command in `root` and captures its combined output and
+/// exit status.
+///
+/// # Errors
+/// Returns an error when the shell cannot be started or the command exceeds
+/// [`COMMAND_TIMEOUT`].
+pub(crate) async fn capture_command(
+    root: &Path,
+    command: &str,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    let mut shell_command = tokio::process::Command::new("sh");
+    shell_command.arg("-c").arg(command).current_dir(root);
+    let output = run_with_capped_output(shell_command, command).await?;
+
+    let mut content = String::from_utf8_lossy(&output.stdout).into_owned();
+    content.push_str(&String::from_utf8_lossy(&output.stderr));
+    if !content.is_empty() && !content.ends_with('\n') {
+        content.push('\n');
+    }
+    let _ = write!(content, "[{}]", output.status);
+    let original_len = content.len() + output.dropped_len;
+
+    Ok(CapturedContext {
+        content: truncate_context_from(content, original_len),
+        label: format!("Command `{command}`"),
+    })
+}
+
+/// Fetches one `http` or `https` URL with `curl` and captures the response
+/// body as text.
+///
+/// # Errors
+/// Returns an error when the URL scheme is unsupported, `curl` cannot be
+/// started, the request fails or exceeds [`COMMAND_TIMEOUT`], or the body is
+/// binary.
+pub(crate) async fn capture_url(url: &str) -> Result<CapturedContext, ContextAttachmentError> {
+    if !is_http_url(url) {
+        return Err(ContextAttachmentError::UnsupportedUrl {
+            url: url.to_string(),
+        });
+    }
+
+    let mut curl_command = tokio::process::Command::new("curl");
+    curl_command
+        .args([
+            "-fsSL",
+            "--proto",
+            "=http,https",
+            "--proto-redir",
+            "=http,https",
+        ])
+        .arg("--max-time")
+        .arg(COMMAND_TIMEOUT.as_secs().to_string())
+        .arg(url);
+    let output = run_with_capped_output(curl_command, &format!("curl {url}")).await?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.trim();
+
+        return Err(ContextAttachmentError::UrlFetch {
+            url: url.to_string(),
+            reason: if reason.is_empty() {
+                output.status.to_string()
+            } else {
+                reason.to_string()
+            },
+        });
+    }
+    if output.stdout.contains(&0) {
+        return Err(ContextAttachmentError::BinaryFile {
+            path: url.to_string(),
+        });
+    }
+
+    let content = String::from_utf8_lossy(&output.stdout).into_owned();
+    let original_len = content.len() + output.dropped_len;
+
+    Ok(CapturedContext {
+        content: truncate_context_from(content, original_len),
+        label: format!("URL `{url}`"),
+    })
+}
+
+/// Wraps one session diff already read through the git boundary.
+///
+/// # Errors
+/// Returns [`ContextAttachmentError::EmptyDiff`] when the diff is blank.
+pub(crate) fn capture_diff(
+    session: &str,
+    diff: String,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    if diff.trim().is_empty() {
+        return Err(ContextAttachmentError::EmptyDiff {
+            session: session.to_string(),
+        });
+    }
+
+    Ok(CapturedContext {
+        content: truncate_context(diff),
+        label: format!("Diff of session `{session}`"),
+    })
+}
+
+/// Loads one saved snippet by name from the project or user snippet folder.
+///
+/// Each folder is checked for `<name>.md` and then `<name>`. A project
+/// snippet replaces a user snippet with the same name.
+///
+/// # Errors
+/// Returns [`ContextAttachmentError::InvalidSnippetName`] when `name` could
+/// leave the snippet folders, or
+/// [`ContextAttachmentError::SnippetNotFound`] when neither folder holds a
+/// readable snippet with that name.
+pub(crate) async fn capture_snippet(
+    fs_client: &dyn FsClient,
+    user_dir: &Path,
+    project_dir: &Path,
+    name: &str,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    if !is_valid_snippet_name(name) {
+        return Err(ContextAttachmentError::InvalidSnippetName {
+            name: name.to_string(),
+        });
+    }
+
+    for path in [project_dir, user_dir]
+        .into_iter()
+        .flat_map(|directory| snippet_candidates(directory, name))
+    {
+        if let Ok((bytes, file_len)) = fs_client
+            .read_file_prefix(path, MAX_CONTEXT_ATTACHMENT_BYTES)
+            .await
+        {
+            return Ok(CapturedContext {
+                content: truncate_prefix_context(&bytes, file_len),
+                label: format!("Snippet `{name}`"),
+            });
+        }
+    }
+
+    Err(ContextAttachmentError::SnippetNotFound {
+        name: name.to_string(),
+    })
+}
+
+/// Selects one discovered skill by exact name or name prefix.
+///
+/// The label is the bare skill name so prompt submission can resolve the
+/// skill again, and the content is the skill body without front matter.
+///
+/// # Errors
+/// Returns [`ContextAttachmentError::SkillNotFound`] when no skill matches
+/// `name`.
+pub(crate) fn capture_skill(
+    skills: &[Skill],
+    name: &str,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    let skill =
+        skill::find_skill(skills, name).ok_or_else(|| ContextAttachmentError::SkillNotFound {
+            name: name.to_string(),
+        })?;
+
+    Ok(CapturedContext {
+        content: truncate_context(skill.body.clone()),
+        label: skill.name.clone(),
+    })
+}
+
+/// Returns whether `name` names a file directly inside a snippet folder.
+fn is_valid_snippet_name(name: &str) -> bool {
+    !name.is_empty() && name != "." && name != ".." && !name.contains(['/', '\\'])
+}
+
+/// Returns whether `url` uses the `http` or `https` scheme.
+fn is_http_url(url: &str) -> bool {
+    let lowercase_url = url.to_ascii_lowercase();
+
+    lowercase_url.starts_with("http://") || lowercase_url.starts_with("https://")
+}
+
+/// Output of one child process whose streams were read with a byte cap.
+struct CappedOutput {
+    /// Number of output bytes read past the cap and discarded.
+    dropped_len: usize,
+    /// Exit status of the child process.
+    status: ExitStatus,
+    /// Captured stderr, at most [`MAX_CONTEXT_ATTACHMENT_BYTES`] long.
+    stderr: Vec<u8>,
+    /// Captured stdout, at most [`MAX_CONTEXT_ATTACHMENT_BYTES`] long.
+    stdout: Vec<u8>,
+}
+
+/// Runs `command` and keeps at most [`MAX_CONTEXT_ATTACHMENT_BYTES`] of each
+/// output stream.
+///
+/// Output past the cap is drained and discarded so a chatty command cannot
+/// grow memory without bound or block on a full pipe.
+///
+/// # Errors
+/// Returns an error when the process cannot be started or read, or does not
+/// exit within [`COMMAND_TIMEOUT`].
+async fn run_with_capped_output(
+    mut command: tokio::process::Command,
+    command_label: &str,
+) -> Result<CappedOutput, ContextAttachmentError> {
+    let spawn_error = |source| ContextAttachmentError::CommandSpawn {
+        command: command_label.to_string(),
+        source,
+    };
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()
+        .map_err(spawn_error)?;
+    let (Some(stdout), Some(stderr)) = (child.stdout.take(), child.stderr.take()) else {
+        return Err(spawn_error(std::io::Error::other(
+            "child output pipes are unavailable",
+        )));
+    };
+
+    let output_future =
+        async { tokio::try_join!(read_capped(stdout), read_capped(stderr), child.wait()) };
+    let ((stdout, stdout_dropped_len), (stderr, stderr_dropped_len), status) =
+        tokio::time::timeout(COMMAND_TIMEOUT, output_future)
+            .await
+            .map_err(|_| ContextAttachmentError::CommandTimeout {
+                command: command_label.to_string(),
+                seconds: COMMAND_TIMEOUT.as_secs(),
+            })?
+            .map_err(spawn_error)?;
+
+    Ok(CappedOutput {
+        dropped_len: stdout_dropped_len + stderr_dropped_len,
+        status,
+        stderr,
+        stdout,
+    })
+}
+
+/// Reads `reader` to the end, keeping at most
+/// [`MAX_CONTEXT_ATTACHMENT_BYTES`] and counting the discarded bytes.
+async fn read_capped(mut reader: impl AsyncRead + Unpin) -> std::io::Result<(Vec<u8>, usize)> {
+    let mut captured = Vec::new();
+    let mut dropped_len = 0;
+    let mut buffer = vec![0_u8; OUTPUT_READ_CHUNK_BYTES];
+
+    loop {
+        let read_len = reader.read(&mut buffer).await?;
+        if read_len == 0 {
+            return Ok((captured, dropped_len));
+        }
+
+        let kept_len = read_len.min(MAX_CONTEXT_ATTACHMENT_BYTES - captured.len());
+        captured.extend_from_slice(&buffer[..kept_len]);
+        dropped_len += read_len - kept_len;
+    }
+}
+
+/// Returns the file paths checked for one snippet name in `directory`.
+fn snippet_candidates(directory: &Path, name: &str) -> [PathBuf; 2] {
+    [
+        directory.join(format!("{name}.{SNIPPET_EXTENSION}")),
+        directory.join(name),
+    ]
+}
+
+/// Truncates `content` to [`MAX_CONTEXT_ATTACHMENT_BYTES`] on a character
+/// boundary and notes how much was dropped.
+fn truncate_context(content: String) -> String {
+    let original_len = content.len();
+
+    truncate_context_from(content, original_len)
+}
+
+/// Decodes one capped file prefix and notes truncation when the file is
+/// longer than the prefix.
+fn truncate_prefix_context(prefix: &[u8], file_len: u64) -> String {
+    let original_len = usize::try_from(file_len)
+        .unwrap_or(usize::MAX)
+        .max(prefix.len());
+
+    truncate_context_from(String::from_utf8_lossy(prefix).into_owned(), original_len)
+}
+
+/// Truncates `content` like [`truncate_context`], reporting `original_len`
+/// as the source size when output was already capped while reading.
+fn truncate_context_from(mut content: String, original_len: usize) -> String {
+    if content.len() <= MAX_CONTEXT_ATTACHMENT_BYTES && original_len <= content.len() {
+        return content;
+    }
+
+    let mut cut_index = content.len().min(MAX_CONTEXT_ATTACHMENT_BYTES);
+    while !content.is_char_boundary(cut_index) {
+        cut_index -= 1;
+    }
+    content.truncate(cut_index);
+    let _ = write!(
+        content,
+        "\n... (truncated: kept {cut_index} of {original_len} bytes)"
+    );
+
+    content
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::infra::fs::RealFsClient;
+
+    #[tokio::test]
+    async fn test_capture_file_reads_text_relative_to_root() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        std::fs::write(temp_dir.path().join("build.log"), "warning: unused").expect("write");
+
+        // Act
+        let captured = capture_file(&RealFsClient, temp_dir.path(), "build.log")
+            .await
+            .expect("file should be captured");
+
+        // Assert
+        assert_eq!(
+            captured,
+            CapturedContext {
+                content: "warning: unused".to_string(),
+                label: "File `build.log`".to_string(),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capture_file_stops_reading_at_cap() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        let file_len = MAX_CONTEXT_ATTACHMENT_BYTES * 4;
+        std::fs::write(temp_dir.path().join("huge.log"), "a".repeat(file_len)).expect("write");
+
+        // Act
+        let captured = capture_file(&RealFsClient, temp_dir.path(), "huge.log")
+            .await
+            .expect("file should be captured");
+
+        // Assert
+        let (kept, note) = captured
+            .content
+            .split_once("\n... ")
+            .expect("truncation note should be appended");
+        assert_eq!(kept.len(), MAX_CONTEXT_ATTACHMENT_BYTES);
+        assert_eq!(
+            note,
+            format!("(truncated: kept {MAX_CONTEXT_ATTACHMENT_BYTES} of {file_len} bytes)")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_capture_file_rejects_binary_content() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        std::fs::write(temp_dir.path().join("image.bin"), [0x89, 0x00, 0x01]).expect("write");
+
+        // Act
+        let result = capture_file(&RealFsClient, temp_dir.path(), "image.bin").await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ContextAttachmentError::BinaryFile { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_capture_command_includes_output_and_exit_status() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+
+        // Act
+        let captured = capture_command(temp_dir.path(), "echo out; echo err >&2; exit 3")
+            .await
+            .expect("command should be captured");
+
+        // Assert
+        assert!(captured.content.starts_with("out\nerr\n["));
+        assert!(captured.content.contains('3'));
+        assert_eq!(captured.label, "Command `echo out; echo err >&2; exit 3`");
+    }
+
+    #[test]
+    fn test_capture_diff_rejects_empty_diff() {
+        // Arrange
+        let diff = "\n".to_string();
+
+        // Act
+        let result = capture_diff("fix-login", diff);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ContextAttachmentError::EmptyDiff { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_capture_command_caps_output_while_reading() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        let output_len = MAX_CONTEXT_ATTACHMENT_BYTES * 4;
+
+        // Act
+        let captured = capture_command(temp_dir.path(), &format!("head -c {output_len} /dev/zero"))
+            .await
+            .expect("command should be captured");
+
+        // Assert
+        let (kept, note) = captured
+            .content
+            .split_once("\n... ")
+            .expect("truncation note should be appended");
+        assert_eq!(kept.len(), MAX_CONTEXT_ATTACHMENT_BYTES);
+        assert!(note.contains(&format!(
+            "of {} bytes",
+            output_len + "\n[exit status: 0]".len()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_capture_url_rejects_non_http_schemes() {
+        // Arrange
+        let url = "file:///etc/passwd";
+
+        // Act
+        let result = capture_url(url).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ContextAttachmentError::UnsupportedUrl { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_capture_snippet_rejects_names_with_path_separators() {
+        // Arrange
+        let user_dir = tempdir().expect("create user dir");
+        let project_dir = tempdir().expect("create project dir");
+
+        // Act
+        let results = [
+            capture_snippet(
+                &RealFsClient,
+                user_dir.path(),
+                project_dir.path(),
+                "../secret",
+            )
+            .await,
+            capture_snippet(&RealFsClient, user_dir.path(), project_dir.path(), "..").await,
+            capture_snippet(&RealFsClient, user_dir.path(), project_dir.path(), "a\\b").await,
+        ];
+
+        // Assert
+        assert!(results.iter().all(|result| matches!(
+            result,
+            Err(ContextAttachmentError::InvalidSnippetName { .. })
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_capture_snippet_prefers_project_snippet() {
+        // Arrange
+        let user_dir = tempdir().expect("create user dir");
+        let project_dir = tempdir().expect("create project dir");
+        std::fs::write(user_dir.path().join("style.md"), "User style").expect("write");
+        std::fs::write(project_dir.path().join("style.md"), "Project style").expect("write");
+        std::fs::write(user_dir.path().join("notes.txt"), "User notes").expect("write");
+
+        // Act
+        let style = capture_snippet(&RealFsClient, user_dir.path(), project_dir.path(), "style")
+            .await
+            .expect("style snippet should load");
+        let notes = capture_snippet(
+            &RealFsClient,
+            user_dir.path(),
+            project_dir.path(),
+            "notes.txt",
+        )
+        .await
+        .expect("notes snippet should load");
+        let missing = capture_snippet(
+            &RealFsClient,
+            user_dir.path(),
+            project_dir.path(),
+            "missing",
+        )
+        .await;
+
+        // Assert
+        assert_eq!(style.content, "Project style");
+        assert_eq!(notes.content, "User notes");
+        assert!(matches!(
+            missing,
+            Err(ContextAttachmentError::SnippetNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_capture_skill_uses_skill_name_as_label() {
+        // Arrange
+        let skills = vec![
+            Skill::parse(
+                "review",
+                "---\nname: review\n---\nCheck the diff.",
+                PathBuf::from("skills/review/SKILL.md"),
+                crate::domain::skill::SkillScope::Project,
+            )
+            .expect("skill should parse"),
+        ];
+
+        // Act
+        let review = capture_skill(&skills, "rev").expect("review skill should load");
+        let missing = capture_skill(&skills, "deploy");
+
+        // Assert
+        assert_eq!(review.label, "review");
+        assert_eq!(review.content, "Check the diff.");
+        assert!(matches!(
+            missing,
+            Err(ContextAttachmentError::SkillNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_truncate_context_cuts_on_character_boundary() {
+        // Arrange
+        let content = "é".repeat(MAX_CONTEXT_ATTACHMENT_BYTES);
+
+        // Act
+        let truncated = truncate_context(content);
+
+        // Assert
+        let (kept, note) = truncated
+            .split_once("\n... ")
+            .expect("truncation note should be appended");
+        assert_eq!(kept.len(), MAX_CONTEXT_ATTACHMENT_BYTES);
+        assert_eq!(
+            note,
+            format!(
+                "(truncated: kept {MAX_CONTEXT_ATTACHMENT_BYTES} of {} bytes)",
+                MAX_CONTEXT_ATTACHMENT_BYTES * 2
+            )
+        );
+    }
+}
@@ -12,9 +12,13 @@ use tokio::sync::mpsc;
 
 use crate::app::App;
 use crate::runtime::{FRAME_INTERVAL, event, terminal};
+use crate::ui::hyperlink::HyperlinkBackend;
+use crate::ui::theme;
 
 /// Concrete terminal type used by the production runtime entry point.
-pub(crate) type TuiTerminal = Terminal<CrosstermBackend<io::Stdout>>;
+///
+/// The hyperlink wrapper turns link-tagged cells into OSC 8 sequences.
+pub(crate) type TuiTerminal = Terminal<HyperlinkBackend<CrosstermBackend<io::Stdout>>>;
 
 /// Converts a backend-specific error into `io::Error`.
 ///
@@ -35,12 +39,21 @@ pub(crate) enum EventResult {
 
 /// Runs the TUI event/render loop until the user exits.
 ///
+/// `mouse_capture` controls whether the terminal reports mouse events to the
+/// app or keeps its native text selection.
+///
 /// # Errors
 /// Returns an error if terminal setup, rendering, or event processing fails.
-pub async fn run(app: &mut App) -> io::Result<()> {
+pub async fn run(app: &mut App, mouse_capture: bool) -> io::Result<()> {
+    terminal::set_mouse_capture(mouse_capture);
     let terminal_guard = terminal::TerminalGuard::new();
     let mut terminal = terminal::setup_terminal(&terminal_guard)?;
 
+    if let Some(background) = terminal::query_background() {
+        theme::record_background(background);
+    }
+    theme::apply(app.settings.theme_mode());
+
     // Spawn a dedicated thread for crossterm event reading so the main async
     // loop can yield to tokio between iterations.
     let (event_tx, mut event_rx) = mpsc::unbounded_channel();
@@ -161,13 +174,15 @@ mod tests {
 
     use crossterm::event::{Event, KeyCode, KeyEvent, KeyModifiers};
     use ratatui::backend::TestBackend;
+    use ratatui::style::Modifier;
     use tempfile::tempdir;
 
     use super::*;
-    use crate::app::AppEvent;
+    use crate::app::{AppEvent, Tab};
     use crate::db::Database;
     use crate::domain::session::tests::SessionFixtureBuilder;
     use crate::domain::session::{SessionHandles, Status};
+    use crate::domain::setting::SettingName;
     use crate::ui::state::app_mode::{AppMode, DoneSessionOutputMode};
 
     /// Test-only loop state that records call counts and scripted outcomes.
@@ -264,6 +279,55 @@ mod tests {
         (app, base_dir)
     }
 
+    /// Builds one test app whose persisted settings enable the split layout.
+    async fn new_test_app_with_split_layout() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        database
+            .upsert_setting(SettingName::SplitLayout, "true")
+            .await
+            .expect("failed to persist split layout");
+
+        let app = App::new_with_clients(
+            base_path.clone(),
+            base_path,
+            None,
+            database,
+            test_app_clients(),
+        )
+        .await
+        .expect("failed to build test app");
+
+        (app, base_dir)
+    }
+
+    /// Adds one titled review session with transcript output to `app`.
+    fn push_split_layout_session(app: &mut App, base_dir: &tempfile::TempDir) {
+        let mut session = SessionFixtureBuilder::new()
+            .id("split-session")
+            .folder(base_dir.path().to_path_buf())
+            .output("split preview transcript")
+            .status(Status::Review)
+            .build();
+        session.title = Some("Split layout session".to_string());
+        app.sessions.push_session(session);
+        app.sessions.table_state.select(Some(0));
+    }
+
+    /// Flattens the buffer columns in `columns` into one searchable string.
+    fn buffer_columns_text(
+        buffer: &ratatui::buffer::Buffer,
+        columns: std::ops::Range<u16>,
+    ) -> String {
+        (0..buffer.area.height)
+            .flat_map(|row| columns.clone().map(move |column| (column, row)))
+            .map(|position| buffer[position].symbol())
+            .collect()
+    }
+
     /// Flattens a test terminal buffer into one searchable string.
     fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
         buffer
@@ -337,6 +401,7 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         if let Some(session) = app
             .sessions
@@ -376,4 +441,56 @@ mod tests {
             "expected rendered session output to contain synced handle text: {rendered_text}"
         );
     }
+
+    #[tokio::test]
+    async fn render_frame_shows_session_preview_beside_list_in_split_layout() {
+        // Arrange
+        let (mut app, base_dir) = new_test_app_with_split_layout().await;
+        let mut terminal =
+            Terminal::new(TestBackend::new(160, 24)).expect("failed to create test terminal");
+        push_split_layout_session(&mut app, &base_dir);
+        app.tabs.set(Tab::Sessions);
+
+        // Act
+        render_frame(&mut app, &mut terminal).expect("failed to render frame");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let list_text = buffer_columns_text(buffer, 0..72);
+        let preview_text = buffer_columns_text(buffer, 72..160);
+        assert!(list_text.contains("Split layout se"));
+        assert!(preview_text.contains("split preview transcript"));
+        assert!(buffer[(100, 5)].modifier.contains(Modifier::DIM));
+        assert!(!buffer[(10, 5)].modifier.contains(Modifier::DIM));
+    }
+
+    #[tokio::test]
+    async fn render_frame_keeps_dimmed_list_beside_session_view_in_split_layout() {
+        // Arrange
+        let (mut app, base_dir) = new_test_app_with_split_layout().await;
+        let mut terminal =
+            Terminal::new(TestBackend::new(160, 24)).expect("failed to create test terminal");
+        push_split_layout_session(&mut app, &base_dir);
+        app.tabs.set(Tab::Sessions);
+        app.mode = AppMode::View {
+            done_session_output_mode: DoneSessionOutputMode::Summary,
+            review_status_message: None,
+            review_text: None,
+            session_id: "split-session".into(),
+            scroll_offset: None,
+            transcript_search: None,
+        };
+
+        // Act
+        render_frame(&mut app, &mut terminal).expect("failed to render frame");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let list_text = buffer_columns_text(buffer, 0..72);
+        let session_text = buffer_columns_text(buffer, 72..160);
+        assert!(list_text.contains("Split layout se"));
+        assert!(session_text.contains("split preview transcript"));
+        assert!(buffer[(10, 5)].modifier.contains(Modifier::DIM));
+        assert!(!buffer[(100, 5)].modifier.contains(Modifier::DIM));
+    }
 }
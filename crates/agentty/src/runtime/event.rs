@@ -11,7 +11,7 @@ use ratatui::backend::Backend;
 use tokio::sync::mpsc;
 
 use crate::app::{App, AppEvent};
-use crate::runtime::{EventResult, FRAME_INTERVAL, key_handler, mode};
+use crate::runtime::{EventResult, FRAME_INTERVAL, key_handler, mode, mouse_handler};
 use crate::ui::state::app_mode::AppMode;
 
 /// Reads terminal events from an underlying event backend.
@@ -36,6 +36,46 @@ impl EventSource for CrosstermEventSource {
     }
 }
 
+/// Set while a foreground child process owns the terminal so the reader thread
+/// stops consuming its keystrokes.
+static EVENT_READER_PAUSED: AtomicBool = AtomicBool::new(false);
+
+/// Set by the reader thread once it has observed [`EVENT_READER_PAUSED`] and
+/// stopped polling.
+static EVENT_READER_PARKED: AtomicBool = AtomicBool::new(false);
+
+/// Longest time [`EventReaderPause::acquire`] waits for the reader thread to
+/// park before handing over the terminal anyway.
+const EVENT_READER_PARK_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// Keeps the terminal event reader thread parked while alive.
+///
+/// Held around foreground child processes such as `$EDITOR` so crossterm does
+/// not read their input; dropping the guard resumes event reading.
+pub(crate) struct EventReaderPause;
+
+impl EventReaderPause {
+    /// Pauses the reader thread and waits until it stops polling, bounded by
+    /// [`EVENT_READER_PARK_TIMEOUT`] for runtimes without a reader thread.
+    pub(crate) async fn acquire() -> Self {
+        EVENT_READER_PAUSED.store(true, Ordering::Relaxed);
+
+        let deadline = tokio::time::Instant::now() + EVENT_READER_PARK_TIMEOUT;
+        while !EVENT_READER_PARKED.load(Ordering::Relaxed) && tokio::time::Instant::now() < deadline
+        {
+            tokio::time::sleep(FRAME_INTERVAL).await;
+        }
+
+        Self
+    }
+}
+
+impl Drop for EventReaderPause {
+    fn drop(&mut self) {
+        EVENT_READER_PAUSED.store(false, Ordering::Relaxed);
+    }
+}
+
 /// Represents the next runtime wake-up source while awaiting input or redraw.
 enum LoopSignal {
     /// One pending app-side event routed through the internal bus.
@@ -68,6 +108,14 @@ fn spawn_event_reader_with_source(
                 break;
             }
 
+            if EVENT_READER_PAUSED.load(Ordering::Relaxed) {
+                EVENT_READER_PARKED.store(true, Ordering::Relaxed);
+                std::thread::sleep(FRAME_INTERVAL);
+
+                continue;
+            }
+            EVENT_READER_PARKED.store(false, Ordering::Relaxed);
+
             match event_source.poll(FRAME_INTERVAL) {
                 Ok(true) => {
                     if let Ok(event) = event_source.read()
@@ -131,6 +179,7 @@ where
         LoopSignal::AppEvent(app_event) => {
             if let Some(event) = *app_event {
                 app.apply_app_events(event).await;
+                app.run_deferred_workflows().await;
             }
 
             None
@@ -167,6 +216,8 @@ where
 ///
 /// `Event::Paste` is handled in text-input modes so multiline clipboard
 /// content is inserted as text instead of interpreted as navigation keys.
+/// `Event::Mouse` is hit-tested against the active layout by
+/// [`mouse_handler::handle_mouse_event`].
 async fn process_event<B: Backend>(
     app: &mut App,
     terminal: &mut Terminal<B>,
@@ -175,6 +226,10 @@ async fn process_event<B: Backend>(
 where
     B::Error: std::error::Error + Send + Sync + 'static,
 {
+    if let Some(Event::Mouse(mouse)) = event {
+        return mouse_handler::handle_mouse_event(app, terminal, mouse).await;
+    }
+
     process_event_with_key_handler(app, terminal, event, |app, terminal, key| {
         Box::pin(key_handler::handle_key_event(app, terminal, key))
     })
@@ -247,7 +302,7 @@ mod tests {
     use crate::domain::agent::AgentKind;
     use crate::domain::input::InputState;
     use crate::domain::session::{Session, SessionSize, SessionStats, Status};
-    use crate::infra::agent::protocol::QuestionItem;
+    use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
     use crate::ui::state::app_mode::{AppMode, QuestionFocus};
     use crate::ui::state::prompt::{PromptAttachmentState, PromptHistoryState, PromptSlashState};
 
@@ -427,8 +482,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentKind::Gemini.default_model(),
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -441,6 +498,7 @@ mod tests {
             status: Status::New,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         });
         app.mode = AppMode::Prompt {
@@ -480,6 +538,7 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             current_index: 0,
             focus: QuestionFocus::Answer,
             input: InputState::default(),
@@ -487,6 +546,7 @@ mod tests {
             review_text: None,
             scroll_offset: None,
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["yes".to_string()],
                 text: "Is this enough?".to_string(),
             }],
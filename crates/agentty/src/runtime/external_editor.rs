@@ -0,0 +1,235 @@
+//! External `$VISUAL`/`$EDITOR` composition for long prompt drafts.
+
+use std::path::{Path, PathBuf};
+use std::process::ExitStatus;
+
+use crate::app;
+use crate::infra::fs::{FsClient, FsError};
+
+/// Editor command used when neither `VISUAL` nor `EDITOR` is set.
+const FALLBACK_EDITOR: &str = "vi";
+
+/// File name of the per-session prompt draft handed to the editor.
+const PROMPT_FILE_NAME: &str = "prompt.md";
+
+/// Typed error returned while composing a prompt in an external editor.
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum ExternalEditorError {
+    /// The session identifier is empty.
+    #[error("Session id is missing for editor temp storage")]
+    EmptySessionId,
+
+    /// The editor process exited unsuccessfully, so the edit is discarded.
+    #[error("Editor `{editor}` exited with {status}; prompt left unchanged")]
+    Exit {
+        /// Editor command that was run.
+        editor: String,
+        /// Exit status reported by the shell.
+        status: ExitStatus,
+    },
+
+    /// A filesystem operation on the prompt draft file failed.
+    #[error("{context}: {source}")]
+    Fs {
+        /// Human-readable operation label.
+        context: &'static str,
+        /// Underlying filesystem-boundary error.
+        source: FsError,
+    },
+
+    /// The editor process could not be started.
+    #[error("Failed to start editor `{editor}`: {source}")]
+    Spawn {
+        /// Editor command that was run.
+        editor: String,
+        /// Underlying process spawn error.
+        source: std::io::Error,
+    },
+}
+
+/// Opens `text` in the user's editor and returns the saved result.
+///
+/// The draft is written to `AGENTTY_ROOT/tmp/<session-id>/prompt.md`, which
+/// is removed again once the editor exits. The caller must suspend the TUI
+/// before calling this so the editor owns the terminal.
+///
+/// # Errors
+/// Returns an error when the draft file cannot be written or read, or when
+/// the editor fails to start or exits unsuccessfully.
+pub(crate) async fn edit_prompt_text(
+    fs_client: &dyn FsClient,
+    session_id: &str,
+    text: &str,
+) -> Result<String, ExternalEditorError> {
+    let prompt_file_path = prompt_file_path(session_id)?;
+    let editor = resolve_editor_command(std::env::var("VISUAL").ok(), std::env::var("EDITOR").ok());
+
+    edit_text_with_editor(fs_client, &prompt_file_path, &editor, text).await
+}
+
+/// Returns the draft file path handed to the editor for one session.
+///
+/// # Errors
+/// Returns [`ExternalEditorError::EmptySessionId`] when `session_id` is
+/// empty.
+fn prompt_file_path(session_id: &str) -> Result<PathBuf, ExternalEditorError> {
+    if session_id.is_empty() {
+        return Err(ExternalEditorError::EmptySessionId);
+    }
+
+    Ok(app::agentty_home()
+        .join("tmp")
+        .join(session_id)
+        .join(PROMPT_FILE_NAME))
+}
+
+/// Picks the editor command, preferring `VISUAL` over `EDITOR` and ignoring
+/// blank values.
+fn resolve_editor_command(visual: Option<String>, editor: Option<String>) -> String {
+    [visual, editor]
+        .into_iter()
+        .flatten()
+        .map(|command| command.trim().to_string())
+        .find(|command| !command.is_empty())
+        .unwrap_or_else(|| FALLBACK_EDITOR.to_string())
+}
+
+/// Writes `text` to `path`, runs `editor` on it through `sh`, and reads the
+/// result back.
+///
+/// Running through `sh` lets editor commands carry arguments, such as
+/// `code --wait`.
+async fn edit_text_with_editor(
+    fs_client: &dyn FsClient,
+    path: &Path,
+    editor: &str,
+    text: &str,
+) -> Result<String, ExternalEditorError> {
+    if let Some(parent) = path.parent() {
+        fs_client
+            .create_dir_all(parent.to_path_buf())
+            .await
+            .map_err(|source| ExternalEditorError::Fs {
+                context: "Failed to create editor draft directory",
+                source,
+            })?;
+    }
+    fs_client
+        .write_file(path.to_path_buf(), text.as_bytes().to_vec())
+        .await
+        .map_err(|source| ExternalEditorError::Fs {
+            context: "Failed to write editor draft",
+            source,
+        })?;
+
+    let status = tokio::process::Command::new("sh")
+        .arg("-c")
+        .arg(format!("{editor} \"$1\""))
+        .arg("sh")
+        .arg(path)
+        .status()
+        .await
+        .map_err(|source| ExternalEditorError::Spawn {
+            editor: editor.to_string(),
+            source,
+        })?;
+    let edited_bytes = if status.success() {
+        fs_client.read_file(path.to_path_buf()).await
+    } else {
+        Ok(Vec::new())
+    };
+    // Best-effort: a stale draft is overwritten by the next edit anyway.
+    let _ = fs_client.remove_file(path.to_path_buf()).await;
+
+    if !status.success() {
+        return Err(ExternalEditorError::Exit {
+            editor: editor.to_string(),
+            status,
+        });
+    }
+
+    let edited_bytes = edited_bytes.map_err(|source| ExternalEditorError::Fs {
+        context: "Failed to read editor draft",
+        source,
+    })?;
+
+    Ok(normalize_edited_text(&String::from_utf8_lossy(
+        &edited_bytes,
+    )))
+}
+
+/// Drops the single trailing newline most editors append on save.
+fn normalize_edited_text(text: &str) -> String {
+    text.strip_suffix("\r\n")
+        .or_else(|| text.strip_suffix('\n'))
+        .unwrap_or(text)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::infra::fs::RealFsClient;
+
+    #[test]
+    fn test_resolve_editor_command_prefers_visual_then_editor() {
+        // Arrange
+        let visual = Some("code --wait".to_string());
+        let editor = Some("vim".to_string());
+
+        // Act
+        let with_visual = resolve_editor_command(visual, editor.clone());
+        let with_blank_visual = resolve_editor_command(Some("  ".to_string()), editor);
+        let without_any = resolve_editor_command(None, None);
+
+        // Assert
+        assert_eq!(with_visual, "code --wait");
+        assert_eq!(with_blank_visual, "vim");
+        assert_eq!(without_any, FALLBACK_EDITOR);
+    }
+
+    #[test]
+    fn test_normalize_edited_text_drops_one_trailing_newline() {
+        // Arrange
+        let text = "First\n\nSecond\n\n";
+
+        // Act
+        let normalized = normalize_edited_text(text);
+
+        // Assert
+        assert_eq!(normalized, "First\n\nSecond\n");
+    }
+
+    #[tokio::test]
+    async fn test_edit_text_with_editor_returns_saved_text_and_removes_draft() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        let path = temp_dir.path().join("session").join(PROMPT_FILE_NAME);
+        let editor = "sed -i.bak 's/draft/final/'";
+
+        // Act
+        let edited = edit_text_with_editor(&RealFsClient, &path, editor, "A draft [Image #1]\n")
+            .await
+            .expect("edit should succeed");
+
+        // Assert
+        assert_eq!(edited, "A final [Image #1]");
+        assert!(!path.exists());
+    }
+
+    #[tokio::test]
+    async fn test_edit_text_with_editor_reports_failed_editor_exit() {
+        // Arrange
+        let temp_dir = tempdir().expect("create temp dir");
+        let path = temp_dir.path().join(PROMPT_FILE_NAME);
+
+        // Act
+        let result = edit_text_with_editor(&RealFsClient, &path, "false", "Draft").await;
+
+        // Assert
+        assert!(matches!(result, Err(ExternalEditorError::Exit { .. })));
+        assert!(!path.exists());
+    }
+}
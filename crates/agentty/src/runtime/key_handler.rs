@@ -3,14 +3,18 @@ use std::io;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use ratatui::Terminal;
 use ratatui::backend::Backend;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::Rect;
 
-use crate::app::{App, ReviewCacheEntry, diff_content_hash, review_loading_message};
-use crate::domain::session::SessionId;
+use crate::app::{
+    App, ReviewCacheEntry, ReviewRequestDraft, diff_content_hash, review_loading_message,
+};
+use crate::domain::session::{SessionId, Status};
 use crate::runtime::mode::confirmation::ConfirmationDecision;
 use crate::runtime::{EventResult, backend_err, mode};
+use crate::ui::layout;
 use crate::ui::state::app_mode::{
     AppMode, ConfirmationIntent, ConfirmationViewMode, DoneSessionOutputMode,
+    ReviewRequestDraftFocus, ReviewRequestDraftState, ReviewRequestDraftStatus,
 };
 
 /// Routes key events to the active mode handler and returns the next runtime
@@ -44,6 +48,9 @@ where
     match &app.mode {
         AppMode::List => mode::list::handle(app, key).await,
         AppMode::SyncBlockedPopup { .. } => Ok(mode::sync_blocked::handle(app, key)),
+        AppMode::IssuePicker { .. } => Ok(mode::issue_picker::handle(app, key)),
+        AppMode::PlanReview { .. } => Ok(mode::plan_review::handle(app, key).await),
+        AppMode::SessionSearch { .. } => mode::session_search::handle(app, terminal, key).await,
         AppMode::ViewInfoPopup { .. } => Ok(handle_view_info_popup_key(app, key)),
         AppMode::Confirmation { .. } => {
             unreachable!("confirmation mode is handled before dispatch matching")
@@ -52,7 +59,8 @@ where
         AppMode::Prompt { .. } => mode::prompt::handle(app, terminal, key).await,
         AppMode::Question { .. } => {
             let size = terminal.size().map_err(backend_err)?;
-            let terminal_rect = Rect::new(0, 0, size.width, size.height);
+            let terminal_rect =
+                session_chat_terminal_rect(app, Rect::new(0, 0, size.width, size.height));
 
             Ok(mode::question::handle(app, terminal_rect, key).await)
         }
@@ -75,16 +83,26 @@ where
 
 /// Returns the central content area after removing the global status and
 /// footer bars from the full terminal rectangle.
-fn content_area_for_terminal(terminal_rect: Rect) -> Rect {
-    let outer_chunks = Layout::default()
-        .constraints([
-            Constraint::Length(1),
-            Constraint::Min(0),
-            Constraint::Length(1),
-        ])
-        .split(terminal_rect);
-
-    outer_chunks[1]
+pub(crate) fn content_area_for_terminal(terminal_rect: Rect) -> Rect {
+    layout::frame_areas(terminal_rect).content_area
+}
+
+/// Returns the content-area region drawn by session chat modes: the right
+/// pane when the split layout applies, otherwise the whole content area.
+pub(crate) fn session_chat_area(app: &App, content_area: Rect) -> Rect {
+    layout::split_areas(content_area, app.settings.split_layout())
+        .map_or(content_area, |split_areas| split_areas.session_area)
+}
+
+/// Returns the terminal rectangle narrowed to the session chat pane width so
+/// transcript wrapping and scroll math match the rendered pane.
+pub(crate) fn session_chat_terminal_rect(app: &App, terminal_rect: Rect) -> Rect {
+    let content_area = content_area_for_terminal(terminal_rect);
+
+    Rect {
+        width: session_chat_area(app, content_area).width,
+        ..terminal_rect
+    }
 }
 
 /// Handles key input while a session-scoped informational popup is visible.
@@ -118,24 +136,42 @@ fn handle_view_info_popup_key(app: &mut App, key: KeyEvent) -> EventResult {
 /// Handles key input while the publish-branch input overlay is visible.
 ///
 /// Only `Esc` cancels the overlay. Plain character keys continue to edit the
-/// branch name so session-view shortcuts like `q` and `p` do not leak through
-/// while the text field has focus.
+/// focused field so session-view shortcuts like `q` and `p` do not leak
+/// through while a text field has focus. When a review-request template
+/// preview is present, `Tab` cycles between the branch, title, and body
+/// fields, `Alt+Enter` or `Shift+Enter` inserts a body newline, and `Enter`
+/// waits until template generation finishes.
 fn handle_publish_branch_input_key(app: &mut App, key: KeyEvent) -> EventResult {
-    let publish_branch_input =
+    let mut publish_branch_input =
         PublishBranchInputModeState::from_mode(std::mem::replace(&mut app.mode, AppMode::List));
-    let input_locked = publish_branch_input.locked_upstream_ref.is_some();
+    let input_locked = publish_branch_input.is_focused_input_locked();
 
     match key.code {
         KeyCode::Esc => {
             app.mode = publish_branch_input.restore_view.into_view_mode();
         }
+        KeyCode::Tab | KeyCode::BackTab => {
+            publish_branch_input.cycle_focus(key.code == KeyCode::BackTab);
+            app.mode = publish_branch_input.into_mode();
+        }
+        KeyCode::Enter
+            if publish_branch_input.is_body_focused()
+                && mode::input_key::should_insert_newline(key) =>
+        {
+            app.mode = publish_branch_input
+                .apply_input_edit(crate::domain::input::InputState::insert_newline);
+        }
+        KeyCode::Enter if publish_branch_input.is_draft_loading() => {
+            app.mode = publish_branch_input.into_mode();
+        }
         KeyCode::Enter => {
-            let remote_branch_name = if input_locked {
+            let remote_branch_name = if publish_branch_input.locked_upstream_ref.is_some() {
                 Some(publish_branch_input.input.text().trim().to_string())
             } else {
                 (!publish_branch_input.input.text().trim().is_empty())
                     .then(|| publish_branch_input.input.text().trim().to_string())
             };
+            let review_request_draft = publish_branch_input.confirmed_review_request_draft();
             let session_id = publish_branch_input.restore_view.session_id.clone();
 
             app.start_publish_branch_action(
@@ -143,6 +179,7 @@ fn handle_publish_branch_input_key(app: &mut App, key: KeyEvent) -> EventResult
                 &session_id,
                 publish_branch_input.publish_branch_action,
                 remote_branch_name,
+                review_request_draft,
             );
         }
         KeyCode::Left if !input_locked => {
@@ -205,6 +242,7 @@ struct PublishBranchInputModeState {
     locked_upstream_ref: Option<String>,
     publish_branch_action: crate::domain::session::PublishBranchAction,
     restore_view: ConfirmationViewMode,
+    review_request_draft: Option<ReviewRequestDraftState>,
 }
 
 impl PublishBranchInputModeState {
@@ -216,6 +254,7 @@ impl PublishBranchInputModeState {
             locked_upstream_ref,
             publish_branch_action,
             restore_view,
+            review_request_draft,
         } = mode
         else {
             unreachable!("mode must be publish-branch input in this handler");
@@ -227,15 +266,86 @@ impl PublishBranchInputModeState {
             locked_upstream_ref,
             publish_branch_action,
             restore_view,
+            review_request_draft,
         }
     }
 
-    /// Applies one input edit and rebuilds the publish-branch overlay mode.
+    /// Returns the field that currently receives text edits.
+    fn focus(&self) -> ReviewRequestDraftFocus {
+        self.review_request_draft
+            .as_ref()
+            .map_or(ReviewRequestDraftFocus::Branch, |review_request_draft| {
+                review_request_draft.focus
+            })
+    }
+
+    /// Returns whether the focused field rejects edits because it is the
+    /// locked remote branch field.
+    fn is_focused_input_locked(&self) -> bool {
+        self.focus() == ReviewRequestDraftFocus::Branch && self.locked_upstream_ref.is_some()
+    }
+
+    /// Returns whether the multiline review-request body has focus.
+    fn is_body_focused(&self) -> bool {
+        self.focus() == ReviewRequestDraftFocus::Body
+    }
+
+    /// Returns whether the review-request template is still being filled.
+    fn is_draft_loading(&self) -> bool {
+        self.review_request_draft
+            .as_ref()
+            .is_some_and(|review_request_draft| {
+                review_request_draft.status == ReviewRequestDraftStatus::Loading
+            })
+    }
+
+    /// Moves focus to the next or previous editable field.
+    fn cycle_focus(&mut self, is_reverse: bool) {
+        let is_branch_locked = self.locked_upstream_ref.is_some();
+        if let Some(review_request_draft) = &mut self.review_request_draft {
+            review_request_draft.focus =
+                review_request_draft.cycled_focus(is_branch_locked, is_reverse);
+        }
+    }
+
+    /// Returns the edited review-request title and body when the template
+    /// draft is ready and keeps a non-empty title.
+    fn confirmed_review_request_draft(&self) -> Option<ReviewRequestDraft> {
+        let review_request_draft = self
+            .review_request_draft
+            .as_ref()
+            .filter(|review_request_draft| review_request_draft.is_ready())?;
+        let title = review_request_draft.title.text().trim();
+        if title.is_empty() {
+            return None;
+        }
+        let body = review_request_draft.body.text().trim();
+
+        Some(ReviewRequestDraft {
+            body: (!body.is_empty()).then(|| body.to_string()),
+            title: title.to_string(),
+        })
+    }
+
+    /// Applies one input edit to the focused field and rebuilds the
+    /// publish-branch overlay mode.
     fn apply_input_edit(
         mut self,
         edit: impl FnOnce(&mut crate::domain::input::InputState),
     ) -> AppMode {
-        edit(&mut self.input);
+        match self.review_request_draft.as_mut() {
+            Some(review_request_draft)
+                if review_request_draft.focus == ReviewRequestDraftFocus::Title =>
+            {
+                edit(&mut review_request_draft.title);
+            }
+            Some(review_request_draft)
+                if review_request_draft.focus == ReviewRequestDraftFocus::Body =>
+            {
+                edit(&mut review_request_draft.body);
+            }
+            _ => edit(&mut self.input),
+        }
 
         self.into_mode()
     }
@@ -248,6 +358,7 @@ impl PublishBranchInputModeState {
             locked_upstream_ref: self.locked_upstream_ref,
             publish_branch_action: self.publish_branch_action,
             restore_view: self.restore_view,
+            review_request_draft: self.review_request_draft,
         }
     }
 }
@@ -383,6 +494,9 @@ async fn handle_confirmation_confirm(app: &mut App) -> io::Result<EventResult> {
         ConfirmationIntent::CancelSession => {
             handle_cancel_session_confirmation(app, confirmation_session_id).await
         }
+        ConfirmationIntent::StopSession => {
+            handle_stop_session_confirmation(app, confirmation_session_id).await
+        }
         ConfirmationIntent::MergeSession => {
             handle_merge_confirmation(app, confirmation_session_id, restore_view).await
         }
@@ -408,6 +522,27 @@ async fn handle_cancel_session_confirmation(
     Ok(EventResult::Continue)
 }
 
+/// Stops the confirmed session's running turn, when it is still in progress,
+/// and returns to list mode.
+async fn handle_stop_session_confirmation(
+    app: &mut App,
+    confirmation_session_id: Option<SessionId>,
+) -> io::Result<EventResult> {
+    app.mode = AppMode::List;
+
+    if let Some(session_id) = confirmation_session_id
+        && app
+            .sessions
+            .sessions
+            .iter()
+            .any(|session| session.id == session_id && session.status == Status::InProgress)
+    {
+        mode::session_view::end_in_progress_turn(app, &session_id).await;
+    }
+
+    Ok(EventResult::Continue)
+}
+
 /// Restores view mode and attempts to add confirmed session to merge queue.
 async fn handle_merge_confirmation(
     app: &mut App,
@@ -741,6 +876,37 @@ mod tests {
         assert!(matches!(app.mode, AppMode::List));
     }
 
+    #[tokio::test]
+    async fn test_handle_confirmation_decision_confirm_stops_running_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app_with_git().await;
+        let session_id = app
+            .create_session()
+            .await
+            .expect("failed to create session");
+        set_session_status_for_test(&mut app, &session_id, Status::InProgress);
+        app.mode = AppMode::Confirmation {
+            confirmation_intent: ConfirmationIntent::StopSession,
+            confirmation_message: "Stop session \"test\"?".to_string(),
+            confirmation_title: "Confirm Stop".to_string(),
+            restore_view: None,
+            session_id: Some(session_id.clone().into()),
+            selected_confirmation_index: 0,
+        };
+
+        // Act
+        let event_result =
+            handle_confirmation_decision(&mut app, ConfirmationDecision::Confirm).await;
+
+        // Assert
+        assert!(matches!(event_result, Ok(EventResult::Continue)));
+        assert!(matches!(app.mode, AppMode::List));
+        assert!(matches!(
+            app.sessions.sessions.first(),
+            Some(session) if session.id == session_id && session.status == Status::Review
+        ));
+    }
+
     #[tokio::test]
     async fn test_handle_confirmation_decision_confirm_cancels_session_when_context_exists() {
         // Arrange
@@ -815,6 +981,7 @@ mod tests {
                 review_text: Some(ref review_text),
                 session_id: ref session_id_in_mode,
                 scroll_offset: Some(6),
+                ..
             } if session_id_in_mode == &session_id
                 && review_status_message == &review_loading_message(AgentModel::Gpt54)
                 && review_text == "Review output"
@@ -858,6 +1025,7 @@ mod tests {
                 review_text: None,
                 session_id: ref session_id_in_mode,
                 scroll_offset: Some(2),
+                ..
             } if session_id_in_mode == &session_id
         ));
         app.sessions.sync_from_handles();
@@ -881,6 +1049,7 @@ mod tests {
                 scroll_offset: Some(7),
                 session_id: "session-id".into(),
             },
+            review_request_draft: None,
         };
 
         // Act
@@ -899,6 +1068,7 @@ mod tests {
                 review_text: Some(ref review_text),
                 ref session_id,
                 scroll_offset: Some(7),
+                ..
             } if session_id == "session-id"
                 && status_message == &review_loading_message(AgentModel::Gpt54)
                 && review_text == "Critical finding"
@@ -930,6 +1100,7 @@ mod tests {
                 scroll_offset: Some(4),
                 session_id: session_id.into(),
             },
+            review_request_draft: None,
         };
 
         // Act
@@ -968,6 +1139,7 @@ mod tests {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_draft: None,
         };
 
         // Act
@@ -1010,6 +1182,7 @@ mod tests {
                     scroll_offset: None,
                     session_id: "session-id".into(),
                 },
+                review_request_draft: None,
             };
             let modifiers = if character.is_ascii_uppercase() || character == '?' {
                 KeyModifiers::SHIFT
@@ -1051,6 +1224,7 @@ mod tests {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_draft: None,
         };
 
         // Act
@@ -1084,6 +1258,7 @@ mod tests {
                 scroll_offset: None,
                 session_id: "session-id".into(),
             },
+            review_request_draft: None,
         };
 
         // Act
@@ -1106,6 +1281,100 @@ mod tests {
         assert_eq!(input.text(), "review/custom");
     }
 
+    #[tokio::test]
+    async fn test_handle_publish_branch_input_key_enter_waits_for_review_request_draft() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::PublishBranchInput {
+            default_branch_name: "wt/session".to_string(),
+            input: crate::domain::input::InputState::default(),
+            locked_upstream_ref: None,
+            publish_branch_action: crate::domain::session::PublishBranchAction::PublishPullRequest,
+            restore_view: ConfirmationViewMode {
+                done_session_output_mode: DoneSessionOutputMode::Summary,
+                review_status_message: None,
+                review_text: None,
+                scroll_offset: None,
+                session_id: "session-id".into(),
+            },
+            review_request_draft: Some(ReviewRequestDraftState::loading(
+                ".github/pull_request_template.md".to_string(),
+            )),
+        };
+
+        // Act
+        let event_result = handle_publish_branch_input_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert!(matches!(event_result, EventResult::Continue));
+        assert!(matches!(
+            app.mode,
+            AppMode::PublishBranchInput {
+                review_request_draft: Some(ReviewRequestDraftState {
+                    status: ReviewRequestDraftStatus::Loading,
+                    ..
+                }),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_publish_branch_input_key_tab_edits_review_request_draft_fields() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::PublishBranchInput {
+            default_branch_name: "wt/session".to_string(),
+            input: crate::domain::input::InputState::default(),
+            locked_upstream_ref: None,
+            publish_branch_action: crate::domain::session::PublishBranchAction::PublishPullRequest,
+            restore_view: ConfirmationViewMode {
+                done_session_output_mode: DoneSessionOutputMode::Summary,
+                review_status_message: None,
+                review_text: None,
+                scroll_offset: None,
+                session_id: "session-id".into(),
+            },
+            review_request_draft: Some(ReviewRequestDraftState {
+                body: crate::domain::input::InputState::with_text("Body".to_string()),
+                focus: ReviewRequestDraftFocus::Branch,
+                status: ReviewRequestDraftStatus::Ready,
+                template_path: ".github/pull_request_template.md".to_string(),
+                title: crate::domain::input::InputState::with_text("Title".to_string()),
+            }),
+        };
+
+        // Act
+        handle_publish_branch_input_key(&mut app, KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        handle_publish_branch_input_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('!'), KeyModifiers::SHIFT),
+        );
+        handle_publish_branch_input_key(&mut app, KeyEvent::new(KeyCode::Tab, KeyModifiers::NONE));
+        handle_publish_branch_input_key(&mut app, KeyEvent::new(KeyCode::Enter, KeyModifiers::ALT));
+        handle_publish_branch_input_key(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE),
+        );
+
+        // Assert
+        let AppMode::PublishBranchInput {
+            input,
+            review_request_draft: Some(review_request_draft),
+            ..
+        } = &app.mode
+        else {
+            unreachable!("mode should remain publish-branch input");
+        };
+        assert!(input.is_empty());
+        assert_eq!(review_request_draft.focus, ReviewRequestDraftFocus::Body);
+        assert_eq!(review_request_draft.title.text(), "Title!");
+        assert_eq!(review_request_draft.body.text(), "Body\nx");
+    }
+
     #[test]
     fn test_next_open_command_index_wraps_to_start() {
         // Arrange
@@ -1163,6 +1432,7 @@ mod tests {
                 review_text: Some(ref review_text),
                 ref session_id,
                 scroll_offset: Some(3),
+                ..
             } if session_id == "session-id"
                 && status_message == &review_loading_message(AgentModel::Gpt54)
                 && review_text == "Critical finding"
@@ -1270,6 +1540,7 @@ mod tests {
                 review_text: None,
                 ref session_id,
                 scroll_offset: Some(4),
+                ..
             } if session_id == "session-id"
         ));
     }
@@ -1322,6 +1593,7 @@ mod tests {
                 review_text: None,
                 ref session_id,
                 scroll_offset: Some(2),
+                ..
             } if session_id == &expected_session_id
         ));
     }
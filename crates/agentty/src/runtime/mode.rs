@@ -5,8 +5,11 @@ pub(crate) mod confirmation;
 pub(crate) mod diff;
 pub(crate) mod help;
 pub(crate) mod input_key;
+pub(crate) mod issue_picker;
 pub(crate) mod list;
+pub(crate) mod plan_review;
 pub(crate) mod prompt;
 pub(crate) mod question;
+pub(crate) mod session_search;
 pub(crate) mod session_view;
 pub(crate) mod sync_blocked;
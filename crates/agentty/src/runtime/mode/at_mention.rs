@@ -11,6 +11,7 @@ use crate::app::AppEvent;
 use crate::domain::input::InputState;
 use crate::domain::session::SessionId;
 use crate::infra::file_index::{self, FileEntry};
+use crate::infra::symbol_index::{self, SymbolEntry, SymbolIndex};
 use crate::ui::state::prompt::PromptAtMentionState;
 
 /// Delay applied before a fresh `@`-mention filesystem walk starts.
@@ -69,6 +70,9 @@ pub(crate) fn sync_action(
 }
 
 /// Starts asynchronous loading of `@`-mention entries for one composer root.
+///
+/// File entries are published first so path completion opens quickly; the
+/// slower `@#` symbol index follows in a second event from the same task.
 pub(crate) fn start_loading_entries(
     event_tx: mpsc::UnboundedSender<AppEvent>,
     lookup_root: PathBuf,
@@ -80,14 +84,27 @@ pub(crate) fn start_loading_entries(
     let handle = tokio::spawn(async move {
         tokio::time::sleep(AT_MENTION_LOAD_DEBOUNCE).await;
 
-        let entries = tokio::task::spawn_blocking(move || file_index::list_files(&lookup_root))
-            .await
-            .unwrap_or_default();
+        let file_lookup_root = lookup_root.clone();
+        let entries =
+            tokio::task::spawn_blocking(move || file_index::list_files(&file_lookup_root))
+                .await
+                .unwrap_or_default();
 
         // Fire-and-forget: receiver may be dropped during shutdown.
         let _ = event_tx.send(AppEvent::AtMentionEntriesLoaded {
             entries,
+            session_id: session_id.clone(),
+        });
+
+        let symbol_index =
+            tokio::task::spawn_blocking(move || symbol_index::build_symbol_index(&lookup_root))
+                .await
+                .unwrap_or_default();
+
+        // Fire-and-forget: receiver may be dropped during shutdown.
+        let _ = event_tx.send(AppEvent::AtMentionSymbolsLoaded {
             session_id,
+            symbol_index,
         });
 
         finish_pending_load(&task_session_id, request_id);
@@ -145,7 +162,7 @@ pub(crate) fn lookup_root(
 }
 
 /// Clears one visible `@`-mention dropdown state.
-pub(crate) fn dismiss(at_mention_state: &mut Option<PromptAtMentionState>) {
+pub(crate) fn dismiss(at_mention_state: &mut Option<Box<PromptAtMentionState>>) {
     *at_mention_state = None;
 }
 
@@ -161,8 +178,7 @@ pub(crate) fn move_selection_up(at_mention_state: &mut PromptAtMentionState) {
 
 /// Moves the highlighted `@`-mention row down by one filtered item.
 pub(crate) fn move_selection_down(input: &InputState, at_mention_state: &mut PromptAtMentionState) {
-    let filtered_count =
-        filtered_entries(input, at_mention_state).map_or(0_usize, |entries| entries.len());
+    let filtered_count = filtered_count(input, at_mention_state);
     let max_index = filtered_count.saturating_sub(1);
 
     at_mention_state.selected_index = (at_mention_state.selected_index + 1).min(max_index);
@@ -170,11 +186,32 @@ pub(crate) fn move_selection_down(input: &InputState, at_mention_state: &mut Pro
 
 /// Returns the replacement text for the highlighted `@`-mention entry, if the
 /// input still contains an active `@query`.
+///
+/// `@#` symbol queries insert a `path:line` anchor. When `include_snippet` is
+/// `true`, the symbol's declaration source follows the anchor in a fenced
+/// code block; file and directory entries ignore the flag.
 pub(crate) fn selected_replacement(
     input: &InputState,
     at_mention_state: &PromptAtMentionState,
+    include_snippet: bool,
 ) -> Option<AtMentionSelection> {
     let (at_start, query) = input.at_mention_query()?;
+    if let Some(symbol_query) = symbol_index::symbol_query(&query) {
+        let index = at_mention_state.symbol_index.as_ref()?;
+        let filtered = symbol_index::filter_symbols(&index.symbols, symbol_query);
+        let clamped_index = at_mention_state
+            .selected_index
+            .min(filtered.len().saturating_sub(1));
+
+        return filtered
+            .get(clamped_index)
+            .map(|symbol| AtMentionSelection {
+                at_start,
+                cursor: input.cursor,
+                text: format_symbol_mention_text(index, symbol, include_snippet),
+            });
+    }
+
     let filtered = file_index::filter_entries(&at_mention_state.all_entries, &query);
     let clamped_index = at_mention_state
         .selected_index
@@ -187,17 +224,19 @@ pub(crate) fn selected_replacement(
     })
 }
 
-/// Returns the filtered `@`-mention entries for the current input query.
-fn filtered_entries<'a>(
-    input: &InputState,
-    at_mention_state: &'a PromptAtMentionState,
-) -> Option<Vec<&'a FileEntry>> {
-    let (_, query) = input.at_mention_query()?;
-
-    Some(file_index::filter_entries(
-        &at_mention_state.all_entries,
-        &query,
-    ))
+/// Returns how many `@`-mention rows match the current input query.
+fn filtered_count(input: &InputState, at_mention_state: &PromptAtMentionState) -> usize {
+    let Some((_, query)) = input.at_mention_query() else {
+        return 0;
+    };
+
+    if let Some(symbol_query) = symbol_index::symbol_query(&query) {
+        return at_mention_state.symbol_index.as_ref().map_or(0, |index| {
+            symbol_index::filter_symbols(&index.symbols, symbol_query).len()
+        });
+    }
+
+    file_index::filter_entries(&at_mention_state.all_entries, &query).len()
 }
 
 /// Formats one selected file or directory entry for insertion into the input.
@@ -209,6 +248,26 @@ fn format_mention_text(entry: &FileEntry) -> String {
     format!("@{} ", entry.path)
 }
 
+/// Formats one selected symbol as a `path:line` anchor, optionally followed
+/// by its declaration source in a fenced code block.
+fn format_symbol_mention_text(
+    index: &SymbolIndex,
+    symbol: &SymbolEntry,
+    include_snippet: bool,
+) -> String {
+    let anchor = format!("@{} ", symbol.anchor());
+    if !include_snippet {
+        return anchor;
+    }
+
+    let Some(snippet) = index.snippet(symbol) else {
+        return anchor;
+    };
+    let language = symbol.path.rsplit('.').next().unwrap_or_default();
+
+    format!("{}\n```{language}\n{snippet}\n```\n", anchor.trim_end())
+}
+
 #[cfg(test)]
 mod tests {
     use tempfile::TempDir;
@@ -260,8 +319,8 @@ mod tests {
         }]);
 
         // Act
-        let selection =
-            selected_replacement(&input, &at_mention_state).expect("expected directory selection");
+        let selection = selected_replacement(&input, &at_mention_state, false)
+            .expect("expected directory selection");
 
         // Assert
         assert_eq!(
@@ -274,6 +333,69 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_selected_replacement_inserts_symbol_anchor() {
+        // Arrange
+        let input = InputState::with_text("@#merge".to_string());
+        let mut at_mention_state = PromptAtMentionState::new(Vec::new());
+        at_mention_state.symbol_index = Some(SymbolIndex {
+            root: PathBuf::from("/project"),
+            symbols: vec![SymbolEntry {
+                kind: symbol_index::SymbolKind::Function,
+                line: 42,
+                name: "SessionManager::merge_session".to_string(),
+                path: "src/session.rs".to_string(),
+            }],
+        });
+
+        // Act
+        let selection = selected_replacement(&input, &at_mention_state, false)
+            .expect("expected symbol selection");
+
+        // Assert
+        assert_eq!(selection.text, "@src/session.rs:42 ");
+    }
+
+    #[test]
+    fn test_selected_replacement_appends_symbol_snippet() {
+        // Arrange
+        let temp_dir = TempDir::new().expect("create temp dir");
+        std::fs::write(
+            temp_dir.path().join("lib.rs"),
+            "fn merge() {\n    done();\n}\n",
+        )
+        .expect("write source file");
+        let input = InputState::with_text("@#merge".to_string());
+        let mut at_mention_state = PromptAtMentionState::new(Vec::new());
+        at_mention_state.symbol_index = Some(symbol_index::build_symbol_index(temp_dir.path()));
+
+        // Act
+        let selection = selected_replacement(&input, &at_mention_state, true)
+            .expect("expected symbol selection");
+
+        // Assert
+        assert_eq!(
+            selection.text,
+            "@lib.rs:1\n```rs\nfn merge() {\n    done();\n}\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_move_selection_down_counts_symbols_for_symbol_query() {
+        // Arrange
+        let input = InputState::with_text("@#".to_string());
+        let mut at_mention_state = PromptAtMentionState::new(vec![FileEntry {
+            is_dir: false,
+            path: "src/lib.rs".to_string(),
+        }]);
+
+        // Act
+        move_selection_down(&input, &mut at_mention_state);
+
+        // Assert
+        assert_eq!(at_mention_state.selected_index, 0);
+    }
+
     #[test]
     fn test_lookup_root_prefers_materialized_session_folder() {
         // Arrange
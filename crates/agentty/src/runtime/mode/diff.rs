@@ -1,38 +1,67 @@
-use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::KeyEvent;
 use ratatui::layout::Rect;
 
 use crate::app::App;
 use crate::runtime::EventResult;
 use crate::ui::component::file_explorer::FileExplorer;
 use crate::ui::state::app_mode::{AppMode, DiffScrollCache, DoneSessionOutputMode, HelpContext};
+use crate::ui::state::keymap::{self, KeyAction, KeymapMode};
 use crate::ui::util::{diff_view_max_scroll_offset, parse_diff_lines, selected_diff_lines};
 
 /// Handles key input while the app is in `AppMode::Diff`.
 ///
-/// File selection via `j`/`k` wraps around between the first and last file
-/// explorer entries. Leaving diff mode restores the prior question snapshot
-/// when present; otherwise it rebuilds session view with any cached focused
-/// review output for the same session.
+/// Keys resolve through the active keymap. File selection (`j`/`k` by
+/// default) wraps around between the first and last file explorer entries.
+/// Leaving diff mode restores the prior question snapshot when present;
+/// otherwise it rebuilds session view with any cached focused review output for
+/// the same session.
 pub(crate) fn handle(app: &mut App, content_area: Rect, key: KeyEvent) -> EventResult {
-    if handle_help_key(app, key) {
+    let Some(action) = keymap::active().action(KeymapMode::Diff, key) else {
         return EventResult::Continue;
-    }
+    };
 
-    if handle_exit_key(app, key) {
-        return EventResult::Continue;
+    match action {
+        KeyAction::Help => open_help(app),
+        KeyAction::Back => exit_diff(app),
+        _ => handle_navigation_action(app, content_area, action),
     }
 
-    handle_navigation_key(app, content_area, key);
-
     EventResult::Continue
 }
 
-/// Opens diff help while preserving the current diff-mode snapshot.
-fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
-    if key.code != KeyCode::Char('?') {
-        return false;
+/// Scrolls the selected file diff by `step` lines for `KeyAction::Down` and
+/// `KeyAction::Up`, or moves the file selection once for
+/// `KeyAction::NextFile` and `KeyAction::PreviousFile`.
+pub(crate) fn scroll(app: &mut App, content_area: Rect, action: KeyAction, step: u16) {
+    let repeat_count = match action {
+        KeyAction::Down | KeyAction::Up => step,
+        _ => 1,
+    };
+
+    for _ in 0..repeat_count {
+        handle_navigation_action(app, content_area, action);
     }
+}
 
+/// Selects the file tree item at `index`, resetting the diff scroll when the
+/// selection changes.
+pub(crate) fn select_file(app: &mut App, index: usize) {
+    if let AppMode::Diff {
+        file_explorer_selected_index,
+        scroll_cache,
+        scroll_offset,
+        ..
+    } = &mut app.mode
+        && *file_explorer_selected_index != index
+    {
+        *file_explorer_selected_index = index;
+        *scroll_cache = None;
+        *scroll_offset = 0;
+    }
+}
+
+/// Opens diff help while preserving the current diff-mode snapshot.
+fn open_help(app: &mut App) {
     let mode = std::mem::replace(&mut app.mode, AppMode::List);
     if let AppMode::Diff {
         diff,
@@ -56,16 +85,10 @@ fn handle_help_key(app: &mut App, key: KeyEvent) -> bool {
     } else {
         app.mode = mode;
     }
-
-    true
 }
 
 /// Leaves diff mode and restores the originating view or question state.
-fn handle_exit_key(app: &mut App, key: KeyEvent) -> bool {
-    if !matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) {
-        return false;
-    }
-
+fn exit_diff(app: &mut App) {
     let mode = std::mem::replace(&mut app.mode, AppMode::List);
     if let AppMode::Diff {
         restore_question,
@@ -84,17 +107,16 @@ fn handle_exit_key(app: &mut App, key: KeyEvent) -> bool {
                 review_text,
                 session_id,
                 scroll_offset: None,
+                transcript_search: None,
             }
         };
     } else {
         app.mode = mode;
     }
-
-    true
 }
 
-/// Applies file-selection and scroll navigation keys in diff mode.
-fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
+/// Applies file-selection and scroll navigation actions in diff mode.
+fn handle_navigation_action(app: &mut App, content_area: Rect, action: KeyAction) {
     if let AppMode::Diff {
         diff,
         file_explorer_selected_index,
@@ -103,8 +125,8 @@ fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
         ..
     } = &mut app.mode
     {
-        match key.code {
-            KeyCode::Char('j') if is_plain_char_key(key, 'j') => {
+        match action {
+            KeyAction::NextFile => {
                 let parsed = parse_diff_lines(diff);
                 let count = FileExplorer::count_items(&parsed);
                 let new_index =
@@ -116,7 +138,7 @@ fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
                     *scroll_offset = 0;
                 }
             }
-            KeyCode::Char('k') if is_plain_char_key(key, 'k') => {
+            KeyAction::PreviousFile => {
                 let parsed = parse_diff_lines(diff);
                 let count = FileExplorer::count_items(&parsed);
                 let new_index =
@@ -128,9 +150,7 @@ fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
                     *scroll_offset = 0;
                 }
             }
-            KeyCode::Down | KeyCode::Char('J' | 'j')
-                if key.code == KeyCode::Down || is_shift_char_key(key, 'j') =>
-            {
+            KeyAction::Down => {
                 let max_scroll_offset = diff_max_scroll_offset(
                     diff,
                     content_area,
@@ -143,9 +163,7 @@ fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
                     .saturating_add(1)
                     .min(max_scroll_offset);
             }
-            KeyCode::Up | KeyCode::Char('K' | 'k')
-                if key.code == KeyCode::Up || is_shift_char_key(key, 'k') =>
-            {
+            KeyAction::Up => {
                 let max_scroll_offset = diff_max_scroll_offset(
                     diff,
                     content_area,
@@ -161,26 +179,6 @@ fn handle_navigation_key(app: &mut App, content_area: Rect, key: KeyEvent) {
     }
 }
 
-/// Returns true when the key event is a plain character key with no
-/// modifiers.
-fn is_plain_char_key(key: KeyEvent, character: char) -> bool {
-    key.code == KeyCode::Char(character) && key.modifiers == KeyModifiers::NONE
-}
-
-/// Returns true when the key event is a shifted character key, accepting both
-/// uppercase and lowercase char payloads emitted by terminals.
-fn is_shift_char_key(key: KeyEvent, character: char) -> bool {
-    let lowercase_character = character.to_ascii_lowercase();
-    let uppercase_character = character.to_ascii_uppercase();
-
-    key.modifiers == KeyModifiers::SHIFT
-        && matches!(
-            key.code,
-            KeyCode::Char(pressed)
-                if pressed == lowercase_character || pressed == uppercase_character
-        )
-}
-
 /// Returns the max valid scroll offset for the active diff selection.
 fn diff_max_scroll_offset(
     diff: &str,
@@ -211,7 +209,7 @@ fn diff_max_scroll_offset(
 
 #[cfg(test)]
 mod tests {
-    use crossterm::event::KeyModifiers;
+    use crossterm::event::{KeyCode, KeyModifiers};
     use ratatui::layout::Rect;
     use tempfile::tempdir;
 
@@ -705,7 +703,7 @@ mod tests {
     async fn test_handle_quit_with_question_snapshot_restores_question_mode() {
         // Arrange — diff opened from question mode carries a snapshot.
         use crate::domain::input::InputState;
-        use crate::infra::agent::protocol::QuestionItem;
+        use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
         use crate::ui::state::app_mode::QuestionModeSnapshot;
 
         let (mut app, _base_dir) = new_test_app().await;
@@ -716,9 +714,11 @@ mod tests {
             file_explorer_selected_index: 0,
             restore_question: Some(QuestionModeSnapshot {
                 at_mention_state: None,
+                checked_option_indexes: Vec::new(),
                 current_index: 0,
                 input: InputState::default(),
                 questions: vec![QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: Vec::new(),
                     text: "Q?".to_string(),
                 }],
@@ -755,20 +755,23 @@ mod tests {
     async fn test_handle_question_then_help_then_exit_preserves_restore_question() {
         // Arrange — diff opened from question mode, then user opens help with `?`.
         use crate::domain::input::InputState;
-        use crate::infra::agent::protocol::QuestionItem;
+        use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
         use crate::ui::state::app_mode::QuestionModeSnapshot;
 
         let (mut app, _base_dir) = new_test_app().await;
         let snapshot = QuestionModeSnapshot {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             current_index: 1,
             input: InputState::default(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: Vec::new(),
                     text: "Q1?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: Vec::new(),
                     text: "Q2?".to_string(),
                 },
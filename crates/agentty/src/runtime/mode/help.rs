@@ -1,8 +1,9 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::KeyEvent;
 
 use crate::app::App;
 use crate::runtime::EventResult;
 use crate::ui::state::app_mode::AppMode;
+use crate::ui::state::keymap::{self, KeyAction, KeymapMode};
 
 /// Handles key input while the app is showing the help overlay.
 pub(crate) fn handle(app: &mut App, key: KeyEvent) -> EventResult {
@@ -11,17 +12,17 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> EventResult {
         context: _,
     } = &mut app.mode
     {
-        match key.code {
-            KeyCode::Char('?' | 'q') | KeyCode::Esc => {
+        match keymap::active().action(KeymapMode::Help, key) {
+            Some(KeyAction::Close) => {
                 let mode = std::mem::replace(&mut app.mode, AppMode::List);
                 if let AppMode::Help { context, .. } = mode {
                     app.mode = context.restore_mode();
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => {
+            Some(KeyAction::Down) => {
                 *scroll_offset = scroll_offset.saturating_add(1);
             }
-            KeyCode::Char('k') | KeyCode::Up => {
+            Some(KeyAction::Up) => {
                 *scroll_offset = scroll_offset.saturating_sub(1);
             }
             _ => {}
@@ -33,7 +34,7 @@ pub(crate) fn handle(app: &mut App, key: KeyEvent) -> EventResult {
 
 #[cfg(test)]
 mod tests {
-    use crossterm::event::KeyModifiers;
+    use crossterm::event::{KeyCode, KeyModifiers};
     use tempfile::tempdir;
 
     use super::*;
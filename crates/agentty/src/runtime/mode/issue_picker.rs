@@ -0,0 +1,160 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::runtime::EventResult;
+use crate::ui::state::app_mode::{AppMode, IssuePickerStatus};
+
+/// Handles key input while the forge issue picker overlay is visible.
+///
+/// `j`/`k` move the selection, `Enter` starts a session from the selected
+/// issue, and `Esc`/`q` close the picker. While the selected issue is loading
+/// only the close keys are accepted.
+pub(crate) fn handle(app: &mut App, key: KeyEvent) -> EventResult {
+    let AppMode::IssuePicker {
+        issues,
+        selected_issue_index,
+        status,
+    } = &mut app.mode
+    else {
+        return EventResult::Continue;
+    };
+
+    match key.code {
+        KeyCode::Esc => app.mode = AppMode::List,
+        KeyCode::Char(character) if character.eq_ignore_ascii_case(&'q') => {
+            app.mode = AppMode::List;
+        }
+        KeyCode::Char('j') | KeyCode::Down if *status == IssuePickerStatus::Ready => {
+            if *selected_issue_index + 1 < issues.len() {
+                *selected_issue_index += 1;
+            }
+        }
+        KeyCode::Char('k') | KeyCode::Up if *status == IssuePickerStatus::Ready => {
+            *selected_issue_index = selected_issue_index.saturating_sub(1);
+        }
+        KeyCode::Enter => app.start_selected_issue_load(),
+        _ => {}
+    }
+
+    EventResult::Continue
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::db::Database;
+    use crate::domain::session::{ForgeIssueSummary, ForgeKind};
+
+    /// Builds one client bundle with deterministic agent availability for
+    /// test app startup.
+    fn test_app_clients() -> crate::app::AppClients {
+        crate::app::AppClients::new().with_agent_availability_probe(std::sync::Arc::new(
+            crate::infra::agent::StaticAgentAvailabilityProbe {
+                available_agent_kinds: crate::domain::agent::AgentKind::ALL.to_vec(),
+            },
+        ))
+    }
+
+    async fn new_test_app() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let app = App::new_with_clients(
+            base_path.clone(),
+            base_path,
+            None,
+            database,
+            test_app_clients(),
+        )
+        .await
+        .expect("failed to build app");
+
+        (app, base_dir)
+    }
+
+    fn issue_summary(number: u64) -> ForgeIssueSummary {
+        ForgeIssueSummary {
+            display_id: format!("#{number}"),
+            forge_kind: ForgeKind::GitHub,
+            labels: Vec::new(),
+            title: format!("Issue {number}"),
+            web_url: format!("https://github.com/agentty-xyz/agentty/issues/{number}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_j_moves_selection_within_listed_issues() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::IssuePicker {
+            issues: vec![issue_summary(2), issue_summary(1)],
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Ready,
+        };
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        );
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::IssuePicker {
+                selected_issue_index: 1,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_enter_marks_selected_issue_as_starting() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::IssuePicker {
+            issues: vec![issue_summary(2)],
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Ready,
+        };
+
+        // Act
+        handle(&mut app, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::IssuePicker {
+                status: IssuePickerStatus::Starting,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_esc_closes_issue_picker() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::IssuePicker {
+            issues: Vec::new(),
+            selected_issue_index: 0,
+            status: IssuePickerStatus::Loading,
+        };
+
+        // Act
+        let event_result = handle(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        // Assert
+        assert!(matches!(event_result, EventResult::Continue));
+        assert!(matches!(app.mode, AppMode::List));
+    }
+}
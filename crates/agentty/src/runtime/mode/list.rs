@@ -12,27 +12,35 @@ use crate::ui::state::app_mode::{
     AppMode, ConfirmationIntent, DoneSessionOutputMode, HelpContext, QuestionFocus,
 };
 use crate::ui::state::help_action::{
-    HelpAction, project_list_actions, session_list_actions, settings_actions, stats_actions,
-    task_actions,
+    HelpAction, dashboard_actions, project_list_actions, session_list_actions, settings_actions,
+    stats_actions, task_actions,
 };
+use crate::ui::state::keymap::{self, KeyAction, KeymapMode};
 use crate::ui::state::prompt::{PromptAttachmentState, PromptHistoryState};
 use crate::ui::util::inline_text;
 
 /// Handles key input while the app is in list mode.
 ///
-/// Pressing `q` opens a confirmation overlay instead of quitting immediately,
+/// Keys resolve through the active keymap. With the default bindings,
+/// pressing `q` opens a confirmation overlay instead of quitting immediately,
 /// with `No` selected by default. Pressing `Enter` on the `Projects` tab
 /// selects the active project and then moves focus to `Tab::Sessions`.
 /// `c` opens a cancel confirmation overlay for review sessions and unstarted
-/// draft sessions, and `Tab` cycles tabs forward while `Shift+Tab` cycles
-/// backward.
+/// draft sessions, or a stop confirmation for the running session highlighted
+/// on the `Dashboard` tab. `i` opens the forge issue picker on the `Sessions`
+/// tab, `/` opens the global session search, and `Tab` cycles tabs forward
+/// while `Shift+Tab` cycles backward.
 pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResult> {
     if app.tabs.current() == Tab::Settings && app.settings.is_editing_text_input() {
         return handle_settings_text_input(app, key).await;
     }
 
-    match key.code {
-        KeyCode::Char('q') => {
+    let Some(action) = keymap::active().action(KeymapMode::List, key) else {
+        return Ok(EventResult::Continue);
+    };
+
+    match action {
+        KeyAction::Quit => {
             app.mode = AppMode::Confirmation {
                 confirmation_intent: ConfirmationIntent::Quit,
                 confirmation_message: "Quit agentty?".to_string(),
@@ -44,41 +52,30 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
 
             return Ok(EventResult::Continue);
         }
-        KeyCode::Tab => {
+        KeyAction::NextTab => {
             app.next_tab();
         }
-        KeyCode::BackTab => {
+        KeyAction::PreviousTab => {
             app.previous_tab();
         }
-        KeyCode::Char('a')
-            if app.tabs.current() == Tab::Sessions && key.modifiers == KeyModifiers::NONE =>
-        {
+        KeyAction::NewSession if app.tabs.current() == Tab::Sessions => {
             open_new_session_prompt(app).await?;
         }
-        KeyCode::Char('A')
-            if app.tabs.current() == Tab::Sessions && key.modifiers == KeyModifiers::SHIFT =>
-        {
+        KeyAction::NewDraftSession if app.tabs.current() == Tab::Sessions => {
             open_new_draft_session_prompt(app).await?;
         }
-        KeyCode::Char('j') | KeyCode::Down => match app.tabs.current() {
-            Tab::Projects => app.next_project(),
-            Tab::Sessions => app.next(),
-            Tab::Tasks => app.scroll_task_roadmap_down(),
-            Tab::Stats => {}
-            Tab::Settings => app.settings.next(),
-        },
-        KeyCode::Char('k') | KeyCode::Up => match app.tabs.current() {
-            Tab::Projects => app.previous_project(),
-            Tab::Sessions => app.previous(),
-            Tab::Tasks => app.scroll_task_roadmap_up(),
-            Tab::Stats => {}
-            Tab::Settings => app.settings.previous(),
-        },
-        KeyCode::Char('g') if app.tabs.current() == Tab::Tasks => {
+        KeyAction::IssuePicker if app.tabs.current() == Tab::Sessions => {
+            app.start_issue_picker();
+        }
+        KeyAction::Down | KeyAction::Up => move_selection(app, action),
+        KeyAction::Top if app.tabs.current() == Tab::Tasks => {
             app.reset_task_roadmap_scroll();
         }
-        KeyCode::Enter => return handle_enter_key(app).await,
-        KeyCode::Char('c') if app.tabs.current() == Tab::Sessions => {
+        KeyAction::Open => return handle_enter_key(app).await,
+        KeyAction::CancelSession if app.tabs.current() == Tab::Dashboard => {
+            open_stop_dashboard_session_confirmation(app);
+        }
+        KeyAction::CancelSession if app.tabs.current() == Tab::Sessions => {
             let selected_session = app.selected_session().and_then(|session| {
                 session
                     .allows_cancel_action()
@@ -95,10 +92,13 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
                 };
             }
         }
-        KeyCode::Char(character) if character.eq_ignore_ascii_case(&'s') => {
+        KeyAction::Sync => {
             sync_main_branch(app);
         }
-        KeyCode::Char('?') => {
+        KeyAction::Search => {
+            app.start_session_search();
+        }
+        KeyAction::Help => {
             open_list_help_overlay(app);
         }
         _ => {}
@@ -107,9 +107,28 @@ pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> io::Result<EventResu
     Ok(EventResult::Continue)
 }
 
+/// Moves the current tab's selection, or scrolls the roadmap, one step for
+/// `KeyAction::Down` and `KeyAction::Up`; other actions are ignored.
+pub(crate) fn move_selection(app: &mut App, action: KeyAction) {
+    match (action, app.tabs.current()) {
+        (KeyAction::Down, Tab::Projects) => app.next_project(),
+        (KeyAction::Down, Tab::Sessions) => app.next(),
+        (KeyAction::Down, Tab::Dashboard) => app.next_dashboard_session(),
+        (KeyAction::Down, Tab::Tasks) => app.scroll_task_roadmap_down(),
+        (KeyAction::Down, Tab::Settings) => app.settings.next(),
+        (KeyAction::Up, Tab::Projects) => app.previous_project(),
+        (KeyAction::Up, Tab::Sessions) => app.previous(),
+        (KeyAction::Up, Tab::Dashboard) => app.previous_dashboard_session(),
+        (KeyAction::Up, Tab::Tasks) => app.scroll_task_roadmap_up(),
+        (KeyAction::Up, Tab::Settings) => app.settings.previous(),
+        _ => {}
+    }
+}
+
 /// Handles `Enter` in list mode and triggers the selected tab primary action.
 ///
-/// On the sessions tab, any selected session can be opened in view mode.
+/// On the sessions and dashboard tabs, the selected session can be opened in
+/// view mode.
 async fn handle_enter_key(app: &mut App) -> io::Result<EventResult> {
     match app.tabs.current() {
         Tab::Projects => {
@@ -118,38 +137,13 @@ async fn handle_enter_key(app: &mut App) -> io::Result<EventResult> {
             }
         }
         Tab::Sessions => {
-            if let Some(session_index) = app.sessions.table_state.selected()
-                && let Some(session) = app.sessions.sessions.get(session_index)
-            {
-                let session_id = session.id.clone();
-
-                if session.status == Status::Question {
-                    let questions = session.questions.clone();
-                    let selected_option_index = question::default_option_index(&questions, 0);
-                    let (review_status_message, review_text) = app.review_view_state(&session_id);
-                    app.mode = AppMode::Question {
-                        at_mention_state: None,
-                        review_status_message,
-                        review_text,
-                        session_id,
-                        questions,
-                        responses: Vec::new(),
-                        current_index: 0,
-                        focus: QuestionFocus::Answer,
-                        input: InputState::default(),
-                        scroll_offset: None,
-                        selected_option_index,
-                    };
-                } else {
-                    let (review_status_message, review_text) = app.review_view_state(&session_id);
-                    app.mode = AppMode::View {
-                        done_session_output_mode: DoneSessionOutputMode::Summary,
-                        review_status_message,
-                        review_text,
-                        session_id,
-                        scroll_offset: None,
-                    };
-                }
+            if let Some(session_index) = app.sessions.table_state.selected() {
+                open_session_at_index(app, session_index);
+            }
+        }
+        Tab::Dashboard => {
+            if let Some(session_index) = app.selected_dashboard_session_index() {
+                open_session_at_index(app, session_index);
             }
         }
         Tab::Settings => {
@@ -161,12 +155,53 @@ async fn handle_enter_key(app: &mut App) -> io::Result<EventResult> {
     Ok(EventResult::Continue)
 }
 
+/// Opens the session at `session_index` in question mode when it is waiting
+/// for clarification answers, or in session view otherwise.
+pub(crate) fn open_session_at_index(app: &mut App, session_index: usize) {
+    let Some(session) = app.sessions.sessions.get(session_index) else {
+        return;
+    };
+    let session_id = session.id.clone();
+
+    if session.status == Status::Question {
+        let questions = session.questions.clone();
+        let input = question::default_question_input(&questions, 0);
+        let selected_option_index = question::default_option_index(&questions, 0);
+        let (review_status_message, review_text) = app.review_view_state(&session_id);
+        app.mode = AppMode::Question {
+            at_mention_state: None,
+            checked_option_indexes: Vec::new(),
+            review_status_message,
+            review_text,
+            session_id,
+            questions,
+            responses: Vec::new(),
+            current_index: 0,
+            focus: QuestionFocus::Answer,
+            input,
+            scroll_offset: None,
+            selected_option_index,
+        };
+        question::sync_question_at_mention_state(app);
+    } else {
+        let (review_status_message, review_text) = app.review_view_state(&session_id);
+        app.mode = AppMode::View {
+            done_session_output_mode: DoneSessionOutputMode::Summary,
+            review_status_message,
+            review_text,
+            session_id,
+            scroll_offset: None,
+            transcript_search: None,
+        };
+    }
+}
+
 /// Handles text input while a settings editor is active.
 ///
-/// The `Open Commands` editor is multiline: `Alt+Enter`/`Shift+Enter` insert
-/// a newline. Terminals that emit `\r`/`\n` as character keys are also
-/// treated as newline insertion to match prompt input behavior. Plain
-/// `Enter` finishes editing.
+/// The `Open Commands`, `Project Instructions`, and `MCP Servers` editors are
+/// multiline: `Alt+Enter`/`Shift+Enter` insert a newline. Terminals that emit
+/// `\r`/`\n` as character keys are also treated as newline insertion to match
+/// prompt input behavior. Plain `Enter` finishes editing.
 /// Arrow keys move the cursor.
 async fn handle_settings_text_input(app: &mut App, key: KeyEvent) -> io::Result<EventResult> {
     match key.code {
@@ -211,7 +246,7 @@ async fn handle_settings_text_input(app: &mut App, key: KeyEvent) -> io::Result<
 
 /// Returns whether settings text editing should insert a newline.
 fn should_insert_settings_newline(app: &App, key: KeyEvent) -> bool {
-    app.settings.is_editing_open_commands()
+    app.settings.is_editing_multiline_text_input()
         && (is_settings_newline_character_key(key.code)
             || is_settings_modified_enter_key(key)
             || is_settings_control_newline_key(key))
@@ -247,6 +282,27 @@ fn is_settings_text_key(key: KeyEvent) -> bool {
     key.modifiers == KeyModifiers::NONE || key.modifiers == KeyModifiers::SHIFT
 }
 
+/// Opens a stop confirmation for the highlighted dashboard tile when its
+/// session is running an agent turn.
+fn open_stop_dashboard_session_confirmation(app: &mut App) {
+    let Some(session) = app
+        .selected_dashboard_session_index()
+        .and_then(|session_index| app.sessions.sessions.get(session_index))
+        .filter(|session| session.status == Status::InProgress)
+    else {
+        return;
+    };
+
+    app.mode = AppMode::Confirmation {
+        confirmation_intent: ConfirmationIntent::StopSession,
+        confirmation_message: format!("Stop session \"{}\"?", inline_text(session.display_title())),
+        confirmation_title: "Confirm Stop".to_string(),
+        restore_view: None,
+        session_id: Some(session.id.clone()),
+        selected_confirmation_index: DEFAULT_OPTION_INDEX,
+    };
+}
+
 /// Starts selected-project branch sync and immediately opens a loading popup.
 fn sync_main_branch(app: &mut App) {
     app.start_sync_main();
@@ -280,6 +336,17 @@ fn list_keybindings(app: &App) -> Vec<HelpAction> {
         return task_actions();
     }
 
+    if app.tabs.current() == Tab::Dashboard {
+        let selected_session = app
+            .selected_dashboard_session_index()
+            .and_then(|session_index| app.sessions.sessions.get(session_index));
+
+        return dashboard_actions(
+            selected_session.is_some_and(|session| session.status == Status::InProgress),
+            selected_session.is_some(),
+        );
+    }
+
     let is_sessions_tab = app.tabs.current() == Tab::Sessions;
     let selected_session = app.selected_session();
     let can_cancel_selected_session =
@@ -336,7 +403,8 @@ mod tests {
     use super::*;
     use crate::app::{AppEvent, MockSyncMainRunner, SyncMainOutcome, SyncSessionStartError};
     use crate::db::Database;
-    use crate::infra::agent::protocol::QuestionItem;
+    use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
+    use crate::ui::state::app_mode::IssuePickerStatus;
 
     /// Builds one client bundle with deterministic agent availability for
     /// test app startup.
@@ -679,10 +747,12 @@ mod tests {
             .expect("failed to create session");
         let expected_questions: Vec<QuestionItem> = vec![
             QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["main".to_string(), "develop".to_string()],
                 text: "Need a target branch?".to_string(),
             },
             QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Yes".to_string(), "No".to_string()],
                 text: "Need migration notes?".to_string(),
             },
@@ -812,6 +882,7 @@ mod tests {
                 review_text: None,
                 ref session_id,
                 scroll_offset: None,
+                ..
             } if session_id == &expected_session_id
         ));
     }
@@ -846,6 +917,7 @@ mod tests {
                 review_text: None,
                 ref session_id,
                 scroll_offset: None,
+                ..
             } if session_id == &expected_session_id
         ));
     }
@@ -1353,6 +1425,57 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_handle_i_key_opens_loading_issue_picker() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.tabs.set(Tab::Sessions);
+
+        // Act
+        let event_result = handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('i'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(event_result, EventResult::Continue));
+        assert!(matches!(
+            app.mode,
+            AppMode::IssuePicker {
+                status: IssuePickerStatus::Loading,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_slash_key_opens_session_search_from_any_tab() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.tabs.set(Tab::Projects);
+
+        // Act
+        let event_result = handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(event_result, EventResult::Continue));
+        assert!(matches!(
+            app.mode,
+            AppMode::SessionSearch {
+                ref hits,
+                ref input,
+                selected_hit_index: 0,
+            } if hits.is_empty() && input.text().is_empty()
+        ));
+    }
+
     #[tokio::test]
     async fn test_handle_sync_key_shows_failure_when_upstream_is_missing() {
         // Arrange
@@ -1546,4 +1669,120 @@ mod tests {
                 && project_name.is_some()
         ));
     }
+
+    /// Builds one session fixture in `status` for dashboard key tests.
+    fn dashboard_session(session_id: &str, status: Status) -> Session {
+        let mut session = crate::domain::session::tests::SessionFixtureBuilder::new()
+            .id(session_id)
+            .status(status)
+            .folder(std::env::temp_dir())
+            .build();
+        session.title = Some(format!("Dashboard {session_id}"));
+
+        session
+    }
+
+    #[tokio::test]
+    async fn test_handle_down_key_on_dashboard_cycles_running_sessions_only() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.sessions.replace_sessions(vec![
+            dashboard_session("running-a", Status::InProgress),
+            dashboard_session("review", Status::Review),
+            dashboard_session("running-b", Status::InProgress),
+        ]);
+        app.tabs.set(Tab::Dashboard);
+        let initial_selection = app.selected_dashboard_session_index();
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+        let next_selection = app.selected_dashboard_session_index();
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert_eq!(initial_selection, Some(0));
+        assert_eq!(next_selection, Some(2));
+        assert_eq!(app.selected_dashboard_session_index(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_handle_enter_key_on_dashboard_opens_selected_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.sessions.replace_sessions(vec![
+            dashboard_session("review", Status::Review),
+            dashboard_session("running", Status::InProgress),
+        ]);
+        app.tabs.set(Tab::Dashboard);
+
+        // Act
+        handle(&mut app, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE))
+            .await
+            .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::View { ref session_id, .. } if session_id == "running"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_cancel_key_on_dashboard_opens_stop_confirmation_for_running_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.sessions
+            .replace_sessions(vec![dashboard_session("running", Status::InProgress)]);
+        app.tabs.set(Tab::Dashboard);
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::Confirmation {
+                confirmation_intent: ConfirmationIntent::StopSession,
+                ref confirmation_message,
+                session_id: Some(ref session_id),
+                ..
+            } if confirmation_message == "Stop session \"Dashboard running\"?"
+                && session_id == "running"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_cancel_key_on_dashboard_ignores_queued_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.sessions
+            .replace_sessions(vec![dashboard_session("queued", Status::Queued)]);
+        app.tabs.set(Tab::Dashboard);
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('c'), KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::List));
+    }
 }
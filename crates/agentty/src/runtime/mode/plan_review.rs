@@ -0,0 +1,214 @@
+use crossterm::event::{KeyCode, KeyEvent};
+
+use crate::app::App;
+use crate::domain::plan::SessionPlan;
+use crate::infra::channel::TurnPrompt;
+use crate::runtime::EventResult;
+use crate::runtime::mode::input_key::is_insertable_char_key;
+use crate::ui::state::app_mode::{AppMode, ConfirmationViewMode};
+use crate::ui::state::plan_review::PlanReviewState;
+
+/// Handles key input while the plan-review overlay is visible.
+///
+/// `j`/`k` move the selection, `Space` keeps or drops the selected step, `e`
+/// edits its text inline, `Enter` approves the kept steps, and `Esc`/`q`
+/// close the overlay. While editing, `Enter` stores the edit and `Esc`
+/// discards it.
+pub(crate) async fn handle(app: &mut App, key: KeyEvent) -> EventResult {
+    let mode = std::mem::replace(&mut app.mode, AppMode::List);
+    let AppMode::PlanReview {
+        mut review,
+        restore_view,
+    } = mode
+    else {
+        app.mode = mode;
+
+        return EventResult::Continue;
+    };
+
+    if let Some(input) = &mut review.editing_input {
+        match key.code {
+            KeyCode::Esc => review.editing_input = None,
+            KeyCode::Enter => review.commit_edit(),
+            KeyCode::Backspace => input.delete_backward(),
+            KeyCode::Left => input.move_left(),
+            KeyCode::Right => input.move_right(),
+            KeyCode::Char(character) if is_insertable_char_key(key) => {
+                input.insert_char(character);
+            }
+            _ => {}
+        }
+        app.mode = AppMode::PlanReview {
+            review,
+            restore_view,
+        };
+
+        return EventResult::Continue;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.mode = restore_view.into_view_mode();
+
+            return EventResult::Continue;
+        }
+        KeyCode::Enter if !review.approved_steps().is_empty() => {
+            approve_plan(app, &review, restore_view).await;
+
+            return EventResult::Continue;
+        }
+        KeyCode::Char('j') | KeyCode::Down => review.select_next(),
+        KeyCode::Char('k') | KeyCode::Up => review.select_previous(),
+        KeyCode::Char(' ') => review.toggle_selected(),
+        KeyCode::Char('e') => review.start_editing(),
+        _ => {}
+    }
+    app.mode = AppMode::PlanReview {
+        review,
+        restore_view,
+    };
+
+    EventResult::Continue
+}
+
+/// Persists the kept steps as the approved plan and starts the first
+/// write-enabled turn that implements it.
+async fn approve_plan(app: &mut App, review: &PlanReviewState, restore_view: ConfirmationViewMode) {
+    let session_id = restore_view.session_id.clone();
+    let plan = SessionPlan::approved(review.approved_steps());
+    app.mode = restore_view.into_view_mode();
+
+    if let Err(error) = app.set_session_plan(&session_id, Some(plan.clone())).await {
+        app.append_output_for_session(&session_id, &format!("\n[Plan Error] {error}\n"))
+            .await;
+
+        return;
+    }
+
+    app.reply(&session_id, TurnPrompt::from_text(plan.approval_prompt()))
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::db::Database;
+    use crate::domain::plan::PlanStep;
+    use crate::ui::state::app_mode::DoneSessionOutputMode;
+
+    /// Builds one client bundle with deterministic agent availability for
+    /// test app startup.
+    fn test_app_clients() -> crate::app::AppClients {
+        crate::app::AppClients::new().with_agent_availability_probe(std::sync::Arc::new(
+            crate::infra::agent::StaticAgentAvailabilityProbe {
+                available_agent_kinds: crate::domain::agent::AgentKind::ALL.to_vec(),
+            },
+        ))
+    }
+
+    async fn new_test_app() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let app = App::new_with_clients(
+            base_path.clone(),
+            base_path,
+            None,
+            database,
+            test_app_clients(),
+        )
+        .await
+        .expect("failed to build app");
+
+        (app, base_dir)
+    }
+
+    fn plan_review_mode() -> AppMode {
+        AppMode::PlanReview {
+            review: PlanReviewState::new(vec![
+                PlanStep::new("Add the column", Vec::new()),
+                PlanStep::new("Update docs", Vec::new()),
+            ]),
+            restore_view: ConfirmationViewMode {
+                done_session_output_mode: DoneSessionOutputMode::Summary,
+                review_status_message: None,
+                review_text: None,
+                scroll_offset: None,
+                session_id: "session-id".into(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_space_drops_selected_step() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = plan_review_mode();
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE),
+        )
+        .await;
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char(' '), KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        let AppMode::PlanReview { review, .. } = &app.mode else {
+            unreachable!("expected plan review mode");
+        };
+        assert_eq!(
+            review.approved_steps(),
+            vec![PlanStep::new("Add the column", Vec::new())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_edit_keys_reword_selected_step() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = plan_review_mode();
+
+        // Act
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('e'), KeyModifiers::NONE),
+        )
+        .await;
+        handle(
+            &mut app,
+            KeyEvent::new(KeyCode::Char('s'), KeyModifiers::NONE),
+        )
+        .await;
+        handle(&mut app, KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)).await;
+
+        // Assert
+        let AppMode::PlanReview { review, .. } = &app.mode else {
+            unreachable!("expected plan review mode");
+        };
+        assert_eq!(review.editing_input, None);
+        assert_eq!(review.steps[0].step.text, "Add the columns");
+    }
+
+    #[tokio::test]
+    async fn test_handle_escape_restores_view_mode() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = plan_review_mode();
+
+        // Act
+        handle(&mut app, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE)).await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::View { .. }));
+    }
+}
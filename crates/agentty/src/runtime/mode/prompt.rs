@@ -1,26 +1,42 @@
 use std::io;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 use crossterm::event::{self, KeyCode, KeyEvent};
 use ratatui::Terminal;
 use ratatui::backend::Backend;
+use ratatui::layout::Rect;
 
-use crate::app::{App, ReviewCacheEntry, SessionStatsUsage, diff_content_hash};
+use crate::app::{
+    App, AppEvent, ReviewCacheEntry, SessionStatsUsage, agentty_home, diff_content_hash,
+};
 use crate::domain::agent::{AgentKind, ReasoningLevel};
 use crate::domain::input::InputState;
-use crate::domain::session::SessionId;
-use crate::infra::channel::{TurnPrompt, TurnPromptAttachment};
+use crate::domain::plan::SessionPlan;
+use crate::domain::session::{SESSION_DATA_DIR, SessionId};
+use crate::domain::skill::Skill;
+use crate::infra::channel::{TurnPrompt, TurnPromptAttachment, TurnPromptContext, TurnPromptSkill};
+use crate::runtime::context_attachment::{
+    self, CapturedContext, ContextAttachmentError, SNIPPETS_DIR,
+};
+use crate::runtime::event::EventReaderPause;
 use crate::runtime::mode::{at_mention, input_key};
-use crate::runtime::{EventResult, clipboard_image};
+use crate::runtime::{EventResult, clipboard_image, external_editor, key_handler};
 use crate::ui::state::app_mode::{AppMode, DoneSessionOutputMode};
 use crate::ui::state::prompt::{
-    PromptAtMentionState, PromptSlashStage,
-    apply_prompt_delete_range as apply_prompt_delete_range_components,
+    PromptAtMentionState, PromptContextDirective, PromptContextKind, PromptHistorySearchState,
+    PromptSlashStage, apply_prompt_delete_range as apply_prompt_delete_range_components,
     current_line_delete_range as prompt_current_line_delete_range, drain_prompt_submission,
-    insert_prompt_character, insert_prompt_local_image, insert_prompt_text,
-    prompt_slash_option_count, resolve_prompt_slash_selection,
+    filter_prompt_history, insert_pending_prompt_context, insert_prompt_character,
+    insert_prompt_context, insert_prompt_local_image, insert_prompt_text,
+    prompt_context_directive_at_cursor, prompt_slash_option_count, replace_prompt_text,
+    resolve_prompt_slash_selection,
 };
 use crate::ui::util::{format_token_count, move_input_cursor_down, move_input_cursor_up};
 
+/// Monotonic counter used to match background context captures to their
+/// pending placeholders.
+static NEXT_PROMPT_CONTEXT_CAPTURE_ID: AtomicU64 = AtomicU64::new(1);
+
 /// Captures prompt-mode routing flags derived from the current session.
 ///
 /// Draft sessions only stage prompts while they remain in `Status::New`.
@@ -104,6 +120,12 @@ where
         return Ok(EventResult::Continue);
     };
 
+    if is_prompt_history_search_active(app) {
+        handle_prompt_history_search_key(app, &prompt_context, key).await;
+
+        return Ok(EventResult::Continue);
+    }
+
     if !prompt_context.is_slash_command() {
         reset_prompt_slash_state(app);
     }
@@ -123,8 +145,10 @@ where
 fn handle_at_mention_key(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
         KeyCode::Esc => dismiss_at_mention(app),
-        KeyCode::Enter if !input_key::should_insert_newline(key) => handle_at_mention_select(app),
-        KeyCode::Tab => handle_at_mention_select(app),
+        KeyCode::Enter if !input_key::should_insert_newline(key) => {
+            handle_at_mention_select(app, false);
+        }
+        KeyCode::Tab => handle_at_mention_select(app, true),
         KeyCode::Up => handle_at_mention_up(app),
         KeyCode::Down => handle_at_mention_down(app),
         _ => return false,
@@ -158,10 +182,10 @@ where
         KeyCode::Right => handle_prompt_right(app, key),
         KeyCode::Up => handle_prompt_up_key(app, terminal, prompt_context)?,
         KeyCode::Down => handle_prompt_down_key(app, terminal, prompt_context)?,
-        KeyCode::Char('k') if prompt_context.is_slash_command() && is_plain_char_key(key, 'k') => {
+        KeyCode::Char('k') if is_slash_menu_navigation_key(app, prompt_context, key, 'k') => {
             handle_prompt_up_key(app, terminal, prompt_context)?;
         }
-        KeyCode::Char('j') if prompt_context.is_slash_command() && is_plain_char_key(key, 'j') => {
+        KeyCode::Char('j') if is_slash_menu_navigation_key(app, prompt_context, key, 'j') => {
             handle_prompt_down_key(app, terminal, prompt_context)?;
         }
         KeyCode::Home => handle_prompt_input(app, InputState::move_home),
@@ -178,6 +202,12 @@ where
         KeyCode::Char('v') if is_prompt_image_paste_key(key) => {
             handle_prompt_image_paste(app, prompt_context).await;
         }
+        KeyCode::Char('g') if input_key::is_control_key(key) => {
+            handle_prompt_external_editor(app, terminal, prompt_context).await?;
+        }
+        KeyCode::Char('r') if input_key::is_control_key(key) => {
+            open_prompt_history_search(app).await;
+        }
         KeyCode::Char('a') if input_key::is_control_key(key) => {
             handle_prompt_input(app, InputState::move_line_start);
         }
@@ -261,7 +291,7 @@ fn prompt_context(app: &mut App) -> Option<PromptContext> {
             session_id,
             ..
         } => (
-            is_active_at_mention(at_mention_state.as_ref(), input),
+            is_active_at_mention(at_mention_state.as_deref(), input),
             input.text().starts_with('/'),
             *scroll_offset,
             session_id.clone(),
@@ -330,7 +360,7 @@ fn sync_prompt_at_mention_state(app: &mut App) {
             at_mention_state,
             input,
             ..
-        } => at_mention::sync_action(input, at_mention_state.as_ref()),
+        } => at_mention::sync_action(input, at_mention_state.as_deref()),
         _ => return,
     };
 
@@ -372,6 +402,27 @@ fn is_plain_char_key(key: KeyEvent, character: char) -> bool {
     key.code == KeyCode::Char(character) && key.modifiers == event::KeyModifiers::NONE
 }
 
+/// Returns true when a plain `j`/`k` key should move the slash-menu
+/// highlight.
+///
+/// Once the slash input has arguments after its command, `j` and `k` are
+/// typed as text so prompt-template arguments can contain them.
+fn is_slash_menu_navigation_key(
+    app: &App,
+    prompt_context: &PromptContext,
+    key: KeyEvent,
+    character: char,
+) -> bool {
+    if !prompt_context.is_slash_command() || !is_plain_char_key(key, character) {
+        return false;
+    }
+
+    match &app.mode {
+        AppMode::Prompt { input, .. } => !input.text().contains(char::is_whitespace),
+        _ => false,
+    }
+}
+
 /// Returns true when the key event should paste one clipboard image into the
 /// prompt composer.
 fn is_prompt_image_paste_key(key: KeyEvent) -> bool {
@@ -397,7 +448,7 @@ where
         return Ok(());
     }
 
-    let input_width = prompt_input_width(terminal)?;
+    let input_width = prompt_input_width(app, terminal)?;
     if let AppMode::Prompt { input, .. } = &mut app.mode {
         let next_cursor = move_input_cursor_up(input.text(), input_width, input.cursor);
         if next_cursor != input.cursor {
@@ -428,7 +479,7 @@ where
         return Ok(());
     }
 
-    let input_width = prompt_input_width(terminal)?;
+    let input_width = prompt_input_width(app, terminal)?;
     if let AppMode::Prompt { input, .. } = &mut app.mode {
         let next_cursor = move_input_cursor_down(input.text(), input_width, input.cursor);
         if next_cursor != input.cursor {
@@ -495,43 +546,23 @@ fn navigate_prompt_history_down(app: &mut App) {
 }
 
 fn advance_prompt_slash_selection(app: &mut App) {
-    let (
-        available_agent_kinds,
-        input_text,
-        selected_agent,
-        selected_index,
-        session_agent_kind,
-        stage,
-    ) = match &app.mode {
-        AppMode::Prompt {
-            input, slash_state, ..
-        } => (
-            slash_state.available_agent_kinds.clone(),
-            input.text().to_string(),
-            slash_state.selected_agent,
-            slash_state.selected_index,
-            app.selected_session()
-                .map_or(AgentKind::Codex, |session| session.model.kind()),
-            slash_state.stage,
-        ),
-        _ => return,
+    let session_agent_kind = app
+        .selected_session()
+        .map_or(AgentKind::Codex, |session| session.model.kind());
+    let AppMode::Prompt {
+        input, slash_state, ..
+    } = &mut app.mode
+    else {
+        return;
     };
 
-    let option_count = prompt_slash_option_count(
-        &input_text,
-        stage,
-        selected_agent,
-        &available_agent_kinds,
-        session_agent_kind,
-    );
+    let option_count = prompt_slash_option_count(input.text(), slash_state, session_agent_kind);
     if option_count == 0 {
         return;
     }
 
-    if let AppMode::Prompt { slash_state, .. } = &mut app.mode {
-        let max_index = option_count.saturating_sub(1);
-        slash_state.selected_index = (selected_index + 1).min(max_index);
-    }
+    let max_index = option_count.saturating_sub(1);
+    slash_state.selected_index = (slash_state.selected_index + 1).min(max_index);
 }
 
 /// Submits the active prompt when it passes prompt-mode validation.
@@ -539,16 +570,33 @@ fn advance_prompt_slash_selection(app: &mut App) {
 /// A submitted prompt clears any cached focused-review output for the session
 /// so the next turn starts from the raw transcript again.
 async fn handle_prompt_submit_key(app: &mut App, prompt_context: &PromptContext) {
+    if handle_prompt_context_directive(app, prompt_context).await {
+        return;
+    }
+
     if prompt_context.is_slash_command() {
         handle_prompt_slash_submit(app, prompt_context).await;
 
         return;
     }
 
+    if has_pending_prompt_context(app) {
+        append_prompt_status_line(
+            app,
+            &prompt_context.session_id,
+            "Attach Context",
+            "Wait for context capture to finish before submitting",
+        )
+        .await;
+
+        return;
+    }
+
     let prompt = take_submitted_turn_prompt(app);
     if prompt.is_empty() {
         return;
     }
+    app.record_prompt_history(&prompt.text).await;
 
     if prompt_context.is_draft_session() {
         if let Err(error) = app
@@ -581,7 +629,219 @@ async fn handle_prompt_submit_key(app: &mut App, prompt_context: &PromptContext)
         review_text: None,
         session_id: prompt_context.session_id.clone(),
         scroll_offset: None,
+        transcript_search: None,
+    };
+}
+
+/// Replaces a `/file`, `/run`, `/url`, `/diff`, `/snippet`, or `/skill` line
+/// under the cursor with a context placeholder token instead of submitting
+/// the prompt.
+///
+/// Returns whether the cursor line was a context directive. `/run` and `/url`
+/// insert a pending placeholder and capture in the background; see
+/// [`start_prompt_context_capture`]. Capture failures are reported as a
+/// prompt status line and leave the directive text in place so it can be
+/// corrected.
+async fn handle_prompt_context_directive(app: &mut App, prompt_context: &PromptContext) -> bool {
+    let directive = match &app.mode {
+        AppMode::Prompt { input, .. } => prompt_context_directive_at_cursor(input),
+        _ => None,
+    };
+    let Some(directive) = directive else {
+        return false;
+    };
+
+    let argument = directive.argument.clone();
+    let captured = match directive.kind {
+        PromptContextKind::Command => {
+            let lookup_root = prompt_lookup_root(app, prompt_context);
+            start_prompt_context_capture(app, prompt_context, &directive, async move {
+                context_attachment::capture_command(&lookup_root, &argument).await
+            });
+
+            return true;
+        }
+        PromptContextKind::Url => {
+            start_prompt_context_capture(app, prompt_context, &directive, async move {
+                context_attachment::capture_url(&argument).await
+            });
+
+            return true;
+        }
+        PromptContextKind::Diff => capture_session_diff(app, &argument).await,
+        PromptContextKind::File => {
+            context_attachment::capture_file(
+                app.services.fs_client().as_ref(),
+                &prompt_lookup_root(app, prompt_context),
+                &argument,
+            )
+            .await
+        }
+        PromptContextKind::Snippet => {
+            context_attachment::capture_snippet(
+                app.services.fs_client().as_ref(),
+                &agentty_home().join(SNIPPETS_DIR),
+                &app.working_dir().join(SESSION_DATA_DIR).join(SNIPPETS_DIR),
+                &argument,
+            )
+            .await
+        }
+        PromptContextKind::Skill => {
+            context_attachment::capture_skill(prompt_skills(app), &argument)
+        }
+    };
+    apply_captured_prompt_context(app, prompt_context, &directive, captured).await;
+
+    true
+}
+
+/// Replaces one directive line with a pending placeholder and runs `capture`
+/// on a background task.
+///
+/// The result arrives as [`AppEvent::PromptContextCaptured`], so a slow
+/// command or fetch never blocks drawing or input handling.
+fn start_prompt_context_capture(
+    app: &mut App,
+    prompt_context: &PromptContext,
+    directive: &PromptContextDirective,
+    capture: impl Future<Output = Result<CapturedContext, ContextAttachmentError>> + Send + 'static,
+) {
+    let capture_id = NEXT_PROMPT_CONTEXT_CAPTURE_ID.fetch_add(1, Ordering::Relaxed);
+    let AppMode::Prompt {
+        attachment_state,
+        history_state,
+        input,
+        slash_state,
+        ..
+    } = &mut app.mode
+    else {
+        return;
+    };
+    insert_pending_prompt_context(
+        attachment_state,
+        history_state,
+        input,
+        slash_state,
+        directive,
+        capture_id,
+    );
+    sync_prompt_at_mention_state(app);
+
+    let directive_line = format!("{} {}", directive.kind.directive(), directive.argument);
+    let event_tx = app.services.event_sender();
+    let session_id = prompt_context.session_id.clone();
+    tokio::spawn(async move {
+        let result = capture.await.map_err(|error| error.to_string());
+
+        // Fire-and-forget: receiver may be dropped during shutdown.
+        let _ = event_tx.send(AppEvent::PromptContextCaptured {
+            capture_id,
+            directive_line,
+            result,
+            session_id,
+        });
+    });
+}
+
+/// Inserts one captured context attachment in place of its directive line,
+/// or reports the capture failure as a prompt status line.
+async fn apply_captured_prompt_context(
+    app: &mut App,
+    prompt_context: &PromptContext,
+    directive: &PromptContextDirective,
+    captured: Result<CapturedContext, ContextAttachmentError>,
+) {
+    match captured {
+        Ok(captured) => {
+            if let AppMode::Prompt {
+                attachment_state,
+                history_state,
+                input,
+                slash_state,
+                ..
+            } = &mut app.mode
+            {
+                insert_prompt_context(
+                    attachment_state,
+                    history_state,
+                    input,
+                    slash_state,
+                    directive,
+                    captured.label,
+                    captured.content,
+                );
+            }
+
+            sync_prompt_at_mention_state(app);
+        }
+        Err(error) => {
+            append_prompt_status_line(
+                app,
+                &prompt_context.session_id,
+                "Attach Context Error",
+                &error.to_string(),
+            )
+            .await;
+        }
+    }
+}
+
+/// Returns whether a `/run` or `/url` placeholder is still being captured.
+fn has_pending_prompt_context(app: &App) -> bool {
+    match &app.mode {
+        AppMode::Prompt {
+            attachment_state, ..
+        } => attachment_state.has_pending_context(),
+        _ => false,
+    }
+}
+
+/// Returns the skills discovered when the active prompt opened.
+fn prompt_skills(app: &App) -> &[Skill] {
+    match &app.mode {
+        AppMode::Prompt { slash_state, .. } => &slash_state.skills,
+        _ => &[],
+    }
+}
+
+/// Reads the worktree diff of the session whose id starts with `query` or
+/// whose title equals it.
+async fn capture_session_diff(
+    app: &App,
+    query: &str,
+) -> Result<CapturedContext, ContextAttachmentError> {
+    let Some((session_name, folder, base_branch)) = app
+        .sessions
+        .sessions
+        .iter()
+        .find(|session| session.id.starts_with(query) || session.title.as_deref() == Some(query))
+        .map(|session| {
+            (
+                session
+                    .title
+                    .clone()
+                    .unwrap_or_else(|| session.id.to_string()),
+                session.folder.clone(),
+                session.base_branch.clone(),
+            )
+        })
+    else {
+        return Err(ContextAttachmentError::SessionNotFound {
+            query: query.to_string(),
+        });
     };
+
+    let diff = app
+        .services
+        .git_client()
+        .diff(folder, base_branch)
+        .await
+        .map_err(|error| ContextAttachmentError::Diff {
+            session: session_name.clone(),
+            reason: error.to_string(),
+        })?;
+
+    context_attachment::capture_diff(&session_name, diff)
 }
 
 /// Pastes one clipboard image into the prompt composer as an inline
@@ -616,6 +876,242 @@ async fn handle_prompt_image_paste(app: &mut App, prompt_context: &PromptContext
     }
 }
 
+/// Suspends the TUI, opens the composer text in `$VISUAL`/`$EDITOR`, and
+/// loads the saved result back into the composer.
+///
+/// Attachments whose placeholders survive the edit are kept; placeholders
+/// removed in the editor drop their attachments. Editor failures leave the
+/// composer unchanged and are reported as a prompt status line.
+async fn handle_prompt_external_editor<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    prompt_context: &PromptContext,
+) -> io::Result<()>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let text = match &app.mode {
+        AppMode::Prompt { input, .. } => input.text().to_string(),
+        _ => return Ok(()),
+    };
+
+    let event_reader_pause = EventReaderPause::acquire().await;
+    let keyboard_enhancement_enabled = crate::runtime::terminal::suspend_terminal()?;
+    let edit_result = external_editor::edit_prompt_text(
+        app.services.fs_client().as_ref(),
+        &prompt_context.session_id,
+        &text,
+    )
+    .await;
+    crate::runtime::terminal::resume_terminal(keyboard_enhancement_enabled)?;
+    drop(event_reader_pause);
+    terminal.clear().map_err(crate::runtime::backend_err)?;
+
+    match edit_result {
+        Ok(edited_text) => {
+            if let AppMode::Prompt {
+                attachment_state,
+                history_state,
+                input,
+                slash_state,
+                ..
+            } = &mut app.mode
+            {
+                replace_prompt_text(
+                    attachment_state,
+                    history_state,
+                    input,
+                    slash_state,
+                    edited_text,
+                );
+            }
+
+            sync_prompt_at_mention_state(app);
+        }
+        Err(error) => {
+            append_prompt_status_line(
+                app,
+                &prompt_context.session_id,
+                "Editor Error",
+                &error.to_string(),
+            )
+            .await;
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether the `Ctrl+R` prompt-history search is open.
+fn is_prompt_history_search_active(app: &App) -> bool {
+    matches!(
+        &app.mode,
+        AppMode::Prompt { history_state, .. } if history_state.search.is_some()
+    )
+}
+
+/// Opens the `Ctrl+R` fuzzy search over the active project's persisted
+/// prompt history.
+async fn open_prompt_history_search(app: &mut App) {
+    let entries = app.load_prompt_history().await;
+
+    if let AppMode::Prompt { history_state, .. } = &mut app.mode {
+        history_state.reset_navigation();
+        history_state.search = Some(PromptHistorySearchState::new(entries));
+    }
+}
+
+/// Handles keys while the prompt-history search dropdown is open.
+///
+/// Typed characters edit the fuzzy query. `Up`/`Down`, `Ctrl+P`/`Ctrl+N`,
+/// and a repeated `Ctrl+R` move the selection. `Enter` replaces the composer
+/// text with the selected prompt, `Ctrl+O` runs it as a new session, and `Esc`
+/// closes the search.
+async fn handle_prompt_history_search_key(
+    app: &mut App,
+    prompt_context: &PromptContext,
+    key: KeyEvent,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('c') if is_prompt_cancel_key(key) => {
+            close_prompt_history_search(app);
+        }
+        KeyCode::Enter => {
+            if let Some(prompt) = selected_prompt_history_entry(app) {
+                insert_prompt_history_entry(app, prompt);
+            }
+        }
+        KeyCode::Char('o') if input_key::is_control_key(key) => {
+            if let Some(prompt) = selected_prompt_history_entry(app) {
+                rerun_prompt_history_entry(app, prompt_context, prompt).await;
+            }
+        }
+        KeyCode::Up => move_prompt_history_search_selection(app, false),
+        KeyCode::Char('p') if input_key::is_control_key(key) => {
+            move_prompt_history_search_selection(app, false);
+        }
+        KeyCode::Down => move_prompt_history_search_selection(app, true),
+        KeyCode::Char('n' | 'r') if input_key::is_control_key(key) => {
+            move_prompt_history_search_selection(app, true);
+        }
+        KeyCode::Backspace => edit_prompt_history_search_query(app, |query| {
+            query.pop();
+        }),
+        KeyCode::Char(character) if input_key::is_insertable_char_key(key) => {
+            edit_prompt_history_search_query(app, |query| query.push(character));
+        }
+        _ => {}
+    }
+}
+
+/// Closes the prompt-history search and leaves the composer text unchanged.
+fn close_prompt_history_search(app: &mut App) {
+    if let AppMode::Prompt { history_state, .. } = &mut app.mode {
+        history_state.search = None;
+    }
+}
+
+/// Applies one edit to the history search query and highlights the best
+/// match again.
+fn edit_prompt_history_search_query(app: &mut App, edit: impl FnOnce(&mut String)) {
+    if let AppMode::Prompt { history_state, .. } = &mut app.mode
+        && let Some(history_search) = &mut history_state.search
+    {
+        edit(&mut history_search.query);
+        history_search.selected_index = 0;
+    }
+}
+
+/// Moves the highlighted history match toward older prompts when `older` is
+/// `true`, or toward newer ones otherwise.
+fn move_prompt_history_search_selection(app: &mut App, older: bool) {
+    if let AppMode::Prompt { history_state, .. } = &mut app.mode
+        && let Some(history_search) = &mut history_state.search
+    {
+        let match_count =
+            filter_prompt_history(&history_search.entries, &history_search.query).len();
+        history_search.selected_index = if older {
+            (history_search.selected_index + 1).min(match_count.saturating_sub(1))
+        } else {
+            history_search.selected_index.saturating_sub(1)
+        };
+    }
+}
+
+/// Returns the prompt highlighted in the history search, if any matches.
+fn selected_prompt_history_entry(app: &App) -> Option<String> {
+    let AppMode::Prompt { history_state, .. } = &app.mode else {
+        return None;
+    };
+    let history_search = history_state.search.as_ref()?;
+
+    filter_prompt_history(&history_search.entries, &history_search.query)
+        .get(history_search.selected_index)
+        .map(|prompt| (*prompt).to_string())
+}
+
+/// Replaces the composer text with one historical prompt and closes the
+/// search.
+fn insert_prompt_history_entry(app: &mut App, prompt: String) {
+    if let AppMode::Prompt {
+        attachment_state,
+        history_state,
+        input,
+        slash_state,
+        ..
+    } = &mut app.mode
+    {
+        history_state.search = None;
+        replace_prompt_text(attachment_state, history_state, input, slash_state, prompt);
+    }
+
+    sync_prompt_at_mention_state(app);
+}
+
+/// Starts one historical prompt as the first turn of a new session and opens
+/// that session.
+///
+/// A blank new session behind the composer is reused. Otherwise a fresh
+/// session is created and the current composer draft is discarded.
+async fn rerun_prompt_history_entry(app: &mut App, prompt_context: &PromptContext, prompt: String) {
+    let session_id = if prompt_context.can_delete_on_cancel() {
+        prompt_context.session_id.clone()
+    } else {
+        match app.create_session().await {
+            Ok(session_id) => SessionId::from(session_id),
+            Err(error) => {
+                append_prompt_status_line(
+                    app,
+                    &prompt_context.session_id,
+                    "History Error",
+                    &error.to_string(),
+                )
+                .await;
+
+                return;
+            }
+        }
+    };
+
+    cleanup_prompt_attachment_state(app).await;
+    app.record_prompt_history(&prompt).await;
+    if let Err(error) = app
+        .start_session(&session_id, TurnPrompt::from_text(prompt))
+        .await
+    {
+        append_output_for_session(app, &session_id, &format!("\n[Error] {error}\n")).await;
+    }
+
+    app.mode = AppMode::View {
+        done_session_output_mode: DoneSessionOutputMode::Summary,
+        review_status_message: None,
+        review_text: None,
+        session_id,
+        scroll_offset: None,
+        transcript_search: None,
+    };
+}
+
 /// Inserts one persisted image placeholder into the prompt input and records
 /// the attachment metadata in prompt state.
 fn insert_pasted_image_placeholder(app: &mut App, local_image_path: std::path::PathBuf) {
@@ -643,16 +1139,38 @@ fn insert_pasted_image_placeholder(app: &mut App, local_image_path: std::path::P
 /// session workflow.
 ///
 /// Attachments are filtered against the submitted text so manually deleted
-/// `[Image #n]` placeholders do not leave orphaned image inputs in the final
-/// turn payload.
+/// `[Image #n]` or context placeholders do not leave orphaned inputs in the
+/// final turn payload. `[Skill #n]` attachments are resolved back to their
+/// discovered skill so providers can reference the `SKILL.md` file.
 fn take_submitted_turn_prompt(app: &mut App) -> TurnPrompt {
     match &mut app.mode {
         AppMode::Prompt {
             attachment_state,
             input,
+            slash_state,
             ..
         } => {
             let submission = drain_prompt_submission(attachment_state, input);
+            let (skill_attachments, context_attachments): (Vec<_>, Vec<_>) = submission
+                .context_attachments
+                .into_iter()
+                .partition(|attachment| attachment.kind == PromptContextKind::Skill);
+            let skills = skill_attachments
+                .into_iter()
+                .filter_map(|attachment| {
+                    let skill = slash_state
+                        .skills
+                        .iter()
+                        .find(|skill| skill.name == attachment.label)?;
+
+                    Some(TurnPromptSkill {
+                        body: attachment.content,
+                        name: attachment.label,
+                        path: skill.path.clone(),
+                        placeholder: attachment.placeholder,
+                    })
+                })
+                .collect();
             let attachments = submission
                 .attachments
                 .into_iter()
@@ -661,9 +1179,19 @@ fn take_submitted_turn_prompt(app: &mut App) -> TurnPrompt {
                     local_image_path: attachment.local_image_path,
                 })
                 .collect();
+            let context_attachments = context_attachments
+                .into_iter()
+                .map(|attachment| TurnPromptContext {
+                    content: attachment.content,
+                    label: attachment.label,
+                    placeholder: attachment.placeholder,
+                })
+                .collect();
 
             TurnPrompt {
                 attachments,
+                context_attachments,
+                skills,
                 text: submission.text,
             }
         }
@@ -684,25 +1212,17 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
 
     match selection {
         Some(crate::ui::state::prompt::PromptSuggestionSelection::Command("/apply")) => {
-            if let AppMode::Prompt {
-                input, slash_state, ..
-            } = &mut app.mode
-            {
-                input.take_text();
-                slash_state.reset();
-            }
+            clear_prompt_slash_input(app);
             handle_apply_command(app, prompt_context).await;
         }
         Some(crate::ui::state::prompt::PromptSuggestionSelection::Command("/stats")) => {
-            if let AppMode::Prompt {
-                input, slash_state, ..
-            } = &mut app.mode
-            {
-                input.take_text();
-                slash_state.reset();
-            }
+            clear_prompt_slash_input(app);
             handle_stats_command(app, prompt_context).await;
         }
+        Some(crate::ui::state::prompt::PromptSuggestionSelection::Command("/plan")) => {
+            clear_prompt_slash_input(app);
+            handle_plan_command(app, prompt_context).await;
+        }
         Some(crate::ui::state::prompt::PromptSuggestionSelection::Command("/reasoning")) => {
             let selected_reasoning_level = app
                 .session_at(prompt_context.session_index)
@@ -735,13 +1255,7 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
             }
         }
         Some(crate::ui::state::prompt::PromptSuggestionSelection::Model(selected_model)) => {
-            if let AppMode::Prompt {
-                input, slash_state, ..
-            } = &mut app.mode
-            {
-                input.take_text();
-                slash_state.reset();
-            }
+            clear_prompt_slash_input(app);
 
             // Best-effort: model switch failure is non-critical.
             let _ = app
@@ -749,23 +1263,109 @@ async fn handle_prompt_slash_submit(app: &mut App, prompt_context: &PromptContex
                 .await;
         }
         Some(crate::ui::state::prompt::PromptSuggestionSelection::Reasoning(reasoning_level)) => {
-            if let AppMode::Prompt {
-                input, slash_state, ..
-            } = &mut app.mode
-            {
-                input.take_text();
-                slash_state.reset();
-            }
+            clear_prompt_slash_input(app);
 
             // Best-effort: reasoning override failure is non-critical.
             let _ = app
                 .set_session_reasoning_level(&prompt_context.session_id, Some(reasoning_level))
                 .await;
         }
+        Some(crate::ui::state::prompt::PromptSuggestionSelection::Template(template_index)) => {
+            handle_prompt_template_selection(app, prompt_context, template_index).await;
+        }
+        Some(crate::ui::state::prompt::PromptSuggestionSelection::Skill(skill_index)) => {
+            handle_prompt_skill_selection(app, prompt_context, skill_index).await;
+        }
         None => {}
     }
 }
 
+/// Clears the composer text and resets slash-menu state after a slash
+/// command is applied.
+fn clear_prompt_slash_input(app: &mut App) {
+    if let AppMode::Prompt {
+        input, slash_state, ..
+    } = &mut app.mode
+    {
+        input.take_text();
+        slash_state.reset();
+    }
+}
+
+/// Replaces the slash input with the selected template expanded by the
+/// arguments typed after its command, then applies any model or reasoning
+/// level the template pins for the session.
+async fn handle_prompt_template_selection(
+    app: &mut App,
+    prompt_context: &PromptContext,
+    template_index: usize,
+) {
+    let AppMode::Prompt {
+        input, slash_state, ..
+    } = &mut app.mode
+    else {
+        return;
+    };
+    let Some(template) = slash_state.prompt_templates.get(template_index) else {
+        return;
+    };
+    let arguments = input
+        .text()
+        .split_once(char::is_whitespace)
+        .map_or("", |(_, arguments)| arguments);
+    let expanded_prompt = template.expand(arguments);
+    let (model, reasoning_level) = (template.model, template.reasoning_level);
+    *input = InputState::with_text(expanded_prompt);
+    slash_state.reset();
+
+    if let Some(model) = model {
+        // Best-effort: model switch failure is non-critical.
+        let _ = app
+            .set_session_model(&prompt_context.session_id, model)
+            .await;
+    }
+
+    if let Some(reasoning_level) = reasoning_level {
+        // Best-effort: reasoning override failure is non-critical.
+        let _ = app
+            .set_session_reasoning_level(&prompt_context.session_id, Some(reasoning_level))
+            .await;
+    }
+}
+
+/// Replaces the slash input line with a `[Skill #n]` placeholder for the
+/// selected skill.
+async fn handle_prompt_skill_selection(
+    app: &mut App,
+    prompt_context: &PromptContext,
+    skill_index: usize,
+) {
+    let directive = match &app.mode {
+        AppMode::Prompt {
+            input, slash_state, ..
+        } => slash_state
+            .skills
+            .get(skill_index)
+            .map(|skill| PromptContextDirective {
+                argument: skill.name.clone(),
+                end: input
+                    .text()
+                    .chars()
+                    .take_while(|character| *character != '\n')
+                    .count(),
+                kind: PromptContextKind::Skill,
+                start: 0,
+            }),
+        _ => None,
+    };
+    let Some(directive) = directive else {
+        return;
+    };
+
+    let captured = context_attachment::capture_skill(prompt_skills(app), &directive.argument);
+    apply_captured_prompt_context(app, prompt_context, &directive, captured).await;
+}
+
 /// Cancels the active prompt and drops any composer-owned attachment files.
 ///
 /// Existing focused-review output is restored into session view because no new
@@ -798,6 +1398,7 @@ async fn handle_prompt_cancel_key(app: &mut App, prompt_context: &PromptContext)
         review_text: prompt_review_text(app),
         session_id: prompt_context.session_id.clone(),
         scroll_offset: prompt_context.scroll_offset,
+        transcript_search: None,
     };
 }
 
@@ -863,6 +1464,8 @@ async fn cleanup_prompt_attachment_state(app: &mut App) {
 
             TurnPrompt {
                 attachments,
+                context_attachments: Vec::new(),
+                skills: Vec::new(),
                 text: String::new(),
             }
         }
@@ -996,6 +1599,7 @@ async fn handle_apply_command(app: &mut App, prompt_context: &PromptContext) {
         review_text: None,
         session_id: prompt_context.session_id.clone(),
         scroll_offset: None,
+        transcript_search: None,
     };
 }
 
@@ -1030,6 +1634,42 @@ async fn handle_stats_command(app: &App, prompt_context: &PromptContext) {
     append_output_for_session(app, &prompt_context.session_id, &stats_output).await;
 }
 
+/// Handles `/plan` by toggling plan-first mode for the session and
+/// appending the new state to the session transcript.
+///
+/// Enabling plan-first mode makes the next turns read-only until the agent
+/// proposes a plan and the user approves it.
+async fn handle_plan_command(app: &mut App, prompt_context: &PromptContext) {
+    let is_plan_enabled = app
+        .session_at(prompt_context.session_index)
+        .is_some_and(|session| session.plan.is_some());
+    let (plan, message) = if is_plan_enabled {
+        (
+            None,
+            "Plan-first mode disabled. Turns can edit the worktree.",
+        )
+    } else {
+        (
+            Some(SessionPlan::planning()),
+            "Plan-first mode enabled. The next turn is read-only and proposes a plan for approval.",
+        )
+    };
+
+    if let Err(error) = app.set_session_plan(&prompt_context.session_id, plan).await {
+        append_prompt_status_line(
+            app,
+            &prompt_context.session_id,
+            "Plan Error",
+            &error.to_string(),
+        )
+        .await;
+
+        return;
+    }
+
+    append_prompt_status_line(app, &prompt_context.session_id, "Plan", message).await;
+}
+
 struct TokenUsageRow {
     in_tokens: String,
     model: String,
@@ -1134,13 +1774,17 @@ fn format_duration(total_seconds: i64) -> String {
     format!("{hours:02}:{minutes:02}:{seconds:02}")
 }
 
-fn prompt_input_width<B: Backend>(terminal: &Terminal<B>) -> io::Result<u16>
+fn prompt_input_width<B: Backend>(app: &App, terminal: &Terminal<B>) -> io::Result<u16>
 where
     B::Error: std::error::Error + Send + Sync + 'static,
 {
-    let terminal_width = terminal.size().map_err(crate::runtime::backend_err)?.width;
+    let terminal_size = terminal.size().map_err(crate::runtime::backend_err)?;
+    let session_chat_rect = key_handler::session_chat_terminal_rect(
+        app,
+        Rect::new(0, 0, terminal_size.width, terminal_size.height),
+    );
 
-    Ok(terminal_width.saturating_sub(2))
+    Ok(session_chat_rect.width.saturating_sub(2))
 }
 
 /// Moves the prompt cursor left with modifier-aware behavior.
@@ -1320,23 +1964,7 @@ fn handle_prompt_char(app: &mut App, character: char) {
 /// indexes the active project working directory until the session folder is
 /// materialized.
 fn activate_at_mention(app: &mut App, prompt_context: &PromptContext) {
-    let lookup_root = app
-        .sessions
-        .sessions
-        .get(prompt_context.session_index)
-        .map_or_else(
-            || app.working_dir().to_path_buf(),
-            |session| {
-                let session_folder = session.folder.clone();
-                let has_session_folder = app.services.fs_client().is_dir(session_folder.clone());
-
-                at_mention::lookup_root(
-                    app.working_dir().to_path_buf(),
-                    Some(session_folder),
-                    has_session_folder,
-                )
-            },
-        );
+    let lookup_root = prompt_lookup_root(app, prompt_context);
     let session_id = prompt_context.session_id.clone();
     let event_tx = app.services.event_sender();
 
@@ -1346,10 +1974,34 @@ fn activate_at_mention(app: &mut App, prompt_context: &PromptContext) {
         at_mention_state, ..
     } = &mut app.mode
     {
-        *at_mention_state = Some(PromptAtMentionState::new(Vec::new()));
+        *at_mention_state = Some(Box::new(PromptAtMentionState::new(Vec::new())));
     }
 }
 
+/// Returns the directory that prompt `@` lookups and context directives
+/// resolve relative paths against.
+///
+/// Uses the session worktree once it exists and falls back to the active
+/// project working directory for draft sessions without a worktree yet.
+fn prompt_lookup_root(app: &App, prompt_context: &PromptContext) -> std::path::PathBuf {
+    app.sessions
+        .sessions
+        .get(prompt_context.session_index)
+        .map_or_else(
+            || app.working_dir().to_path_buf(),
+            |session| {
+                let session_folder = session.folder.clone();
+                let has_session_folder = app.services.fs_client().is_dir(session_folder.clone());
+
+                at_mention::lookup_root(
+                    app.working_dir().to_path_buf(),
+                    Some(session_folder),
+                    has_session_folder,
+                )
+            },
+        )
+}
+
 /// Clears the at-mention state.
 fn dismiss_at_mention(app: &mut App) {
     if let AppMode::Prompt {
@@ -1383,14 +2035,17 @@ fn handle_at_mention_down(app: &mut App) {
     }
 }
 
-/// Selects the currently highlighted file and inserts it into the input.
-fn handle_at_mention_select(app: &mut App) {
+/// Selects the currently highlighted file or symbol and inserts it into the
+/// input.
+///
+/// `include_snippet` appends the source of a selected `@#` symbol.
+fn handle_at_mention_select(app: &mut App, include_snippet: bool) {
     let replacement = match &app.mode {
         AppMode::Prompt {
             at_mention_state: Some(state),
             input,
             ..
-        } => at_mention::selected_replacement(input, state),
+        } => at_mention::selected_replacement(input, state, include_snippet),
         _ => return,
     };
 
@@ -1536,7 +2191,7 @@ mod tests {
                 .expect("failed to create session")
         };
         app.mode = AppMode::Prompt {
-            at_mention_state,
+            at_mention_state: at_mention_state.map(Box::new),
             attachment_state: PromptAttachmentState::default(),
             history_state: PromptHistoryState::new(Vec::new()),
             review_status_message: None,
@@ -1581,6 +2236,28 @@ mod tests {
         }
     }
 
+    /// Waits until the app emits a `PromptContextCaptured` event and skips
+    /// unrelated background events produced during startup.
+    async fn wait_for_prompt_context_captured_event(app: &mut App) -> crate::app::AppEvent {
+        let timeout = std::time::Duration::from_secs(5);
+        let deadline = tokio::time::Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+            let next_event = tokio::time::timeout(remaining, app.next_app_event())
+                .await
+                .expect("context capture event should arrive")
+                .expect("context capture event channel closed unexpectedly");
+
+            if matches!(
+                next_event,
+                crate::app::AppEvent::PromptContextCaptured { .. }
+            ) {
+                return next_event;
+            }
+        }
+    }
+
     #[test]
     fn test_is_plain_char_key_for_plain_character() {
         // Arrange
@@ -1833,7 +2510,10 @@ mod tests {
             .collect::<Vec<_>>();
 
         // Assert
-        assert_eq!(commands, vec!["/apply", "/model", "/reasoning", "/stats"]);
+        assert_eq!(
+            commands,
+            vec!["/apply", "/model", "/plan", "/reasoning", "/stats"]
+        );
     }
 
     #[test]
@@ -1870,14 +2550,12 @@ mod tests {
 
     #[test]
     fn test_prompt_slash_option_count_for_agent_stage() {
-        // Arrange & Act
-        let count = prompt_slash_option_count(
-            "/model",
-            PromptSlashStage::Agent,
-            None,
-            AgentKind::ALL,
-            AgentKind::Codex,
-        );
+        // Arrange
+        let mut slash_state = PromptSlashState::new();
+        slash_state.stage = PromptSlashStage::Agent;
+
+        // Act
+        let count = prompt_slash_option_count("/model", &slash_state, AgentKind::Codex);
 
         // Assert
         assert_eq!(count, AgentKind::ALL.len());
@@ -1885,14 +2563,13 @@ mod tests {
 
     #[test]
     fn test_prompt_slash_option_count_for_model_stage() {
-        // Arrange & Act
-        let count = prompt_slash_option_count(
-            "/model",
-            PromptSlashStage::Model,
-            Some(AgentKind::Claude),
-            AgentKind::ALL,
-            AgentKind::Codex,
-        );
+        // Arrange
+        let mut slash_state = PromptSlashState::new();
+        slash_state.stage = PromptSlashStage::Model;
+        slash_state.selected_agent = Some(AgentKind::Claude);
+
+        // Act
+        let count = prompt_slash_option_count("/model", &slash_state, AgentKind::Codex);
 
         // Assert
         assert_eq!(count, AgentKind::Claude.models().len());
@@ -1901,16 +2578,11 @@ mod tests {
     #[test]
     fn test_prompt_slash_option_count_for_agent_stage_uses_available_agent_kinds() {
         // Arrange
-        let available_agent_kinds = [AgentKind::Codex];
+        let mut slash_state = PromptSlashState::with_available_agent_kinds(vec![AgentKind::Codex]);
+        slash_state.stage = PromptSlashStage::Agent;
 
         // Act
-        let count = prompt_slash_option_count(
-            "/model",
-            PromptSlashStage::Agent,
-            None,
-            &available_agent_kinds,
-            AgentKind::Codex,
-        );
+        let count = prompt_slash_option_count("/model", &slash_state, AgentKind::Codex);
 
         // Assert
         assert_eq!(count, 1);
@@ -2131,6 +2803,33 @@ mod tests {
         assert!(app.sessions.sessions[0].output.contains("## Session Stats"));
     }
 
+    #[tokio::test]
+    async fn test_handle_prompt_slash_submit_toggles_plan_first_mode() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("/plan", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_slash_submit(&mut app, &prompt_context).await;
+        let enabled_plan = app.sessions.sessions[0].plan.clone();
+        handle_plan_command(&mut app, &prompt_context).await;
+
+        // Assert
+        app.sessions.sync_from_handles();
+        assert_eq!(enabled_plan, Some(SessionPlan::planning()));
+        assert_eq!(app.sessions.sessions[0].plan, None);
+        assert!(
+            app.sessions.sessions[0]
+                .output
+                .contains("[Plan] Plan-first mode enabled.")
+        );
+        assert!(
+            app.sessions.sessions[0]
+                .output
+                .contains("[Plan] Plan-first mode disabled.")
+        );
+    }
+
     #[tokio::test]
     async fn test_handle_prompt_slash_submit_prefills_reasoning_selection_from_default_setting() {
         // Arrange
@@ -2191,6 +2890,38 @@ mod tests {
         assert!(app.sessions.sessions[0].output.contains("## Session Stats"));
     }
 
+    #[tokio::test]
+    async fn test_handle_prompt_slash_submit_expands_template_and_applies_reasoning_pin() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("/tests \"parser module\"", None).await;
+        let template = crate::domain::prompt_template::PromptTemplate::parse(
+            "tests",
+            "+++\nreasoning = \"high\"\n+++\nWrite tests for the {{target}}.",
+            crate::domain::prompt_template::PromptTemplateScope::Project,
+        )
+        .expect("template should parse");
+        if let AppMode::Prompt { slash_state, .. } = &mut app.mode {
+            *slash_state = PromptSlashState::new().with_prompt_templates(vec![template]);
+        }
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_slash_submit(&mut app, &prompt_context).await;
+
+        // Assert
+        if let AppMode::Prompt {
+            input, slash_state, ..
+        } = &app.mode
+        {
+            assert_eq!(input.text(), "Write tests for the parser module.");
+            assert_eq!(slash_state.stage, PromptSlashStage::Command);
+        }
+        assert_eq!(
+            app.sessions.sessions[0].reasoning_level_override,
+            Some(ReasoningLevel::High)
+        );
+    }
+
     /// Verifies slash submit ignores unmatched commands and preserves the
     /// prompt state.
     #[tokio::test]
@@ -2695,7 +3426,7 @@ mod tests {
         let (mut app, _base_dir) = new_test_prompt_app("email@test", Some(state)).await;
 
         // Act
-        handle_at_mention_select(&mut app);
+        handle_at_mention_select(&mut app, false);
 
         // Assert
         assert!(matches!(app.mode, AppMode::Prompt { .. }));
@@ -2720,7 +3451,7 @@ mod tests {
         let (mut app, _base_dir) = new_test_prompt_app("@src", Some(state)).await;
 
         // Act
-        handle_at_mention_select(&mut app);
+        handle_at_mention_select(&mut app, false);
 
         // Assert
         assert!(matches!(app.mode, AppMode::Prompt { .. }));
@@ -2748,7 +3479,7 @@ mod tests {
         let (mut app, _base_dir) = new_test_prompt_app("@src/ma", Some(state)).await;
 
         // Act
-        handle_at_mention_select(&mut app);
+        handle_at_mention_select(&mut app, false);
 
         // Assert
         if let AppMode::Prompt {
@@ -2927,6 +3658,175 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_replaces_file_directive_with_placeholder() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("Explain\n/file README.md", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::Prompt { .. }));
+        if let AppMode::Prompt {
+            attachment_state,
+            input,
+            ..
+        } = &app.mode
+        {
+            assert_eq!(input.text(), "Explain\n[File #1]");
+            assert_eq!(attachment_state.context_attachments.len(), 1);
+            assert_eq!(attachment_state.context_attachments[0].content, "test");
+            assert_eq!(
+                attachment_state.context_attachments[0].label,
+                "File `README.md`"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_captures_run_directive_in_background() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("Explain\n/run echo hi", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+        let is_pending_before_event = has_pending_prompt_context(&app);
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+        let captured_event = wait_for_prompt_context_captured_event(&mut app).await;
+        app.apply_app_events(captured_event).await;
+
+        // Assert
+        assert!(is_pending_before_event);
+        assert!(matches!(app.mode, AppMode::Prompt { .. }));
+        if let AppMode::Prompt {
+            attachment_state,
+            input,
+            ..
+        } = &app.mode
+        {
+            assert_eq!(input.text(), "Explain\n[Command #1]");
+            assert!(!attachment_state.has_pending_context());
+            assert!(
+                attachment_state.context_attachments[0]
+                    .content
+                    .starts_with("hi\n")
+            );
+            assert_eq!(
+                attachment_state.context_attachments[0].label,
+                "Command `echo hi`"
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_restores_url_directive_when_capture_fails() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("/url file:///etc/passwd", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+        let captured_event = wait_for_prompt_context_captured_event(&mut app).await;
+        app.apply_app_events(captured_event).await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::Prompt { .. }));
+        if let AppMode::Prompt {
+            attachment_state,
+            input,
+            ..
+        } = &app.mode
+        {
+            assert_eq!(input.text(), "/url file:///etc/passwd");
+            assert!(attachment_state.context_attachments.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_keeps_directive_when_capture_fails() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("/snippet missing", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::Prompt { .. }));
+        if let AppMode::Prompt {
+            attachment_state,
+            input,
+            ..
+        } = &app.mode
+        {
+            assert_eq!(input.text(), "/snippet missing");
+            assert!(attachment_state.context_attachments.is_empty());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_attaches_selected_skill_from_slash_menu() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("/sk", None).await;
+        let skill = Skill::parse(
+            "review",
+            "---\ndescription: Review the diff\n---\nCheck every file.",
+            std::path::PathBuf::from("/repo/skills/review/SKILL.md"),
+            crate::domain::skill::SkillScope::Project,
+        )
+        .expect("skill should parse");
+        if let AppMode::Prompt { slash_state, .. } = &mut app.mode {
+            *slash_state = PromptSlashState::new().with_skills(vec![skill]);
+        }
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+        if let AppMode::Prompt { input, .. } = &mut app.mode {
+            input.insert_text(" Review the parser");
+        }
+        let prompt = take_submitted_turn_prompt(&mut app);
+
+        // Assert
+        assert_eq!(prompt.text, "[Skill #1] Review the parser");
+        assert!(prompt.context_attachments.is_empty());
+        assert_eq!(
+            prompt.skills,
+            vec![TurnPromptSkill {
+                body: "Check every file.".to_string(),
+                name: "review".to_string(),
+                path: std::path::PathBuf::from("/repo/skills/review/SKILL.md"),
+                placeholder: "[Skill #1]".to_string(),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_stages_draft_with_inlined_context() {
+        // Arrange
+        let (mut app, _base_dir) =
+            new_test_draft_prompt_app("Explain\n/file README.md", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::View { .. }));
+        assert_eq!(
+            app.sessions.sessions[0].prompt,
+            "Explain\n[File #1]\n\nAttached context:\n\n[File #1] File `README.md`\n```\ntest\n```"
+        );
+        assert_eq!(
+            app.sessions.sessions[0].title.as_deref(),
+            Some("Explain\n[File #1]")
+        );
+    }
+
     #[tokio::test]
     async fn test_handle_prompt_submit_key_starts_regular_session_with_image_turn() {
         // Arrange
@@ -3454,4 +4354,113 @@ mod tests {
             "attachments must survive validation failure so the user keeps their pasted files",
         );
     }
+
+    #[tokio::test]
+    async fn test_handle_prompt_submit_key_records_prompt_history() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_draft_prompt_app("Review the parser", None).await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+
+        // Act
+        handle_prompt_submit_key(&mut app, &prompt_context).await;
+
+        // Assert
+        assert_eq!(app.load_prompt_history().await, vec!["Review the parser"]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_history_search_key_inserts_selected_match() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("draft", None).await;
+        app.record_prompt_history("Review the parser").await;
+        app.record_prompt_history("Fix the login flow").await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+        open_prompt_history_search(&mut app).await;
+
+        // Act
+        for character in "pars".chars() {
+            handle_prompt_history_search_key(
+                &mut app,
+                &prompt_context,
+                KeyEvent::new(KeyCode::Char(character), event::KeyModifiers::NONE),
+            )
+            .await;
+        }
+        handle_prompt_history_search_key(
+            &mut app,
+            &prompt_context,
+            KeyEvent::new(KeyCode::Enter, event::KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        let AppMode::Prompt {
+            history_state,
+            input,
+            ..
+        } = &app.mode
+        else {
+            unreachable!("expected AppMode::Prompt after history selection");
+        };
+        assert_eq!(input.text(), "Review the parser");
+        assert!(history_state.search.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_history_search_key_moves_to_older_match_and_closes() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("draft", None).await;
+        app.record_prompt_history("First prompt").await;
+        app.record_prompt_history("Second prompt").await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+        open_prompt_history_search(&mut app).await;
+
+        // Act
+        handle_prompt_history_search_key(
+            &mut app,
+            &prompt_context,
+            KeyEvent::new(KeyCode::Char('r'), event::KeyModifiers::CONTROL),
+        )
+        .await;
+        let selected_prompt = selected_prompt_history_entry(&app);
+        handle_prompt_history_search_key(
+            &mut app,
+            &prompt_context,
+            KeyEvent::new(KeyCode::Esc, event::KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(selected_prompt.as_deref(), Some("First prompt"));
+        assert!(!is_prompt_history_search_active(&app));
+        assert!(matches!(
+            &app.mode,
+            AppMode::Prompt { input, .. } if input.text() == "draft"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_prompt_history_search_key_reruns_prompt_in_blank_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_prompt_app("", None).await;
+        app.record_prompt_history("Fix the login flow").await;
+        let prompt_context = prompt_context(&mut app).expect("expected prompt context");
+        open_prompt_history_search(&mut app).await;
+
+        // Act
+        handle_prompt_history_search_key(
+            &mut app,
+            &prompt_context,
+            KeyEvent::new(KeyCode::Char('o'), event::KeyModifiers::CONTROL),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(
+            &app.mode,
+            AppMode::View { session_id, .. } if *session_id == prompt_context.session_id
+        ));
+        assert_eq!(app.sessions.sessions.len(), 1);
+        assert_eq!(app.sessions.sessions[0].prompt, "Fix the login flow");
+    }
 }
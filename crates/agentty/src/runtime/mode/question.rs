@@ -4,7 +4,7 @@ use ratatui::layout::Rect;
 use crate::app::{self, App, AppEvent};
 use crate::domain::input::InputState;
 use crate::domain::session::{SessionId, Status};
-use crate::infra::agent::protocol::QuestionItem;
+use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
 use crate::infra::channel::TurnPrompt;
 use crate::runtime::EventResult;
 use crate::runtime::mode::{at_mention, input_key};
@@ -18,13 +18,21 @@ use crate::ui::state::prompt::PromptAtMentionState;
 /// Default response stored when users skip one model question.
 const NO_ANSWER: &str = "no answer";
 
+/// Initial answer text for file-pick questions so the worktree file dropdown
+/// opens immediately.
+const FILE_PICK_INPUT: &str = "@";
+
+/// Separator placed between checked multi-select answers.
+const MULTI_SELECT_SEPARATOR: &str = ", ";
+
 /// Applies one key event in question-answer mode.
 ///
 /// `Tab` toggles focus between the question panel and the chat output for
 /// scrolling. When chat is focused, scroll keys (`j`/`k`/`Up`/`Down`/`g`/`G`/
 /// `Ctrl+d`/`Ctrl+u`) navigate the session transcript. `Enter` submits the
-/// typed answer (or `no answer` when blank), and `Esc` ends the entire turn
-/// without sending a reply, reverting the session to `Review`.
+/// answer for the active question kind (or `no answer` when blank), and `Esc`
+/// ends the entire turn without sending a reply, reverting the session to
+/// `Review`.
 pub(crate) async fn handle(app: &mut App, terminal_size: Rect, key: KeyEvent) -> EventResult {
     if handle_focus_toggle(app, key) {
         return EventResult::Continue;
@@ -166,11 +174,14 @@ fn question_view_metrics(app: &App, terminal_size: Rect) -> QuestionViewMetrics
         AppMode::List
         | AppMode::Confirmation { .. }
         | AppMode::SyncBlockedPopup { .. }
+        | AppMode::IssuePicker { .. }
+        | AppMode::SessionSearch { .. }
         | AppMode::Prompt { .. }
         | AppMode::View { .. }
         | AppMode::Diff { .. }
         | AppMode::Help { .. }
         | AppMode::OpenCommandSelector { .. }
+        | AppMode::PlanReview { .. }
         | AppMode::PublishBranchInput { .. }
         | AppMode::ViewInfoPopup { .. } => (None, None),
     };
@@ -178,17 +189,11 @@ fn question_view_metrics(app: &App, terminal_size: Rect) -> QuestionViewMetrics
         .and_then(|index| app.sessions.sessions.get(index))
         .map_or(0, |session| {
             let active_progress = app.session_progress_message(session_id);
-            let active_prompt_output = app
-                .sessions
-                .active_prompt_outputs()
-                .get(session_id)
-                .map(std::string::String::as_str);
 
             SessionChatPage::rendered_output_line_count(
                 session,
                 output_width,
                 SessionOutputLineContext {
-                    active_prompt_output,
                     active_progress,
                     done_session_output_mode: DoneSessionOutputMode::Summary,
                     review_status_message,
@@ -285,6 +290,7 @@ fn take_question_snapshot(app: &mut App) -> Option<QuestionModeSnapshot> {
 
     if let AppMode::Question {
         at_mention_state,
+        checked_option_indexes,
         current_index,
         input,
         questions,
@@ -299,6 +305,7 @@ fn take_question_snapshot(app: &mut App) -> Option<QuestionModeSnapshot> {
     {
         Some(QuestionModeSnapshot {
             at_mention_state,
+            checked_option_indexes,
             current_index,
             input,
             questions,
@@ -345,19 +352,37 @@ pub(crate) fn handle_paste(app: &mut App, pasted_text: &str) {
 }
 
 /// Returns the default selected option index for a question at the given
-/// position. Returns `Some(0)` when the question has predefined options so
+/// position. Returns `Some(0)` when the question kind lists answer options so
 /// the UI starts in option-selection mode, or `None` when there are no
-/// predefined options so the input line opens immediately.
+/// options so the input line opens immediately.
 pub(crate) fn default_option_index(
     questions: &[QuestionItem],
     question_index: usize,
 ) -> Option<usize> {
     questions
         .get(question_index)
-        .filter(|item| !item.options.is_empty())
+        .filter(|item| !item.answer_options().is_empty())
         .map(|_| 0)
 }
 
+/// Returns the initial answer input for a question at the given position.
+///
+/// File-pick questions start with an `@` token so the worktree file dropdown
+/// opens immediately. Every other kind starts with an empty input.
+pub(crate) fn default_question_input(
+    questions: &[QuestionItem],
+    question_index: usize,
+) -> InputState {
+    let is_file_pick = questions
+        .get(question_index)
+        .is_some_and(|item| item.kind == QuestionKind::FilePick);
+    if is_file_pick {
+        return InputState::with_text(FILE_PICK_INPUT.to_string());
+    }
+
+    InputState::default()
+}
+
 /// Semantic action emitted by one question-mode key event.
 enum QuestionAction {
     Submit(String),
@@ -369,14 +394,17 @@ enum QuestionAction {
 
 /// Resolves and applies one key event against question input state.
 ///
-/// When navigating predefined options (`selected_option_index` is `Some`),
+/// When navigating answer options (`selected_option_index` is `Some`),
 /// `Up`/`Down`/`j`/`k` cycle through the options. Moving past the last (or
 /// first) option automatically enters free-text mode where the text input is
-/// visible. In free-text mode, `Up` returns to the last predefined option
-/// and `Down` wraps to the first.
+/// visible, except for confirm questions which only wrap between `Yes` and
+/// `No`. In free-text mode, `Up` returns to the last option and `Down` wraps
+/// to the first. `Space` toggles the highlighted option of a multi-select
+/// question, and `y`/`n` answer a confirm question directly.
 fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionAction> {
     let action = {
         let AppMode::Question {
+            checked_option_indexes,
             current_index,
             input,
             questions,
@@ -387,9 +415,13 @@ fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionActio
             return None;
         };
 
-        let option_count = questions
-            .get(*current_index)
-            .map_or(0, |item| item.options.len());
+        let question_item = questions.get(*current_index);
+        let kind = question_item.map(|item| item.kind).unwrap_or_default();
+        let options = question_item
+            .map(QuestionItem::answer_options)
+            .unwrap_or_default();
+        let option_count = options.len();
+        let allows_free_text = kind.allows_free_text();
         let is_navigating_options = selected_option_index.is_some();
 
         match key.code {
@@ -401,16 +433,33 @@ fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionActio
 
                 QuestionAction::Continue
             }
-            KeyCode::Enter => {
-                resolve_enter_action(input, questions, *current_index, selected_option_index)
+            KeyCode::Enter => resolve_enter_action(
+                input,
+                &options,
+                kind,
+                checked_option_indexes,
+                *selected_option_index,
+            ),
+            KeyCode::Char(' ') if is_navigating_options && kind == QuestionKind::MultiSelect => {
+                if let Some(option_index) = *selected_option_index {
+                    toggle_checked_option(checked_option_indexes, option_index);
+                }
+
+                QuestionAction::Continue
+            }
+            KeyCode::Char(character @ ('y' | 'n')) if kind == QuestionKind::Confirm => {
+                let option_index = usize::from(character == 'n');
+                let response = options.get(option_index).cloned().unwrap_or_default();
+
+                QuestionAction::Submit(response)
             }
             KeyCode::Up | KeyCode::Char('k') if is_navigating_options => {
-                navigate_option_up(selected_option_index);
+                navigate_option_up(selected_option_index, option_count, allows_free_text);
 
                 QuestionAction::Continue
             }
             KeyCode::Down | KeyCode::Char('j') if is_navigating_options => {
-                navigate_option_down(selected_option_index, option_count);
+                navigate_option_down(selected_option_index, option_count, allows_free_text);
 
                 QuestionAction::Continue
             }
@@ -444,20 +493,29 @@ fn resolve_question_action(app: &mut App, key: KeyEvent) -> Option<QuestionActio
 
 /// Resolves an `Enter` key press in question mode.
 ///
-/// When navigating options, submits the highlighted predefined option. In
-/// free-text mode, submits the typed text.
+/// Multi-select questions submit every checked option. Other kinds submit
+/// the highlighted option while navigating options, or the typed text in
+/// free-text mode.
 fn resolve_enter_action(
     input: &mut InputState,
-    questions: &[QuestionItem],
-    current_index: usize,
-    selected_option_index: &mut Option<usize>,
+    options: &[String],
+    kind: QuestionKind,
+    checked_option_indexes: &[usize],
+    selected_option_index: Option<usize>,
 ) -> QuestionAction {
-    if let Some(option_index) = *selected_option_index {
-        let selected_text = questions
-            .get(current_index)
-            .and_then(|item| item.options.get(option_index))
-            .cloned()
-            .unwrap_or_default();
+    if kind == QuestionKind::MultiSelect {
+        let response_text = multi_select_response(
+            input,
+            options,
+            checked_option_indexes,
+            selected_option_index,
+        );
+
+        return QuestionAction::Submit(normalize_response_text(&response_text));
+    }
+
+    if let Some(option_index) = selected_option_index {
+        let selected_text = options.get(option_index).cloned().unwrap_or_default();
 
         QuestionAction::Submit(normalize_response_text(&selected_text))
     } else {
@@ -467,21 +525,78 @@ fn resolve_enter_action(
     }
 }
 
+/// Builds the response text for one multi-select question.
+///
+/// Checked options are listed in option order, followed by the typed custom
+/// answer when free-text mode is active. When nothing is checked and no
+/// custom answer is typed, the highlighted option is used so `Enter` alone
+/// still answers the question.
+fn multi_select_response(
+    input: &mut InputState,
+    options: &[String],
+    checked_option_indexes: &[usize],
+    selected_option_index: Option<usize>,
+) -> String {
+    let mut answers = options
+        .iter()
+        .enumerate()
+        .filter(|(option_index, _)| checked_option_indexes.contains(option_index))
+        .map(|(_, option)| option.clone())
+        .collect::<Vec<_>>();
+
+    if selected_option_index.is_none() {
+        let custom_answer = input.take_text();
+        if !custom_answer.trim().is_empty() {
+            answers.push(custom_answer.trim().to_string());
+        }
+    }
+
+    if answers.is_empty()
+        && let Some(option) = selected_option_index.and_then(|index| options.get(index))
+    {
+        answers.push(option.clone());
+    }
+
+    answers.join(MULTI_SELECT_SEPARATOR)
+}
+
+/// Checks the option at `option_index`, or unchecks it when already checked.
+fn toggle_checked_option(checked_option_indexes: &mut Vec<usize>, option_index: usize) {
+    if let Some(position) = checked_option_indexes
+        .iter()
+        .position(|checked_index| *checked_index == option_index)
+    {
+        checked_option_indexes.remove(position);
+    } else {
+        checked_option_indexes.push(option_index);
+    }
+}
+
 /// Moves the selected option index up, entering free-text mode when wrapping
-/// past the first predefined option.
-fn navigate_option_up(selected_option_index: &mut Option<usize>) {
+/// past the first option of a kind that allows free text.
+fn navigate_option_up(
+    selected_option_index: &mut Option<usize>,
+    option_count: usize,
+    allows_free_text: bool,
+) {
     *selected_option_index = match *selected_option_index {
-        Some(0) => None,
+        Some(0) if allows_free_text => None,
+        Some(0) => Some(option_count.saturating_sub(1)),
         Some(index) => Some(index.saturating_sub(1)),
         None => unreachable!(),
     };
 }
 
 /// Moves the selected option index down, entering free-text mode when
-/// advancing past the last predefined option.
-fn navigate_option_down(selected_option_index: &mut Option<usize>, option_count: usize) {
+/// advancing past the last option of a kind that allows free text.
+fn navigate_option_down(
+    selected_option_index: &mut Option<usize>,
+    option_count: usize,
+    allows_free_text: bool,
+) {
     *selected_option_index = match *selected_option_index {
-        Some(index) if index + 1 >= option_count => None,
+        Some(index) if index + 1 >= option_count && allows_free_text => None,
+        Some(index) if index + 1 >= option_count => Some(0),
         Some(index) => Some(index + 1),
         None => unreachable!(),
     };
@@ -600,8 +715,13 @@ fn is_active_at_mention(app: &App) -> bool {
 fn handle_at_mention_key(app: &mut App, key: KeyEvent) -> bool {
     match key.code {
         KeyCode::Esc => dismiss_question_at_mention(app),
-        KeyCode::Enter | KeyCode::Tab => {
-            handle_question_at_mention_select(app);
+        KeyCode::Enter => {
+            handle_question_at_mention_select(app, false);
+
+            return true;
+        }
+        KeyCode::Tab => {
+            handle_question_at_mention_select(app, true);
 
             return true;
         }
@@ -619,7 +739,9 @@ fn handle_at_mention_key(app: &mut App, key: KeyEvent) -> bool {
 /// Opens the dropdown when the cursor sits inside an `@` token and the
 /// dropdown is not yet visible. Resets the selection index when already open.
 /// Dismisses the dropdown when the cursor moves away from any `@` token.
-fn sync_question_at_mention_state(app: &mut App) {
+/// Callers entering question mode run this once so file-pick questions show
+/// the worktree file list before the first key press.
+pub(crate) fn sync_question_at_mention_state(app: &mut App) {
     let (session_id, sync_action) = match &app.mode {
         AppMode::Question {
             at_mention_state,
@@ -629,7 +751,7 @@ fn sync_question_at_mention_state(app: &mut App) {
             ..
         } => (
             session_id.clone(),
-            at_mention::sync_action(input, at_mention_state.as_ref()),
+            at_mention::sync_action(input, at_mention_state.as_deref()),
         ),
         _ => return,
     };
@@ -679,7 +801,7 @@ fn activate_question_at_mention(app: &mut App, session_id: &str) {
         at_mention_state, ..
     } = &mut app.mode
     {
-        *at_mention_state = Some(PromptAtMentionState::new(Vec::new()));
+        *at_mention_state = Some(Box::new(PromptAtMentionState::new(Vec::new())));
     }
 }
 
@@ -716,15 +838,17 @@ fn handle_question_at_mention_down(app: &mut App) {
     }
 }
 
-/// Selects the currently highlighted file and inserts it into the question
-/// input.
-fn handle_question_at_mention_select(app: &mut App) {
+/// Selects the currently highlighted file or symbol and inserts it into the
+/// question input.
+///
+/// `include_snippet` appends the source of a selected `@#` symbol.
+fn handle_question_at_mention_select(app: &mut App, include_snippet: bool) {
     let replacement = match &app.mode {
         AppMode::Question {
             at_mention_state: Some(state),
             input,
             ..
-        } => at_mention::selected_replacement(input, state),
+        } => at_mention::selected_replacement(input, state, include_snippet),
         _ => return,
     };
 
@@ -746,6 +870,8 @@ fn handle_question_at_mention_select(app: &mut App) {
 /// Stores one question response and runs follow-up reply when complete.
 async fn submit_response(app: &mut App, response: String) {
     let Some((session_id, questions, responses)) = store_question_response(app, response) else {
+        sync_question_at_mention_state(app);
+
         return;
     };
 
@@ -756,6 +882,7 @@ async fn submit_response(app: &mut App, response: String) {
         review_text: None,
         session_id: session_id.clone(),
         scroll_offset: None,
+        transcript_search: None,
     };
     app.reply(&session_id, TurnPrompt::from_text(question_reply))
         .await;
@@ -827,6 +954,7 @@ async fn end_turn_no_answer(app: &mut App) {
         review_text,
         session_id,
         scroll_offset: None,
+        transcript_search: None,
     };
 }
 
@@ -838,6 +966,7 @@ fn store_question_response(
 ) -> Option<(SessionId, Vec<QuestionItem>, Vec<String>)> {
     let AppMode::Question {
         at_mention_state,
+        checked_option_indexes,
         current_index,
         input,
         questions,
@@ -852,8 +981,9 @@ fn store_question_response(
 
     responses.push(response);
     *current_index += 1;
-    *input = InputState::default();
+    *input = default_question_input(questions, *current_index);
     *at_mention_state = None;
+    checked_option_indexes.clear();
     *selected_option_index = default_option_index(questions, *current_index);
 
     if *current_index < questions.len() {
@@ -941,15 +1071,18 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "missing-session".into(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Yes".to_string(), "No".to_string()],
                     text: "Need a target branch?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Unit".to_string(), "Integration".to_string()],
                     text: "Need tests?".to_string(),
                 },
@@ -996,15 +1129,18 @@ mod tests {
         );
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-esc".into(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Yes".to_string(), "No".to_string()],
                     text: "First question?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["A".to_string(), "B".to_string()],
                     text: "Second question?".to_string(),
                 },
@@ -1059,8 +1195,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: String::new(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1073,14 +1211,17 @@ mod tests {
             status: Status::Question,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         });
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: session_id.into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Q?".to_string(),
             }],
@@ -1132,8 +1273,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: String::new(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -1146,6 +1289,7 @@ mod tests {
             status: Status::Question,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         });
         app.sessions.handles.insert(
@@ -1154,10 +1298,12 @@ mod tests {
         );
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: session_id.into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Q?".to_string(),
             }],
@@ -1217,10 +1363,12 @@ mod tests {
             .expect("failed to open timing window");
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: session_id.into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Q?".to_string(),
             }],
@@ -1262,10 +1410,12 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "missing-session".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Today".to_string(), "Tomorrow".to_string()],
                 text: "Need exact date?".to_string(),
             }],
@@ -1302,10 +1452,12 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Default".to_string()],
                 text: "Question".to_string(),
             }],
@@ -1354,10 +1506,12 @@ mod tests {
     fn question_mode_with_options() -> AppMode {
         AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec![
                     "Option A".to_string(),
                     "Option B".to_string(),
@@ -1523,15 +1677,18 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "missing-session".into(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Yes".to_string(), "No".to_string()],
                     text: "Continue?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Details".to_string(), "Skip".to_string()],
                     text: "Follow-up?".to_string(),
                 },
@@ -1784,15 +1941,18 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "missing-session".into(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Foo".to_string()],
                     text: "First question?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Alpha".to_string(), "Beta".to_string()],
                     text: "Pick one?".to_string(),
                 },
@@ -1828,6 +1988,7 @@ mod tests {
     fn test_default_option_index_returns_first_when_options_exist() {
         // Arrange
         let questions = vec![QuestionItem {
+            kind: QuestionKind::SingleChoice,
             options: vec!["A".to_string(), "B".to_string()],
             text: "Pick?".to_string(),
         }];
@@ -1841,6 +2002,7 @@ mod tests {
         // Arrange — without predefined options the UI starts directly in
         // free-text input mode.
         let questions = vec![QuestionItem {
+            kind: QuestionKind::SingleChoice,
             options: Vec::new(),
             text: "Type something?".to_string(),
         }];
@@ -1849,6 +2011,179 @@ mod tests {
         assert_eq!(default_option_index(&questions, 0), None);
     }
 
+    #[test]
+    fn test_default_option_index_follows_question_kind() {
+        // Arrange — free-text questions ignore options while confirm
+        // questions always list `Yes`/`No`.
+        let questions = vec![
+            QuestionItem::with_options("Why?", vec!["Because".to_string()])
+                .with_kind(QuestionKind::FreeText),
+            QuestionItem::new("Proceed?").with_kind(QuestionKind::Confirm),
+        ];
+
+        // Act & Assert
+        assert_eq!(default_option_index(&questions, 0), None);
+        assert_eq!(default_option_index(&questions, 1), Some(0));
+    }
+
+    /// Creates a question mode for `questions` with the production defaults
+    /// for the first question's kind.
+    fn question_mode_for(questions: Vec<QuestionItem>) -> AppMode {
+        AppMode::Question {
+            at_mention_state: None,
+            checked_option_indexes: Vec::new(),
+            review_status_message: None,
+            review_text: None,
+            session_id: "missing-session".into(),
+            responses: Vec::new(),
+            current_index: 0,
+            focus: QuestionFocus::Answer,
+            input: default_question_input(&questions, 0),
+            scroll_offset: None,
+            selected_option_index: default_option_index(&questions, 0),
+            questions,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_space_and_enter_submit_checked_multi_select_options() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = question_mode_for(vec![
+            QuestionItem::with_options(
+                "Pick some?",
+                vec![
+                    "Option A".to_string(),
+                    "Option B".to_string(),
+                    "Option C".to_string(),
+                ],
+            )
+            .with_kind(QuestionKind::MultiSelect),
+            QuestionItem::new("Anything else?"),
+        ]);
+
+        // Act
+        for key_code in [
+            KeyCode::Char(' '),
+            KeyCode::Down,
+            KeyCode::Down,
+            KeyCode::Char(' '),
+            KeyCode::Enter,
+        ] {
+            let _ = handle(
+                &mut app,
+                TEST_TERMINAL_SIZE,
+                KeyEvent::new(key_code, KeyModifiers::NONE),
+            )
+            .await;
+        }
+
+        // Assert
+        let AppMode::Question {
+            checked_option_indexes,
+            current_index,
+            responses,
+            ..
+        } = &app.mode
+        else {
+            unreachable!("expected question mode");
+        };
+        assert_eq!(*current_index, 1);
+        assert!(checked_option_indexes.is_empty());
+        assert_eq!(responses, &vec!["Option A, Option C".to_string()]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_y_answers_confirm_question() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = question_mode_for(vec![
+            QuestionItem::new("Proceed?").with_kind(QuestionKind::Confirm),
+            QuestionItem::new("Anything else?"),
+        ]);
+
+        // Act
+        let _ = handle(
+            &mut app,
+            TEST_TERMINAL_SIZE,
+            KeyEvent::new(KeyCode::Char('y'), KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(
+            &app.mode,
+            AppMode::Question {
+                current_index: 1,
+                responses,
+                ..
+            } if responses == &vec!["Yes".to_string()]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_down_from_last_confirm_option_wraps_to_first() {
+        // Arrange — confirm questions have no free-text input to wrap into.
+        let mut app = new_test_app().await;
+        app.mode = question_mode_for(vec![
+            QuestionItem::new("Proceed?").with_kind(QuestionKind::Confirm),
+        ]);
+        if let AppMode::Question {
+            selected_option_index,
+            ..
+        } = &mut app.mode
+        {
+            *selected_option_index = Some(1);
+        }
+
+        // Act
+        let _ = handle(
+            &mut app,
+            TEST_TERMINAL_SIZE,
+            KeyEvent::new(KeyCode::Down, KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::Question {
+                selected_option_index: Some(0),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_store_question_response_prefills_file_pick_input() {
+        // Arrange
+        let mut app = new_test_app().await;
+        app.mode = question_mode_for(vec![
+            QuestionItem::new("Why?").with_kind(QuestionKind::FreeText),
+            QuestionItem::new("Which file?").with_kind(QuestionKind::FilePick),
+        ]);
+
+        // Act
+        let _ = handle(
+            &mut app,
+            TEST_TERMINAL_SIZE,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(
+            &app.mode,
+            AppMode::Question {
+                at_mention_state: Some(_),
+                current_index: 1,
+                input,
+                selected_option_index: None,
+                ..
+            } if input.text() == "@"
+        ));
+    }
+
     #[test]
     fn test_default_option_index_returns_none_for_out_of_bounds() {
         // Arrange
@@ -2072,10 +2407,12 @@ mod tests {
         // Arrange
         let questions = vec![
             QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["main".to_string(), "develop".to_string()],
                 text: "Need target?".to_string(),
             },
             QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Yes".to_string(), "No".to_string()],
                 text: "Need tests?".to_string(),
             },
@@ -2100,10 +2437,12 @@ mod tests {
 
         AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Question?".to_string(),
             }],
@@ -2548,15 +2887,18 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "missing-session".into(),
             questions: vec![
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["Yes".to_string(), "No".to_string()],
                     text: "Continue?".to_string(),
                 },
                 QuestionItem {
+                    kind: QuestionKind::SingleChoice,
                     options: vec!["A".to_string()],
                     text: "Follow-up?".to_string(),
                 },
@@ -2595,10 +2937,12 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-esc-chat".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Yes".to_string()],
                 text: "Continue?".to_string(),
             }],
@@ -2636,10 +2980,12 @@ mod tests {
         let mut app = new_test_app().await;
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-esc-answer".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Q?".to_string(),
             }],
@@ -2692,8 +3038,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: String::new(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -2706,15 +3054,18 @@ mod tests {
             status: Status::Question,
             summary: None,
             title: None,
+            turns: Vec::new(),
             updated_at: 0,
         });
 
         app.mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: session_id.into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["A".to_string()],
                 text: "Pick one".to_string(),
             }],
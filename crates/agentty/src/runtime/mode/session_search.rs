@@ -0,0 +1,333 @@
+use std::io;
+
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::Terminal;
+use ratatui::backend::Backend;
+
+use crate::app::App;
+use crate::domain::session::SessionSearchHit;
+use crate::runtime::EventResult;
+use crate::runtime::mode::input_key::is_insertable_char_key;
+use crate::runtime::mode::{list, session_view};
+use crate::ui::state::app_mode::AppMode;
+
+/// Handles key input while the global session search overlay is visible.
+///
+/// Typed characters edit the query and rerun the search, `Up`/`Down` move
+/// the selection, `Enter` opens the selected hit at its matching turn, and
+/// `Esc` closes the overlay.
+pub(crate) async fn handle<B: Backend>(
+    app: &mut App,
+    terminal: &Terminal<B>,
+    key: KeyEvent,
+) -> io::Result<EventResult>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let AppMode::SessionSearch {
+        hits,
+        input,
+        selected_hit_index,
+    } = &mut app.mode
+    else {
+        return Ok(EventResult::Continue);
+    };
+
+    match key.code {
+        KeyCode::Esc => app.mode = AppMode::List,
+        KeyCode::Down => {
+            if *selected_hit_index + 1 < hits.len() {
+                *selected_hit_index += 1;
+            }
+        }
+        KeyCode::Up => {
+            *selected_hit_index = selected_hit_index.saturating_sub(1);
+        }
+        KeyCode::Left => input.move_left(),
+        KeyCode::Right => input.move_right(),
+        KeyCode::Enter => {
+            if let Some(hit) = hits.get(*selected_hit_index).cloned() {
+                let query = input.text().to_string();
+                open_search_hit(app, terminal, &hit, &query).await?;
+            }
+        }
+        KeyCode::Backspace => {
+            input.delete_backward();
+            refresh_search_hits(app).await;
+        }
+        KeyCode::Char(character) if is_insertable_char_key(key) => {
+            input.insert_char(character);
+            refresh_search_hits(app).await;
+        }
+        _ => {}
+    }
+
+    Ok(EventResult::Continue)
+}
+
+/// Reruns the search for the current overlay query and selects the best hit.
+async fn refresh_search_hits(app: &mut App) {
+    let AppMode::SessionSearch { input, .. } = &app.mode else {
+        return;
+    };
+    let query = input.text().to_string();
+    let search_hits = app.search_sessions(&query).await;
+
+    if let AppMode::SessionSearch {
+        hits,
+        input,
+        selected_hit_index,
+    } = &mut app.mode
+        && input.text() == query
+    {
+        *hits = search_hits;
+        *selected_hit_index = 0;
+    }
+}
+
+/// Switches to the hit project, opens the hit session, and scrolls the
+/// session view to the matching turn.
+///
+/// Returns to list mode when the session can no longer be opened.
+async fn open_search_hit<B: Backend>(
+    app: &mut App,
+    terminal: &Terminal<B>,
+    hit: &SessionSearchHit,
+    query: &str,
+) -> io::Result<()>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let Ok(Some(session_index)) = app.select_session_search_hit(hit).await else {
+        app.mode = AppMode::List;
+
+        return Ok(());
+    };
+
+    list::open_session_at_index(app, session_index);
+    session_view::focus_transcript_turn(app, terminal, hit.turn_number, query)
+}
+
+#[cfg(test)]
+mod tests {
+    use crossterm::event::KeyModifiers;
+    use ratatui::backend::TestBackend;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::db::Database;
+    use crate::domain::input::InputState;
+    use crate::domain::session::{SessionId, SessionSearchSource};
+
+    /// Builds one client bundle with deterministic agent availability for
+    /// test app startup.
+    fn test_app_clients() -> crate::app::AppClients {
+        crate::app::AppClients::new().with_agent_availability_probe(std::sync::Arc::new(
+            crate::infra::agent::StaticAgentAvailabilityProbe {
+                available_agent_kinds: crate::domain::agent::AgentKind::ALL.to_vec(),
+            },
+        ))
+    }
+
+    async fn new_test_app() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let app = App::new_with_clients(
+            base_path.clone(),
+            base_path,
+            None,
+            database,
+            test_app_clients(),
+        )
+        .await
+        .expect("failed to build app");
+
+        (app, base_dir)
+    }
+
+    /// Inserts one titled done session into `project_id`.
+    async fn insert_titled_session(app: &App, session_id: &str, project_id: i64, title: &str) {
+        let db = app.services.db();
+        db.insert_session(session_id, "gpt-5.4", "main", "Done", project_id)
+            .await
+            .expect("failed to insert session");
+        db.update_session_title(session_id, title)
+            .await
+            .expect("failed to update title");
+    }
+
+    fn search_hit(session_id: &str, project_id: i64) -> SessionSearchHit {
+        SessionSearchHit {
+            project_id: Some(project_id),
+            project_name: "project".to_string(),
+            session_id: SessionId::from(session_id),
+            snippet: "Fix flaky sqlx test".to_string(),
+            snippet_match_ranges: vec![4..9, 10..14],
+            source: SessionSearchSource::Title,
+            title: "Fix flaky sqlx test".to_string(),
+            turn_number: None,
+        }
+    }
+
+    fn test_terminal() -> Terminal<TestBackend> {
+        Terminal::new(TestBackend::new(100, 30)).expect("failed to create terminal")
+    }
+
+    #[tokio::test]
+    async fn test_handle_typing_searches_sessions_in_other_projects() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let other_project_id = app
+            .services
+            .db()
+            .upsert_project("/tmp/other-project", Some("main"))
+            .await
+            .expect("failed to insert project");
+        insert_titled_session(&app, "session-a", other_project_id, "Fix flaky sqlx test").await;
+        app.start_session_search();
+        let terminal = test_terminal();
+
+        // Act
+        for character in "flaky".chars() {
+            handle(
+                &mut app,
+                &terminal,
+                KeyEvent::new(KeyCode::Char(character), KeyModifiers::NONE),
+            )
+            .await
+            .expect("failed to handle key");
+        }
+
+        // Assert
+        let AppMode::SessionSearch { hits, input, .. } = &app.mode else {
+            unreachable!("mode should remain session search");
+        };
+        assert_eq!(input.text(), "flaky");
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].session_id.as_str(), "session-a");
+        assert_eq!(hits[0].project_name, "other-project");
+    }
+
+    #[tokio::test]
+    async fn test_handle_arrow_keys_move_selection_within_hits() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.mode = AppMode::SessionSearch {
+            hits: vec![search_hit("session-a", 1), search_hit("session-b", 1)],
+            input: InputState::with_text("flaky".to_string()),
+            selected_hit_index: 0,
+        };
+        let terminal = test_terminal();
+
+        // Act
+        for key_code in [KeyCode::Down, KeyCode::Down, KeyCode::Up, KeyCode::Down] {
+            handle(
+                &mut app,
+                &terminal,
+                KeyEvent::new(key_code, KeyModifiers::NONE),
+            )
+            .await
+            .expect("failed to handle key");
+        }
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::SessionSearch {
+                selected_hit_index: 1,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_enter_opens_selected_hit_in_session_view() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let project_id = app.projects.active_project_id();
+        insert_titled_session(&app, "session-a", project_id, "Fix flaky sqlx test").await;
+        app.refresh_sessions_now().await;
+        app.mode = AppMode::SessionSearch {
+            hits: vec![search_hit("session-a", project_id)],
+            input: InputState::with_text("fix flaky".to_string()),
+            selected_hit_index: 0,
+        };
+        let terminal = test_terminal();
+
+        // Act
+        handle(
+            &mut app,
+            &terminal,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        let AppMode::View {
+            session_id,
+            transcript_search,
+            ..
+        } = &app.mode
+        else {
+            unreachable!("hit should open session view");
+        };
+        assert_eq!(session_id.as_str(), "session-a");
+        assert_eq!(
+            transcript_search
+                .as_ref()
+                .map(crate::ui::state::transcript_search::TranscriptSearchState::query),
+            Some("flaky")
+        );
+        assert_eq!(app.sessions.table_state.selected(), Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_handle_enter_returns_to_list_for_missing_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let project_id = app.projects.active_project_id();
+        app.mode = AppMode::SessionSearch {
+            hits: vec![search_hit("missing-session", project_id)],
+            input: InputState::with_text("flaky".to_string()),
+            selected_hit_index: 0,
+        };
+        let terminal = test_terminal();
+
+        // Act
+        handle(
+            &mut app,
+            &terminal,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::List));
+    }
+
+    #[tokio::test]
+    async fn test_handle_esc_closes_session_search() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        app.start_session_search();
+        let terminal = test_terminal();
+
+        // Act
+        let event_result = handle(
+            &mut app,
+            &terminal,
+            KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+        )
+        .await
+        .expect("failed to handle key");
+
+        // Assert
+        assert!(matches!(event_result, EventResult::Continue));
+        assert!(matches!(app.mode, AppMode::List));
+    }
+}
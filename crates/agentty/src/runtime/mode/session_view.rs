@@ -1,8 +1,10 @@
 use std::io;
 
-use crossterm::event::{self, KeyCode, KeyEvent};
+use crossterm::event::{KeyCode, KeyEvent};
 use ratatui::Terminal;
 use ratatui::backend::Backend;
+use ratatui::layout::Rect;
+use ratatui::text::Line;
 
 use crate::app::session::remote_branch_name_from_upstream_ref;
 use crate::app::{
@@ -11,17 +13,23 @@ use crate::app::{
 };
 use crate::domain::agent::AgentModel;
 use crate::domain::input::InputState;
+use crate::domain::plan::SessionPlan;
 use crate::domain::session::{FollowUpTaskAction, PublishBranchAction, SessionId, Status};
-use crate::runtime::EventResult;
 use crate::runtime::mode::confirmation::DEFAULT_OPTION_INDEX;
 use crate::runtime::mode::input_key::is_insertable_char_key;
+use crate::runtime::{EventResult, key_handler};
 use crate::ui::component::session_output::SessionOutputLineContext;
+use crate::ui::markdown;
 use crate::ui::page::session_chat::SessionChatPage;
 use crate::ui::state::app_mode::{
     AppMode, ConfirmationIntent, ConfirmationViewMode, DoneSessionOutputMode, HelpContext,
+    ReviewRequestDraftState,
 };
 use crate::ui::state::help_action::{self, ViewSessionState};
+use crate::ui::state::keymap::{self, KeyAction, KeymapMode};
+use crate::ui::state::plan_review::PlanReviewState;
 use crate::ui::state::prompt::{PromptAttachmentState, PromptHistoryState};
+use crate::ui::state::transcript_search::{self, SearchDirection, TranscriptSearchState};
 
 #[derive(Clone)]
 struct ViewContext {
@@ -31,6 +39,7 @@ struct ViewContext {
     scroll_offset: Option<u16>,
     session_id: SessionId,
     session_index: usize,
+    transcript_search: Option<TranscriptSearchState>,
 }
 
 #[derive(Clone, Copy)]
@@ -46,6 +55,7 @@ struct ViewPendingUpdate {
     review_status_message: Option<String>,
     review_text: Option<String>,
     scroll_offset: Option<u16>,
+    transcript_search: Option<TranscriptSearchState>,
 }
 
 impl ViewPendingUpdate {
@@ -56,6 +66,7 @@ impl ViewPendingUpdate {
             review_status_message: view_context.review_status_message.clone(),
             review_text: view_context.review_text.clone(),
             scroll_offset: view_context.scroll_offset,
+            transcript_search: view_context.transcript_search.clone(),
         }
     }
 }
@@ -71,6 +82,7 @@ struct ViewKeyContext<'a> {
 struct ViewSessionSnapshot {
     can_start_staged_session: bool,
     can_open_worktree: bool,
+    can_review_plan: bool,
     follow_up_task_action: Option<FollowUpTaskAction>,
     publish_pull_request_action: Option<PublishBranchAction>,
     session_state: ViewSessionState,
@@ -105,8 +117,17 @@ where
         metrics: view_metrics,
         session_snapshot: &view_session_snapshot,
     };
+    let output_width = view_output_width(app, terminal)?;
+    let is_transcript_key = handle_transcript_navigation_key(
+        app,
+        key,
+        &view_key_context,
+        output_width,
+        &mut pending_update,
+    );
 
-    if !handle_view_key(app, key, view_key_context, &mut pending_update).await {
+    if !is_transcript_key && !handle_view_key(app, key, view_key_context, &mut pending_update).await
+    {
         return Ok(EventResult::Continue);
     }
 
@@ -116,11 +137,48 @@ where
         pending_update.review_status_message,
         pending_update.review_text,
         pending_update.scroll_offset,
+        pending_update.transcript_search,
     );
 
     Ok(EventResult::Continue)
 }
 
+/// Scrolls the session transcript by `step` lines for `KeyAction::Down` and
+/// `KeyAction::Up`, keeping the bottom-pinned follow behavior used by keys.
+pub(crate) fn scroll<B: Backend>(
+    app: &mut App,
+    terminal: &Terminal<B>,
+    action: KeyAction,
+    step: u16,
+) -> io::Result<()>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let Some(view_context) = view_context(app) else {
+        return Ok(());
+    };
+    let view_metrics = view_metrics(app, terminal, &view_context)?;
+    let scroll_offset = match action {
+        KeyAction::Down => scroll_offset_down(view_context.scroll_offset, view_metrics, step),
+        KeyAction::Up => Some(scroll_offset_up(
+            view_context.scroll_offset,
+            view_metrics,
+            step,
+        )),
+        _ => return Ok(()),
+    };
+
+    if let AppMode::View {
+        scroll_offset: view_scroll_offset,
+        ..
+    } = &mut app.mode
+    {
+        *view_scroll_offset = scroll_offset;
+    }
+
+    Ok(())
+}
+
 /// Applies one view-mode key press and updates pending output/scroll state.
 ///
 /// Returns `false` when key handling already transitioned mode and should skip
@@ -174,14 +232,14 @@ async fn handle_primary_view_key(
     view_session_snapshot: &ViewSessionSnapshot,
     pending_update: &ViewPendingUpdate,
 ) -> Option<bool> {
-    match key.code {
-        KeyCode::Char('q') => {
+    match view_key_action(key)? {
+        KeyAction::Back => {
             app.mode = AppMode::List;
         }
-        KeyCode::Char('o') if view_session_snapshot.can_open_worktree => {
+        KeyAction::OpenWorktree if view_session_snapshot.can_open_worktree => {
             open_worktree_for_view_session(app, view_context).await;
         }
-        KeyCode::Char('l') if view_session_snapshot.follow_up_task_action.is_some() => {
+        KeyAction::LaunchFollowUpTask if view_session_snapshot.follow_up_task_action.is_some() => {
             if let Err(error) = app
                 .launch_or_open_selected_follow_up_task(&view_context.session_id)
                 .await
@@ -195,7 +253,7 @@ async fn handle_primary_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('s') if view_session_snapshot.can_start_staged_session => {
+        KeyAction::StartSession if view_session_snapshot.can_start_staged_session => {
             if let Err(error) = app.start_staged_session(&view_context.session_id).await {
                 app.append_output_for_session(
                     &view_context.session_id,
@@ -206,13 +264,17 @@ async fn handle_primary_view_key(
 
             return Some(false);
         }
-        KeyCode::Char('[') if app.has_multiple_follow_up_tasks(&view_context.session_id) => {
+        KeyAction::PreviousFollowUpTask
+            if app.has_multiple_follow_up_tasks(&view_context.session_id) =>
+        {
             app.select_previous_follow_up_task(&view_context.session_id);
         }
-        KeyCode::Char(']') if app.has_multiple_follow_up_tasks(&view_context.session_id) => {
+        KeyAction::NextFollowUpTask
+            if app.has_multiple_follow_up_tasks(&view_context.session_id) =>
+        {
             app.select_next_follow_up_task(&view_context.session_id);
         }
-        KeyCode::Enter if is_view_action_allowed(view_session_snapshot.session_status) => {
+        KeyAction::Reply if is_view_action_allowed(view_session_snapshot.session_status) => {
             switch_view_to_prompt(
                 app,
                 view_context,
@@ -223,10 +285,10 @@ async fn handle_primary_view_key(
                 pending_update.scroll_offset,
             );
         }
-        KeyCode::Char('/')
-            if is_view_action_allowed(view_session_snapshot.session_status)
-                && is_insertable_char_key(key) =>
-        {
+        KeyAction::ReviewPlan if view_session_snapshot.can_review_plan => {
+            open_plan_review(app, view_context);
+        }
+        KeyAction::CommandsMenu if is_view_action_allowed(view_session_snapshot.session_status) => {
             switch_view_to_prompt(
                 app,
                 view_context,
@@ -249,25 +311,29 @@ fn handle_scroll_key(
     view_metrics: ViewMetrics,
     pending_update: &mut ViewPendingUpdate,
 ) -> bool {
-    match key.code {
-        KeyCode::Char('j') | KeyCode::Down => {
+    let Some(action) = view_key_action(key) else {
+        return false;
+    };
+
+    match action {
+        KeyAction::Down => {
             pending_update.scroll_offset =
                 scroll_offset_down(pending_update.scroll_offset, view_metrics, 1);
         }
-        KeyCode::Char('k') | KeyCode::Up => {
+        KeyAction::Up => {
             pending_update.scroll_offset = Some(scroll_offset_up(
                 pending_update.scroll_offset,
                 view_metrics,
                 1,
             ));
         }
-        KeyCode::Char('g') => pending_update.scroll_offset = Some(0),
-        KeyCode::Char('G') => pending_update.scroll_offset = None,
-        KeyCode::Char('d') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+        KeyAction::Top => pending_update.scroll_offset = Some(0),
+        KeyAction::Bottom => pending_update.scroll_offset = None,
+        KeyAction::HalfPageDown => {
             pending_update.scroll_offset =
                 scroll_offset_half_page_down(pending_update.scroll_offset, view_metrics);
         }
-        KeyCode::Char('u') if key.modifiers.contains(event::KeyModifiers::CONTROL) => {
+        KeyAction::HalfPageUp => {
             pending_update.scroll_offset = Some(scroll_offset_half_page_up(
                 pending_update.scroll_offset,
                 view_metrics,
@@ -279,6 +345,133 @@ fn handle_scroll_key(
     true
 }
 
+/// Handles transcript search and prompt-jump keys in session view.
+///
+/// While a search query is being edited every key goes to the query. Outside
+/// editing, the search action (`Ctrl+f` by default) opens a search, the match
+/// actions (`n`/`N`) move between matches of the open search, `Esc` closes
+/// it, and the prompt actions (`{`/`}`) jump between user prompts.
+///
+/// Returns `true` when the key was consumed.
+fn handle_transcript_navigation_key(
+    app: &App,
+    key: KeyEvent,
+    view_key_context: &ViewKeyContext<'_>,
+    output_width: u16,
+    pending_update: &mut ViewPendingUpdate,
+) -> bool {
+    let view_metrics = view_key_context.metrics;
+
+    if let Some(transcript_search) = pending_update
+        .transcript_search
+        .take_if(|transcript_search| transcript_search.is_editing)
+    {
+        let lines = view_rendered_lines(app, view_key_context.context, output_width);
+        let (search_state, scroll_offset) =
+            edit_transcript_search(transcript_search, key, &lines, view_metrics);
+        pending_update.transcript_search = search_state;
+        pending_update.scroll_offset = scroll_offset;
+
+        return true;
+    }
+
+    if key.code == KeyCode::Esc && pending_update.transcript_search.is_some() {
+        pending_update.transcript_search = None;
+
+        return true;
+    }
+
+    match view_key_action(key) {
+        Some(KeyAction::Search) => {
+            pending_update.transcript_search =
+                Some(TranscriptSearchState::new(pending_update.scroll_offset));
+        }
+        Some(action @ (KeyAction::NextMatch | KeyAction::PreviousMatch))
+            if pending_update.transcript_search.is_some() =>
+        {
+            let direction = if action == KeyAction::NextMatch {
+                SearchDirection::Forward
+            } else {
+                SearchDirection::Backward
+            };
+            let lines = view_rendered_lines(app, view_key_context.context, output_width);
+            let top_line = view_top_line(pending_update.scroll_offset, view_metrics);
+            if let Some(transcript_search) = pending_update.transcript_search.as_mut() {
+                transcript_search.focus_adjacent_match(&lines, top_line, direction);
+                if let Some(focused_line) = transcript_search.focused_line() {
+                    pending_update.scroll_offset =
+                        scroll_offset_for_line(focused_line, view_metrics);
+                }
+            }
+        }
+        Some(action @ (KeyAction::NextPrompt | KeyAction::PreviousPrompt)) => {
+            let direction = if action == KeyAction::NextPrompt {
+                SearchDirection::Forward
+            } else {
+                SearchDirection::Backward
+            };
+            let lines = view_rendered_lines(app, view_key_context.context, output_width);
+            let top_line = view_top_line(pending_update.scroll_offset, view_metrics);
+            pending_update.scroll_offset =
+                match transcript_search::adjacent_prompt_line(&lines, top_line, direction) {
+                    Some(prompt_line) => scroll_offset_for_line(prompt_line, view_metrics),
+                    None if direction == SearchDirection::Forward => None,
+                    None => Some(0),
+                };
+        }
+        _ => return false,
+    }
+
+    true
+}
+
+/// Applies one key press to a transcript search query being edited.
+///
+/// Returns the updated search, or `None` when the search was cancelled, plus
+/// the scroll offset that keeps the focused match visible. Each query edit
+/// re-runs the search from the scroll position where the search started.
+fn edit_transcript_search(
+    mut transcript_search: TranscriptSearchState,
+    key: KeyEvent,
+    lines: &[Line<'_>],
+    view_metrics: ViewMetrics,
+) -> (Option<TranscriptSearchState>, Option<u16>) {
+    let origin_scroll_offset = transcript_search.origin_scroll_offset;
+
+    match key.code {
+        KeyCode::Esc => return (None, origin_scroll_offset),
+        KeyCode::Enter if transcript_search.query().is_empty() => {
+            return (None, origin_scroll_offset);
+        }
+        KeyCode::Enter => {
+            transcript_search.is_editing = false;
+            let scroll_offset = transcript_search
+                .focused_line()
+                .map_or(origin_scroll_offset, |focused_line| {
+                    scroll_offset_for_line(focused_line, view_metrics)
+                });
+
+            return (Some(transcript_search), scroll_offset);
+        }
+        KeyCode::Backspace => transcript_search.input.delete_backward(),
+        KeyCode::Left => transcript_search.input.move_left(),
+        KeyCode::Right => transcript_search.input.move_right(),
+        KeyCode::Char(character) if is_insertable_char_key(key) => {
+            transcript_search.input.insert_char(character);
+        }
+        _ => {}
+    }
+
+    transcript_search.refresh_matches(lines, view_top_line(origin_scroll_offset, view_metrics));
+    let scroll_offset = transcript_search
+        .focused_line()
+        .map_or(origin_scroll_offset, |focused_line| {
+            scroll_offset_for_line(focused_line, view_metrics)
+        });
+
+    (Some(transcript_search), scroll_offset)
+}
+
 /// Handles workflow actions in session view such as diff, publish, review,
 /// merge, rebase, cancellation, and help.
 async fn handle_workflow_view_key(
@@ -288,18 +481,11 @@ async fn handle_workflow_view_key(
     view_session_snapshot: &ViewSessionSnapshot,
     pending_update: &mut ViewPendingUpdate,
 ) -> Option<bool> {
-    match key.code {
-        KeyCode::Char('d')
-            if !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && is_view_diff_allowed(view_session_snapshot.session_status) =>
-        {
+    match view_key_action(key)? {
+        KeyAction::Diff if is_view_diff_allowed(view_session_snapshot.session_status) => {
             let _ = show_diff_for_view_session(app, view_context).await;
         }
-        KeyCode::Char(character)
-            if character.eq_ignore_ascii_case(&'p')
-                && !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.publish_pull_request_action.is_some() =>
-        {
+        KeyAction::Publish if view_session_snapshot.publish_pull_request_action.is_some() => {
             let Some(publish_pull_request_action) =
                 view_session_snapshot.publish_pull_request_action
             else {
@@ -309,17 +495,13 @@ async fn handle_workflow_view_key(
 
             return Some(false);
         }
-        KeyCode::Char(character)
-            if character.eq_ignore_ascii_case(&'f')
-                && !key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && is_view_review_allowed(view_session_snapshot.session_status) =>
-        {
+        KeyAction::Review if is_view_review_allowed(view_session_snapshot.session_status) => {
             open_or_regenerate_review(app, view_context, pending_update).await;
         }
-        KeyCode::Char('m') if is_view_action_allowed(view_session_snapshot.session_status) => {
+        KeyAction::Merge if is_view_action_allowed(view_session_snapshot.session_status) => {
             open_merge_confirmation(app, view_context);
         }
-        KeyCode::Char('r') if is_view_rebase_allowed(view_session_snapshot.session_status) => {
+        KeyAction::Rebase if is_view_rebase_allowed(view_session_snapshot.session_status) => {
             rebase_view_session(app, &view_context.session_id).await;
         }
         _ if is_done_output_toggle_key(view_session_snapshot.session_status, key) => {
@@ -327,15 +509,12 @@ async fn handle_workflow_view_key(
                 pending_update.done_session_output_mode.toggled();
             pending_update.scroll_offset = None;
         }
-        KeyCode::Char('c')
-            if key.modifiers.contains(event::KeyModifiers::CONTROL)
-                && view_session_snapshot.session_status == Status::InProgress =>
-        {
+        KeyAction::Stop if view_session_snapshot.session_status == Status::InProgress => {
             end_in_progress_turn(app, &view_context.session_id).await;
 
             return Some(false);
         }
-        KeyCode::Char('?') => {
+        KeyAction::Help => {
             open_view_help_overlay(app, view_context, view_session_snapshot);
             return Some(false);
         }
@@ -360,6 +539,24 @@ fn open_merge_confirmation(app: &mut App, view_context: &ViewContext) {
     };
 }
 
+/// Opens the plan-review overlay for the proposed steps of the viewed
+/// plan-first session.
+fn open_plan_review(app: &mut App, view_context: &ViewContext) {
+    let Some(plan) = app
+        .sessions
+        .sessions
+        .get(view_context.session_index)
+        .and_then(|session| session.plan.as_ref())
+    else {
+        return;
+    };
+
+    app.mode = AppMode::PlanReview {
+        review: PlanReviewState::new(plan.steps.clone()),
+        restore_view: confirmation_view_mode(view_context),
+    };
+}
+
 /// Opens the viewed session worktree directly or shows a command selector when
 /// multiple open commands are configured.
 async fn open_worktree_for_view_session(app: &mut App, view_context: &ViewContext) {
@@ -454,6 +651,11 @@ fn view_session_snapshot(app: &App, view_context: &ViewContext) -> Option<ViewSe
             && session.status == Status::New
             && session.has_staged_drafts(),
         can_open_worktree,
+        can_review_plan: is_view_action_allowed(session_status)
+            && session
+                .plan
+                .as_ref()
+                .is_some_and(SessionPlan::is_awaiting_approval),
         follow_up_task_action: app.selected_follow_up_task_action(&view_context.session_id),
         publish_pull_request_action: session.publish_pull_request_action(),
         session_state: help_action::session_view_state(session),
@@ -462,19 +664,21 @@ fn view_session_snapshot(app: &App, view_context: &ViewContext) -> Option<ViewSe
 }
 
 /// Applies in-place updates for active view output mode, review
-/// status/text, and scroll position.
+/// status/text, scroll position, and transcript search.
 fn apply_view_scroll_and_output_mode(
     app: &mut App,
     done_session_output_mode: DoneSessionOutputMode,
     review_status_message: Option<String>,
     review_text: Option<String>,
     scroll_offset: Option<u16>,
+    transcript_search: Option<TranscriptSearchState>,
 ) {
     if let AppMode::View {
         done_session_output_mode: view_done_session_output_mode,
         review_status_message: view_review_status_message,
         review_text: view_review_text,
         scroll_offset: view_scroll_offset,
+        transcript_search: view_transcript_search,
         ..
     } = &mut app.mode
     {
@@ -482,17 +686,18 @@ fn apply_view_scroll_and_output_mode(
         *view_review_status_message = review_status_message;
         *view_review_text = review_text;
         *view_scroll_offset = scroll_offset;
+        *view_transcript_search = transcript_search;
     }
 }
 
 /// Returns whether the key event toggles done-session output mode.
 fn is_done_output_toggle_key(status: Status, key: KeyEvent) -> bool {
-    let is_toggle_key = matches!(
-        key.code,
-        KeyCode::Char(character) if character.eq_ignore_ascii_case(&'t')
-    );
+    status == Status::Done && view_key_action(key) == Some(KeyAction::ToggleOutput)
+}
 
-    status == Status::Done && is_toggle_key && !key.modifiers.contains(event::KeyModifiers::CONTROL)
+/// Resolves one key press through the active session-view keymap.
+fn view_key_action(key: KeyEvent) -> Option<KeyAction> {
+    keymap::active().action(KeymapMode::View, key)
 }
 
 /// Returns whether `o` can access the session worktree.
@@ -546,7 +751,7 @@ fn is_view_rebase_allowed(status: Status) -> bool {
 /// `[Stopped]` error. After signalling, the persisted status is
 /// updated to `Review`, the in-memory snapshot and shared handle are
 /// refreshed, and UI events are emitted.
-async fn end_in_progress_turn(app: &mut App, session_id: &str) {
+pub(crate) async fn end_in_progress_turn(app: &mut App, session_id: &str) {
     let timestamp_seconds =
         app::session::unix_timestamp_from_system_time(app.services.clock().now_system_time());
 
@@ -664,6 +869,12 @@ fn open_publish_branch_input(
         .map(remote_branch_name_from_upstream_ref)
         .map(InputState::with_text)
         .unwrap_or_default();
+    let review_request_draft = match publish_branch_action {
+        PublishBranchAction::Push => None,
+        PublishBranchAction::PublishPullRequest => app
+            .start_review_request_draft(&view_context.session_id)
+            .map(ReviewRequestDraftState::loading),
+    };
 
     app.mode = AppMode::PublishBranchInput {
         default_branch_name,
@@ -671,27 +882,28 @@ fn open_publish_branch_input(
         locked_upstream_ref,
         publish_branch_action,
         restore_view: confirmation_view_mode(view_context),
+        review_request_draft,
     };
 }
 
 fn view_context(app: &mut App) -> Option<ViewContext> {
-    let (done_session_output_mode, review_status_message, review_text, session_id, scroll_offset) =
-        match &app.mode {
-            AppMode::View {
-                done_session_output_mode,
-                review_status_message,
-                review_text,
-                session_id,
-                scroll_offset,
-            } => (
-                *done_session_output_mode,
-                review_status_message.clone(),
-                review_text.clone(),
-                session_id.clone(),
-                *scroll_offset,
-            ),
-            _ => return None,
-        };
+    let AppMode::View {
+        done_session_output_mode,
+        review_status_message,
+        review_text,
+        session_id,
+        scroll_offset,
+        transcript_search,
+    } = &app.mode
+    else {
+        return None;
+    };
+    let done_session_output_mode = *done_session_output_mode;
+    let review_status_message = review_status_message.clone();
+    let review_text = review_text.clone();
+    let session_id = session_id.clone();
+    let scroll_offset = *scroll_offset;
+    let transcript_search = transcript_search.clone();
 
     let Some(session_index) = app.session_index_for_id(&session_id) else {
         app.mode = AppMode::List;
@@ -706,9 +918,84 @@ fn view_context(app: &mut App) -> Option<ViewContext> {
         scroll_offset,
         session_id,
         session_index,
+        transcript_search,
     })
 }
 
+/// Scrolls the open session view to the matched search turn and opens a
+/// submitted transcript search for the longest `query` term.
+///
+/// Turn `0` scrolls to the transcript start and `None` keeps the default
+/// bottom-pinned view. When the highlighted term occurs inside the target
+/// turn, the view scrolls to that occurrence instead of the turn prompt.
+///
+/// # Errors
+/// Returns an error if terminal size cannot be read.
+pub(crate) fn focus_transcript_turn<B: Backend>(
+    app: &mut App,
+    terminal: &Terminal<B>,
+    turn_number: Option<u32>,
+    query: &str,
+) -> io::Result<()>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let Some(view_context) = view_context(app) else {
+        return Ok(());
+    };
+    let view_metrics = view_metrics(app, terminal, &view_context)?;
+    let lines = view_rendered_lines(app, &view_context, view_output_width(app, terminal)?);
+    let turn_line = match turn_number {
+        Some(0) => Some(0),
+        Some(turn_number) => lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| markdown::is_user_prompt_start_line(line))
+            .nth(usize::try_from(turn_number - 1).unwrap_or(usize::MAX))
+            .map(|(line_index, _)| line_index.saturating_sub(1)),
+        None => None,
+    };
+    let mut scroll_offset =
+        turn_line.and_then(|turn_line| scroll_offset_for_line(turn_line, view_metrics));
+    let transcript_search = query
+        .split_whitespace()
+        .max_by_key(|term| term.chars().count())
+        .map(|term| {
+            let anchor_line =
+                turn_line.unwrap_or_else(|| view_top_line(scroll_offset, view_metrics));
+            let mut transcript_search = TranscriptSearchState::new(scroll_offset);
+            transcript_search.input = InputState::with_text(term.to_string());
+            transcript_search.is_editing = false;
+            transcript_search.refresh_matches(&lines, anchor_line);
+
+            let next_turn_line = transcript_search::adjacent_prompt_line(
+                &lines,
+                anchor_line,
+                SearchDirection::Forward,
+            );
+            if let (Some(_), Some(focused_line)) = (turn_line, transcript_search.focused_line())
+                && focused_line >= anchor_line
+                && next_turn_line.is_none_or(|next_turn_line| focused_line < next_turn_line)
+            {
+                scroll_offset = scroll_offset_for_line(focused_line, view_metrics);
+            }
+
+            transcript_search
+        });
+
+    if let AppMode::View {
+        scroll_offset: view_scroll_offset,
+        transcript_search: view_transcript_search,
+        ..
+    } = &mut app.mode
+    {
+        *view_scroll_offset = scroll_offset;
+        *view_transcript_search = transcript_search;
+    }
+
+    Ok(())
+}
+
 fn view_metrics<B: Backend>(
     app: &App,
     terminal: &Terminal<B>,
@@ -719,7 +1006,7 @@ where
 {
     let terminal_size = terminal.size().map_err(crate::runtime::backend_err)?;
     let view_height = terminal_size.height.saturating_sub(5);
-    let output_width = terminal_size.width.saturating_sub(2);
+    let output_width = view_output_width(app, terminal)?;
     let total_lines = view_total_lines(
         app,
         &view_context.session_id,
@@ -736,6 +1023,68 @@ where
     })
 }
 
+/// Returns the session output panel width for the current terminal size and
+/// layout.
+fn view_output_width<B: Backend>(app: &App, terminal: &Terminal<B>) -> io::Result<u16>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let terminal_size = terminal.size().map_err(crate::runtime::backend_err)?;
+    let session_chat_rect = key_handler::session_chat_terminal_rect(
+        app,
+        Rect::new(0, 0, terminal_size.width, terminal_size.height),
+    );
+
+    Ok(session_chat_rect.width.saturating_sub(2))
+}
+
+/// Returns the rendered session output lines for the viewed session, in the
+/// same line space as the view scroll offset.
+fn view_rendered_lines(
+    app: &App,
+    view_context: &ViewContext,
+    output_width: u16,
+) -> Vec<Line<'static>> {
+    let active_progress = app.session_progress_message(&view_context.session_id);
+
+    app.sessions
+        .sessions
+        .get(view_context.session_index)
+        .map(|session| {
+            SessionChatPage::rendered_output_lines(
+                session,
+                output_width,
+                SessionOutputLineContext {
+                    active_progress,
+                    done_session_output_mode: view_context.done_session_output_mode,
+                    review_status_message: view_context.review_status_message.as_deref(),
+                    review_text: view_context.review_text.as_deref(),
+                },
+            )
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the first visible rendered line for a view scroll offset, where
+/// `None` means the view is pinned to the bottom.
+fn view_top_line(scroll_offset: Option<u16>, metrics: ViewMetrics) -> usize {
+    usize::from(
+        scroll_offset.unwrap_or_else(|| metrics.total_lines.saturating_sub(metrics.view_height)),
+    )
+}
+
+/// Returns the scroll offset that puts `line` at the top of the view, or
+/// `None` when the line is already inside the bottom-pinned page.
+fn scroll_offset_for_line(line: usize, metrics: ViewMetrics) -> Option<u16> {
+    let max_offset = metrics.total_lines.saturating_sub(metrics.view_height);
+    let line = u16::try_from(line).unwrap_or(u16::MAX);
+    if line >= max_offset {
+        return None;
+    }
+
+    Some(line)
+}
+
 fn view_total_lines(
     app: &App,
     session_id: &str,
@@ -746,11 +1095,6 @@ fn view_total_lines(
     output_width: u16,
 ) -> u16 {
     let active_progress = app.session_progress_message(session_id);
-    let active_prompt_output = app
-        .sessions
-        .active_prompt_outputs()
-        .get(session_id)
-        .map(std::string::String::as_str);
 
     app.sessions
         .sessions
@@ -760,7 +1104,6 @@ fn view_total_lines(
                 session,
                 output_width,
                 SessionOutputLineContext {
-                    active_prompt_output,
                     active_progress,
                     done_session_output_mode,
                     review_status_message,
@@ -1304,6 +1647,7 @@ mod tests {
             review_text: None,
             session_id: "missing-session".into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
 
         // Act
@@ -1324,6 +1668,7 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(4),
+            transcript_search: None,
         };
 
         // Act
@@ -1352,6 +1697,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: Some(1),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -1379,6 +1725,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: Some(1),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -1402,6 +1749,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: Some(1),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -1422,6 +1770,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: Some(1),
+            transcript_search: None,
         };
         let mut context = view_context(&mut app).expect("expected view context");
         context.session_index = 99;
@@ -1602,6 +1951,7 @@ mod tests {
             review_text: None,
             session_id: expected_session_id.clone().into(),
             scroll_offset: Some(3),
+            transcript_search: None,
         };
 
         // Act
@@ -1611,6 +1961,7 @@ mod tests {
             Some(expected_status_message.clone()),
             None,
             Some(1),
+            None,
         );
 
         // Assert
@@ -1622,11 +1973,239 @@ mod tests {
                 review_text: None,
                 ref session_id,
             scroll_offset: Some(1),
+            ..
             } if session_id == &expected_session_id
                 && actual_status_message == &expected_status_message
         ));
     }
 
+    /// Transcript with two prompts and one `needle` line after each prompt.
+    const NAVIGATION_TRANSCRIPT: &str = concat!(
+        " › first\n\nalpha\nbeta\nneedle one\ngamma\ndelta\n\n",
+        " › second\n\nzeta\nneedle two\neta\ntheta\niota\nkappa\nlambda\nmu\nnu\nxi\n",
+    );
+
+    /// Builds one review-state session view over `output` for transcript
+    /// navigation tests.
+    async fn new_test_app_with_transcript(output: &str) -> (App, tempfile::TempDir, ViewContext) {
+        let (mut app, base_dir, session_id) = new_test_app_with_session().await;
+        app.sessions.sessions[0].status = Status::Review;
+        app.sessions.sessions[0].output = output.to_string();
+        app.mode = AppMode::View {
+            done_session_output_mode: DoneSessionOutputMode::Summary,
+            review_status_message: None,
+            review_text: None,
+            session_id: session_id.into(),
+            scroll_offset: Some(0),
+            transcript_search: None,
+        };
+        let view_context = view_context(&mut app).expect("expected view context");
+
+        (app, base_dir, view_context)
+    }
+
+    /// Sends one key through transcript navigation with an 80-column output
+    /// panel and a five-line viewport.
+    fn press_transcript_key(
+        app: &App,
+        view_context: &ViewContext,
+        pending_update: &mut ViewPendingUpdate,
+        key: KeyEvent,
+    ) -> bool {
+        let view_session_snapshot =
+            view_session_snapshot(app, view_context).expect("expected view session snapshot");
+        let total_lines = view_rendered_lines(app, view_context, 80).len();
+        let view_key_context = ViewKeyContext {
+            context: view_context,
+            metrics: ViewMetrics {
+                total_lines: u16::try_from(total_lines).expect("line count should fit"),
+                view_height: 5,
+            },
+            session_snapshot: &view_session_snapshot,
+        };
+
+        handle_transcript_navigation_key(app, key, &view_key_context, 80, pending_update)
+    }
+
+    /// Returns the rendered line index of the first line containing `text`.
+    fn rendered_line_index(app: &App, view_context: &ViewContext, text: &str) -> u16 {
+        let line_index = view_rendered_lines(app, view_context, 80)
+            .iter()
+            .position(|line| transcript_search::line_text(line).contains(text))
+            .expect("text should be rendered");
+
+        u16::try_from(line_index).expect("line index should fit")
+    }
+
+    #[tokio::test]
+    async fn test_transcript_search_finds_and_cycles_matches() {
+        // Arrange
+        let (app, _base_dir, view_context) =
+            new_test_app_with_transcript(NAVIGATION_TRANSCRIPT).await;
+        let mut pending_update = ViewPendingUpdate::from_context(&view_context);
+        let first_match_line = rendered_line_index(&app, &view_context, "needle one");
+        let second_match_line = rendered_line_index(&app, &view_context, "needle two");
+
+        // Act
+        let is_search_opened = press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL),
+        );
+        for character in "need".chars() {
+            press_transcript_key(
+                &app,
+                &view_context,
+                &mut pending_update,
+                KeyEvent::new(KeyCode::Char(character), KeyModifiers::NONE),
+            );
+        }
+        let typed_scroll_offset = pending_update.scroll_offset;
+        press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE),
+        );
+        press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE),
+        );
+        let next_scroll_offset = pending_update.scroll_offset;
+        press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('N'), KeyModifiers::SHIFT),
+        );
+
+        // Assert
+        let transcript_search = pending_update
+            .transcript_search
+            .expect("search should stay open");
+        assert!(is_search_opened);
+        assert_eq!(transcript_search.query(), "need");
+        assert!(!transcript_search.is_editing);
+        assert_eq!(transcript_search.match_lines.len(), 2);
+        assert_eq!(typed_scroll_offset, Some(first_match_line));
+        assert_eq!(next_scroll_offset, Some(second_match_line));
+        assert_eq!(pending_update.scroll_offset, Some(first_match_line));
+    }
+
+    #[tokio::test]
+    async fn test_transcript_search_escape_restores_origin_scroll() {
+        // Arrange
+        let (app, _base_dir, view_context) =
+            new_test_app_with_transcript(NAVIGATION_TRANSCRIPT).await;
+        let mut pending_update = ViewPendingUpdate::from_context(&view_context);
+
+        // Act
+        for key in [
+            KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL),
+            KeyEvent::new(KeyCode::Char('t'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('w'), KeyModifiers::NONE),
+            KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE),
+        ] {
+            press_transcript_key(&app, &view_context, &mut pending_update, key);
+        }
+        let typed_scroll_offset = pending_update.scroll_offset;
+        let is_escape_consumed = press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert_ne!(typed_scroll_offset, Some(0));
+        assert!(is_escape_consumed);
+        assert!(pending_update.transcript_search.is_none());
+        assert_eq!(pending_update.scroll_offset, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_transcript_prompt_keys_jump_between_prompts() {
+        // Arrange
+        let (app, _base_dir, view_context) =
+            new_test_app_with_transcript(NAVIGATION_TRANSCRIPT).await;
+        let mut pending_update = ViewPendingUpdate::from_context(&view_context);
+        let second_prompt_line = rendered_line_index(&app, &view_context, " › second");
+
+        // Act
+        press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('}'), KeyModifiers::SHIFT),
+        );
+        let next_prompt_scroll_offset = pending_update.scroll_offset;
+        press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('{'), KeyModifiers::SHIFT),
+        );
+
+        // Assert
+        assert_eq!(next_prompt_scroll_offset, Some(second_prompt_line - 1));
+        assert_eq!(pending_update.scroll_offset, Some(0));
+    }
+
+    #[tokio::test]
+    async fn test_focus_transcript_turn_scrolls_to_match_inside_turn() {
+        // Arrange
+        let (mut app, _base_dir, view_context) =
+            new_test_app_with_transcript(NAVIGATION_TRANSCRIPT).await;
+        let second_match_line = rendered_line_index(&app, &view_context, "needle two");
+        let terminal = Terminal::new(ratatui::backend::TestBackend::new(82, 10))
+            .expect("failed to create terminal");
+
+        // Act
+        focus_transcript_turn(&mut app, &terminal, Some(2), "the needle")
+            .expect("failed to focus turn");
+
+        // Assert
+        let AppMode::View {
+            scroll_offset,
+            transcript_search,
+            ..
+        } = &app.mode
+        else {
+            unreachable!("mode should remain session view");
+        };
+        let transcript_search = transcript_search.as_ref().expect("search should be opened");
+        assert_eq!(*scroll_offset, Some(second_match_line));
+        assert_eq!(transcript_search.query(), "needle");
+        assert!(!transcript_search.is_editing);
+        assert_eq!(
+            transcript_search.focused_line(),
+            Some(usize::from(second_match_line))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_transcript_navigation_ignores_match_keys_without_search() {
+        // Arrange
+        let (app, _base_dir, view_context) =
+            new_test_app_with_transcript(NAVIGATION_TRANSCRIPT).await;
+        let mut pending_update = ViewPendingUpdate::from_context(&view_context);
+
+        // Act
+        let is_consumed = press_transcript_key(
+            &app,
+            &view_context,
+            &mut pending_update,
+            KeyEvent::new(KeyCode::Char('n'), KeyModifiers::NONE),
+        );
+
+        // Assert
+        assert!(!is_consumed);
+        assert_eq!(pending_update.scroll_offset, Some(0));
+    }
+
     #[test]
     fn test_is_review_loading_status_message_matches_model_aware_message() {
         // Arrange
@@ -1675,6 +2254,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
         let mut next_review_status_message = None;
@@ -1715,6 +2295,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
         let mut next_review_status_message = None;
@@ -1758,6 +2339,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
         let mut next_review_status_message = None;
@@ -1794,6 +2376,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 99,
+            transcript_search: None,
         };
         let mut app = app;
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
@@ -1833,6 +2416,7 @@ mod tests {
             scroll_offset: Some(0),
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
 
         // Act
@@ -1860,6 +2444,7 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(0),
+            transcript_search: None,
         };
         let context = ViewContext {
             done_session_output_mode: DoneSessionOutputMode::Summary,
@@ -1868,6 +2453,7 @@ mod tests {
             scroll_offset: Some(0),
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
 
         // Act
@@ -1898,6 +2484,7 @@ mod tests {
             scroll_offset: Some(0),
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
 
         // Act
@@ -1929,6 +2516,7 @@ mod tests {
             scroll_offset: Some(0),
             session_id: session_id.into(),
             session_index: 99,
+            transcript_search: None,
         };
 
         // Act
@@ -1963,6 +2551,7 @@ mod tests {
             review_text: Some("Critical finding".to_string()),
             session_id: session_id.clone().into(),
             scroll_offset: Some(5),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -2005,6 +2594,7 @@ mod tests {
             review_text: Some("Critical finding".to_string()),
             session_id: session_id.clone().into(),
             scroll_offset: Some(4),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -2054,6 +2644,7 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let context = view_context(&mut app).expect("expected view context");
 
@@ -2069,6 +2660,7 @@ mod tests {
                 review_text: None,
                 session_id: ref mode_session_id,
             scroll_offset: Some(2),
+            ..
             } if mode_session_id == &session_id
         ));
     }
@@ -2099,10 +2691,12 @@ mod tests {
             scroll_offset: Some(3),
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
         let view_session_snapshot = ViewSessionSnapshot {
             can_start_staged_session: false,
             can_open_worktree: true,
+            can_review_plan: false,
             follow_up_task_action: None,
             publish_pull_request_action: Some(PublishBranchAction::PublishPullRequest),
             session_state: ViewSessionState::Review,
@@ -2144,6 +2738,7 @@ mod tests {
             scroll_offset: Some(5),
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
 
         // Act
@@ -2169,6 +2764,7 @@ mod tests {
                         session_id: ref restored_session_id,
             scroll_offset: Some(5),
                     },
+                review_request_draft: None,
             } if default_branch_name == &crate::app::session::session_branch(&session_id)
                 && input_state.cursor == 0
                 && input_state.text().is_empty()
@@ -2190,6 +2786,7 @@ mod tests {
             scroll_offset: Some(1),
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
 
         // Act
@@ -2322,6 +2919,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
         let mut next_review_status_message = None;
@@ -2362,6 +2960,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut next_done_session_output_mode = DoneSessionOutputMode::Summary;
         let mut next_review_status_message = None;
@@ -2400,6 +2999,7 @@ mod tests {
             scroll_offset: Some(5),
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
 
@@ -2432,6 +3032,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
 
@@ -2466,6 +3067,7 @@ mod tests {
             scroll_offset: None,
             session_id: session_id.clone().into(),
             session_index: 0,
+            transcript_search: None,
         };
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
 
@@ -2490,12 +3092,14 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let view_context = view_context(&mut app).expect("expected view context");
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
         let view_session_snapshot = ViewSessionSnapshot {
             can_start_staged_session: false,
             can_open_worktree: false,
+            can_review_plan: false,
             follow_up_task_action: None,
             publish_pull_request_action: None,
             session_state: ViewSessionState::Done,
@@ -2562,6 +3166,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: Some(0),
+            transcript_search: None,
         };
         let backend = ratatui::backend::TestBackend::new(120, 30);
         let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
@@ -2599,12 +3204,14 @@ mod tests {
             review_text: Some("Focused review".to_string()),
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let view_context = view_context(&mut app).expect("expected view context");
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
         let view_session_snapshot = ViewSessionSnapshot {
             can_start_staged_session: false,
             can_open_worktree: true,
+            can_review_plan: false,
             follow_up_task_action: None,
             publish_pull_request_action: None,
             session_state: ViewSessionState::Review,
@@ -2657,12 +3264,14 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let view_context = view_context(&mut app).expect("expected view context");
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
         let view_session_snapshot = ViewSessionSnapshot {
             can_start_staged_session: false,
             can_open_worktree: true,
+            can_review_plan: false,
             follow_up_task_action: None,
             publish_pull_request_action: Some(PublishBranchAction::PublishPullRequest),
             session_state: ViewSessionState::Review,
@@ -2708,12 +3317,14 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let view_context = view_context(&mut app).expect("expected view context");
         let mut pending_update = ViewPendingUpdate::from_context(&view_context);
         let view_session_snapshot = ViewSessionSnapshot {
             can_start_staged_session: false,
             can_open_worktree: true,
+            can_review_plan: false,
             follow_up_task_action: None,
             publish_pull_request_action: Some(PublishBranchAction::PublishPullRequest),
             session_state: ViewSessionState::Review,
@@ -2761,6 +3372,7 @@ mod tests {
             review_text: None,
             session_id: session_id.clone().into(),
             scroll_offset: Some(2),
+            transcript_search: None,
         };
         let view_context = view_context(&mut app).expect("expected view context");
         let view_metrics = ViewMetrics {
@@ -2783,6 +3395,7 @@ mod tests {
             let view_session_snapshot = ViewSessionSnapshot {
                 can_start_staged_session: false,
                 can_open_worktree: false,
+                can_review_plan: false,
                 follow_up_task_action: None,
                 publish_pull_request_action: None,
                 session_state: ViewSessionState::Done,
@@ -2825,6 +3438,7 @@ mod tests {
             scroll_offset: Some(10),
             session_id: session_id.into(),
             session_index: 0,
+            transcript_search: None,
         };
         let pending_update = ViewPendingUpdate::from_context(&view_context);
 
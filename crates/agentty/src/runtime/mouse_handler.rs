@@ -0,0 +1,745 @@
+use std::io;
+
+use crossterm::event::{KeyEvent, MouseButton, MouseEvent, MouseEventKind};
+use ratatui::Terminal;
+use ratatui::backend::Backend;
+use ratatui::layout::{Position, Rect};
+
+use crate::app::{App, Tab};
+use crate::runtime::{EventResult, backend_err, key_handler, mode};
+use crate::ui::component::file_explorer::FileExplorer;
+use crate::ui::component::tab::Tabs;
+use crate::ui::layout::{self, ListAreas, SplitAreas};
+use crate::ui::page::session_chat::SessionChatPage;
+use crate::ui::page::{dashboard, session_list};
+use crate::ui::state::app_mode::AppMode;
+use crate::ui::state::help_action::{self, HelpAction};
+use crate::ui::state::keymap::{self, KeyAction};
+use crate::ui::{diff_util, util};
+
+/// Lines scrolled per mouse wheel notch in session transcripts and diffs.
+const MOUSE_SCROLL_LINES: u16 = 3;
+
+/// Routes one mouse event by hit-testing it against the layout of the active
+/// mode.
+///
+/// Left clicks switch tabs, select session rows (a second click opens the
+/// selected session), open review-request links from the session status
+/// column, select diff file-tree items, and replay the shortcut of a clicked
+/// footer hint through [`key_handler::handle_key_event`]. In the split layout
+/// a click on the other pane moves focus there. The wheel moves the list
+/// selection, scrolls the session transcript and diff, and steps through
+/// diff files over the file tree. Other modes ignore the mouse.
+pub(crate) async fn handle_mouse_event<B: Backend>(
+    app: &mut App,
+    terminal: &mut Terminal<B>,
+    mouse: MouseEvent,
+) -> io::Result<EventResult>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    let size = terminal.size().map_err(backend_err)?;
+    let content_area =
+        key_handler::content_area_for_terminal(Rect::new(0, 0, size.width, size.height));
+    let position = Position::new(mouse.column, mouse.row);
+
+    match mouse.kind {
+        MouseEventKind::ScrollDown => {
+            handle_scroll(app, terminal, content_area, position, KeyAction::Down)?;
+        }
+        MouseEventKind::ScrollUp => {
+            handle_scroll(app, terminal, content_area, position, KeyAction::Up)?;
+        }
+        MouseEventKind::Down(MouseButton::Left) => {
+            let footer_key = match &app.mode {
+                AppMode::List => handle_list_mode_click(app, content_area, position),
+                AppMode::View { .. } => handle_view_click(app, content_area, position),
+                AppMode::Diff { .. } => handle_diff_click(app, content_area, position),
+                _ => None,
+            };
+
+            if let Some(footer_key) = footer_key {
+                return key_handler::handle_key_event(app, terminal, footer_key).await;
+            }
+        }
+        _ => {}
+    }
+
+    Ok(EventResult::Continue)
+}
+
+/// Applies one wheel step in `action` direction to the scrollable region
+/// under `position`.
+fn handle_scroll<B: Backend>(
+    app: &mut App,
+    terminal: &Terminal<B>,
+    content_area: Rect,
+    position: Position,
+    action: KeyAction,
+) -> io::Result<()>
+where
+    B::Error: std::error::Error + Send + Sync + 'static,
+{
+    match &app.mode {
+        AppMode::List => mode::list::move_selection(app, action),
+        AppMode::View { .. } => {
+            mode::session_view::scroll(app, terminal, action, MOUSE_SCROLL_LINES)?;
+        }
+        AppMode::Diff { .. } => {
+            let file_list_area = diff_util::diff_page_areas(content_area).file_list_area;
+            let action = match action {
+                KeyAction::Down if file_list_area.contains(position) => KeyAction::NextFile,
+                KeyAction::Up if file_list_area.contains(position) => KeyAction::PreviousFile,
+                _ => action,
+            };
+
+            mode::diff::scroll(app, content_area, action, MOUSE_SCROLL_LINES);
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Handles one list-mode click, opening the selected session when the click
+/// lands on the split-layout preview pane.
+fn handle_list_mode_click(
+    app: &mut App,
+    content_area: Rect,
+    position: Position,
+) -> Option<KeyEvent> {
+    let split_areas = if app.tabs.current() == Tab::Sessions {
+        layout::split_areas(content_area, app.settings.split_layout())
+    } else {
+        None
+    };
+    let Some(SplitAreas {
+        list_area,
+        session_area,
+    }) = split_areas
+    else {
+        return handle_list_click(app, content_area, position);
+    };
+
+    if session_area.contains(position) {
+        if let Some(selected_index) = app.sessions.table_state.selected() {
+            mode::list::open_session_at_index(app, selected_index);
+        }
+
+        return None;
+    }
+
+    handle_list_click(app, list_area, position)
+}
+
+/// Handles one session-view click, moving focus back to the list when the
+/// click lands on the split-layout list pane.
+fn handle_view_click(app: &mut App, content_area: Rect, position: Position) -> Option<KeyEvent> {
+    if let Some(SplitAreas { list_area, .. }) =
+        layout::split_areas(content_area, app.settings.split_layout())
+        && list_area.contains(position)
+    {
+        app.mode = AppMode::List;
+
+        return handle_list_click(app, list_area, position);
+    }
+
+    view_footer_key(
+        app,
+        key_handler::session_chat_area(app, content_area),
+        position,
+    )
+}
+
+/// Handles one list click on the tabs, a session row, or the page footer,
+/// returning the shortcut of a clicked footer hint.
+fn handle_list_click(app: &mut App, content_area: Rect, position: Position) -> Option<KeyEvent> {
+    let ListAreas {
+        page_area,
+        tabs_area,
+    } = layout::list_areas(content_area);
+
+    if tabs_area.contains(position) {
+        let clicked_tab = Tabs::new(
+            app.tabs.current(),
+            app.active_project_id(),
+            app.active_project_has_tasks_tab(),
+            app.projects.project_items(),
+        )
+        .tab_at_column(tabs_area, position.x);
+        if let Some(clicked_tab) = clicked_tab {
+            app.tabs.set(clicked_tab);
+        }
+
+        return None;
+    }
+
+    if app.tabs.current() == Tab::Settings && app.settings.is_editing_text_input() {
+        return None;
+    }
+
+    let footer_area = layout::page_areas(page_area).footer_area;
+    if let Some(footer_key) = footer_key(&list_footer_actions(app), footer_area, position) {
+        return Some(footer_key);
+    }
+
+    match app.tabs.current() {
+        Tab::Sessions => handle_session_row_click(app, page_area, position),
+        Tab::Dashboard => handle_dashboard_tile_click(app, page_area, position),
+        _ => {}
+    }
+
+    None
+}
+
+/// Highlights the clicked dashboard tile, or opens its session when the tile
+/// was already highlighted.
+fn handle_dashboard_tile_click(app: &mut App, page_area: Rect, position: Position) {
+    let selected_session_index = app.selected_dashboard_session_index();
+    let Some(session_index) = dashboard::dashboard_session_at(
+        &app.sessions.sessions,
+        selected_session_index,
+        page_area,
+        position,
+    ) else {
+        return;
+    };
+
+    if selected_session_index == Some(session_index) {
+        mode::list::open_session_at_index(app, session_index);
+
+        return;
+    }
+
+    app.select_dashboard_session(session_index);
+}
+
+/// Selects the clicked session, opens it when it was already selected, or
+/// opens its review request when the click lands on the status column.
+fn handle_session_row_click(app: &mut App, page_area: Rect, position: Position) {
+    let Some(hit) = session_list::session_at(
+        &app.sessions.sessions,
+        app.sessions.table_state.offset(),
+        page_area,
+        position,
+        app.wall_clock_unix_seconds(),
+    ) else {
+        return;
+    };
+
+    if hit.is_status_column && app.open_session_review_request(hit.session_index) {
+        app.select_session(hit.session_index);
+
+        return;
+    }
+
+    if app.sessions.table_state.selected() == Some(hit.session_index) {
+        mode::list::open_session_at_index(app, hit.session_index);
+
+        return;
+    }
+
+    app.select_session(hit.session_index);
+}
+
+/// Returns the footer actions rendered by the current list tab.
+fn list_footer_actions(app: &App) -> Vec<HelpAction> {
+    match app.tabs.current() {
+        Tab::Projects => help_action::project_list_footer_actions(),
+        Tab::Sessions => session_list::footer_actions(app.selected_session()),
+        Tab::Dashboard => dashboard::footer_actions(
+            app.selected_dashboard_session_index()
+                .and_then(|session_index| app.sessions.sessions.get(session_index)),
+        ),
+        Tab::Stats => help_action::stats_footer_actions(),
+        Tab::Settings => help_action::settings_footer_actions(),
+        Tab::Tasks => Vec::new(),
+    }
+}
+
+/// Returns the shortcut of the session-view footer hint under `position`
+/// inside the session chat `area`.
+///
+/// The transcript search bar replaces the footer while a search is open, so
+/// clicks there are ignored.
+fn view_footer_key(app: &App, area: Rect, position: Position) -> Option<KeyEvent> {
+    let AppMode::View {
+        done_session_output_mode,
+        session_id,
+        transcript_search: None,
+        ..
+    } = &app.mode
+    else {
+        return None;
+    };
+    let session = app
+        .sessions
+        .sessions
+        .iter()
+        .find(|session| session.id == *session_id)?;
+    let can_open_worktree = *app
+        .sessions
+        .session_worktree_availability()
+        .get(session_id.as_str())
+        .unwrap_or(&false);
+    let actions =
+        SessionChatPage::view_footer_actions(session, can_open_worktree, *done_session_output_mode);
+    // View mode keeps a one-row footer inside the page margin, matching the
+    // shared list-page split.
+    let footer_area = layout::page_areas(area).footer_area;
+
+    footer_key(&actions, footer_area, position)
+}
+
+/// Selects the clicked diff file-tree item, or returns the shortcut of a
+/// clicked diff footer hint.
+fn handle_diff_click(app: &mut App, content_area: Rect, position: Position) -> Option<KeyEvent> {
+    let AppMode::Diff {
+        diff,
+        file_explorer_selected_index,
+        ..
+    } = &app.mode
+    else {
+        return None;
+    };
+    let areas = diff_util::diff_page_areas(content_area);
+
+    if areas.file_list_area.contains(position) {
+        let parsed_lines = util::parse_diff_lines(diff);
+        let clicked_index = FileExplorer::new(&parsed_lines)
+            .selected_index(*file_explorer_selected_index)
+            .index_at_row(areas.file_list_area, position.y);
+        if let Some(clicked_index) = clicked_index {
+            mode::diff::select_file(app, clicked_index);
+        }
+
+        return None;
+    }
+
+    footer_key(
+        &help_action::diff_footer_actions(),
+        areas.footer_area,
+        position,
+    )
+}
+
+/// Returns the key press replaying the footer hint under `position`.
+fn footer_key(actions: &[HelpAction], footer_area: Rect, position: Position) -> Option<KeyEvent> {
+    help_action::footer_action_at(actions, footer_area, position)
+        .and_then(|action| keymap::key_event_for_label(&action.key))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use crossterm::event::KeyModifiers;
+    use mockall::predicate::eq;
+    use ratatui::backend::TestBackend;
+    use tempfile::tempdir;
+
+    use super::*;
+    use crate::app::AppClients;
+    use crate::db::Database;
+    use crate::domain::session::tests::SessionFixtureBuilder;
+    use crate::domain::session::{
+        ForgeKind, ReviewRequest, ReviewRequestState, ReviewRequestSummary, Session, Status,
+    };
+    use crate::domain::setting::SettingName;
+    use crate::infra::browser::{BrowserClient, MockBrowserClient};
+    use crate::ui::state::app_mode::DoneSessionOutputMode;
+
+    const TERMINAL_WIDTH: u16 = 100;
+    const TERMINAL_HEIGHT: u16 = 20;
+    const SPLIT_TERMINAL_WIDTH: u16 = 160;
+    /// Row of the first grouped `Active sessions` entry in a 100x20 terminal
+    /// when the merge queue group is empty.
+    const FIRST_ACTIVE_SESSION_ROW: u16 = 11;
+    const REVIEW_REQUEST_URL: &str = "https://github.com/agentty-xyz/agentty/pull/42";
+    const TWO_FILE_DIFF: &str = concat!(
+        "diff --git a/src/main.rs b/src/main.rs\n",
+        "@@ -1 +1 @@\n",
+        "-old\n",
+        "+new\n",
+        "diff --git a/README.md b/README.md\n",
+        "@@ -1 +1 @@\n",
+        "-old\n",
+        "+new\n",
+    );
+
+    /// Builds one test app with an injected browser boundary.
+    async fn new_test_app_with_browser_client(
+        browser_client: Arc<dyn BrowserClient>,
+    ) -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        let clients = AppClients::new()
+            .with_agent_availability_probe(Arc::new(
+                crate::infra::agent::StaticAgentAvailabilityProbe {
+                    available_agent_kinds: crate::domain::agent::AgentKind::ALL.to_vec(),
+                },
+            ))
+            .with_browser_client(browser_client);
+        let app = App::new_with_clients(base_path.clone(), base_path, None, database, clients)
+            .await
+            .expect("failed to build app");
+
+        (app, base_dir)
+    }
+
+    /// Builds one test app with a strict mocked browser boundary.
+    async fn new_test_app() -> (App, tempfile::TempDir) {
+        new_test_app_with_browser_client(Arc::new(MockBrowserClient::new())).await
+    }
+
+    /// Builds one test app whose persisted settings enable the split layout.
+    async fn new_split_layout_test_app() -> (App, tempfile::TempDir) {
+        let base_dir = tempdir().expect("failed to create temp dir");
+        let base_path = base_dir.path().to_path_buf();
+        let database = Database::open_in_memory()
+            .await
+            .expect("failed to open in-memory db");
+        database
+            .upsert_setting(SettingName::SplitLayout, "true")
+            .await
+            .expect("failed to persist split layout");
+        let clients = AppClients::new().with_agent_availability_probe(Arc::new(
+            crate::infra::agent::StaticAgentAvailabilityProbe {
+                available_agent_kinds: crate::domain::agent::AgentKind::ALL.to_vec(),
+            },
+        ));
+        let app = App::new_with_clients(base_path.clone(), base_path, None, database, clients)
+            .await
+            .expect("failed to build app");
+
+        (app, base_dir)
+    }
+
+    fn test_terminal() -> Terminal<TestBackend> {
+        Terminal::new(TestBackend::new(TERMINAL_WIDTH, TERMINAL_HEIGHT))
+            .expect("failed to create terminal")
+    }
+
+    fn mouse_event(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    fn left_click(column: u16, row: u16) -> MouseEvent {
+        mouse_event(MouseEventKind::Down(MouseButton::Left), column, row)
+    }
+
+    fn session_fixture(id: &str, status: Status) -> Session {
+        SessionFixtureBuilder::new()
+            .id(id)
+            .status(status)
+            .folder(std::env::temp_dir())
+            .build()
+    }
+
+    fn content_area() -> Rect {
+        key_handler::content_area_for_terminal(Rect::new(0, 0, TERMINAL_WIDTH, TERMINAL_HEIGHT))
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_tab_switches_tab() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        app.tabs.set(Tab::Stats);
+
+        // Act
+        handle_mouse_event(&mut app, &mut terminal, left_click(3, 2))
+            .await
+            .expect("failed to handle mouse event");
+
+        // Assert
+        assert_eq!(app.tabs.current(), Tab::Projects);
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_selects_then_opens_session_row() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        app.tabs.set(Tab::Sessions);
+        app.sessions.sessions = vec![session_fixture("session-1", Status::Review)];
+        app.sessions.table_state.select(None);
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(4, FIRST_ACTIVE_SESSION_ROW),
+        )
+        .await
+        .expect("failed to handle first click");
+        let selected_after_first_click = app.sessions.table_state.selected();
+        let is_list_after_first_click = matches!(app.mode, AppMode::List);
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(4, FIRST_ACTIVE_SESSION_ROW),
+        )
+        .await
+        .expect("failed to handle second click");
+
+        // Assert
+        assert_eq!(selected_after_first_click, Some(0));
+        assert!(is_list_after_first_click);
+        assert!(matches!(
+            &app.mode,
+            AppMode::View { session_id, .. } if session_id == "session-1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_status_column_opens_review_request() {
+        // Arrange
+        let mut browser_client = MockBrowserClient::new();
+        browser_client
+            .expect_open_url()
+            .with(eq(REVIEW_REQUEST_URL.to_string()))
+            .times(1)
+            .return_const(());
+        let (mut app, _base_dir) = new_test_app_with_browser_client(Arc::new(browser_client)).await;
+        let mut terminal = test_terminal();
+        app.tabs.set(Tab::Sessions);
+        let mut session = session_fixture("session-1", Status::Review);
+        session.review_request = Some(ReviewRequest {
+            last_refreshed_at: 0,
+            summary: ReviewRequestSummary {
+                display_id: "#42".to_string(),
+                forge_kind: ForgeKind::GitHub,
+                source_branch: "wt/session-1".to_string(),
+                state: ReviewRequestState::Open,
+                status_summary: None,
+                target_branch: "main".to_string(),
+                title: "feat".to_string(),
+                web_url: REVIEW_REQUEST_URL.to_string(),
+            },
+        });
+        app.sessions.sessions = vec![session];
+        app.sessions.table_state.select(Some(0));
+        let list_areas = layout::list_areas(content_area());
+        let status_column = (0..TERMINAL_WIDTH)
+            .find(|column| {
+                session_list::session_at(
+                    &app.sessions.sessions,
+                    0,
+                    list_areas.page_area,
+                    Position::new(*column, FIRST_ACTIVE_SESSION_ROW),
+                    app.wall_clock_unix_seconds(),
+                )
+                .is_some_and(|hit| hit.is_status_column)
+            })
+            .expect("status column should be hit-testable");
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(status_column, FIRST_ACTIVE_SESSION_ROW),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::List));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_wheel_moves_session_selection() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        app.tabs.set(Tab::Sessions);
+        app.sessions.sessions = vec![
+            session_fixture("session-1", Status::Review),
+            session_fixture("session-2", Status::Review),
+        ];
+        app.sessions.table_state.select(Some(0));
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            mouse_event(MouseEventKind::ScrollDown, 4, FIRST_ACTIVE_SESSION_ROW),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert_eq!(app.sessions.table_state.selected(), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_wheel_scrolls_view_transcript() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        let output = "transcript line\n".repeat(80);
+        app.sessions.replace_sessions(vec![
+            SessionFixtureBuilder::new()
+                .id("session-1")
+                .status(Status::Review)
+                .folder(std::env::temp_dir())
+                .output(output)
+                .build(),
+        ]);
+        app.mode = AppMode::View {
+            done_session_output_mode: DoneSessionOutputMode::Summary,
+            review_status_message: None,
+            review_text: None,
+            session_id: "session-1".to_string().into(),
+            scroll_offset: None,
+            transcript_search: None,
+        };
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            mouse_event(MouseEventKind::ScrollUp, 10, 8),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::View {
+                scroll_offset: Some(_),
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_diff_file_tree_selects_file() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        app.mode = AppMode::Diff {
+            diff: TWO_FILE_DIFF.to_string(),
+            file_explorer_selected_index: 0,
+            scroll_cache: None,
+            restore_question: None,
+            session_id: "session-1".to_string().into(),
+            scroll_offset: 4,
+        };
+        let file_list_area = diff_util::diff_page_areas(content_area()).file_list_area;
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(file_list_area.x + 2, file_list_area.y + 2),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(
+            app.mode,
+            AppMode::Diff {
+                file_explorer_selected_index: 1,
+                scroll_offset: 0,
+                ..
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_diff_footer_replays_back_shortcut() {
+        // Arrange
+        let (mut app, _base_dir) = new_test_app().await;
+        let mut terminal = test_terminal();
+        app.mode = AppMode::Diff {
+            diff: TWO_FILE_DIFF.to_string(),
+            file_explorer_selected_index: 0,
+            scroll_cache: None,
+            restore_question: None,
+            session_id: "session-1".to_string().into(),
+            scroll_offset: 0,
+        };
+        let footer_area = diff_util::diff_page_areas(content_area()).footer_area;
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(footer_area.x, footer_area.y),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::View { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_split_preview_focuses_session() {
+        // Arrange
+        let (mut app, _base_dir) = new_split_layout_test_app().await;
+        let mut terminal = Terminal::new(TestBackend::new(SPLIT_TERMINAL_WIDTH, TERMINAL_HEIGHT))
+            .expect("failed to create terminal");
+        app.tabs.set(Tab::Sessions);
+        app.sessions
+            .replace_sessions(vec![session_fixture("session-1", Status::Review)]);
+        app.sessions.table_state.select(Some(0));
+
+        // Act
+        handle_mouse_event(&mut app, &mut terminal, left_click(120, 10))
+            .await
+            .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(
+            &app.mode,
+            AppMode::View { session_id, .. } if session_id == "session-1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_handle_mouse_event_click_on_split_list_returns_focus_to_list() {
+        // Arrange
+        let (mut app, _base_dir) = new_split_layout_test_app().await;
+        let mut terminal = Terminal::new(TestBackend::new(SPLIT_TERMINAL_WIDTH, TERMINAL_HEIGHT))
+            .expect("failed to create terminal");
+        app.tabs.set(Tab::Sessions);
+        app.sessions.replace_sessions(vec![
+            session_fixture("session-1", Status::Review),
+            session_fixture("session-2", Status::Review),
+        ]);
+        app.sessions.table_state.select(Some(0));
+        app.mode = AppMode::View {
+            done_session_output_mode: DoneSessionOutputMode::Summary,
+            review_status_message: None,
+            review_text: None,
+            session_id: "session-1".to_string().into(),
+            scroll_offset: None,
+            transcript_search: None,
+        };
+
+        // Act
+        handle_mouse_event(
+            &mut app,
+            &mut terminal,
+            left_click(4, FIRST_ACTIVE_SESSION_ROW + 1),
+        )
+        .await
+        .expect("failed to handle mouse event");
+
+        // Assert
+        assert!(matches!(app.mode, AppMode::List));
+        assert_eq!(app.sessions.table_state.selected(), Some(1));
+    }
+}
@@ -1,20 +1,32 @@
 use std::cell::Cell;
-use std::io;
+use std::io::{self, Write as _};
+use std::os::fd::{AsFd as _, AsRawFd as _};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use crossterm::cursor::Show;
 use crossterm::event::{
-    DisableBracketedPaste, EnableBracketedPaste, KeyboardEnhancementFlags,
-    PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    DisableBracketedPaste, DisableMouseCapture, EnableBracketedPaste, EnableMouseCapture,
+    KeyboardEnhancementFlags, PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
 };
 use crossterm::execute;
 use crossterm::terminal::{
     EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
     supports_keyboard_enhancement,
 };
+use nix::poll::{PollFd, PollFlags, PollTimeout, poll};
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
 use crate::runtime::TuiTerminal;
+use crate::ui::hyperlink::HyperlinkBackend;
+use crate::ui::theme::{self, Background};
+
+/// OSC 11 query asking the terminal to report its background color.
+const BACKGROUND_QUERY: &[u8] = b"\x1b]11;?\x07";
+
+/// Maximum time to wait for a background color reply before giving up.
+const BACKGROUND_QUERY_TIMEOUT: Duration = Duration::from_millis(100);
 
 /// Abstraction over terminal transitions so setup/restore paths can be tested
 /// without touching real terminal state.
@@ -30,16 +42,18 @@ trait TerminalOperation {
     /// flags for reporting modified keys like `Alt+Enter`.
     fn supports_keyboard_enhancement(&self) -> io::Result<bool>;
 
-    /// Enters the alternate screen and enables bracketed paste, optionally
-    /// enabling keyboard enhancement flags first.
+    /// Enters the alternate screen and enables bracketed paste and, unless
+    /// disabled for this run, mouse capture, optionally enabling keyboard
+    /// enhancement flags first.
     fn enter_alternate_screen(
         &self,
         stdout: &mut io::Stdout,
         keyboard_enhancement_enabled: bool,
     ) -> io::Result<()>;
 
-    /// Leaves the alternate screen, disables bracketed paste, and restores the
-    /// terminal cursor, optionally popping keyboard enhancement flags first.
+    /// Leaves the alternate screen, disables bracketed paste and mouse capture,
+    /// and restores the terminal cursor, optionally popping keyboard
+    /// enhancement flags first.
     fn leave_alternate_screen(
         &self,
         stdout: &mut io::Stdout,
@@ -48,7 +62,10 @@ trait TerminalOperation {
 }
 
 /// Production terminal operations backed by `crossterm`.
-struct CrosstermTerminalOperation;
+struct CrosstermTerminalOperation {
+    /// Whether entering the alternate screen also enables mouse capture.
+    mouse_capture: AtomicBool,
+}
 
 impl TerminalOperation for CrosstermTerminalOperation {
     fn enable_raw_mode(&self) -> io::Result<()> {
@@ -74,10 +91,16 @@ impl TerminalOperation for CrosstermTerminalOperation {
                 PushKeyboardEnhancementFlags(keyboard_enhancement_flags()),
                 EnterAlternateScreen,
                 EnableBracketedPaste
-            )
+            )?;
         } else {
-            execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)
+            execute!(stdout, EnterAlternateScreen, EnableBracketedPaste)?;
+        }
+
+        if self.mouse_capture.load(Ordering::Relaxed) {
+            execute!(stdout, EnableMouseCapture)?;
         }
+
+        Ok(())
     }
 
     fn leave_alternate_screen(
@@ -89,18 +112,37 @@ impl TerminalOperation for CrosstermTerminalOperation {
             execute!(
                 stdout,
                 PopKeyboardEnhancementFlags,
+                DisableMouseCapture,
                 DisableBracketedPaste,
                 LeaveAlternateScreen,
                 Show
             )
         } else {
-            execute!(stdout, DisableBracketedPaste, LeaveAlternateScreen, Show)
+            execute!(
+                stdout,
+                DisableMouseCapture,
+                DisableBracketedPaste,
+                LeaveAlternateScreen,
+                Show
+            )
         }
     }
 }
 
 /// Shared production terminal operation implementation.
-static CROSSTERM_TERMINAL_OPERATION: CrosstermTerminalOperation = CrosstermTerminalOperation;
+static CROSSTERM_TERMINAL_OPERATION: CrosstermTerminalOperation = CrosstermTerminalOperation {
+    mouse_capture: AtomicBool::new(true),
+};
+
+/// Sets whether later terminal setup and resume enable mouse capture.
+///
+/// With capture disabled the terminal keeps its native click-and-drag text
+/// selection, and clicks and wheel scrolling no longer reach the app.
+pub(crate) fn set_mouse_capture(enabled: bool) {
+    CROSSTERM_TERMINAL_OPERATION
+        .mouse_capture
+        .store(enabled, Ordering::Relaxed);
+}
 
 /// Returns the keyboard enhancement flag set used to disambiguate modified key
 /// presses in terminals that support the kitty keyboard protocol without
@@ -162,11 +204,71 @@ impl Drop for TerminalGuard {
 /// Enter keys remain distinguishable over transports like SSH and `tmux`.
 pub(crate) fn setup_terminal(guard: &TerminalGuard) -> io::Result<TuiTerminal> {
     let stdout = prepare_terminal_stdout_with_operation(&CROSSTERM_TERMINAL_OPERATION, guard)?;
-    let backend = CrosstermBackend::new(stdout);
+    let backend = HyperlinkBackend::new(CrosstermBackend::new(stdout));
 
     Terminal::new(backend)
 }
 
+/// Hands the terminal back to the shell so a foreground child process such as
+/// `$EDITOR` can draw on it.
+///
+/// Returns whether keyboard enhancement flags were active so
+/// [`resume_terminal`] can restore the same key reporting mode.
+///
+/// # Errors
+/// Returns an error when leaving the alternate screen or raw mode fails.
+pub(crate) fn suspend_terminal() -> io::Result<bool> {
+    suspend_terminal_with_operation(&CROSSTERM_TERMINAL_OPERATION)
+}
+
+/// Re-enters raw mode and the alternate screen after [`suspend_terminal`].
+///
+/// # Errors
+/// Returns an error when raw mode or the alternate screen cannot be restored.
+pub(crate) fn resume_terminal(keyboard_enhancement_enabled: bool) -> io::Result<()> {
+    resume_terminal_with_operation(&CROSSTERM_TERMINAL_OPERATION, keyboard_enhancement_enabled)
+}
+
+/// Queries the terminal background color with OSC 11 and returns its tone.
+///
+/// Must run after raw mode is enabled and before the event reader starts so
+/// the reply is consumed here instead of surfacing as key input. Terminals
+/// that do not answer within [`BACKGROUND_QUERY_TIMEOUT`] yield `None`.
+pub(crate) fn query_background() -> Option<Background> {
+    let mut stdout = io::stdout();
+    stdout.write_all(BACKGROUND_QUERY).ok()?;
+    stdout.flush().ok()?;
+
+    let stdin = io::stdin();
+    let deadline = Instant::now() + BACKGROUND_QUERY_TIMEOUT;
+    let mut response = Vec::new();
+    let mut buffer = [0_u8; 64];
+    while !is_background_reply_complete(&response) {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let timeout = PollTimeout::try_from(remaining).ok()?;
+        let mut poll_fds = [PollFd::new(stdin.as_fd(), PollFlags::POLLIN)];
+        if remaining.is_zero() || poll(&mut poll_fds, timeout).ok()? == 0 {
+            break;
+        }
+
+        // Reads the descriptor directly so no reply bytes are left in a
+        // userspace buffer that the crossterm event reader cannot see.
+        let read_count = nix::unistd::read(stdin.as_raw_fd(), &mut buffer).ok()?;
+        if read_count == 0 {
+            break;
+        }
+
+        response.extend_from_slice(&buffer[..read_count]);
+    }
+
+    theme::parse_background_response(&response)
+}
+
+/// Returns whether an OSC reply ended with a BEL or ST terminator.
+fn is_background_reply_complete(response: &[u8]) -> bool {
+    response.ends_with(b"\x07") || response.ends_with(b"\x1b\\")
+}
+
 /// Enables terminal modes with the supplied operation provider and returns the
 /// configured stdout handle for later terminal construction.
 fn prepare_terminal_stdout_with_operation(
@@ -185,6 +287,30 @@ fn prepare_terminal_stdout_with_operation(
     Ok(stdout)
 }
 
+/// Leaves the alternate screen and raw mode with the supplied operation
+/// provider, returning whether keyboard enhancement flags were popped.
+fn suspend_terminal_with_operation(operation: &dyn TerminalOperation) -> io::Result<bool> {
+    let keyboard_enhancement_enabled =
+        matches!(operation.supports_keyboard_enhancement(), Ok(true));
+    let mut stdout = io::stdout();
+    operation.leave_alternate_screen(&mut stdout, keyboard_enhancement_enabled)?;
+    operation.disable_raw_mode()?;
+
+    Ok(keyboard_enhancement_enabled)
+}
+
+/// Re-enables raw mode and the alternate screen with the supplied operation
+/// provider.
+fn resume_terminal_with_operation(
+    operation: &dyn TerminalOperation,
+    keyboard_enhancement_enabled: bool,
+) -> io::Result<()> {
+    operation.enable_raw_mode()?;
+
+    let mut stdout = io::stdout();
+    operation.enter_alternate_screen(&mut stdout, keyboard_enhancement_enabled)
+}
+
 /// Restores terminal modes and ignores failures so drop paths do not panic.
 fn restore_terminal_state(operation: &dyn TerminalOperation, keyboard_enhancement_enabled: bool) {
     let mut stdout = io::stdout();
@@ -307,6 +433,57 @@ mod tests {
         assert!(!guard.keyboard_enhancement_enabled());
     }
 
+    /// Verifies suspend pops keyboard enhancement flags when they were
+    /// enabled and reports that state for resume.
+    #[test]
+    fn suspend_terminal_leaves_alternate_screen_before_raw_mode() {
+        // Arrange
+        let mut operation = MockTerminalOperation::new();
+        let mut sequence = mockall::Sequence::new();
+        operation
+            .expect_supports_keyboard_enhancement()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|| Ok(true));
+        operation
+            .expect_leave_alternate_screen()
+            .once()
+            .in_sequence(&mut sequence)
+            .withf(|_, keyboard_enhancement_enabled| *keyboard_enhancement_enabled)
+            .returning(|_, _| Ok(()));
+        operation
+            .expect_disable_raw_mode()
+            .once()
+            .in_sequence(&mut sequence)
+            .returning(|| Ok(()));
+
+        // Act
+        let result = suspend_terminal_with_operation(&operation);
+
+        // Assert
+        assert!(result.expect("suspend should succeed"));
+    }
+
+    /// Verifies resume does not enter the alternate screen when raw mode
+    /// cannot be restored.
+    #[test]
+    fn resume_terminal_returns_error_when_enable_raw_mode_fails() {
+        // Arrange
+        let mut operation = MockTerminalOperation::new();
+        operation
+            .expect_enable_raw_mode()
+            .once()
+            .returning(|| Err(io::Error::other("enable failed")));
+        operation.expect_enter_alternate_screen().times(0);
+
+        // Act
+        let result = resume_terminal_with_operation(&operation, false);
+
+        // Assert
+        let error = result.expect_err("resume should fail when raw mode fails");
+        assert_eq!(error.to_string(), "enable failed");
+    }
+
     /// Verifies restore still attempts alternate-screen cleanup when raw-mode
     /// cleanup fails.
     #[test]
@@ -1,6 +1,10 @@
 pub mod activity_heatmap;
 pub mod component;
 pub mod diff_util;
+/// Language-aware syntax highlighting mapped onto palette tokens.
+pub mod highlight;
+/// OSC 8 hyperlink encoding and the terminal backend that emits it.
+pub mod hyperlink;
 pub mod icon;
 pub mod layout;
 pub mod markdown;
@@ -13,6 +17,8 @@ pub mod state;
 pub mod style;
 mod task_roadmap;
 pub mod text_util;
+/// Runtime-selectable color themes resolved into palette tokens.
+pub mod theme;
 pub mod util;
 
 /// A trait for UI components that enforces a standard rendering interface.
@@ -6,8 +6,11 @@ pub mod file_explorer;
 pub mod footer_bar;
 pub mod help_overlay;
 pub mod info_overlay;
+pub mod issue_picker_overlay;
 pub mod open_command_overlay;
+pub mod plan_review_overlay;
 pub mod publish_branch_overlay;
 pub mod session_output;
+pub mod session_search_overlay;
 pub mod status_bar;
 pub mod tab;
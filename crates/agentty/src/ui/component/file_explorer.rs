@@ -111,6 +111,26 @@ impl FileExplorer {
         self
     }
 
+    /// Returns the tree item index rendered at terminal `row` when the
+    /// explorer is drawn in `area`, or `None` for borders and empty rows.
+    ///
+    /// Mirrors the list viewport, which scrolls only far enough to keep the
+    /// selected item visible.
+    pub fn index_at_row(&self, area: Rect, row: u16) -> Option<usize> {
+        let list_area = Block::default().borders(Borders::ALL).inner(area);
+        if !(list_area.top()..list_area.bottom()).contains(&row) {
+            return None;
+        }
+
+        let selected_index = self
+            .selected_index
+            .min(self.file_list_lines.len().saturating_sub(1));
+        let viewport_offset = (selected_index + 1).saturating_sub(usize::from(list_area.height));
+        let index = viewport_offset + usize::from(row - list_area.y);
+
+        (index < self.file_list_lines.len()).then_some(index)
+    }
+
     /// Returns the next selected index for a file list of `item_count` items.
     ///
     /// Selection wraps to the first item when moving forward from the last
@@ -226,7 +246,7 @@ impl FileExplorer {
         if file_list_lines.is_empty() {
             file_list_lines.push(Line::from(Span::styled(
                 NO_FILES_LABEL,
-                Style::default().fg(style::palette::TEXT_SUBTLE),
+                Style::default().fg(style::palette::text_subtle()),
             )));
         }
 
@@ -326,7 +346,7 @@ impl FileExplorer {
 
             lines.push(Line::from(Span::styled(
                 line_text,
-                Style::default().fg(style::palette::WARNING),
+                Style::default().fg(style::palette::warning()),
             )));
             items.push(FileTreeItem::Folder(folder_path.clone()));
 
@@ -351,13 +371,13 @@ impl FileExplorer {
             let file_path = format!("{path_prefix}{}", file.name);
             let mut spans = vec![Span::styled(
                 file_name,
-                Style::default().fg(style::palette::ACCENT),
+                Style::default().fg(style::palette::accent()),
             )];
 
             if let Some(rename_from) = &file.rename_from {
                 spans.push(Span::styled(
                     format!("{RENAME_ORIGIN_PREFIX}{rename_from}"),
-                    Style::default().fg(style::palette::TEXT_SUBTLE),
+                    Style::default().fg(style::palette::text_subtle()),
                 ));
             }
 
@@ -379,9 +399,9 @@ impl Component for FileExplorer {
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title(Span::styled(
                 FILE_EXPLORER_TITLE,
-                Style::default().fg(style::palette::ACCENT),
+                Style::default().fg(style::palette::accent()),
             )))
-            .highlight_style(Style::default().bg(style::palette::SURFACE));
+            .highlight_style(Style::default().bg(style::palette::surface()));
 
         let mut state = ListState::default();
         state.select(Some(self.selected_index));
@@ -415,6 +435,34 @@ mod tests {
     ];
     const UNCHANGED_DIFF_LINE: &str = " unchanged";
 
+    #[test]
+    fn test_index_at_row_follows_scrolled_selection() {
+        // Arrange
+        let diff = [
+            DIFF_SAME_PATH_HEADER,
+            UNCHANGED_DIFF_LINE,
+            DIFF_README_HEADER,
+            UNCHANGED_DIFF_LINE,
+            DIFF_NESTED_HEADER,
+            UNCHANGED_DIFF_LINE,
+        ]
+        .join("\n");
+        let parsed_lines = crate::ui::util::parse_diff_lines(&diff);
+        let area = Rect::new(0, 2, 30, 5);
+
+        // Act
+        let top_row = FileExplorer::new(&parsed_lines).index_at_row(area, 3);
+        let border_row = FileExplorer::new(&parsed_lines).index_at_row(area, 2);
+        let scrolled_top_row = FileExplorer::new(&parsed_lines)
+            .selected_index(5)
+            .index_at_row(area, 3);
+
+        // Assert
+        assert_eq!(top_row, Some(0));
+        assert_eq!(border_row, None);
+        assert_eq!(scrolled_top_row, Some(3));
+    }
+
     #[test]
     fn test_file_list_lines_with_same_path() {
         // Arrange
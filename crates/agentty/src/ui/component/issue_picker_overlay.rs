@@ -0,0 +1,243 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use crate::domain::session::{AGENT_ISSUE_LABEL, ForgeIssueSummary};
+use crate::ui::state::app_mode::IssuePickerStatus;
+use crate::ui::style::palette;
+use crate::ui::text_util::truncate_with_ellipsis;
+use crate::ui::{Component, overlay};
+
+const MIN_OVERLAY_HEIGHT: u16 = 12;
+const MIN_OVERLAY_WIDTH: u16 = 60;
+const OVERLAY_HEIGHT_PERCENT: u16 = 60;
+const OVERLAY_WIDTH_PERCENT: u16 = 70;
+
+/// Centered popup that lists open forge issues and starts one session from
+/// the selected issue.
+pub struct IssuePickerOverlay<'a> {
+    issues: &'a [ForgeIssueSummary],
+    selected_issue_index: usize,
+    status: &'a IssuePickerStatus,
+}
+
+impl<'a> IssuePickerOverlay<'a> {
+    /// Creates an issue picker popup from listed issues and load state.
+    pub fn new(issues: &'a [ForgeIssueSummary], status: &'a IssuePickerStatus) -> Self {
+        Self {
+            issues,
+            selected_issue_index: 0,
+            status,
+        }
+    }
+
+    /// Sets which issue row is currently highlighted.
+    #[must_use]
+    pub fn selected_issue_index(mut self, selected_issue_index: usize) -> Self {
+        self.selected_issue_index = selected_issue_index;
+        self
+    }
+
+    /// Returns all render lines for this popup.
+    ///
+    /// Loading, failure, and empty states replace the issue rows with one
+    /// status line so the popup keeps a stable header and help hint.
+    fn lines(&self, row_width: usize) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                "Start session from issue",
+                Style::default()
+                    .fg(palette::warning())
+                    .add_modifier(Modifier::BOLD),
+            )])
+            .alignment(Alignment::Center),
+            Line::from(""),
+        ];
+
+        match self.status {
+            IssuePickerStatus::Loading => {
+                lines.push(status_line("Loading open issues...", palette::text_muted()));
+            }
+            IssuePickerStatus::Starting => {
+                let selected_issue = self
+                    .issues
+                    .get(self.selected_issue_index)
+                    .map_or("issue", |issue| issue.display_id.as_str());
+                lines.push(status_line(
+                    &format!("Loading {selected_issue}..."),
+                    palette::text_muted(),
+                ));
+            }
+            IssuePickerStatus::Failed(error) => {
+                lines.push(status_line(error, palette::danger()));
+            }
+            IssuePickerStatus::Ready if self.issues.is_empty() => {
+                lines.push(status_line(
+                    &format!(
+                        "No open issues are assigned to you or labelled `{AGENT_ISSUE_LABEL}`."
+                    ),
+                    palette::text_muted(),
+                ));
+            }
+            IssuePickerStatus::Ready => {
+                for (index, issue) in self.issues.iter().enumerate() {
+                    lines.push(issue_line(
+                        issue,
+                        index == self.selected_issue_index,
+                        row_width,
+                    ));
+                }
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(vec![Span::styled(
+                self.help_text(),
+                Style::default().fg(palette::text_muted()),
+            )])
+            .alignment(Alignment::Center),
+        );
+
+        lines
+    }
+
+    /// Returns the bottom help hint for the current load state.
+    fn help_text(&self) -> &'static str {
+        match self.status {
+            IssuePickerStatus::Ready if !self.issues.is_empty() => {
+                "j/k: move | Enter: start session | Esc: cancel"
+            }
+            IssuePickerStatus::Loading | IssuePickerStatus::Starting => "Esc: cancel",
+            IssuePickerStatus::Ready | IssuePickerStatus::Failed(_) => "Esc: close",
+        }
+    }
+
+    /// Returns the centered popup rectangle constrained to terminal bounds.
+    fn popup_area(area: Rect) -> Rect {
+        overlay::centered_popup_area(
+            area,
+            OVERLAY_WIDTH_PERCENT,
+            OVERLAY_HEIGHT_PERCENT,
+            MIN_OVERLAY_WIDTH,
+            MIN_OVERLAY_HEIGHT,
+        )
+    }
+}
+
+impl Component for IssuePickerOverlay<'_> {
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let popup_area = Self::popup_area(area);
+        let row_width = overlay::overlay_content_width(popup_area.width)
+            .saturating_sub(1)
+            .max(1);
+        let lines = self.lines(row_width);
+
+        let paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true })
+            .block(overlay::overlay_block("Issues", palette::accent()));
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+/// Builds one centered status line for non-list picker states.
+fn status_line(text: &str, color: ratatui::style::Color) -> Line<'static> {
+    Line::from(Span::styled(text.to_string(), Style::default().fg(color)))
+        .alignment(Alignment::Center)
+}
+
+/// Builds one issue row, emphasizing the selected row by background color.
+fn issue_line(issue: &ForgeIssueSummary, is_selected: bool, row_width: usize) -> Line<'static> {
+    let labels = if issue.labels.is_empty() {
+        String::new()
+    } else {
+        format!("  [{}]", issue.labels.join(", "))
+    };
+    let issue_label = truncate_with_ellipsis(
+        &format!("{} {}{labels}", issue.display_id, issue.title),
+        row_width,
+    );
+
+    if is_selected {
+        return Line::from(Span::styled(
+            format!(" {issue_label:<row_width$}"),
+            Style::default()
+                .fg(palette::surface_overlay())
+                .bg(palette::accent())
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    Line::from(vec![
+        Span::styled(" ", Style::default().fg(palette::text_subtle())),
+        Span::styled(issue_label, Style::default().fg(palette::text())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::session::ForgeKind;
+
+    #[test]
+    fn test_issue_picker_overlay_lines_include_issue_title_and_labels() {
+        // Arrange
+        let issues = vec![issue_summary()];
+        let status = IssuePickerStatus::Ready;
+        let overlay = IssuePickerOverlay::new(&issues, &status);
+
+        // Act
+        let lines = overlay.lines(60);
+        let issue_text: String = lines[2]
+            .spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect();
+
+        // Assert
+        assert!(issue_text.contains("#12 Add picker  [agentty]"));
+        assert_eq!(lines[2].spans[0].style.bg, Some(palette::accent()));
+    }
+
+    #[test]
+    fn test_issue_picker_overlay_render_shows_empty_state() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let status = IssuePickerStatus::Ready;
+        let overlay = IssuePickerOverlay::new(&[], &status);
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("No open issues are assigned to you"));
+        assert!(text.contains("Esc: close"));
+    }
+
+    fn issue_summary() -> ForgeIssueSummary {
+        ForgeIssueSummary {
+            display_id: "#12".to_string(),
+            forge_kind: ForgeKind::GitHub,
+            labels: vec!["agentty".to_string()],
+            title: "Add picker".to_string(),
+            web_url: "https://github.com/agentty-xyz/agentty/issues/12".to_string(),
+        }
+    }
+}
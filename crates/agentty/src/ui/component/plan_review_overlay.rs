@@ -0,0 +1,207 @@
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph, Wrap};
+
+use crate::ui::state::plan_review::PlanReviewState;
+use crate::ui::style::palette;
+use crate::ui::text_util::truncate_with_ellipsis;
+use crate::ui::{Component, overlay};
+
+const MIN_OVERLAY_HEIGHT: u16 = 12;
+const MIN_OVERLAY_WIDTH: u16 = 60;
+const OVERLAY_HEIGHT_PERCENT: u16 = 60;
+const OVERLAY_WIDTH_PERCENT: u16 = 70;
+
+/// Centered popup that reviews one proposed plan before approval.
+pub struct PlanReviewOverlay<'a> {
+    review: &'a PlanReviewState,
+}
+
+impl<'a> PlanReviewOverlay<'a> {
+    /// Creates a plan-review popup for the current review state.
+    pub fn new(review: &'a PlanReviewState) -> Self {
+        Self { review }
+    }
+
+    /// Returns all render lines for this popup.
+    ///
+    /// Kept steps render a `[x]` marker and dropped steps `[ ]`. The selected
+    /// row is emphasized by background color, and shows the inline editor
+    /// while a step is being edited.
+    fn lines(&self, row_width: usize) -> Vec<Line<'static>> {
+        let mut lines = vec![
+            Line::from(vec![Span::styled(
+                "Review proposed plan",
+                Style::default()
+                    .fg(palette::warning())
+                    .add_modifier(Modifier::BOLD),
+            )])
+            .alignment(Alignment::Center),
+            Line::from(""),
+        ];
+
+        for (step_index, review_step) in self.review.steps.iter().enumerate() {
+            let is_selected = step_index == self.review.selected_step_index;
+            let marker = if review_step.is_included {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+            let step_text = match &self.review.editing_input {
+                Some(input) if is_selected => format!("{}▏", input.text()),
+                _ => review_step.step.text.clone(),
+            };
+            let step_label = truncate_with_ellipsis(
+                &format!("{marker} {}. {step_text}", step_index + 1),
+                row_width,
+            );
+
+            if is_selected {
+                lines.push(Line::from(Span::styled(
+                    format!(" {step_label:<row_width$}"),
+                    Style::default()
+                        .fg(palette::surface_overlay())
+                        .bg(palette::accent())
+                        .add_modifier(Modifier::BOLD),
+                )));
+            } else {
+                let text_color = if review_step.is_included {
+                    palette::text()
+                } else {
+                    palette::text_subtle()
+                };
+                lines.push(Line::from(vec![
+                    Span::raw(" "),
+                    Span::styled(step_label, Style::default().fg(text_color)),
+                ]));
+            }
+
+            if !review_step.step.files.is_empty() {
+                lines.push(Line::from(Span::styled(
+                    truncate_with_ellipsis(
+                        &format!("       {}", review_step.step.files.join(", ")),
+                        row_width,
+                    ),
+                    Style::default().fg(palette::text_muted()),
+                )));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(vec![Span::styled(
+                Self::hint_text(self.review.editing_input.is_some()),
+                Style::default().fg(palette::text_muted()),
+            )])
+            .alignment(Alignment::Center),
+        );
+
+        lines
+    }
+
+    /// Returns the bottom key hint for the current edit state.
+    fn hint_text(is_editing: bool) -> &'static str {
+        if is_editing {
+            "Enter: save step | Esc: discard edit"
+        } else {
+            "j/k: move | Space: keep/drop | e: edit | Enter: approve | Esc: close"
+        }
+    }
+
+    /// Returns the centered popup rectangle constrained to terminal bounds.
+    fn popup_area(area: Rect) -> Rect {
+        overlay::centered_popup_area(
+            area,
+            OVERLAY_WIDTH_PERCENT,
+            OVERLAY_HEIGHT_PERCENT,
+            MIN_OVERLAY_WIDTH,
+            MIN_OVERLAY_HEIGHT,
+        )
+    }
+}
+
+impl Component for PlanReviewOverlay<'_> {
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let popup_area = Self::popup_area(area);
+        let row_width = overlay::overlay_content_width(popup_area.width)
+            .saturating_sub(1)
+            .max(1);
+        let lines = self.lines(row_width);
+
+        let paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Left)
+            .wrap(Wrap { trim: true })
+            .block(overlay::overlay_block("Plan", palette::accent()));
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(paragraph, popup_area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::input::InputState;
+    use crate::domain::plan::PlanStep;
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    fn review_state() -> PlanReviewState {
+        PlanReviewState::new(vec![
+            PlanStep::new("Add the column", vec!["migrations/001.sql".to_string()]),
+            PlanStep::new("Update docs", Vec::new()),
+        ])
+    }
+
+    #[test]
+    fn test_plan_review_overlay_lines_mark_kept_and_dropped_steps() {
+        // Arrange
+        let mut review = review_state();
+        review.select_next();
+        review.toggle_selected();
+        let overlay = PlanReviewOverlay::new(&review);
+
+        // Act
+        let lines = overlay.lines(48);
+        let texts = lines.iter().map(line_text).collect::<Vec<_>>();
+
+        // Assert
+        assert!(
+            texts
+                .iter()
+                .any(|text| text.contains("[x] 1. Add the column"))
+        );
+        assert!(texts.iter().any(|text| text.contains("migrations/001.sql")));
+        assert!(texts.iter().any(|text| text.contains("[ ] 2. Update docs")));
+    }
+
+    #[test]
+    fn test_plan_review_overlay_lines_show_inline_editor_for_selected_step() {
+        // Arrange
+        let mut review = review_state();
+        review.editing_input = Some(InputState::with_text("Add the plan column".to_string()));
+        let overlay = PlanReviewOverlay::new(&review);
+
+        // Act
+        let lines = overlay.lines(48);
+        let texts = lines.iter().map(line_text).collect::<Vec<_>>();
+
+        // Assert
+        assert!(
+            texts
+                .iter()
+                .any(|text| text.contains("1. Add the plan column▏"))
+        );
+        assert_eq!(
+            texts.last().map(String::as_str),
+            Some("Enter: save step | Esc: discard edit")
+        );
+    }
+}
@@ -6,24 +6,38 @@ use ratatui::widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap};
 
 use crate::domain::input::InputState;
 use crate::ui::component::chat_input::ChatInput;
+use crate::ui::state::app_mode::{
+    ReviewRequestDraftFocus, ReviewRequestDraftState, ReviewRequestDraftStatus,
+};
 use crate::ui::style::palette;
 use crate::ui::{Component, overlay};
 
 const REVIEW_REQUEST_EDITABLE_HELP_TEXT: &str = "Enter: publish review request | Esc: cancel";
 const REVIEW_REQUEST_LOCKED_HELP_TEXT: &str = "Enter: refresh review request | Esc: cancel";
+const REVIEW_REQUEST_DRAFT_HELP_TEXT: &str =
+    "Enter: publish review request | Tab: next field | Alt+Enter: newline | Esc: cancel";
+const REVIEW_REQUEST_DRAFT_LOADING_HELP_TEXT: &str = "Filling template... | Esc: cancel";
 const REVIEW_REQUEST_TITLE: &str = "Publish Review Request";
 const INPUT_TITLE: &str = "Remote Branch";
+const DRAFT_TITLE_INPUT_TITLE: &str = "Title";
+const DRAFT_BODY_INPUT_TITLE: &str = "Description";
 const MIN_OVERLAY_HEIGHT: u16 = 11;
 const MIN_OVERLAY_WIDTH: u16 = 58;
+const MIN_DRAFT_OVERLAY_HEIGHT: u16 = 24;
 const OVERLAY_HEIGHT_PERCENT: u16 = 42;
+const DRAFT_OVERLAY_HEIGHT_PERCENT: u16 = 84;
 const OVERLAY_WIDTH_PERCENT: u16 = 62;
 
 /// Centered popup that collects an optional remote branch name before
 /// publishing or refreshing the linked review request.
+///
+/// When the repository ships a review-request template, the popup also
+/// previews the template-filled title and description for editing.
 pub struct PublishBranchOverlay<'a> {
     default_branch_name: &'a str,
     input: &'a InputState,
     locked_upstream_ref: Option<&'a str>,
+    review_request_draft: Option<&'a ReviewRequestDraftState>,
 }
 
 impl<'a> PublishBranchOverlay<'a> {
@@ -37,9 +51,21 @@ impl<'a> PublishBranchOverlay<'a> {
             default_branch_name,
             input,
             locked_upstream_ref,
+            review_request_draft: None,
         }
     }
 
+    /// Sets the template-filled review-request preview shown below the
+    /// branch field.
+    #[must_use]
+    pub fn review_request_draft(
+        mut self,
+        review_request_draft: Option<&'a ReviewRequestDraftState>,
+    ) -> Self {
+        self.review_request_draft = review_request_draft;
+        self
+    }
+
     /// Returns the centered popup rectangle constrained to terminal bounds.
     fn popup_area(area: Rect) -> Rect {
         overlay::centered_popup_area(
@@ -51,6 +77,27 @@ impl<'a> PublishBranchOverlay<'a> {
         )
     }
 
+    /// Returns the taller popup rectangle used when a review-request draft
+    /// preview is visible.
+    fn draft_popup_area(area: Rect) -> Rect {
+        overlay::centered_popup_area(
+            area,
+            OVERLAY_WIDTH_PERCENT,
+            DRAFT_OVERLAY_HEIGHT_PERCENT,
+            MIN_OVERLAY_WIDTH,
+            MIN_DRAFT_OVERLAY_HEIGHT,
+        )
+    }
+
+    /// Returns whether the field is focused for text edits.
+    fn is_focused(&self, focus: ReviewRequestDraftFocus) -> bool {
+        self.review_request_draft
+            .map_or(ReviewRequestDraftFocus::Branch, |review_request_draft| {
+                review_request_draft.focus
+            })
+            == focus
+    }
+
     /// Returns the placeholder shown before the first publish.
     fn placeholder(&self) -> String {
         format!("Leave blank to push as `{}`", self.default_branch_name)
@@ -72,6 +119,17 @@ impl<'a> PublishBranchOverlay<'a> {
 
     /// Returns the footer help line for the current overlay state.
     fn help_text(&self) -> &'static str {
+        if let Some(review_request_draft) = self.review_request_draft {
+            return match review_request_draft.status {
+                ReviewRequestDraftStatus::Loading => REVIEW_REQUEST_DRAFT_LOADING_HELP_TEXT,
+                ReviewRequestDraftStatus::Ready => REVIEW_REQUEST_DRAFT_HELP_TEXT,
+                ReviewRequestDraftStatus::Failed(_) if self.locked_upstream_ref.is_some() => {
+                    REVIEW_REQUEST_LOCKED_HELP_TEXT
+                }
+                ReviewRequestDraftStatus::Failed(_) => REVIEW_REQUEST_EDITABLE_HELP_TEXT,
+            };
+        }
+
         if self.locked_upstream_ref.is_some() {
             REVIEW_REQUEST_LOCKED_HELP_TEXT
         } else {
@@ -88,13 +146,13 @@ impl<'a> PublishBranchOverlay<'a> {
             .border_type(BorderType::Rounded)
             .border_style(
                 Style::default()
-                    .fg(palette::ACCENT)
+                    .fg(palette::accent())
                     .add_modifier(Modifier::BOLD),
             )
             .title(Span::styled(
                 title,
                 Style::default()
-                    .fg(palette::ACCENT)
+                    .fg(palette::accent())
                     .add_modifier(Modifier::BOLD),
             ));
         let paragraph = Paragraph::new(self.input.text()).block(block);
@@ -102,28 +160,109 @@ impl<'a> PublishBranchOverlay<'a> {
         f.render_widget(Clear, area);
         f.render_widget(paragraph, area);
     }
+
+    /// Renders the editable branch field or its locked variant.
+    fn render_branch_field(&self, f: &mut Frame, area: Rect) {
+        if self.locked_upstream_ref.is_some() {
+            self.render_locked_branch_field(f, area);
+
+            return;
+        }
+
+        let placeholder = self.placeholder();
+        let input = ChatInput::new(INPUT_TITLE, self.input.text(), self.input.cursor)
+            .placeholder(&placeholder)
+            .active(self.is_focused(ReviewRequestDraftFocus::Branch));
+
+        input.render(f, area);
+    }
+
+    /// Renders the review-request title and description preview, or the
+    /// generation status while the template is not ready.
+    fn render_review_request_draft(
+        &self,
+        f: &mut Frame,
+        title_area: Rect,
+        body_area: Rect,
+        review_request_draft: &ReviewRequestDraftState,
+    ) {
+        let status_text = match &review_request_draft.status {
+            ReviewRequestDraftStatus::Ready => None,
+            ReviewRequestDraftStatus::Loading => Some(format!(
+                "Filling `{}` from the session summary and diff...",
+                review_request_draft.template_path
+            )),
+            ReviewRequestDraftStatus::Failed(error) => Some(format!(
+                "Could not fill `{}`: {error}\n\nPublishing will use the session commit message \
+                 instead.",
+                review_request_draft.template_path
+            )),
+        };
+        if let Some(status_text) = status_text {
+            let status = Paragraph::new(status_text)
+                .style(Style::default().fg(palette::text_muted()))
+                .wrap(Wrap { trim: true });
+            let status_area = Rect {
+                height: title_area.height + body_area.height,
+                ..title_area
+            };
+
+            f.render_widget(status, status_area);
+
+            return;
+        }
+
+        ChatInput::new(
+            DRAFT_TITLE_INPUT_TITLE,
+            review_request_draft.title.text(),
+            review_request_draft.title.cursor,
+        )
+        .active(self.is_focused(ReviewRequestDraftFocus::Title))
+        .render(f, title_area);
+        ChatInput::new(
+            DRAFT_BODY_INPUT_TITLE,
+            review_request_draft.body.text(),
+            review_request_draft.body.cursor,
+        )
+        .active(self.is_focused(ReviewRequestDraftFocus::Body))
+        .render(f, body_area);
+    }
 }
 
 impl Component for PublishBranchOverlay<'_> {
     fn render(&self, f: &mut Frame, area: Rect) {
-        let popup_area = Self::popup_area(area);
-        let block = overlay::overlay_block(REVIEW_REQUEST_TITLE, palette::ACCENT);
+        let popup_area = if self.review_request_draft.is_some() {
+            Self::draft_popup_area(area)
+        } else {
+            Self::popup_area(area)
+        };
+        let block = overlay::overlay_block(REVIEW_REQUEST_TITLE, palette::accent());
         let inner_area = block.inner(popup_area);
-        let sections = Layout::vertical([
-            Constraint::Min(2),
-            Constraint::Length(3),
-            Constraint::Length(1),
-        ])
-        .split(inner_area);
+        let constraints = if self.review_request_draft.is_some() {
+            vec![
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Min(5),
+                Constraint::Length(1),
+            ]
+        } else {
+            vec![
+                Constraint::Min(2),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ]
+        };
+        let sections = Layout::vertical(constraints).split(inner_area);
         let message = Paragraph::new(Line::from(vec![Span::styled(
             self.message_text(),
-            Style::default().fg(palette::TEXT_MUTED),
+            Style::default().fg(palette::text_muted()),
         )]))
         .wrap(Wrap { trim: true });
         let help = Paragraph::new(
             Line::from(vec![Span::styled(
                 self.help_text(),
-                Style::default().fg(palette::TEXT_MUTED),
+                Style::default().fg(palette::text_muted()),
             )])
             .alignment(Alignment::Center),
         );
@@ -131,16 +270,11 @@ impl Component for PublishBranchOverlay<'_> {
         f.render_widget(Clear, popup_area);
         f.render_widget(block, popup_area);
         f.render_widget(message, sections[0]);
-        if self.locked_upstream_ref.is_some() {
-            self.render_locked_branch_field(f, sections[1]);
-        } else {
-            let placeholder = self.placeholder();
-            let input = ChatInput::new(INPUT_TITLE, self.input.text(), self.input.cursor)
-                .placeholder(&placeholder);
-
-            input.render(f, sections[1]);
+        self.render_branch_field(f, sections[1]);
+        if let Some(review_request_draft) = self.review_request_draft {
+            self.render_review_request_draft(f, sections[2], sections[3], review_request_draft);
         }
-        f.render_widget(help, sections[2]);
+        f.render_widget(help, sections[sections.len() - 1]);
     }
 }
 
@@ -250,4 +384,72 @@ mod tests {
         assert!(text.contains("review request"));
         assert!(text.contains(REVIEW_REQUEST_EDITABLE_HELP_TEXT));
     }
+
+    #[test]
+    fn test_publish_branch_overlay_render_shows_ready_review_request_draft() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let input = InputState::default();
+        let review_request_draft = ReviewRequestDraftState {
+            body: InputState::with_text("## Summary\nAdds template support".to_string()),
+            focus: ReviewRequestDraftFocus::Title,
+            status: ReviewRequestDraftStatus::Ready,
+            template_path: ".github/pull_request_template.md".to_string(),
+            title: InputState::with_text("Fill review-request templates".to_string()),
+        };
+        let overlay = PublishBranchOverlay::new(&input, "wt/ff45463f", None)
+            .review_request_draft(Some(&review_request_draft));
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains(DRAFT_TITLE_INPUT_TITLE));
+        assert!(text.contains("Fill review-request templates"));
+        assert!(text.contains("Adds template support"));
+        assert!(text.contains("Tab: next field"));
+    }
+
+    #[test]
+    fn test_publish_branch_overlay_render_shows_loading_review_request_draft() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let input = InputState::default();
+        let review_request_draft = ReviewRequestDraftState::loading(
+            ".gitlab/merge_request_templates/Default.md".to_string(),
+        );
+        let overlay = PublishBranchOverlay::new(&input, "wt/ff45463f", None)
+            .review_request_draft(Some(&review_request_draft));
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains(".gitlab/merge_request_templates/Default.md"));
+        assert!(text.contains(REVIEW_REQUEST_DRAFT_LOADING_HELP_TEXT));
+    }
 }
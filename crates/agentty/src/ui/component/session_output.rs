@@ -1,17 +1,20 @@
 use std::fmt::Write as _;
+use std::ops::Range;
 
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use serde_json;
 
+use crate::domain::plan::PlanStatus;
 use crate::domain::session::{PublishedBranchSyncStatus, Session, Status};
 use crate::icon::Icon;
 use crate::infra::agent::protocol::AgentResponseSummary;
 use crate::ui::markdown::{self, render_markdown};
 use crate::ui::state::app_mode::DoneSessionOutputMode;
+use crate::ui::state::transcript_search;
 use crate::ui::util::{bottom_pinned_scroll_offset, panel_inner_width};
 use crate::ui::{Component, style, text_util};
 
@@ -30,7 +33,6 @@ const REVIEW_SUGGESTIONS_HEADER_WITH_HINT: &str = "### Suggestions (type \"/appl
 
 /// Session chat output panel renderer.
 pub struct SessionOutput<'a> {
-    active_prompt_output: Option<&'a str>,
     active_progress: Option<&'a str>,
     /// Selected panel content for the session output panel.
     done_session_output_mode: DoneSessionOutputMode,
@@ -40,6 +42,8 @@ pub struct SessionOutput<'a> {
     review_status_message: Option<&'a str>,
     review_text: Option<&'a str>,
     scroll_offset: Option<u16>,
+    /// Transcript search query and focused match line to highlight.
+    search_highlight: Option<(&'a str, Option<usize>)>,
     session: &'a Session,
 }
 
@@ -47,9 +51,6 @@ pub struct SessionOutput<'a> {
 /// session snapshot.
 #[derive(Clone, Copy)]
 pub(crate) struct SessionOutputLineContext<'a> {
-    /// Exact prompt transcript block for the currently active turn, when one
-    /// has been submitted in this app process.
-    pub(crate) active_prompt_output: Option<&'a str>,
     /// Transient progress text rendered in the active-status loader row.
     pub(crate) active_progress: Option<&'a str>,
     /// Completed-session panel mode currently selected by the user.
@@ -66,24 +67,17 @@ impl<'a> SessionOutput<'a> {
     /// Creates a new session output component.
     pub fn new(session: &'a Session) -> Self {
         Self {
-            active_prompt_output: None,
             active_progress: None,
             done_session_output_mode: DoneSessionOutputMode::Summary,
             markdown_render_cache: None,
             review_status_message: None,
             review_text: None,
             scroll_offset: None,
+            search_highlight: None,
             session,
         }
     }
 
-    /// Sets the exact prompt transcript block for the currently active turn.
-    #[must_use]
-    pub fn active_prompt_output(mut self, active_prompt_output: Option<&'a str>) -> Self {
-        self.active_prompt_output = active_prompt_output;
-        self
-    }
-
     /// Sets transient progress text rendered in the loader row.
     #[must_use]
     pub fn active_progress(mut self, active_progress: &'a str) -> Self {
@@ -128,6 +122,14 @@ impl<'a> SessionOutput<'a> {
         self
     }
 
+    /// Highlights transcript search matches for `query`, emphasizing the
+    /// rendered line at `focused_line`.
+    #[must_use]
+    pub fn search_highlight(mut self, query: &'a str, focused_line: Option<usize>) -> Self {
+        self.search_highlight = Some((query, focused_line));
+        self
+    }
+
     /// Returns the rendered output line count for chat content at a given
     /// width.
     ///
@@ -138,12 +140,125 @@ impl<'a> SessionOutput<'a> {
         output_width: u16,
         context: SessionOutputLineContext<'_>,
     ) -> u16 {
-        let output_area = Rect::new(0, 0, output_width, 0);
-        let lines = Self::output_lines(session, output_area, context, None);
+        let lines = Self::rendered_lines(session, output_width, context);
 
         u16::try_from(lines.len()).unwrap_or(u16::MAX)
     }
 
+    /// Returns the rendered output lines for chat content at a given width.
+    ///
+    /// Line indices match the scroll offset space, so transcript search and
+    /// prompt navigation can map positions onto scroll targets.
+    pub(crate) fn rendered_lines(
+        session: &Session,
+        output_width: u16,
+        context: SessionOutputLineContext<'_>,
+    ) -> Vec<Line<'static>> {
+        let output_area = Rect::new(0, 0, output_width, 0);
+
+        Self::output_lines(session, output_area, context, None)
+    }
+
+    /// Patches highlight styles onto every `query` match in `lines`.
+    ///
+    /// Matches on `focused_line` use a stronger style so the current match
+    /// stands out from the rest.
+    fn highlight_search_matches(
+        lines: Vec<Line<'static>>,
+        query: &str,
+        focused_line: Option<usize>,
+    ) -> Vec<Line<'static>> {
+        lines
+            .into_iter()
+            .enumerate()
+            .map(|(line_index, line)| {
+                let match_ranges = transcript_search::line_match_ranges(&line, query);
+                if match_ranges.is_empty() {
+                    return line;
+                }
+
+                let highlight_style = if focused_line == Some(line_index) {
+                    Self::focused_search_match_style()
+                } else {
+                    Self::search_match_style()
+                };
+
+                Self::highlight_line_ranges(line, &match_ranges, highlight_style)
+            })
+            .collect()
+    }
+
+    /// Splits spans at match boundaries and patches `highlight_style` onto
+    /// the byte ranges covered by `match_ranges`.
+    fn highlight_line_ranges(
+        line: Line<'static>,
+        match_ranges: &[Range<usize>],
+        highlight_style: Style,
+    ) -> Line<'static> {
+        let Line {
+            alignment,
+            spans,
+            style,
+        } = line;
+        let mut highlighted_spans = Vec::with_capacity(spans.len());
+        let mut span_start = 0;
+
+        for span in spans {
+            let span_end = span_start + span.content.len();
+            let mut boundaries = vec![0, span.content.len()];
+            for match_range in match_ranges {
+                for boundary in [match_range.start, match_range.end] {
+                    if boundary > span_start && boundary < span_end {
+                        boundaries.push(boundary - span_start);
+                    }
+                }
+            }
+            boundaries.sort_unstable();
+            boundaries.dedup();
+
+            for segment in boundaries.windows(2) {
+                let (segment_start, segment_end) = (segment[0], segment[1]);
+                let is_match = match_ranges.iter().any(|match_range| {
+                    match_range.start <= span_start + segment_start
+                        && span_start + segment_end <= match_range.end
+                });
+                let segment_style = if is_match {
+                    span.style.patch(highlight_style)
+                } else {
+                    span.style
+                };
+
+                highlighted_spans.push(Span::styled(
+                    span.content[segment_start..segment_end].to_string(),
+                    segment_style,
+                ));
+            }
+
+            span_start = span_end;
+        }
+
+        Line {
+            alignment,
+            spans: highlighted_spans,
+            style,
+        }
+    }
+
+    /// Returns the style applied to transcript search matches.
+    fn search_match_style() -> Style {
+        Style::default()
+            .fg(style::palette::surface_overlay())
+            .bg(style::palette::warning_soft())
+    }
+
+    /// Returns the style applied to the focused transcript search match.
+    fn focused_search_match_style() -> Style {
+        Style::default()
+            .fg(style::palette::surface_overlay())
+            .bg(style::palette::warning())
+            .add_modifier(Modifier::BOLD)
+    }
+
     /// Builds rendered markdown lines and contextual status/help rows for the
     /// current session state.
     ///
@@ -167,7 +282,6 @@ impl<'a> SessionOutput<'a> {
         markdown_render_cache: Option<&markdown::MarkdownRenderCache>,
     ) -> Vec<Line<'static>> {
         let SessionOutputLineContext {
-            active_prompt_output,
             active_progress,
             done_session_output_mode,
             review_status_message,
@@ -176,7 +290,7 @@ impl<'a> SessionOutput<'a> {
         let status = session.status;
         let output_text = Self::output_text(session, done_session_output_mode);
         let (completed_turn_text, active_turn_text) =
-            Self::transcript_sections(status, &output_text, active_prompt_output);
+            Self::transcript_sections(session, &output_text);
         let completed_turn_text = Self::output_text_with_spaced_user_input(completed_turn_text);
         let active_turn_text = active_turn_text.map(Self::output_text_with_spaced_user_input);
         let inner_width = panel_inner_width(output_area, Self::output_panel_borders());
@@ -212,6 +326,7 @@ impl<'a> SessionOutput<'a> {
         if Self::shows_review_lines(session.status) {
             Self::append_review_lines(&mut lines, review_text, inner_width, markdown_render_cache);
         }
+        Self::append_plan_lines(&mut lines, session);
         Self::append_published_branch_sync_lines(&mut lines, session);
 
         if matches!(
@@ -246,6 +361,61 @@ impl<'a> SessionOutput<'a> {
         lines
     }
 
+    /// Appends the plan-first checklist when the session has a plan.
+    ///
+    /// The header shows the approval stage, and approved plans also show how
+    /// many steps the agent reported as completed.
+    fn append_plan_lines(lines: &mut Vec<Line<'static>>, session: &Session) {
+        let Some(plan) = &session.plan else {
+            return;
+        };
+
+        while lines.last().is_some_and(|line| line.width() == 0) {
+            lines.pop();
+        }
+
+        let mut header = format!("Plan · {}", plan.status.label());
+        if plan.status == PlanStatus::Approved {
+            let _ = write!(
+                header,
+                " ({}/{} done)",
+                plan.completed_step_count(),
+                plan.steps.len()
+            );
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            header,
+            Style::default()
+                .fg(style::palette::warning())
+                .add_modifier(Modifier::BOLD),
+        )));
+
+        if plan.steps.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "  Waiting for the agent to propose a plan.",
+                Style::default().fg(style::palette::text_muted()),
+            )));
+
+            return;
+        }
+
+        for (step_index, step) in plan.steps.iter().enumerate() {
+            let marker = if step.is_done { "[x]" } else { "[ ]" };
+            let mut spans = vec![Span::styled(
+                format!("  {marker} {}. {}", step_index + 1, step.text),
+                Style::default().fg(style::palette::text()),
+            )];
+            if !step.files.is_empty() {
+                spans.push(Span::styled(
+                    format!(" ({})", step.files.join(", ")),
+                    Style::default().fg(style::palette::text_muted()),
+                ));
+            }
+            lines.push(Line::from(spans));
+        }
+    }
+
     /// Appends one automatic published-branch sync status row when the latest
     /// completed turn started, finished, or failed an auto-push.
     fn append_published_branch_sync_lines(lines: &mut Vec<Line<'static>>, session: &Session) {
@@ -269,26 +439,23 @@ impl<'a> SessionOutput<'a> {
         )]));
     }
 
-    /// Splits the transcript at the exact active-turn prompt block captured
-    /// when the current turn was submitted.
+    /// Splits the transcript at the prompt block of the latest persisted turn
+    /// while that turn is still running.
     fn transcript_sections<'text>(
-        status: Status,
+        session: &Session,
         output_text: &'text str,
-        active_prompt_output: Option<&str>,
     ) -> (&'text str, Option<&'text str>) {
-        if !matches!(
-            status,
-            Status::InProgress | Status::Queued | Status::Rebasing | Status::Merging
-        ) {
+        if !matches!(session.status, Status::InProgress | Status::Queued) {
             return (output_text, None);
         }
-        let Some(active_prompt_output) = active_prompt_output else {
-            return (output_text, None);
-        };
-        let Some(active_prompt_start) = output_text.rfind(active_prompt_output) else {
+        let Some(active_prompt_start) = session.turns.last().map(|turn| turn.transcript_start)
+        else {
             return (output_text, None);
         };
-        if active_prompt_start == 0 {
+        if active_prompt_start == 0
+            || active_prompt_start >= output_text.len()
+            || !output_text.is_char_boundary(active_prompt_start)
+        {
             return (output_text, None);
         }
 
@@ -320,7 +487,7 @@ impl<'a> SessionOutput<'a> {
 
         Line::from(vec![Span::styled(
             format!("Press t to switch to {toggle_target}."),
-            Style::default().fg(style::palette::TEXT_SUBTLE),
+            Style::default().fg(style::palette::text_subtle()),
         )])
     }
 
@@ -765,11 +932,11 @@ impl<'a> SessionOutput<'a> {
         sync_status: PublishedBranchSyncStatus,
     ) -> ratatui::style::Color {
         match sync_status {
-            PublishedBranchSyncStatus::Idle => style::palette::TEXT_MUTED,
+            PublishedBranchSyncStatus::Idle => style::palette::text_muted(),
             PublishedBranchSyncStatus::InProgress | PublishedBranchSyncStatus::Failed => {
-                style::palette::WARNING
+                style::palette::warning()
             }
-            PublishedBranchSyncStatus::Succeeded => style::palette::SUCCESS,
+            PublishedBranchSyncStatus::Succeeded => style::palette::success(),
         }
     }
 }
@@ -785,7 +952,6 @@ impl Component for SessionOutput<'_> {
             self.session,
             output_area,
             SessionOutputLineContext {
-                active_prompt_output: self.active_prompt_output,
                 active_progress: self.active_progress,
                 done_session_output_mode: self.done_session_output_mode,
                 review_status_message: self.review_status_message,
@@ -793,6 +959,12 @@ impl Component for SessionOutput<'_> {
             },
             self.markdown_render_cache,
         );
+        let lines = match self.search_highlight {
+            Some((query, focused_line)) if !query.is_empty() => {
+                Self::highlight_search_matches(lines, query, focused_line)
+            }
+            _ => lines,
+        };
         let final_scroll = bottom_pinned_scroll_offset(
             output_area,
             Self::output_panel_borders(),
@@ -814,10 +986,11 @@ impl Component for SessionOutput<'_> {
 
 #[cfg(test)]
 mod tests {
-    use ratatui::style::Modifier;
     use serde_json;
 
     use super::*;
+    use crate::domain::plan::{PlanStep, SessionPlan};
+    use crate::domain::session::SessionTurn;
     use crate::infra::agent::protocol::AgentResponseSummary;
 
     /// Builds one output-line context with defaults suitable for tests.
@@ -828,7 +1001,6 @@ mod tests {
         active_progress: Option<&'a str>,
     ) -> SessionOutputLineContext<'a> {
         SessionOutputLineContext {
-            active_prompt_output: None,
             active_progress,
             done_session_output_mode,
             review_status_message,
@@ -854,6 +1026,20 @@ mod tests {
             .build()
     }
 
+    /// Returns one active prompt turn starting at `prompt_block` in `output`.
+    fn active_turn_fixture(output: &str, prompt_block: &str) -> Vec<SessionTurn> {
+        let transcript_start = output
+            .find(prompt_block)
+            .expect("prompt block should exist in output");
+
+        vec![SessionTurn {
+            number: 1,
+            prompt: prompt_block.trim_start_matches('\n').to_string(),
+            skills: Vec::new(),
+            transcript_start,
+        }]
+    }
+
     fn buffer_text(buffer: &ratatui::buffer::Buffer) -> String {
         buffer
             .content()
@@ -905,6 +1091,37 @@ mod tests {
         assert!(text.contains("Auto-pushing published branch after completed turn..."));
     }
 
+    #[test]
+    fn test_output_lines_show_plan_checklist_with_progress() {
+        // Arrange
+        let mut session = session_fixture();
+        let mut plan = SessionPlan::approved(vec![
+            PlanStep::new("Add the column", vec!["migrations/001.sql".to_string()]),
+            PlanStep::new("Update docs", Vec::new()),
+        ]);
+        plan.complete_steps(&[1]);
+        session.plan = Some(plan);
+        session.status = Status::Review;
+
+        // Act
+        let lines = SessionOutput::output_lines(
+            &session,
+            Rect::new(0, 0, 80, 5),
+            line_context(DoneSessionOutputMode::Summary, None, None, None),
+            None,
+        );
+        let text = lines
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        // Assert
+        assert!(text.contains("Plan · Approved (1/2 done)"));
+        assert!(text.contains("  [x] 1. Add the column (migrations/001.sql)"));
+        assert!(text.contains("  [ ] 2. Update docs"));
+    }
+
     #[test]
     fn test_output_lines_show_completed_published_branch_sync_message() {
         // Arrange
@@ -1069,6 +1286,7 @@ mod tests {
         let mut session = session_fixture();
         session.output =
             " › hi\n\n[Commit] No changes to commit.\n\n › add hello world\n\n".to_string();
+        session.turns = active_turn_fixture(&session.output, "\n › add hello world");
         session.summary = Some(summary_fixture());
         session.status = Status::InProgress;
 
@@ -1076,10 +1294,7 @@ mod tests {
         let lines = SessionOutput::output_lines(
             &session,
             Rect::new(0, 0, 80, 8),
-            SessionOutputLineContext {
-                active_prompt_output: Some("\n › add hello world\n\n"),
-                ..line_context(DoneSessionOutputMode::Summary, None, None, None)
-            },
+            line_context(DoneSessionOutputMode::Summary, None, None, None),
             None,
         );
         let text = lines
@@ -1107,6 +1322,7 @@ mod tests {
         // Arrange
         let mut session = session_fixture();
         session.output = " › add hello world\n\nI added the README change.\n".to_string();
+        session.turns = active_turn_fixture(&session.output, " › add hello world");
         session.summary = Some(summary_fixture());
         session.status = Status::InProgress;
 
@@ -1114,10 +1330,7 @@ mod tests {
         let lines = SessionOutput::output_lines(
             &session,
             Rect::new(0, 0, 80, 8),
-            SessionOutputLineContext {
-                active_prompt_output: Some(" › add hello world\n\n"),
-                ..line_context(DoneSessionOutputMode::Summary, None, None, None)
-            },
+            line_context(DoneSessionOutputMode::Summary, None, None, None),
             None,
         );
         let text = lines
@@ -1144,6 +1357,7 @@ mod tests {
         session.output = " › hi\n\nprevious answer\n\n › actual prompt\n\nstreaming answer\n › \
                           quoted output\n"
             .to_string();
+        session.turns = active_turn_fixture(&session.output, "\n › actual prompt");
         session.summary = Some(summary_fixture());
         session.status = Status::InProgress;
 
@@ -1151,10 +1365,7 @@ mod tests {
         let lines = SessionOutput::output_lines(
             &session,
             Rect::new(0, 0, 80, 8),
-            SessionOutputLineContext {
-                active_prompt_output: Some("\n › actual prompt\n\n"),
-                ..line_context(DoneSessionOutputMode::Summary, None, None, None)
-            },
+            line_context(DoneSessionOutputMode::Summary, None, None, None),
             None,
         );
         let text = lines
@@ -1533,7 +1744,10 @@ mod tests {
                 SessionOutput::output_panel_borders()
             )
         );
-        assert_eq!(prompt_line.spans[0].style.fg, Some(style::palette::ACCENT));
+        assert_eq!(
+            prompt_line.spans[0].style.fg,
+            Some(style::palette::accent())
+        );
         assert!(
             prompt_line.spans[0]
                 .style
@@ -0,0 +1,350 @@
+use std::ops::Range;
+
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Position, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Clear, Paragraph};
+use unicode_width::UnicodeWidthStr;
+
+use crate::domain::input::InputState;
+use crate::domain::session::SessionSearchHit;
+use crate::ui::style::palette;
+use crate::ui::text_util::truncate_with_ellipsis;
+use crate::ui::{Component, overlay};
+
+const MIN_OVERLAY_HEIGHT: u16 = 12;
+const MIN_OVERLAY_WIDTH: u16 = 60;
+const OVERLAY_HEIGHT_PERCENT: u16 = 70;
+const OVERLAY_WIDTH_PERCENT: u16 = 80;
+
+/// Rows reserved around the hit list for the query, spacing, and help hint.
+const FIXED_ROW_COUNT: usize = 4;
+
+/// Rows rendered for each hit: heading and snippet.
+const HIT_ROW_COUNT: usize = 2;
+
+/// Prompt marker rendered before the query text.
+const QUERY_PREFIX: &str = "/ ";
+
+/// Centered popup that searches sessions across all projects and opens the
+/// selected hit.
+pub struct SessionSearchOverlay<'a> {
+    hits: &'a [SessionSearchHit],
+    input: &'a InputState,
+    selected_hit_index: usize,
+}
+
+impl<'a> SessionSearchOverlay<'a> {
+    /// Creates a session search popup from the current query and hits.
+    pub fn new(input: &'a InputState, hits: &'a [SessionSearchHit]) -> Self {
+        Self {
+            hits,
+            input,
+            selected_hit_index: 0,
+        }
+    }
+
+    /// Sets which hit is currently highlighted.
+    #[must_use]
+    pub fn selected_hit_index(mut self, selected_hit_index: usize) -> Self {
+        self.selected_hit_index = selected_hit_index;
+        self
+    }
+
+    /// Returns all render lines for this popup.
+    ///
+    /// Only the hits that fit in `row_count` rows are rendered, scrolled so
+    /// the selected hit stays visible.
+    fn lines(&self, row_width: usize, row_count: usize) -> Vec<Line<'static>> {
+        let mut lines = vec![self.query_line(), Line::from("")];
+
+        if self.input.text().trim().is_empty() {
+            lines.push(status_line(
+                "Type to search titles, prompts, summaries, and transcripts.",
+            ));
+        } else if self.hits.is_empty() {
+            lines.push(status_line("No sessions match this search."));
+        } else {
+            let hit_capacity = row_count.saturating_sub(FIXED_ROW_COUNT) / HIT_ROW_COUNT;
+            for hit_index in
+                visible_hit_range(self.hits.len(), self.selected_hit_index, hit_capacity)
+            {
+                let hit = &self.hits[hit_index];
+                let is_selected = hit_index == self.selected_hit_index;
+                lines.push(hit_heading_line(hit, is_selected, row_width));
+                lines.push(hit_snippet_line(hit, row_width));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(
+            Line::from(Span::styled(
+                self.help_text(),
+                Style::default().fg(palette::text_muted()),
+            ))
+            .alignment(Alignment::Center),
+        );
+
+        lines
+    }
+
+    /// Builds the editable query row.
+    fn query_line(&self) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(QUERY_PREFIX, Style::default().fg(palette::accent())),
+            Span::styled(
+                self.input.text().to_string(),
+                Style::default().fg(palette::text()),
+            ),
+        ])
+    }
+
+    /// Returns the bottom help hint for the current result state.
+    fn help_text(&self) -> &'static str {
+        if self.hits.is_empty() {
+            return "Esc: close";
+        }
+
+        "Up/Down: move | Enter: open | Esc: close"
+    }
+
+    /// Returns the centered popup rectangle constrained to terminal bounds.
+    fn popup_area(area: Rect) -> Rect {
+        overlay::centered_popup_area(
+            area,
+            OVERLAY_WIDTH_PERCENT,
+            OVERLAY_HEIGHT_PERCENT,
+            MIN_OVERLAY_WIDTH,
+            MIN_OVERLAY_HEIGHT,
+        )
+    }
+}
+
+impl Component for SessionSearchOverlay<'_> {
+    fn render(&self, f: &mut Frame, area: Rect) {
+        let popup_area = Self::popup_area(area);
+        let block = overlay::overlay_block("Search Sessions", palette::accent());
+        let inner_area = block.inner(popup_area);
+        let row_width = usize::from(inner_area.width).max(1);
+        let lines = self.lines(row_width, usize::from(inner_area.height));
+
+        f.render_widget(Clear, popup_area);
+        f.render_widget(Paragraph::new(lines).block(block), popup_area);
+
+        let cursor_offset = QUERY_PREFIX.width()
+            + self
+                .input
+                .text()
+                .chars()
+                .take(self.input.cursor)
+                .collect::<String>()
+                .width();
+        f.set_cursor_position(Position::new(
+            inner_area
+                .x
+                .saturating_add(u16::try_from(cursor_offset).unwrap_or(u16::MAX))
+                .min(inner_area.right().saturating_sub(1)),
+            inner_area.y,
+        ));
+    }
+}
+
+/// Returns the hit indices visible in a list of `hit_capacity` hits that keeps
+/// `selected_hit_index` on screen.
+fn visible_hit_range(
+    hit_count: usize,
+    selected_hit_index: usize,
+    hit_capacity: usize,
+) -> Range<usize> {
+    let hit_capacity = hit_capacity.max(1);
+    let first_hit_index = (selected_hit_index + 1).saturating_sub(hit_capacity);
+
+    first_hit_index..(first_hit_index + hit_capacity).min(hit_count)
+}
+
+/// Builds one centered status line for empty result states.
+fn status_line(text: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        text.to_string(),
+        Style::default().fg(palette::text_muted()),
+    ))
+    .alignment(Alignment::Center)
+}
+
+/// Builds the hit heading row with title, project, and matched field,
+/// emphasizing the selected hit by background color.
+fn hit_heading_line(hit: &SessionSearchHit, is_selected: bool, row_width: usize) -> Line<'static> {
+    let location = match hit.turn_number {
+        Some(turn_number) if turn_number > 0 => {
+            format!(
+                "{} · {} · turn {turn_number}",
+                hit.project_name,
+                hit.source.label()
+            )
+        }
+        _ => format!("{} · {}", hit.project_name, hit.source.label()),
+    };
+    let title_width = row_width.saturating_sub(location.width() + 3).max(1);
+    let title = truncate_with_ellipsis(&hit.title.replace('\n', " "), title_width);
+
+    if is_selected {
+        let heading = format!(" {title}  {location}");
+
+        return Line::from(Span::styled(
+            format!("{heading:<row_width$}"),
+            Style::default()
+                .fg(palette::surface_overlay())
+                .bg(palette::accent())
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+
+    Line::from(vec![
+        Span::styled(
+            format!(" {title}"),
+            Style::default()
+                .fg(palette::text())
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {location}"),
+            Style::default().fg(palette::text_subtle()),
+        ),
+    ])
+}
+
+/// Builds the indented snippet row with matched terms highlighted.
+fn hit_snippet_line(hit: &SessionSearchHit, row_width: usize) -> Line<'static> {
+    let snippet_width = row_width.saturating_sub(3);
+    let mut spans = vec![Span::raw("   ")];
+    let mut used_width = 0;
+    let mut byte_offset = 0;
+
+    let mut push_segment = |text: &str, style: Style, spans: &mut Vec<Span<'static>>| {
+        let remaining_width = snippet_width.saturating_sub(used_width);
+        if remaining_width == 0 || text.is_empty() {
+            return;
+        }
+        let segment = truncate_with_ellipsis(text, remaining_width);
+        used_width += segment.width();
+        spans.push(Span::styled(segment, style));
+    };
+    let snippet_style = Style::default().fg(palette::text_muted());
+    let match_style = Style::default()
+        .fg(palette::warning())
+        .add_modifier(Modifier::BOLD);
+
+    for match_range in &hit.snippet_match_ranges {
+        let (Some(before_match), Some(matched_text)) = (
+            hit.snippet.get(byte_offset..match_range.start),
+            hit.snippet.get(match_range.clone()),
+        ) else {
+            continue;
+        };
+        push_segment(before_match, snippet_style, &mut spans);
+        push_segment(matched_text, match_style, &mut spans);
+        byte_offset = match_range.end;
+    }
+    if let Some(rest) = hit.snippet.get(byte_offset..) {
+        push_segment(rest, snippet_style, &mut spans);
+    }
+
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::session::{SessionId, SessionSearchSource};
+
+    /// Builds one transcript hit whose snippet highlights `flaky`.
+    fn search_hit(title: &str) -> SessionSearchHit {
+        SessionSearchHit {
+            project_id: Some(1),
+            project_name: "agentty".to_string(),
+            session_id: SessionId::from("session-a"),
+            snippet: "…fixed the flaky sqlx test…".to_string(),
+            snippet_match_ranges: vec![13..18, 19..23],
+            source: SessionSearchSource::Transcript,
+            title: title.to_string(),
+            turn_number: Some(3),
+        }
+    }
+
+    /// Concatenates the span text of one rendered line.
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn test_session_search_overlay_lines_show_hit_location_and_highlight() {
+        // Arrange
+        let input = InputState::with_text("flaky".to_string());
+        let hits = vec![search_hit("Stabilize tests")];
+        let overlay = SessionSearchOverlay::new(&input, &hits);
+
+        // Act
+        let lines = overlay.lines(80, 20);
+
+        // Assert
+        assert_eq!(line_text(&lines[0]), "/ flaky");
+        assert!(line_text(&lines[2]).contains("Stabilize tests  agentty · transcript · turn 3"));
+        assert_eq!(lines[2].spans[0].style.bg, Some(palette::accent()));
+        let highlighted_span = lines[3]
+            .spans
+            .iter()
+            .find(|span| span.style.fg == Some(palette::warning()))
+            .expect("matched term should be highlighted");
+        assert_eq!(highlighted_span.content, "flaky");
+    }
+
+    #[test]
+    fn test_session_search_overlay_lines_scroll_to_selected_hit() {
+        // Arrange
+        let input = InputState::with_text("test".to_string());
+        let hits = (0..6)
+            .map(|index| search_hit(&format!("Session {index}")))
+            .collect::<Vec<_>>();
+        let overlay = SessionSearchOverlay::new(&input, &hits).selected_hit_index(5);
+
+        // Act
+        let lines = overlay.lines(80, 8);
+
+        // Assert
+        let rendered_text = lines.iter().map(line_text).collect::<Vec<_>>().join("\n");
+        assert!(rendered_text.contains("Session 4"));
+        assert!(rendered_text.contains("Session 5"));
+        assert!(!rendered_text.contains("Session 3"));
+    }
+
+    #[test]
+    fn test_session_search_overlay_render_shows_empty_result_state() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 40);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let input = InputState::with_text("missing".to_string());
+        let overlay = SessionSearchOverlay::new(&input, &[]);
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                overlay.render(frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let buffer = terminal.backend().buffer();
+        let text: String = buffer
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect();
+        assert!(text.contains("No sessions match this search."));
+        assert!(text.contains("Esc: close"));
+    }
+}
@@ -8,8 +8,10 @@ use crate::app::UpdateStatus;
 use crate::ui::page::fyi;
 use crate::ui::{Component, style};
 
-/// Top status bar showing current version, update progress, and availability.
+/// Top status bar showing current version, update progress, availability,
+/// and a disabled control API.
 pub struct StatusBar<'a> {
+    control_api_error: Option<&'a str>,
     current_version: String,
     latest_available_version: Option<String>,
     page_fyis: Option<&'a [&'static str]>,
@@ -21,6 +23,7 @@ impl<'a> StatusBar<'a> {
     /// Creates a status bar with the current version.
     pub fn new(current_version: String) -> Self {
         Self {
+            control_api_error: None,
             current_version,
             latest_available_version: None,
             page_fyis: None,
@@ -52,6 +55,13 @@ impl<'a> StatusBar<'a> {
         self
     }
 
+    /// Sets the reason the control socket failed to start, when it did.
+    #[must_use]
+    pub fn control_api_error(mut self, control_api_error: Option<&'a str>) -> Self {
+        self.control_api_error = control_api_error;
+        self
+    }
+
     /// Sets the background auto-update progress state.
     #[must_use]
     pub fn update_status(mut self, update_status: Option<UpdateStatus>) -> Self {
@@ -65,12 +75,13 @@ impl<'a> StatusBar<'a> {
     /// to the manual update hint.
     fn update_progress_text(&self) -> Option<(String, ratatui::style::Color)> {
         match &self.update_status {
-            Some(UpdateStatus::InProgress { version }) => {
-                Some((format!("Updating to {version}..."), style::palette::ACCENT))
-            }
+            Some(UpdateStatus::InProgress { version }) => Some((
+                format!("Updating to {version}..."),
+                style::palette::accent(),
+            )),
             Some(UpdateStatus::Complete { version }) => Some((
                 format!("Updated to {version} — restart to use new version"),
-                style::palette::SUCCESS,
+                style::palette::success(),
             )),
             Some(UpdateStatus::Failed { .. }) | None => None,
         }
@@ -89,7 +100,7 @@ impl Component for StatusBar<'_> {
         let mut version_spans = vec![Span::styled(
             format!(" Agentty {}", self.current_version),
             Style::default()
-                .fg(style::palette::ACCENT)
+                .fg(style::palette::accent())
                 .add_modifier(Modifier::BOLD),
         )];
 
@@ -107,7 +118,17 @@ impl Component for StatusBar<'_> {
                      agentty@latest"
                 ),
                 Style::default()
-                    .fg(style::palette::WARNING)
+                    .fg(style::palette::warning())
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+
+        if let Some(control_api_error) = self.control_api_error {
+            version_spans.push(Span::raw(" | "));
+            version_spans.push(Span::styled(
+                format!("Control API disabled: {control_api_error}"),
+                Style::default()
+                    .fg(style::palette::warning())
                     .add_modifier(Modifier::BOLD),
             ));
         }
@@ -116,14 +137,14 @@ impl Component for StatusBar<'_> {
             version_spans.push(Span::raw(" | "));
             version_spans.push(Span::styled(
                 format!("FYI: {page_fyi_text}"),
-                Style::default().fg(style::palette::TEXT_MUTED),
+                Style::default().fg(style::palette::text_muted()),
             ));
         }
 
         let status_bar = Paragraph::new(Line::from(version_spans)).style(
             Style::default()
-                .bg(style::palette::SURFACE)
-                .fg(style::palette::TEXT),
+                .bg(style::palette::surface())
+                .fg(style::palette::text()),
         );
         f.render_widget(status_bar, area);
     }
@@ -305,6 +326,27 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_status_bar_render_shows_disabled_control_api() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(120, 1);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let status_bar = StatusBar::new("v0.1.12".to_string())
+            .control_api_error(Some("Another agentty instance is listening"));
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                Component::render(&status_bar, frame, area);
+            })
+            .expect("failed to draw");
+
+        // Assert
+        let text = buffer_text(&terminal);
+        assert!(text.contains("Control API disabled: Another agentty instance is listening"));
+    }
+
     #[test]
     fn test_status_bar_render_shows_manual_hint_on_update_failure() {
         // Arrange
@@ -32,6 +32,33 @@ impl Tabs<'_> {
             projects,
         }
     }
+
+    /// Returns the tab whose label covers terminal `column` when the tabs are
+    /// rendered in `area`.
+    ///
+    /// Clicking the project-scope label resolves to `Tab::Projects`, where the
+    /// active project is chosen; separators resolve to `None`.
+    pub fn tab_at_column(&self, area: Rect, column: u16) -> Option<Tab> {
+        let spans = tab_spans(
+            self.current_tab,
+            self.active_project_id,
+            self.has_tasks_tab,
+            self.projects,
+        );
+        let mut span_start = area.x;
+
+        for (span, target) in spans.iter().zip(tab_span_targets(self.has_tasks_tab)) {
+            let span_width = u16::try_from(span.width()).unwrap_or(u16::MAX);
+            let span_end = span_start.saturating_add(span_width);
+            if (span_start..span_end).contains(&column) {
+                return target;
+            }
+
+            span_start = span_end;
+        }
+
+        None
+    }
 }
 
 impl Component for Tabs<'_> {
@@ -45,7 +72,7 @@ impl Component for Tabs<'_> {
         let paragraph = Paragraph::new(line).block(
             Block::default()
                 .borders(Borders::BOTTOM)
-                .border_style(Style::default().fg(style::palette::BORDER))
+                .border_style(Style::default().fg(style::palette::border()))
                 .padding(Padding::top(1)),
         );
         f.render_widget(paragraph, area);
@@ -74,9 +101,22 @@ fn tab_spans(
     spans
 }
 
+/// Returns the tab targeted by each span from [`tab_spans`], in the same
+/// order.
+fn tab_span_targets(has_tasks_tab: bool) -> Vec<Option<Tab>> {
+    let mut targets = vec![Some(Tab::Projects), None, Some(Tab::Projects)];
+
+    for tab in Tab::project_scoped_tabs(has_tasks_tab) {
+        targets.push(None);
+        targets.push(Some(*tab));
+    }
+
+    targets
+}
+
 /// Returns one styled separator span between tabs.
 fn tab_separator_span() -> Span<'static> {
-    Span::styled("|", Style::default().fg(style::palette::BORDER))
+    Span::styled("|", Style::default().fg(style::palette::border()))
 }
 
 /// Returns one styled tab span with active/inactive affordance treatment.
@@ -87,13 +127,13 @@ fn tab_span(tab: Tab, current_tab: Tab) -> Span<'static> {
         return Span::styled(
             label,
             Style::default()
-                .bg(style::palette::SURFACE)
-                .fg(style::palette::WARNING)
+                .bg(style::palette::surface())
+                .fg(style::palette::warning())
                 .add_modifier(Modifier::BOLD),
         );
     }
 
-    Span::styled(label, Style::default().fg(style::palette::TEXT_MUTED))
+    Span::styled(label, Style::default().fg(style::palette::text_muted()))
 }
 
 /// Returns a styled span describing the active project for project-scoped tabs.
@@ -103,14 +143,14 @@ fn project_context_span(active_project_id: i64, projects: &[ProjectListItem]) ->
             || {
                 (
                     "None".to_string(),
-                    Style::default().fg(style::palette::TEXT_SUBTLE),
+                    Style::default().fg(style::palette::text_subtle()),
                 )
             },
             |project_name| {
                 (
                     project_name,
                     Style::default()
-                        .fg(style::palette::ACCENT_SOFT)
+                        .fg(style::palette::accent_soft())
                         .add_modifier(Modifier::BOLD),
                 )
             },
@@ -156,7 +196,7 @@ mod tests {
         // Assert
         assert_eq!(
             rendered_tabs,
-            " Projects | Project: None | Sessions | Stats | Settings "
+            " Projects | Project: None | Sessions | Dashboard | Stats | Settings "
         );
     }
 
@@ -169,13 +209,14 @@ mod tests {
         let spans = tab_spans(current_tab, 0, false, &[]);
 
         // Assert
-        assert_eq!(spans[0].style.fg, Some(style::palette::TEXT_MUTED));
-        assert_eq!(spans[2].style.fg, Some(style::palette::TEXT_SUBTLE));
-        assert_eq!(spans[4].style.fg, Some(style::palette::TEXT_MUTED));
-        assert_eq!(spans[6].style.fg, Some(style::palette::WARNING));
-        assert_eq!(spans[6].style.bg, Some(style::palette::SURFACE));
-        assert_eq!(spans[8].style.fg, Some(style::palette::TEXT_MUTED));
-        assert!(spans[6].style.add_modifier.contains(Modifier::BOLD));
+        assert_eq!(spans[0].style.fg, Some(style::palette::text_muted()));
+        assert_eq!(spans[2].style.fg, Some(style::palette::text_subtle()));
+        assert_eq!(spans[4].style.fg, Some(style::palette::text_muted()));
+        assert_eq!(spans[6].style.fg, Some(style::palette::text_muted()));
+        assert_eq!(spans[8].style.fg, Some(style::palette::warning()));
+        assert_eq!(spans[8].style.bg, Some(style::palette::surface()));
+        assert_eq!(spans[10].style.fg, Some(style::palette::text_muted()));
+        assert!(spans[8].style.add_modifier.contains(Modifier::BOLD));
     }
 
     #[test]
@@ -198,12 +239,12 @@ mod tests {
         // Assert
         assert_eq!(
             rendered_tabs,
-            " Projects | Project: Primary | Sessions | Stats | Settings "
+            " Projects | Project: Primary | Sessions | Dashboard | Stats | Settings "
         );
-        assert_eq!(spans[2].style.fg, Some(style::palette::ACCENT_SOFT));
+        assert_eq!(spans[2].style.fg, Some(style::palette::accent_soft()));
         assert!(spans[2].style.add_modifier.contains(Modifier::BOLD));
-        assert_eq!(spans[4].style.fg, Some(style::palette::WARNING));
-        assert_eq!(spans[4].style.bg, Some(style::palette::SURFACE));
+        assert_eq!(spans[4].style.fg, Some(style::palette::warning()));
+        assert_eq!(spans[4].style.bg, Some(style::palette::surface()));
     }
 
     #[test]
@@ -219,10 +260,10 @@ mod tests {
         assert_eq!(spans[3].content.as_ref(), "|");
         assert_eq!(spans[5].content.as_ref(), "|");
         assert_eq!(spans[7].content.as_ref(), "|");
-        assert_eq!(spans[1].style.fg, Some(style::palette::BORDER));
-        assert_eq!(spans[3].style.fg, Some(style::palette::BORDER));
-        assert_eq!(spans[5].style.fg, Some(style::palette::BORDER));
-        assert_eq!(spans[7].style.fg, Some(style::palette::BORDER));
+        assert_eq!(spans[1].style.fg, Some(style::palette::border()));
+        assert_eq!(spans[3].style.fg, Some(style::palette::border()));
+        assert_eq!(spans[5].style.fg, Some(style::palette::border()));
+        assert_eq!(spans[7].style.fg, Some(style::palette::border()));
     }
 
     #[test]
@@ -235,7 +276,7 @@ mod tests {
 
         // Assert
         assert_eq!(spans[2].content.as_ref(), " Project: None ");
-        assert_eq!(spans[2].style.fg, Some(style::palette::TEXT_SUBTLE));
+        assert_eq!(spans[2].style.fg, Some(style::palette::text_subtle()));
     }
 
     #[test]
@@ -254,10 +295,37 @@ mod tests {
         // Assert
         assert_eq!(
             rendered_tabs,
-            " Projects | Project: None | Sessions | Tasks | Stats | Settings "
+            " Projects | Project: None | Sessions | Dashboard | Tasks | Stats | Settings "
         );
-        assert_eq!(spans[6].style.fg, Some(style::palette::WARNING));
-        assert_eq!(spans[6].style.bg, Some(style::palette::SURFACE));
+        assert_eq!(spans[8].style.fg, Some(style::palette::warning()));
+        assert_eq!(spans[8].style.bg, Some(style::palette::surface()));
+    }
+
+    #[test]
+    fn test_tab_at_column_resolves_labels_and_skips_separators() {
+        // Arrange
+        let tabs = Tabs::new(Tab::Sessions, 0, true, &[]);
+        let area = Rect::new(2, 0, 80, 3);
+        // Rendered row: " Projects | Project: None | Sessions | Dashboard | Tasks |
+        // Stats | Settings "
+
+        // Act
+        let projects_tab = tabs.tab_at_column(area, 3);
+        let separator = tabs.tab_at_column(area, 12);
+        let project_scope = tabs.tab_at_column(area, 16);
+        let dashboard_tab = tabs.tab_at_column(area, 44);
+        let tasks_tab = tabs.tab_at_column(area, 54);
+        let settings_tab = tabs.tab_at_column(area, 72);
+        let past_last_tab = tabs.tab_at_column(area, 80);
+
+        // Assert
+        assert_eq!(projects_tab, Some(Tab::Projects));
+        assert_eq!(separator, None);
+        assert_eq!(project_scope, Some(Tab::Projects));
+        assert_eq!(dashboard_tab, Some(Tab::Dashboard));
+        assert_eq!(tasks_tab, Some(Tab::Tasks));
+        assert_eq!(settings_tab, Some(Tab::Settings));
+        assert_eq!(past_last_tab, None);
     }
 
     /// Creates a `ProjectListItem` for tab-label rendering tests.
@@ -672,7 +672,7 @@ fn review_fallback_highlight(
 }
 
 /// Parses the destination file path from a `diff --git` header line.
-fn parse_diff_file_path(line: &str) -> Option<String> {
+pub fn parse_diff_file_path(line: &str) -> Option<String> {
     let suffix = line.strip_prefix("diff --git a/")?;
     let (_, rhs) = suffix.split_once(" b/")?;
 
@@ -0,0 +1,325 @@
+//! Language-aware syntax highlighting for fenced code blocks and diff content.
+//!
+//! Grammars come from the bundled `syntect` syntax set, while colors are
+//! resolved from scope names onto the shared [`palette`] tokens so highlighted
+//! code follows the rest of the terminal UI instead of an editor theme.
+
+use std::ops::Range;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use ratatui::style::{Color, Modifier, Style};
+use syntect::easy::ScopeRangeIterator;
+use syntect::parsing::{ParseState, Scope, ScopeStack, SyntaxReference, SyntaxSet};
+
+use crate::ui::style::palette;
+
+/// Scope prefix rendered in italics on top of its palette color.
+const COMMENT_SCOPE: &str = "comment";
+
+/// Fence and extension aliases for languages without a bundled grammar that
+/// read well with a close relative.
+const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("console", "sh"),
+    ("cts", "js"),
+    ("jsonc", "json"),
+    ("jsx", "js"),
+    ("mts", "js"),
+    ("shell", "sh"),
+    ("ts", "js"),
+    ("tsx", "js"),
+    ("typescript", "js"),
+    ("zsh", "sh"),
+];
+
+/// Palette token lookup resolved when a scope is styled.
+type PaletteToken = fn() -> Color;
+
+/// Scope prefixes mapped to palette colors, ordered from most to least
+/// specific so nested scopes resolve to the closest meaningful token.
+const SCOPE_COLORS: &[(&str, PaletteToken)] = &[
+    (COMMENT_SCOPE, palette::text_subtle),
+    ("string", palette::success),
+    ("constant.character.escape", palette::warning_soft),
+    ("constant", palette::warning),
+    ("entity.name.function", palette::info),
+    ("support.function", palette::info),
+    ("variable.function", palette::info),
+    ("entity.name.tag", palette::danger_soft),
+    ("entity.other.attribute-name", palette::warning_soft),
+    ("entity.name", palette::accent_soft),
+    ("support.type", palette::accent_soft),
+    ("support.class", palette::accent_soft),
+    ("storage.type", palette::accent),
+    ("storage", palette::question),
+    ("keyword.operator", palette::text_muted),
+    ("keyword", palette::question),
+    ("meta.attribute", palette::warning_soft),
+    ("meta.annotation", palette::warning_soft),
+];
+
+/// Incremental highlighter for one code block or one diff file.
+///
+/// Lines must be fed in document order because grammar state, such as an
+/// open block comment or string, carries over from one line to the next.
+#[derive(Clone)]
+pub struct CodeHighlighter {
+    parse_state: ParseState,
+    scope_stack: ScopeStack,
+}
+
+impl CodeHighlighter {
+    /// Returns a highlighter for one fenced-code info string such as
+    /// `rust`, `ts title="a.ts"`, or `{.python}`.
+    ///
+    /// Returns `None` when the info string is empty or names a language
+    /// without a bundled grammar.
+    pub fn for_fence_info(info: &str) -> Option<Self> {
+        let language = info
+            .split(|character: char| character.is_whitespace() || character == ',')
+            .next()?
+            .trim_matches(|character| matches!(character, '{' | '}' | '.'));
+
+        Self::for_syntax(find_syntax(language)?)
+    }
+
+    /// Returns a highlighter for one file path, resolved by extension first
+    /// and then by file name for extension-less files like `Makefile`.
+    pub fn for_path(path: &str) -> Option<Self> {
+        let path = Path::new(path);
+        let syntax = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .and_then(find_syntax)
+            .or_else(|| {
+                path.file_name()
+                    .and_then(|file_name| file_name.to_str())
+                    .and_then(find_syntax)
+            })?;
+
+        Self::for_syntax(syntax)
+    }
+
+    /// Highlights one line and returns byte ranges paired with styles
+    /// layered on top of `base_style`.
+    ///
+    /// Ranges are contiguous and cover the whole line. When the grammar fails
+    /// to parse, the line falls back to one unstyled range.
+    pub fn highlight_line(&mut self, line: &str, base_style: Style) -> Vec<(Range<usize>, Style)> {
+        let Ok(operations) = self.parse_state.parse_line(line, syntax_set()) else {
+            return vec![(0..line.len(), base_style)];
+        };
+
+        let mut segments: Vec<(Range<usize>, Style)> = Vec::new();
+        for (range, operation) in ScopeRangeIterator::new(&operations, line) {
+            // Best-effort: a malformed scope operation only loses coloring.
+            let _ = self.scope_stack.apply(operation);
+            if range.is_empty() {
+                continue;
+            }
+
+            let style = scope_style(&self.scope_stack, base_style);
+            match segments.last_mut() {
+                Some((last_range, last_style))
+                    if *last_style == style && last_range.end == range.start =>
+                {
+                    last_range.end = range.end;
+                }
+                _ => segments.push((range, style)),
+            }
+        }
+
+        if segments.is_empty() {
+            segments.push((0..line.len(), base_style));
+        }
+
+        segments
+    }
+
+    /// Builds a highlighter for one resolved syntax, skipping plain text so
+    /// callers keep their flat fallback style.
+    fn for_syntax(syntax: &SyntaxReference) -> Option<Self> {
+        if syntax.name == syntax_set().find_syntax_plain_text().name {
+            return None;
+        }
+
+        Some(Self {
+            parse_state: ParseState::new(syntax),
+            scope_stack: ScopeStack::new(),
+        })
+    }
+}
+
+/// Returns the lazily loaded bundled syntax set.
+fn syntax_set() -> &'static SyntaxSet {
+    static SYNTAX_SET: OnceLock<SyntaxSet> = OnceLock::new();
+
+    SYNTAX_SET.get_or_init(SyntaxSet::load_defaults_nonewlines)
+}
+
+/// Returns parsed scope selectors for [`SCOPE_COLORS`] with their palette
+/// token lookups and resolved text modifiers.
+///
+/// Colors stay as lookups so cached selectors follow the active theme.
+fn scope_styles() -> &'static [(Scope, PaletteToken, Modifier)] {
+    static SCOPES: OnceLock<Vec<(Scope, PaletteToken, Modifier)>> = OnceLock::new();
+
+    SCOPES.get_or_init(|| {
+        SCOPE_COLORS
+            .iter()
+            .filter_map(|(scope, color)| {
+                let modifier = if *scope == COMMENT_SCOPE {
+                    Modifier::ITALIC
+                } else {
+                    Modifier::empty()
+                };
+
+                Some((Scope::new(scope).ok()?, *color, modifier))
+            })
+            .collect()
+    })
+}
+
+/// Resolves one fence token or file extension to a bundled grammar.
+fn find_syntax(token: &str) -> Option<&'static SyntaxReference> {
+    if token.is_empty() {
+        return None;
+    }
+
+    let lowercase_token = token.to_ascii_lowercase();
+    let token = LANGUAGE_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == lowercase_token)
+        .map_or(token, |(_, target)| *target);
+
+    syntax_set().find_syntax_by_token(token)
+}
+
+/// Resolves the style for the innermost scope on `scope_stack` that maps to
+/// a palette token, keeping `base_style` for unmatched text.
+fn scope_style(scope_stack: &ScopeStack, base_style: Style) -> Style {
+    scope_stack
+        .as_slice()
+        .iter()
+        .rev()
+        .find_map(|scope| {
+            scope_styles()
+                .iter()
+                .find(|(selector, _, _)| selector.is_prefix_of(*scope))
+        })
+        .map_or(base_style, |(_, color, modifier)| {
+            base_style.fg(color()).add_modifier(*modifier)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the text and style for each highlighted segment.
+    fn highlighted_segments(highlighter: &mut CodeHighlighter, line: &str) -> Vec<(String, Style)> {
+        highlighter
+            .highlight_line(line, Style::default())
+            .into_iter()
+            .map(|(range, style)| (line[range].to_string(), style))
+            .collect()
+    }
+
+    #[test]
+    fn for_fence_info_resolves_language_token_with_attributes() {
+        // Arrange / Act
+        let rust_highlighter = CodeHighlighter::for_fence_info("rust,ignore");
+        let python_highlighter = CodeHighlighter::for_fence_info("{.python}");
+        let typescript_highlighter = CodeHighlighter::for_fence_info("ts title=\"main.ts\"");
+
+        // Assert
+        assert!(rust_highlighter.is_some());
+        assert!(python_highlighter.is_some());
+        assert!(typescript_highlighter.is_some());
+    }
+
+    #[test]
+    fn for_fence_info_returns_none_for_unknown_or_empty_language() {
+        // Arrange / Act
+        let unknown_highlighter = CodeHighlighter::for_fence_info("not-a-language");
+        let empty_highlighter = CodeHighlighter::for_fence_info("");
+        let plain_text_highlighter = CodeHighlighter::for_fence_info("txt");
+
+        // Assert
+        assert!(unknown_highlighter.is_none());
+        assert!(empty_highlighter.is_none());
+        assert!(plain_text_highlighter.is_none());
+    }
+
+    #[test]
+    fn for_path_resolves_extension_and_file_name() {
+        // Arrange / Act
+        let rust_highlighter = CodeHighlighter::for_path("src/main.rs");
+        let makefile_highlighter = CodeHighlighter::for_path("Makefile");
+        let unknown_highlighter = CodeHighlighter::for_path("notes.unknown");
+
+        // Assert
+        assert!(rust_highlighter.is_some());
+        assert!(makefile_highlighter.is_some());
+        assert!(unknown_highlighter.is_none());
+    }
+
+    #[test]
+    fn highlight_line_maps_scopes_to_palette_tokens() {
+        // Arrange
+        let mut highlighter = CodeHighlighter::for_fence_info("rust").expect("rust grammar");
+
+        // Act
+        let segments = highlighted_segments(&mut highlighter, "let name = \"agentty\"; // note");
+
+        // Assert
+        let joined = segments
+            .iter()
+            .map(|(text, _)| text.as_str())
+            .collect::<String>();
+        assert_eq!(joined, "let name = \"agentty\"; // note");
+        assert!(segments.contains(&("let".to_string(), Style::default().fg(palette::accent()))));
+        assert!(
+            segments
+                .iter()
+                .any(|(text, style)| text.contains("agentty")
+                    && style.fg == Some(palette::success()))
+        );
+        assert!(segments.iter().any(|(text, style)| {
+            text.contains("note")
+                && style.fg == Some(palette::text_subtle())
+                && style.add_modifier.contains(Modifier::ITALIC)
+        }));
+    }
+
+    #[test]
+    fn highlight_line_keeps_state_across_lines() {
+        // Arrange
+        let mut highlighter = CodeHighlighter::for_fence_info("rust").expect("rust grammar");
+
+        // Act
+        highlighted_segments(&mut highlighter, "/* start");
+        let segments = highlighted_segments(&mut highlighter, "still comment */");
+
+        // Assert
+        assert_eq!(segments[0].0, "still comment */");
+        assert_eq!(segments[0].1.fg, Some(palette::text_subtle()));
+    }
+
+    #[test]
+    fn highlight_line_preserves_base_style_background() {
+        // Arrange
+        let mut highlighter = CodeHighlighter::for_path("main.py").expect("python grammar");
+        let base_style = Style::default().bg(palette::surface_success());
+
+        // Act
+        let segments = highlighter.highlight_line("def run(): pass", base_style);
+
+        // Assert
+        assert!(
+            segments
+                .iter()
+                .all(|(_, style)| style.bg == Some(palette::surface_success()))
+        );
+    }
+}
@@ -0,0 +1,294 @@
+//! OSC 8 hyperlink support for rendered terminal text.
+//!
+//! Ratatui measures every buffer cell symbol with `unicode-width`, so raw
+//! escape sequences inside spans would be counted as visible columns and break
+//! layout. Link targets are carried invisibly instead: each visible character
+//! of link text is followed by a zero-width Unicode tag sequence encoding the
+//! URL. Tag characters extend the preceding grapheme, so they stay inside the
+//! same buffer cell, and [`HyperlinkBackend`] strips them at draw time and
+//! wraps the affected cells in OSC 8 sequences.
+
+use std::io::{self, Write};
+
+use ratatui::backend::{Backend, ClearType, WindowSize};
+use ratatui::buffer::Cell;
+use ratatui::layout::{Position, Size};
+
+/// Tag character that starts one encoded link target inside a cell symbol.
+const LINK_MARKER: char = '\u{E007F}';
+/// Base code point of the Unicode tag block mirroring printable ASCII.
+const TAG_BASE: u32 = 0xE_0000;
+/// URL schemes that terminals can open directly from an OSC 8 link.
+const LINKABLE_SCHEMES: &[&str] = &["file://", "http://", "https://", "mailto:"];
+
+/// Returns whether `url` uses a scheme terminals can open from a hyperlink.
+///
+/// Relative paths and anchors stay plain styled text because the terminal has
+/// no base location to resolve them against.
+pub fn is_linkable(url: &str) -> bool {
+    let lowercase_url = url.to_ascii_lowercase();
+
+    LINKABLE_SCHEMES
+        .iter()
+        .any(|scheme| lowercase_url.starts_with(scheme))
+}
+
+/// Returns `text` with every visible character tagged with the encoded `url`.
+///
+/// The returned string has the same display width as `text`.
+pub fn link_text(text: &str, url: &str) -> String {
+    let encoded_url = encode_url(url);
+    let mut linked_text = String::with_capacity(text.len() * (encoded_url.len() + 1));
+
+    for character in text.chars() {
+        linked_text.push(character);
+        if !character.is_whitespace() && !character.is_control() {
+            linked_text.push_str(&encoded_url);
+        }
+    }
+
+    linked_text
+}
+
+/// Splits one buffer cell symbol into its visible text and link target.
+///
+/// Returns `None` when the symbol carries no encoded link.
+fn split_symbol(symbol: &str) -> Option<(&str, String)> {
+    let (visible, encoded_url) = symbol.split_once(LINK_MARKER)?;
+    let url = encoded_url
+        .chars()
+        .map(|character| {
+            let code_point = u32::from(character).checked_sub(TAG_BASE)?;
+            let byte = u8::try_from(code_point).ok()?;
+
+            byte.is_ascii_graphic().then_some(char::from(byte))
+        })
+        .collect::<Option<String>>()?;
+    if url.is_empty() {
+        return None;
+    }
+
+    Some((visible, url))
+}
+
+/// Encodes `url` as a link marker followed by tag characters, percent-encoding
+/// bytes outside printable ASCII so the target always round-trips.
+fn encode_url(url: &str) -> String {
+    let mut encoded_url = String::from(LINK_MARKER);
+
+    for byte in url.bytes() {
+        if byte.is_ascii_graphic() {
+            encoded_url.push(tag_character(byte));
+
+            continue;
+        }
+
+        for percent_byte in format!("%{byte:02X}").bytes() {
+            encoded_url.push(tag_character(percent_byte));
+        }
+    }
+
+    encoded_url
+}
+
+/// Maps one printable ASCII byte onto its Unicode tag character.
+fn tag_character(byte: u8) -> char {
+    char::from_u32(TAG_BASE + u32::from(byte)).unwrap_or(LINK_MARKER)
+}
+
+/// Terminal backend wrapper that turns tagged link cells into OSC 8
+/// hyperlinks.
+///
+/// Frames without links are forwarded to the inner backend unchanged.
+pub struct HyperlinkBackend<B> {
+    inner: B,
+}
+
+impl<B> HyperlinkBackend<B> {
+    /// Wraps one terminal backend.
+    pub fn new(inner: B) -> Self {
+        Self { inner }
+    }
+}
+
+impl<B: Backend<Error = io::Error> + Write> HyperlinkBackend<B> {
+    /// Draws one run of cells sharing the same link target, opening and
+    /// closing the OSC 8 hyperlink around it.
+    fn draw_run(&mut self, run: &mut Vec<(u16, u16, Cell)>, url: Option<&str>) -> io::Result<()> {
+        if run.is_empty() {
+            return Ok(());
+        }
+
+        if let Some(url) = url {
+            write!(self.inner, "\x1b]8;;{url}\x1b\\")?;
+        }
+        self.inner
+            .draw(run.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+        if url.is_some() {
+            write!(self.inner, "\x1b]8;;\x1b\\")?;
+        }
+        run.clear();
+
+        Ok(())
+    }
+}
+
+impl<B: Backend<Error = io::Error> + Write> Backend for HyperlinkBackend<B> {
+    type Error = io::Error;
+
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let cells = content.collect::<Vec<_>>();
+        if !cells
+            .iter()
+            .any(|(_, _, cell)| cell.symbol().contains(LINK_MARKER))
+        {
+            return self.inner.draw(cells.into_iter());
+        }
+
+        let mut run = Vec::new();
+        let mut run_url: Option<String> = None;
+        for (x, y, cell) in cells {
+            let mut cell = cell.clone();
+            let url = split_symbol(cell.symbol())
+                .map(|(visible, url)| (visible.to_string(), url))
+                .map(|(visible, url)| {
+                    cell.set_symbol(&visible);
+
+                    url
+                });
+            if url != run_url {
+                self.draw_run(&mut run, run_url.as_deref())?;
+                run_url = url;
+            }
+
+            run.push((x, y, cell));
+        }
+
+        self.draw_run(&mut run, run_url.as_deref())
+    }
+
+    fn append_lines(&mut self, line_count: u16) -> io::Result<()> {
+        self.inner.append_lines(line_count)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.inner.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.inner.show_cursor()
+    }
+
+    fn get_cursor_position(&mut self) -> io::Result<Position> {
+        self.inner.get_cursor_position()
+    }
+
+    fn set_cursor_position<P: Into<Position>>(&mut self, position: P) -> io::Result<()> {
+        self.inner.set_cursor_position(position)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.inner.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.inner.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Size> {
+        self.inner.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.inner.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Backend::flush(&mut self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::backend::CrosstermBackend;
+    use unicode_width::UnicodeWidthStr;
+
+    use super::*;
+
+    #[test]
+    fn is_linkable_accepts_absolute_schemes_only() {
+        // Arrange / Act / Assert
+        assert!(is_linkable("https://example.com"));
+        assert!(is_linkable("MAILTO:dev@example.com"));
+        assert!(!is_linkable("src/main.rs"));
+        assert!(!is_linkable("#section"));
+    }
+
+    #[test]
+    fn link_text_keeps_display_width_and_round_trips_url() {
+        // Arrange
+        let url = "https://example.com/a b";
+
+        // Act
+        let linked_text = link_text("do cs", url);
+        let first_symbol = linked_text
+            .split_once('o')
+            .map(|(first_symbol, _)| first_symbol)
+            .unwrap_or_default();
+
+        // Assert
+        assert_eq!(linked_text.width(), 5);
+        assert_eq!(
+            split_symbol(first_symbol),
+            Some(("d", "https://example.com/a%20b".to_string()))
+        );
+    }
+
+    #[test]
+    fn split_symbol_ignores_plain_symbols() {
+        // Arrange / Act / Assert
+        assert_eq!(split_symbol("a"), None);
+        assert_eq!(split_symbol("a\u{E007F}"), None);
+    }
+
+    #[test]
+    fn draw_wraps_linked_cells_in_osc8_sequences() {
+        // Arrange
+        let mut output = Vec::new();
+        let mut backend = HyperlinkBackend::new(CrosstermBackend::new(&mut output));
+        let encoded_url = encode_url("https://example.com");
+        let mut first_cell = Cell::default();
+        first_cell.set_symbol(&format!("a{encoded_url}"));
+        let mut second_cell = Cell::default();
+        second_cell.set_symbol(&format!("b{encoded_url}"));
+        let mut plain_cell = Cell::default();
+        plain_cell.set_symbol("c");
+
+        // Act
+        backend
+            .draw(
+                [
+                    (0, 0, &first_cell),
+                    (1, 0, &second_cell),
+                    (2, 0, &plain_cell),
+                ]
+                .into_iter(),
+            )
+            .expect("draw should succeed");
+
+        // Assert
+        let output = String::from_utf8(output).expect("utf-8 output");
+        let open_index = output
+            .find("\x1b]8;;https://example.com\x1b\\")
+            .expect("open sequence");
+        let close_index = output.find("\x1b]8;;\x1b\\").expect("close sequence");
+        assert_eq!(output.matches("\x1b]8;;https://example.com").count(), 1);
+        assert!(open_index < output.find("ab").expect("linked cells"));
+        assert!(output.find("ab").expect("linked cells") < close_index);
+        assert!(close_index < output.rfind('c').expect("plain cell"));
+        assert!(!output.contains(LINK_MARKER));
+    }
+}
@@ -1,9 +1,11 @@
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Borders;
 
+use crate::domain::composer::attachment_token_end_index;
 use crate::domain::input::{is_at_mention_boundary, is_at_mention_query_character};
+use crate::ui::style::palette;
 use crate::ui::text_util;
 
 /// Maximum number of visible content lines inside the chat input viewport.
@@ -16,10 +18,13 @@ const CHAT_INPUT_PROMPT_PREFIX_WIDTH: u16 = 3;
 const QUESTION_PANEL_HELP_HEIGHT: u16 = 1;
 const QUESTION_PANEL_SPACER_HEIGHT: u16 = 1;
 const SLASH_MENU_BORDER_HEIGHT: u16 = 2;
-/// Foreground color used for chat input `@` lookup tokens.
-const CHAT_INPUT_AT_MENTION_COLOR: Color = Color::LightBlue;
-/// Foreground color used for inline prompt image placeholders.
-const CHAT_INPUT_IMAGE_TOKEN_COLOR: Color = Color::Yellow;
+/// Shared page margin that keeps list tables and footers aligned.
+const PAGE_MARGIN: u16 = 1;
+/// Narrowest content width that still fits the session table beside a
+/// readable transcript; narrower terminals fall back to one pane.
+const SPLIT_LAYOUT_MIN_WIDTH: u16 = 140;
+/// Share of the content width given to the session list pane.
+const SPLIT_LAYOUT_LIST_PERCENT: u16 = 45;
 
 /// Height allocation for question mode's prompt, answer input, and footer.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -49,6 +54,104 @@ pub struct QuestionPanelAreas {
     pub spacer_area: Rect,
 }
 
+/// Top-level frame regions shared by rendering and mouse hit-testing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameAreas {
+    /// Central area where the active page or mode is drawn.
+    pub content_area: Rect,
+    /// Bottom bar with directory, branch, and git status details.
+    pub footer_bar_area: Rect,
+    /// Top bar with version, FYIs, and update status.
+    pub status_bar_area: Rect,
+}
+
+/// List-mode regions inside the frame content area.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ListAreas {
+    /// Area used by the selected tab page.
+    pub page_area: Rect,
+    /// Area used by the header tabs.
+    pub tabs_area: Rect,
+}
+
+/// Page regions shared by list pages that pin one help row to the bottom.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PageAreas {
+    /// One-line help footer listing the page shortcuts.
+    pub footer_area: Rect,
+    /// Main page content above the footer.
+    pub main_area: Rect,
+}
+
+/// Side-by-side regions used by the optional split layout.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SplitAreas {
+    /// Left pane with the tabs and the session list.
+    pub list_area: Rect,
+    /// Right pane with the selected session transcript.
+    pub session_area: Rect,
+}
+
+/// Splits the full terminal area into status bar, content, and footer bar.
+pub fn frame_areas(area: Rect) -> FrameAreas {
+    let [status_bar_area, content_area, footer_bar_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(0),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+
+    FrameAreas {
+        content_area,
+        footer_bar_area,
+        status_bar_area,
+    }
+}
+
+/// Splits the frame content area into the header tabs and the selected page.
+pub fn list_areas(content_area: Rect) -> ListAreas {
+    let [tabs_area, page_area] =
+        Layout::vertical([Constraint::Length(3), Constraint::Min(0)]).areas(content_area);
+
+    ListAreas {
+        page_area,
+        tabs_area,
+    }
+}
+
+/// Splits the frame content area into the list and session panes when the
+/// split layout is enabled and the content area is wide enough for both.
+pub fn split_areas(content_area: Rect, is_split_layout_enabled: bool) -> Option<SplitAreas> {
+    if !is_split_layout_enabled || content_area.width < SPLIT_LAYOUT_MIN_WIDTH {
+        return None;
+    }
+
+    let [list_area, session_area] = Layout::horizontal([
+        Constraint::Percentage(SPLIT_LAYOUT_LIST_PERCENT),
+        Constraint::Min(0),
+    ])
+    .areas(content_area);
+
+    Some(SplitAreas {
+        list_area,
+        session_area,
+    })
+}
+
+/// Splits one list page into its main content and a bottom help row inside
+/// the shared one-cell page margin.
+pub fn page_areas(area: Rect) -> PageAreas {
+    let chunks = Layout::default()
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .margin(PAGE_MARGIN)
+        .split(area);
+
+    PageAreas {
+        footer_area: chunks[1],
+        main_area: chunks[0],
+    }
+}
+
 /// Split an area into a centered content column with side gutters.
 pub fn centered_horizontal_layout(area: Rect) -> std::rc::Rc<[Rect]> {
     Layout::default()
@@ -453,7 +556,7 @@ fn compute_input_layout_data(input: &str, width: u16) -> InputLayout {
     let prefix_span = Span::styled(
         prefix,
         Style::default()
-            .fg(Color::Cyan)
+            .fg(palette::accent())
             .add_modifier(Modifier::BOLD),
     );
     let prefix_width = prefix_span.width();
@@ -466,18 +569,18 @@ fn compute_input_layout_data(input: &str, width: u16) -> InputLayout {
     let mut line_index: usize = 0;
 
     let mut in_mention = false;
-    let mut image_token_end: Option<usize> = None;
+    let mut attachment_token_end: Option<usize> = None;
     let mut last_ch = None;
 
     let input_chars = input.chars().collect::<Vec<_>>();
     for (character_index, ch) in input_chars.iter().copied().enumerate() {
-        if image_token_end.is_some_and(|end_index| character_index >= end_index) {
-            image_token_end = None;
+        if attachment_token_end.is_some_and(|end_index| character_index >= end_index) {
+            attachment_token_end = None;
         }
 
         if ch == '\n' {
             in_mention = false;
-            image_token_end = None;
+            attachment_token_end = None;
             cursor_positions.push((current_width, line_index));
             display_lines.push(Line::from(std::mem::take(&mut current_line_spans)));
             current_line_spans = vec![Span::raw(continuation_padding.clone())];
@@ -513,18 +616,19 @@ fn compute_input_layout_data(input: &str, width: u16) -> InputLayout {
             in_mention = false;
         }
 
-        if image_token_end.is_none() && ch == '[' {
-            image_token_end = image_token_end_index(&input_chars, character_index);
+        if attachment_token_end.is_none() && ch == '[' {
+            attachment_token_end = attachment_token_end_index(&input_chars, character_index);
         }
 
-        let is_image_token = image_token_end.is_some_and(|end_index| character_index < end_index);
+        let is_attachment_token =
+            attachment_token_end.is_some_and(|end_index| character_index < end_index);
 
-        let style = if is_image_token {
+        let style = if is_attachment_token {
             Style::default()
-                .fg(CHAT_INPUT_IMAGE_TOKEN_COLOR)
+                .fg(palette::warning())
                 .add_modifier(Modifier::BOLD)
         } else if in_mention {
-            Style::default().fg(CHAT_INPUT_AT_MENTION_COLOR)
+            Style::default().fg(palette::info())
         } else {
             Style::default()
         };
@@ -565,41 +669,6 @@ fn compute_input_layout_data(input: &str, width: u16) -> InputLayout {
     }
 }
 
-/// Returns the exclusive end index for an `[Image #n]` placeholder token that
-/// starts at `start_index`.
-fn image_token_end_index(input_chars: &[char], start_index: usize) -> Option<usize> {
-    let token_body = input_chars.get(start_index..)?;
-
-    if token_body.len() < "[Image #1]".chars().count() || token_body.first() != Some(&'[') {
-        return None;
-    }
-
-    let image_prefix = ['[', 'I', 'm', 'a', 'g', 'e', ' ', '#'];
-    if token_body.get(..image_prefix.len())? != image_prefix {
-        return None;
-    }
-
-    let mut scan_index = start_index + image_prefix.len();
-    let mut saw_digit = false;
-
-    while let Some(ch) = input_chars.get(scan_index) {
-        if ch.is_ascii_digit() {
-            saw_digit = true;
-            scan_index += 1;
-
-            continue;
-        }
-
-        if *ch == ']' && saw_digit {
-            return Some(scan_index + 1);
-        }
-
-        return None;
-    }
-
-    None
-}
-
 fn target_line_index(
     current_y: usize,
     cursor_positions: &[(usize, usize)],
@@ -673,6 +742,87 @@ enum VerticalDirection {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_frame_areas_reserve_status_and_footer_rows() {
+        // Arrange
+        let area = Rect::new(0, 0, 80, 24);
+
+        // Act
+        let frame_areas = frame_areas(area);
+
+        // Assert
+        assert_eq!(frame_areas.status_bar_area, Rect::new(0, 0, 80, 1));
+        assert_eq!(frame_areas.content_area, Rect::new(0, 1, 80, 22));
+        assert_eq!(frame_areas.footer_bar_area, Rect::new(0, 23, 80, 1));
+        assert_eq!(
+            list_areas(frame_areas.content_area),
+            ListAreas {
+                page_area: Rect::new(0, 4, 80, 19),
+                tabs_area: Rect::new(0, 1, 80, 3),
+            }
+        );
+    }
+
+    #[test]
+    fn test_page_areas_use_shared_page_margin() {
+        // Arrange
+        let area = Rect::new(5, 3, 40, 10);
+
+        // Act
+        let PageAreas {
+            footer_area,
+            main_area,
+        } = page_areas(area);
+
+        // Assert
+        assert_eq!(main_area.x, area.x + PAGE_MARGIN);
+        assert_eq!(main_area.y, area.y + PAGE_MARGIN);
+        assert_eq!(main_area.width, area.width - PAGE_MARGIN.saturating_mul(2));
+        assert_eq!(
+            main_area.height,
+            area.height - PAGE_MARGIN.saturating_mul(2) - 1
+        );
+        assert_eq!(footer_area.x, area.x + PAGE_MARGIN);
+        assert_eq!(footer_area.y, area.y + area.height - PAGE_MARGIN - 1);
+        assert_eq!(
+            footer_area.width,
+            area.width - PAGE_MARGIN.saturating_mul(2)
+        );
+        assert_eq!(footer_area.height, 1);
+    }
+
+    #[test]
+    fn test_split_areas_share_content_width_when_enabled_and_wide() {
+        // Arrange
+        let content_area = Rect::new(0, 1, 200, 40);
+
+        // Act
+        let split_areas = split_areas(content_area, true);
+
+        // Assert
+        let SplitAreas {
+            list_area,
+            session_area,
+        } = split_areas.expect("wide content should split");
+        assert_eq!(list_area, Rect::new(0, 1, 90, 40));
+        assert_eq!(session_area, Rect::new(90, 1, 110, 40));
+    }
+
+    #[test]
+    fn test_split_areas_fall_back_when_disabled_or_narrow() {
+        // Arrange
+        let wide_content_area = Rect::new(0, 1, 200, 40);
+        let narrow_content_area = Rect::new(0, 1, SPLIT_LAYOUT_MIN_WIDTH - 1, 40);
+
+        // Act
+        let disabled_split = split_areas(wide_content_area, false);
+        let narrow_split = split_areas(narrow_content_area, true);
+
+        // Assert
+        assert_eq!(disabled_split, None);
+        assert_eq!(narrow_split, None);
+    }
+
     #[test]
     fn test_centered_content_rect_centers_requested_size() {
         // Arrange
@@ -1250,7 +1400,7 @@ mod tests {
 
         // "@file" (indices 7..12) should be highlighted
         for span in spans.iter().take(12).skip(7) {
-            assert_eq!(span.style.fg, Some(CHAT_INPUT_AT_MENTION_COLOR));
+            assert_eq!(span.style.fg, Some(palette::info()));
         }
 
         // " world" (indices 12..18) should be normal style
@@ -1273,7 +1423,7 @@ mod tests {
         let spans = &line.spans;
 
         for span in spans.iter().take(12).skip(7) {
-            assert_eq!(span.style.fg, Some(CHAT_INPUT_AT_MENTION_COLOR));
+            assert_eq!(span.style.fg, Some(palette::info()));
         }
         assert_eq!(spans[12].content, ",");
         assert_eq!(spans[12].style.fg, None);
@@ -1293,7 +1443,7 @@ mod tests {
         let spans = &line.spans;
 
         for span in spans.iter().take(12).skip(7) {
-            assert_eq!(span.style.fg, Some(CHAT_INPUT_AT_MENTION_COLOR));
+            assert_eq!(span.style.fg, Some(palette::info()));
         }
         assert_eq!(spans[12].content, ")");
         assert_eq!(spans[12].style.fg, None);
@@ -1313,7 +1463,7 @@ mod tests {
         let spans = &line.spans;
 
         for span in spans.iter().take(21).skip(9) {
-            assert_eq!(span.style.fg, Some(CHAT_INPUT_AT_MENTION_COLOR));
+            assert_eq!(span.style.fg, Some(palette::info()));
         }
         assert_eq!(spans[21].content, ")");
         assert_eq!(spans[21].style.fg, None);
@@ -1353,11 +1503,32 @@ mod tests {
         let image_end = image_start + "[Image #12]".chars().count();
 
         for span in spans.iter().take(image_end).skip(image_start) {
-            assert_eq!(span.style.fg, Some(CHAT_INPUT_IMAGE_TOKEN_COLOR));
+            assert_eq!(span.style.fg, Some(palette::warning()));
             assert!(span.style.add_modifier.contains(Modifier::BOLD));
         }
     }
 
+    #[test]
+    fn test_compute_input_layout_highlights_context_placeholder_tokens() {
+        // Arrange
+        let input = "Explain [Command #3] output";
+        let width = 60;
+
+        // Act
+        let (lines, _, _) = compute_input_layout(input, width, 0);
+
+        // Assert
+        let line = &lines[0];
+        let spans = &line.spans;
+        let token_start = 1 + "Explain ".chars().count();
+        let token_end = token_start + "[Command #3]".chars().count();
+
+        for span in spans.iter().take(token_end).skip(token_start) {
+            assert_eq!(span.style.fg, Some(palette::warning()));
+        }
+        assert_eq!(spans[token_end].style.fg, None);
+    }
+
     #[test]
     fn test_compute_input_layout_does_not_highlight_invalid_image_like_tokens() {
         // Arrange
@@ -1,16 +1,24 @@
 use std::cell::RefCell;
 use std::collections::VecDeque;
 
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use unicode_width::UnicodeWidthChar;
 
+use crate::ui::highlight::CodeHighlighter;
+use crate::ui::style::palette;
 use crate::ui::util::wrap_styled_line;
+use crate::ui::{hyperlink, theme};
 
 const USER_PROMPT_PREFIX: &str = " › ";
 const CLARIFICATION_HEADER: &str = "Clarifications:";
 const CLARIFICATION_PROMPT_PREFIX: &str = USER_PROMPT_PREFIX;
 const STATS_LABEL_WIDTH: usize = 22;
+/// Fenced-code info string that renders session stats as aligned metrics.
+const STATS_FENCE_INFO: &str = "stats";
+/// Separator placed between rendered table columns.
+const TABLE_COLUMN_SEPARATOR: &str = " │ ";
 /// Maximum number of distinct markdown blocks cached at once.
 const MARKDOWN_RENDER_CACHE_ENTRY_LIMIT: usize = 8;
 const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
@@ -21,13 +29,15 @@ struct MarkdownRenderCacheEntry {
     content_hash: u64,
     content_len: usize,
     lines: Vec<Line<'static>>,
+    theme_generation: u64,
     width: usize,
 }
 
 /// Bounded LRU cache for `render_markdown` output.
 ///
 /// Stores the most recent rendered `Vec<Line<'static>>` values keyed by a
-/// content fingerprint and target width. Keeping several entries avoids cache
+/// content fingerprint, target width, and [`theme::generation`], since the
+/// lines carry resolved theme colors. Keeping several entries avoids cache
 /// thrash when one frame renders transcript text alongside summary, review, or
 /// footer markdown blocks. Interior mutability via `RefCell` allows cache
 /// updates through shared references so the cache can be threaded through
@@ -45,12 +55,13 @@ impl Default for MarkdownRenderCache {
 }
 
 impl MarkdownRenderCache {
-    /// Returns cached rendered lines when the text hash and width match,
-    /// otherwise renders fresh and updates the cache.
+    /// Returns cached rendered lines when the text hash, width, and active
+    /// theme match, otherwise renders fresh and updates the cache.
     pub fn render(&self, text: &str, width: usize) -> Vec<Line<'static>> {
         let content_hash = Self::hash_text(text);
         let content_len = text.len();
-        if let Some(lines) = self.cached_lines(content_hash, content_len, width) {
+        let theme_generation = theme::generation();
+        if let Some(lines) = self.cached_lines(content_hash, content_len, theme_generation, width) {
             return lines;
         }
 
@@ -60,6 +71,7 @@ impl MarkdownRenderCache {
             content_hash,
             content_len,
             lines: lines.clone(),
+            theme_generation,
             width,
         });
 
@@ -72,12 +84,14 @@ impl MarkdownRenderCache {
         &self,
         content_hash: u64,
         content_len: usize,
+        theme_generation: u64,
         width: usize,
     ) -> Option<Vec<Line<'static>>> {
         let mut entries = self.entries.borrow_mut();
         let entry_index = entries.iter().position(|entry| {
             entry.content_hash == content_hash
                 && entry.content_len == content_len
+                && entry.theme_generation == theme_generation
                 && entry.width == width
         })?;
         let entry = entries.remove(entry_index)?;
@@ -100,7 +114,7 @@ impl MarkdownRenderCache {
 
     /// Computes a fast non-cryptographic content hash for cache key
     /// comparison.
-    fn hash_text(text: &str) -> u64 {
+    pub(crate) fn hash_text(text: &str) -> u64 {
         let mut hash = FNV_OFFSET_BASIS;
 
         for byte in text.as_bytes() {
@@ -112,13 +126,6 @@ impl MarkdownRenderCache {
     }
 }
 
-#[derive(Clone, Copy)]
-enum BlockState {
-    Paragraph,
-    FencedCode,
-    FencedStats,
-}
-
 /// Distinguishes prompt-block payloads that share `USER_PROMPT_PREFIX`.
 #[derive(Clone, Copy)]
 enum PromptBlockKind {
@@ -127,9 +134,13 @@ enum PromptBlockKind {
 }
 
 /// Converts markdown text into styled, word-wrapped lines for terminal display.
+///
+/// Prompt blocks are session metadata and render verbatim; every run of text
+/// between them is parsed as `CommonMark` with GitHub tables, strikethrough,
+/// and task lists.
 pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
     let mut rendered_lines = Vec::new();
-    let mut block_state = BlockState::Paragraph;
+    let mut markdown_lines = Vec::new();
     let mut is_user_prompt_block = false;
     let mut active_prompt_block_kind = PromptBlockKind::UserPrompt;
 
@@ -138,8 +149,9 @@ pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
         if let Some(prompt_line) = user_prompt_block_line(raw_line, &mut is_user_prompt_block) {
             if starts_user_prompt_block {
                 // Prompt lines are session metadata, not markdown content.
+                rendered_lines.extend(render_markdown_section(&markdown_lines, width));
+                markdown_lines.clear();
                 active_prompt_block_kind = prompt_block_kind(raw_line);
-                block_state = BlockState::Paragraph;
                 rendered_lines.push(prompt_block_padding_line(width, active_prompt_block_kind));
             }
 
@@ -157,22 +169,11 @@ pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
             continue;
         }
 
-        if is_fence_delimiter(raw_line) {
-            block_state = match block_state {
-                BlockState::Paragraph => opening_fence_block_state(raw_line),
-                BlockState::FencedCode | BlockState::FencedStats => BlockState::Paragraph,
-            };
-
-            continue;
-        }
-
-        match block_state {
-            BlockState::Paragraph => rendered_lines.extend(render_markdown_line(raw_line, width)),
-            BlockState::FencedCode => rendered_lines.extend(render_code_line(raw_line, width)),
-            BlockState::FencedStats => rendered_lines.extend(render_stats_line(raw_line, width)),
-        }
+        markdown_lines.push(raw_line);
     }
 
+    rendered_lines.extend(render_markdown_section(&markdown_lines, width));
+
     if is_user_prompt_block {
         rendered_lines.push(prompt_block_padding_line(width, active_prompt_block_kind));
     }
@@ -184,6 +185,759 @@ pub fn render_markdown(text: &str, width: usize) -> Vec<Line<'static>> {
     rendered_lines
 }
 
+/// Returns whether `line` is the first rendered row of a user prompt block.
+///
+/// Clarification blocks share the prompt marker text but use a different
+/// marker style, so they are not reported as user prompts.
+pub fn is_user_prompt_start_line(line: &Line<'_>) -> bool {
+    line.spans.first().is_some_and(|span| {
+        span.content == USER_PROMPT_PREFIX && span.style == user_prompt_prefix_style()
+    })
+}
+
+/// Renders one run of markdown source lines between prompt blocks.
+///
+/// Blank lines around the section are kept as-is so transcript spacing next
+/// to prompt blocks matches the stored output.
+fn render_markdown_section(raw_lines: &[&str], width: usize) -> Vec<Line<'static>> {
+    let leading_blank_count = raw_lines
+        .iter()
+        .take_while(|raw_line| raw_line.trim().is_empty())
+        .count();
+    if leading_blank_count == raw_lines.len() {
+        return vec![Line::from(""); leading_blank_count];
+    }
+
+    let trailing_blank_count = raw_lines
+        .iter()
+        .rev()
+        .take_while(|raw_line| raw_line.trim().is_empty())
+        .count();
+    let markdown =
+        raw_lines[leading_blank_count..raw_lines.len() - trailing_blank_count].join("\n");
+    let mut lines = vec![Line::from(""); leading_blank_count];
+    lines.extend(MarkdownRenderer::new(width).render(&markdown));
+    lines.extend(vec![Line::from(""); trailing_blank_count]);
+
+    lines
+}
+
+/// One open block container that shapes the prefix of every line inside it.
+enum MarkdownContainer {
+    BlockQuote,
+    List {
+        is_tight: bool,
+        next_number: Option<u64>,
+    },
+    ListItem {
+        is_marker_pending: bool,
+        is_tight: bool,
+        marker: String,
+    },
+}
+
+/// Fenced or indented code collected until the block closes.
+struct MarkdownCodeBlock {
+    content: String,
+    info: String,
+}
+
+/// Table cells collected until the table closes.
+#[derive(Default)]
+struct MarkdownTable {
+    alignments: Vec<Alignment>,
+    current_row: Vec<Vec<Span<'static>>>,
+    header_row: Vec<Vec<Span<'static>>>,
+    rows: Vec<Vec<Vec<Span<'static>>>>,
+}
+
+/// Event-driven `CommonMark` renderer producing wrapped terminal lines.
+///
+/// Soft line breaks render as real line breaks so agent output keeps its
+/// line structure, matching how chat transcripts are usually written.
+struct MarkdownRenderer {
+    block_styles: Vec<Style>,
+    code_block: Option<MarkdownCodeBlock>,
+    containers: Vec<MarkdownContainer>,
+    inline_spans: Vec<Span<'static>>,
+    inline_styles: Vec<Style>,
+    lines: Vec<Line<'static>>,
+    link_urls: Vec<String>,
+    needs_block_gap: bool,
+    table: Option<MarkdownTable>,
+    width: usize,
+}
+
+impl MarkdownRenderer {
+    /// Creates a renderer wrapping content to `width` columns.
+    fn new(width: usize) -> Self {
+        Self {
+            block_styles: Vec::new(),
+            code_block: None,
+            containers: Vec::new(),
+            inline_spans: Vec::new(),
+            inline_styles: Vec::new(),
+            lines: Vec::new(),
+            link_urls: Vec::new(),
+            needs_block_gap: false,
+            table: None,
+            width,
+        }
+    }
+
+    /// Renders one markdown document into terminal lines.
+    fn render(mut self, markdown: &str) -> Vec<Line<'static>> {
+        let options =
+            Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+        let events = Parser::new_ext(markdown, options).collect::<Vec<_>>();
+
+        for (index, event) in events.iter().enumerate() {
+            match event {
+                Event::Start(tag) => self.start_tag(tag, &events[index + 1..]),
+                Event::End(tag_end) => self.end_tag(*tag_end),
+                _ => self.inline_event(event),
+            }
+        }
+        self.flush_inline();
+
+        self.lines
+    }
+
+    /// Opens one block or inline tag.
+    ///
+    /// `following_events` lets lists decide up front whether they are tight.
+    fn start_tag(&mut self, tag: &Tag<'_>, following_events: &[Event<'_>]) {
+        match tag {
+            Tag::Paragraph | Tag::HtmlBlock => self.start_block(),
+            Tag::Heading { level, .. } => {
+                self.start_block();
+                self.inline_styles.push(heading_style(*level as usize));
+            }
+            Tag::BlockQuote(_) => {
+                self.start_block();
+                self.containers.push(MarkdownContainer::BlockQuote);
+                self.block_styles
+                    .push(Style::default().fg(palette::text_muted()));
+            }
+            Tag::CodeBlock(kind) => {
+                self.start_block();
+                let info = match kind {
+                    CodeBlockKind::Fenced(info) => info.to_string(),
+                    CodeBlockKind::Indented => String::new(),
+                };
+                self.code_block = Some(MarkdownCodeBlock {
+                    content: String::new(),
+                    info,
+                });
+            }
+            Tag::List(first_number) => {
+                self.start_block();
+                self.containers.push(MarkdownContainer::List {
+                    is_tight: is_tight_list(following_events),
+                    next_number: *first_number,
+                });
+            }
+            Tag::Item => {
+                self.start_block();
+                self.start_list_item();
+            }
+            Tag::Table(alignments) => {
+                self.start_block();
+                self.table = Some(MarkdownTable {
+                    alignments: alignments.clone(),
+                    ..MarkdownTable::default()
+                });
+            }
+            Tag::Emphasis => self.push_inline_modifier(Modifier::ITALIC),
+            Tag::Strong => self.push_inline_modifier(Modifier::BOLD),
+            Tag::Strikethrough => self.push_inline_modifier(Modifier::CROSSED_OUT),
+            Tag::Link { dest_url, .. } | Tag::Image { dest_url, .. } => {
+                self.link_urls.push(dest_url.to_string());
+                self.inline_styles.push(link_style(self.inline_style()));
+            }
+            _ => {}
+        }
+    }
+
+    /// Closes one block or inline tag.
+    fn end_tag(&mut self, tag_end: TagEnd) {
+        match tag_end {
+            TagEnd::Paragraph | TagEnd::HtmlBlock => self.end_block(),
+            TagEnd::Heading(_) => {
+                self.end_block();
+                self.inline_styles.pop();
+            }
+            TagEnd::BlockQuote(_) => {
+                self.end_block();
+                self.containers.pop();
+                self.block_styles.pop();
+            }
+            TagEnd::CodeBlock => {
+                self.render_code_block();
+                self.end_block();
+            }
+            TagEnd::List(_) => {
+                self.end_block();
+                self.containers.pop();
+            }
+            TagEnd::Item => {
+                self.flush_inline();
+                self.containers.pop();
+            }
+            TagEnd::TableCell => {
+                let cell = std::mem::take(&mut self.inline_spans);
+                if let Some(table) = &mut self.table {
+                    table.current_row.push(cell);
+                }
+            }
+            TagEnd::TableHead => {
+                if let Some(table) = &mut self.table {
+                    table.header_row = std::mem::take(&mut table.current_row);
+                }
+            }
+            TagEnd::TableRow => {
+                if let Some(table) = &mut self.table {
+                    let row = std::mem::take(&mut table.current_row);
+                    table.rows.push(row);
+                }
+            }
+            TagEnd::Table => {
+                self.render_table();
+                self.end_block();
+            }
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.inline_styles.pop();
+            }
+            TagEnd::Link | TagEnd::Image => {
+                self.link_urls.pop();
+                self.inline_styles.pop();
+            }
+            _ => {}
+        }
+    }
+
+    /// Renders one leaf event such as text, code, breaks, and rules.
+    fn inline_event(&mut self, event: &Event<'_>) {
+        match event {
+            Event::Text(text) => {
+                if let Some(code_block) = &mut self.code_block {
+                    code_block.content.push_str(text);
+                } else {
+                    self.push_text(text, self.inline_style());
+                }
+            }
+            Event::Code(code) => self.push_text(code, inline_code_style()),
+            Event::Html(html) => {
+                for (index, html_line) in html.trim_end_matches('\n').split('\n').enumerate() {
+                    if index > 0 {
+                        self.flush_inline();
+                    }
+                    self.push_text(html_line, html_style());
+                }
+            }
+            Event::InlineHtml(html) => self.push_text(html, html_style()),
+            Event::SoftBreak | Event::HardBreak => self.flush_inline(),
+            Event::Rule => {
+                self.start_block();
+                let rule_line = horizontal_rule_line(self.content_width());
+                self.push_line(rule_line.spans);
+                self.end_block();
+            }
+            Event::TaskListMarker(is_checked) => {
+                let marker = if *is_checked { "[x] " } else { "[ ] " };
+                self.inline_spans
+                    .push(Span::styled(marker.to_string(), list_prefix_style()));
+            }
+            Event::FootnoteReference(label) => {
+                self.push_text(&format!("[^{label}]"), self.inline_style());
+            }
+            Event::InlineMath(math) | Event::DisplayMath(math) => {
+                self.push_text(math, inline_code_style());
+            }
+            Event::Start(_) | Event::End(_) => {}
+        }
+    }
+
+    /// Separates a new block from the previous sibling with one blank line,
+    /// except between items and nested lists of a tight list.
+    fn start_block(&mut self) {
+        self.flush_inline();
+        if !std::mem::take(&mut self.needs_block_gap) || self.is_in_tight_list() {
+            return;
+        }
+
+        let mut gap_spans = self.gap_prefix();
+        if let Some(last_span) = gap_spans.last_mut() {
+            last_span.content = last_span.content.trim_end().to_string().into();
+        }
+        self.lines.push(Line::from(gap_spans));
+    }
+
+    /// Flushes pending inline content and requests a gap before the next
+    /// sibling block.
+    fn end_block(&mut self) {
+        self.flush_inline();
+        self.needs_block_gap = true;
+    }
+
+    /// Opens one list item, advancing the enclosing list's numbering.
+    fn start_list_item(&mut self) {
+        let (marker, is_tight) = match self.containers.last_mut() {
+            Some(MarkdownContainer::List {
+                is_tight,
+                next_number,
+            }) => {
+                let marker = match next_number {
+                    Some(number) => {
+                        let marker = format!("{number}. ");
+                        *number += 1;
+
+                        marker
+                    }
+                    None => "- ".to_string(),
+                };
+
+                (marker, *is_tight)
+            }
+            _ => ("- ".to_string(), true),
+        };
+
+        self.containers.push(MarkdownContainer::ListItem {
+            is_marker_pending: true,
+            is_tight,
+            marker,
+        });
+    }
+
+    /// Returns whether the innermost container is a tight list or one of its
+    /// items.
+    fn is_in_tight_list(&self) -> bool {
+        matches!(
+            self.containers.last(),
+            Some(
+                MarkdownContainer::List { is_tight: true, .. }
+                    | MarkdownContainer::ListItem { is_tight: true, .. }
+            )
+        )
+    }
+
+    /// Returns the style for new inline text.
+    fn inline_style(&self) -> Style {
+        self.inline_styles
+            .last()
+            .or_else(|| self.block_styles.last())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Pushes one nested inline style adding `modifier`.
+    fn push_inline_modifier(&mut self, modifier: Modifier) {
+        let style = self.inline_style().add_modifier(modifier);
+        self.inline_styles.push(style);
+    }
+
+    /// Appends inline text, attaching the enclosing link target when the
+    /// terminal can open it.
+    fn push_text(&mut self, text: &str, style: Style) {
+        let content = match self.link_urls.last() {
+            Some(url) if hyperlink::is_linkable(url) => hyperlink::link_text(text, url),
+            _ => text.to_string(),
+        };
+
+        self.inline_spans.push(Span::styled(content, style));
+    }
+
+    /// Wraps pending inline spans into lines at the current content width.
+    ///
+    /// Table cells keep their spans until the cell closes.
+    fn flush_inline(&mut self) {
+        if self.inline_spans.is_empty() || self.table.is_some() {
+            return;
+        }
+
+        let spans = std::mem::take(&mut self.inline_spans);
+        for line in wrap_inline_spans(spans, self.content_width()) {
+            self.push_line(line.spans);
+        }
+    }
+
+    /// Renders the collected code block with syntax highlighting, or as
+    /// aligned metrics for `stats` fences.
+    fn render_code_block(&mut self) {
+        let Some(code_block) = self.code_block.take() else {
+            return;
+        };
+        if code_block.content.is_empty() {
+            return;
+        }
+
+        let content_width = self.content_width();
+        let is_stats_block = code_block.info.trim() == STATS_FENCE_INFO;
+        let mut highlighter = CodeHighlighter::for_fence_info(&code_block.info);
+        for raw_line in code_block
+            .content
+            .strip_suffix('\n')
+            .unwrap_or(&code_block.content)
+            .split('\n')
+        {
+            let code_lines = if is_stats_block {
+                render_stats_line(raw_line, content_width)
+            } else {
+                render_code_line(raw_line, highlighter.as_mut(), content_width)
+            };
+
+            for code_line in code_lines {
+                self.push_line(code_line.spans);
+            }
+        }
+    }
+
+    /// Lays out the collected table fitted to the current content width.
+    fn render_table(&mut self) {
+        let Some(table) = self.table.take() else {
+            return;
+        };
+
+        for table_line in render_table_lines(table, self.content_width()) {
+            self.push_line(table_line);
+        }
+    }
+
+    /// Pushes one content line behind the prefixes of all open containers.
+    fn push_line(&mut self, spans: Vec<Span<'static>>) {
+        let mut line_spans = Vec::new();
+        for container in &mut self.containers {
+            match container {
+                MarkdownContainer::BlockQuote => {
+                    line_spans.push(Span::styled("│ ", blockquote_prefix_style()));
+                }
+                MarkdownContainer::List { .. } => {}
+                MarkdownContainer::ListItem {
+                    is_marker_pending,
+                    marker,
+                    ..
+                } => {
+                    let prefix = if std::mem::take(is_marker_pending) {
+                        marker.clone()
+                    } else {
+                        " ".repeat(marker.chars().count())
+                    };
+                    line_spans.push(Span::styled(prefix, list_prefix_style()));
+                }
+            }
+        }
+        line_spans.extend(spans);
+
+        self.lines.push(Line::from(line_spans));
+    }
+
+    /// Returns container prefixes for one blank separator line without
+    /// consuming pending list markers.
+    fn gap_prefix(&self) -> Vec<Span<'static>> {
+        self.containers
+            .iter()
+            .filter_map(|container| match container {
+                MarkdownContainer::BlockQuote => {
+                    Some(Span::styled("│ ", blockquote_prefix_style()))
+                }
+                MarkdownContainer::List { .. } => None,
+                MarkdownContainer::ListItem { marker, .. } => Some(Span::styled(
+                    " ".repeat(marker.chars().count()),
+                    list_prefix_style(),
+                )),
+            })
+            .collect()
+    }
+
+    /// Returns the width left for content after container prefixes.
+    fn content_width(&self) -> usize {
+        if self.width == 0 {
+            return 0;
+        }
+
+        let prefix_width = self
+            .containers
+            .iter()
+            .map(|container| match container {
+                MarkdownContainer::BlockQuote => 2,
+                MarkdownContainer::List { .. } => 0,
+                MarkdownContainer::ListItem { marker, .. } => marker.chars().count(),
+            })
+            .sum::<usize>();
+
+        self.width.saturating_sub(prefix_width).max(1)
+    }
+}
+
+/// Returns whether the list starting right before `following_events` is
+/// tight, meaning none of its own items wrap their content in paragraphs.
+fn is_tight_list(following_events: &[Event<'_>]) -> bool {
+    let mut depth = 0_usize;
+
+    for event in following_events {
+        match event {
+            Event::Start(Tag::List(_)) => depth += 1,
+            Event::End(TagEnd::List(_)) => {
+                if depth == 0 {
+                    return true;
+                }
+                depth -= 1;
+            }
+            Event::Start(Tag::Paragraph) if depth == 0 => return false,
+            _ => {}
+        }
+    }
+
+    true
+}
+
+/// Word-wraps inline spans, collapsing whitespace runs into single spaces.
+///
+/// Unlike [`wrap_styled_line`], words may span several styled spans, so
+/// punctuation that directly follows inline code or emphasis stays attached,
+/// and words wider than `width` fall back to character wrapping.
+fn wrap_inline_spans(spans: Vec<Span<'static>>, width: usize) -> Vec<Line<'static>> {
+    let mut wrapped_lines = Vec::new();
+    let mut current_spans = Vec::new();
+    let mut current_width = 0;
+    let mut word_spans = Vec::new();
+    let mut word_width = 0;
+    let mut pending_space_style = None;
+
+    for span in spans {
+        let style = span.style;
+        for character in span.content.chars() {
+            if !character.is_whitespace() {
+                push_verbatim_span_character(&mut word_spans, style, character);
+                word_width += character_display_width(character);
+
+                continue;
+            }
+
+            flush_inline_word(
+                &mut wrapped_lines,
+                &mut current_spans,
+                &mut current_width,
+                &mut word_spans,
+                &mut word_width,
+                pending_space_style.take(),
+                width,
+            );
+            if current_width > 0 {
+                pending_space_style = Some(style);
+            }
+        }
+    }
+
+    flush_inline_word(
+        &mut wrapped_lines,
+        &mut current_spans,
+        &mut current_width,
+        &mut word_spans,
+        &mut word_width,
+        pending_space_style,
+        width,
+    );
+
+    if !current_spans.is_empty() {
+        wrapped_lines.push(Line::from(current_spans));
+    }
+
+    if wrapped_lines.is_empty() {
+        wrapped_lines.push(Line::from(""));
+    }
+
+    wrapped_lines
+}
+
+/// Appends one buffered word to the current line, breaking before it when it
+/// does not fit and hard-wrapping words wider than `width`.
+fn flush_inline_word(
+    wrapped_lines: &mut Vec<Line<'static>>,
+    current_spans: &mut Vec<Span<'static>>,
+    current_width: &mut usize,
+    word_spans: &mut Vec<Span<'static>>,
+    word_width: &mut usize,
+    space_style: Option<Style>,
+    width: usize,
+) {
+    if word_spans.is_empty() {
+        return;
+    }
+
+    let space_width = usize::from(space_style.is_some() && *current_width > 0);
+    if width > 0 && *current_width > 0 && *current_width + space_width + *word_width > width {
+        wrapped_lines.push(Line::from(std::mem::take(current_spans)));
+        *current_width = 0;
+    } else if let Some(space_style) = space_style
+        && *current_width > 0
+    {
+        push_verbatim_span_character(current_spans, space_style, ' ');
+        *current_width += 1;
+    }
+
+    for span in std::mem::take(word_spans) {
+        for character in span.content.chars() {
+            if width == 0 {
+                push_verbatim_span_character(current_spans, span.style, character);
+                *current_width += character_display_width(character);
+            } else {
+                push_character_with_hard_wrap(
+                    wrapped_lines,
+                    current_spans,
+                    current_width,
+                    span.style,
+                    character,
+                    width,
+                );
+            }
+        }
+    }
+
+    *word_width = 0;
+}
+
+/// Lays out one table as aligned rows separated by box-drawing borders.
+///
+/// Columns keep their natural width when the table fits. Otherwise narrow
+/// columns keep their width and the remaining space is shared evenly across
+/// wider columns, whose cells then wrap.
+fn render_table_lines(table: MarkdownTable, width: usize) -> Vec<Vec<Span<'static>>> {
+    let MarkdownTable {
+        alignments,
+        header_row,
+        rows,
+        ..
+    } = table;
+    let column_count = std::iter::once(&header_row)
+        .chain(&rows)
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(alignments.len());
+    if column_count == 0 {
+        return Vec::new();
+    }
+
+    let header_row = header_row
+        .into_iter()
+        .map(|cell| {
+            cell.into_iter()
+                .map(|span| {
+                    let style = span.style.add_modifier(Modifier::BOLD);
+
+                    span.style(style)
+                })
+                .collect()
+        })
+        .collect::<Vec<Vec<Span<'static>>>>();
+    let natural_widths = (0..column_count)
+        .map(|column_index| {
+            std::iter::once(&header_row)
+                .chain(&rows)
+                .filter_map(|row| row.get(column_index))
+                .map(|cell| wrap_inline_spans(cell.clone(), 0)[0].width())
+                .max()
+                .unwrap_or(0)
+                .max(1)
+        })
+        .collect::<Vec<_>>();
+    let separator_width = TABLE_COLUMN_SEPARATOR.chars().count() * (column_count - 1);
+    let column_widths = if width == 0 {
+        natural_widths
+    } else {
+        fit_table_column_widths(&natural_widths, width.saturating_sub(separator_width))
+    };
+
+    let mut table_lines = render_table_row(header_row, &column_widths, &alignments);
+    let header_rule = column_widths
+        .iter()
+        .map(|column_width| "─".repeat(*column_width))
+        .collect::<Vec<_>>()
+        .join("─┼─");
+    table_lines.push(vec![Span::styled(header_rule, table_border_style())]);
+    for row in rows {
+        table_lines.extend(render_table_row(row, &column_widths, &alignments));
+    }
+
+    table_lines
+}
+
+/// Fits natural column widths into `available_width` cells.
+fn fit_table_column_widths(natural_widths: &[usize], available_width: usize) -> Vec<usize> {
+    if natural_widths.iter().sum::<usize>() <= available_width {
+        return natural_widths.to_vec();
+    }
+
+    let mut column_widths = natural_widths.to_vec();
+    let mut remaining_width = available_width.max(natural_widths.len());
+    let mut open_columns = (0..natural_widths.len()).collect::<Vec<_>>();
+    loop {
+        let share = remaining_width / open_columns.len();
+        let (fitting_columns, wide_columns): (Vec<usize>, Vec<usize>) = open_columns
+            .iter()
+            .partition(|column_index| natural_widths[**column_index] <= share);
+        if fitting_columns.is_empty() || wide_columns.is_empty() {
+            let extra_width = remaining_width % open_columns.len();
+            for (position, column_index) in open_columns.iter().enumerate() {
+                column_widths[*column_index] = share + usize::from(position < extra_width);
+            }
+
+            return column_widths;
+        }
+
+        for column_index in fitting_columns {
+            remaining_width -= natural_widths[column_index];
+        }
+        open_columns = wide_columns;
+    }
+}
+
+/// Renders one table row, wrapping each cell to its column width.
+///
+/// The last column is not right-padded so rows carry no trailing blanks.
+fn render_table_row(
+    row: Vec<Vec<Span<'static>>>,
+    column_widths: &[usize],
+    alignments: &[Alignment],
+) -> Vec<Vec<Span<'static>>> {
+    let mut cells = row.into_iter();
+    let wrapped_cells = column_widths
+        .iter()
+        .map(|column_width| wrap_inline_spans(cells.next().unwrap_or_default(), *column_width))
+        .collect::<Vec<_>>();
+    let row_height = wrapped_cells.iter().map(Vec::len).max().unwrap_or(1);
+
+    (0..row_height)
+        .map(|line_index| {
+            let mut spans = Vec::new();
+            for (column_index, wrapped_cell) in wrapped_cells.iter().enumerate() {
+                if column_index > 0 {
+                    spans.push(Span::styled(TABLE_COLUMN_SEPARATOR, table_border_style()));
+                }
+
+                let cell_line = wrapped_cell.get(line_index).cloned().unwrap_or_default();
+                let padding = column_widths[column_index].saturating_sub(cell_line.width());
+                let (left_padding, right_padding) = match alignments.get(column_index) {
+                    Some(Alignment::Right) => (padding, 0),
+                    Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+                    _ => (0, padding),
+                };
+
+                if left_padding > 0 {
+                    spans.push(Span::raw(" ".repeat(left_padding)));
+                }
+                spans.extend(cell_line.spans);
+                if right_padding > 0 && column_index + 1 < column_widths.len() {
+                    spans.push(Span::raw(" ".repeat(right_padding)));
+                }
+            }
+
+            spans
+        })
+        .collect()
+}
+
 /// Resolves one prompt block style from the first prefixed line.
 fn prompt_block_kind(raw_line: &str) -> PromptBlockKind {
     let is_clarification_header = raw_line
@@ -382,94 +1136,6 @@ fn pad_line_to_width(mut line: Line<'static>, width: usize, style: Style) -> Lin
     line
 }
 
-fn render_markdown_line(raw_line: &str, width: usize) -> Vec<Line<'static>> {
-    if raw_line.is_empty() {
-        return vec![Line::from("")];
-    }
-
-    if raw_line.starts_with(USER_PROMPT_PREFIX) {
-        return render_prompt_block_line(raw_line, true, width, PromptBlockKind::UserPrompt);
-    }
-
-    if let Some((level, content)) = parse_heading(raw_line) {
-        return render_inline_line(content, heading_style(level), width);
-    }
-
-    if is_horizontal_rule(raw_line) {
-        return vec![horizontal_rule_line(width)];
-    }
-
-    if let Some(content) = raw_line.strip_prefix("> ") {
-        return render_prefixed_inline_line(
-            "│ ",
-            "│ ",
-            content,
-            blockquote_prefix_style(),
-            Style::default().fg(Color::Gray),
-            width,
-        );
-    }
-
-    if let Some(content) = parse_bullet_content(raw_line) {
-        return render_prefixed_inline_line(
-            "- ",
-            "  ",
-            content,
-            list_prefix_style(),
-            Style::default(),
-            width,
-        );
-    }
-
-    if let Some((prefix, content)) = parse_numbered_content(raw_line) {
-        let continuation_prefix = " ".repeat(prefix.chars().count());
-
-        return render_prefixed_inline_line(
-            &prefix,
-            &continuation_prefix,
-            content,
-            list_prefix_style(),
-            Style::default(),
-            width,
-        );
-    }
-
-    render_inline_line(raw_line, Style::default(), width)
-}
-
-fn render_prefixed_inline_line(
-    prefix: &str,
-    continuation_prefix: &str,
-    content: &str,
-    prefix_style: Style,
-    content_style: Style,
-    width: usize,
-) -> Vec<Line<'static>> {
-    let prefix_width = prefix.chars().count();
-    if width <= prefix_width {
-        let mut spans = vec![Span::styled(prefix.to_string(), prefix_style)];
-        spans.extend(parse_inline_spans(content, content_style));
-
-        return wrap_styled_line(spans, width);
-    }
-
-    let wrapped_content = render_inline_line(content, content_style, width - prefix_width);
-    let mut lines = Vec::with_capacity(wrapped_content.len());
-
-    for (index, line) in wrapped_content.into_iter().enumerate() {
-        let marker = if index == 0 {
-            prefix
-        } else {
-            continuation_prefix
-        };
-        let mut spans = vec![Span::styled(marker.to_string(), prefix_style)];
-        spans.extend(line.spans);
-        lines.push(Line::from(spans));
-    }
-
-    lines
-}
-
 /// Wraps one verbatim line while preserving a fixed prefix for wrapped
 /// continuations.
 ///
@@ -601,14 +1267,28 @@ fn clarification_prompt_spans(content: &str, content_style: Style) -> Vec<Span<'
     spans
 }
 
-fn render_inline_line(content: &str, base_style: Style, width: usize) -> Vec<Line<'static>> {
-    let inline_spans = parse_inline_spans(content, base_style);
+/// Renders one fenced-code line, applying syntax colors when the fence
+/// language resolved to a grammar.
+fn render_code_line(
+    raw_line: &str,
+    highlighter: Option<&mut CodeHighlighter>,
+    width: usize,
+) -> Vec<Line<'static>> {
+    let Some(highlighter) = highlighter else {
+        return wrap_verbatim_line(raw_line, code_block_style(), width);
+    };
+
+    if raw_line.is_empty() {
+        return vec![Line::from("")];
+    }
 
-    wrap_styled_line(inline_spans, width)
-}
+    let spans = highlighter
+        .highlight_line(raw_line, code_block_style())
+        .into_iter()
+        .map(|(range, style)| Span::styled(raw_line[range].to_string(), style))
+        .collect();
 
-fn render_code_line(raw_line: &str, width: usize) -> Vec<Line<'static>> {
-    wrap_verbatim_line(raw_line, code_block_style(), width)
+    wrap_verbatim_spans(spans, width)
 }
 
 fn render_stats_line(raw_line: &str, width: usize) -> Vec<Line<'static>> {
@@ -943,42 +1623,6 @@ fn flush_literal_span(spans: &mut Vec<Span<'static>>, literal: &mut String, styl
     spans.push(Span::styled(std::mem::take(literal), style));
 }
 
-fn parse_heading(raw_line: &str) -> Option<(usize, &str)> {
-    if let Some(content) = raw_line.strip_prefix("#### ") {
-        return Some((4, content));
-    }
-
-    if let Some(content) = raw_line.strip_prefix("### ") {
-        return Some((3, content));
-    }
-
-    if let Some(content) = raw_line.strip_prefix("## ") {
-        return Some((2, content));
-    }
-
-    raw_line.strip_prefix("# ").map(|content| (1, content))
-}
-
-fn parse_bullet_content(raw_line: &str) -> Option<&str> {
-    if let Some(content) = raw_line.strip_prefix("- ") {
-        return Some(content);
-    }
-
-    raw_line.strip_prefix("* ")
-}
-
-fn parse_numbered_content(raw_line: &str) -> Option<(String, &str)> {
-    let digit_count = raw_line.chars().take_while(char::is_ascii_digit).count();
-    if digit_count == 0 {
-        return None;
-    }
-
-    let (digits, suffix) = raw_line.split_at(digit_count);
-    let content = suffix.strip_prefix(". ")?;
-
-    Some((format!("{digits}. "), content))
-}
-
 /// Parses one clarification question line like `1. Q: Need tests?`.
 fn parse_clarification_question_line(raw_line: &str) -> Option<(String, &str)> {
     let digit_count = raw_line.chars().take_while(char::is_ascii_digit).count();
@@ -992,38 +1636,12 @@ fn parse_clarification_question_line(raw_line: &str) -> Option<(String, &str)> {
     Some((format!("{digits}. "), content))
 }
 
-fn opening_fence_block_state(raw_line: &str) -> BlockState {
-    if is_stats_fence(raw_line) {
-        return BlockState::FencedStats;
-    }
-
-    BlockState::FencedCode
-}
-
-fn is_fence_delimiter(raw_line: &str) -> bool {
-    raw_line.trim().starts_with("```")
-}
-
-fn is_stats_fence(raw_line: &str) -> bool {
-    raw_line.trim().starts_with("```stats")
-}
-
 fn parse_stats_metric_line(raw_line: &str) -> Option<(&str, &str)> {
     let (metric, value) = raw_line.split_once('\t')?;
 
     Some((metric, value))
 }
 
-fn is_horizontal_rule(raw_line: &str) -> bool {
-    let trimmed = raw_line.trim();
-    if trimmed.len() < 3 {
-        return false;
-    }
-
-    trimmed.chars().all(|character| character == '-')
-        || trimmed.chars().all(|character| character == '*')
-}
-
 fn horizontal_rule_line(width: usize) -> Line<'static> {
     if width == 0 {
         return Line::from("");
@@ -1037,44 +1655,61 @@ fn horizontal_rule_line(width: usize) -> Line<'static> {
 
 fn heading_style(level: usize) -> Style {
     let color = match level {
-        1 => Color::Cyan,
-        2 => Color::Blue,
-        3 => Color::Green,
-        _ => Color::Yellow,
+        1 => palette::accent(),
+        2 => palette::info(),
+        3 => palette::success(),
+        _ => palette::warning(),
     };
 
     Style::default().fg(color).add_modifier(Modifier::BOLD)
 }
 
 fn list_prefix_style() -> Style {
-    Style::default().fg(Color::DarkGray)
+    Style::default().fg(palette::text_subtle())
 }
 
 fn blockquote_prefix_style() -> Style {
     Style::default()
-        .fg(Color::DarkGray)
+        .fg(palette::text_subtle())
         .add_modifier(Modifier::DIM)
 }
 
 fn horizontal_rule_style() -> Style {
     Style::default()
-        .fg(Color::DarkGray)
+        .fg(palette::text_subtle())
         .add_modifier(Modifier::DIM)
 }
 
+fn table_border_style() -> Style {
+    Style::default().fg(palette::text_subtle())
+}
+
+fn html_style() -> Style {
+    Style::default().fg(palette::text_muted())
+}
+
+/// Returns the style for link text layered over the surrounding style.
+fn link_style(base_style: Style) -> Style {
+    base_style
+        .fg(palette::info())
+        .add_modifier(Modifier::UNDERLINED)
+}
+
 fn code_block_style() -> Style {
-    Style::default().fg(Color::Gray).bg(Color::Black)
+    Style::default()
+        .fg(palette::text_muted())
+        .bg(palette::surface_overlay())
 }
 
 fn stats_metric_style() -> Style {
     Style::default()
-        .fg(Color::Cyan)
+        .fg(palette::accent())
         .add_modifier(Modifier::BOLD)
 }
 
 fn stats_section_style() -> Style {
     Style::default()
-        .fg(Color::Green)
+        .fg(palette::success())
         .add_modifier(Modifier::BOLD)
 }
 
@@ -1084,13 +1719,13 @@ fn stats_value_style() -> Style {
 
 /// Returns the background color used for clarification prompt blocks.
 fn clarification_background_color() -> Color {
-    Color::Rgb(28, 38, 48)
+    palette::surface_info()
 }
 
 /// Returns the style for the visible `CLARIFICATION_PROMPT_PREFIX` marker.
 fn clarification_prompt_prefix_style() -> Style {
     Style::default()
-        .fg(Color::Yellow)
+        .fg(palette::warning())
         .bg(clarification_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1098,7 +1733,7 @@ fn clarification_prompt_prefix_style() -> Style {
 /// Returns the style for clarification heading text.
 fn clarification_header_style() -> Style {
     Style::default()
-        .fg(Color::LightYellow)
+        .fg(palette::warning_soft())
         .bg(clarification_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1106,7 +1741,7 @@ fn clarification_header_style() -> Style {
 /// Returns the style for numbered clarification question indexes.
 fn clarification_question_index_style() -> Style {
     Style::default()
-        .fg(Color::White)
+        .fg(palette::text())
         .bg(clarification_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1114,7 +1749,7 @@ fn clarification_question_index_style() -> Style {
 /// Returns the style for `Q:` labels in clarification blocks.
 fn clarification_question_label_style() -> Style {
     Style::default()
-        .fg(Color::Cyan)
+        .fg(palette::accent())
         .bg(clarification_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1122,7 +1757,7 @@ fn clarification_question_label_style() -> Style {
 /// Returns the style for `A:` labels in clarification blocks.
 fn clarification_answer_label_style() -> Style {
     Style::default()
-        .fg(Color::Green)
+        .fg(palette::success())
         .bg(clarification_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1130,19 +1765,19 @@ fn clarification_answer_label_style() -> Style {
 /// Returns the style for clarification text content.
 fn clarification_content_style() -> Style {
     Style::default()
-        .fg(Color::Gray)
+        .fg(palette::text_muted())
         .bg(clarification_background_color())
 }
 
 /// Returns the background color used for rendered user prompt blocks.
 fn user_prompt_background_color() -> Color {
-    Color::DarkGray
+    palette::surface()
 }
 
 /// Returns the style for the visible `USER_PROMPT_PREFIX` marker.
 fn user_prompt_prefix_style() -> Style {
     Style::default()
-        .fg(Color::Cyan)
+        .fg(palette::accent())
         .bg(user_prompt_background_color())
         .add_modifier(Modifier::BOLD)
 }
@@ -1155,7 +1790,7 @@ fn user_prompt_content_style() -> Style {
 /// Returns the style for one `@` lookup token within user prompt content.
 fn user_prompt_lookup_style() -> Style {
     Style::default()
-        .fg(Color::LightBlue)
+        .fg(palette::info())
         .bg(user_prompt_background_color())
 }
 
@@ -1165,7 +1800,7 @@ fn prompt_block_continuation_padding() -> String {
 }
 
 fn inline_code_style() -> Style {
-    Style::default().fg(Color::Yellow)
+    Style::default().fg(palette::warning())
 }
 
 fn find_matching_backtick(characters: &[char], start_index: usize) -> Option<usize> {
@@ -1287,6 +1922,24 @@ mod tests {
         }));
     }
 
+    #[test]
+    fn test_is_user_prompt_start_line_matches_only_user_prompt_markers() {
+        // Arrange
+        let input = " › first\n   second\n\nanswer\n\n › Clarifications:\n   1. Q: Why?";
+
+        // Act
+        let lines = render_markdown(input, 80);
+        let prompt_line_indices = lines
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| is_user_prompt_start_line(line))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        // Assert
+        assert_eq!(prompt_line_indices, vec![1]);
+    }
+
     #[test]
     fn test_render_markdown_keeps_prompt_continuation_line_verbatim() {
         // Arrange
@@ -1546,9 +2199,71 @@ mod tests {
         // Assert
         assert_eq!(lines.len(), 1);
         assert_eq!(lines[0].to_string(), "let value = **raw**;");
+        assert!(
+            lines[0]
+                .spans
+                .iter()
+                .all(|span| span.style.bg == code_block_style().bg)
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_highlights_fenced_code_by_info_string() {
+        // Arrange
+        let input = "```rust
+fn main() {}
+```";
+
+        // Act
+        let lines = render_markdown(input, 80);
+
+        // Assert
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].to_string(), "fn main() {}");
+        assert_eq!(lines[0].spans[0].content, "fn");
+        assert_ne!(lines[0].spans[0].style, code_block_style());
+        assert!(lines[0].spans.iter().any(|span| span.content == "main"
+            && span.style.fg == Some(crate::ui::style::palette::info())));
+    }
+
+    #[test]
+    fn test_render_markdown_keeps_flat_code_style_for_unknown_language() {
+        // Arrange
+        let input = "```not-a-language
+fn main() {}
+```";
+
+        // Act
+        let lines = render_markdown(input, 80);
+
+        // Assert
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].spans.len(), 1);
         assert_eq!(lines[0].spans[0].style, code_block_style());
     }
 
+    #[test]
+    fn test_render_markdown_wraps_highlighted_code_lines() {
+        // Arrange
+        let input = "```python
+value = \"abcdef\"
+```";
+
+        // Act
+        let lines = render_markdown(input, 8);
+
+        // Assert
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].to_string(), "value = ");
+        assert_eq!(lines[1].to_string(), "\"abcdef\"");
+        assert!(
+            lines[1]
+                .spans
+                .iter()
+                .all(|span| span.style.fg == Some(crate::ui::style::palette::success()))
+        );
+    }
+
     #[test]
     fn test_render_markdown_treats_unclosed_fence_as_code() {
         // Arrange
@@ -1655,6 +2370,119 @@ mod tests {
         assert_eq!(lines[0].spans[0].style, horizontal_rule_style());
     }
 
+    #[test]
+    fn test_render_markdown_renders_nested_lists_and_quotes() {
+        // Arrange
+        let input = "- parent\n  - child\n    > quoted\n2. next";
+
+        // Act
+        let lines = render_markdown(input, 40);
+
+        // Assert
+        let rendered = lines.iter().map(Line::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec!["- parent", "  - child", "    │ quoted", "", "2. next"]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_separates_loose_list_items() {
+        // Arrange
+        let input = "- first\n\n- second";
+
+        // Act
+        let lines = render_markdown(input, 40);
+
+        // Assert
+        let rendered = lines.iter().map(Line::to_string).collect::<Vec<_>>();
+        assert_eq!(rendered, vec!["- first", "", "- second"]);
+    }
+
+    #[test]
+    fn test_render_markdown_lays_out_table_with_aligned_columns() {
+        // Arrange
+        let input = "| Name | Count |\n| :--- | ---: |\n| alpha | 7 |\n| b | 12 |";
+
+        // Act
+        let lines = render_markdown(input, 40);
+
+        // Assert
+        let rendered = lines.iter().map(Line::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec![
+                "Name  │ Count",
+                "──────┼──────",
+                "alpha │     7",
+                "b     │    12",
+            ]
+        );
+        assert!(
+            lines[0].spans[0]
+                .style
+                .add_modifier
+                .contains(Modifier::BOLD)
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_fits_wide_table_to_width() {
+        // Arrange
+        let input = "| id | description |\n| --- | --- |\n| 1 | one two three four five |";
+
+        // Act
+        let lines = render_markdown(input, 16);
+
+        // Assert
+        assert!(lines.iter().all(|line| line.width() <= 16));
+        let rendered = lines.iter().map(Line::to_string).collect::<Vec<_>>();
+        assert_eq!(
+            rendered,
+            vec![
+                "id │ description",
+                "───┼────────────",
+                "1  │ one two",
+                "   │ three four",
+                "   │ five",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_markdown_tags_reference_link_text_with_target() {
+        // Arrange
+        let input = "See [docs][guide].\n\n[guide]: https://example.com/guide";
+
+        // Act
+        let lines = render_markdown(input, 40);
+
+        // Assert
+        assert_eq!(lines.len(), 1);
+        let link_span = lines[0]
+            .spans
+            .iter()
+            .find(|span| span.style.add_modifier.contains(Modifier::UNDERLINED))
+            .expect("link span");
+        assert_eq!(
+            link_span.content,
+            hyperlink::link_text("docs", "https://example.com/guide")
+        );
+        assert_eq!(lines[0].width(), "See docs.".len());
+    }
+
+    #[test]
+    fn test_render_markdown_keeps_relative_link_text_untagged() {
+        // Arrange
+        let input = "Open [main](src/main.rs) first";
+
+        // Act
+        let lines = render_markdown(input, 40);
+
+        // Assert
+        assert_eq!(lines[0].to_string(), "Open main first");
+    }
+
     #[test]
     fn test_markdown_render_cache_retains_multiple_entries() {
         // Arrange
@@ -1671,6 +2499,25 @@ mod tests {
         assert_eq!(cache.entries.borrow().len(), 2);
     }
 
+    #[test]
+    fn test_markdown_render_cache_rerenders_after_theme_switch() {
+        // Arrange
+        let cache = MarkdownRenderCache::default();
+        theme::apply(theme::ThemeMode::Dark);
+        let dark_lines = cache.render("# Heading", 24);
+
+        // Act
+        theme::apply(theme::ThemeMode::Light);
+        let light_lines = cache.render("# Heading", 24);
+
+        // Assert
+        assert_ne!(
+            dark_lines[0].spans[0].style.fg,
+            light_lines[0].spans[0].style.fg
+        );
+        assert_eq!(light_lines, render_markdown("# Heading", 24));
+    }
+
     #[test]
     fn test_markdown_render_cache_evicts_least_recently_used_entry() {
         // Arrange
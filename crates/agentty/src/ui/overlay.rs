@@ -62,6 +62,8 @@ pub(crate) struct ViewInfoPopupRenderContext<'a> {
 
 /// Borrowed parameters for rendering the help overlay and its background page.
 pub(crate) struct HelpOverlayRenderContext<'a> {
+    /// Shared diff highlight cache reused by the restored diff page.
+    pub(crate) diff_highlight_cache: &'a page::diff::DiffHighlightCache,
     /// Help overlay content and the background page to restore behind it.
     pub(crate) help_context: &'a HelpContext,
     /// Shared tab-list state rendered behind list-backed help overlays.
@@ -105,6 +107,56 @@ pub(crate) fn render_confirmation_overlay(
     .render(f, area);
 }
 
+/// Renders the list background and forge issue picker overlay.
+pub(crate) fn render_issue_picker_overlay(
+    f: &mut Frame,
+    area: Rect,
+    mode: &AppMode,
+    list_background: ListBackgroundRenderContext<'_>,
+    wall_clock_unix_seconds: i64,
+) {
+    render_list_background(f, area, list_background, wall_clock_unix_seconds);
+    render_overlay_backdrop(f, area);
+
+    let AppMode::IssuePicker {
+        issues,
+        selected_issue_index,
+        status,
+    } = mode
+    else {
+        unreachable!("matched issue picker mode above");
+    };
+
+    component::issue_picker_overlay::IssuePickerOverlay::new(issues, status)
+        .selected_issue_index(*selected_issue_index)
+        .render(f, area);
+}
+
+/// Renders the list background and global session search overlay.
+pub(crate) fn render_session_search_overlay(
+    f: &mut Frame,
+    area: Rect,
+    mode: &AppMode,
+    list_background: ListBackgroundRenderContext<'_>,
+    wall_clock_unix_seconds: i64,
+) {
+    render_list_background(f, area, list_background, wall_clock_unix_seconds);
+    render_overlay_backdrop(f, area);
+
+    let AppMode::SessionSearch {
+        hits,
+        input,
+        selected_hit_index,
+    } = mode
+    else {
+        unreachable!("matched session search mode above");
+    };
+
+    component::session_search_overlay::SessionSearchOverlay::new(input, hits)
+        .selected_hit_index(*selected_hit_index)
+        .render(f, area);
+}
+
 /// Renders the list background and sync informational popup overlay.
 pub(crate) fn render_sync_blocked_popup(
     f: &mut Frame,
@@ -161,7 +213,6 @@ pub(crate) fn render_view_info_popup(
             .get(&restore_view.session_id)
             .map(std::string::String::as_str);
         page::session_chat::SessionChatPage::new(page::session_chat::SessionChatPageInput {
-            active_prompt_output: None,
             active_progress,
             default_reasoning_level,
             markdown_render_cache,
@@ -203,24 +254,10 @@ pub(crate) fn sync_popup_message(
 
 /// Renders help overlay above the context-specific background page.
 pub(crate) fn render_help(f: &mut Frame, area: Rect, context: HelpOverlayRenderContext<'_>) {
-    let HelpOverlayRenderContext {
-        help_context,
-        list_background,
-        markdown_render_cache,
-        scroll_offset,
-        session_progress_messages,
-        wall_clock_unix_seconds,
-    } = context;
+    let help_context = context.help_context;
+    let scroll_offset = context.scroll_offset;
 
-    render_help_background(
-        f,
-        area,
-        help_context,
-        list_background,
-        markdown_render_cache,
-        session_progress_messages,
-        wall_clock_unix_seconds,
-    );
+    render_help_background(f, area, context);
     render_overlay_backdrop(f, area);
 
     component::help_overlay::HelpOverlay::new(help_context)
@@ -294,8 +331,8 @@ pub(crate) fn overlay_block(title: &str, border_color: Color) -> Block<'static>
 /// Returns the dimmed backdrop style applied behind overlay popups.
 fn overlay_backdrop_style() -> Style {
     Style::default()
-        .bg(palette::SURFACE_OVERLAY)
-        .fg(palette::TEXT_MUTED)
+        .bg(palette::surface_overlay())
+        .fg(palette::text_muted())
 }
 
 /// Returns the shared title text style for overlay frame headers.
@@ -368,15 +405,16 @@ fn resolve_help_background<'a>(
 }
 
 /// Renders background content behind help based on the source `HelpContext`.
-fn render_help_background(
-    f: &mut Frame,
-    area: Rect,
-    help_context: &HelpContext,
-    list_background: ListBackgroundRenderContext<'_>,
-    markdown_render_cache: &markdown::MarkdownRenderCache,
-    session_progress_messages: &HashMap<SessionId, String>,
-    wall_clock_unix_seconds: i64,
-) {
+fn render_help_background(f: &mut Frame, area: Rect, context: HelpOverlayRenderContext<'_>) {
+    let HelpOverlayRenderContext {
+        diff_highlight_cache,
+        help_context,
+        list_background,
+        markdown_render_cache,
+        scroll_offset: _,
+        session_progress_messages,
+        wall_clock_unix_seconds,
+    } = context;
     let sessions = list_background.sessions;
 
     match resolve_help_background(help_context, sessions) {
@@ -397,12 +435,12 @@ fn render_help_background(
                 review_text: review_text.clone(),
                 session_id: session_id.into(),
                 scroll_offset,
+                transcript_search: None,
             };
             let active_progress = session_progress_messages
                 .get(session_id)
                 .map(std::string::String::as_str);
             page::session_chat::SessionChatPage::new(page::session_chat::SessionChatPageInput {
-                active_prompt_output: None,
                 active_progress,
                 default_reasoning_level: list_background.settings.reasoning_level,
                 markdown_render_cache,
@@ -425,6 +463,7 @@ fn render_help_background(
             scroll_offset,
             file_explorer_selected_index,
         )
+        .highlight_cache(diff_highlight_cache)
         .render(f, area),
         None => {}
     }
@@ -664,7 +703,7 @@ mod tests {
             .content()
             .first()
             .expect("buffer should contain at least one cell");
-        assert_eq!(first_cell.bg, palette::SURFACE_OVERLAY);
-        assert_eq!(first_cell.fg, palette::TEXT_MUTED);
+        assert_eq!(first_cell.bg, palette::surface_overlay());
+        assert_eq!(first_cell.fg, palette::text_muted());
     }
 }
@@ -1,5 +1,6 @@
 //! Full-screen UI page modules.
 
+pub mod dashboard;
 pub mod diff;
 pub mod fyi;
 pub mod project_list;
@@ -0,0 +1,438 @@
+use std::collections::HashMap;
+
+use ratatui::Frame;
+use ratatui::layout::{Alignment, Constraint, Layout, Position, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::domain::session::{Session, SessionId, Status};
+use crate::ui::layout::PageAreas;
+use crate::ui::state::help_action;
+use crate::ui::util::{
+    format_duration_compact, format_token_count, inline_text, truncate_with_ellipsis, wrap_lines,
+};
+use crate::ui::{Page, layout, style};
+
+/// Narrowest tile width before the grid drops a column.
+const TILE_MIN_WIDTH: u16 = 40;
+/// Tile height, including borders.
+const TILE_HEIGHT: u16 = 8;
+/// Placeholder shown while no session is running.
+const EMPTY_DASHBOARD_MESSAGE: &str = "No running sessions. Start one from the Sessions tab.";
+/// Placeholder shown in a tile before the agent reports any progress.
+const WAITING_FOR_OUTPUT_MESSAGE: &str = "Waiting for agent output...";
+
+/// Grid of live tiles for every running session in the active project.
+pub struct DashboardPage<'a> {
+    selected_session_index: Option<usize>,
+    session_progress_messages: &'a HashMap<SessionId, String>,
+    sessions: &'a [Session],
+    wall_clock_unix_seconds: i64,
+}
+
+impl<'a> DashboardPage<'a> {
+    /// Creates a dashboard renderer from live session snapshots, their latest
+    /// progress messages, and the raw index of the highlighted tile.
+    pub fn new(
+        sessions: &'a [Session],
+        session_progress_messages: &'a HashMap<SessionId, String>,
+        selected_session_index: Option<usize>,
+        wall_clock_unix_seconds: i64,
+    ) -> Self {
+        Self {
+            selected_session_index,
+            session_progress_messages,
+            sessions,
+            wall_clock_unix_seconds,
+        }
+    }
+}
+
+impl Page for DashboardPage<'_> {
+    /// Renders the running-session grid and footer.
+    fn render(&mut self, f: &mut Frame, area: Rect) {
+        let PageAreas {
+            footer_area,
+            main_area,
+        } = layout::page_areas(area);
+        let tiles = dashboard_tiles(self.sessions, self.selected_session_index, main_area);
+
+        if tiles.is_empty() {
+            let placeholder = Paragraph::new(EMPTY_DASHBOARD_MESSAGE)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(style::palette::text_muted()))
+                .block(Block::default().borders(Borders::ALL).title("Dashboard"));
+            f.render_widget(placeholder, main_area);
+        }
+
+        for tile in &tiles {
+            self.render_tile(f, tile);
+        }
+
+        self.render_footer(f, footer_area);
+    }
+}
+
+impl DashboardPage<'_> {
+    /// Renders one bordered session tile with status, timer, token usage, and
+    /// the latest agent thought.
+    fn render_tile(&self, f: &mut Frame, tile: &DashboardTile) {
+        let Some(session) = self.sessions.get(tile.session_index) else {
+            return;
+        };
+        let is_selected = self.selected_session_index == Some(tile.session_index);
+        let border_style = if is_selected {
+            Style::default()
+                .fg(style::palette::accent())
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(style::palette::border())
+        };
+        let title_width = usize::from(tile.area.width.saturating_sub(4));
+        let tile_title = format!(
+            " {} ",
+            truncate_with_ellipsis(&inline_text(session.display_title()), title_width)
+        );
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(border_style)
+            .title(tile_title);
+        let inner_area = block.inner(tile.area);
+
+        let mut lines = vec![
+            self.status_line(session),
+            Line::from(Span::styled(
+                format!(
+                    "Tokens: {} in | {} out",
+                    format_token_count(session.stats.input_tokens),
+                    format_token_count(session.stats.output_tokens)
+                ),
+                Style::default().fg(style::palette::text_muted()),
+            )),
+        ];
+        let thought_line_count = usize::from(inner_area.height).saturating_sub(lines.len());
+        lines.extend(self.thought_lines(
+            session,
+            usize::from(inner_area.width),
+            thought_line_count,
+        ));
+
+        f.render_widget(Paragraph::new(lines).block(block), tile.area);
+    }
+
+    /// Returns the colored status label followed by the elapsed timer and
+    /// model.
+    fn status_line(&self, session: &Session) -> Line<'static> {
+        let elapsed_label = format_duration_compact(
+            session.in_progress_duration_seconds(self.wall_clock_unix_seconds),
+        );
+
+        Line::from(vec![
+            Span::styled(
+                session.status.to_string(),
+                Style::default()
+                    .fg(style::status_color(session.status))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!(" | {elapsed_label} | {}", session.model.as_str()),
+                Style::default().fg(style::palette::text_muted()),
+            ),
+        ])
+    }
+
+    /// Returns the trailing wrapped lines of the latest agent thought that
+    /// fit in `line_count` rows.
+    ///
+    /// The live progress message wins; otherwise the last non-empty
+    /// transcript line stands in until the agent reports progress.
+    fn thought_lines(
+        &self,
+        session: &Session,
+        width: usize,
+        line_count: usize,
+    ) -> Vec<Line<'static>> {
+        if width == 0 || line_count == 0 {
+            return Vec::new();
+        }
+
+        let latest_thought = self
+            .session_progress_messages
+            .get(&session.id)
+            .map(String::as_str)
+            .or_else(|| {
+                session
+                    .output
+                    .lines()
+                    .rev()
+                    .find(|line| !line.trim().is_empty())
+            })
+            .map_or(WAITING_FOR_OUTPUT_MESSAGE, str::trim);
+        let wrapped_lines = wrap_lines(latest_thought, width);
+        let first_visible_line = wrapped_lines.len().saturating_sub(line_count);
+
+        wrapped_lines[first_visible_line..]
+            .iter()
+            .map(|line| {
+                Line::from(Span::styled(
+                    line.to_string(),
+                    Style::default().fg(style::palette::text()),
+                ))
+            })
+            .collect()
+    }
+
+    /// Renders the dashboard footer with actions and running-session totals.
+    fn render_footer(&self, f: &mut Frame, area: Rect) {
+        let footer_chunks =
+            Layout::horizontal([Constraint::Min(0), Constraint::Min(0)]).split(area);
+        let selected_session = self
+            .selected_session_index
+            .and_then(|session_index| self.sessions.get(session_index));
+        let help = Paragraph::new(help_action::footer_line(&footer_actions(selected_session)));
+        f.render_widget(help, footer_chunks[0]);
+
+        let running_sessions = dashboard_session_indexes(self.sessions)
+            .into_iter()
+            .filter_map(|session_index| self.sessions.get(session_index))
+            .collect::<Vec<_>>();
+        let total_input: u64 = running_sessions
+            .iter()
+            .map(|session| session.stats.input_tokens)
+            .sum();
+        let total_output: u64 = running_sessions
+            .iter()
+            .map(|session| session.stats.output_tokens)
+            .sum();
+        let summary = format!(
+            "Running: {} | Input: {} | Output: {}",
+            running_sessions.len(),
+            format_token_count(total_input),
+            format_token_count(total_output)
+        );
+        let totals = Paragraph::new(summary)
+            .style(Style::default().fg(style::palette::text_muted()))
+            .alignment(Alignment::Right);
+        f.render_widget(totals, footer_chunks[1]);
+    }
+}
+
+/// One dashboard tile placed in the grid.
+struct DashboardTile {
+    area: Rect,
+    session_index: usize,
+}
+
+/// Returns raw indexes of the sessions shown as dashboard tiles, in list
+/// order.
+pub fn dashboard_session_indexes(sessions: &[Session]) -> Vec<usize> {
+    sessions
+        .iter()
+        .enumerate()
+        .filter(|(_, session)| session.status.is_busy())
+        .map(|(session_index, _)| session_index)
+        .collect()
+}
+
+/// Returns the footer actions shown for the highlighted dashboard tile.
+pub(crate) fn footer_actions(selected_session: Option<&Session>) -> Vec<help_action::HelpAction> {
+    help_action::dashboard_footer_actions(
+        selected_session.is_some_and(|session| session.status == Status::InProgress),
+        selected_session.is_some(),
+    )
+}
+
+/// Returns the raw index of the session whose tile covers `position` when
+/// the dashboard page is drawn in `area`.
+pub fn dashboard_session_at(
+    sessions: &[Session],
+    selected_session_index: Option<usize>,
+    area: Rect,
+    position: Position,
+) -> Option<usize> {
+    dashboard_tiles(
+        sessions,
+        selected_session_index,
+        layout::page_areas(area).main_area,
+    )
+    .into_iter()
+    .find(|tile| tile.area.contains(position))
+    .map(|tile| tile.session_index)
+}
+
+/// Lays out the visible tiles in `main_area`.
+///
+/// Columns shrink to fit [`TILE_MIN_WIDTH`], and the grid scrolls by whole
+/// rows so the selected tile always stays on screen.
+fn dashboard_tiles(
+    sessions: &[Session],
+    selected_session_index: Option<usize>,
+    main_area: Rect,
+) -> Vec<DashboardTile> {
+    let session_indexes = dashboard_session_indexes(sessions);
+    let column_count = (main_area.width / TILE_MIN_WIDTH).max(1);
+    let visible_row_count = (main_area.height / TILE_HEIGHT).max(1);
+    let selected_position = selected_session_index
+        .and_then(|selected_index| {
+            session_indexes
+                .iter()
+                .position(|session_index| *session_index == selected_index)
+        })
+        .unwrap_or(0);
+    let selected_row = selected_position / usize::from(column_count);
+    let first_visible_row = selected_row.saturating_sub(usize::from(visible_row_count) - 1);
+
+    let row_areas = Layout::vertical(vec![
+        Constraint::Length(TILE_HEIGHT);
+        usize::from(visible_row_count)
+    ])
+    .split(main_area);
+    let cell_areas = row_areas.iter().flat_map(|row_area| {
+        Layout::horizontal(vec![
+            Constraint::Ratio(1, u32::from(column_count));
+            usize::from(column_count)
+        ])
+        .split(*row_area)
+        .to_vec()
+    });
+
+    session_indexes
+        .into_iter()
+        .skip(first_visible_row * usize::from(column_count))
+        .zip(cell_areas)
+        .map(|(session_index, area)| DashboardTile {
+            area,
+            session_index,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use ratatui::Terminal;
+    use ratatui::backend::TestBackend;
+
+    use super::*;
+    use crate::domain::session::tests::SessionFixtureBuilder;
+
+    /// Builds one titled session fixture with token usage for dashboard
+    /// tests.
+    fn session_fixture(session_id: &str, status: Status) -> Session {
+        let mut session = SessionFixtureBuilder::new()
+            .id(session_id)
+            .status(status)
+            .output("Earlier line\nReading the parser module\n")
+            .build();
+        session.title = Some(format!("Title {session_id}"));
+        session.stats.input_tokens = 1_500;
+        session.stats.output_tokens = 300;
+
+        session
+    }
+
+    /// Renders the dashboard into a test terminal and returns its text.
+    fn render_dashboard_text(
+        sessions: &[Session],
+        session_progress_messages: &HashMap<SessionId, String>,
+        selected_session_index: Option<usize>,
+    ) -> String {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).expect("failed to create terminal");
+        terminal
+            .draw(|frame| {
+                DashboardPage::new(
+                    sessions,
+                    session_progress_messages,
+                    selected_session_index,
+                    0,
+                )
+                .render(frame, frame.area());
+            })
+            .expect("failed to draw");
+
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(ratatui::buffer::Cell::symbol)
+            .collect()
+    }
+
+    #[test]
+    fn test_dashboard_session_indexes_keep_only_busy_sessions() {
+        // Arrange
+        let sessions = vec![
+            session_fixture("review", Status::Review),
+            session_fixture("running", Status::InProgress),
+            session_fixture("queued", Status::Queued),
+            session_fixture("done", Status::Done),
+        ];
+
+        // Act
+        let session_indexes = dashboard_session_indexes(&sessions);
+
+        // Assert
+        assert_eq!(session_indexes, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_render_shows_running_tiles_with_progress_and_tokens() {
+        // Arrange
+        let sessions = vec![
+            session_fixture("alpha", Status::InProgress),
+            session_fixture("beta", Status::Review),
+            session_fixture("gamma", Status::InProgress),
+        ];
+        let session_progress_messages = HashMap::from([(
+            SessionId::from("alpha"),
+            "Planning the refactor".to_string(),
+        )]);
+
+        // Act
+        let text = render_dashboard_text(&sessions, &session_progress_messages, Some(0));
+
+        // Assert
+        assert!(text.contains("Title alpha"));
+        assert!(text.contains("Title gamma"));
+        assert!(!text.contains("Title beta"));
+        assert!(text.contains("Planning the refactor"));
+        assert!(text.contains("Reading the parser module"));
+        assert!(text.contains("Tokens: 1.5k in | 300 out"));
+        assert!(text.contains("Running: 2"));
+    }
+
+    #[test]
+    fn test_render_shows_placeholder_without_running_sessions() {
+        // Arrange
+        let sessions = vec![session_fixture("beta", Status::Review)];
+
+        // Act
+        let text = render_dashboard_text(&sessions, &HashMap::new(), None);
+
+        // Assert
+        assert!(text.contains(EMPTY_DASHBOARD_MESSAGE));
+    }
+
+    #[test]
+    fn test_dashboard_session_at_resolves_tiles_across_grid_columns() {
+        // Arrange
+        let sessions = vec![
+            session_fixture("alpha", Status::InProgress),
+            session_fixture("beta", Status::Review),
+            session_fixture("gamma", Status::InProgress),
+        ];
+        let area = Rect::new(0, 0, 100, 24);
+
+        // Act
+        let first_tile = dashboard_session_at(&sessions, Some(0), area, Position::new(5, 3));
+        let second_tile = dashboard_session_at(&sessions, Some(0), area, Position::new(60, 3));
+        let empty_cell = dashboard_session_at(&sessions, Some(0), area, Position::new(5, 12));
+
+        // Assert
+        assert_eq!(first_tile, Some(0));
+        assert_eq!(second_tile, Some(2));
+        assert_eq!(empty_cell, None);
+    }
+}
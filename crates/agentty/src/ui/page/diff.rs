@@ -1,3 +1,7 @@
+use std::cell::RefCell;
+use std::ops::Range;
+use std::rc::Rc;
+
 use ratatui::Frame;
 use ratatui::layout::Rect;
 use ratatui::style::Style;
@@ -6,21 +10,84 @@ use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::domain::session::Session;
 use crate::ui::component::file_explorer::FileExplorer;
+use crate::ui::highlight::CodeHighlighter;
+use crate::ui::markdown::MarkdownRenderCache;
 use crate::ui::state::help_action;
 use crate::ui::util::{DiffLine, DiffLineKind, inline_text, parse_diff_lines, selected_diff_lines};
-use crate::ui::{Component, Page, diff_util, style};
+use crate::ui::{Component, Page, diff_util, style, theme};
 
 const SCROLL_X_OFFSET: u16 = 0;
 const SCROLLBAR_TRACK_SYMBOL: &str = "│";
 const SCROLLBAR_THUMB_SYMBOL: &str = "█";
 const WRAPPED_CHUNK_START_INDEX: usize = 0;
 
+/// Styled byte ranges covering the content of one diff line.
+type DiffLineSegments = Vec<(Range<usize>, Style)>;
+
+/// Caches syntax-highlighted segments for the most recently rendered diff.
+///
+/// Entries are keyed by a fingerprint of the diff text, which includes every
+/// file path, the selected file-explorer entry, and [`theme::generation`],
+/// since segments carry resolved theme colors. Redraws and the scrollbar
+/// layout pass reuse the segments instead of re-running the highlighter.
+#[derive(Default)]
+pub struct DiffHighlightCache {
+    entry: RefCell<Option<DiffHighlightCacheEntry>>,
+}
+
+/// Inputs that determine the highlighted segments of one diff view.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct DiffHighlightCacheKey {
+    diff_hash: u64,
+    diff_len: usize,
+    file_explorer_selected_index: usize,
+    theme_generation: u64,
+}
+
+/// Cached highlighted segments aligned with the rendered diff lines.
+struct DiffHighlightCacheEntry {
+    key: DiffHighlightCacheKey,
+    segments: Rc<[DiffLineSegments]>,
+}
+
+impl DiffHighlightCache {
+    /// Returns highlighted segments for `lines`, reusing the cached result
+    /// when the diff text, selection, and active theme are unchanged.
+    fn segments(
+        &self,
+        diff: &str,
+        file_explorer_selected_index: usize,
+        lines: &[DiffLine<'_>],
+    ) -> Rc<[DiffLineSegments]> {
+        let key = DiffHighlightCacheKey {
+            diff_hash: MarkdownRenderCache::hash_text(diff),
+            diff_len: diff.len(),
+            file_explorer_selected_index,
+            theme_generation: theme::generation(),
+        };
+        if let Some(entry) = self.entry.borrow().as_ref()
+            && entry.key == key
+        {
+            return Rc::clone(&entry.segments);
+        }
+
+        let segments: Rc<[DiffLineSegments]> = DiffPage::highlight_segments(lines).into();
+        *self.entry.borrow_mut() = Some(DiffHighlightCacheEntry {
+            key,
+            segments: Rc::clone(&segments),
+        });
+
+        segments
+    }
+}
+
 /// Renders the current session's git diff in a scrollable page.
 pub struct DiffPage<'a> {
     pub diff: String,
     pub scroll_offset: u16,
     pub session: &'a Session,
     pub file_explorer_selected_index: usize,
+    pub highlight_cache: Option<&'a DiffHighlightCache>,
 }
 
 impl<'a> DiffPage<'a> {
@@ -36,9 +103,18 @@ impl<'a> DiffPage<'a> {
             scroll_offset,
             session,
             file_explorer_selected_index,
+            highlight_cache: None,
         }
     }
 
+    /// Reuses highlighted segments from `cache` across frames.
+    #[must_use]
+    pub fn highlight_cache(mut self, cache: &'a DiffHighlightCache) -> Self {
+        self.highlight_cache = Some(cache);
+
+        self
+    }
+
     /// Renders the right-side diff panel with line-number gutters and
     /// change totals prefixed in the title.
     fn render_diff_content(
@@ -50,30 +126,34 @@ impl<'a> DiffPage<'a> {
         total_removed_lines: u64,
     ) {
         let title = Line::from(vec![
-            Span::styled(" (", Style::default().fg(style::palette::WARNING)),
+            Span::styled(" (", Style::default().fg(style::palette::warning())),
             Span::styled(
                 format!("+{total_added_lines}"),
-                Style::default().fg(style::palette::SUCCESS),
+                Style::default().fg(style::palette::success()),
             ),
-            Span::styled(" ", Style::default().fg(style::palette::WARNING)),
+            Span::styled(" ", Style::default().fg(style::palette::warning())),
             Span::styled(
                 format!("-{total_removed_lines}"),
-                Style::default().fg(style::palette::DANGER),
+                Style::default().fg(style::palette::danger()),
             ),
             Span::styled(
                 format!(") Diff — {} ", inline_text(self.session.display_title())),
-                Style::default().fg(style::palette::WARNING),
+                Style::default().fg(style::palette::warning()),
             ),
         ]);
 
+        let segments = match self.highlight_cache {
+            Some(cache) => cache.segments(&self.diff, self.file_explorer_selected_index, parsed),
+            None => Self::highlight_segments(parsed).into(),
+        };
         let mut layout = diff_util::diff_render_layout(parsed, area, false);
-        let mut lines = Self::build_diff_lines(parsed, layout);
+        let mut lines = Self::build_diff_lines(parsed, &segments, layout);
         let mut show_scrollbar =
             diff_util::diff_has_scrollable_overflow(lines.len(), layout.viewport_height);
 
         if show_scrollbar {
             layout = diff_util::diff_render_layout(parsed, area, true);
-            lines = Self::build_diff_lines(parsed, layout);
+            lines = Self::build_diff_lines(parsed, &segments, layout);
             show_scrollbar =
                 diff_util::diff_has_scrollable_overflow(lines.len(), layout.viewport_height);
         }
@@ -105,35 +185,85 @@ impl<'a> DiffPage<'a> {
     /// Returns the style used for added diff lines.
     fn addition_line_style() -> Style {
         Style::default()
-            .fg(style::palette::SUCCESS)
-            .bg(style::palette::SURFACE_SUCCESS)
+            .fg(style::palette::success())
+            .bg(style::palette::surface_success())
     }
 
     /// Returns the style used for removed diff lines.
     fn deletion_line_style() -> Style {
         Style::default()
-            .fg(style::palette::DANGER)
-            .bg(style::palette::SURFACE_DANGER)
+            .fg(style::palette::danger())
+            .bg(style::palette::surface_danger())
     }
 
-    /// Builds wrapped diff lines for the diff panel, optionally reserving one
-    /// column for the scrollbar thumb.
+    /// Returns the base style applied to the content of one diff line kind.
+    fn content_style(kind: DiffLineKind) -> Style {
+        match kind {
+            DiffLineKind::Addition => Self::addition_line_style(),
+            DiffLineKind::Deletion => Self::deletion_line_style(),
+            DiffLineKind::Context => Style::default().fg(style::palette::text_muted()),
+            DiffLineKind::FileHeader | DiffLineKind::HunkHeader => Style::default(),
+        }
+    }
+
+    /// Syntax-highlights the content of every diff line, restarting grammar
+    /// state at each file and hunk header.
+    ///
+    /// Returns one entry per parsed line; header lines get no segments.
+    fn highlight_segments(parsed: &[DiffLine<'_>]) -> Vec<DiffLineSegments> {
+        let mut segments = Vec::with_capacity(parsed.len());
+        let mut file_highlighter: Option<CodeHighlighter> = None;
+        let mut highlighter: Option<CodeHighlighter> = None;
+
+        for diff_line in parsed {
+            match diff_line.kind {
+                DiffLineKind::FileHeader => {
+                    if let Some(file_path) = diff_util::parse_diff_file_path(diff_line.content) {
+                        file_highlighter = CodeHighlighter::for_path(&file_path);
+                        highlighter.clone_from(&file_highlighter);
+                    }
+                    segments.push(Vec::new());
+                }
+                DiffLineKind::HunkHeader => {
+                    // Hunks are not contiguous, so grammar state restarts.
+                    highlighter.clone_from(&file_highlighter);
+                    segments.push(Vec::new());
+                }
+                DiffLineKind::Addition | DiffLineKind::Deletion | DiffLineKind::Context => {
+                    let content_style = Self::content_style(diff_line.kind);
+                    segments.push(match highlighter.as_mut() {
+                        Some(highlighter) => {
+                            highlighter.highlight_line(diff_line.content, content_style)
+                        }
+                        None => vec![(0..diff_line.content.len(), content_style)],
+                    });
+                }
+            }
+        }
+
+        segments
+    }
+
+    /// Builds wrapped diff lines for the diff panel from precomputed
+    /// highlight `segments`, optionally reserving one column for the
+    /// scrollbar thumb.
     fn build_diff_lines<'line>(
         parsed: &[DiffLine<'line>],
+        segments: &[DiffLineSegments],
         layout: diff_util::DiffRenderLayout,
     ) -> Vec<Line<'line>> {
-        let gutter_style = Style::default().fg(style::palette::TEXT_SUBTLE);
+        let gutter_style = Style::default().fg(style::palette::text_subtle());
         let mut lines: Vec<Line<'line>> = Vec::with_capacity(parsed.len());
 
-        for diff_line in parsed {
-            let (sign, content_style) = match diff_line.kind {
+        for (diff_line, line_segments) in parsed.iter().zip(segments) {
+            let sign = match diff_line.kind {
                 DiffLineKind::FileHeader => {
                     if diff_line.content.starts_with("diff ") && !lines.is_empty() {
                         lines.push(Line::from(""));
                     }
                     lines.push(Line::from(Span::styled(
                         diff_line.content,
-                        Style::default().fg(style::palette::WARNING),
+                        Style::default().fg(style::palette::warning()),
                     )));
 
                     continue;
@@ -141,15 +271,16 @@ impl<'a> DiffPage<'a> {
                 DiffLineKind::HunkHeader => {
                     lines.push(Line::from(Span::styled(
                         diff_line.content,
-                        Style::default().fg(style::palette::ACCENT),
+                        Style::default().fg(style::palette::accent()),
                     )));
 
                     continue;
                 }
-                DiffLineKind::Addition => ("+", Self::addition_line_style()),
-                DiffLineKind::Deletion => ("-", Self::deletion_line_style()),
-                DiffLineKind::Context => (" ", Style::default().fg(style::palette::TEXT_MUTED)),
+                DiffLineKind::Addition => "+",
+                DiffLineKind::Deletion => "-",
+                DiffLineKind::Context => " ",
             };
+            let content_style = Self::content_style(diff_line.kind);
 
             let old_str = match diff_line.old_line {
                 Some(num) => format!("{num:>width$}", width = layout.gutter_width),
@@ -163,20 +294,26 @@ impl<'a> DiffPage<'a> {
             let gutter_text = format!("{old_str}│{new_str} ");
             let content_available = layout.content_width.saturating_sub(layout.prefix_width);
             let chunks = diff_util::wrap_diff_content(diff_line.content, content_available);
+            let mut chunk_start = 0;
 
             for (idx, chunk) in chunks.iter().enumerate() {
-                if idx == WRAPPED_CHUNK_START_INDEX {
-                    lines.push(Line::from(vec![
+                let chunk_range = chunk_start..chunk_start + chunk.len();
+                chunk_start = chunk_range.end;
+                let mut spans = if idx == WRAPPED_CHUNK_START_INDEX {
+                    vec![
                         Span::styled(gutter_text.clone(), gutter_style),
                         Span::styled(sign, content_style),
-                        Span::styled(*chunk, content_style),
-                    ]));
+                    ]
                 } else {
-                    lines.push(Line::from(vec![
-                        Span::styled(" ".repeat(layout.prefix_width), gutter_style),
-                        Span::styled(*chunk, content_style),
-                    ]));
-                }
+                    vec![Span::styled(" ".repeat(layout.prefix_width), gutter_style)]
+                };
+                spans.extend(Self::chunk_spans(
+                    diff_line.content,
+                    chunk_range,
+                    line_segments,
+                ));
+
+                lines.push(Line::from(spans));
             }
         }
 
@@ -187,6 +324,35 @@ impl<'a> DiffPage<'a> {
         lines
     }
 
+    /// Returns the styled spans of `segments` that fall inside one wrapped
+    /// chunk of `content`.
+    ///
+    /// Always yields at least one span so empty lines keep their line style.
+    fn chunk_spans<'line>(
+        content: &'line str,
+        chunk_range: Range<usize>,
+        segments: &[(Range<usize>, Style)],
+    ) -> Vec<Span<'line>> {
+        let mut spans = segments
+            .iter()
+            .filter_map(|(segment_range, segment_style)| {
+                let start = segment_range.start.max(chunk_range.start);
+                let end = segment_range.end.min(chunk_range.end);
+
+                (start < end).then(|| Span::styled(&content[start..end], *segment_style))
+            })
+            .collect::<Vec<_>>();
+
+        if spans.is_empty() {
+            let style = segments
+                .first()
+                .map_or_else(Style::default, |(_, style)| *style);
+            spans.push(Span::styled(&content[chunk_range], style));
+        }
+
+        spans
+    }
+
     /// Renders a slim scrollbar inside the diff panel so users can see their
     /// position in long diffs at a glance.
     fn render_diff_scrollbar(
@@ -222,12 +388,12 @@ impl<'a> DiffPage<'a> {
             let (symbol, symbol_style) = if is_thumb_line {
                 (
                     SCROLLBAR_THUMB_SYMBOL,
-                    Style::default().fg(style::palette::WARNING),
+                    Style::default().fg(style::palette::warning()),
                 )
             } else {
                 (
                     SCROLLBAR_TRACK_SYMBOL,
-                    Style::default().fg(style::palette::TEXT_SUBTLE),
+                    Style::default().fg(style::palette::text_subtle()),
                 )
             };
 
@@ -424,15 +590,137 @@ mod tests {
         // Assert
         let buffer = terminal.backend().buffer();
         assert!(
-            background_cell_count(buffer, style::palette::SURFACE_SUCCESS) > 0,
+            background_cell_count(buffer, style::palette::surface_success()) > 0,
             "expected added lines to include success background tint"
         );
         assert!(
-            background_cell_count(buffer, style::palette::SURFACE_DANGER) > 0,
+            background_cell_count(buffer, style::palette::surface_danger()) > 0,
             "expected removed lines to include danger background tint"
         );
     }
 
+    #[test]
+    fn test_build_diff_lines_highlights_code_by_file_extension() {
+        // Arrange
+        let parsed = parse_diff_lines(concat!(
+            "diff --git a/src/main.rs b/src/main.rs\n",
+            "@@ -1,1 +1,1 @@\n",
+            "+fn main() {}\n"
+        ));
+        let layout = diff_util::DiffRenderLayout {
+            content_width: 80,
+            gutter_width: 1,
+            prefix_width: 5,
+            viewport_height: 10,
+        };
+
+        let segments = DiffPage::highlight_segments(&parsed);
+
+        // Act
+        let lines = DiffPage::build_diff_lines(&parsed, &segments, layout);
+
+        // Assert
+        let addition_line = &lines[2];
+        assert_eq!(addition_line.to_string(), " │1 +fn main() {}");
+        assert!(addition_line.spans.iter().any(|span| span.content == "main"
+            && span.style.fg == Some(style::palette::info())
+            && span.style.bg == Some(style::palette::surface_success())));
+    }
+
+    #[test]
+    fn test_build_diff_lines_keeps_flat_style_for_unknown_extension() {
+        // Arrange
+        let parsed = parse_diff_lines(concat!(
+            "diff --git a/notes.unknown b/notes.unknown\n",
+            "@@ -1,1 +1,1 @@\n",
+            "+fn main() {}\n"
+        ));
+        let layout = diff_util::DiffRenderLayout {
+            content_width: 80,
+            gutter_width: 1,
+            prefix_width: 5,
+            viewport_height: 10,
+        };
+
+        let segments = DiffPage::highlight_segments(&parsed);
+
+        // Act
+        let lines = DiffPage::build_diff_lines(&parsed, &segments, layout);
+
+        // Assert
+        let content_span = lines[2].spans.last().expect("content span");
+        assert_eq!(content_span.content, "fn main() {}");
+        assert_eq!(content_span.style, DiffPage::addition_line_style());
+    }
+
+    #[test]
+    fn test_build_diff_lines_splits_highlighted_spans_across_wrapped_chunks() {
+        // Arrange
+        let parsed = parse_diff_lines(concat!(
+            "diff --git a/app.py b/app.py\n",
+            "@@ -1,1 +1,1 @@\n",
+            " value = \"abcdef\"\n"
+        ));
+        let layout = diff_util::DiffRenderLayout {
+            content_width: 13,
+            gutter_width: 1,
+            prefix_width: 5,
+            viewport_height: 10,
+        };
+
+        let segments = DiffPage::highlight_segments(&parsed);
+
+        // Act
+        let lines = DiffPage::build_diff_lines(&parsed, &segments, layout);
+
+        // Assert
+        assert_eq!(lines[2].to_string(), "1│1  value = ");
+        assert_eq!(lines[3].to_string(), "     \"abcdef\"");
+        assert!(
+            lines[3]
+                .spans
+                .iter()
+                .skip(1)
+                .all(|span| span.style.fg == Some(style::palette::success()))
+        );
+    }
+
+    #[test]
+    fn test_diff_highlight_cache_reuses_segments_for_same_diff() {
+        // Arrange
+        let cache = DiffHighlightCache::default();
+        let parsed = parse_diff_lines(SAMPLE_DIFF);
+        let first_segments = cache.segments(SAMPLE_DIFF, 0, &parsed);
+
+        // Act
+        let second_segments = cache.segments(SAMPLE_DIFF, 0, &parsed);
+        let other_selection_segments = cache.segments(SAMPLE_DIFF, 1, &parsed);
+
+        // Assert
+        assert!(Rc::ptr_eq(&first_segments, &second_segments));
+        assert!(!Rc::ptr_eq(&first_segments, &other_selection_segments));
+    }
+
+    #[test]
+    fn test_diff_highlight_cache_rehighlights_after_theme_switch() {
+        // Arrange
+        let cache = DiffHighlightCache::default();
+        let parsed = parse_diff_lines(SAMPLE_DIFF);
+        theme::apply(theme::ThemeMode::Dark);
+        let dark_segments = cache.segments(SAMPLE_DIFF, 0, &parsed);
+
+        // Act
+        theme::apply(theme::ThemeMode::Light);
+        let light_segments = cache.segments(SAMPLE_DIFF, 0, &parsed);
+
+        // Assert
+        assert!(!Rc::ptr_eq(&dark_segments, &light_segments));
+        assert_eq!(
+            &*light_segments,
+            DiffPage::highlight_segments(&parsed).as_slice()
+        );
+    }
+
     #[test]
     fn test_render_shows_scrollbar_for_overflowing_diff() {
         // Arrange
@@ -48,6 +48,7 @@ pub(crate) fn current_page_messages(
         | AppMode::Question { .. }
         | AppMode::ViewInfoPopup { .. }
         | AppMode::OpenCommandSelector { .. }
+        | AppMode::PlanReview { .. }
         | AppMode::PublishBranchInput { .. }
         | AppMode::Confirmation {
             restore_view: Some(_),
@@ -139,6 +140,7 @@ mod tests {
             review_text: None,
             session_id: "session-id".into(),
             scroll_offset: None,
+            transcript_search: None,
         };
 
         // Act
@@ -1,21 +1,27 @@
+use std::ops::Range;
+
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::style::{Color, Modifier, Style};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::Paragraph;
+use unicode_width::UnicodeWidthStr;
 
 use crate::domain::agent::ReasoningLevel;
 use crate::domain::input::{self, extract_at_mention_query};
+use crate::domain::plan::SessionPlan;
 use crate::domain::session::{Session, Status};
-use crate::infra::agent::protocol::QuestionItem;
-use crate::infra::file_index;
+use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
+use crate::infra::{file_index, symbol_index};
 use crate::ui::component::chat_input::{ChatInput, SuggestionItem, SuggestionList};
 use crate::ui::component::session_output::{SessionOutput, SessionOutputLineContext};
 use crate::ui::state::app_mode::{AppMode, DoneSessionOutputMode, QuestionFocus};
 use crate::ui::state::help_action::{self, ViewHelpState, ViewSessionState};
 use crate::ui::state::prompt::{
-    PromptAtMentionState, PromptSlashState, build_prompt_slash_suggestion_list,
+    PromptAtMentionState, PromptHistorySearchState, PromptHistoryState, PromptSlashState,
+    build_prompt_slash_suggestion_list, filter_prompt_history,
 };
+use crate::ui::state::transcript_search::TranscriptSearchState;
 use crate::ui::util::{
     calculate_input_height, format_duration_compact, format_token_count, inline_text,
     overlay_area_above, question_panel_areas, question_panel_reserved_height,
@@ -46,7 +52,6 @@ impl PreparedPromptPanel {
 
 /// Chat page renderer for a single session.
 pub struct SessionChatPage<'a> {
-    pub active_prompt_output: Option<&'a str>,
     pub active_progress: Option<&'a str>,
     pub can_open_worktree: bool,
     pub default_reasoning_level: ReasoningLevel,
@@ -61,9 +66,6 @@ pub struct SessionChatPage<'a> {
 /// Borrowed inputs needed to construct one session chat page renderer.
 #[derive(Clone, Copy)]
 pub struct SessionChatPageInput<'a> {
-    /// Exact prompt transcript block for the currently active turn, when one
-    /// has been submitted in this app process.
-    pub active_prompt_output: Option<&'a str>,
     /// Transient progress text rendered in the active-status loader row.
     pub active_progress: Option<&'a str>,
     /// Active project-scoped default reasoning level.
@@ -85,24 +87,25 @@ pub struct SessionChatPageInput<'a> {
 impl<'a> SessionChatPage<'a> {
     /// Fixed prompt-mode actions rendered in the composer help footer while
     /// staging drafts for an explicit draft session.
-    const NEW_SESSION_PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
+    const NEW_SESSION_PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 5] = [
         help_action::HelpAction::new("stage draft", "Enter", "Stage draft"),
         help_action::HelpAction::new("newline", "Alt+Enter", "Insert newline"),
         help_action::HelpAction::new("paste image", "Ctrl+V/Alt+V", "Paste image"),
         help_action::HelpAction::new("cancel", "Esc", "Cancel prompt"),
+        help_action::HelpAction::new("editor", "Ctrl+G", "Open external editor"),
     ];
     /// Fixed prompt-mode actions rendered in the composer help footer.
-    const PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 4] = [
+    const PROMPT_FOOTER_ACTIONS: [help_action::HelpAction; 5] = [
         help_action::HelpAction::new("submit", "Enter", "Submit prompt"),
         help_action::HelpAction::new("newline", "Alt+Enter", "Insert newline"),
         help_action::HelpAction::new("paste image", "Ctrl+V/Alt+V", "Paste image"),
         help_action::HelpAction::new("cancel", "Esc", "Cancel prompt"),
+        help_action::HelpAction::new("editor", "Ctrl+G", "Open external editor"),
     ];
 
     /// Creates a session chat page renderer.
     pub fn new(input: SessionChatPageInput<'a>) -> Self {
         let SessionChatPageInput {
-            active_prompt_output,
             active_progress,
             default_reasoning_level,
             markdown_render_cache,
@@ -114,7 +117,6 @@ impl<'a> SessionChatPage<'a> {
         } = input;
 
         Self {
-            active_prompt_output,
             active_progress,
             can_open_worktree: false,
             default_reasoning_level,
@@ -149,6 +151,26 @@ impl<'a> SessionChatPage<'a> {
         SessionOutput::rendered_line_count(session, output_width, context)
     }
 
+    /// Returns the rendered output lines for chat content at a given width,
+    /// using the same line rules as [`Self::rendered_output_line_count`].
+    pub(crate) fn rendered_output_lines(
+        session: &Session,
+        output_width: u16,
+        context: SessionOutputLineContext<'_>,
+    ) -> Vec<Line<'static>> {
+        SessionOutput::rendered_lines(session, output_width, context)
+    }
+
+    /// Returns the open transcript search for view mode.
+    fn transcript_search(&self) -> Option<&TranscriptSearchState> {
+        match self.mode {
+            AppMode::View {
+                transcript_search, ..
+            } => transcript_search.as_ref(),
+            _ => None,
+        }
+    }
+
     /// Returns the selected `Done`-session output mode for the active page
     /// mode.
     fn done_session_output_mode(&self) -> DoneSessionOutputMode {
@@ -158,6 +180,7 @@ impl<'a> SessionChatPage<'a> {
                 ..
             } => *done_session_output_mode,
             AppMode::OpenCommandSelector { restore_view, .. }
+            | AppMode::PlanReview { restore_view, .. }
             | AppMode::PublishBranchInput { restore_view, .. } => {
                 restore_view.done_session_output_mode
             }
@@ -165,6 +188,8 @@ impl<'a> SessionChatPage<'a> {
             AppMode::List
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::IssuePicker { .. }
+            | AppMode::SessionSearch { .. }
             | AppMode::Prompt { .. }
             | AppMode::Question { .. }
             | AppMode::Diff { .. }
@@ -191,6 +216,7 @@ impl<'a> SessionChatPage<'a> {
                 ..
             } => review_status_message.as_deref(),
             AppMode::OpenCommandSelector { restore_view, .. }
+            | AppMode::PlanReview { restore_view, .. }
             | AppMode::PublishBranchInput { restore_view, .. }
             | AppMode::ViewInfoPopup { restore_view, .. } => {
                 restore_view.review_status_message.as_deref()
@@ -198,6 +224,8 @@ impl<'a> SessionChatPage<'a> {
             AppMode::List
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::IssuePicker { .. }
+            | AppMode::SessionSearch { .. }
             | AppMode::Diff { .. }
             | AppMode::Help { .. } => None,
         }
@@ -213,11 +241,14 @@ impl<'a> SessionChatPage<'a> {
             | AppMode::Prompt { review_text, .. }
             | AppMode::Question { review_text, .. } => review_text.as_deref(),
             AppMode::OpenCommandSelector { restore_view, .. }
+            | AppMode::PlanReview { restore_view, .. }
             | AppMode::PublishBranchInput { restore_view, .. }
             | AppMode::ViewInfoPopup { restore_view, .. } => restore_view.review_text.as_deref(),
             AppMode::List
             | AppMode::Confirmation { .. }
             | AppMode::SyncBlockedPopup { .. }
+            | AppMode::IssuePicker { .. }
+            | AppMode::SessionSearch { .. }
             | AppMode::Diff { .. }
             | AppMode::Help { .. } => None,
         }
@@ -240,12 +271,22 @@ impl<'a> SessionChatPage<'a> {
 
     /// Builds the current prompt suggestion list and clamps the highlighted
     /// row into the visible item window.
+    ///
+    /// An open `Ctrl+R` history search replaces every other dropdown.
     fn build_prompt_suggestion_list(
         input: &input::InputState,
+        history_state: &PromptHistoryState,
         slash_state: &PromptSlashState,
         at_mention_state: Option<&PromptAtMentionState>,
         session: &Session,
     ) -> Option<SuggestionList> {
+        if let Some(history_search) = &history_state.search {
+            return Some(build_prompt_history_search_list(
+                history_search,
+                AT_MENTION_DEFAULT_MAX_VISIBLE,
+            ));
+        }
+
         let input_text = input.text();
         let cursor = input.cursor;
 
@@ -268,6 +309,7 @@ impl<'a> SessionChatPage<'a> {
         let AppMode::Prompt {
             at_mention_state,
             attachment_state,
+            history_state,
             input,
             slash_state,
             ..
@@ -278,8 +320,9 @@ impl<'a> SessionChatPage<'a> {
 
         let suggestion_list = Self::build_prompt_suggestion_list(
             input,
+            history_state,
             slash_state,
-            at_mention_state.as_ref(),
+            at_mention_state.as_deref(),
             session,
         );
         let dropdown_row_count = suggestion_list
@@ -293,7 +336,11 @@ impl<'a> SessionChatPage<'a> {
         let max_bottom_height = area.height.saturating_sub(1);
 
         Some(PreparedPromptPanel {
-            footer_text: Self::prompt_footer_line(session, attachment_state.attachments.len()),
+            footer_text: Self::prompt_footer_line(
+                session,
+                attachment_state.attachments.len(),
+                attachment_state.has_pending_context(),
+            ),
             suggestion_list,
             title: format!(" [{}] ", session.model.as_str()),
             total_height: desired_bottom_height.min(max_bottom_height),
@@ -345,7 +392,6 @@ impl<'a> SessionChatPage<'a> {
         if let Some(cache) = self.markdown_render_cache {
             output = output.markdown_render_cache(cache);
         }
-        output = output.active_prompt_output(self.active_prompt_output);
         output = output.review_status_message(self.review_status_message());
         output = output.review_text(self.review_text());
         if let Some(scroll_offset) = self.scroll_offset {
@@ -354,6 +400,10 @@ impl<'a> SessionChatPage<'a> {
         if let Some(active_progress) = self.active_progress {
             output = output.active_progress(active_progress);
         }
+        if let Some(transcript_search) = self.transcript_search() {
+            output = output
+                .search_highlight(transcript_search.query(), transcript_search.focused_line());
+        }
         Self::render_session_header(
             f,
             output_chunks[0],
@@ -408,7 +458,7 @@ impl<'a> SessionChatPage<'a> {
             Line::from(title_spans),
             Line::from(Span::styled(
                 metadata_text,
-                Style::default().fg(style::palette::TEXT_MUTED),
+                Style::default().fg(style::palette::text_muted()),
             )),
         ]
     }
@@ -470,7 +520,7 @@ impl<'a> SessionChatPage<'a> {
             let question_item = questions.get(*current_index);
             let question = question_item.map_or("", |item| item.text.as_str());
             let options = question_item
-                .map(|item| item.options.as_slice())
+                .map(QuestionItem::answer_options)
                 .unwrap_or_default();
             let is_free_text_mode = selected_option_index.is_none();
             let input_text = if is_free_text_mode { input.text() } else { "" };
@@ -488,6 +538,9 @@ impl<'a> SessionChatPage<'a> {
     }
 
     /// Renders the context-aware bottom panel for prompt and question modes.
+    ///
+    /// List mode renders the read-only split-layout preview, which leaves
+    /// the footer row empty because the list pane owns the shortcuts.
     fn render_bottom_panel(
         &self,
         f: &mut Frame,
@@ -495,6 +548,10 @@ impl<'a> SessionChatPage<'a> {
         session: &Session,
         prepared_prompt_panel: Option<&PreparedPromptPanel>,
     ) {
+        if matches!(self.mode, AppMode::List) {
+            return;
+        }
+
         if let AppMode::Prompt { input, .. } = self.mode {
             let Some(prepared_prompt_panel) = prepared_prompt_panel else {
                 return;
@@ -529,6 +586,7 @@ impl<'a> SessionChatPage<'a> {
 
         if let AppMode::Question {
             at_mention_state,
+            checked_option_indexes,
             focus,
             questions,
             current_index,
@@ -541,7 +599,8 @@ impl<'a> SessionChatPage<'a> {
                 f,
                 bottom_area,
                 &QuestionPanelState {
-                    at_mention_state: at_mention_state.as_ref(),
+                    at_mention_state: at_mention_state.as_deref(),
+                    checked_option_indexes,
                     current_index: *current_index,
                     focus: *focus,
                     input,
@@ -553,6 +612,12 @@ impl<'a> SessionChatPage<'a> {
             return;
         }
 
+        if let Some(transcript_search) = self.transcript_search() {
+            Self::render_transcript_search_bar(f, bottom_area, transcript_search);
+
+            return;
+        }
+
         let help_actions = Self::view_footer_actions(
             session,
             self.can_open_worktree,
@@ -562,6 +627,78 @@ impl<'a> SessionChatPage<'a> {
         f.render_widget(help_message, bottom_area);
     }
 
+    /// Renders the one-line transcript search bar with its match position
+    /// and search shortcuts.
+    fn render_transcript_search_bar(
+        f: &mut Frame,
+        bottom_area: Rect,
+        transcript_search: &TranscriptSearchState,
+    ) {
+        let search_line = Self::transcript_search_line(transcript_search);
+        f.render_widget(Paragraph::new(search_line), bottom_area);
+
+        if transcript_search.is_editing {
+            let cursor_offset = u16::try_from(
+                transcript_search
+                    .query()
+                    .chars()
+                    .take(transcript_search.input.cursor)
+                    .collect::<String>()
+                    .width(),
+            )
+            .unwrap_or(u16::MAX);
+            f.set_cursor_position((
+                bottom_area
+                    .x
+                    .saturating_add(1)
+                    .saturating_add(cursor_offset)
+                    .min(bottom_area.right().saturating_sub(1)),
+                bottom_area.y,
+            ));
+        }
+    }
+
+    /// Builds the transcript search bar line: query, match position, and
+    /// shortcuts for the current search stage.
+    fn transcript_search_line(transcript_search: &TranscriptSearchState) -> Line<'static> {
+        let match_status = match (
+            transcript_search.focused_match_index,
+            transcript_search.match_lines.len(),
+        ) {
+            _ if transcript_search.query().is_empty() => String::new(),
+            (_, 0) => "no matches".to_string(),
+            (Some(focused_match_index), match_count) => {
+                format!("{}/{match_count}", focused_match_index + 1)
+            }
+            (None, match_count) => format!("{match_count} matches"),
+        };
+        let mut spans = vec![
+            Span::styled(
+                "/",
+                Style::default()
+                    .fg(style::palette::accent())
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                transcript_search.query().to_string(),
+                Style::default().fg(style::palette::text()),
+            ),
+        ];
+        if !match_status.is_empty() {
+            spans.push(Span::raw("  "));
+            spans.push(help_action::footer_muted_span(match_status));
+        }
+        spans.push(help_action::footer_separator_span());
+        spans.extend(
+            help_action::footer_line(&help_action::transcript_search_footer_actions(
+                transcript_search.is_editing,
+            ))
+            .spans,
+        );
+
+        Line::from(spans)
+    }
+
     /// Returns the footer action list for a given session in view mode.
     ///
     /// `InProgress` sessions keep worktree access while hiding edit and diff
@@ -571,8 +708,9 @@ impl<'a> SessionChatPage<'a> {
     /// shortcuts with read-only assist generation (`m` opens merge
     /// confirmation before queueing), and `Done` sessions expose only
     /// read-only shortcuts. `Canceled` sessions expose only `back`, `scroll`,
-    /// and `help`.
-    fn view_footer_actions(
+    /// and `help`. Editable plan-first sessions with a proposed plan also
+    /// expose the plan-review shortcut right after `back`.
+    pub(crate) fn view_footer_actions(
         session: &Session,
         can_open_worktree: bool,
         done_session_output_mode: DoneSessionOutputMode,
@@ -584,18 +722,35 @@ impl<'a> SessionChatPage<'a> {
             session_state,
         });
 
+        let can_review_plan = matches!(
+            session_state,
+            ViewSessionState::Interactive
+                | ViewSessionState::NewSession
+                | ViewSessionState::Review
+                | ViewSessionState::AgentReview
+        ) && session
+            .plan
+            .as_ref()
+            .is_some_and(SessionPlan::is_awaiting_approval);
+        if can_review_plan && let Some(plan_review_action) = help_action::plan_review_action() {
+            actions.insert(actions.len().min(1), plan_review_action);
+        }
+
         if session_state == ViewSessionState::Done {
             let toggle_action_label = Self::done_toggle_action_label(done_session_output_mode);
-            if let Some(toggle_action_index) = actions.iter().position(|action| action.key == "t") {
-                actions[toggle_action_index] =
-                    help_action::HelpAction::new(toggle_action_label, "t", "Switch summary/output");
+            if let Some(toggle_action) = actions
+                .iter_mut()
+                .find(|action| action.popup_label == "Switch summary/output")
+            {
+                toggle_action.footer_label = toggle_action_label;
             }
         }
 
         actions
     }
 
-    /// Returns the `t` footer label for `Status::Done` output mode toggling.
+    /// Returns the output-toggle footer label for `Status::Done` output mode
+    /// toggling.
     fn done_toggle_action_label(done_session_output_mode: DoneSessionOutputMode) -> &'static str {
         match done_session_output_mode {
             DoneSessionOutputMode::Summary => "output",
@@ -605,9 +760,18 @@ impl<'a> SessionChatPage<'a> {
 
     /// Returns the prompt-mode footer line shown under the composer using the
     /// same highlighted key styling used by other Agentty help text while
-    /// appending attachment readiness as muted status text.
-    fn prompt_footer_line(session: &Session, attachment_count: usize) -> Line<'static> {
-        let mut footer_line = help_action::footer_line(Self::prompt_footer_actions(session));
+    /// appending background context captures and attachment readiness as
+    /// muted status text.
+    fn prompt_footer_line(
+        session: &Session,
+        attachment_count: usize,
+        is_capturing_context: bool,
+    ) -> Line<'static> {
+        let mut footer_line = help_action::footer_line(&Self::prompt_footer_actions(session));
+
+        if is_capturing_context {
+            Self::append_prompt_footer_note(&mut footer_line, "capturing context...".to_string());
+        }
 
         if attachment_count > 0 {
             let suffix = if attachment_count == 1 { "" } else { "s" };
@@ -622,12 +786,12 @@ impl<'a> SessionChatPage<'a> {
 
     /// Returns the fixed prompt-mode actions rendered in the composer help
     /// footer.
-    fn prompt_footer_actions(session: &Session) -> &'static [help_action::HelpAction] {
+    fn prompt_footer_actions(session: &Session) -> [help_action::HelpAction; 5] {
         if session.status == Status::New && session.is_draft_session() {
-            return &Self::NEW_SESSION_PROMPT_FOOTER_ACTIONS;
+            return Self::NEW_SESSION_PROMPT_FOOTER_ACTIONS;
         }
 
-        &Self::PROMPT_FOOTER_ACTIONS
+        Self::PROMPT_FOOTER_ACTIONS
     }
 
     /// Appends one muted informational note to the prompt footer line.
@@ -641,6 +805,7 @@ impl<'a> SessionChatPage<'a> {
 #[derive(Clone, Copy)]
 struct QuestionPanelState<'a> {
     at_mention_state: Option<&'a PromptAtMentionState>,
+    checked_option_indexes: &'a [usize],
     current_index: usize,
     focus: QuestionFocus,
     input: &'a input::InputState,
@@ -652,7 +817,8 @@ struct QuestionPanelState<'a> {
 /// and help footer.
 fn render_question_panel(f: &mut Frame, bottom_area: Rect, state: &QuestionPanelState<'_>) {
     let QuestionPanelState {
-        at_mention_state,
+        at_mention_state: _,
+        checked_option_indexes,
         current_index,
         focus,
         input,
@@ -661,8 +827,9 @@ fn render_question_panel(f: &mut Frame, bottom_area: Rect, state: &QuestionPanel
     } = *state;
     let question_item = questions.get(current_index);
     let question = question_item.map_or("", |item| item.text.as_str());
+    let kind = question_item.map(|item| item.kind).unwrap_or_default();
     let options = question_item
-        .map(|item| item.options.as_slice())
+        .map(QuestionItem::answer_options)
         .unwrap_or_default();
     let is_free_text_mode = selected_option_index.is_none();
     let input_text = if is_free_text_mode { input.text() } else { "" };
@@ -675,87 +842,157 @@ fn render_question_panel(f: &mut Frame, bottom_area: Rect, state: &QuestionPanel
     );
 
     let is_chat_focused = focus == QuestionFocus::Chat;
-    let question_title = format!("Question {}/{}", current_index + 1, questions.len());
-    if panel_areas.question_area.height > 0 {
-        let title_color = if is_chat_focused {
-            style::palette::TEXT_MUTED
-        } else {
-            style::palette::QUESTION
-        };
-        let title_line = Line::from(Span::styled(
-            &question_title,
-            Style::default()
-                .fg(title_color)
-                .add_modifier(Modifier::BOLD),
-        ));
-        let text_color = if is_chat_focused {
-            style::palette::TEXT_MUTED
-        } else {
-            Color::Yellow
-        };
-        let mut lines = vec![title_line];
-        lines.extend(
-            wrap_lines(question, usize::from(bottom_area.width.max(1)))
-                .into_iter()
-                .map(|line| line.style(Style::default().fg(text_color))),
-        );
-        let question_para = Paragraph::new(lines);
-        f.render_widget(question_para, panel_areas.question_area);
+    let mut question_title = format!("Question {}/{}", current_index + 1, questions.len());
+    if let Some(kind_hint) = question_kind_hint(kind) {
+        question_title.push_str(" \u{b7} ");
+        question_title.push_str(kind_hint);
     }
+    render_question_text(
+        f,
+        panel_areas.question_area,
+        &question_title,
+        question,
+        is_chat_focused,
+    );
 
     if panel_areas.options_area.height > 0 {
         render_question_options(
             f,
             panel_areas.options_area,
-            options,
-            selected_option_index,
+            &QuestionOptionsState {
+                checked_option_indexes,
+                kind,
+                options: &options,
+                selected_option_index,
+            },
             is_chat_focused,
         );
     }
 
-    // Always render the input widget so the panel height stays stable
-    // across mode transitions. When navigating options the input shows a
-    // placeholder; when in free-text mode it is fully editable.
-    let (display_text, display_cursor) = if is_free_text_mode {
-        (input.text(), input.cursor)
+    render_question_answer_input(
+        f,
+        bottom_area,
+        panel_areas.input_area,
+        state,
+        is_free_text_mode.then_some(input),
+    );
+    render_question_help_footer(
+        f,
+        panel_areas.help_area,
+        panel_areas.help_area.height,
+        focus,
+        kind,
+    );
+}
+
+/// Renders the question title line followed by the wrapped question text.
+fn render_question_text(
+    f: &mut Frame,
+    area: Rect,
+    question_title: &str,
+    question: &str,
+    dimmed: bool,
+) {
+    if area.height == 0 {
+        return;
+    }
+
+    let (title_color, text_color) = if dimmed {
+        (style::palette::text_muted(), style::palette::text_muted())
     } else {
-        ("", 0)
+        (style::palette::question(), style::palette::warning())
     };
-    let input_placeholder = "Type answer (Enter: send, Esc: end turn)";
-    let available_above = usize::from(panel_areas.input_area.y.saturating_sub(bottom_area.y));
+    let title_line = Line::from(Span::styled(
+        question_title,
+        Style::default()
+            .fg(title_color)
+            .add_modifier(Modifier::BOLD),
+    ));
+    let mut lines = vec![title_line];
+    lines.extend(
+        wrap_lines(question, usize::from(area.width.max(1)))
+            .into_iter()
+            .map(|line| line.style(Style::default().fg(text_color))),
+    );
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+/// Renders the answer input and, while typing, its `@` file dropdown.
+///
+/// `free_text_input` is `None` while an option is selected. The input is
+/// still drawn, with a placeholder, so the panel height stays stable across
+/// mode transitions. File-pick questions answer through the `@` dropdown.
+fn render_question_answer_input(
+    f: &mut Frame,
+    bottom_area: Rect,
+    input_area: Rect,
+    state: &QuestionPanelState<'_>,
+    free_text_input: Option<&input::InputState>,
+) {
+    let kind = state
+        .questions
+        .get(state.current_index)
+        .map(|item| item.kind)
+        .unwrap_or_default();
+    let (display_text, display_cursor) =
+        free_text_input.map_or(("", 0), |input| (input.text(), input.cursor));
+    let available_above = usize::from(input_area.y.saturating_sub(bottom_area.y));
     let at_mention_max_visible = available_above
         .saturating_sub(2)
         .clamp(1, AT_MENTION_DEFAULT_MAX_VISIBLE);
-    let at_mention_menu = if is_free_text_mode {
-        at_mention_state.and_then(|state| {
-            build_at_mention_suggestion_list_with_capacity(
-                display_text,
-                display_cursor,
-                state,
-                at_mention_max_visible,
-            )
-        })
-    } else {
-        None
-    };
+    let at_mention_menu =
+        free_text_input
+            .and(state.at_mention_state)
+            .and_then(|at_mention_state| {
+                build_at_mention_suggestion_list_with_capacity(
+                    display_text,
+                    display_cursor,
+                    at_mention_state,
+                    at_mention_max_visible,
+                )
+            });
     let chat_input = ChatInput::new("Answer", display_text, display_cursor)
-        .placeholder(input_placeholder)
-        .active(is_free_text_mode && !is_chat_focused);
-    if panel_areas.input_area.height > 0 {
-        chat_input.render(f, panel_areas.input_area);
+        .placeholder(question_input_placeholder(kind))
+        .active(free_text_input.is_some() && state.focus != QuestionFocus::Chat);
+    if input_area.height > 0 {
+        chat_input.render(f, input_area);
     }
 
-    render_question_at_mention_overlay(f, bottom_area, panel_areas.input_area, at_mention_menu);
-    render_question_help_footer(
-        f,
-        panel_areas.help_area,
-        panel_areas.help_area.height,
-        focus,
-    );
+    render_question_at_mention_overlay(f, bottom_area, input_area, at_mention_menu);
+}
+
+/// Returns the short answer-widget hint appended to the question title, or
+/// `None` for the default single-choice kind.
+fn question_kind_hint(kind: QuestionKind) -> Option<&'static str> {
+    match kind {
+        QuestionKind::SingleChoice => None,
+        QuestionKind::MultiSelect => Some("Select all that apply"),
+        QuestionKind::FreeText => Some("Free text"),
+        QuestionKind::Confirm => Some("Yes or no"),
+        QuestionKind::FilePick => Some("Pick a file"),
+    }
+}
+
+/// Returns the answer input placeholder for one question kind.
+fn question_input_placeholder(kind: QuestionKind) -> &'static str {
+    match kind {
+        QuestionKind::SingleChoice | QuestionKind::FreeText => {
+            "Type answer (Enter: send, Esc: end turn)"
+        }
+        QuestionKind::MultiSelect => "Type another answer (Enter: send checked, Esc: end turn)",
+        QuestionKind::Confirm => "Choose Yes or No (Enter: send, Esc: end turn)",
+        QuestionKind::FilePick => "Type @ to pick a file (Enter: send, Esc: end turn)",
+    }
 }
 
 /// Renders the question-mode help footer with context-aware action hints.
-fn render_question_help_footer(f: &mut Frame, area: Rect, help_height: u16, focus: QuestionFocus) {
+fn render_question_help_footer(
+    f: &mut Frame,
+    area: Rect,
+    help_height: u16,
+    focus: QuestionFocus,
+    kind: QuestionKind,
+) {
     if help_height == 0 {
         return;
     }
@@ -772,6 +1009,15 @@ fn render_question_help_footer(f: &mut Frame, area: Rect, help_height: u16, focu
             "Answer",
         ));
     } else {
+        match kind {
+            QuestionKind::MultiSelect => {
+                help_actions.push(help_action::HelpAction::new("toggle", "Space", "Toggle"));
+            }
+            QuestionKind::Confirm => {
+                help_actions.push(help_action::HelpAction::new("answer", "y/n", "Yes/No"));
+            }
+            QuestionKind::SingleChoice | QuestionKind::FreeText | QuestionKind::FilePick => {}
+        }
         help_actions.push(help_action::HelpAction::new("send", "Enter", "Submit"));
     }
 
@@ -819,7 +1065,8 @@ const AT_MENTION_DEFAULT_MAX_VISIBLE: usize = 10;
 /// window never exceeds what the overlay can display.
 ///
 /// Returns `None` when the input has no active `@` query or when no file
-/// entries match the query.
+/// entries match the query. `@#` queries list indexed symbols instead of
+/// files and return `None` until the symbol index has loaded.
 fn build_at_mention_suggestion_list_with_capacity(
     input_text: &str,
     cursor: usize,
@@ -827,18 +1074,34 @@ fn build_at_mention_suggestion_list_with_capacity(
     max_visible: usize,
 ) -> Option<SuggestionList> {
     let (_, query) = extract_at_mention_query(input_text, cursor)?;
-    let filtered = file_index::filter_entries(&at_mention_state.all_entries, &query);
+    if let Some(symbol_query) = symbol_index::symbol_query(&query) {
+        let index = at_mention_state.symbol_index.as_ref()?;
+        let filtered = symbol_index::filter_symbols(&index.symbols, symbol_query);
+        let (window, selected_index) =
+            at_mention_window(filtered.len(), at_mention_state.selected_index, max_visible)?;
+        let items = filtered[window]
+            .iter()
+            .map(|symbol| SuggestionItem {
+                badge: Some(symbol.kind.label().to_string()),
+                detail: None,
+                label: symbol.name.clone(),
+                metadata: Some(symbol.anchor()),
+            })
+            .collect();
 
-    if filtered.is_empty() {
-        return None;
+        return Some(SuggestionList {
+            items,
+            selected_index,
+            title: "Symbols (\u{2191}\u{2193} move, Enter anchor, Tab anchor + source, Esc \
+                    dismiss)"
+                .to_string(),
+        });
     }
-    let window_start = at_mention_state
-        .selected_index
-        .saturating_sub(max_visible / 2);
-    let window_end = filtered.len().min(window_start + max_visible);
-    let window_start = window_end.saturating_sub(max_visible);
 
-    let items: Vec<SuggestionItem> = filtered[window_start..window_end]
+    let filtered = file_index::filter_entries(&at_mention_state.all_entries, &query);
+    let (window, selected_index) =
+        at_mention_window(filtered.len(), at_mention_state.selected_index, max_visible)?;
+    let items: Vec<SuggestionItem> = filtered[window]
         .iter()
         .map(|entry| {
             let label = if entry.is_dir {
@@ -856,57 +1119,204 @@ fn build_at_mention_suggestion_list_with_capacity(
         })
         .collect();
 
-    let display_index = at_mention_state
-        .selected_index
-        .min(filtered.len().saturating_sub(1))
-        .saturating_sub(window_start);
-
     Some(SuggestionList {
         items,
-        selected_index: display_index,
+        selected_index,
         title: "Files (\u{2191}\u{2193} move, Enter select, Esc dismiss)".to_string(),
     })
 }
 
+/// Builds the `Ctrl+R` prompt-history search dropdown.
+///
+/// The typed query is shown in the dropdown title. When nothing matches, one
+/// placeholder row keeps the dropdown, and therefore the query, on screen.
+fn build_prompt_history_search_list(
+    history_search: &PromptHistorySearchState,
+    max_visible: usize,
+) -> SuggestionList {
+    let title = format!(
+        "History: {}\u{2502} (\u{2191}\u{2193} move, Enter insert, Ctrl+O new session, Esc \
+         dismiss)",
+        history_search.query
+    );
+    let filtered = filter_prompt_history(&history_search.entries, &history_search.query);
+    let Some((window, selected_index)) =
+        at_mention_window(filtered.len(), history_search.selected_index, max_visible)
+    else {
+        return SuggestionList {
+            items: vec![SuggestionItem {
+                badge: None,
+                detail: None,
+                label: "No matching prompts".to_string(),
+                metadata: None,
+            }],
+            selected_index: 0,
+            title,
+        };
+    };
+    let items = filtered[window]
+        .iter()
+        .map(|prompt| {
+            let mut prompt_lines = prompt.lines();
+            let first_line = prompt_lines.next().unwrap_or_default().to_string();
+            let more_line_count = prompt_lines.count();
+
+            SuggestionItem {
+                badge: None,
+                detail: None,
+                label: first_line,
+                metadata: (more_line_count > 0).then(|| format!("+{more_line_count} lines")),
+            }
+        })
+        .collect();
+
+    SuggestionList {
+        items,
+        selected_index,
+        title,
+    }
+}
+
+/// Returns the visible row range that keeps `selected_index` in view and the
+/// selected row's position inside that range.
+///
+/// Returns `None` when there are no rows to show.
+fn at_mention_window(
+    item_count: usize,
+    selected_index: usize,
+    max_visible: usize,
+) -> Option<(Range<usize>, usize)> {
+    if item_count == 0 {
+        return None;
+    }
+    let window_start = selected_index.saturating_sub(max_visible / 2);
+    let window_end = item_count.min(window_start + max_visible);
+    let window_start = window_end.saturating_sub(max_visible);
+    let display_index = selected_index
+        .min(item_count.saturating_sub(1))
+        .saturating_sub(window_start);
+
+    Some((window_start..window_end, display_index))
+}
+
+/// Answer option state for the active question passed to the option list
+/// renderer.
+#[derive(Clone, Copy)]
+struct QuestionOptionsState<'a> {
+    checked_option_indexes: &'a [usize],
+    kind: QuestionKind,
+    options: &'a [String],
+    selected_option_index: Option<usize>,
+}
+
 /// Renders the answer option list for the active question.
 ///
-/// Each predefined option is shown as a numbered line with the currently
-/// selected option highlighted. The input widget below the options serves
-/// as the "type custom answer" area, so no virtual entry is appended here.
+/// The input widget below the options serves as the "type custom answer"
+/// area, so no virtual entry is appended here. Free-text and file-pick
+/// questions have no options.
 fn render_question_options(
+    f: &mut Frame,
+    area: Rect,
+    options_state: &QuestionOptionsState<'_>,
+    dimmed: bool,
+) {
+    let QuestionOptionsState {
+        checked_option_indexes,
+        kind,
+        options,
+        selected_option_index,
+    } = *options_state;
+
+    match kind {
+        QuestionKind::MultiSelect => render_multi_select_options(
+            f,
+            area,
+            options,
+            checked_option_indexes,
+            selected_option_index,
+            dimmed,
+        ),
+        QuestionKind::SingleChoice | QuestionKind::Confirm => {
+            render_choice_options(f, area, options, selected_option_index, dimmed);
+        }
+        QuestionKind::FreeText | QuestionKind::FilePick => {}
+    }
+}
+
+/// Renders multi-select options with a `[x]`/`[ ]` checkbox per option.
+fn render_multi_select_options(
     f: &mut Frame,
     area: Rect,
     options: &[String],
+    checked_option_indexes: &[usize],
+    selected_option_index: Option<usize>,
+    dimmed: bool,
+) {
+    let labels = options
+        .iter()
+        .enumerate()
+        .map(|(option_index, option_text)| {
+            let checkbox = if checked_option_indexes.contains(&option_index) {
+                "[x]"
+            } else {
+                "[ ]"
+            };
+
+            format!("{}. {checkbox} {option_text}", option_index + 1)
+        });
+    render_question_option_rows(f, area, labels, selected_option_index, dimmed);
+}
+
+/// Renders single-choice options, or the `Yes`/`No` pair of a confirm
+/// question, as numbered rows.
+fn render_choice_options(
+    f: &mut Frame,
+    area: Rect,
+    options: &[String],
+    selected_option_index: Option<usize>,
+    dimmed: bool,
+) {
+    let labels = options
+        .iter()
+        .enumerate()
+        .map(|(option_index, option_text)| format!("{}. {option_text}", option_index + 1));
+    render_question_option_rows(f, area, labels, selected_option_index, dimmed);
+}
+
+/// Renders the `Options:` header and one row per label, highlighting the
+/// selected row.
+fn render_question_option_rows(
+    f: &mut Frame,
+    area: Rect,
+    labels: impl Iterator<Item = String>,
     selected_option_index: Option<usize>,
     dimmed: bool,
 ) {
-    let mut lines: Vec<Line<'_>> = Vec::with_capacity(options.len() + 1);
     let header_color = if dimmed {
-        style::palette::TEXT_MUTED
+        style::palette::text_muted()
     } else {
-        Color::Yellow
+        style::palette::warning()
     };
-    lines.push(Line::from(Span::styled(
+    let mut lines = vec![Line::from(Span::styled(
         "Options:",
         Style::default().fg(header_color),
-    )));
+    ))];
 
-    for (option_index, option_text) in options.iter().enumerate() {
+    for (option_index, label) in labels.enumerate() {
         let is_selected = selected_option_index == Some(option_index);
         let prefix = if is_selected { "▸ " } else { "  " };
-        let label = format!("{prefix}{}. {option_text}", option_index + 1);
         let style = if dimmed {
-            Style::default().fg(style::palette::TEXT_MUTED)
+            Style::default().fg(style::palette::text_muted())
         } else if is_selected {
             Style::default()
-                .fg(Color::Black)
-                .bg(Color::Yellow)
+                .fg(style::palette::surface_overlay())
+                .bg(style::palette::warning())
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(Color::White)
+            Style::default().fg(style::palette::text())
         };
 
-        lines.push(Line::from(Span::styled(label, style)));
+        lines.push(Line::from(Span::styled(format!("{prefix}{label}"), style)));
     }
 
     f.render_widget(Paragraph::new(lines), area);
@@ -928,8 +1338,10 @@ mod tests {
     use crate::agent::AgentModel;
     use crate::domain::agent::{AgentKind, ReasoningLevel};
     use crate::domain::input::InputState;
-    use crate::infra::agent::protocol::QuestionItem;
+    use crate::domain::plan::PlanStep;
+    use crate::infra::agent::protocol::{QuestionItem, QuestionKind};
     use crate::infra::file_index::FileEntry;
+    use crate::infra::symbol_index::{SymbolEntry, SymbolIndex, SymbolKind};
     use crate::ui::state::app_mode::QuestionFocus;
     use crate::ui::state::prompt::{PromptAttachmentState, PromptHistoryState, PromptSlashState};
 
@@ -943,7 +1355,6 @@ mod tests {
     /// Builds a default test page for one session and mode.
     fn test_session_chat_page<'a>(session: &'a Session, mode: &'a AppMode) -> SessionChatPage<'a> {
         SessionChatPage::new(SessionChatPageInput {
-            active_prompt_output: None,
             active_progress: None,
             default_reasoning_level: ReasoningLevel::default(),
             markdown_render_cache: test_markdown_render_cache(),
@@ -1349,6 +1760,83 @@ mod tests {
         assert_eq!(menu.items[1].detail, None);
     }
 
+    #[test]
+    fn test_build_at_mention_suggestion_list_lists_symbols_for_hash_query() {
+        // Arrange
+        let mut state = PromptAtMentionState::new(vec![FileEntry {
+            is_dir: false,
+            path: "src/session.rs".to_string(),
+        }]);
+        state.symbol_index = Some(SymbolIndex {
+            root: PathBuf::from("/project"),
+            symbols: vec![SymbolEntry {
+                kind: SymbolKind::Function,
+                line: 42,
+                name: "SessionManager::merge_session".to_string(),
+                path: "src/session.rs".to_string(),
+            }],
+        });
+
+        // Act
+        let menu = SessionChatPage::build_at_mention_suggestion_list("@#merge", 7, &state)
+            .expect("expected suggestion list");
+
+        // Assert
+        assert_eq!(menu.items.len(), 1);
+        assert_eq!(menu.items[0].label, "SessionManager::merge_session");
+        assert_eq!(menu.items[0].badge, Some("fn".to_string()));
+        assert_eq!(
+            menu.items[0].metadata,
+            Some("src/session.rs:42".to_string())
+        );
+        assert!(menu.title.starts_with("Symbols"));
+    }
+
+    #[test]
+    fn test_build_at_mention_suggestion_list_waits_for_symbol_index() {
+        // Arrange
+        let state = PromptAtMentionState::new(Vec::new());
+
+        // Act
+        let menu = SessionChatPage::build_at_mention_suggestion_list("@#merge", 7, &state);
+
+        // Assert
+        assert!(menu.is_none());
+    }
+
+    #[test]
+    fn test_build_prompt_history_search_list_shows_query_and_extra_line_count() {
+        // Arrange
+        let mut history_search = PromptHistorySearchState::new(vec![
+            "Fix login\nKeep the session cookie".to_string(),
+            "Update docs".to_string(),
+        ]);
+        history_search.query = "login".to_string();
+
+        // Act
+        let menu = build_prompt_history_search_list(&history_search, 5);
+
+        // Assert
+        assert!(menu.title.starts_with("History: login"));
+        assert_eq!(menu.items.len(), 1);
+        assert_eq!(menu.items[0].label, "Fix login");
+        assert_eq!(menu.items[0].metadata.as_deref(), Some("+1 lines"));
+    }
+
+    #[test]
+    fn test_build_prompt_history_search_list_keeps_placeholder_row_without_matches() {
+        // Arrange
+        let mut history_search = PromptHistorySearchState::new(vec!["Update docs".to_string()]);
+        history_search.query = "zzz".to_string();
+
+        // Act
+        let menu = build_prompt_history_search_list(&history_search, 5);
+
+        // Assert
+        assert_eq!(menu.items.len(), 1);
+        assert_eq!(menu.items[0].label, "No matching prompts");
+    }
+
     #[test]
     fn test_build_slash_suggestion_list_for_command_stage_includes_commands() {
         // Act
@@ -1376,7 +1864,6 @@ mod tests {
             &session,
             20,
             SessionOutputLineContext {
-                active_prompt_output: None,
                 active_progress: None,
                 done_session_output_mode: DoneSessionOutputMode::Summary,
                 review_status_message: None,
@@ -1406,6 +1893,22 @@ mod tests {
         assert!(!help_text.contains("Enter: reply"));
     }
 
+    #[test]
+    fn test_view_help_text_review_shows_plan_review_for_proposed_plan() {
+        // Arrange
+        let mut session = session_fixture();
+        session.status = Status::Review;
+        let mut plan = SessionPlan::planning();
+        plan.propose_steps(vec![PlanStep::new("Add the column", Vec::new())]);
+        session.plan = Some(plan);
+
+        // Act
+        let help_text = view_help_text(&session, true, DoneSessionOutputMode::Summary);
+
+        // Assert
+        assert!(help_text.starts_with("q: back | a: review plan"));
+    }
+
     #[test]
     fn test_view_help_text_rebasing_keeps_open() {
         // Arrange
@@ -1585,7 +2088,6 @@ mod tests {
             scroll_offset: None,
         };
         let page = SessionChatPage::new(SessionChatPageInput {
-            active_prompt_output: None,
             active_progress: None,
             default_reasoning_level: ReasoningLevel::default(),
             markdown_render_cache: test_markdown_render_cache(),
@@ -1659,6 +2161,7 @@ mod tests {
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: Some("Preparing review...".to_string()),
             review_text: Some("Focused review".to_string()),
             session_id: "session-id".into(),
@@ -1689,7 +2192,6 @@ mod tests {
             &session,
             40,
             SessionOutputLineContext {
-                active_prompt_output: None,
                 active_progress: None,
                 done_session_output_mode: DoneSessionOutputMode::Summary,
                 review_status_message: None,
@@ -1702,7 +2204,6 @@ mod tests {
             &session,
             40,
             SessionOutputLineContext {
-                active_prompt_output: None,
                 active_progress: None,
                 done_session_output_mode: DoneSessionOutputMode::Summary,
                 review_status_message: Some("Preparing review..."),
@@ -1723,28 +2224,31 @@ mod tests {
         let attachment_count = 2;
 
         // Act
-        let footer_line = SessionChatPage::prompt_footer_line(&session, attachment_count);
+        let footer_line = SessionChatPage::prompt_footer_line(&session, attachment_count, false);
 
         // Assert
         assert_eq!(
             footer_line.to_string(),
-            "Enter: submit | Alt+Enter: newline | Ctrl+V/Alt+V: paste image | Esc: cancel | 2 \
-             images ready"
+            "Enter: submit | Alt+Enter: newline | Ctrl+V/Alt+V: paste image | Esc: cancel | \
+             Ctrl+G: editor | 2 images ready"
         );
         assert_eq!(
             footer_line.spans[0].style,
             Style::default()
-                .fg(Color::Cyan)
+                .fg(style::palette::accent())
                 .add_modifier(Modifier::BOLD)
         );
-        assert_eq!(footer_line.spans[1].style, Style::default().fg(Color::Gray));
+        assert_eq!(
+            footer_line.spans[1].style,
+            Style::default().fg(style::palette::text_muted())
+        );
         assert_eq!(
             footer_line.spans[footer_line.spans.len() - 2].style,
-            Style::default().fg(Color::DarkGray)
+            Style::default().fg(style::palette::text_subtle())
         );
         assert_eq!(
             footer_line.spans[footer_line.spans.len() - 1].style,
-            Style::default().fg(Color::Gray)
+            Style::default().fg(style::palette::text_muted())
         );
         assert!(!footer_line.to_string().contains("send images with Codex"));
     }
@@ -1758,13 +2262,29 @@ mod tests {
         let attachment_count = 1;
 
         // Act
-        let footer_line = SessionChatPage::prompt_footer_line(&session, attachment_count);
+        let footer_line = SessionChatPage::prompt_footer_line(&session, attachment_count, false);
 
         // Assert
         assert!(footer_line.to_string().contains("1 image ready"));
         assert!(!footer_line.to_string().contains("send images with Codex"));
     }
 
+    #[test]
+    fn test_prompt_footer_line_notes_pending_context_capture() {
+        // Arrange
+        let session = session_fixture();
+
+        // Act
+        let footer_line = SessionChatPage::prompt_footer_line(&session, 0, true);
+
+        // Assert
+        assert!(
+            footer_line
+                .to_string()
+                .ends_with("Ctrl+G: editor | capturing context...")
+        );
+    }
+
     #[test]
     fn test_prompt_footer_line_uses_stage_label_for_new_sessions() {
         // Arrange
@@ -1773,7 +2293,7 @@ mod tests {
         session.is_draft = true;
 
         // Act
-        let footer_line = SessionChatPage::prompt_footer_line(&session, 0);
+        let footer_line = SessionChatPage::prompt_footer_line(&session, 0, false);
 
         // Assert
         assert!(footer_line.to_string().contains("Enter: stage draft"));
@@ -1788,10 +2308,12 @@ mod tests {
         let answer = "Use two phases: schema and runtime.";
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: question.clone(),
             }],
@@ -1826,10 +2348,12 @@ mod tests {
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Need details?".to_string(),
             }],
@@ -1856,10 +2380,12 @@ mod tests {
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Yes".to_string(), "No".to_string()],
                 text: "Continue?".to_string(),
             }],
@@ -2049,7 +2575,6 @@ mod tests {
         session.in_progress_started_at = Some(0);
         let mode = AppMode::List;
         let mut page = SessionChatPage::new(SessionChatPageInput {
-            active_prompt_output: None,
             active_progress: None,
             default_reasoning_level: ReasoningLevel::default(),
             markdown_render_cache: test_markdown_render_cache(),
@@ -2083,10 +2608,12 @@ mod tests {
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: "Need a detailed migration plan with rollback guidance?".to_string(),
             }],
@@ -2121,10 +2648,12 @@ mod tests {
         let question = "Need a detailed migration plan with rollback guidance?".to_string();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: Vec::new(),
                 text: question.clone(),
             }],
@@ -2171,10 +2700,12 @@ mod tests {
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec!["Yes".to_string(), "No".to_string()],
                 text: "Continue?".to_string(),
             }],
@@ -2206,16 +2737,64 @@ mod tests {
         assert!(text.contains("No"), "should render second option");
     }
 
+    #[test]
+    fn test_render_question_mode_multi_select_shows_checkboxes_and_kind_hint() {
+        // Arrange
+        let session = session_fixture();
+        let mode = AppMode::Question {
+            at_mention_state: None,
+            checked_option_indexes: vec![1],
+            review_status_message: None,
+            review_text: None,
+            session_id: "session-id".into(),
+            questions: vec![
+                QuestionItem::with_options(
+                    "Which targets?",
+                    vec!["Linux".to_string(), "macOS".to_string()],
+                )
+                .with_kind(QuestionKind::MultiSelect),
+            ],
+            responses: Vec::new(),
+            current_index: 0,
+            focus: QuestionFocus::Answer,
+            input: InputState::default(),
+            scroll_offset: None,
+            selected_option_index: Some(0),
+        };
+        let mut page = test_session_chat_page(&session, &mode);
+        let width = 60;
+        let height = 14;
+        let backend = ratatui::backend::TestBackend::new(width, height);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+
+        // Act
+        terminal
+            .draw(|frame| {
+                let area = frame.area();
+                Page::render(&mut page, frame, area);
+            })
+            .expect("failed to draw multi-select question mode");
+
+        // Assert
+        let text = buffer_text(terminal.backend().buffer());
+        assert!(text.contains("Select all that apply"));
+        assert!(text.contains("1. [ ] Linux"));
+        assert!(text.contains("2. [x] macOS"));
+        assert!(text.contains("Space: toggle"));
+    }
+
     #[test]
     fn test_render_question_mode_with_options_in_small_terminal_does_not_panic() {
         // Arrange
         let session = session_fixture();
         let mode = AppMode::Question {
             at_mention_state: None,
+            checked_option_indexes: Vec::new(),
             review_status_message: None,
             review_text: None,
             session_id: "session-id".into(),
             questions: vec![QuestionItem {
+                kind: QuestionKind::SingleChoice,
                 options: vec![
                     "A".to_string(),
                     "B".to_string(),
@@ -1,23 +1,26 @@
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState};
 
 use crate::domain::session::{Session, SessionSize, Status};
-use crate::ui::state::help_action;
+use crate::ui::layout::PageAreas;
+use crate::ui::state::help_action::{self, HelpAction};
 use crate::ui::util::{
     first_table_column_width, format_duration_compact, inline_text, truncate_spans_with_ellipsis,
 };
-use crate::ui::{Page, markdown, style};
+use crate::ui::{Page, layout, markdown, style};
+
+/// Rows occupied by the table header and its bottom margin.
+const TABLE_HEADER_HEIGHT: u16 = 2;
+/// Index of the status column, which also shows the review-request indicator.
+const STATUS_COLUMN_INDEX: usize = 3;
 
 /// Uses row-background highlighting without a textual cursor glyph.
 const ROW_HIGHLIGHT_SYMBOL: &str = "";
 /// Horizontal spacing between table columns in the session list.
 const TABLE_COLUMN_SPACING: u16 = 2;
-/// Shared page margin that keeps table and footer spacing aligned with other
-/// pages.
-const PAGE_MARGIN: u16 = 1;
 /// Placeholder text rendered under group headers with no sessions.
 const GROUP_EMPTY_PLACEHOLDER: &str = "No sessions...";
 
@@ -44,27 +47,19 @@ impl<'a> SessionListPage<'a> {
             wall_clock_unix_seconds,
         }
     }
-
-    /// Splits the available page area into main and footer regions using the
-    /// shared page margin convention.
-    fn content_chunks(area: Rect) -> (Rect, Rect) {
-        let chunks = Layout::default()
-            .constraints([Constraint::Min(0), Constraint::Length(1)])
-            .margin(PAGE_MARGIN)
-            .split(area);
-
-        (chunks[0], chunks[1])
-    }
 }
 
 impl Page for SessionListPage<'_> {
     fn render(&mut self, f: &mut Frame, area: Rect) {
-        let (main_area, footer_area) = Self::content_chunks(area);
+        let PageAreas {
+            footer_area,
+            main_area,
+        } = layout::page_areas(area);
 
-        let selected_style = Style::default().bg(style::palette::SURFACE);
+        let selected_style = Style::default().bg(style::palette::surface());
         let header_style = Style::default()
-            .bg(style::palette::SURFACE)
-            .fg(style::palette::TEXT_MUTED)
+            .bg(style::palette::surface())
+            .fg(style::palette::text_muted())
             .add_modifier(Modifier::BOLD);
         let header_cells = ["Session", "Model", "Size", "Status", "Timer"]
             .iter()
@@ -75,13 +70,7 @@ impl Page for SessionListPage<'_> {
             .bottom_margin(1);
 
         let block = Block::default().borders(Borders::ALL).title("Sessions");
-        let column_constraints = [
-            Constraint::Fill(1),
-            model_column_width(self.sessions),
-            size_column_width(),
-            status_column_width(self.sessions),
-            timer_column_width(self.sessions, self.wall_clock_unix_seconds),
-        ];
+        let column_constraints = column_constraints(self.sessions, self.wall_clock_unix_seconds);
         let title_column_width = first_table_column_width(
             block.inner(main_area).width,
             &column_constraints,
@@ -115,16 +104,84 @@ impl Page for SessionListPage<'_> {
     }
 }
 
-/// Builds footer help content for session list mode.
-fn session_list_help_line(selected_session: Option<&Session>) -> Line<'static> {
+/// Session row under one mouse position in the session list.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SessionListHit {
+    /// Whether the position falls inside the status column, which carries the
+    /// review-request indicator.
+    pub is_status_column: bool,
+    /// Index of the hit session in the raw session slice.
+    pub session_index: usize,
+}
+
+/// Returns the session row rendered at `position` in the session list page
+/// drawn in `area`.
+///
+/// `table_offset` is the grouped-row scroll offset left by the last render.
+/// Group labels, placeholders, the header, and borders resolve to `None`.
+pub fn session_at(
+    sessions: &[Session],
+    table_offset: usize,
+    area: Rect,
+    position: Position,
+    wall_clock_unix_seconds: i64,
+) -> Option<SessionListHit> {
+    let table_area = Block::default()
+        .borders(Borders::ALL)
+        .inner(layout::page_areas(area).main_area);
+    let rows_area = Rect {
+        y: table_area.y.saturating_add(TABLE_HEADER_HEIGHT),
+        height: table_area.height.saturating_sub(TABLE_HEADER_HEIGHT),
+        ..table_area
+    };
+    if !rows_area.contains(position) {
+        return None;
+    }
+
+    let row_index = table_offset + usize::from(position.y - rows_area.y);
+    let SessionTableRow::Session(session) =
+        grouped_session_rows(sessions).into_iter().nth(row_index)?
+    else {
+        return None;
+    };
+    let session_index = sessions
+        .iter()
+        .position(|candidate| candidate.id == session.id)?;
+    let columns = Layout::horizontal(column_constraints(sessions, wall_clock_unix_seconds))
+        .spacing(TABLE_COLUMN_SPACING)
+        .split(rows_area);
+    let is_status_column = columns
+        .get(STATUS_COLUMN_INDEX)
+        .is_some_and(|status_column| status_column.contains(position));
+
+    Some(SessionListHit {
+        is_status_column,
+        session_index,
+    })
+}
+
+/// Returns the session table column widths in render order.
+fn column_constraints(sessions: &[Session], wall_clock_unix_seconds: i64) -> [Constraint; 5] {
+    [
+        Constraint::Fill(1),
+        model_column_width(sessions),
+        size_column_width(),
+        status_column_width(sessions),
+        timer_column_width(sessions, wall_clock_unix_seconds),
+    ]
+}
+
+/// Returns the footer actions shown for the selected session.
+pub(crate) fn footer_actions(selected_session: Option<&Session>) -> Vec<HelpAction> {
     let can_cancel_selected_session = selected_session.is_some_and(Session::allows_cancel_action);
     let can_open_selected_session = selected_session.is_some();
-    let actions = help_action::session_list_footer_actions(
-        can_cancel_selected_session,
-        can_open_selected_session,
-    );
 
-    help_action::footer_line(&actions)
+    help_action::session_list_footer_actions(can_cancel_selected_session, can_open_selected_session)
+}
+
+/// Builds footer help content for session list mode.
+fn session_list_help_line(selected_session: Option<&Session>) -> Line<'static> {
+    help_action::footer_line(&footer_actions(selected_session))
 }
 
 /// Prepares list table state for grouped row rendering.
@@ -277,7 +334,7 @@ fn render_table_row(
 /// Renders a non-selectable group label row.
 fn render_group_label_row(group: SessionGroup) -> Row<'static> {
     let cells = vec![
-        Cell::from(group.label()).style(Style::default().fg(style::palette::ACCENT)),
+        Cell::from(group.label()).style(Style::default().fg(style::palette::accent())),
         Cell::from(""),
         Cell::from(""),
         Cell::from(""),
@@ -290,7 +347,8 @@ fn render_group_label_row(group: SessionGroup) -> Row<'static> {
 /// Renders a non-selectable placeholder row for empty groups.
 fn render_empty_group_placeholder_row() -> Row<'static> {
     let cells = vec![
-        Cell::from(GROUP_EMPTY_PLACEHOLDER).style(Style::default().fg(style::palette::TEXT_SUBTLE)),
+        Cell::from(GROUP_EMPTY_PLACEHOLDER)
+            .style(Style::default().fg(style::palette::text_subtle())),
         Cell::from(""),
         Cell::from(""),
         Cell::from(""),
@@ -412,12 +470,12 @@ fn timer_column_width(sessions: &[Session], wall_clock_unix_seconds: i64) -> Con
 /// Returns the palette color representing each session size bucket.
 fn size_color(size: SessionSize) -> Color {
     match size {
-        SessionSize::Xs => style::palette::SUCCESS,
-        SessionSize::S => style::palette::SUCCESS_SOFT,
-        SessionSize::M => style::palette::WARNING,
-        SessionSize::L => style::palette::WARNING_SOFT,
-        SessionSize::Xl => style::palette::DANGER_SOFT,
-        SessionSize::Xxl => style::palette::DANGER,
+        SessionSize::Xs => style::palette::success(),
+        SessionSize::S => style::palette::success_soft(),
+        SessionSize::M => style::palette::warning(),
+        SessionSize::L => style::palette::warning_soft(),
+        SessionSize::Xl => style::palette::danger_soft(),
+        SessionSize::Xxl => style::palette::danger(),
     }
 }
 
@@ -454,8 +512,10 @@ mod tests {
             in_progress_started_at: None,
             in_progress_total_seconds: 0,
             is_draft: false,
+            issue: None,
             model: AgentModel::Gemini3FlashPreview,
             output: String::new(),
+            plan: None,
             project_name: "project".to_string(),
             prompt: String::new(),
             reasoning_level_override: None,
@@ -468,6 +528,7 @@ mod tests {
             status,
             summary: None,
             title: Some(id.to_string()),
+            turns: Vec::new(),
             updated_at: 0,
         }
     }
@@ -510,31 +571,6 @@ mod tests {
         None
     }
 
-    #[test]
-    fn test_content_chunks_use_shared_page_margin() {
-        // Arrange
-        let area = Rect::new(5, 3, 40, 10);
-
-        // Act
-        let (main_area, footer_area) = SessionListPage::content_chunks(area);
-
-        // Assert
-        assert_eq!(main_area.x, area.x + PAGE_MARGIN);
-        assert_eq!(main_area.y, area.y + PAGE_MARGIN);
-        assert_eq!(main_area.width, area.width - PAGE_MARGIN.saturating_mul(2));
-        assert_eq!(
-            main_area.height,
-            area.height - PAGE_MARGIN.saturating_mul(2) - 1
-        );
-        assert_eq!(footer_area.x, area.x + PAGE_MARGIN);
-        assert_eq!(footer_area.y, area.y + area.height - PAGE_MARGIN - 1);
-        assert_eq!(
-            footer_area.width,
-            area.width - PAGE_MARGIN.saturating_mul(2)
-        );
-        assert_eq!(footer_area.height, 1);
-    }
-
     #[test]
     fn test_status_bar_fyi_rotates_between_session_list_messages() {
         // Arrange
@@ -868,12 +904,12 @@ mod tests {
     fn test_size_color_uses_expected_palette() {
         // Arrange
         let test_cases = [
-            (SessionSize::Xs, style::palette::SUCCESS),
-            (SessionSize::S, style::palette::SUCCESS_SOFT),
-            (SessionSize::M, style::palette::WARNING),
-            (SessionSize::L, style::palette::WARNING_SOFT),
-            (SessionSize::Xl, style::palette::DANGER_SOFT),
-            (SessionSize::Xxl, style::palette::DANGER),
+            (SessionSize::Xs, style::palette::success()),
+            (SessionSize::S, style::palette::success_soft()),
+            (SessionSize::M, style::palette::warning()),
+            (SessionSize::L, style::palette::warning_soft()),
+            (SessionSize::Xl, style::palette::danger_soft()),
+            (SessionSize::Xxl, style::palette::danger()),
         ];
 
         // Act & Assert
@@ -931,6 +967,77 @@ mod tests {
         assert!(help_text.contains("Enter: open session"));
     }
 
+    #[test]
+    fn test_session_at_maps_rendered_rows_and_status_column() {
+        // Arrange
+        let backend = ratatui::backend::TestBackend::new(100, 14);
+        let mut terminal = ratatui::Terminal::new(backend).expect("failed to create terminal");
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+        let sessions = vec![
+            test_session("archive-1", Status::Done),
+            test_session("active-1", Status::Review),
+        ];
+        terminal
+            .draw(|frame| {
+                SessionListPage::new(&sessions, &mut table_state, 0).render(frame, frame.area());
+            })
+            .expect("failed to draw");
+        let buffer = terminal.backend().buffer();
+        let active_row_y = (0..buffer.area.height)
+            .find(|row| {
+                (0..buffer.area.width)
+                    .map(|column| buffer[(column, *row)].symbol())
+                    .collect::<String>()
+                    .contains("active-1")
+            })
+            .expect("active session row should render");
+        let status_column_x = (0..buffer.area.width)
+            .find(|column| buffer[(*column, active_row_y)].symbol() == "R")
+            .expect("status cell should render");
+        let area = buffer.area;
+
+        // Act
+        let title_hit = session_at(
+            &sessions,
+            table_state.offset(),
+            area,
+            Position::new(4, active_row_y),
+            0,
+        );
+        let status_hit = session_at(
+            &sessions,
+            table_state.offset(),
+            area,
+            Position::new(status_column_x, active_row_y),
+            0,
+        );
+        let group_label_hit = session_at(
+            &sessions,
+            table_state.offset(),
+            area,
+            Position::new(4, active_row_y - 1),
+            0,
+        );
+
+        // Assert
+        assert_eq!(
+            title_hit,
+            Some(SessionListHit {
+                is_status_column: false,
+                session_index: 1,
+            })
+        );
+        assert_eq!(
+            status_hit,
+            Some(SessionListHit {
+                is_status_column: true,
+                session_index: 1,
+            })
+        );
+        assert_eq!(group_label_hit, None);
+    }
+
     #[test]
     fn test_render_shows_live_active_work_timer_in_grouped_session_row() {
         // Arrange
@@ -1043,8 +1150,8 @@ mod tests {
         let buffer = terminal.backend().buffer();
         let fallback_cell = &buffer.content()[0];
         let new_cell = find_text_start_cell(buffer, "New").unwrap_or(fallback_cell);
-        assert_eq!(new_cell.fg, style::palette::TEXT_MUTED);
-        assert_eq!(new_cell.bg, style::palette::SURFACE);
+        assert_eq!(new_cell.fg, style::palette::text_muted());
+        assert_eq!(new_cell.bg, style::palette::surface());
         assert_ne!(new_cell.fg, new_cell.bg);
     }
 }
@@ -1,12 +1,13 @@
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::{Constraint, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::Line;
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 
 use crate::app::setting::SettingsManager;
+use crate::ui::layout::PageAreas;
 use crate::ui::state::help_action;
-use crate::ui::{Page, style};
+use crate::ui::{Page, layout, style};
 
 /// Uses row-background highlighting without a textual cursor glyph.
 const ROW_HIGHLIGHT_SYMBOL: &str = "";
@@ -27,18 +28,15 @@ impl<'a> SettingsPage<'a> {
 
 impl Page for SettingsPage<'_> {
     fn render(&mut self, f: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
-            .constraints([Constraint::Min(0), Constraint::Length(1)])
-            .margin(1)
-            .split(area);
+        let PageAreas {
+            footer_area,
+            main_area,
+        } = layout::page_areas(area);
 
-        let main_area = chunks[0];
-        let footer_area = chunks[1];
-
-        let selected_style = Style::default().bg(style::palette::SURFACE);
+        let selected_style = Style::default().bg(style::palette::surface());
         let header_style = Style::default()
-            .bg(style::palette::SURFACE)
-            .fg(style::palette::TEXT_MUTED)
+            .bg(style::palette::surface())
+            .fg(style::palette::text_muted())
             .add_modifier(Modifier::BOLD);
         let header_cells = ["Setting", "Value"].iter().map(|h| Cell::from(*h));
         let header = Row::new(header_cells)
@@ -5,6 +5,7 @@ use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Cell, Paragraph, Row, Table};
 
 use crate::domain::session::{DailyActivity, Session};
+use crate::ui::layout::PageAreas;
 use crate::ui::page::session_list::{model_column_width, project_column_width};
 use crate::ui::state::help_action;
 use crate::ui::util::{
@@ -12,7 +13,7 @@ use crate::ui::util::{
     format_token_count, heatmap_intensity_level, heatmap_max_count, inline_text,
     visible_heatmap_week_count,
 };
-use crate::ui::{Page, style};
+use crate::ui::{Page, layout, style};
 
 const DAY_LABELS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
 const HEATMAP_CELL_WIDTH: usize = 2;
@@ -41,13 +42,10 @@ impl<'a> StatsPage<'a> {
 impl Page for StatsPage<'_> {
     /// Renders the dashboard with activity heatmap, token table, and footer.
     fn render(&mut self, f: &mut Frame, area: Rect) {
-        let chunks = Layout::default()
-            .constraints([Constraint::Min(0), Constraint::Length(1)])
-            .margin(1)
-            .split(area);
-
-        let main_area = chunks[0];
-        let footer_area = chunks[1];
+        let PageAreas {
+            footer_area,
+            main_area,
+        } = layout::page_areas(area);
         let main_chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -77,8 +75,8 @@ impl StatsPage<'_> {
     /// Renders per-session token statistics using the shared table palette.
     fn render_table(&self, f: &mut Frame, area: Rect) {
         let header_style = Style::default()
-            .bg(style::palette::SURFACE)
-            .fg(style::palette::TEXT_MUTED)
+            .bg(style::palette::surface())
+            .fg(style::palette::text_muted())
             .add_modifier(Modifier::BOLD);
         let header_cells = ["Session", "Project", "Model", "Input", "Output"]
             .iter()
@@ -148,7 +146,7 @@ impl StatsPage<'_> {
             output_display
         );
         let stats = Paragraph::new(summary)
-            .style(Style::default().fg(style::palette::TEXT_MUTED))
+            .style(Style::default().fg(style::palette::text_muted()))
             .alignment(Alignment::Right);
         f.render_widget(stats, footer_chunks[1]);
     }
@@ -171,13 +169,13 @@ impl StatsPage<'_> {
         );
         lines.push(Line::from(Span::styled(
             month_row,
-            Style::default().fg(style::palette::TEXT_MUTED),
+            Style::default().fg(style::palette::text_muted()),
         )));
 
         for (day_index, day_label) in DAY_LABELS.iter().enumerate() {
             let mut spans = vec![Span::styled(
                 format!("{day_label} "),
-                Style::default().fg(style::palette::TEXT_MUTED),
+                Style::default().fg(style::palette::text_muted()),
             )];
 
             let first_visible_week = grid[day_index].len().saturating_sub(visible_week_count);
@@ -195,7 +193,7 @@ impl StatsPage<'_> {
         if content_width < 24 {
             lines.push(Line::from(Span::styled(
                 format!("Max/day: {max_count}"),
-                Style::default().fg(style::palette::TEXT_MUTED),
+                Style::default().fg(style::palette::text_muted()),
             )));
 
             return lines;
@@ -203,7 +201,7 @@ impl StatsPage<'_> {
 
         let mut legend = vec![Span::styled(
             "Less ",
-            Style::default().fg(style::palette::TEXT_MUTED),
+            Style::default().fg(style::palette::text_muted()),
         )];
         for intensity in 0_u8..=4 {
             legend.push(Span::styled(
@@ -214,7 +212,7 @@ impl StatsPage<'_> {
         }
         legend.push(Span::styled(
             "More",
-            Style::default().fg(style::palette::TEXT_MUTED),
+            Style::default().fg(style::palette::text_muted()),
         ));
         if content_width >= 36 {
             legend.push(Span::raw(format!(" | Max/day: {max_count}")));
@@ -235,7 +233,7 @@ impl StatsPage<'_> {
             2 => Color::Rgb(0, 109, 50),
             3 => Color::Rgb(38, 166, 65),
             4 => Color::Rgb(57, 211, 83),
-            _ => Color::Rgb(33, 38, 45),
+            _ => style::palette::surface(),
         }
     }
 }
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 use std::path::Path;
 
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::Rect;
 use ratatui::widgets::TableState;
 
 use crate::app::session::session_branch;
@@ -10,8 +10,9 @@ use crate::app::session_state::SessionGitStatus;
 use crate::app::{SettingsManager, Tab, UpdateStatus};
 use crate::domain::project::ProjectListItem;
 use crate::domain::session::{DailyActivity, Session, SessionId};
+use crate::ui::layout::FrameAreas;
 use crate::ui::state::app_mode::{AppMode, ConfirmationViewMode, HelpContext};
-use crate::ui::{component, markdown, page, router};
+use crate::ui::{component, layout, markdown, page, router};
 
 /// A trait for UI pages that enforces a standard rendering interface.
 pub trait Page {
@@ -27,12 +28,13 @@ pub trait Component {
 
 /// Immutable data required to draw a single UI frame.
 pub struct RenderContext<'a> {
-    /// Exact prompt transcript blocks keyed by session id for active turns.
-    pub active_prompt_outputs: &'a HashMap<SessionId, String>,
     /// Identifier of the currently active project.
     pub active_project_id: i64,
     /// Active top-level tab selection.
     pub current_tab: Tab,
+    /// Raw session index highlighted on the `Dashboard` tab, when any
+    /// session is running.
+    pub dashboard_selected_session_index: Option<usize>,
     /// Whether the active project exposes the roadmap-backed `Tasks` tab.
     pub has_tasks_tab: bool,
     /// Current local branch name for the active project.
@@ -43,8 +45,12 @@ pub struct RenderContext<'a> {
     pub git_status: Option<(u32, u32)>,
     /// Newer stable version when one is available.
     pub latest_available_version: Option<&'a str>,
+    /// Reason the control socket failed to start, when it did.
+    pub control_api_error: Option<&'a str>,
     /// Shared render cache for session transcript markdown output.
     pub markdown_render_cache: &'a markdown::MarkdownRenderCache,
+    /// Shared render cache for syntax-highlighted diff segments.
+    pub diff_highlight_cache: &'a page::diff::DiffHighlightCache,
     /// Current app mode and its transient state.
     pub mode: &'a AppMode,
     /// Table selection state for the projects list.
@@ -121,18 +127,11 @@ struct FooterBarRenderContext<'a> {
 
 /// Renders a complete frame including status bar, content area, and footer.
 pub fn render(f: &mut Frame, context: RenderContext<'_>) {
-    let area = f.area();
-    let outer_chunks = Layout::default()
-        .constraints([
-            Constraint::Length(1),
-            Constraint::Min(0),
-            Constraint::Length(1),
-        ])
-        .split(area);
-
-    let status_bar_area = outer_chunks[0];
-    let content_area = outer_chunks[1];
-    let footer_bar_area = outer_chunks[2];
+    let FrameAreas {
+        content_area,
+        footer_bar_area,
+        status_bar_area,
+    } = layout::frame_areas(f.area());
 
     component::status_bar::StatusBar::new(current_version_display_text())
         .latest_available_version(
@@ -146,6 +145,7 @@ pub fn render(f: &mut Frame, context: RenderContext<'_>) {
         ))
         .fyi_rotation_index(context.status_bar_fyi_rotation_index)
         .update_status(context.update_status.cloned())
+        .control_api_error(context.control_api_error)
         .render(f, status_bar_area);
     render_footer_bar(
         f,
@@ -205,6 +205,10 @@ fn render_footer_bar(f: &mut Frame, footer_bar_area: Rect, context: FooterBarRen
             restore_view: ConfirmationViewMode { session_id, .. },
             ..
         }
+        | AppMode::PlanReview {
+            restore_view: ConfirmationViewMode { session_id, .. },
+            ..
+        }
         | AppMode::PublishBranchInput {
             restore_view: ConfirmationViewMode { session_id, .. },
             ..
@@ -318,6 +322,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let sessions = vec![session];
         let session_index_by_id = session_index_by_id(&sessions);
@@ -366,6 +371,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let sessions = vec![session];
         let session_index_by_id = session_index_by_id(&sessions);
@@ -509,6 +515,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let sessions = vec![session];
         let session_index_by_id = session_index_by_id(&sessions);
@@ -564,6 +571,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let sessions = vec![session];
         let session_index_by_id = session_index_by_id(&sessions);
@@ -619,6 +627,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let sessions = vec![session];
         let session_index_by_id = session_index_by_id(&sessions);
@@ -1,28 +1,41 @@
 use std::collections::HashMap;
 
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
-use ratatui::widgets::TableState;
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Modifier, Style};
+use ratatui::widgets::{Paragraph, TableState};
 
 use crate::app::{SettingsManager, Tab};
 use crate::domain::agent::ReasoningLevel;
 use crate::domain::input::InputState;
 use crate::domain::project::ProjectListItem;
 use crate::domain::session::{DailyActivity, Session, SessionId};
+use crate::ui::layout::{ListAreas, SplitAreas};
 use crate::ui::overlay::{
     HelpOverlayRenderContext, SyncBlockedPopupRenderContext, ViewInfoPopupRenderContext,
 };
-use crate::ui::state::app_mode::{AppMode, ConfirmationIntent, ConfirmationViewMode};
-use crate::ui::{Component, Page, RenderContext, component, markdown, overlay, page};
+use crate::ui::state::app_mode::{
+    AppMode, ConfirmationIntent, ConfirmationViewMode, ReviewRequestDraftState,
+};
+use crate::ui::state::plan_review::PlanReviewState;
+use crate::ui::{
+    Component, Page, RenderContext, component, layout, markdown, overlay, page, style,
+};
 
 /// Shared borrowed data required to render list-page backgrounds.
 pub(crate) struct ListBackgroundRenderContext<'a> {
     /// Identifier for the currently active project in the project list tab.
     pub(crate) active_project_id: i64,
     pub(crate) current_tab: Tab,
+    /// Raw session index highlighted on the dashboard tab.
+    pub(crate) dashboard_selected_session_index: Option<usize>,
     pub(crate) has_tasks_tab: bool,
+    /// Shared render cache for the split-layout session preview.
+    pub(crate) markdown_render_cache: &'a markdown::MarkdownRenderCache,
     pub(crate) project_table_state: &'a mut TableState,
     pub(crate) projects: &'a [ProjectListItem],
+    /// Active progress messages shown in the split-layout session preview.
+    pub(crate) session_progress_messages: &'a HashMap<SessionId, String>,
     pub(crate) sessions: &'a [Session],
     pub(crate) settings: &'a mut SettingsManager,
     pub(crate) stats_activity: &'a [DailyActivity],
@@ -37,9 +50,12 @@ struct RouteSharedContext<'a> {
     /// Identifier for the active project shared across list-mode renders.
     active_project_id: i64,
     current_tab: Tab,
+    dashboard_selected_session_index: Option<usize>,
     has_tasks_tab: bool,
+    markdown_render_cache: &'a markdown::MarkdownRenderCache,
     project_table_state: &'a mut TableState,
     projects: &'a [ProjectListItem],
+    session_progress_messages: &'a HashMap<SessionId, String>,
     sessions: &'a [Session],
     settings: &'a mut SettingsManager,
     stats_activity: &'a [DailyActivity],
@@ -56,9 +72,12 @@ impl RouteSharedContext<'_> {
         ListBackgroundRenderContext {
             active_project_id: self.active_project_id,
             current_tab: self.current_tab,
+            dashboard_selected_session_index: self.dashboard_selected_session_index,
             has_tasks_tab: self.has_tasks_tab,
+            markdown_render_cache: self.markdown_render_cache,
             project_table_state: self.project_table_state,
             projects: self.projects,
+            session_progress_messages: self.session_progress_messages,
             sessions: self.sessions,
             settings: self.settings,
             stats_activity: self.stats_activity,
@@ -73,7 +92,6 @@ impl RouteSharedContext<'_> {
 /// Borrowed inputs for rendering a session chat page.
 #[derive(Clone, Copy)]
 struct SessionChatRenderContext<'a> {
-    active_prompt_outputs: &'a HashMap<SessionId, String>,
     default_reasoning_level: ReasoningLevel,
     markdown_render_cache: &'a markdown::MarkdownRenderCache,
     mode: &'a AppMode,
@@ -90,12 +108,12 @@ struct SessionChatRenderContext<'a> {
 #[derive(Clone, Copy)]
 struct PublishBranchOverlayContext<'a> {
     default_branch_name: &'a str,
-    active_prompt_outputs: &'a HashMap<SessionId, String>,
     default_reasoning_level: ReasoningLevel,
     markdown_render_cache: &'a markdown::MarkdownRenderCache,
     input: &'a InputState,
     locked_upstream_ref: Option<&'a str>,
     restore_view: &'a ConfirmationViewMode,
+    review_request_draft: Option<&'a ReviewRequestDraftState>,
     session_progress_messages: &'a HashMap<SessionId, String>,
     session_worktree_availability: &'a HashMap<SessionId, bool>,
     sessions: &'a [Session],
@@ -104,8 +122,8 @@ struct PublishBranchOverlayContext<'a> {
 /// Shared immutable routing inputs that are not part of list-background state.
 #[derive(Clone, Copy)]
 struct RouteAuxContext<'a> {
-    active_prompt_outputs: &'a HashMap<SessionId, String>,
     default_reasoning_level: ReasoningLevel,
+    diff_highlight_cache: &'a page::diff::DiffHighlightCache,
     markdown_render_cache: &'a markdown::MarkdownRenderCache,
     session_progress_messages: &'a HashMap<SessionId, String>,
     session_worktree_availability: &'a HashMap<SessionId, bool>,
@@ -116,9 +134,10 @@ struct RouteAuxContext<'a> {
 pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>) {
     let RenderContext {
         active_project_id,
-        active_prompt_outputs,
         current_tab,
+        dashboard_selected_session_index,
         has_tasks_tab,
+        diff_highlight_cache,
         markdown_render_cache,
         mode,
         project_table_state,
@@ -139,9 +158,12 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
     let mut shared = RouteSharedContext {
         active_project_id,
         current_tab,
+        dashboard_selected_session_index,
         has_tasks_tab,
+        markdown_render_cache,
         project_table_state,
         projects,
+        session_progress_messages,
         sessions,
         settings,
         stats_activity,
@@ -152,8 +174,8 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
     };
 
     let aux = RouteAuxContext {
-        active_prompt_outputs,
         default_reasoning_level: shared.settings.reasoning_level,
+        diff_highlight_cache,
         markdown_render_cache,
         session_progress_messages,
         session_worktree_availability,
@@ -164,7 +186,40 @@ pub(crate) fn route_frame(f: &mut Frame, area: Rect, context: RenderContext<'_>)
         return;
     }
 
-    render_session_or_diff_mode(f, area, mode, shared.sessions, aux);
+    let session_area = if matches!(mode, AppMode::Diff { .. }) {
+        area
+    } else {
+        render_split_list_pane(f, area, &mut shared, aux.wall_clock_unix_seconds)
+    };
+
+    render_session_or_diff_mode(f, session_area, mode, shared.sessions, aux);
+}
+
+/// Renders the unfocused session list pane beside session-scoped modes when
+/// the split layout applies and returns the area left for the session pane.
+fn render_split_list_pane(
+    f: &mut Frame,
+    content_area: Rect,
+    shared: &mut RouteSharedContext<'_>,
+    wall_clock_unix_seconds: i64,
+) -> Rect {
+    let Some(SplitAreas {
+        list_area,
+        session_area,
+    }) = layout::split_areas(content_area, shared.settings.split_layout())
+    else {
+        return content_area;
+    };
+
+    render_list_pane(
+        f,
+        list_area,
+        shared.list_background(),
+        wall_clock_unix_seconds,
+    );
+    render_unfocused_pane(f, list_area);
+
+    session_area
 }
 
 /// Renders all list/overlay-driven modes and returns whether it handled `mode`.
@@ -183,6 +238,20 @@ fn render_list_or_overlay_mode(
             aux.wall_clock_unix_seconds,
         ),
         AppMode::Confirmation { .. } => render_confirmation_mode(f, area, mode, shared, aux),
+        AppMode::IssuePicker { .. } => overlay::render_issue_picker_overlay(
+            f,
+            area,
+            mode,
+            shared.list_background(),
+            aux.wall_clock_unix_seconds,
+        ),
+        AppMode::SessionSearch { .. } => overlay::render_session_search_overlay(
+            f,
+            area,
+            mode,
+            shared.list_background(),
+            aux.wall_clock_unix_seconds,
+        ),
 
         AppMode::SyncBlockedPopup {
             default_branch,
@@ -204,7 +273,9 @@ fn render_list_or_overlay_mode(
             },
         ),
         AppMode::ViewInfoPopup { .. } => {
-            render_view_info_popup_mode(f, area, mode, shared.sessions, aux);
+            let session_area = render_split_list_pane(f, area, shared, aux.wall_clock_unix_seconds);
+
+            render_view_info_popup_mode(f, session_area, mode, shared.sessions, aux);
         }
         AppMode::Help {
             context: help_context,
@@ -213,6 +284,7 @@ fn render_list_or_overlay_mode(
             f,
             area,
             HelpOverlayRenderContext {
+                diff_highlight_cache: aux.diff_highlight_cache,
                 help_context,
                 list_background: shared.list_background(),
                 markdown_render_cache: aux.markdown_render_cache,
@@ -226,6 +298,7 @@ fn render_list_or_overlay_mode(
         | AppMode::Question { .. }
         | AppMode::PublishBranchInput { .. }
         | AppMode::OpenCommandSelector { .. }
+        | AppMode::PlanReview { .. }
         | AppMode::Diff { .. } => {
             return false;
         }
@@ -260,19 +333,12 @@ fn render_confirmation_mode(
         ConfirmationIntent::MergeSession | ConfirmationIntent::RegenerateReview
     ) && let Some(view_mode) = restore_view
     {
+        let session_area = render_split_list_pane(f, area, shared, aux.wall_clock_unix_seconds);
+
         render_session_confirmation_overlay(
             f,
-            area,
-            SessionOverlayRenderContext {
-                active_prompt_outputs: aux.active_prompt_outputs,
-                default_reasoning_level: aux.default_reasoning_level,
-                markdown_render_cache: aux.markdown_render_cache,
-                restore_view: view_mode,
-                session_progress_messages: aux.session_progress_messages,
-                session_worktree_availability: aux.session_worktree_availability,
-                sessions: shared.sessions,
-                wall_clock_unix_seconds: aux.wall_clock_unix_seconds,
-            },
+            session_area,
+            SessionOverlayRenderContext::new(view_mode, shared.sessions, aux),
             &SessionConfirmationContext {
                 confirmation_message,
                 confirmation_title,
@@ -348,8 +414,6 @@ struct SessionConfirmationContext<'a> {
 /// session chat page.
 #[derive(Clone, Copy)]
 struct SessionOverlayRenderContext<'a> {
-    /// Exact prompt transcript blocks keyed by session id for active turns.
-    active_prompt_outputs: &'a HashMap<SessionId, String>,
     /// Active project-scoped default reasoning level.
     default_reasoning_level: ReasoningLevel,
     /// Shared render cache for session transcript markdown.
@@ -380,7 +444,6 @@ fn render_session_overlay_background(
         f,
         area,
         SessionChatRenderContext {
-            active_prompt_outputs: context.active_prompt_outputs,
             default_reasoning_level: context.default_reasoning_level,
             markdown_render_cache: context.markdown_render_cache,
             mode: &background_mode,
@@ -413,6 +476,26 @@ fn render_session_confirmation_overlay(
     .render(f, area);
 }
 
+impl<'a> SessionOverlayRenderContext<'a> {
+    /// Builds overlay render data for one restore view from shared routing
+    /// inputs.
+    fn new(
+        restore_view: &'a ConfirmationViewMode,
+        sessions: &'a [Session],
+        aux: RouteAuxContext<'a>,
+    ) -> Self {
+        Self {
+            default_reasoning_level: aux.default_reasoning_level,
+            markdown_render_cache: aux.markdown_render_cache,
+            restore_view,
+            session_progress_messages: aux.session_progress_messages,
+            session_worktree_availability: aux.session_worktree_availability,
+            sessions,
+            wall_clock_unix_seconds: aux.wall_clock_unix_seconds,
+        }
+    }
+}
+
 /// Renders session-scoped modes tied to one selected session.
 fn render_session_or_diff_mode(
     f: &mut Frame,
@@ -440,7 +523,6 @@ fn render_session_or_diff_mode(
             f,
             area,
             SessionChatRenderContext {
-                active_prompt_outputs: aux.active_prompt_outputs,
                 default_reasoning_level: aux.default_reasoning_level,
                 markdown_render_cache: aux.markdown_render_cache,
                 mode,
@@ -459,61 +541,49 @@ fn render_session_or_diff_mode(
         } => render_open_command_selector_overlay(
             f,
             area,
-            SessionOverlayRenderContext {
-                active_prompt_outputs: aux.active_prompt_outputs,
-                default_reasoning_level: aux.default_reasoning_level,
-                markdown_render_cache: aux.markdown_render_cache,
-                restore_view,
-                session_progress_messages: aux.session_progress_messages,
-                session_worktree_availability: aux.session_worktree_availability,
-                sessions,
-                wall_clock_unix_seconds: aux.wall_clock_unix_seconds,
-            },
+            SessionOverlayRenderContext::new(restore_view, sessions, aux),
             commands,
             *selected_command_index,
         ),
+        AppMode::PlanReview {
+            review,
+            restore_view,
+        } => render_plan_review_overlay(
+            f,
+            area,
+            SessionOverlayRenderContext::new(restore_view, sessions, aux),
+            review,
+        ),
         AppMode::PublishBranchInput {
             default_branch_name,
             input,
             locked_upstream_ref,
             restore_view,
+            review_request_draft,
             ..
         } => render_publish_branch_overlay(
             f,
             area,
             &PublishBranchOverlayContext {
                 default_branch_name,
-                active_prompt_outputs: aux.active_prompt_outputs,
                 default_reasoning_level: aux.default_reasoning_level,
                 markdown_render_cache: aux.markdown_render_cache,
                 input,
                 locked_upstream_ref: locked_upstream_ref.as_deref(),
                 restore_view,
+                review_request_draft: review_request_draft.as_ref(),
                 session_progress_messages: aux.session_progress_messages,
                 session_worktree_availability: aux.session_worktree_availability,
                 sessions,
             },
             aux.wall_clock_unix_seconds,
         ),
-        AppMode::Diff {
-            diff,
-            file_explorer_selected_index,
-            restore_question: _,
-            scroll_cache: _,
-            scroll_offset,
-            session_id,
-        } => render_diff_mode(
-            f,
-            area,
-            sessions,
-            session_id,
-            diff,
-            *scroll_offset,
-            *file_explorer_selected_index,
-        ),
+        AppMode::Diff { .. } => render_diff_mode(f, area, mode, sessions, aux.diff_highlight_cache),
         AppMode::List
         | AppMode::Confirmation { .. }
         | AppMode::SyncBlockedPopup { .. }
+        | AppMode::IssuePicker { .. }
+        | AppMode::SessionSearch { .. }
         | AppMode::ViewInfoPopup { .. }
         | AppMode::Help { .. } => {}
     }
@@ -534,6 +604,18 @@ fn render_open_command_selector_overlay(
         .render(f, area);
 }
 
+/// Renders the plan-review overlay above the originating session chat.
+fn render_plan_review_overlay(
+    f: &mut Frame,
+    area: Rect,
+    overlay_context: SessionOverlayRenderContext<'_>,
+    review: &PlanReviewState,
+) {
+    render_session_overlay_background(f, area, overlay_context);
+
+    component::plan_review_overlay::PlanReviewOverlay::new(review).render(f, area);
+}
+
 /// Renders the publish-branch input overlay above the originating session
 /// chat.
 fn render_publish_branch_overlay(
@@ -544,12 +626,12 @@ fn render_publish_branch_overlay(
 ) {
     let PublishBranchOverlayContext {
         default_branch_name,
-        active_prompt_outputs,
         default_reasoning_level,
         markdown_render_cache,
         input,
         locked_upstream_ref,
         restore_view,
+        review_request_draft,
         session_progress_messages,
         session_worktree_availability,
         sessions,
@@ -558,7 +640,6 @@ fn render_publish_branch_overlay(
         f,
         area,
         SessionOverlayRenderContext {
-            active_prompt_outputs,
             default_reasoning_level,
             markdown_render_cache,
             restore_view,
@@ -574,13 +655,13 @@ fn render_publish_branch_overlay(
         default_branch_name,
         locked_upstream_ref,
     )
+    .review_request_draft(review_request_draft)
     .render(f, area);
 }
 
 /// Renders the session chat page for all session-chat modes.
 fn render_session_chat(f: &mut Frame, area: Rect, context: SessionChatRenderContext<'_>) {
     let SessionChatRenderContext {
-        active_prompt_outputs,
         default_reasoning_level,
         markdown_render_cache,
         mode,
@@ -599,12 +680,8 @@ fn render_session_chat(f: &mut Frame, area: Rect, context: SessionChatRenderCont
     let active_progress = session_progress_messages
         .get(session_id)
         .map(std::string::String::as_str);
-    let active_prompt_output = active_prompt_outputs
-        .get(session_id)
-        .map(std::string::String::as_str);
 
     page::session_chat::SessionChatPage::new(page::session_chat::SessionChatPageInput {
-        active_prompt_output,
         active_progress,
         default_reasoning_level,
         markdown_render_cache,
@@ -626,36 +703,135 @@ fn render_session_chat(f: &mut Frame, area: Rect, context: SessionChatRenderCont
 fn render_diff_mode(
     f: &mut Frame,
     area: Rect,
+    mode: &AppMode,
     sessions: &[Session],
-    session_id: &str,
-    diff: &str,
-    scroll_offset: u16,
-    file_explorer_selected_index: usize,
+    diff_highlight_cache: &page::diff::DiffHighlightCache,
 ) {
-    if let Some(session) = sessions.iter().find(|session| session.id == session_id) {
+    let AppMode::Diff {
+        diff,
+        file_explorer_selected_index,
+        scroll_offset,
+        session_id,
+        ..
+    } = mode
+    else {
+        unreachable!("matched diff mode above");
+    };
+
+    if let Some(session) = sessions.iter().find(|session| &session.id == session_id) {
         page::diff::DiffPage::new(
             session,
-            diff.to_string(),
-            scroll_offset,
-            file_explorer_selected_index,
+            diff.clone(),
+            *scroll_offset,
+            *file_explorer_selected_index,
         )
+        .highlight_cache(diff_highlight_cache)
         .render(f, area);
     }
 }
 
 /// Renders base list tabs and the currently selected list tab content.
+///
+/// With the split layout enabled on a wide enough `Sessions` tab, the list
+/// moves to the left pane and the selected session's live transcript renders
+/// as an unfocused preview on the right.
 pub(crate) fn render_list_background(
     f: &mut Frame,
     content_area: Rect,
     context: ListBackgroundRenderContext<'_>,
     wall_clock_unix_seconds: i64,
+) {
+    let split_areas = if context.current_tab == Tab::Sessions {
+        layout::split_areas(content_area, context.settings.split_layout())
+    } else {
+        None
+    };
+    let Some(SplitAreas {
+        list_area,
+        session_area,
+    }) = split_areas
+    else {
+        render_list_pane(f, content_area, context, wall_clock_unix_seconds);
+
+        return;
+    };
+    let session_preview = SessionPreviewRenderContext {
+        default_reasoning_level: context.settings.reasoning_level,
+        markdown_render_cache: context.markdown_render_cache,
+        selected_session_index: context.table_state.selected(),
+        session_progress_messages: context.session_progress_messages,
+        sessions: context.sessions,
+        wall_clock_unix_seconds,
+    };
+
+    render_list_pane(f, list_area, context, wall_clock_unix_seconds);
+    render_session_preview(f, session_area, session_preview);
+    render_unfocused_pane(f, session_area);
+}
+
+/// Borrowed inputs for the read-only session preview in the split layout.
+#[derive(Clone, Copy)]
+struct SessionPreviewRenderContext<'a> {
+    default_reasoning_level: ReasoningLevel,
+    markdown_render_cache: &'a markdown::MarkdownRenderCache,
+    selected_session_index: Option<usize>,
+    session_progress_messages: &'a HashMap<SessionId, String>,
+    sessions: &'a [Session],
+    wall_clock_unix_seconds: i64,
+}
+
+/// Renders the selected session's header, live transcript, and diff stats as
+/// a read-only preview, or a hint when no session is selected.
+fn render_session_preview(f: &mut Frame, area: Rect, context: SessionPreviewRenderContext<'_>) {
+    let Some((session_index, session)) = context
+        .selected_session_index
+        .and_then(|session_index| Some((session_index, context.sessions.get(session_index)?)))
+    else {
+        let hint = Paragraph::new("Select a session to preview it here.")
+            .alignment(Alignment::Center)
+            .style(Style::default().fg(style::palette::text_muted()));
+        f.render_widget(hint, layout::centered_content_rect(area, area.width, 1));
+
+        return;
+    };
+
+    page::session_chat::SessionChatPage::new(page::session_chat::SessionChatPageInput {
+        active_progress: context
+            .session_progress_messages
+            .get(&session.id)
+            .map(String::as_str),
+        default_reasoning_level: context.default_reasoning_level,
+        markdown_render_cache: context.markdown_render_cache,
+        mode: &AppMode::List,
+        scroll_offset: None,
+        session_index,
+        sessions: context.sessions,
+        wall_clock_unix_seconds: context.wall_clock_unix_seconds,
+    })
+    .render(f, area);
+}
+
+/// Dims the pane that does not own keyboard focus in the split layout.
+fn render_unfocused_pane(f: &mut Frame, area: Rect) {
+    f.buffer_mut()
+        .set_style(area, Style::default().add_modifier(Modifier::DIM));
+}
+
+/// Renders list tabs and the selected list tab content into `content_area`.
+fn render_list_pane(
+    f: &mut Frame,
+    content_area: Rect,
+    context: ListBackgroundRenderContext<'_>,
+    wall_clock_unix_seconds: i64,
 ) {
     let ListBackgroundRenderContext {
         active_project_id,
         current_tab,
+        dashboard_selected_session_index,
         has_tasks_tab,
         project_table_state,
         projects,
+        session_progress_messages,
         sessions,
         settings,
         stats_activity,
@@ -663,14 +839,16 @@ pub(crate) fn render_list_background(
         task_roadmap_error,
         task_roadmap_scroll_offset,
         table_state,
+        ..
     } = context;
 
-    let chunks = Layout::default()
-        .constraints([Constraint::Length(3), Constraint::Min(0)])
-        .split(content_area);
+    let ListAreas {
+        page_area,
+        tabs_area,
+    } = layout::list_areas(content_area);
 
     component::tab::Tabs::new(current_tab, active_project_id, has_tasks_tab, projects)
-        .render(f, chunks[0]);
+        .render(f, tabs_area);
 
     match current_tab {
         Tab::Projects => {
@@ -679,7 +857,7 @@ pub(crate) fn render_list_background(
                 project_table_state,
                 active_project_id,
             )
-            .render(f, chunks[1]);
+            .render(f, page_area);
         }
         Tab::Sessions => {
             page::session_list::SessionListPage::new(
@@ -687,7 +865,16 @@ pub(crate) fn render_list_background(
                 table_state,
                 wall_clock_unix_seconds,
             )
-            .render(f, chunks[1]);
+            .render(f, page_area);
+        }
+        Tab::Dashboard => {
+            page::dashboard::DashboardPage::new(
+                sessions,
+                session_progress_messages,
+                dashboard_selected_session_index,
+                wall_clock_unix_seconds,
+            )
+            .render(f, page_area);
         }
         Tab::Tasks => {
             let mut page = page::task::TasksPage::new(
@@ -695,13 +882,13 @@ pub(crate) fn render_list_background(
                 task_roadmap_error,
                 task_roadmap_scroll_offset,
             );
-            page.render(f, chunks[1]);
+            page.render(f, page_area);
         }
         Tab::Stats => {
-            page::stat::StatsPage::new(sessions, stats_activity).render(f, chunks[1]);
+            page::stat::StatsPage::new(sessions, stats_activity).render(f, page_area);
         }
         Tab::Settings => {
-            page::setting::SettingsPage::new(settings).render(f, chunks[1]);
+            page::setting::SettingsPage::new(settings).render(f, page_area);
         }
     }
 }
@@ -753,6 +940,7 @@ mod tests {
             review_text: None,
             session_id: session_id.into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let progress_messages = HashMap::new();
         let cache = markdown::MarkdownRenderCache::default();
@@ -766,8 +954,8 @@ mod tests {
                     &mode,
                     &sessions,
                     RouteAuxContext {
-                        active_prompt_outputs: &HashMap::new(),
                         default_reasoning_level: ReasoningLevel::default(),
+                        diff_highlight_cache: &page::diff::DiffHighlightCache::default(),
                         markdown_render_cache: &cache,
                         session_progress_messages: &progress_messages,
                         session_worktree_availability: &HashMap::new(),
@@ -794,6 +982,7 @@ mod tests {
             review_text: None,
             session_id: "missing-session".into(),
             scroll_offset: None,
+            transcript_search: None,
         };
         let progress_messages = HashMap::new();
         let sessions = Vec::new();
@@ -810,8 +999,8 @@ mod tests {
                     &mode,
                     &sessions,
                     RouteAuxContext {
-                        active_prompt_outputs: &HashMap::new(),
                         default_reasoning_level: ReasoningLevel::default(),
+                        diff_highlight_cache: &page::diff::DiffHighlightCache::default(),
                         markdown_render_cache: &cache,
                         session_progress_messages: &progress_messages,
                         session_worktree_availability: &HashMap::new(),
@@ -855,8 +1044,8 @@ mod tests {
                     &mode,
                     &sessions,
                     RouteAuxContext {
-                        active_prompt_outputs: &HashMap::new(),
                         default_reasoning_level: ReasoningLevel::default(),
+                        diff_highlight_cache: &page::diff::DiffHighlightCache::default(),
                         markdown_render_cache: &cache,
                         session_progress_messages: &progress_messages,
                         session_worktree_availability: &HashMap::new(),
@@ -901,7 +1090,6 @@ mod tests {
                     frame,
                     frame.area(),
                     SessionOverlayRenderContext {
-                        active_prompt_outputs: &HashMap::new(),
                         default_reasoning_level: ReasoningLevel::High,
                         markdown_render_cache: &cache,
                         restore_view: &view_mode,
@@ -2,4 +2,7 @@
 
 pub mod app_mode;
 pub mod help_action;
+pub mod keymap;
+pub mod plan_review;
 pub mod prompt;
+pub mod transcript_search;
@@ -1,11 +1,13 @@
 use ratatui::layout::Rect;
 
 use super::help_action::{self, HelpAction, ViewHelpState, ViewSessionState};
+use super::plan_review::PlanReviewState;
 use super::prompt::{
     PromptAtMentionState, PromptAttachmentState, PromptHistoryState, PromptSlashState,
 };
+use super::transcript_search::TranscriptSearchState;
 use crate::domain::input::InputState;
-use crate::domain::session::{PublishBranchAction, SessionId};
+use crate::domain::session::{ForgeIssueSummary, PublishBranchAction, SessionId, SessionSearchHit};
 use crate::infra::agent::protocol::QuestionItem;
 
 /// Selects the visible panel content for session view output.
@@ -37,6 +39,8 @@ pub enum ConfirmationIntent {
     Quit,
     /// Confirms canceling a selected review session.
     CancelSession,
+    /// Confirms stopping the running turn of a dashboard session.
+    StopSession,
     /// Confirms queueing merge for the active view session.
     MergeSession,
     /// Confirms regenerating the focused review for the active view session.
@@ -64,6 +68,7 @@ impl ConfirmationViewMode {
             review_text: self.review_text,
             session_id: self.session_id,
             scroll_offset: self.scroll_offset,
+            transcript_search: None,
         }
     }
 }
@@ -82,7 +87,8 @@ pub struct DiffScrollCache {
 /// is focused), the full question state is snapshotted here so it can be
 /// restored when leaving the diff view.
 pub struct QuestionModeSnapshot {
-    pub at_mention_state: Option<PromptAtMentionState>,
+    pub at_mention_state: Option<Box<PromptAtMentionState>>,
+    pub checked_option_indexes: Vec<usize>,
     pub current_index: usize,
     pub input: InputState,
     pub questions: Vec<QuestionItem>,
@@ -100,6 +106,7 @@ impl QuestionModeSnapshot {
     pub fn into_question_mode(self) -> AppMode {
         AppMode::Question {
             at_mention_state: self.at_mention_state,
+            checked_option_indexes: self.checked_option_indexes,
             current_index: self.current_index,
             focus: QuestionFocus::Answer,
             input: self.input,
@@ -124,6 +131,109 @@ pub enum QuestionFocus {
     Chat,
 }
 
+/// Field that owns keyboard input inside the publish-branch popup.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ReviewRequestDraftFocus {
+    /// Remote branch name field.
+    #[default]
+    Branch,
+    /// Review-request title field.
+    Title,
+    /// Multiline review-request body field.
+    Body,
+}
+
+/// Generation state for one template-based review-request draft.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReviewRequestDraftStatus {
+    /// The utility prompt is still filling the repository template.
+    Loading,
+    /// The generated title and body are ready for editing.
+    Ready,
+    /// Generation failed; publishing falls back to the head commit message.
+    Failed(String),
+}
+
+/// Editable review-request preview filled from a repository template before
+/// publishing creates a new review request.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewRequestDraftState {
+    /// Editable review-request description.
+    pub body: InputState,
+    /// Field that currently receives text edits.
+    pub focus: ReviewRequestDraftFocus,
+    /// Current generation state of the draft.
+    pub status: ReviewRequestDraftStatus,
+    /// Repository-relative template path used to seed the draft.
+    pub template_path: String,
+    /// Editable single-line review-request title.
+    pub title: InputState,
+}
+
+impl ReviewRequestDraftState {
+    /// Creates a draft preview that waits for template generation.
+    pub fn loading(template_path: String) -> Self {
+        Self {
+            body: InputState::default(),
+            focus: ReviewRequestDraftFocus::Branch,
+            status: ReviewRequestDraftStatus::Loading,
+            template_path,
+            title: InputState::default(),
+        }
+    }
+
+    /// Returns whether the generated title and body can be edited.
+    pub fn is_ready(&self) -> bool {
+        self.status == ReviewRequestDraftStatus::Ready
+    }
+
+    /// Returns the focus that follows the current one, skipping the branch
+    /// field when it is locked and draft fields until generation completes.
+    #[must_use]
+    pub fn cycled_focus(
+        &self,
+        is_branch_locked: bool,
+        is_reverse: bool,
+    ) -> ReviewRequestDraftFocus {
+        let mut focus_order = Vec::with_capacity(3);
+        if !is_branch_locked {
+            focus_order.push(ReviewRequestDraftFocus::Branch);
+        }
+        if self.is_ready() {
+            focus_order.extend([
+                ReviewRequestDraftFocus::Title,
+                ReviewRequestDraftFocus::Body,
+            ]);
+        }
+
+        let Some(current_index) = focus_order.iter().position(|focus| *focus == self.focus) else {
+            return focus_order.first().copied().unwrap_or(self.focus);
+        };
+        let next_index = if is_reverse {
+            current_index
+                .checked_sub(1)
+                .unwrap_or(focus_order.len() - 1)
+        } else {
+            (current_index + 1) % focus_order.len()
+        };
+
+        focus_order[next_index]
+    }
+}
+
+/// Background-load state for the forge issue picker overlay.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum IssuePickerStatus {
+    /// Open issues are still being listed through the forge CLI.
+    Loading,
+    /// Issues are listed and one can be selected.
+    Ready,
+    /// The selected issue is loading before its session is created.
+    Starting,
+    /// Listing or loading failed with user-facing error text.
+    Failed(String),
+}
+
 /// Represents the active UI mode for the application.
 pub enum AppMode {
     List,
@@ -177,6 +287,34 @@ pub enum AppMode {
         /// Highlighted command index in `commands`.
         selected_command_index: usize,
     },
+    /// Plan-review overlay opened from session view when a plan-first
+    /// session has proposed steps waiting for approval.
+    PlanReview {
+        /// Reviewable plan rows and inline-edit state.
+        review: PlanReviewState,
+        /// View state restored after approval or cancel.
+        restore_view: ConfirmationViewMode,
+    },
+    /// List-mode overlay that starts one session from an open forge issue
+    /// assigned to the user or labelled for agents.
+    IssuePicker {
+        /// Listed issues in display/selection order.
+        issues: Vec<ForgeIssueSummary>,
+        /// Highlighted issue index in `issues`.
+        selected_issue_index: usize,
+        /// Current background-load state.
+        status: IssuePickerStatus,
+    },
+    /// List-mode overlay that searches session titles, prompts, summaries,
+    /// and transcripts across all projects.
+    SessionSearch {
+        /// Ranked hits for the current query, one per session.
+        hits: Vec<SessionSearchHit>,
+        /// Editable search query.
+        input: InputState,
+        /// Highlighted hit index in `hits`.
+        selected_hit_index: usize,
+    },
     /// Session-view popup that collects an optional remote branch name before
     /// publishing or refreshing the current forge review request.
     PublishBranchInput {
@@ -192,11 +330,15 @@ pub enum AppMode {
         publish_branch_action: PublishBranchAction,
         /// View state restored after publish or cancel.
         restore_view: ConfirmationViewMode,
+        /// Template-filled review-request preview, present only when the
+        /// repository ships a review-request template and publishing will
+        /// create a new review request.
+        review_request_draft: Option<ReviewRequestDraftState>,
     },
     /// Session chat composer for the first prompt or a follow-up reply.
     Prompt {
         /// Active `@`-mention dropdown state for file and directory lookup.
-        at_mention_state: Option<PromptAtMentionState>,
+        at_mention_state: Option<Box<PromptAtMentionState>>,
         /// Ordered local image attachments referenced by inline placeholders in
         /// `input`.
         attachment_state: PromptAttachmentState,
@@ -228,6 +370,8 @@ pub enum AppMode {
         review_text: Option<String>,
         session_id: SessionId,
         scroll_offset: Option<u16>,
+        /// Active transcript search, when the user has opened one.
+        transcript_search: Option<TranscriptSearchState>,
     },
     /// Focused diff view with file-tree navigation and independent scrolling.
     Diff {
@@ -249,7 +393,9 @@ pub enum AppMode {
     /// Interactive clarification flow that asks agent questions one-by-one.
     Question {
         /// File/directory mention dropdown state for the free-text input.
-        at_mention_state: Option<PromptAtMentionState>,
+        at_mention_state: Option<Box<PromptAtMentionState>>,
+        /// Option indexes checked for the active multi-select question.
+        checked_option_indexes: Vec<usize>,
         /// Focused-review status text kept visible above the clarification
         /// panel while a review is still loading or has failed.
         review_status_message: Option<String>,
@@ -271,7 +417,7 @@ pub enum AppMode {
         /// Scroll position applied to the session transcript above the
         /// question panel.
         scroll_offset: Option<u16>,
-        /// Highlighted option index when the current question has predefined
+        /// Highlighted option index when the current question lists answer
         /// options. `None` means free-text input is active.
         selected_option_index: Option<usize>,
     },
@@ -344,6 +490,7 @@ impl HelpContext {
                 review_text,
                 session_id,
                 scroll_offset,
+                transcript_search: None,
             },
             HelpContext::Diff {
                 diff,
@@ -371,6 +518,25 @@ impl HelpContext {
 #[cfg(test)]
 mod tests {
     use super::*;
+
+    #[test]
+    fn test_review_request_draft_cycled_focus_skips_locked_branch_and_loading_fields() {
+        // Arrange
+        let mut review_request_draft =
+            ReviewRequestDraftState::loading(".github/pull_request_template.md".to_string());
+
+        // Act
+        let loading_focus = review_request_draft.cycled_focus(false, false);
+        review_request_draft.status = ReviewRequestDraftStatus::Ready;
+        review_request_draft.focus = ReviewRequestDraftFocus::Body;
+        let locked_next_focus = review_request_draft.cycled_focus(true, false);
+        let reverse_focus = review_request_draft.cycled_focus(false, true);
+
+        // Assert
+        assert_eq!(loading_focus, ReviewRequestDraftFocus::Branch);
+        assert_eq!(locked_next_focus, ReviewRequestDraftFocus::Title);
+        assert_eq!(reverse_focus, ReviewRequestDraftFocus::Title);
+    }
     use crate::app::review_loading_message;
     use crate::domain::agent::AgentModel;
     use crate::domain::session::PublishBranchAction;
@@ -416,6 +582,7 @@ mod tests {
                 review_text: Some(ref review_text),
                 ref session_id,
                 scroll_offset: Some(7),
+                ..
             } if session_id == "session-id"
                 && review_status_message == &review_loading_message(AgentModel::Gpt54)
                 && review_text == "Critical finding"
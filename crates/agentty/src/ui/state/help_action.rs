@@ -1,14 +1,20 @@
-use ratatui::style::{Color, Modifier, Style};
+use std::borrow::Cow;
+
+use ratatui::layout::{Position, Rect};
+use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
+use unicode_width::UnicodeWidthStr;
 
 use crate::domain::session::{PublishBranchAction, Session, Status};
+use crate::ui::state::keymap::{self, KeyAction, KeymapMode};
+use crate::ui::style::palette;
 
 /// One user-visible shortcut entry that can be rendered in the footer and
 /// in the help popup.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub struct HelpAction {
     pub(crate) footer_label: &'static str,
-    pub(crate) key: &'static str,
+    pub(crate) key: Cow<'static, str>,
     pub(crate) popup_label: &'static str,
 }
 
@@ -21,7 +27,21 @@ impl HelpAction {
     ) -> Self {
         Self {
             footer_label,
-            key,
+            key: Cow::Borrowed(key),
+            popup_label,
+        }
+    }
+
+    /// Creates one help action descriptor whose key label comes from the
+    /// active keymap.
+    pub(crate) fn keyed(
+        key: impl Into<Cow<'static, str>>,
+        footer_label: &'static str,
+        popup_label: &'static str,
+    ) -> Self {
+        Self {
+            footer_label,
+            key: key.into(),
             popup_label,
         }
     }
@@ -97,28 +117,37 @@ pub(crate) fn session_list_actions(
     can_open_selected_session: bool,
 ) -> Vec<HelpAction> {
     let mut actions = list_base_actions();
-    actions.push(HelpAction::new(
+    actions.extend(list_action(
+        KeyAction::NewSession,
         "start new session",
-        "a",
         "Start new session",
     ));
-    actions.push(HelpAction::new(
+    actions.extend(list_action(
+        KeyAction::NewDraftSession,
         "start draft session",
-        "Shift+A",
         "Start draft session",
     ));
+    actions.extend(list_action(
+        KeyAction::IssuePicker,
+        "start from issue",
+        "Start session from issue",
+    ));
 
     if can_cancel_selected_session {
-        actions.push(HelpAction::new("cancel", "c", "Cancel session"));
+        actions.extend(list_action(
+            KeyAction::CancelSession,
+            "cancel",
+            "Cancel session",
+        ));
     }
 
     if can_open_selected_session {
-        actions.push(HelpAction::new("open session", "Enter", "Open session"));
+        actions.extend(list_action(KeyAction::Open, "open session", "Open session"));
     }
 
-    actions.push(HelpAction::new("nav", "j/k", "Navigate sessions"));
-    actions.push(HelpAction::new("next tab", "Tab", "Switch tab"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_navigation_action("nav", "Navigate sessions"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
@@ -128,22 +157,29 @@ pub(crate) fn session_list_actions(
 /// actions.
 pub(crate) fn project_list_actions() -> Vec<HelpAction> {
     let mut actions = list_base_actions();
-    actions.push(HelpAction::new("select", "Enter", "Select active project"));
-    actions.push(HelpAction::new("nav", "j/k", "Navigate projects"));
-    actions.push(HelpAction::new("next tab", "Tab", "Switch tab"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_action(
+        KeyAction::Open,
+        "select",
+        "Select active project",
+    ));
+    actions.extend(list_navigation_action("nav", "Navigate projects"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
 /// Returns compact projects footer actions for the page-level hint line.
 pub(crate) fn project_list_footer_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("quit", "q", "Quit"),
-        HelpAction::new("select", "Enter", "Select active project"),
-        HelpAction::new("nav", "j/k", "Navigate projects"),
-        HelpAction::new("help", "?", "Help"),
+    [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_action(KeyAction::Open, "select", "Select active project"),
+        list_navigation_action("nav", "Navigate projects"),
+        list_action(KeyAction::Help, "help", "Help"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 /// Returns compact session list footer actions for the page-level hint line.
@@ -151,28 +187,38 @@ pub(crate) fn session_list_footer_actions(
     can_cancel_selected_session: bool,
     can_open_selected_session: bool,
 ) -> Vec<HelpAction> {
-    let mut actions = list_base_actions();
-    actions.push(HelpAction::new(
+    let mut actions: Vec<HelpAction> = [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_action(KeyAction::Sync, "sync", "Sync"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    actions.extend(list_action(
+        KeyAction::NewSession,
         "start new session",
-        "a",
         "Start new session",
     ));
-    actions.push(HelpAction::new(
+    actions.extend(list_action(
+        KeyAction::NewDraftSession,
         "start draft",
-        "Shift+A",
         "Start draft session",
     ));
 
     if can_cancel_selected_session {
-        actions.push(HelpAction::new("cancel", "c", "Cancel session"));
+        actions.extend(list_action(
+            KeyAction::CancelSession,
+            "cancel",
+            "Cancel session",
+        ));
     }
 
     if can_open_selected_session {
-        actions.push(HelpAction::new("open session", "Enter", "Open session"));
+        actions.extend(list_action(KeyAction::Open, "open session", "Open session"));
     }
 
-    actions.push(HelpAction::new("nav", "j/k", "Navigate sessions"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_navigation_action("nav", "Navigate sessions"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
@@ -182,22 +228,25 @@ pub(crate) fn session_list_footer_actions(
 /// actions.
 pub(crate) fn settings_actions() -> Vec<HelpAction> {
     let mut actions = list_base_actions();
-    actions.push(HelpAction::new("nav", "j/k", "Navigate settings"));
-    actions.push(HelpAction::new("edit", "Enter", "Edit setting"));
-    actions.push(HelpAction::new("next tab", "Tab", "Switch tab"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_navigation_action("nav", "Navigate settings"));
+    actions.extend(list_action(KeyAction::Open, "edit", "Edit setting"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
 /// Returns compact settings footer actions for the page-level hint line.
 pub(crate) fn settings_footer_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("quit", "q", "Quit"),
-        HelpAction::new("nav", "j/k", "Navigate settings"),
-        HelpAction::new("edit", "Enter", "Edit setting"),
-        HelpAction::new("help", "?", "Help"),
+    [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_navigation_action("nav", "Navigate settings"),
+        list_action(KeyAction::Open, "edit", "Edit setting"),
+        list_action(KeyAction::Help, "help", "Help"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 /// Returns help actions for the stats page.
@@ -205,18 +254,58 @@ pub(crate) fn settings_footer_actions() -> Vec<HelpAction> {
 /// actions.
 pub(crate) fn stats_actions() -> Vec<HelpAction> {
     let mut actions = list_base_actions();
-    actions.push(HelpAction::new("next tab", "Tab", "Switch tab"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
 /// Returns compact stats footer actions for the page-level hint line.
 pub(crate) fn stats_footer_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("quit", "q", "Quit"),
-        HelpAction::new("help", "?", "Help"),
+    [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_action(KeyAction::Help, "help", "Help"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns help actions for the dashboard page.
+/// These entries are used by the help overlay and include all available
+/// actions.
+pub(crate) fn dashboard_actions(
+    can_stop_selected_session: bool,
+    can_open_selected_session: bool,
+) -> Vec<HelpAction> {
+    let mut actions = list_base_actions();
+    actions.extend(dashboard_session_actions(
+        can_stop_selected_session,
+        can_open_selected_session,
+    ));
+    actions.extend(list_navigation_action("nav", "Navigate running sessions"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
+
+    actions
+}
+
+/// Returns compact dashboard footer actions for the page-level hint line.
+pub(crate) fn dashboard_footer_actions(
+    can_stop_selected_session: bool,
+    can_open_selected_session: bool,
+) -> Vec<HelpAction> {
+    let mut actions: Vec<HelpAction> = list_action(KeyAction::Quit, "quit", "Quit")
+        .into_iter()
+        .collect();
+    actions.extend(dashboard_session_actions(
+        can_stop_selected_session,
+        can_open_selected_session,
+    ));
+    actions.extend(list_navigation_action("nav", "Navigate running sessions"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
+
+    actions
 }
 
 /// Returns help actions for the tasks page.
@@ -224,24 +313,26 @@ pub(crate) fn stats_footer_actions() -> Vec<HelpAction> {
 /// actions.
 pub(crate) fn task_actions() -> Vec<HelpAction> {
     let mut actions = list_base_actions();
-    actions.push(HelpAction::new("scroll", "j/k", "Scroll roadmap"));
-    actions.push(HelpAction::new("top", "g", "Scroll to top"));
-    actions.push(HelpAction::new("next tab", "Tab", "Switch tab"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(list_navigation_action("scroll", "Scroll roadmap"));
+    actions.extend(list_action(KeyAction::Top, "top", "Scroll to top"));
+    actions.extend(list_action(KeyAction::NextTab, "next tab", "Switch tab"));
+    actions.extend(list_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
 /// Returns compact tasks footer actions for the page-level hint line.
 pub(crate) fn task_footer_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("quit", "q", "Quit"),
-        HelpAction::new("scroll", "j/k", "Scroll roadmap"),
-        HelpAction::new("top", "g", "Scroll to top"),
-        HelpAction::new("help", "?", "Help"),
+    [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_navigation_action("scroll", "Scroll roadmap"),
+        list_action(KeyAction::Top, "top", "Scroll to top"),
+        list_action(KeyAction::Help, "help", "Help"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
 }
-
 /// Projects currently available view-mode actions into help entries.
 /// These entries are used by the help overlay and include all available
 /// actions.
@@ -274,62 +365,112 @@ pub(crate) fn view_actions(state: ViewHelpState) -> Vec<HelpAction> {
     let can_stop_session = state.session_state == ViewSessionState::InProgress;
     let can_toggle_done_output = state.session_state == ViewSessionState::Done;
 
-    let mut actions = vec![HelpAction::new("back", "q", "Back to list")];
+    let mut actions = view_action(KeyAction::Back, "back", "Back to list")
+        .into_iter()
+        .collect::<Vec<_>>();
 
     append_view_prompt_actions(&mut actions, state.session_state, can_edit_session);
 
     if can_stop_session {
-        actions.push(HelpAction::new("stop", "Ctrl+c", "Stop running session"));
+        actions.extend(view_action(KeyAction::Stop, "stop", "Stop running session"));
     }
 
     if state.session_state == ViewSessionState::NewSession {
-        actions.push(HelpAction::new("start", "s", "Start staged session"));
+        actions.extend(view_action(
+            KeyAction::StartSession,
+            "start",
+            "Start staged session",
+        ));
     }
 
     if can_open_worktree {
-        actions.push(HelpAction::new("open", "o", "Open worktree"));
+        actions.extend(view_action(
+            KeyAction::OpenWorktree,
+            "open",
+            "Open worktree",
+        ));
     }
 
     if can_show_diff {
-        actions.push(HelpAction::new("diff", "d", "Show diff"));
+        actions.extend(view_action(KeyAction::Diff, "diff", "Show diff"));
     }
 
     if can_show_review {
-        actions.push(HelpAction::new("review", "f", "Focused review"));
+        actions.extend(view_action(KeyAction::Review, "review", "Focused review"));
     }
 
     if let Some(publish_pull_request_action) = state.publish_pull_request_action {
-        actions.push(publish_pull_request_help_action(
+        actions.extend(publish_pull_request_help_action(
             publish_pull_request_action,
         ));
     }
 
     if can_edit_session {
-        actions.push(HelpAction::new(
+        actions.extend(view_action(
+            KeyAction::Merge,
             "add to merge queue",
-            "m",
             "Add to merge queue",
         ));
 
         if state.session_state != ViewSessionState::AgentReview {
-            actions.push(HelpAction::new("rebase", "r", "Rebase"));
+            actions.extend(view_action(KeyAction::Rebase, "rebase", "Rebase"));
         }
     }
 
     if can_toggle_done_output {
-        actions.push(HelpAction::new("toggle view", "t", "Switch summary/output"));
+        actions.extend(view_action(
+            KeyAction::ToggleOutput,
+            "toggle view",
+            "Switch summary/output",
+        ));
     }
 
-    actions.push(HelpAction::new("scroll", "j/k", "Scroll output"));
-    actions.push(HelpAction::new("top", "g", "Scroll to top"));
-    actions.push(HelpAction::new("bottom", "G", "Scroll to bottom"));
-    actions.push(HelpAction::new("half down", "Ctrl+d", "Half page down"));
-    actions.push(HelpAction::new("half up", "Ctrl+u", "Half page up"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    append_view_navigation_actions(&mut actions);
+    actions.extend(view_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
+/// Appends the session-view scroll, prompt-jump, and transcript-search
+/// shortcuts listed in the help overlay.
+fn append_view_navigation_actions(actions: &mut Vec<HelpAction>) {
+    actions.extend(view_pair_action(
+        KeyAction::Down,
+        KeyAction::Up,
+        "scroll",
+        "Scroll output",
+    ));
+    actions.extend(view_action(KeyAction::Top, "top", "Scroll to top"));
+    actions.extend(view_action(KeyAction::Bottom, "bottom", "Scroll to bottom"));
+    actions.extend(view_action(
+        KeyAction::HalfPageDown,
+        "half down",
+        "Half page down",
+    ));
+    actions.extend(view_action(
+        KeyAction::HalfPageUp,
+        "half up",
+        "Half page up",
+    ));
+    actions.extend(view_pair_action(
+        KeyAction::PreviousPrompt,
+        KeyAction::NextPrompt,
+        "prompt",
+        "Previous/next prompt",
+    ));
+    actions.extend(view_action(
+        KeyAction::Search,
+        "search",
+        "Search transcript",
+    ));
+    actions.extend(view_pair_action(
+        KeyAction::NextMatch,
+        KeyAction::PreviousMatch,
+        "match",
+        "Next/previous match",
+    ));
+}
+
 /// Returns compact session-view footer actions for the page-level hint line.
 ///
 /// Interactive and review-oriented sessions keep merge controls discoverable
@@ -359,38 +500,75 @@ pub(crate) fn view_footer_actions(state: ViewHelpState) -> Vec<HelpAction> {
     );
     let can_stop_session = state.session_state == ViewSessionState::InProgress;
 
-    let mut actions = vec![HelpAction::new("back", "q", "Back to list")];
+    let mut actions = view_action(KeyAction::Back, "back", "Back to list")
+        .into_iter()
+        .collect::<Vec<_>>();
 
     append_view_footer_edit_actions(&mut actions, state.session_state, can_edit_session);
 
     if can_stop_session {
-        actions.push(HelpAction::new("stop", "Ctrl+c", "Stop session"));
+        actions.extend(view_action(KeyAction::Stop, "stop", "Stop session"));
     }
 
     if can_open_worktree {
-        actions.push(HelpAction::new("open", "o", "Open worktree"));
+        actions.extend(view_action(
+            KeyAction::OpenWorktree,
+            "open",
+            "Open worktree",
+        ));
     }
 
     if can_show_review {
-        actions.push(HelpAction::new("review", "f", "Focused review"));
+        actions.extend(view_action(KeyAction::Review, "review", "Focused review"));
     }
 
     if let Some(publish_pull_request_action) = state.publish_pull_request_action {
-        actions.push(publish_pull_request_help_action(
+        actions.extend(publish_pull_request_help_action(
             publish_pull_request_action,
         ));
     }
 
     if state.session_state == ViewSessionState::Done {
-        actions.push(HelpAction::new("toggle view", "t", "Switch summary/output"));
+        actions.extend(view_action(
+            KeyAction::ToggleOutput,
+            "toggle view",
+            "Switch summary/output",
+        ));
     }
 
-    actions.push(HelpAction::new("scroll", "j/k", "Scroll output"));
-    actions.push(HelpAction::new("help", "?", "Help"));
+    actions.extend(view_pair_action(
+        KeyAction::Down,
+        KeyAction::Up,
+        "scroll",
+        "Scroll output",
+    ));
+    actions.extend(view_action(KeyAction::Help, "help", "Help"));
 
     actions
 }
 
+/// Returns footer actions shown while a transcript search is open.
+///
+/// Editing a query exposes submit and cancel, while a submitted query exposes
+/// match navigation and clearing.
+pub(crate) fn transcript_search_footer_actions(is_editing: bool) -> Vec<HelpAction> {
+    if is_editing {
+        return vec![
+            HelpAction::new("search", "Enter", "Submit search"),
+            HelpAction::new("cancel", "Esc", "Cancel search"),
+        ];
+    }
+
+    [
+        view_action(KeyAction::NextMatch, "next", "Next match"),
+        view_action(KeyAction::PreviousMatch, "previous", "Previous match"),
+        Some(HelpAction::new("clear", "Esc", "Clear search")),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
 /// Appends footer actions that operate on an editable session in their
 /// canonical order.
 ///
@@ -411,17 +589,21 @@ fn append_view_footer_edit_actions(
     append_view_prompt_actions(actions, session_state, can_edit_session);
 
     if session_state == ViewSessionState::NewSession {
-        actions.push(HelpAction::new("start", "s", "Start staged session"));
+        actions.extend(view_action(
+            KeyAction::StartSession,
+            "start",
+            "Start staged session",
+        ));
     }
 
-    actions.push(HelpAction::new(
+    actions.extend(view_action(
+        KeyAction::Merge,
         "add to merge queue",
-        "m",
         "Add to merge queue",
     ));
 
     if session_state != ViewSessionState::AgentReview {
-        actions.push(HelpAction::new("rebase", "r", "Rebase"));
+        actions.extend(view_action(KeyAction::Rebase, "rebase", "Rebase"));
     }
 }
 
@@ -438,40 +620,64 @@ fn append_view_prompt_actions(
         return;
     }
 
-    actions.push(prompt_action_help_action(session_state));
-    actions.push(HelpAction::new("commands menu", "/", "Open commands menu"));
+    actions.extend(prompt_action_help_action(session_state));
+    actions.extend(view_action(
+        KeyAction::CommandsMenu,
+        "commands menu",
+        "Open commands menu",
+    ));
 }
 
 /// Returns the `Enter` prompt-entry action label appropriate for the current
 /// session state.
-fn prompt_action_help_action(session_state: ViewSessionState) -> HelpAction {
+fn prompt_action_help_action(session_state: ViewSessionState) -> Option<HelpAction> {
     if session_state == ViewSessionState::NewSession {
-        return HelpAction::new("add draft", "Enter", "Add draft");
+        return view_action(KeyAction::Reply, "add draft", "Add draft");
     }
 
-    HelpAction::new("reply", "Enter", "Reply")
+    view_action(KeyAction::Reply, "reply", "Reply")
+}
+
+/// Returns the session-view entry that opens plan review for a plan-first
+/// session with proposed steps waiting for approval.
+pub(crate) fn plan_review_action() -> Option<HelpAction> {
+    view_action(KeyAction::ReviewPlan, "review plan", "Review proposed plan")
 }
 
 /// Returns help entries for diff-mode actions.
 /// These entries are used by the help overlay and include all available
 /// actions.
 pub(crate) fn diff_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("back", "q/Esc", "Back to session"),
-        HelpAction::new("select file", "j/k", "Select file"),
-        HelpAction::new("scroll file", "Up/Down", "Scroll selected file"),
-        HelpAction::new("help", "?", "Help"),
-    ]
+    diff_footer_actions()
 }
 
 /// Returns compact diff footer actions for the page-level hint line.
 pub(crate) fn diff_footer_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("back", "q/Esc", "Back to session"),
-        HelpAction::new("select file", "j/k", "Select file"),
-        HelpAction::new("scroll file", "Up/Down", "Scroll selected file"),
-        HelpAction::new("help", "?", "Help"),
+    let keymap = keymap::active();
+
+    [
+        keymap
+            .all_key_labels(KeymapMode::Diff, KeyAction::Back)
+            .map(|key| HelpAction::keyed(key, "back", "Back to session")),
+        keymap_pair_action(
+            KeymapMode::Diff,
+            KeyAction::NextFile,
+            KeyAction::PreviousFile,
+            "select file",
+            "Select file",
+        ),
+        keymap_pair_action(
+            KeymapMode::Diff,
+            KeyAction::Up,
+            KeyAction::Down,
+            "scroll file",
+            "Scroll selected file",
+        ),
+        keymap_action(KeymapMode::Diff, KeyAction::Help, "help", "Help"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
 }
 
 /// Renders one-line footer help as styled spans where keys are emphasized and
@@ -484,7 +690,7 @@ pub(crate) fn footer_line(actions: &[HelpAction]) -> Line<'static> {
             spans.push(footer_separator_span());
         }
 
-        spans.push(footer_key_span(action.key));
+        spans.push(footer_key_span(action.key.clone()));
         spans.push(footer_muted_span(": "));
         spans.push(footer_muted_span(action.footer_label));
     }
@@ -492,44 +698,185 @@ pub(crate) fn footer_line(actions: &[HelpAction]) -> Line<'static> {
     Line::from(spans)
 }
 
+/// Returns the action whose `key: label` entry is drawn at `position` when a
+/// [`footer_line`] built from `actions` is rendered in `footer_area`.
+///
+/// Separators between entries and positions outside the footer resolve to
+/// `None`.
+pub(crate) fn footer_action_at(
+    actions: &[HelpAction],
+    footer_area: Rect,
+    position: Position,
+) -> Option<&HelpAction> {
+    if !footer_area.contains(position) {
+        return None;
+    }
+
+    let separator_width = footer_separator_span().width();
+    let column_offset = usize::from(position.x - footer_area.x);
+    let mut entry_start = 0;
+
+    for (index, action) in actions.iter().enumerate() {
+        if index > 0 {
+            entry_start += separator_width;
+        }
+
+        let entry_width = action.key.width() + ": ".width() + action.footer_label.width();
+        let entry_end = entry_start + entry_width;
+        if (entry_start..entry_end).contains(&column_offset) {
+            return Some(action);
+        }
+
+        entry_start = entry_end;
+    }
+
+    None
+}
+
 /// Returns one highlighted footer key span.
-pub(crate) fn footer_key_span(key: &'static str) -> Span<'static> {
+pub(crate) fn footer_key_span(key: impl Into<String>) -> Span<'static> {
     Span::styled(
-        key.to_string(),
+        key.into(),
         Style::default()
-            .fg(Color::Cyan)
+            .fg(palette::accent())
             .add_modifier(Modifier::BOLD),
     )
 }
 
 /// Returns one muted footer text span for labels and informational notes.
 pub(crate) fn footer_muted_span(text: impl Into<String>) -> Span<'static> {
-    Span::styled(text.into(), Style::default().fg(Color::Gray))
+    Span::styled(text.into(), Style::default().fg(palette::text_muted()))
 }
 
 /// Returns the shared separator span used between footer help items.
 pub(crate) fn footer_separator_span() -> Span<'static> {
-    Span::styled(" | ", Style::default().fg(Color::DarkGray))
+    Span::styled(" | ", Style::default().fg(palette::text_subtle()))
 }
 
 /// Returns list-mode actions shared by all tabs.
 fn list_base_actions() -> Vec<HelpAction> {
-    vec![
-        HelpAction::new("quit", "q", "Quit"),
-        HelpAction::new("sync", "s", "Sync"),
+    [
+        list_action(KeyAction::Quit, "quit", "Quit"),
+        list_action(KeyAction::Sync, "sync", "Sync"),
+        list_action(KeyAction::Search, "search", "Search all sessions"),
     ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Returns the selected-tile actions shared by the dashboard help overlay and
+/// footer.
+fn dashboard_session_actions(
+    can_stop_selected_session: bool,
+    can_open_selected_session: bool,
+) -> Vec<HelpAction> {
+    let mut actions = Vec::new();
+
+    if can_open_selected_session {
+        actions.extend(list_action(KeyAction::Open, "open session", "Open session"));
+    }
+
+    if can_stop_selected_session {
+        actions.extend(list_action(
+            KeyAction::CancelSession,
+            "stop",
+            "Stop running session",
+        ));
+    }
+
+    actions
 }
 
 /// Returns the view-mode shortcut entry for the current pull-request publish
 /// action.
-fn publish_pull_request_help_action(action: PublishBranchAction) -> HelpAction {
+fn publish_pull_request_help_action(action: PublishBranchAction) -> Option<HelpAction> {
     match action {
-        PublishBranchAction::PublishPullRequest | PublishBranchAction::Push => {
-            HelpAction::new("PR", "p", "Create or refresh forge review request")
-        }
+        PublishBranchAction::PublishPullRequest | PublishBranchAction::Push => view_action(
+            KeyAction::Publish,
+            "PR",
+            "Create or refresh forge review request",
+        ),
     }
 }
 
+/// Returns the list-mode entry for `action`.
+fn list_action(
+    action: KeyAction,
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    keymap_action(KeymapMode::List, action, footer_label, popup_label)
+}
+
+/// Returns the list-mode `down/up` navigation entry.
+fn list_navigation_action(
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    keymap_pair_action(
+        KeymapMode::List,
+        KeyAction::Down,
+        KeyAction::Up,
+        footer_label,
+        popup_label,
+    )
+}
+
+/// Returns the session-view entry for `action`.
+fn view_action(
+    action: KeyAction,
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    keymap_action(KeymapMode::View, action, footer_label, popup_label)
+}
+
+/// Returns the session-view entry for a `first/second` action pair.
+fn view_pair_action(
+    first: KeyAction,
+    second: KeyAction,
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    keymap_pair_action(KeymapMode::View, first, second, footer_label, popup_label)
+}
+
+/// Returns the entry for `action` labeled with its primary chord in the
+/// active keymap, or `None` when the keymap leaves it unbound.
+fn keymap_action(
+    mode: KeymapMode,
+    action: KeyAction,
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    keymap::active()
+        .key_label(mode, action)
+        .map(|key| HelpAction::keyed(key, footer_label, popup_label))
+}
+
+/// Returns one entry for two related actions labeled `first/second`, falling
+/// back to whichever action the active keymap still binds.
+fn keymap_pair_action(
+    mode: KeymapMode,
+    first: KeyAction,
+    second: KeyAction,
+    footer_label: &'static str,
+    popup_label: &'static str,
+) -> Option<HelpAction> {
+    let keymap = keymap::active();
+    let key = match (
+        keymap.key_label(mode, first),
+        keymap.key_label(mode, second),
+    ) {
+        (Some(first_key), Some(second_key)) => format!("{first_key}/{second_key}"),
+        (Some(key), None) | (None, Some(key)) => key.to_string(),
+        (None, None) => return None,
+    };
+
+    Some(HelpAction::keyed(key, footer_label, popup_label))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -565,6 +912,20 @@ mod tests {
         assert!(!actions.iter().any(|action| action.key == "a"));
     }
 
+    #[test]
+    fn test_dashboard_actions_show_stop_only_when_selected_session_is_running() {
+        // Arrange
+        // Act
+        let running_actions = dashboard_actions(true, true);
+        let queued_actions = dashboard_actions(false, true);
+
+        // Assert
+        assert!(running_actions.iter().any(|action| action.key == "c"));
+        assert!(running_actions.iter().any(|action| action.key == "Enter"));
+        assert!(!queued_actions.iter().any(|action| action.key == "c"));
+        assert!(queued_actions.iter().any(|action| action.key == "Enter"));
+    }
+
     #[test]
     fn test_task_actions_include_scroll_shortcuts() {
         // Arrange
@@ -585,6 +946,7 @@ mod tests {
         // Assert
         assert!(actions.iter().any(|action| action.key == "a"));
         assert!(actions.iter().any(|action| action.key == "Shift+A"));
+        assert!(actions.iter().any(|action| action.key == "i"));
     }
 
     #[test]
@@ -610,6 +972,7 @@ mod tests {
         assert!(actions.iter().any(|action| action.key == "Shift+A"));
         assert!(!actions.iter().any(|action| action.key == "d"));
         assert!(!actions.iter().any(|action| action.key == "c"));
+        assert!(!actions.iter().any(|action| action.key == "/"));
         assert!(!actions.iter().any(|action| action.key == "Tab"));
     }
 
@@ -818,6 +1181,38 @@ mod tests {
         assert!(!actions.iter().any(|action| action.key == "r"));
     }
 
+    #[test]
+    fn test_view_actions_include_transcript_navigation() {
+        // Arrange
+        let state = ViewHelpState {
+            can_open_worktree: false,
+            publish_pull_request_action: None,
+            session_state: ViewSessionState::Done,
+        };
+
+        // Act
+        let actions = view_actions(state);
+
+        // Assert
+        assert!(actions.iter().any(|action| action.key == "Ctrl+f"));
+        assert!(actions.iter().any(|action| action.key == "n/N"));
+        assert!(actions.iter().any(|action| action.key == "{/}"));
+    }
+
+    #[test]
+    fn test_transcript_search_footer_actions_follow_editing_state() {
+        // Arrange / Act
+        let editing_actions = transcript_search_footer_actions(true);
+        let submitted_actions = transcript_search_footer_actions(false);
+
+        // Assert
+        assert!(editing_actions.iter().any(|action| action.key == "Enter"));
+        assert!(!editing_actions.iter().any(|action| action.key == "n"));
+        assert!(submitted_actions.iter().any(|action| action.key == "n"));
+        assert!(submitted_actions.iter().any(|action| action.key == "N"));
+        assert!(submitted_actions.iter().any(|action| action.key == "Esc"));
+    }
+
     #[test]
     fn test_view_footer_actions_review_shows_advanced_actions() {
         // Arrange
@@ -894,7 +1289,10 @@ mod tests {
 
         // Act
         let actions = view_footer_actions(state);
-        let ordered_keys = actions.iter().map(|action| action.key).collect::<Vec<_>>();
+        let ordered_keys = actions
+            .iter()
+            .map(|action| action.key.as_ref())
+            .collect::<Vec<_>>();
 
         // Assert
         assert_eq!(&ordered_keys[..6], ["q", "Enter", "/", "s", "m", "r"]);
@@ -939,6 +1337,33 @@ mod tests {
         assert!(!actions.iter().any(|action| action.key == "t"));
     }
 
+    #[test]
+    fn test_footer_action_at_maps_columns_to_entries() {
+        // Arrange
+        let actions = vec![
+            HelpAction::new("quit", "q", "Quit"),
+            HelpAction::new("help", "?", "Help"),
+        ];
+        let footer_area = Rect::new(2, 9, 40, 1);
+        // Rendered footer: "q: quit | ?: help"
+
+        // Act
+        let quit_key = footer_action_at(&actions, footer_area, Position::new(2, 9));
+        let quit_label = footer_action_at(&actions, footer_area, Position::new(8, 9));
+        let separator = footer_action_at(&actions, footer_area, Position::new(10, 9));
+        let help_label = footer_action_at(&actions, footer_area, Position::new(15, 9));
+        let past_end = footer_action_at(&actions, footer_area, Position::new(19, 9));
+        let other_row = footer_action_at(&actions, footer_area, Position::new(2, 8));
+
+        // Assert
+        assert_eq!(quit_key.map(|action| action.popup_label), Some("Quit"));
+        assert_eq!(quit_label.map(|action| action.popup_label), Some("Quit"));
+        assert_eq!(separator, None);
+        assert_eq!(help_label.map(|action| action.popup_label), Some("Help"));
+        assert_eq!(past_end, None);
+        assert_eq!(other_row, None);
+    }
+
     #[test]
     fn test_footer_line_styles_keys_labels_and_separator() {
         // Arrange
@@ -955,16 +1380,25 @@ mod tests {
         assert_eq!(
             line.spans[0].style,
             Style::default()
-                .fg(Color::Cyan)
+                .fg(palette::accent())
                 .add_modifier(Modifier::BOLD)
         );
-        assert_eq!(line.spans[1].style, Style::default().fg(Color::Gray));
-        assert_eq!(line.spans[2].style, Style::default().fg(Color::Gray));
-        assert_eq!(line.spans[3].style, Style::default().fg(Color::DarkGray));
+        assert_eq!(
+            line.spans[1].style,
+            Style::default().fg(palette::text_muted())
+        );
+        assert_eq!(
+            line.spans[2].style,
+            Style::default().fg(palette::text_muted())
+        );
+        assert_eq!(
+            line.spans[3].style,
+            Style::default().fg(palette::text_subtle())
+        );
         assert_eq!(
             line.spans[4].style,
             Style::default()
-                .fg(Color::Cyan)
+                .fg(palette::accent())
                 .add_modifier(Modifier::BOLD)
         );
     }
@@ -1028,7 +1462,7 @@ mod tests {
 
         // Assert
         assert_eq!(span.content, "note");
-        assert_eq!(span.style, Style::default().fg(Color::Gray));
+        assert_eq!(span.style, Style::default().fg(palette::text_muted()));
     }
 
     #[test]
@@ -1039,6 +1473,6 @@ mod tests {
 
         // Assert
         assert_eq!(span.content, " | ");
-        assert_eq!(span.style, Style::default().fg(Color::DarkGray));
+        assert_eq!(span.style, Style::default().fg(palette::text_subtle()));
     }
 }
@@ -0,0 +1,918 @@
+//! Semantic key bindings for command-style modes.
+//!
+//! Command modes (session list, session view, diff, and help) resolve key
+//! presses into [`KeyAction`]s through the active [`Keymap`] instead of
+//! matching hard-coded key codes, and footer/help hints render the chords the
+//! keymap binds. Text-entry modes keep their fixed editing keys.
+//!
+//! The active keymap starts from a [`KeymapPreset`] and applies per-mode
+//! overrides from `AGENTTY_ROOT/keymap.json`, for example:
+//!
+//! ```json
+//! {
+//!   "preset": "vim",
+//!   "bindings": {
+//!     "view": { "merge": "M", "rebase": ["R", "Ctrl+r"] },
+//!     "list": { "issue_picker": [] }
+//!   }
+//! }
+//! ```
+//!
+//! An override replaces every chord of that action; an empty list unbinds it.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::sync::OnceLock;
+use std::{fmt, io};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::Deserialize;
+
+/// File name of the optional keymap file inside the `agentty` home directory.
+pub const KEYMAP_FILE: &str = "keymap.json";
+
+/// Process-wide keymap installed once at startup.
+static ACTIVE_KEYMAP: OnceLock<Keymap> = OnceLock::new();
+
+/// Returns the keymap used by runtime key handlers and help projections.
+///
+/// Falls back to the default preset when startup did not install one.
+pub fn active() -> &'static Keymap {
+    ACTIVE_KEYMAP.get_or_init(|| Keymap::preset(KeymapPreset::Default))
+}
+
+/// Installs `keymap` as the active keymap.
+///
+/// Returns `false` when a keymap was already active, in which case the
+/// existing keymap stays in place.
+pub fn install(keymap: Keymap) -> bool {
+    ACTIVE_KEYMAP.set(keymap).is_ok()
+}
+
+/// Returns the key press of the first chord in a help label such as `j/k`,
+/// `Ctrl+f`, or `/`, so clicked footer hints replay their shortcut.
+///
+/// Returns `None` when the label does not start with a parseable chord.
+pub fn key_event_for_label(label: &str) -> Option<KeyEvent> {
+    let first_chord = if label.starts_with('/') {
+        "/"
+    } else {
+        label.split('/').next()?
+    };
+    let chord = KeyChord::parse(first_chord).ok()?;
+
+    Some(KeyEvent::new(chord.code, chord.modifiers))
+}
+
+/// Loads and validates the keymap file at `path`.
+///
+/// A missing file yields the default preset.
+///
+/// # Errors
+/// Returns an error when the file cannot be read, is not valid keymap JSON,
+/// names unknown presets, modes, actions, or keys, or binds one chord to
+/// several actions of the same mode.
+pub fn load(path: &Path) -> Result<Keymap, KeymapError> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Keymap::from_json(&content),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => {
+            Ok(Keymap::preset(KeymapPreset::Default))
+        }
+        Err(source) => Err(KeymapError::Read {
+            path: path.display().to_string(),
+            source,
+        }),
+    }
+}
+
+/// Failure raised while loading or validating a keymap.
+#[derive(Debug, thiserror::Error)]
+pub enum KeymapError {
+    /// The keymap file exists but could not be read.
+    #[error("Failed to read keymap file `{path}`: {source}")]
+    Read { path: String, source: io::Error },
+
+    /// The keymap file is not valid JSON for the keymap schema.
+    #[error("Invalid keymap file: {0}")]
+    Parse(#[from] serde_json::Error),
+
+    /// The `preset` field names no known preset.
+    #[error("Unknown keymap preset `{0}`, expected `default`, `vim`, or `emacs`")]
+    UnknownPreset(String),
+
+    /// A `bindings` key names no known mode.
+    #[error("Unknown keymap mode `{0}`, expected `list`, `view`, `diff`, or `help`")]
+    UnknownMode(String),
+
+    /// A mode binding names an action the mode does not support.
+    #[error("Unknown `{mode}` keymap action `{action}`")]
+    UnknownAction { action: String, mode: KeymapMode },
+
+    /// A chord string could not be parsed.
+    #[error("Invalid key chord `{0}`")]
+    InvalidChord(String),
+
+    /// Two actions of one mode share the same chord.
+    #[error("Key `{chord}` is bound to both `{first}` and `{second}` in `{mode}` mode")]
+    Conflict {
+        chord: String,
+        first: KeyAction,
+        mode: KeymapMode,
+        second: KeyAction,
+    },
+}
+
+/// Built-in binding sets a keymap file can start from.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum KeymapPreset {
+    /// Agentty's standard bindings.
+    Default,
+    /// Control-key navigation: `Ctrl+n`/`Ctrl+p`, `Alt+<`/`Alt+>`,
+    /// `Ctrl+v`/`Alt+v`, `Ctrl+s` search, and `Ctrl+g` back.
+    Emacs,
+    /// Standard bindings plus `h`/`l` back/open and `Ctrl+e`/`Ctrl+y` line
+    /// scrolling.
+    Vim,
+}
+
+impl KeymapPreset {
+    /// Parses a preset name from the keymap file.
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "default" => Some(Self::Default),
+            "emacs" => Some(Self::Emacs),
+            "vim" => Some(Self::Vim),
+            _ => None,
+        }
+    }
+
+    /// Returns the bindings this preset replaces in the default set.
+    fn overrides(self) -> &'static [(KeymapMode, KeyAction, &'static [&'static str])] {
+        match self {
+            Self::Default => &[],
+            Self::Emacs => EMACS_OVERRIDES,
+            Self::Vim => VIM_OVERRIDES,
+        }
+    }
+}
+
+/// Mode whose key presses are resolved through the keymap.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum KeymapMode {
+    Diff,
+    Help,
+    List,
+    View,
+}
+
+impl KeymapMode {
+    /// All keymap modes in validation order.
+    pub const ALL: [Self; 4] = [Self::List, Self::View, Self::Diff, Self::Help];
+
+    /// Returns the mode name used in the keymap file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Diff => "diff",
+            Self::Help => "help",
+            Self::List => "list",
+            Self::View => "view",
+        }
+    }
+
+    /// Parses a mode name from the keymap file.
+    fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.name() == name)
+    }
+
+    /// Returns the default bindings of every action this mode supports.
+    fn default_bindings(self) -> &'static [(KeyAction, &'static [&'static str])] {
+        match self {
+            Self::Diff => DIFF_DEFAULT_BINDINGS,
+            Self::Help => HELP_DEFAULT_BINDINGS,
+            Self::List => LIST_DEFAULT_BINDINGS,
+            Self::View => VIEW_DEFAULT_BINDINGS,
+        }
+    }
+}
+
+impl fmt::Display for KeymapMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Semantic action a key chord triggers in one [`KeymapMode`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum KeyAction {
+    Back,
+    Bottom,
+    CancelSession,
+    Close,
+    CommandsMenu,
+    Diff,
+    Down,
+    HalfPageDown,
+    HalfPageUp,
+    Help,
+    IssuePicker,
+    LaunchFollowUpTask,
+    Merge,
+    NewDraftSession,
+    NewSession,
+    NextFile,
+    NextFollowUpTask,
+    NextMatch,
+    NextPrompt,
+    NextTab,
+    Open,
+    OpenWorktree,
+    PreviousFile,
+    PreviousFollowUpTask,
+    PreviousMatch,
+    PreviousPrompt,
+    PreviousTab,
+    Publish,
+    Quit,
+    Rebase,
+    Reply,
+    Review,
+    ReviewPlan,
+    Search,
+    StartSession,
+    Stop,
+    Sync,
+    ToggleOutput,
+    Top,
+    Up,
+}
+
+impl KeyAction {
+    /// Returns the action name used in the keymap file.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Back => "back",
+            Self::Bottom => "bottom",
+            Self::CancelSession => "cancel_session",
+            Self::Close => "close",
+            Self::CommandsMenu => "commands_menu",
+            Self::Diff => "diff",
+            Self::Down => "down",
+            Self::HalfPageDown => "half_page_down",
+            Self::HalfPageUp => "half_page_up",
+            Self::Help => "help",
+            Self::IssuePicker => "issue_picker",
+            Self::LaunchFollowUpTask => "launch_follow_up_task",
+            Self::Merge => "merge",
+            Self::NewDraftSession => "new_draft_session",
+            Self::NewSession => "new_session",
+            Self::NextFile => "next_file",
+            Self::NextFollowUpTask => "next_follow_up_task",
+            Self::NextMatch => "next_match",
+            Self::NextPrompt => "next_prompt",
+            Self::NextTab => "next_tab",
+            Self::Open => "open",
+            Self::OpenWorktree => "open_worktree",
+            Self::PreviousFile => "previous_file",
+            Self::PreviousFollowUpTask => "previous_follow_up_task",
+            Self::PreviousMatch => "previous_match",
+            Self::PreviousPrompt => "previous_prompt",
+            Self::PreviousTab => "previous_tab",
+            Self::Publish => "publish",
+            Self::Quit => "quit",
+            Self::Rebase => "rebase",
+            Self::Reply => "reply",
+            Self::Review => "review",
+            Self::ReviewPlan => "review_plan",
+            Self::Search => "search",
+            Self::StartSession => "start_session",
+            Self::Stop => "stop",
+            Self::Sync => "sync",
+            Self::ToggleOutput => "toggle_output",
+            Self::Top => "top",
+            Self::Up => "up",
+        }
+    }
+
+    /// Parses an action name supported by `mode`.
+    fn from_name(mode: KeymapMode, name: &str) -> Option<Self> {
+        mode.default_bindings()
+            .iter()
+            .map(|(action, _)| *action)
+            .find(|action| action.name() == name)
+    }
+}
+
+impl fmt::Display for KeyAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Normalized key press matched against bindings.
+///
+/// Character keys fold `Shift` into the character itself, so `Shift+a`, `A`,
+/// and a terminal-reported `Shift`+`A` all compare equal.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct KeyChord {
+    code: KeyCode,
+    modifiers: KeyModifiers,
+}
+
+impl KeyChord {
+    /// Normalizes one terminal key event.
+    fn from_event(key: KeyEvent) -> Self {
+        Self::normalized(key.code, key.modifiers)
+    }
+
+    /// Folds `Shift` into character and back-tab codes and drops modifiers
+    /// keymaps cannot express.
+    fn normalized(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        let modifiers =
+            modifiers.intersection(KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+
+        match code {
+            KeyCode::Char(character) if modifiers.contains(KeyModifiers::SHIFT) => Self {
+                code: KeyCode::Char(character.to_ascii_uppercase()),
+                modifiers: modifiers.difference(KeyModifiers::SHIFT),
+            },
+            KeyCode::BackTab => Self {
+                code,
+                modifiers: modifiers.difference(KeyModifiers::SHIFT),
+            },
+            _ => Self { code, modifiers },
+        }
+    }
+
+    /// Parses one chord such as `q`, `Shift+A`, `Ctrl+d`, `Alt+<`, or
+    /// `PageDown`.
+    fn parse(chord: &str) -> Result<Self, KeymapError> {
+        let invalid_chord = || KeymapError::InvalidChord(chord.to_string());
+        let (modifier_names, key_name) = match chord.strip_suffix("++") {
+            Some(modifier_names) => (modifier_names.split('+').collect::<Vec<_>>(), "+"),
+            None if chord == "+" => (Vec::new(), "+"),
+            None => {
+                let mut parts = chord.split('+').collect::<Vec<_>>();
+                let key_name = parts.pop().ok_or_else(invalid_chord)?;
+
+                (parts, key_name)
+            }
+        };
+
+        let mut modifiers = KeyModifiers::NONE;
+        for modifier_name in modifier_names {
+            modifiers |= match modifier_name.to_ascii_lowercase().as_str() {
+                "alt" | "meta" => KeyModifiers::ALT,
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "shift" => KeyModifiers::SHIFT,
+                _ => return Err(invalid_chord()),
+            };
+        }
+
+        let code = match key_name.to_ascii_lowercase().as_str() {
+            "backspace" => KeyCode::Backspace,
+            "del" | "delete" => KeyCode::Delete,
+            "down" => KeyCode::Down,
+            "end" => KeyCode::End,
+            "enter" | "return" => KeyCode::Enter,
+            "esc" | "escape" => KeyCode::Esc,
+            "home" => KeyCode::Home,
+            "left" => KeyCode::Left,
+            "pagedown" => KeyCode::PageDown,
+            "pageup" => KeyCode::PageUp,
+            "right" => KeyCode::Right,
+            "space" => KeyCode::Char(' '),
+            "tab" if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+            "tab" => KeyCode::Tab,
+            "up" => KeyCode::Up,
+            lowercase_name => {
+                let mut characters = key_name.chars();
+                match (characters.next(), characters.next()) {
+                    (Some(character), None) if !character.is_control() => {
+                        // Terminals report `Ctrl+D` as `Ctrl` + `d`.
+                        let is_control_letter = modifiers == KeyModifiers::CONTROL;
+
+                        KeyCode::Char(if is_control_letter {
+                            character.to_ascii_lowercase()
+                        } else {
+                            character
+                        })
+                    }
+                    _ => lowercase_name
+                        .strip_prefix('f')
+                        .and_then(|number| number.parse::<u8>().ok())
+                        .filter(|number| (1..=12).contains(number))
+                        .map(KeyCode::F)
+                        .ok_or_else(invalid_chord)?,
+                }
+            }
+        };
+
+        Ok(Self::normalized(code, modifiers))
+    }
+}
+
+/// One chord bound to an action, with the spelling shown in help hints.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct KeyBinding {
+    chord: KeyChord,
+    label: String,
+}
+
+impl KeyBinding {
+    /// Parses one chord string and keeps its spelling as the display label.
+    fn parse(chord: &str) -> Result<Self, KeymapError> {
+        let label = chord.trim();
+
+        Ok(Self {
+            chord: KeyChord::parse(label)?,
+            label: label.to_string(),
+        })
+    }
+}
+
+/// Validated mapping from key chords to semantic actions for every keymap
+/// mode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Keymap {
+    bindings: HashMap<(KeymapMode, KeyAction), Vec<KeyBinding>>,
+}
+
+impl Keymap {
+    /// Builds the keymap for one built-in preset.
+    pub fn preset(preset: KeymapPreset) -> Self {
+        let mut keymap = Self {
+            bindings: HashMap::new(),
+        };
+        for mode in KeymapMode::ALL {
+            for (action, chords) in mode.default_bindings() {
+                keymap.bind_preset_chords(mode, *action, chords);
+            }
+        }
+        for (mode, action, chords) in preset.overrides() {
+            keymap.bind_preset_chords(*mode, *action, chords);
+        }
+
+        keymap
+    }
+
+    /// Parses keymap file content, applies it over its preset, and validates
+    /// the result.
+    ///
+    /// # Errors
+    /// Returns an error for malformed JSON, unknown names or keys, and
+    /// conflicting chords.
+    pub fn from_json(content: &str) -> Result<Self, KeymapError> {
+        let keymap_file: KeymapFile = serde_json::from_str(content)?;
+        let preset = match keymap_file.preset.as_deref() {
+            Some(preset_name) => KeymapPreset::from_name(preset_name)
+                .ok_or_else(|| KeymapError::UnknownPreset(preset_name.to_string()))?,
+            None => KeymapPreset::Default,
+        };
+        let mut keymap = Self::preset(preset);
+
+        for (mode_name, mode_bindings) in keymap_file.bindings {
+            let mode = KeymapMode::from_name(&mode_name)
+                .ok_or_else(|| KeymapError::UnknownMode(mode_name.clone()))?;
+            for (action_name, chords) in mode_bindings {
+                let action = KeyAction::from_name(mode, &action_name).ok_or_else(|| {
+                    KeymapError::UnknownAction {
+                        action: action_name.clone(),
+                        mode,
+                    }
+                })?;
+                let bindings = chords
+                    .into_vec()
+                    .iter()
+                    .map(|chord| KeyBinding::parse(chord))
+                    .collect::<Result<Vec<_>, _>>()?;
+                keymap.bindings.insert((mode, action), bindings);
+            }
+        }
+
+        keymap.validate()?;
+
+        Ok(keymap)
+    }
+
+    /// Resolves one key press to the action it triggers in `mode`.
+    pub fn action(&self, mode: KeymapMode, key: KeyEvent) -> Option<KeyAction> {
+        let chord = KeyChord::from_event(key);
+
+        mode.default_bindings()
+            .iter()
+            .map(|(action, _)| *action)
+            .find(|action| {
+                self.mode_bindings(mode, *action)
+                    .iter()
+                    .any(|binding| binding.chord == chord)
+            })
+    }
+
+    /// Returns the primary chord label of `action`, or `None` when unbound.
+    pub fn key_label(&self, mode: KeymapMode, action: KeyAction) -> Option<&str> {
+        self.mode_bindings(mode, action)
+            .first()
+            .map(|binding| binding.label.as_str())
+    }
+
+    /// Returns every chord label of `action` joined by `/`, or `None` when
+    /// unbound.
+    pub fn all_key_labels(&self, mode: KeymapMode, action: KeyAction) -> Option<String> {
+        let bindings = self.mode_bindings(mode, action);
+        if bindings.is_empty() {
+            return None;
+        }
+
+        Some(
+            bindings
+                .iter()
+                .map(|binding| binding.label.as_str())
+                .collect::<Vec<_>>()
+                .join("/"),
+        )
+    }
+
+    /// Returns the bindings of `action` in `mode`.
+    fn mode_bindings(&self, mode: KeymapMode, action: KeyAction) -> &[KeyBinding] {
+        self.bindings
+            .get(&(mode, action))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Replaces the bindings of `action` with built-in preset chords.
+    fn bind_preset_chords(&mut self, mode: KeymapMode, action: KeyAction, chords: &[&str]) {
+        let bindings = chords
+            .iter()
+            .filter_map(|chord| KeyBinding::parse(chord).ok())
+            .collect();
+        self.bindings.insert((mode, action), bindings);
+    }
+
+    /// Rejects chords bound to more than one action of the same mode.
+    fn validate(&self) -> Result<(), KeymapError> {
+        for mode in KeymapMode::ALL {
+            let mut chord_actions: HashMap<KeyChord, KeyAction> = HashMap::new();
+            for (action, _) in mode.default_bindings() {
+                for binding in self.mode_bindings(mode, *action) {
+                    match chord_actions.insert(binding.chord, *action) {
+                        Some(first) if first != *action => {
+                            return Err(KeymapError::Conflict {
+                                chord: binding.label.clone(),
+                                first,
+                                mode,
+                                second: *action,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// On-disk keymap file schema.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KeymapFile {
+    #[serde(default)]
+    bindings: BTreeMap<String, BTreeMap<String, ChordList>>,
+    #[serde(default)]
+    preset: Option<String>,
+}
+
+/// One chord or a list of chords bound to a single action.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ChordList {
+    Many(Vec<String>),
+    One(String),
+}
+
+impl ChordList {
+    /// Returns the chords as a list.
+    fn into_vec(self) -> Vec<String> {
+        match self {
+            Self::Many(chords) => chords,
+            Self::One(chord) => vec![chord],
+        }
+    }
+}
+
+/// Default session-list bindings.
+const LIST_DEFAULT_BINDINGS: &[(KeyAction, &[&str])] = &[
+    (KeyAction::Quit, &["q"]),
+    (KeyAction::NextTab, &["Tab"]),
+    (KeyAction::PreviousTab, &["Shift+Tab"]),
+    (KeyAction::NewSession, &["a"]),
+    (KeyAction::NewDraftSession, &["Shift+A"]),
+    (KeyAction::IssuePicker, &["i"]),
+    (KeyAction::Down, &["j", "Down"]),
+    (KeyAction::Up, &["k", "Up"]),
+    (KeyAction::Top, &["g"]),
+    (KeyAction::Open, &["Enter"]),
+    (KeyAction::CancelSession, &["c"]),
+    (KeyAction::Sync, &["s", "S"]),
+    (KeyAction::Search, &["/"]),
+    (KeyAction::Help, &["?"]),
+];
+
+/// Default session-view bindings.
+const VIEW_DEFAULT_BINDINGS: &[(KeyAction, &[&str])] = &[
+    (KeyAction::Back, &["q"]),
+    (KeyAction::OpenWorktree, &["o"]),
+    (KeyAction::LaunchFollowUpTask, &["l"]),
+    (KeyAction::StartSession, &["s"]),
+    (KeyAction::PreviousFollowUpTask, &["["]),
+    (KeyAction::NextFollowUpTask, &["]"]),
+    (KeyAction::Reply, &["Enter"]),
+    (KeyAction::CommandsMenu, &["/"]),
+    (KeyAction::ReviewPlan, &["a"]),
+    (KeyAction::Down, &["j", "Down"]),
+    (KeyAction::Up, &["k", "Up"]),
+    (KeyAction::Top, &["g"]),
+    (KeyAction::Bottom, &["G"]),
+    (KeyAction::HalfPageDown, &["Ctrl+d"]),
+    (KeyAction::HalfPageUp, &["Ctrl+u"]),
+    (KeyAction::Search, &["Ctrl+f"]),
+    (KeyAction::NextMatch, &["n"]),
+    (KeyAction::PreviousMatch, &["N"]),
+    (KeyAction::PreviousPrompt, &["{"]),
+    (KeyAction::NextPrompt, &["}"]),
+    (KeyAction::Diff, &["d"]),
+    (KeyAction::Publish, &["p", "P"]),
+    (KeyAction::Review, &["f", "F"]),
+    (KeyAction::Merge, &["m"]),
+    (KeyAction::Rebase, &["r"]),
+    (KeyAction::ToggleOutput, &["t", "T"]),
+    (KeyAction::Stop, &["Ctrl+c"]),
+    (KeyAction::Help, &["?"]),
+];
+
+/// Default diff-mode bindings.
+const DIFF_DEFAULT_BINDINGS: &[(KeyAction, &[&str])] = &[
+    (KeyAction::Back, &["q", "Esc"]),
+    (KeyAction::NextFile, &["j"]),
+    (KeyAction::PreviousFile, &["k"]),
+    (KeyAction::Down, &["Down", "Shift+J"]),
+    (KeyAction::Up, &["Up", "Shift+K"]),
+    (KeyAction::Help, &["?"]),
+];
+
+/// Default help-overlay bindings.
+const HELP_DEFAULT_BINDINGS: &[(KeyAction, &[&str])] = &[
+    (KeyAction::Close, &["?", "q", "Esc"]),
+    (KeyAction::Down, &["j", "Down"]),
+    (KeyAction::Up, &["k", "Up"]),
+];
+
+/// Bindings the `vim` preset replaces in the default set.
+const VIM_OVERRIDES: &[(KeymapMode, KeyAction, &[&str])] = &[
+    (KeymapMode::List, KeyAction::Open, &["Enter", "l"]),
+    (KeymapMode::View, KeyAction::Back, &["q", "h"]),
+    (KeymapMode::View, KeyAction::Down, &["j", "Down", "Ctrl+e"]),
+    (KeymapMode::View, KeyAction::Up, &["k", "Up", "Ctrl+y"]),
+    (KeymapMode::Diff, KeyAction::Back, &["q", "Esc", "h"]),
+];
+
+/// Bindings the `emacs` preset replaces in the default set.
+const EMACS_OVERRIDES: &[(KeymapMode, KeyAction, &[&str])] = &[
+    (KeymapMode::List, KeyAction::Down, &["Ctrl+n", "Down"]),
+    (KeymapMode::List, KeyAction::Up, &["Ctrl+p", "Up"]),
+    (KeymapMode::List, KeyAction::Search, &["Ctrl+s", "/"]),
+    (KeymapMode::View, KeyAction::Back, &["q", "Ctrl+g"]),
+    (KeymapMode::View, KeyAction::Down, &["Ctrl+n", "Down"]),
+    (KeymapMode::View, KeyAction::Up, &["Ctrl+p", "Up"]),
+    (KeymapMode::View, KeyAction::Top, &["Alt+<"]),
+    (KeymapMode::View, KeyAction::Bottom, &["Alt+>"]),
+    (KeymapMode::View, KeyAction::HalfPageDown, &["Ctrl+v"]),
+    (KeymapMode::View, KeyAction::HalfPageUp, &["Alt+v"]),
+    (KeymapMode::View, KeyAction::Search, &["Ctrl+s"]),
+    (KeymapMode::Diff, KeyAction::Back, &["q", "Esc", "Ctrl+g"]),
+    (KeymapMode::Diff, KeyAction::NextFile, &["Ctrl+n"]),
+    (KeymapMode::Diff, KeyAction::PreviousFile, &["Ctrl+p"]),
+    (KeymapMode::Diff, KeyAction::Down, &["Down"]),
+    (KeymapMode::Diff, KeyAction::Up, &["Up"]),
+    (
+        KeymapMode::Help,
+        KeyAction::Close,
+        &["?", "q", "Esc", "Ctrl+g"],
+    ),
+    (KeymapMode::Help, KeyAction::Down, &["Ctrl+n", "Down"]),
+    (KeymapMode::Help, KeyAction::Up, &["Ctrl+p", "Up"]),
+];
+
+#[cfg(test)]
+mod tests {
+    use tempfile::tempdir;
+
+    use super::*;
+
+    #[test]
+    fn test_presets_parse_every_chord_without_conflicts() {
+        // Arrange
+        let presets = [
+            KeymapPreset::Default,
+            KeymapPreset::Vim,
+            KeymapPreset::Emacs,
+        ];
+        let default_chords = KeymapMode::ALL
+            .iter()
+            .flat_map(|mode| mode.default_bindings())
+            .flat_map(|(_, chords)| chords.iter());
+        let override_chords = presets
+            .iter()
+            .flat_map(|preset| preset.overrides())
+            .flat_map(|(_, _, chords)| chords.iter());
+
+        // Act
+        let keymaps = presets.map(Keymap::preset);
+
+        // Assert
+        for chord in default_chords.chain(override_chords) {
+            assert!(KeyChord::parse(chord).is_ok(), "`{chord}` should parse");
+        }
+        for keymap in &keymaps {
+            assert!(keymap.validate().is_ok());
+        }
+        for (mode, action, _) in presets.iter().flat_map(|preset| preset.overrides()) {
+            assert_eq!(KeyAction::from_name(*mode, action.name()), Some(*action));
+        }
+    }
+
+    #[test]
+    fn test_action_normalizes_shifted_characters_and_back_tab() {
+        // Arrange
+        let keymap = Keymap::preset(KeymapPreset::Default);
+
+        // Act
+        let shifted_lowercase = keymap.action(
+            KeymapMode::List,
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::SHIFT),
+        );
+        let uppercase = keymap.action(
+            KeymapMode::List,
+            KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE),
+        );
+        let back_tab = keymap.action(
+            KeymapMode::List,
+            KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT),
+        );
+        let control_a = keymap.action(
+            KeymapMode::List,
+            KeyEvent::new(KeyCode::Char('a'), KeyModifiers::CONTROL),
+        );
+
+        // Assert
+        assert_eq!(shifted_lowercase, Some(KeyAction::NewDraftSession));
+        assert_eq!(uppercase, Some(KeyAction::NewDraftSession));
+        assert_eq!(back_tab, Some(KeyAction::PreviousTab));
+        assert_eq!(control_a, None);
+    }
+
+    #[test]
+    fn test_parse_chord_accepts_named_keys_and_modifiers() {
+        // Arrange
+        let chords = ["Ctrl+D", "Alt+<", "Ctrl++", "PageDown", "F5", "space"];
+
+        // Act
+        let parsed = chords
+            .map(|chord| KeyChord::parse(chord).expect("chord should parse"))
+            .to_vec();
+
+        // Assert
+        assert_eq!(
+            parsed,
+            vec![
+                KeyChord::normalized(KeyCode::Char('d'), KeyModifiers::CONTROL),
+                KeyChord::normalized(KeyCode::Char('<'), KeyModifiers::ALT),
+                KeyChord::normalized(KeyCode::Char('+'), KeyModifiers::CONTROL),
+                KeyChord::normalized(KeyCode::PageDown, KeyModifiers::NONE),
+                KeyChord::normalized(KeyCode::F(5), KeyModifiers::NONE),
+                KeyChord::normalized(KeyCode::Char(' '), KeyModifiers::NONE),
+            ]
+        );
+        assert!(KeyChord::parse("Hyper+x").is_err());
+        assert!(KeyChord::parse("F13").is_err());
+        assert!(KeyChord::parse("Ctrl+").is_err());
+    }
+
+    #[test]
+    fn test_key_event_for_label_uses_first_chord_of_help_label() {
+        // Arrange
+        let labels = ["j/k", "Ctrl+f", "/", "//n", "Enter", "Hyper+x/k"];
+
+        // Act
+        let key_events = labels.map(key_event_for_label).to_vec();
+
+        // Assert
+        assert_eq!(
+            key_events,
+            vec![
+                Some(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)),
+                Some(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL)),
+                Some(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE)),
+                Some(KeyEvent::new(KeyCode::Char('/'), KeyModifiers::NONE)),
+                Some(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE)),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_from_json_applies_preset_and_overrides() {
+        // Arrange
+        let content = r#"{
+            "preset": "emacs",
+            "bindings": {
+                "view": { "merge": "M", "rebase": ["R", "Ctrl+r"] },
+                "list": { "issue_picker": [] }
+            }
+        }"#;
+
+        // Act
+        let keymap = Keymap::from_json(content).expect("keymap should load");
+
+        // Assert
+        assert_eq!(
+            keymap.key_label(KeymapMode::View, KeyAction::Down),
+            Some("Ctrl+n")
+        );
+        assert_eq!(
+            keymap.action(
+                KeymapMode::View,
+                KeyEvent::new(KeyCode::Char('M'), KeyModifiers::SHIFT)
+            ),
+            Some(KeyAction::Merge)
+        );
+        assert_eq!(
+            keymap.action(
+                KeymapMode::View,
+                KeyEvent::new(KeyCode::Char('m'), KeyModifiers::NONE)
+            ),
+            None
+        );
+        assert_eq!(
+            keymap.all_key_labels(KeymapMode::View, KeyAction::Rebase),
+            Some("R/Ctrl+r".to_string())
+        );
+        assert_eq!(
+            keymap.key_label(KeymapMode::List, KeyAction::IssuePicker),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_json_rejects_conflicting_chords_within_one_mode() {
+        // Arrange
+        let content = r#"{ "bindings": { "view": { "merge": "d" } } }"#;
+
+        // Act
+        let error = Keymap::from_json(content).expect_err("conflict should fail");
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "Key `d` is bound to both `diff` and `merge` in `view` mode"
+        );
+    }
+
+    #[test]
+    fn test_from_json_rejects_unknown_names() {
+        // Arrange
+        let unknown_preset = r#"{ "preset": "nano" }"#;
+        let unknown_mode = r#"{ "bindings": { "prompt": {} } }"#;
+        let unknown_action = r#"{ "bindings": { "diff": { "merge": "m" } } }"#;
+        let unknown_field = r#"{ "keys": {} }"#;
+
+        // Act
+        let errors = [unknown_preset, unknown_mode, unknown_action, unknown_field]
+            .map(|content| Keymap::from_json(content).expect_err("keymap should fail"));
+
+        // Assert
+        assert!(matches!(errors[0], KeymapError::UnknownPreset(_)));
+        assert!(matches!(errors[1], KeymapError::UnknownMode(_)));
+        assert!(matches!(
+            errors[2],
+            KeymapError::UnknownAction {
+                mode: KeymapMode::Diff,
+                ..
+            }
+        ));
+        assert!(matches!(errors[3], KeymapError::Parse(_)));
+    }
+
+    #[test]
+    fn test_load_falls_back_to_default_preset_for_missing_file() {
+        // Arrange
+        let temp_dir = tempdir().expect("failed to create temp dir");
+
+        // Act
+        let keymap = load(&temp_dir.path().join(KEYMAP_FILE)).expect("keymap should load");
+
+        // Assert
+        assert_eq!(keymap, Keymap::preset(KeymapPreset::Default));
+    }
+}
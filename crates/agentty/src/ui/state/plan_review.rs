@@ -0,0 +1,167 @@
+//! Plan-review overlay state for approving one proposed session plan.
+//!
+//! Rows keep the proposed step order. Users can drop steps, reword them
+//! inline, and approve the remaining steps as the session plan.
+
+use crate::domain::input::InputState;
+use crate::domain::plan::PlanStep;
+
+/// One reviewable step row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanReviewStep {
+    /// Whether the step is kept when the plan is approved.
+    pub is_included: bool,
+    /// Proposed step, including any inline edits.
+    pub step: PlanStep,
+}
+
+/// Review state for one proposed plan.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlanReviewState {
+    /// Inline editor for the selected step text, when editing.
+    pub editing_input: Option<InputState>,
+    /// Highlighted row index in `steps`.
+    pub selected_step_index: usize,
+    /// Reviewable rows in proposed order.
+    pub steps: Vec<PlanReviewStep>,
+}
+
+impl PlanReviewState {
+    /// Creates review state with every proposed step included.
+    #[must_use]
+    pub fn new(steps: Vec<PlanStep>) -> Self {
+        Self {
+            editing_input: None,
+            selected_step_index: 0,
+            steps: steps
+                .into_iter()
+                .map(|step| PlanReviewStep {
+                    is_included: true,
+                    step,
+                })
+                .collect(),
+        }
+    }
+
+    /// Moves the selection one row down, stopping at the last row.
+    pub fn select_next(&mut self) {
+        if self.selected_step_index + 1 < self.steps.len() {
+            self.selected_step_index += 1;
+        }
+    }
+
+    /// Moves the selection one row up, stopping at the first row.
+    pub fn select_previous(&mut self) {
+        self.selected_step_index = self.selected_step_index.saturating_sub(1);
+    }
+
+    /// Toggles whether the selected step is kept on approval.
+    pub fn toggle_selected(&mut self) {
+        if let Some(review_step) = self.steps.get_mut(self.selected_step_index) {
+            review_step.is_included = !review_step.is_included;
+        }
+    }
+
+    /// Starts editing the selected step text.
+    pub fn start_editing(&mut self) {
+        if let Some(review_step) = self.steps.get(self.selected_step_index) {
+            self.editing_input = Some(InputState::with_text(review_step.step.text.clone()));
+        }
+    }
+
+    /// Stores the edited text on the selected step.
+    ///
+    /// Blank edits are discarded so approved plans never contain empty steps.
+    pub fn commit_edit(&mut self) {
+        let Some(mut input) = self.editing_input.take() else {
+            return;
+        };
+        let text = input.take_text();
+        if text.trim().is_empty() {
+            return;
+        }
+
+        if let Some(review_step) = self.steps.get_mut(self.selected_step_index) {
+            review_step.step.text = text.trim().to_string();
+        }
+    }
+
+    /// Returns the included steps in order.
+    pub fn approved_steps(&self) -> Vec<PlanStep> {
+        self.steps
+            .iter()
+            .filter(|review_step| review_step.is_included)
+            .map(|review_step| review_step.step.clone())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn review_state() -> PlanReviewState {
+        PlanReviewState::new(vec![
+            PlanStep::new("Add the column", vec!["migrations/001.sql".to_string()]),
+            PlanStep::new("Update docs", Vec::new()),
+        ])
+    }
+
+    #[test]
+    fn test_approved_steps_skips_excluded_rows() {
+        // Arrange
+        let mut review = review_state();
+        review.select_next();
+
+        // Act
+        review.toggle_selected();
+
+        // Assert
+        assert_eq!(
+            review.approved_steps(),
+            vec![PlanStep::new(
+                "Add the column",
+                vec!["migrations/001.sql".to_string()]
+            )]
+        );
+    }
+
+    #[test]
+    fn test_commit_edit_rewords_selected_step_and_ignores_blank_text() {
+        // Arrange
+        let mut review = review_state();
+        review.start_editing();
+        review.editing_input = Some(InputState::with_text("  Add the plan column ".to_string()));
+
+        // Act
+        review.commit_edit();
+        review.start_editing();
+        review.editing_input = Some(InputState::with_text("   ".to_string()));
+        review.commit_edit();
+
+        // Assert
+        assert_eq!(review.editing_input, None);
+        assert_eq!(review.steps[0].step.text, "Add the plan column");
+        assert_eq!(
+            review.steps[0].step.files,
+            vec!["migrations/001.sql".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_select_next_and_previous_stay_within_rows() {
+        // Arrange
+        let mut review = review_state();
+
+        // Act
+        review.select_next();
+        review.select_next();
+        let last_index = review.selected_step_index;
+        review.select_previous();
+        review.select_previous();
+
+        // Assert
+        assert_eq!(last_index, 1);
+        assert_eq!(review.selected_step_index, 0);
+    }
+}
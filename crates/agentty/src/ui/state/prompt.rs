@@ -1,21 +1,28 @@
 pub use crate::domain::composer::{
     PromptAttachment, PromptAttachmentState, PromptComposerState, PromptComposerSubmission,
+    PromptContextAttachment, PromptContextDirective, PromptContextKind, PromptHistorySearchState,
     PromptHistoryState, PromptSlashStage, PromptSlashState, PromptSuggestionItem,
     PromptSuggestionList, PromptSuggestionSelection, apply_prompt_delete_range,
-    build_prompt_slash_suggestion_list, current_line_delete_range, drain_prompt_submission,
-    expand_delete_range_to_image_tokens, image_token_ranges, insert_prompt_character,
-    insert_prompt_local_image, insert_prompt_text, prompt_slash_option_count,
-    resolve_prompt_slash_selection,
+    attachment_token_ranges, build_prompt_slash_suggestion_list, current_line_delete_range,
+    drain_prompt_submission, expand_delete_range_to_attachment_tokens, fail_prompt_context,
+    insert_pending_prompt_context, insert_prompt_character, insert_prompt_context,
+    insert_prompt_local_image, insert_prompt_text, prompt_context_directive_at_cursor,
+    prompt_slash_option_count, replace_prompt_text, resolve_prompt_slash_selection,
 };
-use crate::infra::file_index::FileEntry;
+use crate::infra::file_index::{self, FileEntry};
+use crate::infra::symbol_index::SymbolIndex;
 
-/// UI state for prompt `@` file and directory mention selection.
+/// UI state for prompt `@` file, directory, and `@#` symbol mention
+/// selection.
 #[derive(Clone, Debug)]
 pub struct PromptAtMentionState {
     /// Cached list of all files and directories in the session directory.
     pub all_entries: Vec<FileEntry>,
     /// Currently selected index in the filtered dropdown.
     pub selected_index: usize,
+    /// Symbol index for the session directory, once background indexing
+    /// finishes.
+    pub symbol_index: Option<SymbolIndex>,
 }
 
 impl PromptAtMentionState {
@@ -25,6 +32,60 @@ impl PromptAtMentionState {
         Self {
             all_entries,
             selected_index: 0,
+            symbol_index: None,
         }
     }
 }
+
+/// Fuzzy-filters persisted prompts for the `Ctrl+R` history search.
+///
+/// Query characters must appear in order (case-insensitive) within the
+/// prompt. Equal scores keep the most-recent-first order of `entries`.
+pub fn filter_prompt_history<'a>(entries: &'a [String], query: &str) -> Vec<&'a str> {
+    if query.is_empty() {
+        return entries.iter().map(String::as_str).collect();
+    }
+
+    let query_lower = query.to_lowercase();
+    let query_chars: Vec<char> = query_lower.chars().collect();
+    let mut scored: Vec<(i32, &str)> = entries
+        .iter()
+        .filter_map(|entry| {
+            file_index::fuzzy_score(entry, &query_chars, &query_lower, false)
+                .map(|score| (score, entry.as_str()))
+        })
+        .collect();
+
+    scored.sort_by(|(first_score, _), (second_score, _)| second_score.cmp(first_score));
+
+    scored.into_iter().map(|(_, entry)| entry).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_filter_prompt_history_ranks_matches_and_keeps_recency_for_ties() {
+        // Arrange
+        let entries = vec![
+            "Add tests for the parser".to_string(),
+            "Fix flaky login test".to_string(),
+            "Update README".to_string(),
+            "Add tests for the lexer".to_string(),
+        ];
+
+        // Act
+        let all = filter_prompt_history(&entries, "");
+        let login = filter_prompt_history(&entries, "login");
+        let add_tests = filter_prompt_history(&entries, "add tests");
+
+        // Assert
+        assert_eq!(all.len(), 4);
+        assert_eq!(login, vec!["Fix flaky login test"]);
+        assert_eq!(
+            add_tests,
+            vec!["Add tests for the parser", "Add tests for the lexer"]
+        );
+    }
+}
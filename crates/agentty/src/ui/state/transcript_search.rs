@@ -0,0 +1,353 @@
+//! Session-view transcript search state and rendered-line navigation.
+//!
+//! Matching runs against rendered output lines rather than raw transcript
+//! text so match positions share the same coordinate space as the session
+//! view scroll offset.
+
+use std::ops::{Range, RangeInclusive};
+
+use ratatui::text::Line;
+
+use crate::domain::input::InputState;
+use crate::ui::markdown;
+
+/// Unicode tag characters that hyperlink rendering appends after linked
+/// characters to carry their URL invisibly.
+const HYPERLINK_TAG_CHARACTERS: RangeInclusive<char> = '\u{E0000}'..='\u{E007F}';
+
+/// Direction used when moving between transcript matches or prompts.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchDirection {
+    Backward,
+    Forward,
+}
+
+/// Incremental search over the rendered session transcript.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TranscriptSearchState {
+    /// Position of the focused match inside `match_lines`.
+    pub focused_match_index: Option<usize>,
+    /// Query text being typed or last submitted.
+    pub input: InputState,
+    /// Whether key presses currently edit the query.
+    pub is_editing: bool,
+    /// Rendered output lines containing at least one match, in order.
+    pub match_lines: Vec<usize>,
+    /// Scroll offset restored when the query is abandoned while editing.
+    pub origin_scroll_offset: Option<u16>,
+}
+
+impl TranscriptSearchState {
+    /// Starts editing an empty query from the current scroll position.
+    #[must_use]
+    pub fn new(origin_scroll_offset: Option<u16>) -> Self {
+        Self {
+            focused_match_index: None,
+            input: InputState::new(),
+            is_editing: true,
+            match_lines: Vec::new(),
+            origin_scroll_offset,
+        }
+    }
+
+    /// Returns the current query text.
+    pub fn query(&self) -> &str {
+        self.input.text()
+    }
+
+    /// Returns the rendered output line of the focused match.
+    pub fn focused_line(&self) -> Option<usize> {
+        self.focused_match_index
+            .and_then(|match_index| self.match_lines.get(match_index).copied())
+    }
+
+    /// Recomputes matches for `lines` and focuses the first match at or
+    /// after `anchor_line`, wrapping to the first match when none follows.
+    pub fn refresh_matches(&mut self, lines: &[Line<'_>], anchor_line: usize) {
+        self.match_lines = matching_line_indices(lines, self.query());
+        self.focused_match_index = self
+            .match_lines
+            .iter()
+            .position(|line_index| *line_index >= anchor_line)
+            .or_else(|| (!self.match_lines.is_empty()).then_some(0));
+    }
+
+    /// Recomputes matches for `lines` and moves focus one match in
+    /// `direction`, wrapping around the transcript ends.
+    ///
+    /// Movement starts from the focused match, or from `anchor_line` when no
+    /// match is focused yet.
+    pub fn focus_adjacent_match(
+        &mut self,
+        lines: &[Line<'_>],
+        anchor_line: usize,
+        direction: SearchDirection,
+    ) {
+        let current_line = self.focused_line();
+        self.match_lines = matching_line_indices(lines, self.query());
+        if self.match_lines.is_empty() {
+            self.focused_match_index = None;
+
+            return;
+        }
+
+        let next_index = match (direction, current_line) {
+            (SearchDirection::Forward, Some(current_line)) => self
+                .match_lines
+                .iter()
+                .position(|line_index| *line_index > current_line),
+            (SearchDirection::Forward, None) => self
+                .match_lines
+                .iter()
+                .position(|line_index| *line_index >= anchor_line),
+            (SearchDirection::Backward, Some(current_line)) => self
+                .match_lines
+                .iter()
+                .rposition(|line_index| *line_index < current_line),
+            (SearchDirection::Backward, None) => self
+                .match_lines
+                .iter()
+                .rposition(|line_index| *line_index <= anchor_line),
+        };
+        let wrapped_index = match direction {
+            SearchDirection::Forward => 0,
+            SearchDirection::Backward => self.match_lines.len() - 1,
+        };
+
+        self.focused_match_index = Some(next_index.unwrap_or(wrapped_index));
+    }
+}
+
+/// Returns byte ranges of every `query` occurrence inside `text`.
+///
+/// Matching is smart-case: queries without uppercase letters ignore ASCII
+/// case, while any uppercase letter makes the match exact.
+pub fn search_match_ranges(text: &str, query: &str) -> Vec<Range<usize>> {
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let is_case_sensitive = query.chars().any(char::is_uppercase);
+    let (haystack, needle) = if is_case_sensitive {
+        (text.to_string(), query.to_string())
+    } else {
+        (text.to_ascii_lowercase(), query.to_ascii_lowercase())
+    };
+
+    haystack
+        .match_indices(&needle)
+        .map(|(start, matched)| start..start + matched.len())
+        .collect()
+}
+
+/// Returns byte ranges of every `query` match inside the rendered span text
+/// of `line`.
+///
+/// Matching runs on the visible text, so hyperlink tag characters never
+/// split a word. Each range is mapped back onto the rendered text, and its
+/// end is moved past the tag characters of the last matched character so
+/// highlighting never separates a character from its link target.
+pub fn line_match_ranges(line: &Line<'_>, query: &str) -> Vec<Range<usize>> {
+    let rendered_text = rendered_line_text(line);
+    let mut visible_text = String::with_capacity(rendered_text.len());
+    let mut rendered_offsets = Vec::with_capacity(rendered_text.len() + 1);
+    for (rendered_offset, character) in rendered_text.char_indices() {
+        if HYPERLINK_TAG_CHARACTERS.contains(&character) {
+            continue;
+        }
+
+        visible_text.push(character);
+        rendered_offsets.resize(visible_text.len(), rendered_offset);
+    }
+    rendered_offsets.push(rendered_text.len());
+
+    search_match_ranges(&visible_text, query)
+        .into_iter()
+        .map(|range| rendered_offsets[range.start]..rendered_offsets[range.end])
+        .collect()
+}
+
+/// Returns indices of rendered lines containing at least one `query` match.
+pub fn matching_line_indices(lines: &[Line<'_>], query: &str) -> Vec<usize> {
+    lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| !line_match_ranges(line, query).is_empty())
+        .map(|(line_index, _)| line_index)
+        .collect()
+}
+
+/// Returns the rendered line to scroll to for the next or previous user
+/// prompt relative to `top_line`.
+///
+/// The returned line is the padding row above the prompt marker so the whole
+/// prompt block stays visible after the jump.
+pub fn adjacent_prompt_line(
+    lines: &[Line<'_>],
+    top_line: usize,
+    direction: SearchDirection,
+) -> Option<usize> {
+    let mut prompt_lines = lines
+        .iter()
+        .enumerate()
+        .filter(|(_, line)| markdown::is_user_prompt_start_line(line))
+        .map(|(line_index, _)| line_index.saturating_sub(1));
+
+    match direction {
+        SearchDirection::Forward => prompt_lines.find(|line_index| *line_index > top_line),
+        SearchDirection::Backward => prompt_lines.rfind(|line_index| *line_index < top_line),
+    }
+}
+
+/// Concatenates the visible span text of one rendered line, without
+/// hyperlink tag characters.
+pub fn line_text(line: &Line<'_>) -> String {
+    rendered_line_text(line)
+        .chars()
+        .filter(|character| !HYPERLINK_TAG_CHARACTERS.contains(character))
+        .collect()
+}
+
+/// Concatenates the raw span text of one rendered line.
+fn rendered_line_text(line: &Line<'_>) -> String {
+    line.spans
+        .iter()
+        .map(|span| span.content.as_ref())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Builds plain rendered lines from string slices.
+    fn plain_lines(texts: &[&'static str]) -> Vec<Line<'static>> {
+        texts.iter().map(|text| Line::from(*text)).collect()
+    }
+
+    #[test]
+    fn test_search_match_ranges_uses_smart_case() {
+        // Arrange
+        let text = "Error: error ERROR";
+
+        // Act
+        let insensitive_ranges = search_match_ranges(text, "error");
+        let sensitive_ranges = search_match_ranges(text, "ERROR");
+
+        // Assert
+        assert_eq!(insensitive_ranges, vec![0..5, 7..12, 13..18]);
+        assert_eq!(sensitive_ranges, vec![13..18]);
+        assert!(search_match_ranges(text, "").is_empty());
+    }
+
+    #[test]
+    fn test_line_match_ranges_match_across_markdown_link_text() {
+        // Arrange
+        let lines =
+            markdown::render_markdown("See the [setup guide](https://example.com/setup).", 80);
+        let line = lines
+            .iter()
+            .find(|line| line_text(line).contains("setup guide"))
+            .expect("link line should render");
+        let rendered_text = rendered_line_text(line);
+
+        // Act
+        let match_ranges = line_match_ranges(line, "setup guide");
+        let line_indices = matching_line_indices(&lines, "setup guide");
+
+        // Assert
+        assert_ne!(rendered_text, line_text(line));
+        assert_eq!(match_ranges.len(), 1);
+        let matched_text = &rendered_text[match_ranges[0].clone()];
+        assert_eq!(
+            matched_text
+                .chars()
+                .filter(|character| !HYPERLINK_TAG_CHARACTERS.contains(character))
+                .collect::<String>(),
+            "setup guide"
+        );
+        assert!(
+            matched_text
+                .chars()
+                .last()
+                .is_some_and(|character| HYPERLINK_TAG_CHARACTERS.contains(&character))
+        );
+        assert_eq!(line_indices.len(), 1);
+    }
+
+    #[test]
+    fn test_refresh_matches_focuses_first_match_after_anchor() {
+        // Arrange
+        let lines = plain_lines(&["alpha", "beta", "alpha", "gamma", "alpha"]);
+        let mut search_state = TranscriptSearchState::new(None);
+        search_state.input = InputState::with_text("alpha".to_string());
+
+        // Act
+        search_state.refresh_matches(&lines, 1);
+
+        // Assert
+        assert_eq!(search_state.match_lines, vec![0, 2, 4]);
+        assert_eq!(search_state.focused_line(), Some(2));
+    }
+
+    #[test]
+    fn test_refresh_matches_wraps_to_first_match_past_last_line() {
+        // Arrange
+        let lines = plain_lines(&["alpha", "beta"]);
+        let mut search_state = TranscriptSearchState::new(None);
+        search_state.input = InputState::with_text("alpha".to_string());
+
+        // Act
+        search_state.refresh_matches(&lines, 1);
+
+        // Assert
+        assert_eq!(search_state.focused_line(), Some(0));
+    }
+
+    #[test]
+    fn test_focus_adjacent_match_wraps_in_both_directions() {
+        // Arrange
+        let lines = plain_lines(&["alpha", "beta", "alpha"]);
+        let mut search_state = TranscriptSearchState::new(None);
+        search_state.input = InputState::with_text("alpha".to_string());
+        search_state.refresh_matches(&lines, 2);
+
+        // Act
+        search_state.focus_adjacent_match(&lines, 0, SearchDirection::Forward);
+        let wrapped_forward_line = search_state.focused_line();
+        search_state.focus_adjacent_match(&lines, 0, SearchDirection::Backward);
+        let wrapped_backward_line = search_state.focused_line();
+
+        // Assert
+        assert_eq!(wrapped_forward_line, Some(0));
+        assert_eq!(wrapped_backward_line, Some(2));
+    }
+
+    #[test]
+    fn test_adjacent_prompt_line_jumps_between_prompt_blocks() {
+        // Arrange
+        let lines = markdown::render_markdown(" › first\n\nanswer\n\n › second\n\nreply", 40);
+        let first_prompt_line = lines
+            .iter()
+            .position(|line| markdown::is_user_prompt_start_line(line))
+            .expect("first prompt should render");
+        let second_prompt_line = lines
+            .iter()
+            .rposition(|line| markdown::is_user_prompt_start_line(line))
+            .expect("second prompt should render");
+
+        // Act
+        let next_line = adjacent_prompt_line(&lines, 0, SearchDirection::Forward);
+        let previous_line =
+            adjacent_prompt_line(&lines, lines.len() - 1, SearchDirection::Backward);
+
+        // Assert
+        assert_ne!(first_prompt_line, second_prompt_line);
+        assert_eq!(next_line, Some(second_prompt_line - 1));
+        assert_eq!(previous_line, Some(second_prompt_line - 1));
+        assert_eq!(
+            adjacent_prompt_line(&lines, second_prompt_line - 1, SearchDirection::Backward),
+            Some(first_prompt_line - 1)
+        );
+    }
+}
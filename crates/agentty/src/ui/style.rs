@@ -4,60 +4,126 @@ use super::icon::Icon;
 use crate::domain::session::{ReviewRequestState, Status};
 
 /// Shared semantic color tokens for the terminal UI.
+///
+/// Each token reads the active [`theme`](crate::ui::theme), so colors follow
+/// the selected dark, light, high-contrast, or user palette at render time.
 pub mod palette {
     use ratatui::style::Color;
 
+    use crate::ui::theme;
+
     /// Primary accent color used for focused UI elements and titles.
-    pub const ACCENT: Color = Color::Cyan;
+    pub fn accent() -> Color {
+        theme::active().accent
+    }
+
     /// Brighter accent used for secondary emphasis.
-    pub const ACCENT_SOFT: Color = Color::LightCyan;
+    pub fn accent_soft() -> Color {
+        theme::active().accent_soft
+    }
+
     /// Subtle border and separator color.
-    pub const BORDER: Color = Color::DarkGray;
+    pub fn border() -> Color {
+        theme::active().border
+    }
+
     /// Error/danger color for destructive or failed states.
-    pub const DANGER: Color = Color::Red;
+    pub fn danger() -> Color {
+        theme::active().danger
+    }
+
     /// Softer danger tone used for graded severity scales.
-    pub const DANGER_SOFT: Color = Color::LightRed;
+    pub fn danger_soft() -> Color {
+        theme::active().danger_soft
+    }
+
     /// Informational color used for neutral-highlight states.
-    pub const INFO: Color = Color::LightBlue;
+    pub fn info() -> Color {
+        theme::active().info
+    }
+
     /// Color used for question-status emphasis.
-    pub const QUESTION: Color = Color::LightMagenta;
+    pub fn question() -> Color {
+        theme::active().question
+    }
+
     /// Base surface color for bars and selected rows.
-    pub const SURFACE: Color = Color::DarkGray;
+    pub fn surface() -> Color {
+        theme::active().surface
+    }
+
     /// Subtle danger-tinted surface used behind removed diff lines.
-    pub const SURFACE_DANGER: Color = Color::Rgb(48, 24, 24);
+    pub fn surface_danger() -> Color {
+        theme::active().surface_danger
+    }
+
     /// Elevated surface color for table headers.
-    pub const SURFACE_ELEVATED: Color = Color::Gray;
+    pub fn surface_elevated() -> Color {
+        theme::active().surface_elevated
+    }
+
+    /// Subtle info-tinted surface used behind clarification blocks.
+    pub fn surface_info() -> Color {
+        theme::active().surface_info
+    }
+
     /// Subtle success-tinted surface used behind added diff lines.
-    pub const SURFACE_SUCCESS: Color = Color::Rgb(18, 44, 26);
+    pub fn surface_success() -> Color {
+        theme::active().surface_success
+    }
+
     /// Dark surface used to dim background content behind modal overlays.
-    pub const SURFACE_OVERLAY: Color = Color::Black;
+    pub fn surface_overlay() -> Color {
+        theme::active().surface_overlay
+    }
+
     /// Primary readable text color.
-    pub const TEXT: Color = Color::White;
+    pub fn text() -> Color {
+        theme::active().text
+    }
+
     /// Muted text color for secondary copy.
-    pub const TEXT_MUTED: Color = Color::Gray;
+    pub fn text_muted() -> Color {
+        theme::active().text_muted
+    }
+
     /// Extra-muted text color for placeholders and hints.
-    pub const TEXT_SUBTLE: Color = Color::DarkGray;
+    pub fn text_subtle() -> Color {
+        theme::active().text_subtle
+    }
+
     /// Success color for positive states.
-    pub const SUCCESS: Color = Color::Green;
+    pub fn success() -> Color {
+        theme::active().success
+    }
+
     /// Softer success tone used for graded severity scales.
-    pub const SUCCESS_SOFT: Color = Color::LightGreen;
+    pub fn success_soft() -> Color {
+        theme::active().success_soft
+    }
+
     /// Warning color for in-progress and caution states.
-    pub const WARNING: Color = Color::Yellow;
+    pub fn warning() -> Color {
+        theme::active().warning
+    }
+
     /// Softer warning tone used for graded severity scales.
-    pub const WARNING_SOFT: Color = Color::LightYellow;
+    pub fn warning_soft() -> Color {
+        theme::active().warning_soft
+    }
 }
 
 /// Returns the terminal color used for one session status label.
 pub fn status_color(status: Status) -> Color {
     match status {
-        Status::New => palette::TEXT_MUTED,
-        Status::InProgress => palette::WARNING,
-        Status::Review | Status::AgentReview => palette::INFO,
-        Status::Question => palette::QUESTION,
-        Status::Queued => palette::ACCENT_SOFT,
-        Status::Rebasing | Status::Merging => palette::ACCENT,
-        Status::Done => palette::SUCCESS,
-        Status::Canceled => palette::DANGER,
+        Status::New => palette::text_muted(),
+        Status::InProgress => palette::warning(),
+        Status::Review | Status::AgentReview => palette::info(),
+        Status::Question => palette::question(),
+        Status::Queued => palette::accent_soft(),
+        Status::Rebasing | Status::Merging => palette::accent(),
+        Status::Done => palette::success(),
+        Status::Canceled => palette::danger(),
     }
 }
 
@@ -79,10 +145,10 @@ pub fn status_icon(status: Status) -> Icon {
 /// or a soft accent when only a published branch exists (`None`).
 pub fn forge_indicator_color(state: Option<ReviewRequestState>) -> Color {
     match state {
-        Some(ReviewRequestState::Open) => palette::WARNING,
-        Some(ReviewRequestState::Merged) => palette::SUCCESS,
-        Some(ReviewRequestState::Closed) => palette::DANGER,
-        None => palette::ACCENT_SOFT,
+        Some(ReviewRequestState::Open) => palette::warning(),
+        Some(ReviewRequestState::Merged) => palette::success(),
+        Some(ReviewRequestState::Closed) => palette::danger(),
+        None => palette::accent_soft(),
     }
 }
 
@@ -96,7 +162,7 @@ mod tests {
         let color = status_color(Status::New);
 
         // Assert
-        assert_eq!(color, palette::TEXT_MUTED);
+        assert_eq!(color, palette::text_muted());
     }
 
     #[test]
@@ -105,7 +171,7 @@ mod tests {
         let color = status_color(Status::Done);
 
         // Assert
-        assert_eq!(color, palette::SUCCESS);
+        assert_eq!(color, palette::success());
     }
 
     #[test]
@@ -114,7 +180,7 @@ mod tests {
         let color = status_color(Status::Canceled);
 
         // Assert
-        assert_eq!(color, palette::DANGER);
+        assert_eq!(color, palette::danger());
     }
 
     #[test]
@@ -123,7 +189,7 @@ mod tests {
         let color = status_color(Status::InProgress);
 
         // Assert
-        assert_eq!(color, palette::WARNING);
+        assert_eq!(color, palette::warning());
     }
 
     #[test]
@@ -132,7 +198,7 @@ mod tests {
         let color = status_color(Status::Review);
 
         // Assert
-        assert_eq!(color, palette::INFO);
+        assert_eq!(color, palette::info());
     }
 
     #[test]
@@ -141,7 +207,7 @@ mod tests {
         let color = status_color(Status::Merging);
 
         // Assert
-        assert_eq!(color, palette::ACCENT);
+        assert_eq!(color, palette::accent());
     }
 
     #[test]
@@ -177,7 +243,7 @@ mod tests {
         let color = forge_indicator_color(Some(ReviewRequestState::Open));
 
         // Assert
-        assert_eq!(color, palette::WARNING);
+        assert_eq!(color, palette::warning());
     }
 
     #[test]
@@ -186,7 +252,7 @@ mod tests {
         let color = forge_indicator_color(Some(ReviewRequestState::Merged));
 
         // Assert
-        assert_eq!(color, palette::SUCCESS);
+        assert_eq!(color, palette::success());
     }
 
     #[test]
@@ -195,7 +261,7 @@ mod tests {
         let color = forge_indicator_color(Some(ReviewRequestState::Closed));
 
         // Assert
-        assert_eq!(color, palette::DANGER);
+        assert_eq!(color, palette::danger());
     }
 
     #[test]
@@ -204,6 +270,6 @@ mod tests {
         let color = forge_indicator_color(None);
 
         // Assert
-        assert_eq!(color, palette::ACCENT_SOFT);
+        assert_eq!(color, palette::accent_soft());
     }
 }
@@ -0,0 +1,684 @@
+//! Runtime-selectable color themes behind the shared [`palette`] tokens.
+//!
+//! Every page and component resolves colors through [`palette`], which reads
+//! the process-wide active [`Theme`]. The runtime installs one theme at
+//! startup from the persisted [`ThemeMode`], and the settings page swaps it
+//! in place when the user picks another mode.
+//!
+//! [`palette`]: crate::ui::style::palette
+
+#[cfg(test)]
+use std::cell::Cell;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::OnceLock;
+#[cfg(not(test))]
+use std::sync::atomic::{AtomicU64, Ordering};
+#[cfg(not(test))]
+use std::sync::{PoisonError, RwLock};
+use std::{fs, io};
+
+use ratatui::style::Color;
+use serde::Deserialize;
+
+/// File name of the optional user theme inside the agentty home directory.
+pub const THEME_FILE: &str = "theme.toml";
+
+/// Relative luminance above which a terminal background counts as light.
+const LIGHT_BACKGROUND_LUMINANCE: f64 = 0.5;
+
+/// Theme currently used by every palette lookup.
+#[cfg(not(test))]
+static ACTIVE_THEME: RwLock<Theme> = RwLock::new(Theme::dark());
+
+/// Number of [`apply`] calls so far, used to invalidate color-bearing render
+/// caches.
+#[cfg(not(test))]
+static THEME_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(test)]
+thread_local! {
+    /// Per-thread active theme so tests that switch themes cannot change the
+    /// colors seen by tests running in parallel.
+    static ACTIVE_THEME: Cell<Theme> = const { Cell::new(Theme::dark()) };
+
+    /// Per-thread counterpart of the production theme generation counter.
+    static THEME_GENERATION: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Terminal background tone detected once during terminal setup.
+static DETECTED_BACKGROUND: OnceLock<Background> = OnceLock::new();
+
+/// User theme loaded from [`THEME_FILE`] during startup.
+static CUSTOM_THEME: OnceLock<CustomTheme> = OnceLock::new();
+
+/// Errors raised while loading a user theme file.
+#[derive(Debug, thiserror::Error)]
+pub enum ThemeError {
+    /// The theme file exists but could not be read.
+    #[error("Failed to read theme file `{}`: {source}", path.display())]
+    Read {
+        /// Theme file path.
+        path: PathBuf,
+        /// Underlying I/O failure.
+        source: io::Error,
+    },
+    /// The theme file is not valid TOML for the expected shape.
+    #[error("Failed to parse theme file `{}`: {source}", path.display())]
+    Parse {
+        /// Theme file path.
+        path: PathBuf,
+        /// Underlying TOML parse failure.
+        source: toml::de::Error,
+    },
+    /// The `base` key names a theme that does not exist.
+    #[error("Unknown base theme `{0}`; expected `auto`, `dark`, `light`, or `high-contrast`")]
+    UnknownBase(String),
+    /// A `[colors]` key does not name a palette token.
+    #[error("Unknown theme color `{0}`")]
+    UnknownToken(String),
+    /// A `[colors]` value is not a color name, index, or `#rrggbb` value.
+    #[error("Invalid color `{value}` for theme color `{token}`")]
+    InvalidColor {
+        /// Palette token being overridden.
+        token: String,
+        /// Rejected color value.
+        value: String,
+    },
+}
+
+/// Full set of semantic color tokens used by the terminal UI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Theme {
+    /// Primary accent color used for focused UI elements and titles.
+    pub accent: Color,
+    /// Brighter accent used for secondary emphasis.
+    pub accent_soft: Color,
+    /// Subtle border and separator color.
+    pub border: Color,
+    /// Error/danger color for destructive or failed states.
+    pub danger: Color,
+    /// Softer danger tone used for graded severity scales.
+    pub danger_soft: Color,
+    /// Informational color used for neutral-highlight states.
+    pub info: Color,
+    /// Color used for question-status emphasis.
+    pub question: Color,
+    /// Base surface color for bars and selected rows.
+    pub surface: Color,
+    /// Subtle danger-tinted surface used behind removed diff lines.
+    pub surface_danger: Color,
+    /// Elevated surface color for table headers.
+    pub surface_elevated: Color,
+    /// Subtle info-tinted surface used behind clarification blocks.
+    pub surface_info: Color,
+    /// Subtle success-tinted surface used behind added diff lines.
+    pub surface_success: Color,
+    /// Backdrop surface behind modal overlays and text on accent fills.
+    pub surface_overlay: Color,
+    /// Primary readable text color.
+    pub text: Color,
+    /// Muted text color for secondary copy.
+    pub text_muted: Color,
+    /// Extra-muted text color for placeholders and hints.
+    pub text_subtle: Color,
+    /// Success color for positive states.
+    pub success: Color,
+    /// Softer success tone used for graded severity scales.
+    pub success_soft: Color,
+    /// Warning color for in-progress and caution states.
+    pub warning: Color,
+    /// Softer warning tone used for graded severity scales.
+    pub warning_soft: Color,
+}
+
+impl Theme {
+    /// Returns the default palette tuned for dark terminal backgrounds.
+    pub const fn dark() -> Self {
+        Self {
+            accent: Color::Cyan,
+            accent_soft: Color::LightCyan,
+            border: Color::DarkGray,
+            danger: Color::Red,
+            danger_soft: Color::LightRed,
+            info: Color::LightBlue,
+            question: Color::LightMagenta,
+            surface: Color::DarkGray,
+            surface_danger: Color::Rgb(48, 24, 24),
+            surface_elevated: Color::Gray,
+            surface_info: Color::Rgb(28, 38, 48),
+            surface_success: Color::Rgb(18, 44, 26),
+            surface_overlay: Color::Black,
+            text: Color::White,
+            text_muted: Color::Gray,
+            text_subtle: Color::DarkGray,
+            success: Color::Green,
+            success_soft: Color::LightGreen,
+            warning: Color::Yellow,
+            warning_soft: Color::LightYellow,
+        }
+    }
+
+    /// Returns a palette tuned for light terminal backgrounds.
+    ///
+    /// Colors are explicit RGB values because light terminal schemes remap
+    /// the named ANSI colors inconsistently.
+    pub const fn light() -> Self {
+        Self {
+            accent: Color::Rgb(0, 95, 135),
+            accent_soft: Color::Rgb(0, 125, 160),
+            border: Color::Rgb(168, 168, 168),
+            danger: Color::Rgb(175, 0, 0),
+            danger_soft: Color::Rgb(200, 70, 70),
+            info: Color::Rgb(0, 85, 170),
+            question: Color::Rgb(135, 0, 135),
+            surface: Color::Rgb(220, 220, 220),
+            surface_danger: Color::Rgb(255, 222, 222),
+            surface_elevated: Color::Rgb(190, 190, 190),
+            surface_info: Color::Rgb(222, 234, 246),
+            surface_success: Color::Rgb(214, 245, 220),
+            surface_overlay: Color::Rgb(250, 250, 250),
+            text: Color::Rgb(28, 28, 28),
+            text_muted: Color::Rgb(88, 88, 88),
+            text_subtle: Color::Rgb(138, 138, 138),
+            success: Color::Rgb(0, 120, 0),
+            success_soft: Color::Rgb(60, 150, 60),
+            warning: Color::Rgb(170, 90, 0),
+            warning_soft: Color::Rgb(200, 125, 0),
+        }
+    }
+
+    /// Returns a palette that maximizes contrast on dark backgrounds.
+    pub const fn high_contrast() -> Self {
+        Self {
+            accent: Color::LightCyan,
+            accent_soft: Color::White,
+            border: Color::White,
+            danger: Color::LightRed,
+            danger_soft: Color::LightRed,
+            info: Color::LightBlue,
+            question: Color::LightMagenta,
+            surface: Color::Blue,
+            surface_danger: Color::Rgb(110, 0, 0),
+            surface_elevated: Color::White,
+            surface_info: Color::Rgb(0, 0, 95),
+            surface_success: Color::Rgb(0, 85, 0),
+            surface_overlay: Color::Black,
+            text: Color::White,
+            text_muted: Color::White,
+            text_subtle: Color::Gray,
+            success: Color::LightGreen,
+            success_soft: Color::LightGreen,
+            warning: Color::LightYellow,
+            warning_soft: Color::LightYellow,
+        }
+    }
+
+    /// Replaces the color for the token named `name` and returns whether the
+    /// name matched a palette token.
+    fn set_token(&mut self, name: &str, color: Color) -> bool {
+        let slot = match name {
+            "accent" => &mut self.accent,
+            "accent_soft" => &mut self.accent_soft,
+            "border" => &mut self.border,
+            "danger" => &mut self.danger,
+            "danger_soft" => &mut self.danger_soft,
+            "info" => &mut self.info,
+            "question" => &mut self.question,
+            "surface" => &mut self.surface,
+            "surface_danger" => &mut self.surface_danger,
+            "surface_elevated" => &mut self.surface_elevated,
+            "surface_info" => &mut self.surface_info,
+            "surface_success" => &mut self.surface_success,
+            "surface_overlay" => &mut self.surface_overlay,
+            "text" => &mut self.text,
+            "text_muted" => &mut self.text_muted,
+            "text_subtle" => &mut self.text_subtle,
+            "success" => &mut self.success,
+            "success_soft" => &mut self.success_soft,
+            "warning" => &mut self.warning,
+            "warning_soft" => &mut self.warning_soft,
+            _ => return false,
+        };
+        *slot = color;
+
+        true
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// User-selectable theme choice persisted in settings.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum ThemeMode {
+    /// Picks the dark or light palette from the detected terminal background.
+    #[default]
+    Auto,
+    /// Always uses the dark palette.
+    Dark,
+    /// Always uses the light palette.
+    Light,
+    /// Always uses the high-contrast palette.
+    HighContrast,
+    /// Uses the user theme from [`THEME_FILE`], falling back to `Auto`.
+    Custom,
+}
+
+impl ThemeMode {
+    /// All modes in settings-selector order.
+    pub const ALL: [Self; 5] = [
+        Self::Auto,
+        Self::Dark,
+        Self::Light,
+        Self::HighContrast,
+        Self::Custom,
+    ];
+
+    /// Returns the persisted identifier for this mode.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Dark => "dark",
+            Self::Light => "light",
+            Self::HighContrast => "high-contrast",
+            Self::Custom => "custom",
+        }
+    }
+
+    /// Returns the settings-page label for this mode.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Auto => "Auto",
+            Self::Dark => "Dark",
+            Self::Light => "Light",
+            Self::HighContrast => "High Contrast",
+            Self::Custom => "Custom",
+        }
+    }
+
+    /// Returns the next mode in selector order, wrapping at the end.
+    #[must_use]
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|mode| *mode == self).unwrap_or(0);
+
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+impl FromStr for ThemeMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|mode| mode.as_str() == value)
+            .ok_or(())
+    }
+}
+
+/// Light or dark tone of the terminal background.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Background {
+    /// Background with low luminance.
+    Dark,
+    /// Background with high luminance.
+    Light,
+}
+
+/// User theme parsed from [`THEME_FILE`]: a base mode plus token overrides.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomTheme {
+    base: ThemeMode,
+    overrides: Vec<(String, Color)>,
+}
+
+impl CustomTheme {
+    /// Validates a deserialized theme file.
+    ///
+    /// `base` accepts every mode except `custom` and defaults to `auto`.
+    /// `[colors]` keys are palette token names such as `surface_success`, and
+    /// values accept ANSI names, indexes, or `#rrggbb`.
+    ///
+    /// # Errors
+    /// Returns an error for an unknown base, token, or color value.
+    fn from_file(file: ThemeFile) -> Result<Self, ThemeError> {
+        let base = match file.base {
+            None => ThemeMode::Auto,
+            Some(base) => base
+                .parse::<ThemeMode>()
+                .ok()
+                .filter(|mode| *mode != ThemeMode::Custom)
+                .ok_or(ThemeError::UnknownBase(base))?,
+        };
+
+        let mut validation_theme = Theme::dark();
+        let mut overrides = Vec::with_capacity(file.colors.len());
+        for (token, value) in file.colors {
+            let color = Color::from_str(&value).map_err(|_| ThemeError::InvalidColor {
+                token: token.clone(),
+                value: value.clone(),
+            })?;
+            if !validation_theme.set_token(&token, color) {
+                return Err(ThemeError::UnknownToken(token));
+            }
+
+            overrides.push((token, color));
+        }
+
+        Ok(Self { base, overrides })
+    }
+
+    /// Applies the overrides on top of the resolved base theme.
+    fn apply_to(&self, mut theme: Theme) -> Theme {
+        for (token, color) in &self.overrides {
+            theme.set_token(token, *color);
+        }
+
+        theme
+    }
+}
+
+/// On-disk shape of [`THEME_FILE`].
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ThemeFile {
+    base: Option<String>,
+    #[serde(default)]
+    colors: BTreeMap<String, String>,
+}
+
+/// Loads the user theme from `path`.
+///
+/// A missing file is not an error and yields `None` so `Custom` falls back to
+/// the automatic palette.
+///
+/// # Errors
+/// Returns an error when the file cannot be read, is not valid TOML, or
+/// names an unknown base, token, or color.
+pub fn load_custom(path: &Path) -> Result<Option<CustomTheme>, ThemeError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) if error.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(ThemeError::Read {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    parse_custom(&content, path).map(Some)
+}
+
+/// Parses user theme TOML text read from `path`.
+fn parse_custom(content: &str, path: &Path) -> Result<CustomTheme, ThemeError> {
+    let file = toml::from_str::<ThemeFile>(content).map_err(|source| ThemeError::Parse {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    CustomTheme::from_file(file)
+}
+
+/// Stores the user theme for later `Custom` resolution.
+///
+/// Returns `false` when a user theme was already installed.
+pub fn install_custom(custom_theme: CustomTheme) -> bool {
+    CUSTOM_THEME.set(custom_theme).is_ok()
+}
+
+/// Stores the terminal background tone detected during terminal setup.
+///
+/// Returns `false` when a background was already recorded.
+pub fn record_background(background: Background) -> bool {
+    DETECTED_BACKGROUND.set(background).is_ok()
+}
+
+/// Resolves `mode` against the recorded background and user theme, then
+/// makes it the active theme for every subsequent palette lookup.
+pub fn apply(mode: ThemeMode) {
+    let theme = resolve(mode, DETECTED_BACKGROUND.get().copied(), CUSTOM_THEME.get());
+
+    #[cfg(not(test))]
+    {
+        *ACTIVE_THEME.write().unwrap_or_else(PoisonError::into_inner) = theme;
+        THEME_GENERATION.fetch_add(1, Ordering::Relaxed);
+    }
+    #[cfg(test)]
+    {
+        ACTIVE_THEME.set(theme);
+        THEME_GENERATION.set(THEME_GENERATION.get() + 1);
+    }
+}
+
+/// Returns the active theme.
+pub fn active() -> Theme {
+    #[cfg(not(test))]
+    {
+        *ACTIVE_THEME.read().unwrap_or_else(PoisonError::into_inner)
+    }
+    #[cfg(test)]
+    ACTIVE_THEME.get()
+}
+
+/// Returns a counter that changes every time [`apply`] installs a theme.
+///
+/// Caches that store resolved colors compare it to drop stale entries.
+pub fn generation() -> u64 {
+    #[cfg(not(test))]
+    {
+        THEME_GENERATION.load(Ordering::Relaxed)
+    }
+    #[cfg(test)]
+    THEME_GENERATION.get()
+}
+
+/// Resolves one theme mode into concrete colors.
+///
+/// `Auto` falls back to the dark palette when the background is unknown, and
+/// `Custom` falls back to `Auto` when no user theme was loaded.
+pub fn resolve(
+    mode: ThemeMode,
+    background: Option<Background>,
+    custom_theme: Option<&CustomTheme>,
+) -> Theme {
+    match mode {
+        ThemeMode::Auto => match background {
+            Some(Background::Light) => Theme::light(),
+            Some(Background::Dark) | None => Theme::dark(),
+        },
+        ThemeMode::Dark => Theme::dark(),
+        ThemeMode::Light => Theme::light(),
+        ThemeMode::HighContrast => Theme::high_contrast(),
+        ThemeMode::Custom => match custom_theme {
+            Some(custom_theme) => {
+                custom_theme.apply_to(resolve(custom_theme.base, background, None))
+            }
+            None => resolve(ThemeMode::Auto, background, None),
+        },
+    }
+}
+
+/// Parses an OSC 11 background-color reply such as
+/// `ESC ] 11 ; rgb:ffff/ffff/ffff BEL` into a background tone.
+///
+/// Channels may use one to four hex digits, and both BEL and ST terminators
+/// are accepted. Returns `None` for replies that do not carry an `rgb:` value.
+pub fn parse_background_response(response: &[u8]) -> Option<Background> {
+    let response = std::str::from_utf8(response).ok()?;
+    let (_, color) = response.split_once("]11;rgb:")?;
+    let color = color.split(['\u{7}', '\u{1b}']).next().unwrap_or_default();
+
+    let mut channels = color.split('/').map(parse_color_channel);
+    let red = channels.next()??;
+    let green = channels.next()??;
+    let blue = channels.next()??;
+    if channels.next().is_some() {
+        return None;
+    }
+
+    let luminance = 0.2126 * red + 0.7152 * green + 0.0722 * blue;
+    if luminance > LIGHT_BACKGROUND_LUMINANCE {
+        Some(Background::Light)
+    } else {
+        Some(Background::Dark)
+    }
+}
+
+/// Parses one 1-4 digit hex channel into the `0.0..=1.0` range.
+fn parse_color_channel(channel: &str) -> Option<f64> {
+    if channel.is_empty() || channel.len() > 4 {
+        return None;
+    }
+
+    let value = u16::from_str_radix(channel, 16).ok()?;
+    let digit_count = u32::try_from(channel.len()).ok()?;
+    let max_value = 16_u32.pow(digit_count) - 1;
+
+    Some(f64::from(value) / f64::from(max_value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_auto_follows_detected_background() {
+        // Arrange
+        let light_background = Some(Background::Light);
+        let dark_background = Some(Background::Dark);
+
+        // Act
+        let light_theme = resolve(ThemeMode::Auto, light_background, None);
+        let dark_theme = resolve(ThemeMode::Auto, dark_background, None);
+        let unknown_theme = resolve(ThemeMode::Auto, None, None);
+
+        // Assert
+        assert_eq!(light_theme, Theme::light());
+        assert_eq!(dark_theme, Theme::dark());
+        assert_eq!(unknown_theme, Theme::dark());
+    }
+
+    #[test]
+    fn test_resolve_custom_applies_overrides_on_base() {
+        // Arrange
+        let custom_theme = parse_custom(
+            r##"
+base = "light"
+
+[colors]
+accent = "#112233"
+surface_success = "green"
+"##,
+            Path::new(THEME_FILE),
+        )
+        .expect("theme should parse");
+
+        // Act
+        let theme = resolve(
+            ThemeMode::Custom,
+            Some(Background::Dark),
+            Some(&custom_theme),
+        );
+
+        // Assert
+        assert_eq!(theme.accent, Color::Rgb(0x11, 0x22, 0x33));
+        assert_eq!(theme.surface_success, Color::Green);
+        assert_eq!(theme.text, Theme::light().text);
+    }
+
+    #[test]
+    fn test_resolve_custom_without_user_theme_falls_back_to_auto() {
+        // Arrange
+        let background = Some(Background::Light);
+
+        // Act
+        let theme = resolve(ThemeMode::Custom, background, None);
+
+        // Assert
+        assert_eq!(theme, Theme::light());
+    }
+
+    #[test]
+    fn test_parse_custom_rejects_unknown_token_and_color() {
+        // Arrange
+        let unknown_token = "[colors]\nbackground = \"red\"\n";
+        let invalid_color = "[colors]\naccent = \"not-a-color\"\n";
+        let unknown_base = "base = \"custom\"\n";
+
+        // Act
+        let path = Path::new(THEME_FILE);
+        let unknown_token_error = parse_custom(unknown_token, path);
+        let invalid_color_error = parse_custom(invalid_color, path);
+        let unknown_base_error = parse_custom(unknown_base, path);
+
+        // Assert
+        assert!(matches!(
+            unknown_token_error,
+            Err(ThemeError::UnknownToken(token)) if token == "background"
+        ));
+        assert!(matches!(
+            invalid_color_error,
+            Err(ThemeError::InvalidColor { token, .. }) if token == "accent"
+        ));
+        assert!(matches!(
+            unknown_base_error,
+            Err(ThemeError::UnknownBase(base)) if base == "custom"
+        ));
+    }
+
+    #[test]
+    fn test_load_custom_returns_none_for_missing_file() {
+        // Arrange
+        let directory = tempfile::tempdir().expect("tempdir should be created");
+
+        // Act
+        let custom_theme = load_custom(&directory.path().join(THEME_FILE));
+
+        // Assert
+        assert!(matches!(custom_theme, Ok(None)));
+    }
+
+    #[test]
+    fn test_parse_background_response_detects_tone() {
+        // Arrange
+        let light_reply = b"\x1b]11;rgb:ffff/ffff/ffff\x07";
+        let dark_reply = b"\x1b]11;rgb:1e1e/1e1e/1e1e\x1b\\";
+        let short_reply = b"\x1b]11;rgb:f/f/e\x07";
+        let invalid_reply = b"\x1b]11;?\x07";
+
+        // Act
+        let light = parse_background_response(light_reply);
+        let dark = parse_background_response(dark_reply);
+        let short = parse_background_response(short_reply);
+        let invalid = parse_background_response(invalid_reply);
+
+        // Assert
+        assert_eq!(light, Some(Background::Light));
+        assert_eq!(dark, Some(Background::Dark));
+        assert_eq!(short, Some(Background::Light));
+        assert_eq!(invalid, None);
+    }
+
+    #[test]
+    fn test_theme_mode_round_trips_and_cycles() {
+        // Arrange
+        let modes = ThemeMode::ALL;
+
+        // Act
+        let parsed_modes: Vec<ThemeMode> = modes
+            .iter()
+            .map(|mode| mode.as_str().parse().expect("mode should parse"))
+            .collect();
+
+        // Assert
+        assert_eq!(parsed_modes, modes);
+        assert_eq!(ThemeMode::Custom.next(), ThemeMode::Auto);
+        assert_eq!(ThemeMode::Auto.next(), ThemeMode::Dark);
+    }
+}
@@ -70,10 +70,10 @@ fn tab_key_switches_tabs() -> E2eResult {
     Ok(())
 }
 
-/// Verify that pressing Tab cycles through all four tabs in order.
+/// Verify that pressing Tab cycles through all five tabs in order.
 ///
-/// Starts on Projects, presses Tab three times, and asserts each
-/// successive tab becomes selected: Sessions → Stats → Settings.
+/// Starts on Projects, presses Tab four times, and asserts each
+/// successive tab becomes selected: Sessions → Dashboard → Stats → Settings.
 #[test]
 fn tab_cycles_through_all_tabs() -> E2eResult {
     // Arrange, Act, Assert
@@ -91,6 +91,9 @@ fn tab_cycles_through_all_tabs() -> E2eResult {
                     .compose(&common::switch_to_tab("Sessions"))
                     .viewing_pause_ms(2000)
                     .capture_labeled("sessions", "Sessions tab selected")
+                    .compose(&common::switch_to_tab("Dashboard"))
+                    .viewing_pause_ms(2000)
+                    .capture_labeled("dashboard", "Dashboard tab selected")
                     .compose(&common::switch_to_tab("Stats"))
                     .viewing_pause_ms(2000)
                     .capture_labeled("stats", "Stats tab selected")
@@ -104,17 +107,17 @@ fn tab_cycles_through_all_tabs() -> E2eResult {
 
                 assert_eq!(
                     report.captures.len(),
-                    3,
-                    "Expected 3 captures (sessions, stats, settings)"
+                    4,
+                    "Expected 4 captures (sessions, dashboard, stats, settings)"
                 );
 
                 let sessions_frame = common::frame_from_capture(&report.captures[0]);
                 let sessions_full = Region::full(sessions_frame.cols(), sessions_frame.rows());
                 assertion::assert_text_in_region(&sessions_frame, "No sessions", &sessions_full);
 
-                let stats_frame = common::frame_from_capture(&report.captures[1]);
+                let stats_frame = common::frame_from_capture(&report.captures[2]);
                 let stats_full = Region::full(stats_frame.cols(), stats_frame.rows());
-                assertion::assert_text_in_region(&stats_frame, "TokenStats", &stats_full);
+                assertion::assert_text_in_region(&stats_frame, "Token", &stats_full);
             },
         )?;
 
@@ -187,8 +190,8 @@ fn startup_shows_footer_hints() -> E2eResult {
 /// Verify that `BackTab` (Shift+Tab) cycles tabs in reverse order.
 ///
 /// Starts on Projects (first tab), navigates forward to Settings (last tab)
-/// via three Tab presses, then presses `BackTab` three times to cycle back
-/// through Stats → Sessions → Projects.
+/// via four Tab presses, then presses `BackTab` four times to cycle back
+/// through Stats → Dashboard → Sessions → Projects.
 #[test]
 fn backtab_cycles_tabs_reverse() -> E2eResult {
     // Arrange, Act, Assert
@@ -203,6 +206,7 @@ fn backtab_cycles_tabs_reverse() -> E2eResult {
                 scenario
                     .compose(&common::wait_for_agentty_startup())
                     .compose(&common::switch_to_tab("Sessions"))
+                    .compose(&common::switch_to_tab("Dashboard"))
                     .compose(&common::switch_to_tab("Stats"))
                     .compose(&common::switch_to_tab("Settings"))
                     .viewing_pause_ms(2000)
@@ -210,12 +214,15 @@ fn backtab_cycles_tabs_reverse() -> E2eResult {
                     .compose(&common::switch_to_tab_reverse("Stats"))
                     .viewing_pause_ms(1500)
                     .capture_labeled("back_to_stats", "Stats tab after first BackTab")
+                    .compose(&common::switch_to_tab_reverse("Dashboard"))
+                    .viewing_pause_ms(1500)
+                    .capture_labeled("back_to_dashboard", "Dashboard tab after second BackTab")
                     .compose(&common::switch_to_tab_reverse("Sessions"))
                     .viewing_pause_ms(1500)
-                    .capture_labeled("back_to_sessions", "Sessions tab after second BackTab")
+                    .capture_labeled("back_to_sessions", "Sessions tab after third BackTab")
                     .compose(&common::switch_to_tab_reverse("Projects"))
                     .viewing_pause_ms(2000)
-                    .capture_labeled("back_to_projects", "Projects tab after third BackTab")
+                    .capture_labeled("back_to_projects", "Projects tab after fourth BackTab")
             },
             |frame, report| {
                 let full = Region::full(frame.cols(), frame.rows());
@@ -223,13 +230,13 @@ fn backtab_cycles_tabs_reverse() -> E2eResult {
 
                 let stats_frame = common::frame_from_capture(&report.captures[1]);
                 let stats_full = Region::full(stats_frame.cols(), stats_frame.rows());
-                assertion::assert_text_in_region(&stats_frame, "TokenStats", &stats_full);
+                assertion::assert_text_in_region(&stats_frame, "Token", &stats_full);
 
-                let sessions_frame = common::frame_from_capture(&report.captures[2]);
+                let sessions_frame = common::frame_from_capture(&report.captures[3]);
                 let sessions_full = Region::full(sessions_frame.cols(), sessions_frame.rows());
                 assertion::assert_text_in_region(&sessions_frame, "No sessions", &sessions_full);
 
-                let projects_frame = common::frame_from_capture(&report.captures[3]);
+                let projects_frame = common::frame_from_capture(&report.captures[4]);
                 let projects_full = Region::full(projects_frame.cols(), projects_frame.rows());
                 assertion::assert_text_in_region(&projects_frame, "test-project", &projects_full);
             },
@@ -3,6 +3,7 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use agentty::domain::agent::{AgentKind, AgentModel, ReasoningLevel};
+use agentty::domain::permission::PermissionMode;
 use agentty::infra::app_server_router::RoutingAppServerClient;
 use agentty::infra::channel::{
     AgentChannel, AgentRequestKind, StartSessionRequest, TurnRequest, create_agent_channel,
@@ -245,6 +246,9 @@ fn build_turn_request(folder: PathBuf, model: AgentModel) -> TurnRequest {
         prompt: PROTOCOL_COMPLIANCE_PROMPT.to_string().into(),
         provider_conversation_id: None,
         persisted_instruction_conversation_id: None,
+        mcp_servers: Vec::new(),
+        permission_mode: PermissionMode::default(),
+        project_instructions: None,
         reasoning_level: ReasoningLevel::default(),
     }
 }